@@ -14,6 +14,14 @@ pub enum Alignment {
 
     /// Fill the entire axis.
     Fill,
+
+    /// Align the text baselines of the elements on the cross axis of a
+    /// horizontal layout.
+    ///
+    /// It only has a meaning on the cross axis of a `Row`. Anywhere else, like
+    /// in a `Column` or a `Container`, it behaves like [`Alignment::Start`].
+    /// Elements without a baseline are aligned at the start too.
+    Baseline,
 }
 
 impl From<Horizontal> for Alignment {
@@ -1,3 +1,4 @@
+use crate::gradient::{self, Gradient};
 use crate::Color;
 
 /// The background of some element.
@@ -5,7 +6,25 @@ use crate::Color;
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+    /// Colors blending progressively
+    Gradient(Gradient),
+    // TODO: Add image variant
+}
+
+impl Background {
+    /// Returns the [`Background`] with the alpha channel of all of its colors
+    /// multiplied by the given factor.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        match self {
+            Background::Color(color) => Background::Color(Color {
+                a: color.a * factor,
+                ..color
+            }),
+            Background::Gradient(gradient) => {
+                Background::Gradient(gradient.scale_alpha(factor))
+            }
+        }
+    }
 }
 
 impl From<Color> for Background {
@@ -19,3 +38,27 @@ impl From<Color> for Option<Background> {
         Some(Background::from(color))
     }
 }
+
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Background::Gradient(gradient)
+    }
+}
+
+impl From<gradient::Linear> for Background {
+    fn from(linear: gradient::Linear) -> Self {
+        Background::Gradient(Gradient::Linear(linear))
+    }
+}
+
+impl From<Gradient> for Option<Background> {
+    fn from(gradient: Gradient) -> Self {
+        Some(Background::from(gradient))
+    }
+}
+
+impl From<gradient::Linear> for Option<Background> {
+    fn from(linear: gradient::Linear) -> Self {
+        Some(Background::from(linear))
+    }
+}
@@ -1,5 +1,7 @@
 //! Control the fit of some content (like an image) within a space.
-use crate::Size;
+use crate::{Point, Size, Vector};
+
+use std::hash::{Hash, Hasher};
 
 /// The strategy used to fit the contents of a widget to its bounding box.
 ///
@@ -11,7 +13,7 @@ use crate::Size;
 /// in CSS, see [Mozilla's docs][1], or run the `tour` example
 ///
 /// [1]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
-#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub enum ContentFit {
     /// Scale as big as it can be without needing to crop or hide parts.
     ///
@@ -60,6 +62,48 @@ pub enum ContentFit {
     /// image would have been scaled up, it keeps its original resolution to
     /// avoid the bluring that accompanies upscaling images.
     ScaleDown,
+
+    /// Scale the image to cover all of the bounding box, cropping around a
+    /// focal point.
+    ///
+    /// This works much like [`Cover`](Self::Cover), except that the image is
+    /// positioned so its focal point stays as close as possible to the center
+    /// of the widget.
+    ///
+    /// This is best for avatars and hero images, where the subject of the
+    /// image is not in its center.
+    ///
+    /// Focal points are compared by the bits of their coordinates, so a
+    /// [`ContentFit`] is always equal to itself, even with `NaN` coordinates.
+    Crop {
+        /// The focal point of the image, with coordinates normalized in
+        /// `[0.0, 1.0]` from its top-left corner.
+        focal_point: Point,
+    },
+}
+
+impl PartialEq for ContentFit {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Crop { focal_point: a }, Self::Crop { focal_point: b }) => {
+                a.x.to_bits() == b.x.to_bits() && a.y.to_bits() == b.y.to_bits()
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for ContentFit {}
+
+impl Hash for ContentFit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        if let Self::Crop { focal_point } = self {
+            focal_point.x.to_bits().hash(state);
+            focal_point.y.to_bits().hash(state);
+        }
+    }
 }
 
 impl ContentFit {
@@ -84,7 +128,7 @@ impl ContentFit {
                     }
                 }
             }
-            Self::Cover => {
+            Self::Cover | Self::Crop { .. } => {
                 if bounds_ar < content_ar {
                     Size {
                         width: content.width * bounds.height / content.height,
@@ -116,4 +160,81 @@ impl ContentFit {
             }
         }
     }
+
+    /// Returns the offset of the content, once fit to some bounds, from the
+    /// top-left corner of the bounds.
+    ///
+    /// Content smaller than the bounds is centered, while cropped content is
+    /// positioned around its focal point.
+    pub fn offset(&self, fitted: Size, bounds: Size) -> Vector {
+        match self {
+            Self::Crop { focal_point } => Vector::new(
+                crop(fitted.width, bounds.width, focal_point.x),
+                crop(fitted.height, bounds.height, focal_point.y),
+            ),
+            _ => Vector::new(
+                (bounds.width - fitted.width).max(0.0) / 2.0,
+                (bounds.height - fitted.height).max(0.0) / 2.0,
+            ),
+        }
+    }
+}
+
+/// Returns the offset of some content along an axis, so its focal point is
+/// as close as possible to the center of the bounds without uncovering them.
+fn crop(content: f32, bounds: f32, focal_point: f32) -> f32 {
+    let overflow = content - bounds;
+
+    if overflow <= 0.0 {
+        return -overflow / 2.0;
+    }
+
+    (bounds / 2.0 - focal_point.clamp(0.0, 1.0) * content).clamp(-overflow, 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crop_keeps_focal_point_centered() {
+        let fit = ContentFit::Crop {
+            focal_point: Point::new(0.5, 0.2),
+        };
+
+        let content = Size::new(100.0, 200.0);
+        let bounds = Size::new(100.0, 100.0);
+        let fitted = fit.fit(content, bounds);
+
+        assert_eq!(fitted, content);
+        assert_eq!(fit.offset(fitted, bounds), Vector::new(0.0, 0.0));
+
+        let fit = ContentFit::Crop {
+            focal_point: Point::new(0.5, 0.75),
+        };
+
+        assert_eq!(fit.offset(fitted, bounds), Vector::new(0.0, -100.0));
+    }
+
+    #[test]
+    fn crop_is_equal_to_itself() {
+        let fit = ContentFit::Crop {
+            focal_point: Point::new(f32::NAN, 0.5),
+        };
+
+        assert_eq!(fit, fit);
+        assert_ne!(fit, ContentFit::Cover);
+    }
+
+    #[test]
+    fn crop_does_not_uncover_bounds() {
+        let fit = ContentFit::Crop {
+            focal_point: Point::new(1.0, 0.0),
+        };
+
+        assert_eq!(
+            fit.offset(Size::new(300.0, 100.0), Size::new(100.0, 100.0)),
+            Vector::new(-200.0, 0.0)
+        );
+    }
 }
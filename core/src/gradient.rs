@@ -0,0 +1,77 @@
+//! Fill an area with colors that blend progressively.
+use crate::Color;
+
+/// A fill which transitions between colors progressively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A linear gradient, along a straight line.
+    Linear(Linear),
+}
+
+impl Gradient {
+    /// Returns the [`Gradient`] with the alpha channel of all of its colors
+    /// multiplied by the given factor.
+    pub fn scale_alpha(self, factor: f32) -> Self {
+        match self {
+            Gradient::Linear(linear) => Gradient::Linear(Linear {
+                start: scale_alpha(linear.start, factor),
+                end: scale_alpha(linear.end, factor),
+                ..linear
+            }),
+        }
+    }
+}
+
+impl From<Linear> for Gradient {
+    fn from(linear: Linear) -> Self {
+        Gradient::Linear(linear)
+    }
+}
+
+/// A gradient between two colors, along a direction crossing the whole area
+/// it fills.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Linear {
+    /// The angle of the direction of the gradient, in radians.
+    ///
+    /// An angle of `0.0` goes from left to right, and it grows clockwise.
+    pub angle: f32,
+
+    /// The color at the start of the gradient.
+    pub start: Color,
+
+    /// The color at the end of the gradient.
+    pub end: Color,
+}
+
+impl Linear {
+    /// Creates a new [`Linear`] gradient with the given angle, in radians,
+    /// and colors.
+    pub fn new(angle: f32, start: Color, end: Color) -> Self {
+        Self { angle, start, end }
+    }
+
+    /// Creates a new [`Linear`] gradient from left to right.
+    pub fn horizontal(start: Color, end: Color) -> Self {
+        Self::new(0.0, start, end)
+    }
+
+    /// Creates a new [`Linear`] gradient from top to bottom.
+    pub fn vertical(start: Color, end: Color) -> Self {
+        Self::new(std::f32::consts::FRAC_PI_2, start, end)
+    }
+
+    /// Returns the unit vector of the direction of the [`Linear`] gradient.
+    pub fn direction(&self) -> [f32; 2] {
+        let (sin, cos) = self.angle.sin_cos();
+
+        [cos, sin]
+    }
+}
+
+fn scale_alpha(color: Color, factor: f32) -> Color {
+    Color {
+        a: color.a * factor,
+        ..color
+    }
+}
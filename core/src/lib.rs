@@ -25,6 +25,7 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 pub mod alignment;
+pub mod gradient;
 pub mod keyboard;
 pub mod mouse;
 pub mod time;
@@ -38,6 +39,7 @@ mod padding;
 mod pixels;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
@@ -46,10 +48,12 @@ pub use background::Background;
 pub use color::Color;
 pub use content_fit::ContentFit;
 pub use font::Font;
+pub use gradient::Gradient;
 pub use length::Length;
 pub use padding::Padding;
 pub use pixels::Pixels;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
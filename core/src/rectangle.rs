@@ -100,6 +100,38 @@ impl Rectangle<f32> {
         }
     }
 
+    /// Returns the area of the [`Rectangle`].
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
+    /// Computes the smallest [`Rectangle`] containing both this one and the
+    /// given [`Rectangle`].
+    pub fn union(&self, other: &Rectangle<f32>) -> Rectangle<f32> {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        let lower_right_x = (self.x + self.width).max(other.x + other.width);
+        let lower_right_y = (self.y + self.height).max(other.y + other.height);
+
+        Rectangle {
+            x,
+            y,
+            width: lower_right_x - x,
+            height: lower_right_y - y,
+        }
+    }
+
+    /// Expands the [`Rectangle`] by the given amount in every direction.
+    pub fn expand(self, amount: f32) -> Rectangle<f32> {
+        Rectangle {
+            x: self.x - amount,
+            y: self.y - amount,
+            width: self.width + amount * 2.0,
+            height: self.height + amount * 2.0,
+        }
+    }
+
     /// Snaps the [`Rectangle`] to __unsigned__ integer coordinates.
     pub fn snap(self) -> Rectangle<u32> {
         Rectangle {
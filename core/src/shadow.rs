@@ -0,0 +1,34 @@
+use crate::{Color, Vector};
+
+/// A shadow cast by a graphics primitive.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Shadow {
+    /// The [`Color`] of the [`Shadow`].
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the primitive casting it.
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`].
+    pub blur_radius: f32,
+
+    /// The amount the [`Shadow`] grows in every direction before being
+    /// blurred.
+    pub spread_radius: f32,
+}
+
+impl Shadow {
+    /// Returns how far the [`Shadow`] extends beyond the primitive casting
+    /// it, in every direction, ignoring its offset.
+    pub fn extent(&self) -> f32 {
+        (self.spread_radius + self.blur_radius).max(0.0)
+    }
+
+    /// Returns whether the [`Shadow`] is visible at all.
+    pub fn is_visible(&self) -> bool {
+        self.color.a > 0.0
+            && (self.blur_radius > 0.0
+                || self.spread_radius > 0.0
+                || self.offset != Vector::ZERO)
+    }
+}
@@ -75,6 +75,7 @@ impl<Message> canvas::Program<Message> for Arc {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         theme: &Theme,
         bounds: Rectangle,
         _cursor: Cursor,
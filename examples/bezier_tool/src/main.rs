@@ -152,6 +152,7 @@ mod bezier {
         fn draw(
             &self,
             state: &Self::State,
+            _renderer: &dyn canvas::Measure,
             _theme: &Theme,
             bounds: Rectangle,
             cursor: Cursor,
@@ -89,6 +89,7 @@ impl<Message> canvas::Program<Message> for Clock {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &Theme,
         bounds: Rectangle,
         _cursor: Cursor,
@@ -243,6 +243,7 @@ impl<Message> canvas::Program<Message> for Theme {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &iced::Theme,
         bounds: Rectangle,
         _cursor: Cursor,
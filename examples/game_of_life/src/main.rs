@@ -536,6 +536,7 @@ mod grid {
         fn draw(
             &self,
             _interaction: &Interaction,
+            _renderer: &dyn canvas::Measure,
             _theme: &Theme,
             bounds: Rectangle,
             cursor: Cursor,
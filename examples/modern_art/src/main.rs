@@ -61,6 +61,7 @@ impl<Message> canvas::Program<Message> for ModernArt {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &Theme,
         bounds: Rectangle,
         _cursor: Cursor,
@@ -125,6 +125,7 @@ impl canvas::Program<Message> for State {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &Theme,
         bounds: Rectangle,
         _cursor: Cursor,
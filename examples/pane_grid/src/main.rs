@@ -107,7 +107,7 @@ impl Application for Example {
                 pane,
                 target,
             }) => {
-                self.panes.swap(&pane, &target);
+                let _ = self.panes.drop(&pane, target);
             }
             Message::Dragged(_) => {}
             Message::TogglePin(pane) => {
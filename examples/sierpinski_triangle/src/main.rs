@@ -134,6 +134,7 @@ impl canvas::Program<Message> for SierpinskiGraph {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &Theme,
         bounds: Rectangle,
         _cursor: canvas::Cursor,
@@ -156,6 +156,7 @@ impl<Message> canvas::Program<Message> for State {
     fn draw(
         &self,
         _state: &Self::State,
+        _renderer: &dyn canvas::Measure,
         _theme: &Theme,
         bounds: Rectangle,
         _cursor: Cursor,
@@ -189,6 +190,7 @@ impl<Message> canvas::Program<Message> for State {
                     width: 1.0,
                     line_dash: canvas::LineDash {
                         offset: 0,
+                        phase: 0.0,
                         segments: &[3.0, 6.0],
                     },
                     ..Stroke::default()
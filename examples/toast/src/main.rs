@@ -506,6 +506,7 @@ mod toast {
                 10.into(),
                 10.0,
                 Alignment::End,
+                layout::flex::Distribution::Start,
                 self.toasts,
             )
             .translate(Vector::new(position.x, position.y))
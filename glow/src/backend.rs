@@ -7,9 +7,12 @@ use crate::{Settings, Transformation, Viewport};
 
 use iced_graphics::backend;
 use iced_graphics::font;
+use iced_graphics::layer::Text;
 use iced_graphics::{Layer, Primitive};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Color, Font, Rectangle, Size};
+
+use glow::HasContext;
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -37,7 +40,8 @@ impl Backend {
         let shader_version = program::Version::new(gl);
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(gl, &shader_version);
+        let image_pipeline =
+            image::Pipeline::new(gl, &shader_version, settings.image_budget);
         let quad_pipeline = quad::Pipeline::new(gl, &shader_version);
         let triangle_pipeline = triangle::Pipeline::new(gl, &shader_version);
 
@@ -51,6 +55,14 @@ impl Backend {
         }
     }
 
+    /// Sets whether triangle primitives are multisampled.
+    ///
+    /// It only has an effect if the OpenGL context was created with
+    /// multisampling.
+    pub fn set_multisampling(&mut self, multisampling: bool) {
+        self.triangle_pipeline.set_multisampling(multisampling);
+    }
+
     /// Draws the provided primitives in the default framebuffer.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -83,6 +95,69 @@ impl Backend {
         self.image_pipeline.trim_cache(gl);
     }
 
+    /// Draws the provided primitives only inside the given regions of the
+    /// default framebuffer, in physical pixels, clearing them with the
+    /// background color first.
+    ///
+    /// The rest of the framebuffer is left untouched.
+    pub fn present_regions<T: AsRef<str>>(
+        &mut self,
+        gl: &glow::Context,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        regions: &[Rectangle<u32>],
+        background_color: Color,
+        overlay_text: &[T],
+    ) {
+        let viewport_size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor() as f32;
+        let projection = viewport.projection();
+
+        let [r, g, b, a] = background_color.into_linear();
+
+        for region in regions {
+            let bounds = Rectangle::<f32>::from(*region) * (1.0 / scale_factor);
+
+            // Clear exactly the pixels covered by the first layer
+            let clear = (bounds * scale_factor).snap();
+
+            unsafe {
+                gl.enable(glow::SCISSOR_TEST);
+                gl.scissor(
+                    clear.x as i32,
+                    viewport_size.height as i32
+                        - (clear.y + clear.height) as i32,
+                    clear.width as i32,
+                    clear.height as i32,
+                );
+                gl.clear_color(r, g, b, a);
+                gl.clear(glow::COLOR_BUFFER_BIT);
+                gl.disable(glow::SCISSOR_TEST);
+            }
+
+            let mut layers = Layer::generate_within(primitives, bounds);
+            let mut overlay = Layer::overlay(overlay_text, viewport);
+
+            if let Some(overlay_bounds) = overlay.bounds.intersection(&bounds) {
+                overlay.bounds = overlay_bounds;
+                layers.push(overlay);
+            }
+
+            for layer in layers {
+                self.flush(
+                    gl,
+                    scale_factor,
+                    projection,
+                    &layer,
+                    viewport_size.height,
+                );
+            }
+        }
+
+        // The images outside of the regions are still visible, so the cache
+        // is only trimmed when presenting whole frames
+    }
+
     fn flush(
         &mut self,
         gl: &glow::Context,
@@ -120,6 +195,7 @@ impl Backend {
                 target_height,
                 scaled,
                 scale_factor,
+                layer.blend_mode,
             );
         }
 
@@ -139,68 +215,15 @@ impl Backend {
         }
 
         if !layer.text.is_empty() {
-            for text in layer.text.iter() {
-                // Target physical coordinates directly to avoid blurry text
-                let text = glow_glyph::Section {
-                    // TODO: We `round` here to avoid rerasterizing text when
-                    // its position changes slightly. This can make text feel a
-                    // bit "jumpy". We may be able to do better once we improve
-                    // our text rendering/caching pipeline.
-                    screen_position: (
-                        (text.bounds.x * scale_factor).round(),
-                        (text.bounds.y * scale_factor).round(),
-                    ),
-                    // TODO: Fix precision issues with some scale factors.
-                    //
-                    // The `ceil` here can cause some words to render on the
-                    // same line when they should not.
-                    //
-                    // Ideally, `wgpu_glyph` should be able to compute layout
-                    // using logical positions, and then apply the proper
-                    // scaling when rendering. This would ensure that both
-                    // measuring and rendering follow the same layout rules.
-                    bounds: (
-                        (text.bounds.width * scale_factor).ceil(),
-                        (text.bounds.height * scale_factor).ceil(),
-                    ),
-                    text: vec![glow_glyph::Text {
-                        text: text.content,
-                        scale: glow_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: glow_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
-                    layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                glow_glyph::HorizontalAlign::Right
-                            }
-                        })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
-                };
-
-                self.text_pipeline.queue(text);
+            // Rotated text needs its own transformation, so it is drawn after
+            // the rest
+            let (rotated, text): (Vec<_>, Vec<_>) =
+                layer.text.iter().partition(|text| text.rotation != 0.0);
+
+            for text in text {
+                let section = self.text_section(text, scale_factor);
+
+                self.text_pipeline.queue(section);
             }
 
             self.text_pipeline.draw_queued(
@@ -213,6 +236,91 @@ impl Backend {
                     height: bounds.height,
                 },
             );
+
+            for text in rotated {
+                let section = self.text_section(text, scale_factor);
+                let (x, y) = section.screen_position;
+
+                self.text_pipeline.queue(section);
+
+                self.text_pipeline.draw_queued(
+                    gl,
+                    transformation
+                        * Transformation::translate(x, y)
+                        * Transformation::rotate(text.rotation)
+                        * Transformation::translate(-x, -y),
+                    glow_glyph::Region {
+                        x: bounds.x,
+                        y: target_height - (bounds.y + bounds.height),
+                        width: bounds.width,
+                        height: bounds.height,
+                    },
+                );
+            }
+        }
+    }
+
+    fn text_section<'a>(
+        &self,
+        text: &Text<'a>,
+        scale_factor: f32,
+    ) -> glow_glyph::Section<'a> {
+        // Target physical coordinates directly to avoid blurry text
+        glow_glyph::Section {
+            // TODO: We `round` here to avoid rerasterizing text when
+            // its position changes slightly. This can make text feel a
+            // bit "jumpy". We may be able to do better once we improve
+            // our text rendering/caching pipeline.
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            // TODO: Fix precision issues with some scale factors.
+            //
+            // The `ceil` here can cause some words to render on the
+            // same line when they should not.
+            //
+            // Ideally, `wgpu_glyph` should be able to compute layout
+            // using logical positions, and then apply the proper
+            // scaling when rendering. This would ensure that both
+            // measuring and rendering follow the same layout rules.
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![glow_glyph::Text {
+                text: text.content,
+                scale: glow_glyph::ab_glyph::PxScale {
+                    x: text.size * scale_factor,
+                    y: text.size * scale_factor,
+                },
+                font_id: self.text_pipeline.find_font(text.font),
+                extra: glow_glyph::Extra {
+                    color: text.color,
+                    z: 0.0,
+                },
+            }],
+            layout: glow_glyph::Layout::default()
+                .h_align(match text.horizontal_alignment {
+                    alignment::Horizontal::Left => {
+                        glow_glyph::HorizontalAlign::Left
+                    }
+                    alignment::Horizontal::Center => {
+                        glow_glyph::HorizontalAlign::Center
+                    }
+                    alignment::Horizontal::Right => {
+                        glow_glyph::HorizontalAlign::Right
+                    }
+                })
+                .v_align(match text.vertical_alignment {
+                    alignment::Vertical::Top => glow_glyph::VerticalAlign::Top,
+                    alignment::Vertical::Center => {
+                        glow_glyph::VerticalAlign::Center
+                    }
+                    alignment::Vertical::Bottom => {
+                        glow_glyph::VerticalAlign::Bottom
+                    }
+                }),
         }
     }
 }
@@ -242,6 +350,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
@@ -267,6 +379,25 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<iced_native::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
+    }
+
+    fn preload(&mut self, handle: &iced_native::image::Handle) {
+        self.image_pipeline.preload(handle);
+    }
+
+    fn evict(&mut self, handle: &iced_native::image::Handle) {
+        self.image_pipeline.evict(handle);
+    }
+
+    fn memory_usage(&self) -> iced_native::image::Usage {
+        self.image_pipeline.memory_usage()
+    }
 }
 
 #[cfg(feature = "svg")]
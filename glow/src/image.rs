@@ -16,6 +16,7 @@ use iced_graphics::image::vector;
 use iced_graphics::layer;
 use iced_graphics::Rectangle;
 use iced_graphics::Size;
+use iced_native::image;
 
 use glow::HasContext;
 
@@ -30,17 +31,30 @@ pub(crate) struct Pipeline {
     vertex_array: <glow::Context as HasContext>::VertexArray,
     vertex_buffer: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
     storage: Storage,
     #[cfg(feature = "image")]
     raster_cache: RefCell<raster::Cache<Storage>>,
     #[cfg(feature = "svg")]
     vector_cache: RefCell<vector::Cache<Storage>>,
+    budget: image::Budget,
+    #[cfg(feature = "image")]
+    requests: Vec<Request>,
+}
+
+/// A request to change the cache of raster images before the next frame.
+#[cfg(feature = "image")]
+#[derive(Debug)]
+enum Request {
+    Preload(image::Handle),
+    Evict(image::Handle),
 }
 
 impl Pipeline {
     pub fn new(
         gl: &glow::Context,
         shader_version: &program::Version,
+        budget: image::Budget,
     ) -> Pipeline {
         let program = unsafe {
             let vertex_shader = Shader::vertex(
@@ -65,9 +79,15 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Transform") }
                 .expect("Get transform location");
 
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
         unsafe {
             gl.use_program(Some(program));
 
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
             let transform: [f32; 16] = Transformation::identity().into();
             gl.uniform_matrix_4_f32_slice(
                 Some(&transform_location),
@@ -118,11 +138,15 @@ impl Pipeline {
             vertex_array,
             vertex_buffer,
             transform_location,
+            opacity_location,
             storage: Storage::default(),
             #[cfg(feature = "image")]
             raster_cache: RefCell::new(raster::Cache::default()),
             #[cfg(feature = "svg")]
             vector_cache: RefCell::new(vector::Cache::default()),
+            budget,
+            #[cfg(feature = "image")]
+            requests: Vec::new(),
         }
     }
 
@@ -131,6 +155,42 @@ impl Pipeline {
         self.raster_cache.borrow_mut().load(handle).dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<iced_native::time::Duration> {
+        self.raster_cache.borrow_mut().frame_delays(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn preload(&mut self, handle: &image::Handle) {
+        self.requests.push(Request::Preload(handle.clone()));
+    }
+
+    #[cfg(feature = "image")]
+    pub fn evict(&mut self, handle: &image::Handle) {
+        self.requests.push(Request::Evict(handle.clone()));
+    }
+
+    #[cfg(feature = "image")]
+    pub fn memory_usage(&self) -> image::Usage {
+        let raster = self.raster_cache.borrow().usage();
+
+        #[cfg(feature = "svg")]
+        let vector = self.vector_cache.borrow().usage();
+        #[cfg(not(feature = "svg"))]
+        let vector = image::Usage::default();
+
+        // Every image is stored in its own texture
+        image::Usage {
+            images: raster.images,
+            vectors: vector.vectors,
+            bytes: raster.bytes + vector.bytes,
+            allocated: raster.bytes + vector.bytes,
+        }
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(
         &self,
@@ -168,14 +228,35 @@ impl Pipeline {
         let mut vector_cache = self.vector_cache.borrow_mut();
 
         for image in images {
-            let (entry, bounds) = match &image {
+            let (entry, bounds, rotation, flip, opacity) = match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => (
-                    raster_cache.upload(handle, &mut gl, &mut self.storage),
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    rotation,
+                    flip,
+                    filter,
+                    opacity,
+                } => (
+                    if filter.is_none() {
+                        raster_cache.upload(handle, &mut gl, &mut self.storage)
+                    } else {
+                        raster_cache.upload_filtered(
+                            handle,
+                            *filter,
+                            &mut gl,
+                            &mut self.storage,
+                        )
+                    },
                     bounds,
+                    *rotation,
+                    *flip,
+                    *opacity,
                 ),
                 #[cfg(not(feature = "image"))]
-                layer::Image::Raster { handle: _, bounds } => (None, bounds),
+                layer::Image::Raster { bounds, .. } => {
+                    (None, bounds, 0.0, image::Flip::default(), 1.0)
+                }
 
                 #[cfg(feature = "svg")]
                 layer::Image::Vector {
@@ -194,11 +275,16 @@ impl Pipeline {
                             &mut self.storage,
                         ),
                         bounds,
+                        0.0,
+                        image::Flip::default(),
+                        1.0,
                     )
                 }
 
                 #[cfg(not(feature = "svg"))]
-                layer::Image::Vector { bounds, .. } => (None, bounds),
+                layer::Image::Vector { bounds, .. } => {
+                    (None, bounds, 0.0, image::Flip::default(), 1.0)
+                }
             };
 
             unsafe {
@@ -216,15 +302,34 @@ impl Pipeline {
                     continue;
                 }
 
-                let translate = Transformation::translate(bounds.x, bounds.y);
+                // Rotate the image around its center
+                let center = Transformation::translate(
+                    bounds.x + bounds.width / 2.0,
+                    bounds.y + bounds.height / 2.0,
+                );
+                let rotate = Transformation::rotate(rotation);
+                let mirror = Transformation::scale(
+                    if flip.horizontal { -1.0 } else { 1.0 },
+                    if flip.vertical { -1.0 } else { 1.0 },
+                );
+                let translate = Transformation::translate(
+                    -bounds.width / 2.0,
+                    -bounds.height / 2.0,
+                );
                 let scale = Transformation::scale(bounds.width, bounds.height);
-                let transformation = transformation * translate * scale;
+                let transformation = transformation
+                    * center
+                    * rotate
+                    * mirror
+                    * translate
+                    * scale;
                 let matrix: [f32; 16] = transformation.into();
                 gl.uniform_matrix_4_f32_slice(
                     Some(&self.transform_location),
                     false,
                     &matrix,
                 );
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
 
                 gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
 
@@ -241,14 +346,54 @@ impl Pipeline {
     }
 
     pub fn trim_cache(&mut self, mut gl: &glow::Context) {
+        // Both caches share the memory of the budget
+        let reserve = |bytes: u64| image::Budget {
+            memory: self
+                .budget
+                .memory
+                .map(|memory| memory.saturating_sub(bytes)),
+            ..self.budget
+        };
+
         #[cfg(feature = "image")]
-        self.raster_cache
-            .borrow_mut()
-            .trim(&mut self.storage, &mut gl);
+        {
+            #[cfg(feature = "svg")]
+            let budget = reserve(self.vector_cache.borrow().usage().bytes);
+            #[cfg(not(feature = "svg"))]
+            let budget = reserve(0);
+
+            let mut raster_cache = self.raster_cache.borrow_mut();
+
+            raster_cache.trim(&budget, &mut self.storage, &mut gl);
+
+            for request in self.requests.drain(..) {
+                match request {
+                    Request::Preload(handle) => {
+                        let _ = raster_cache.upload(
+                            &handle,
+                            &mut gl,
+                            &mut self.storage,
+                        );
+                    }
+                    Request::Evict(handle) => {
+                        raster_cache.evict(&handle, &mut self.storage, &mut gl);
+                    }
+                }
+            }
+        }
 
         #[cfg(feature = "svg")]
-        self.vector_cache
-            .borrow_mut()
-            .trim(&mut self.storage, &mut gl);
+        {
+            #[cfg(feature = "image")]
+            let budget = reserve(self.raster_cache.borrow().usage().bytes);
+            #[cfg(not(feature = "image"))]
+            let budget = reserve(0);
+
+            self.vector_cache.borrow_mut().trim(
+                &budget,
+                &mut self.storage,
+                &mut gl,
+            );
+        }
     }
 }
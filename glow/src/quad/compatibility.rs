@@ -51,6 +51,13 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "q_Pos"),
+                    (7, "i_EndColor"),
+                    (8, "i_Direction"),
+                    (9, "i_ShadowColor"),
+                    (10, "i_ShadowOffset"),
+                    (11, "i_ShadowBlurRadius"),
+                    (12, "i_ShadowSpreadRadius"),
                 ],
             )
         };
@@ -281,6 +288,66 @@ unsafe fn create_buffers(
         4 * (2 + 2 + 4 + 4 + 4 + 1),
     );
 
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2),
+    );
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4),
+    );
+
+    gl.enable_vertex_attrib_array(9);
+    gl.vertex_attrib_pointer_f32(
+        9,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4 + 2),
+    );
+
+    gl.enable_vertex_attrib_array(10);
+    gl.vertex_attrib_pointer_f32(
+        10,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4 + 2 + 4),
+    );
+
+    gl.enable_vertex_attrib_array(11);
+    gl.vertex_attrib_pointer_f32(
+        11,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4 + 2 + 4 + 2),
+    );
+
+    gl.enable_vertex_attrib_array(12);
+    gl.vertex_attrib_pointer_f32(
+        12,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4 + 2 + 4 + 2 + 1),
+    );
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
     gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None);
@@ -314,6 +381,24 @@ pub struct Vertex {
 
     /// The __quad__ position of the [`Vertex`].
     pub q_position: [f32; 2],
+
+    /// The end color of the gradient of the [`Vertex`], in __linear RGB__.
+    pub end_color: [f32; 4],
+
+    /// The direction of the gradient of the [`Vertex`].
+    pub direction: [f32; 2],
+
+    /// The color of the shadow of the [`Vertex`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The offset of the shadow of the [`Vertex`].
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the shadow of the [`Vertex`].
+    pub shadow_blur_radius: f32,
+
+    /// The spread radius of the shadow of the [`Vertex`].
+    pub shadow_spread_radius: f32,
 }
 
 impl Vertex {
@@ -328,6 +413,12 @@ impl Vertex {
             border_radius: quad.border_radius,
             border_width: quad.border_width,
             q_position: [0.0, 0.0],
+            end_color: quad.end_color,
+            direction: quad.direction,
+            shadow_color: quad.shadow_color,
+            shadow_offset: quad.shadow_offset,
+            shadow_blur_radius: quad.shadow_blur_radius,
+            shadow_spread_radius: quad.shadow_spread_radius,
         };
 
         [
@@ -46,6 +46,12 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "i_EndColor"),
+                    (7, "i_Direction"),
+                    (8, "i_ShadowColor"),
+                    (9, "i_ShadowOffset"),
+                    (10, "i_ShadowBlurRadius"),
+                    (11, "i_ShadowSpreadRadius"),
                 ],
             )
         };
@@ -237,6 +243,72 @@ unsafe fn create_instance_buffer(
     );
     gl.vertex_attrib_divisor(5, 1);
 
+    gl.enable_vertex_attrib_array(6);
+    gl.vertex_attrib_pointer_f32(
+        6,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1),
+    );
+    gl.vertex_attrib_divisor(6, 1);
+
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4),
+    );
+    gl.vertex_attrib_divisor(7, 1);
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2),
+    );
+    gl.vertex_attrib_divisor(8, 1);
+
+    gl.enable_vertex_attrib_array(9);
+    gl.vertex_attrib_pointer_f32(
+        9,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2 + 4),
+    );
+    gl.vertex_attrib_divisor(9, 1);
+
+    gl.enable_vertex_attrib_array(10);
+    gl.vertex_attrib_pointer_f32(
+        10,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2 + 4 + 2),
+    );
+    gl.vertex_attrib_divisor(10, 1);
+
+    gl.enable_vertex_attrib_array(11);
+    gl.vertex_attrib_pointer_f32(
+        11,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2 + 4 + 2 + 1),
+    );
+    gl.vertex_attrib_divisor(11, 1);
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
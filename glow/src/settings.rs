@@ -1,6 +1,8 @@
 //! Configure a renderer.
 pub use iced_graphics::Antialiasing;
 
+use iced_native::image;
+
 /// The settings of a [`Backend`].
 ///
 /// [`Backend`]: crate::Backend
@@ -26,6 +28,11 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The limit on the memory of the textures used to cache images.
+    ///
+    /// By default, images are evicted as soon as they are not drawn.
+    pub image_budget: image::Budget,
 }
 
 impl Default for Settings {
@@ -35,6 +42,7 @@ impl Default for Settings {
             default_text_size: 20.0,
             text_multithreading: false,
             antialiasing: None,
+            image_budget: image::Budget::default(),
         }
     }
 }
@@ -47,6 +55,7 @@ impl std::fmt::Debug for Settings {
             .field("default_text_size", &self.default_text_size)
             .field("text_multithreading", &self.text_multithreading)
             .field("antialiasing", &self.antialiasing)
+            .field("image_budget", &self.image_budget)
             .finish()
     }
 }
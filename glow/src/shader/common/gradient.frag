@@ -14,6 +14,8 @@ layout (location = 0) out vec4 fragColor;
 in vec2 raw_position;
 
 uniform vec4 gradient_direction;
+// 0 = linear (xy = start, zw = end), 1 = radial (xy = center, z = radius)
+uniform int gradient_kind;
 uniform int color_stops_size;
 // GLSL does not support dynamically sized arrays without SSBOs so this is capped to 16 stops
 //stored as color(vec4) -> offset(vec4) sequentially;
@@ -21,12 +23,20 @@ uniform vec4 color_stops[32];
 
 //TODO: rewrite without branching to make ALUs happy
 void main() {
-    vec2 start = gradient_direction.xy;
-    vec2 end = gradient_direction.zw;
-    vec2 gradient_vec = vec2(end - start);
-    vec2 current_vec = vec2(raw_position.xy - start);
-    vec2 unit = normalize(gradient_vec);
-    float coord_offset = dot(unit, current_vec) / length(gradient_vec);
+    float coord_offset;
+
+    if (gradient_kind == 1) {
+        vec2 center = gradient_direction.xy;
+        float radius = gradient_direction.z;
+        coord_offset = length(raw_position.xy - center) / radius;
+    } else {
+        vec2 start = gradient_direction.xy;
+        vec2 end = gradient_direction.zw;
+        vec2 gradient_vec = vec2(end - start);
+        vec2 current_vec = vec2(raw_position.xy - start);
+        vec2 unit = normalize(gradient_vec);
+        coord_offset = dot(unit, current_vec) / length(gradient_vec);
+    }
     //if a gradient has a start/end stop that is identical, the mesh will have a transparent fill
     gl_FragColor = vec4(0.0, 0.0, 0.0, 0.0);
 
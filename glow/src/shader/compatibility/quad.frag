@@ -14,6 +14,12 @@ varying vec2 v_Pos;
 varying vec2 v_Scale;
 varying vec4 v_BorderRadius;
 varying float v_BorderWidth;
+varying vec4 v_EndColor;
+varying vec2 v_Direction;
+varying vec4 v_ShadowColor;
+varying vec2 v_ShadowOffset;
+varying float v_ShadowBlurRadius;
+varying float v_ShadowSpreadRadius;
 
 float _distance(vec2 frag_coord, vec2 position, vec2 size, float radius)
 {
@@ -43,6 +49,36 @@ float selectBorderRadius(vec4 radi, vec2 position, vec2 center)
     return rx;
 }
 
+float gradientMix(vec2 frag_coord, vec2 position, vec2 size, vec2 direction)
+{
+    float extent = max(abs(direction.x) * size.x + abs(direction.y) * size.y, 0.0001);
+    float offset = dot(frag_coord - (position + size * 0.5), direction) / extent + 0.5;
+
+    return clamp(offset, 0.0, 1.0);
+}
+
+float roundedBoxSdf(vec2 to_center, vec2 half_size, float radius)
+{
+    vec2 q = abs(to_center) - half_size + vec2(radius);
+
+    return length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+float shadowAlpha(vec2 frag_coord, vec2 position, vec2 size, float border_radius)
+{
+    vec2 half_size = max(size * 0.5 + vec2(v_ShadowSpreadRadius), vec2(0.0));
+    float radius = clamp(
+        border_radius + v_ShadowSpreadRadius,
+        0.0,
+        min(half_size.x, half_size.y)
+    );
+
+    float d = roundedBoxSdf(frag_coord - (position + size * 0.5), half_size, radius);
+    float blur = max(v_ShadowBlurRadius, 0.5);
+
+    return 1.0 - smoothstep(-blur, blur, d);
+}
+
 void main() {
     vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
 
@@ -52,6 +88,12 @@ void main() {
         (v_Pos + v_Scale * 0.5).xy
     );
 
+    vec4 fill_color = mix(
+        v_Color,
+        v_EndColor,
+        gradientMix(fragCoord, v_Pos, v_Scale, v_Direction)
+    );
+
     float internal_border = max(border_radius - v_BorderWidth, 0.0);
 
     float internal_distance = _distance(
@@ -67,7 +109,7 @@ void main() {
         internal_distance
     );
 
-    vec4 mixed_color = mix(v_Color, v_BorderColor, border_mix);
+    vec4 mixed_color = mix(fill_color, v_BorderColor, border_mix);
 
     float d = _distance(
         fragCoord,
@@ -79,5 +121,41 @@ void main() {
     float radius_alpha =
         1.0 - smoothstep(max(border_radius - 0.5, 0.0), border_radius + 0.5, d);
 
-    gl_FragColor = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+    vec4 quad_color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+
+    if (v_ShadowColor.w <= 0.0) {
+        gl_FragColor = quad_color;
+        return;
+    }
+
+    vec2 shadow_position = v_Pos + v_ShadowOffset;
+
+    float shadow_border_radius = selectBorderRadius(
+        v_BorderRadius,
+        fragCoord,
+        shadow_position + v_Scale * 0.5
+    );
+
+    // The shadow is only visible outside of the quad
+    float shadow = shadowAlpha(
+        fragCoord,
+        shadow_position,
+        v_Scale,
+        shadow_border_radius
+    ) * v_ShadowColor.w * (1.0 - radius_alpha);
+
+    // Draw the quad over its shadow
+    float alpha = quad_color.w + shadow * (1.0 - quad_color.w);
+
+    if (alpha <= 0.0) {
+        gl_FragColor = vec4(0.0);
+        return;
+    }
+
+    vec3 color = (
+        quad_color.xyz * quad_color.w
+        + v_ShadowColor.xyz * shadow * (1.0 - quad_color.w)
+    ) / alpha;
+
+    gl_FragColor = vec4(color, alpha);
 }
@@ -8,6 +8,12 @@ attribute vec4 i_BorderColor;
 attribute vec4 i_BorderRadius;
 attribute float i_BorderWidth;
 attribute vec2 q_Pos;
+attribute vec4 i_EndColor;
+attribute vec2 i_Direction;
+attribute vec4 i_ShadowColor;
+attribute vec2 i_ShadowOffset;
+attribute float i_ShadowBlurRadius;
+attribute float i_ShadowSpreadRadius;
 
 varying vec4 v_Color;
 varying vec4 v_BorderColor;
@@ -15,6 +21,12 @@ varying vec2 v_Pos;
 varying vec2 v_Scale;
 varying vec4 v_BorderRadius;
 varying float v_BorderWidth;
+varying vec4 v_EndColor;
+varying vec2 v_Direction;
+varying vec4 v_ShadowColor;
+varying vec2 v_ShadowOffset;
+varying float v_ShadowBlurRadius;
+varying float v_ShadowSpreadRadius;
 
 
 void main() {
@@ -28,11 +40,26 @@ void main() {
         min(i_BorderRadius.w, min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    // Grow the quad to cover its shadow, if any
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowExtent =
+        max(i_ShadowSpreadRadius + i_ShadowBlurRadius, 0.0) * u_Scale;
+
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if (i_ShadowColor.w > 0.0) {
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - vec2(p_ShadowExtent));
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + vec2(p_ShadowExtent));
+    }
+
+    vec2 p_Size = p_Max - p_Min;
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Size.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -41,6 +68,12 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_EndColor = i_EndColor;
+    v_Direction = i_Direction;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlurRadius = i_ShadowBlurRadius * u_Scale;
+    v_ShadowSpreadRadius = i_ShadowSpreadRadius * u_Scale;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
@@ -117,6 +117,17 @@ impl Pipeline {
         }
     }
 
+    pub fn ascent(&self, size: f32, font: iced_native::Font) -> f32 {
+        use ab_glyph::{Font as _, ScaleFont as _};
+        use glow_glyph::GlyphCruncher;
+
+        let glow_glyph::FontId(font_id) = self.find_font(font);
+
+        self.measure_brush.borrow().fonts()[font_id]
+            .as_scaled(size)
+            .ascent()
+    }
+
     pub fn hit_test(
         &self,
         content: &str,
@@ -5,6 +5,7 @@ use crate::Transformation;
 use iced_graphics::gradient::Gradient;
 use iced_graphics::layer::mesh::{self, Mesh};
 use iced_graphics::triangle::{ColoredVertex2D, Vertex2D};
+use iced_graphics::BlendMode;
 
 use glow::HasContext;
 use std::marker::PhantomData;
@@ -20,6 +21,7 @@ pub(crate) struct Pipeline {
     indices: Buffer<u32>,
     solid: solid::Program,
     gradient: gradient::Program,
+    multisampling: bool,
 }
 
 impl Pipeline {
@@ -50,9 +52,14 @@ impl Pipeline {
             indices,
             solid,
             gradient,
+            multisampling: true,
         }
     }
 
+    pub fn set_multisampling(&mut self, multisampling: bool) {
+        self.multisampling = multisampling;
+    }
+
     pub fn draw(
         &mut self,
         meshes: &[Mesh<'_>],
@@ -60,13 +67,19 @@ impl Pipeline {
         target_height: u32,
         transformation: Transformation,
         scale_factor: f32,
+        blend_mode: BlendMode,
     ) {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Glow::Triangle", "DRAW").enter();
 
         unsafe {
-            gl.enable(glow::MULTISAMPLE);
+            if self.multisampling {
+                gl.enable(glow::MULTISAMPLE);
+            }
+
             gl.enable(glow::SCISSOR_TEST);
+
+            blend_func(gl, blend_mode);
         }
 
         // Count the total amount of vertices & indices we need to handle
@@ -197,66 +210,68 @@ impl Pipeline {
                     }
 
                     if &self.gradient.uniforms.gradient != *gradient {
-                        match gradient {
-                            Gradient::Linear(linear) => {
-                                gl.uniform_4_f32(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .gradient_direction,
-                                    ),
+                        let locations = &self.gradient.uniforms.locations;
+
+                        let (direction, kind) = match gradient {
+                            Gradient::Linear(linear) => (
+                                [
                                     linear.start.x,
                                     linear.start.y,
                                     linear.end.x,
                                     linear.end.y,
-                                );
-
-                                gl.uniform_1_i32(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .color_stops_size,
-                                    ),
-                                    (linear.color_stops.len() * 2) as i32,
-                                );
-
-                                let mut stops = [0.0; 128];
-
-                                for (index, stop) in linear
-                                    .color_stops
-                                    .iter()
-                                    .enumerate()
-                                    .take(16)
-                                {
-                                    let [r, g, b, a] = stop.color.into_linear();
-
-                                    stops[index * 8] = r;
-                                    stops[(index * 8) + 1] = g;
-                                    stops[(index * 8) + 2] = b;
-                                    stops[(index * 8) + 3] = a;
-                                    stops[(index * 8) + 4] = stop.offset;
-                                    stops[(index * 8) + 5] = 0.;
-                                    stops[(index * 8) + 6] = 0.;
-                                    stops[(index * 8) + 7] = 0.;
-                                }
-
-                                gl.uniform_4_f32_slice(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .color_stops,
-                                    ),
-                                    &stops,
-                                );
-                            }
+                                ],
+                                0,
+                            ),
+                            Gradient::Radial(radial) => (
+                                [
+                                    radial.center.x,
+                                    radial.center.y,
+                                    radial.radius,
+                                    0.0,
+                                ],
+                                1,
+                            ),
+                        };
+
+                        gl.uniform_4_f32(
+                            Some(&locations.gradient_direction),
+                            direction[0],
+                            direction[1],
+                            direction[2],
+                            direction[3],
+                        );
+
+                        gl.uniform_1_i32(Some(&locations.gradient_kind), kind);
+
+                        let color_stops = gradient.color_stops();
+
+                        gl.uniform_1_i32(
+                            Some(&locations.color_stops_size),
+                            (color_stops.len() * 2) as i32,
+                        );
+
+                        let mut stops = [0.0; 128];
+
+                        for (index, stop) in
+                            color_stops.iter().enumerate().take(16)
+                        {
+                            let [r, g, b, a] = stop.color.into_linear();
+
+                            stops[index * 8] = r;
+                            stops[(index * 8) + 1] = g;
+                            stops[(index * 8) + 2] = b;
+                            stops[(index * 8) + 3] = a;
+                            stops[(index * 8) + 4] = stop.offset;
+                            stops[(index * 8) + 5] = 0.;
+                            stops[(index * 8) + 6] = 0.;
+                            stops[(index * 8) + 7] = 0.;
                         }
 
+                        gl.uniform_4_f32_slice(
+                            Some(&locations.color_stops),
+                            &stops,
+                        );
+
                         self.gradient.uniforms.gradient = (*gradient).clone();
                     }
 
@@ -279,10 +294,33 @@ impl Pipeline {
             gl.bind_vertex_array(None);
             gl.disable(glow::SCISSOR_TEST);
             gl.disable(glow::MULTISAMPLE);
+
+            blend_func(gl, BlendMode::Normal);
         }
     }
 }
 
+/// Sets the blend function of a [`BlendMode`], for fragments with straight
+/// alpha.
+///
+/// The translucent parts of multiplied and screened geometry are blended as
+/// if they were opaque.
+unsafe fn blend_func(gl: &glow::Context, blend_mode: BlendMode) {
+    let (source, destination) = match blend_mode {
+        BlendMode::Normal => (glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA),
+        BlendMode::Multiply => (glow::DST_COLOR, glow::ONE_MINUS_SRC_ALPHA),
+        BlendMode::Screen => (glow::ONE_MINUS_DST_COLOR, glow::ONE),
+        BlendMode::Additive => (glow::SRC_ALPHA, glow::ONE),
+    };
+
+    gl.blend_func_separate(
+        source,
+        destination,
+        glow::ONE,
+        glow::ONE_MINUS_SRC_ALPHA,
+    );
+}
+
 #[derive(Debug)]
 pub struct Buffer<T> {
     raw: <glow::Context as HasContext>::Buffer,
@@ -537,6 +575,7 @@ mod gradient {
     #[derive(Debug)]
     pub struct Locations {
         pub gradient_direction: <Context as HasContext>::UniformLocation,
+        pub gradient_kind: <Context as HasContext>::UniformLocation,
         pub color_stops_size: <Context as HasContext>::UniformLocation,
         //currently the maximum number of stops is 16 due to lack of SSBO in GL2.1
         pub color_stops: <Context as HasContext>::UniformLocation,
@@ -550,6 +589,10 @@ mod gradient {
             }
             .expect("Gradient - Get gradient_direction.");
 
+            let gradient_kind =
+                unsafe { gl.get_uniform_location(program, "gradient_kind") }
+                    .expect("Gradient - Get gradient_kind.");
+
             let color_stops_size =
                 unsafe { gl.get_uniform_location(program, "color_stops_size") }
                     .expect("Gradient - Get color_stops_size.");
@@ -585,6 +628,7 @@ mod gradient {
                 transform: Transformation::identity(),
                 locations: Locations {
                     gradient_direction,
+                    gradient_kind,
                     color_stops_size,
                     color_stops,
                     transform: transform_location,
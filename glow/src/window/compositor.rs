@@ -1,7 +1,8 @@
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use glow::HasContext;
-use iced_graphics::{compositor, Antialiasing, Size};
+use iced_graphics::damage;
+use iced_graphics::{compositor, Antialiasing, Rectangle, Size};
 
 use core::ffi::c_void;
 use std::marker::PhantomData;
@@ -10,6 +11,8 @@ use std::marker::PhantomData;
 #[allow(missing_debug_implementations)]
 pub struct Compositor<Theme> {
     gl: glow::Context,
+    antialiasing: Option<Antialiasing>,
+    damage: damage::Tracker,
     theme: PhantomData<Theme>,
 }
 
@@ -50,11 +53,14 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
         // Disable multisampling by default
         gl.disable(glow::MULTISAMPLE);
 
+        let antialiasing = settings.antialiasing;
         let renderer = Renderer::new(Backend::new(&gl, settings));
 
         Ok((
             Self {
                 gl,
+                antialiasing,
+                damage: damage::Tracker::default(),
                 theme: PhantomData,
             },
             renderer,
@@ -85,6 +91,9 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
         compositor::Information {
             backend: format!("{:?}", self.gl.version()),
             adapter,
+            // The samples of the default framebuffer are fixed when the
+            // context is created
+            antialiasing: self.antialiasing.into_iter().collect(),
         }
     }
 
@@ -94,18 +103,104 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
         viewport: &Viewport,
         color: Color,
         overlay: &[T],
-    ) {
+        buffer_age: u32,
+    ) -> Vec<Rectangle<u32>> {
         let gl = &self.gl;
+        let tracker = &mut self.damage;
+
+        renderer.with_primitives(|backend, primitives| {
+            match tracker.damage_since(
+                primitives,
+                viewport,
+                color,
+                overlay,
+                buffer_age as usize,
+            ) {
+                Some(regions) => {
+                    if !regions.is_empty() {
+                        backend.present_regions(
+                            gl, primitives, viewport, &regions, color, overlay,
+                        );
+                    }
+
+                    regions
+                }
+                None => {
+                    let [r, g, b, a] = color.into_linear();
+
+                    unsafe {
+                        gl.clear_color(r, g, b, a);
+                        gl.clear(glow::COLOR_BUFFER_BIT);
+                    }
+
+                    backend.present(gl, primitives, viewport, overlay);
+
+                    let size = viewport.physical_size();
+
+                    vec![Rectangle {
+                        x: 0,
+                        y: 0,
+                        width: size.width,
+                        height: size.height,
+                    }]
+                }
+            }
+        })
+    }
 
-        let [r, g, b, a] = color.into_linear();
+    fn invalidate(&mut self) {
+        self.damage.invalidate();
+    }
 
-        unsafe {
-            gl.clear_color(r, g, b, a);
-            gl.clear(glow::COLOR_BUFFER_BIT);
+    fn change_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<Antialiasing>,
+    ) -> Result<(), Error> {
+        if antialiasing.is_some() && antialiasing != self.antialiasing {
+            return Err(Error::AntialiasingNotSupported);
         }
 
-        renderer.with_primitives(|backend, primitive| {
-            backend.present(gl, primitive, viewport, overlay);
+        renderer.with_primitives(|backend, _| {
+            backend.set_multisampling(antialiasing.is_some());
         });
+
+        self.invalidate();
+
+        Ok(())
+    }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, Error> {
+        let size = viewport.physical_size();
+
+        self.invalidate();
+
+        let _ = self.present(renderer, viewport, background_color, overlay, 0);
+
+        let mut pixels =
+            vec![0; size.width as usize * size.height as usize * 4];
+
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                size.width as i32,
+                size.height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // OpenGL stores the rows of the frame from the bottom
+        let row = (size.width as usize * 4).max(1);
+
+        Ok(pixels.chunks(row).rev().flatten().copied().collect())
     }
 }
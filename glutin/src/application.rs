@@ -10,6 +10,7 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
+use iced_winit::input_method::InputMethod;
 use iced_winit::renderer;
 use iced_winit::time::Instant;
 use iced_winit::user_interface;
@@ -231,6 +232,8 @@ async fn run_instance<A, E, C>(
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
+    let mut antialiasing = None;
 
     application::run_command(
         &application,
@@ -243,6 +246,8 @@ async fn run_instance<A, E, C>(
         &mut should_exit,
         &mut proxy,
         &mut debug,
+        &mut screenshots,
+        &mut antialiasing,
         context.window(),
         || compositor.fetch_information(),
     );
@@ -258,9 +263,11 @@ async fn run_instance<A, E, C>(
         ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut redraw_requested = false;
 
     debug.startup_finished();
 
@@ -316,6 +323,8 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
+                        &mut antialiasing,
                         context.window(),
                         || compositor.fetch_information(),
                     );
@@ -373,13 +382,28 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    application::update_input_method(
+                        context.window(),
+                        state.scale_factor(),
+                        &mut input_method,
+                        new_input_method,
+                    );
+                }
+
                 context.window().request_redraw();
+                redraw_requested = true;
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
 
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         crate::window::RedrawRequest::NextFrame => {
                             ControlFlow::Poll
@@ -410,6 +434,22 @@ async fn run_instance<A, E, C>(
                 #[cfg(feature = "tracing")]
                 let _ = info_span!("Application", "FRAME").entered();
 
+                // Redraws requested by the platform may need the whole
+                // frame, since the contents of the window could be lost
+                if !redraw_requested {
+                    compositor.invalidate();
+                }
+
+                redraw_requested = false;
+
+                if let Some(antialiasing) = antialiasing.take() {
+                    if let Err(error) = compositor
+                        .change_antialiasing(&mut renderer, antialiasing)
+                    {
+                        log::warn!("Failed to change antialiasing: {error}");
+                    }
+                }
+
                 debug.render_started();
 
                 #[allow(unsafe_code)]
@@ -465,14 +505,54 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                compositor.present(
+                let damage = compositor.present(
                     &mut renderer,
                     state.viewport(),
                     state.background_color(),
                     &debug.overlay(),
+                    context.buffer_age(),
                 );
 
-                context.swap_buffers().expect("Swap buffers");
+                if !screenshots.is_empty() {
+                    let result = compositor.screenshot(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+
+                    application::send_screenshots(
+                        &mut screenshots,
+                        result,
+                        &state,
+                        &proxy,
+                    );
+                }
+
+                if !damage.is_empty() {
+                    if context.swap_buffers_with_damage_supported() {
+                        let height = state.physical_size().height;
+
+                        // Damage rectangles have their origin at the
+                        // bottom-left corner of the surface
+                        let rects: Vec<_> = damage
+                            .iter()
+                            .map(|region| glutin::Rect {
+                                x: region.x,
+                                y: height
+                                    .saturating_sub(region.y + region.height),
+                                width: region.width,
+                                height: region.height,
+                            })
+                            .collect();
+
+                        context
+                            .swap_buffers_with_damage(&rects)
+                            .expect("Swap buffers");
+                    } else {
+                        context.swap_buffers().expect("Swap buffers");
+                    }
+                }
 
                 debug.render_finished();
 
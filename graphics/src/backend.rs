@@ -2,6 +2,7 @@
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
+use iced_native::time::Duration;
 use iced_native::{Font, Point, Size};
 
 /// The graphics backend of a [`Renderer`].
@@ -45,6 +46,16 @@ pub trait Text {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of text with the given
+    /// size and font to its baseline.
+    ///
+    /// By default, it is approximated as 80% of the size of the text.
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        let _ = font;
+
+        size * 0.8
+    }
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -67,6 +78,20 @@ pub trait Text {
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> Size<u32>;
+
+    /// Returns the delay of each frame of the provided animated image.
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration>;
+
+    /// Uploads the provided image before the next frame is drawn.
+    fn preload(&mut self, _handle: &image::Handle) {}
+
+    /// Evicts the provided image from the cache of the backend.
+    fn evict(&mut self, _handle: &image::Handle) {}
+
+    /// Returns the memory used by the cached images.
+    fn memory_usage(&self) -> image::Usage {
+        image::Usage::default()
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
@@ -74,3 +99,6 @@ pub trait Svg {
     /// Returns the viewport dimensions of the provided SVG.
     fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32>;
 }
+
+/// A graphics backend that supports custom shaders.
+pub trait Shader {}
@@ -0,0 +1,24 @@
+/// The way the colors of some geometry are combined with the colors that
+/// are already drawn behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// The geometry is drawn over its background, using its alpha.
+    #[default]
+    Normal,
+    /// The colors are multiplied, darkening the background.
+    Multiply,
+    /// The inverse of the colors are multiplied, lightening the background.
+    Screen,
+    /// The colors are added together, which is useful for glow effects.
+    Additive,
+}
+
+impl BlendMode {
+    /// All of the available blend modes.
+    pub const ALL: [BlendMode; 4] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Additive,
+    ];
+}
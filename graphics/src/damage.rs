@@ -0,0 +1,558 @@
+//! Track the regions of a frame that change between presentations.
+use crate::blend_mode::BlendMode;
+use crate::gradient::{self, Gradient};
+use crate::{Background, Color, Font, Primitive, Rectangle, Size, Viewport};
+
+use iced_native::{Hasher, Vector};
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher as _};
+use std::sync::Arc;
+
+/// The amount of frames whose damage is remembered by a [`Tracker`].
+const HISTORY: usize = 4;
+
+/// The area that can be wasted when merging two damaged regions, in
+/// physical pixels.
+const AREA_THRESHOLD: f32 = 20_000.0;
+
+/// Computes the regions that differ between two primitives.
+pub fn regions(a: &Primitive, b: &Primitive) -> Vec<Rectangle> {
+    Fingerprint::new(a).regions(&Fingerprint::new(b))
+}
+
+/// Computes the regions that differ between two lists of primitives.
+pub fn list(previous: &[Primitive], current: &[Primitive]) -> Vec<Rectangle> {
+    Fingerprint::list(
+        &previous.iter().map(Fingerprint::new).collect::<Vec<_>>(),
+        &current.iter().map(Fingerprint::new).collect::<Vec<_>>(),
+    )
+}
+
+/// Scales the damaged regions to physical pixels and merges the ones that
+/// are close to each other, discarding anything outside of the given bounds.
+pub fn group(
+    mut damage: Vec<Rectangle>,
+    scale_factor: f32,
+    bounds: Size<u32>,
+) -> Vec<Rectangle<u32>> {
+    let bounds = Rectangle::with_size(Size::new(
+        bounds.width as f32,
+        bounds.height as f32,
+    ));
+
+    damage.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+
+    let mut scaled = damage
+        .into_iter()
+        .filter_map(|region| (region * scale_factor).intersection(&bounds))
+        .filter(|region| region.width >= 1.0 && region.height >= 1.0);
+
+    let mut output = Vec::new();
+
+    if let Some(mut current) = scaled.next() {
+        for region in scaled {
+            let union = current.union(&region);
+
+            if union.area() - current.area() - region.area() <= AREA_THRESHOLD {
+                current = union;
+            } else {
+                output.push(current);
+                current = region;
+            }
+        }
+
+        output.push(current);
+    }
+
+    output
+        .into_iter()
+        .filter_map(|region| snap(region.expand(1.0), bounds))
+        .collect()
+}
+
+/// Remembers the frames presented to a surface to compute the damage of the
+/// next ones.
+#[derive(Debug, Default)]
+pub struct Tracker {
+    last: Option<Frame>,
+    history: VecDeque<Vec<Rectangle>>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    primitives: Vec<Fingerprint>,
+    size: Size<u32>,
+    scale_factor: f64,
+    background_color: Color,
+    overlay: Vec<String>,
+}
+
+impl Tracker {
+    /// Compares the given frame with the last one and returns the regions
+    /// that changed, in physical pixels.
+    ///
+    /// Returns `None` if the whole frame needs to be redrawn, and an empty
+    /// list if nothing changed since the last frame.
+    pub fn damage<T: AsRef<str>>(
+        &mut self,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Option<Vec<Rectangle<u32>>> {
+        self.damage_since(primitives, viewport, background_color, overlay, 1)
+    }
+
+    /// Like [`Tracker::damage`], but returns the regions to redraw on a
+    /// buffer that was presented `age` frames ago.
+    ///
+    /// An `age` of `0` means the contents of the buffer are unknown.
+    pub fn damage_since<T: AsRef<str>>(
+        &mut self,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+        age: usize,
+    ) -> Option<Vec<Rectangle<u32>>> {
+        let size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor();
+
+        let frame = Frame {
+            primitives: primitives.iter().map(Fingerprint::new).collect(),
+            size,
+            scale_factor,
+            background_color,
+            overlay: overlay
+                .iter()
+                .map(|line| line.as_ref().to_owned())
+                .collect(),
+        };
+
+        let damage = match self.last.take() {
+            Some(last)
+                if last.size == frame.size
+                    && last.scale_factor == frame.scale_factor
+                    && last.background_color == frame.background_color
+                    && last.overlay == frame.overlay =>
+            {
+                Some(Fingerprint::list(&last.primitives, &frame.primitives))
+            }
+            _ => None,
+        };
+
+        self.last = Some(frame);
+
+        let damage = match damage {
+            Some(damage) => {
+                // Frames without visible changes are not presented
+                if group(damage.clone(), scale_factor as f32, size).is_empty() {
+                    return Some(Vec::new());
+                }
+
+                damage
+            }
+            None => {
+                self.history.clear();
+
+                vec![Rectangle::with_size(viewport.logical_size())]
+            }
+        };
+
+        self.history.push_front(damage);
+        self.history.truncate(HISTORY);
+
+        if age == 0 || age > self.history.len() {
+            return None;
+        }
+
+        let damage = self.history.iter().take(age).flatten().copied().collect();
+
+        let regions = group(damage, scale_factor as f32, size);
+
+        // A region covering the whole frame is a full redraw
+        let frame = Rectangle {
+            x: 0,
+            y: 0,
+            width: size.width,
+            height: size.height,
+        };
+
+        match regions.as_slice() {
+            [region] if *region == frame => None,
+            _ => Some(regions),
+        }
+    }
+
+    /// Forgets the last frame, forcing the next one to be redrawn entirely.
+    pub fn invalidate(&mut self) {
+        self.last = None;
+        self.history.clear();
+    }
+}
+
+/// Snaps a physical region outwards, keeping it inside the given bounds.
+fn snap(region: Rectangle, bounds: Rectangle) -> Option<Rectangle<u32>> {
+    let region = region.intersection(&bounds)?;
+
+    let x = region.x.floor();
+    let y = region.y.floor();
+
+    Some(Rectangle {
+        x: x as u32,
+        y: y as u32,
+        width: ((region.x + region.width).ceil() - x) as u32,
+        height: ((region.y + region.height).ceil() - y) as u32,
+    })
+}
+
+/// A summary of a [`Primitive`] that is cheap to keep around.
+///
+/// The structure of the [`Primitive`] is kept to locate its changes, while
+/// its leaves are reduced to their bounds and a hash of their contents.
+/// Cached primitives are shared instead of summarized.
+#[derive(Debug)]
+enum Fingerprint {
+    Group(Vec<Fingerprint>),
+    Clip {
+        bounds: Rectangle,
+        content: Box<Fingerprint>,
+    },
+    Blend {
+        mode: BlendMode,
+        content: Box<Fingerprint>,
+    },
+    Translate {
+        translation: Vector,
+        content: Box<Fingerprint>,
+    },
+    Cached(Arc<Primitive>),
+    Leaf {
+        bounds: Rectangle,
+        hash: u64,
+    },
+}
+
+impl Fingerprint {
+    fn new(primitive: &Primitive) -> Self {
+        match primitive {
+            Primitive::Group { primitives } => {
+                Fingerprint::Group(primitives.iter().map(Self::new).collect())
+            }
+            Primitive::Clip { bounds, content } => Fingerprint::Clip {
+                bounds: *bounds,
+                content: Box::new(Self::new(content)),
+            },
+            Primitive::Blend { mode, content } => Fingerprint::Blend {
+                mode: *mode,
+                content: Box::new(Self::new(content)),
+            },
+            Primitive::Translate {
+                translation,
+                content,
+            } => Fingerprint::Translate {
+                translation: *translation,
+                content: Box::new(Self::new(content)),
+            },
+            Primitive::Cached { cache } => Fingerprint::Cached(cache.clone()),
+            _ => {
+                let mut hasher = Hasher::default();
+                hash_leaf(primitive, &mut hasher);
+
+                Fingerprint::Leaf {
+                    bounds: primitive.bounds(),
+                    hash: hasher.finish(),
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Rectangle {
+        match self {
+            Fingerprint::Group(fingerprints) => fingerprints
+                .iter()
+                .map(Self::bounds)
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_else(|| Rectangle::with_size(Size::ZERO)),
+            Fingerprint::Clip { bounds, .. } => bounds.expand(1.0),
+            Fingerprint::Blend { content, .. } => content.bounds(),
+            Fingerprint::Translate {
+                translation,
+                content,
+            } => content.bounds() + *translation,
+            Fingerprint::Cached(cache) => cache.bounds(),
+            Fingerprint::Leaf { bounds, .. } => *bounds,
+        }
+    }
+
+    fn regions(&self, other: &Self) -> Vec<Rectangle> {
+        match (self, other) {
+            (Fingerprint::Group(a), Fingerprint::Group(b)) => {
+                return Self::list(a, b)
+            }
+            (
+                Fingerprint::Clip {
+                    bounds: bounds_a,
+                    content: content_a,
+                },
+                Fingerprint::Clip {
+                    bounds: bounds_b,
+                    content: content_b,
+                },
+            ) if bounds_a == bounds_b => {
+                let clip = bounds_a.expand(1.0);
+
+                return content_a
+                    .regions(content_b)
+                    .into_iter()
+                    .filter_map(|region| region.intersection(&clip))
+                    .collect();
+            }
+            (
+                Fingerprint::Blend {
+                    mode: mode_a,
+                    content: content_a,
+                },
+                Fingerprint::Blend {
+                    mode: mode_b,
+                    content: content_b,
+                },
+            ) if mode_a == mode_b => return content_a.regions(content_b),
+            (
+                Fingerprint::Translate {
+                    translation: translation_a,
+                    content: content_a,
+                },
+                Fingerprint::Translate {
+                    translation: translation_b,
+                    content: content_b,
+                },
+            ) if translation_a == translation_b => {
+                return content_a
+                    .regions(content_b)
+                    .into_iter()
+                    .map(|region| region + *translation_a)
+                    .collect();
+            }
+            (Fingerprint::Cached(a), Fingerprint::Cached(b)) => {
+                if Arc::ptr_eq(a, b) {
+                    return Vec::new();
+                }
+
+                return regions(a, b);
+            }
+            (
+                Fingerprint::Leaf {
+                    bounds: bounds_a,
+                    hash: hash_a,
+                },
+                Fingerprint::Leaf {
+                    bounds: bounds_b,
+                    hash: hash_b,
+                },
+            ) if bounds_a == bounds_b && hash_a == hash_b => return Vec::new(),
+            _ => {}
+        }
+
+        let bounds_a = self.bounds();
+        let bounds_b = other.bounds();
+
+        if bounds_a == bounds_b {
+            vec![bounds_a]
+        } else {
+            vec![bounds_a, bounds_b]
+        }
+    }
+
+    fn list(previous: &[Self], current: &[Self]) -> Vec<Rectangle> {
+        let damage =
+            previous.iter().zip(current).flat_map(|(a, b)| a.regions(b));
+
+        let (smaller, bigger) = if previous.len() < current.len() {
+            (previous, current)
+        } else {
+            (current, previous)
+        };
+
+        // Any primitive added or removed is damaged as a whole
+        damage
+            .chain(bigger[smaller.len()..].iter().map(Self::bounds))
+            .collect()
+    }
+}
+
+/// Hashes everything that affects the pixels drawn by a [`Primitive`]
+/// without children.
+fn hash_leaf(primitive: &Primitive, state: &mut Hasher) {
+    std::mem::discriminant(primitive).hash(state);
+
+    match primitive {
+        Primitive::Text {
+            content,
+            bounds,
+            color,
+            size,
+            font,
+            horizontal_alignment,
+            vertical_alignment,
+            rotation,
+        } => {
+            content.hash(state);
+            hash_rectangle(bounds, state);
+            hash_color(color, state);
+            size.to_bits().hash(state);
+            hash_font(font, state);
+            horizontal_alignment.hash(state);
+            vertical_alignment.hash(state);
+            rotation.to_bits().hash(state);
+        }
+        Primitive::Quad {
+            bounds,
+            background,
+            border_radius,
+            border_width,
+            border_color,
+            shadow,
+        } => {
+            hash_rectangle(bounds, state);
+            hash_background(background, state);
+            hash_floats(border_radius, state);
+            border_width.to_bits().hash(state);
+            hash_color(border_color, state);
+            hash_color(&shadow.color, state);
+            hash_floats(&[shadow.offset.x, shadow.offset.y], state);
+            hash_floats(&[shadow.blur_radius, shadow.spread_radius], state);
+        }
+        Primitive::Image {
+            handle,
+            bounds,
+            rotation,
+            flip,
+            filter,
+            opacity,
+        } => {
+            handle.id().hash(state);
+            hash_rectangle(bounds, state);
+            rotation.to_bits().hash(state);
+            flip.hash(state);
+            hash_floats(
+                &[
+                    filter.blur,
+                    filter.grayscale,
+                    filter.brightness,
+                    filter.contrast,
+                ],
+                state,
+            );
+            filter.tint.is_some().hash(state);
+            filter.tint.iter().for_each(|tint| hash_color(tint, state));
+            opacity.to_bits().hash(state);
+        }
+        Primitive::Svg {
+            handle,
+            color,
+            bounds,
+        } => {
+            handle.id().hash(state);
+            color.is_some().hash(state);
+            color.iter().for_each(|color| hash_color(color, state));
+            hash_rectangle(bounds, state);
+        }
+        Primitive::Shader {
+            handle,
+            bounds,
+            uniforms,
+        } => {
+            handle.id().hash(state);
+            hash_rectangle(bounds, state);
+            hash_floats(uniforms, state);
+        }
+        Primitive::SolidMesh { buffers, size } => {
+            hash_floats(&[size.width, size.height], state);
+            buffers.indices.hash(state);
+            bytemuck::cast_slice::<_, u8>(&buffers.vertices).hash(state);
+        }
+        Primitive::GradientMesh {
+            buffers,
+            size,
+            gradient,
+        } => {
+            hash_floats(&[size.width, size.height], state);
+            hash_gradient(gradient, state);
+            buffers.indices.hash(state);
+            bytemuck::cast_slice::<_, u8>(&buffers.vertices).hash(state);
+        }
+        Primitive::None
+        | Primitive::Group { .. }
+        | Primitive::Clip { .. }
+        | Primitive::Blend { .. }
+        | Primitive::Translate { .. }
+        | Primitive::Cached { .. } => {}
+    }
+}
+
+fn hash_floats(floats: &[f32], state: &mut Hasher) {
+    for float in floats {
+        float.to_bits().hash(state);
+    }
+}
+
+fn hash_rectangle(rectangle: &Rectangle, state: &mut Hasher) {
+    hash_floats(
+        &[rectangle.x, rectangle.y, rectangle.width, rectangle.height],
+        state,
+    );
+}
+
+fn hash_color(color: &Color, state: &mut Hasher) {
+    hash_floats(&[color.r, color.g, color.b, color.a], state);
+}
+
+fn hash_background(background: &Background, state: &mut Hasher) {
+    std::mem::discriminant(background).hash(state);
+
+    match background {
+        Background::Color(color) => hash_color(color, state),
+        Background::Gradient(iced_native::gradient::Gradient::Linear(
+            linear,
+        )) => {
+            linear.angle.to_bits().hash(state);
+            hash_color(&linear.start, state);
+            hash_color(&linear.end, state);
+        }
+    }
+}
+
+fn hash_gradient(gradient: &Gradient, state: &mut Hasher) {
+    std::mem::discriminant(gradient).hash(state);
+
+    match gradient {
+        Gradient::Linear(gradient::Linear { start, end, .. }) => {
+            hash_floats(&[start.x, start.y, end.x, end.y], state);
+        }
+        Gradient::Radial(gradient::Radial { center, radius, .. }) => {
+            hash_floats(&[center.x, center.y, *radius], state);
+        }
+    }
+
+    for stop in gradient.color_stops() {
+        stop.offset.to_bits().hash(state);
+        hash_color(&stop.color, state);
+    }
+}
+
+fn hash_font(font: &Font, state: &mut Hasher) {
+    std::mem::discriminant(font).hash(state);
+
+    if let Font::External { name, bytes } = font {
+        name.hash(state);
+        bytes.as_ptr().hash(state);
+        bytes.len().hash(state);
+    }
+}
@@ -13,6 +13,10 @@ pub enum Error {
     #[error("a suitable graphics adapter or device could not be found")]
     GraphicsAdapterNotFound,
 
+    /// The requested antialiasing strategy is not supported.
+    #[error("the requested antialiasing strategy is not supported")]
+    AntialiasingNotSupported,
+
     /// An error occured in the context's internal backend
     #[error("an error occured in the context's internal backend")]
     BackendError(String),
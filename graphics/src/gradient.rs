@@ -1,17 +1,22 @@
 //! For creating a Gradient.
 pub mod linear;
+pub mod radial;
 
 pub use linear::Linear;
+pub use radial::Radial;
 
 use crate::{Color, Point, Size};
 
 #[derive(Debug, Clone, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
 /// or conically (TBD).
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors from its `center` to the circle of the given
+    /// `radius` around it.
+    Radial(Radial),
 }
 
 impl Gradient {
@@ -19,6 +24,55 @@ impl Gradient {
     pub fn linear(position: impl Into<Position>) -> linear::Builder {
         linear::Builder::new(position.into())
     }
+
+    /// Creates a new radial [`radial::Builder`].
+    pub fn radial(center: Point, radius: f32) -> radial::Builder {
+        radial::Builder::new(center, radius)
+    }
+
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn color_stops(&self) -> &[ColorStop] {
+        match self {
+            Gradient::Linear(linear) => &linear.color_stops,
+            Gradient::Radial(radial) => &radial.color_stops,
+        }
+    }
+}
+
+/// Inserts a new [`ColorStop`] in the sorted stops of a gradient.
+fn add_stop(
+    stops: &mut Vec<ColorStop>,
+    offset: f32,
+    color: Color,
+) -> Result<(), BuilderError> {
+    if !(offset.is_finite() && (0.0..=1.0).contains(&offset)) {
+        return Err(BuilderError::InvalidOffset(offset));
+    }
+
+    match stops
+        .binary_search_by(|stop| stop.offset.partial_cmp(&offset).unwrap())
+    {
+        Ok(_) => Err(BuilderError::DuplicateOffset(offset)),
+        Err(index) => {
+            stops.insert(index, ColorStop { offset, color });
+
+            Ok(())
+        }
+    }
+}
+
+/// An error that happened when building a [`Gradient`].
+#[derive(Debug, thiserror::Error)]
+pub enum BuilderError {
+    #[error("Gradients must contain at least one color stop.")]
+    /// Gradients must contain at least one color stop.
+    MissingColorStop,
+    #[error("Offset {0} must be a unique, finite number.")]
+    /// Offsets in a gradient must all be unique & finite.
+    DuplicateOffset(f32),
+    #[error("Offset {0} must be between 0.0..=1.0.")]
+    /// Offsets in a gradient must be between 0.0..=1.0.
+    InvalidOffset(f32),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1,7 +1,9 @@
 //! Linear gradient builder & definition.
-use crate::gradient::{ColorStop, Gradient, Position};
+use crate::gradient::{self, ColorStop, Gradient, Position};
 use crate::{Color, Point};
 
+pub use crate::gradient::BuilderError;
+
 /// A linear gradient that can be used in the style of [`Fill`] or [`Stroke`].
 ///
 /// [`Fill`]: crate::widget::canvas::Fill
@@ -61,20 +63,9 @@ impl Builder {
     /// [`glow`]: https://docs.rs/iced_glow
     /// [`wgpu`]: https://docs.rs/iced_wgpu
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            match self.stops.binary_search_by(|stop| {
-                stop.offset.partial_cmp(&offset).unwrap()
-            }) {
-                Ok(_) => {
-                    self.error = Some(BuilderError::DuplicateOffset(offset))
-                }
-                Err(index) => {
-                    self.stops.insert(index, ColorStop { offset, color });
-                }
-            }
-        } else {
-            self.error = Some(BuilderError::InvalidOffset(offset))
-        };
+        if let Err(error) = gradient::add_stop(&mut self.stops, offset, color) {
+            self.error = Some(error);
+        }
 
         self
     }
@@ -96,17 +87,3 @@ impl Builder {
         }
     }
 }
-
-/// An error that happened when building a [`Linear`] gradient.
-#[derive(Debug, thiserror::Error)]
-pub enum BuilderError {
-    #[error("Gradients must contain at least one color stop.")]
-    /// Gradients must contain at least one color stop.
-    MissingColorStop,
-    #[error("Offset {0} must be a unique, finite number.")]
-    /// Offsets in a gradient must all be unique & finite.
-    DuplicateOffset(f32),
-    #[error("Offset {0} must be between 0.0..=1.0.")]
-    /// Offsets in a gradient must be between 0.0..=1.0.
-    InvalidOffset(f32),
-}
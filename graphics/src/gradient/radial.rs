@@ -0,0 +1,71 @@
+//! Radial gradient builder & definition.
+use crate::gradient::{self, BuilderError, ColorStop, Gradient};
+use crate::{Color, Point};
+
+/// A radial gradient that can be used in the style of [`Fill`] or [`Stroke`].
+///
+/// [`Fill`]: crate::widget::canvas::Fill
+/// [`Stroke`]: crate::widget::canvas::Stroke
+#[derive(Debug, Clone, PartialEq)]
+pub struct Radial {
+    /// The point where the radial gradient begins.
+    pub center: Point,
+    /// The distance from the center where the radial gradient ends.
+    pub radius: f32,
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub color_stops: Vec<ColorStop>,
+}
+
+/// A [`Radial`] builder.
+#[derive(Debug)]
+pub struct Builder {
+    center: Point,
+    radius: f32,
+    stops: Vec<ColorStop>,
+    error: Option<BuilderError>,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`].
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: vec![],
+            error: None,
+        }
+    }
+
+    /// Adds a new stop, defined by an offset and a color, to the gradient.
+    ///
+    /// `offset` must be between `0.0` and `1.0` or the gradient cannot be built.
+    ///
+    /// Note: when using the [`glow`] backend, any color stop added after the 16th
+    /// will not be displayed.
+    ///
+    /// [`glow`]: https://docs.rs/iced_glow
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        if let Err(error) = gradient::add_stop(&mut self.stops, offset, color) {
+            self.error = Some(error);
+        }
+
+        self
+    }
+
+    /// Builds the radial [`Gradient`] of this [`Builder`].
+    ///
+    /// Returns `BuilderError` if gradient in invalid.
+    pub fn build(self) -> Result<Gradient, BuilderError> {
+        if self.stops.is_empty() {
+            Err(BuilderError::MissingColorStop)
+        } else if let Some(error) = self.error {
+            Err(error)
+        } else {
+            Ok(Gradient::Radial(Radial {
+                center: self.center,
+                radius: self.radius,
+                color_stops: self.stops,
+            }))
+        }
+    }
+}
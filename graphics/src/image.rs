@@ -8,3 +8,53 @@ pub mod vector;
 pub mod storage;
 
 pub use storage::Storage;
+
+#[cfg(any(feature = "image_rs", feature = "svg"))]
+use iced_native::image::{Budget, Eviction};
+
+/// Returns the keys of the unused entries of a cache that need to be evicted
+/// to fit in the [`Budget`], given the bytes used by the entries in use.
+///
+/// Unused entries are described by their key, the generation of the cache in
+/// which they were last used, and their size in bytes.
+#[cfg(any(feature = "image_rs", feature = "svg"))]
+pub(crate) fn evictions<K>(
+    budget: &Budget,
+    in_use: u64,
+    mut unused: Vec<(K, u64, u64)>,
+) -> Vec<K> {
+    let limit = match (budget.eviction, budget.memory) {
+        (Eviction::Unused, _) => {
+            return unused.into_iter().map(|(key, _, _)| key).collect();
+        }
+        (Eviction::LeastRecentlyUsed, Some(limit)) => limit,
+        (Eviction::LeastRecentlyUsed, None) => return Vec::new(),
+    };
+
+    unused.sort_by_key(|(_, generation, _)| *generation);
+
+    let mut total =
+        in_use + unused.iter().map(|(_, _, bytes)| bytes).sum::<u64>();
+    let mut evicted = Vec::new();
+
+    for (key, _, bytes) in unused {
+        if total <= limit {
+            break;
+        }
+
+        total -= bytes;
+        evicted.push(key);
+    }
+
+    evicted
+}
+
+/// Returns the amount of bytes used by an [`Entry`] of some [`Storage`].
+///
+/// [`Entry`]: storage::Entry
+#[cfg(any(feature = "image_rs", feature = "svg"))]
+pub(crate) fn bytes(entry: &impl storage::Entry) -> u64 {
+    let size = entry.size();
+
+    u64::from(size.width) * u64::from(size.height) * 4
+}
@@ -3,6 +3,7 @@ use crate::image::Storage;
 use crate::Size;
 
 use iced_native::image;
+use iced_native::time::Duration;
 
 use bitflags::bitflags;
 use std::collections::{HashMap, HashSet};
@@ -42,7 +43,19 @@ impl<T: Storage> Memory<T> {
 #[derive(Debug)]
 pub struct Cache<T: Storage> {
     map: HashMap<u64, Memory<T>>,
+    animations: HashMap<u64, Option<Vec<Frame>>>,
+    filtered: HashMap<(u64, u64), Memory<T>>,
     hits: HashSet<u64>,
+    filtered_hits: HashSet<(u64, u64)>,
+    last_used: HashMap<Key, u64>,
+    generation: u64,
+}
+
+/// The key of an uploaded image in a [`Cache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    Image(u64),
+    Filtered(u64, u64),
 }
 
 impl<T: Storage> Cache<T> {
@@ -52,11 +65,31 @@ impl<T: Storage> Cache<T> {
             return self.get(handle).unwrap();
         }
 
-        let memory = match handle.data() {
+        let memory = self.decode(handle);
+
+        self.insert(handle, memory);
+        self.get(handle).unwrap()
+    }
+
+    fn decode(&mut self, handle: &image::Handle) -> Memory<T> {
+        if handle.frame() > 0 {
+            return match self
+                .frames(handle)
+                .and_then(|frames| frames.get(handle.frame()))
+            {
+                Some(frame) => Memory::Host(frame.image.clone()),
+                None => Memory::Invalid,
+            };
+        }
+
+        let exif_orientation = handle.applies_exif_orientation();
+
+        match handle.data() {
             image::Data::Path(path) => {
                 if let Ok(image) = image_rs::open(path) {
                     let operation = std::fs::File::open(path)
                         .ok()
+                        .filter(|_| exif_orientation)
                         .map(std::io::BufReader::new)
                         .and_then(|mut reader| {
                             Operation::from_exif(&mut reader).ok()
@@ -70,10 +103,15 @@ impl<T: Storage> Cache<T> {
             }
             image::Data::Bytes(bytes) => {
                 if let Ok(image) = image_rs::load_from_memory(bytes) {
-                    let operation =
-                        Operation::from_exif(&mut std::io::Cursor::new(bytes))
+                    let operation = exif_orientation
+                        .then(|| {
+                            Operation::from_exif(&mut std::io::Cursor::new(
+                                bytes,
+                            ))
                             .ok()
-                            .unwrap_or_else(Operation::empty);
+                        })
+                        .flatten()
+                        .unwrap_or_else(Operation::empty);
 
                     Memory::Host(operation.perform(image.to_rgba8()))
                 } else {
@@ -95,10 +133,16 @@ impl<T: Storage> Cache<T> {
                     Memory::Invalid
                 }
             }
-        };
+        }
+    }
 
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+    /// Returns the delay of each frame of an animated image.
+    ///
+    /// Still images have no frames.
+    pub fn frame_delays(&mut self, handle: &image::Handle) -> Vec<Duration> {
+        self.frames(handle)
+            .map(|frames| frames.iter().map(|frame| frame.delay).collect())
+            .unwrap_or_default()
     }
 
     /// Load image and upload raster data
@@ -125,12 +169,171 @@ impl<T: Storage> Cache<T> {
         }
     }
 
-    /// Trim cache misses from cache
-    pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
+    /// Load image, apply the [`image::Filter`] on the CPU and upload the
+    /// filtered raster data
+    pub fn upload_filtered(
+        &mut self,
+        handle: &image::Handle,
+        filter: image::Filter,
+        state: &mut T::State<'_>,
+        storage: &mut T,
+    ) -> Option<&T::Entry> {
+        let key = (handle.id(), filter_key(&filter));
+        let _ = self.filtered_hits.insert(key);
+
+        if !self.filtered.contains_key(&key) {
+            let memory = match self.decode(handle) {
+                Memory::Host(image) => {
+                    Memory::Host(apply_filter(image, filter))
+                }
+                memory => memory,
+            };
+
+            let _ = self.filtered.insert(key, memory);
+        }
+
+        let memory = self.filtered.get_mut(&key)?;
+
+        if let Memory::Host(image) = memory {
+            let (width, height) = image.dimensions();
+
+            let entry = storage.upload(width, height, image, state)?;
+
+            *memory = Memory::Device(entry);
+        }
+
+        if let Memory::Device(allocation) = memory {
+            Some(allocation)
+        } else {
+            None
+        }
+    }
+
+    /// Evict image and all of its filtered variants from cache
+    pub fn evict(
+        &mut self,
+        handle: &image::Handle,
+        storage: &mut T,
+        state: &mut T::State<'_>,
+    ) {
+        let id = handle.id();
+
+        let _ = self.hits.remove(&id);
+        let _ = self.animations.remove(&id);
+
+        if let Some(Memory::Device(entry)) = self.map.remove(&id) {
+            storage.remove(&entry, state);
+        }
+
+        self.filtered.retain(|(image, _), memory| {
+            let retain = *image != id;
+
+            if !retain {
+                if let Memory::Device(entry) = memory {
+                    storage.remove(entry, state);
+                }
+            }
+
+            retain
+        });
+    }
+
+    /// Returns the amount of images uploaded to the storage and the bytes
+    /// they use
+    pub fn usage(&self) -> image::Usage {
+        self.map
+            .values()
+            .chain(self.filtered.values())
+            .filter_map(|memory| match memory {
+                Memory::Device(entry) => Some(super::bytes(entry)),
+                _ => None,
+            })
+            .fold(image::Usage::default(), |usage, bytes| image::Usage {
+                images: usage.images + 1,
+                bytes: usage.bytes + bytes,
+                ..usage
+            })
+    }
+
+    /// Trim cache misses from cache, keeping the uploaded images that were
+    /// not used if the [`image::Budget`] allows it
+    pub fn trim(
+        &mut self,
+        budget: &image::Budget,
+        storage: &mut T,
+        state: &mut T::State<'_>,
+    ) {
+        self.generation += 1;
+
+        let hits = self.hits.iter().map(|id| Key::Image(*id)).chain(
+            self.filtered_hits
+                .iter()
+                .map(|(id, filter)| Key::Filtered(*id, *filter)),
+        );
+
+        for key in hits {
+            let _ = self.last_used.insert(key, self.generation);
+        }
+
+        let entries = self
+            .map
+            .iter()
+            .map(|(id, memory)| {
+                (Key::Image(*id), self.hits.contains(id), memory)
+            })
+            .chain(self.filtered.iter().map(|(key, memory)| {
+                (
+                    Key::Filtered(key.0, key.1),
+                    self.filtered_hits.contains(key),
+                    memory,
+                )
+            }));
+
+        let mut in_use = 0;
+        let mut unused = Vec::new();
+
+        for (key, hit, memory) in entries {
+            if let Memory::Device(entry) = memory {
+                let bytes = super::bytes(entry);
+
+                if hit {
+                    in_use += bytes;
+                } else {
+                    let last_used =
+                        self.last_used.get(&key).copied().unwrap_or_default();
+
+                    unused.push((key, last_used, bytes));
+                }
+            }
+        }
+
+        let evicted: HashSet<Key> = super::evictions(budget, in_use, unused)
+            .into_iter()
+            .collect();
+
         let hits = &self.hits;
+        let filtered_hits = &self.filtered_hits;
+
+        self.animations.retain(|k, _| hits.contains(k));
 
         self.map.retain(|k, memory| {
-            let retain = hits.contains(k);
+            let retain = hits.contains(k)
+                || (matches!(memory, Memory::Device(_))
+                    && !evicted.contains(&Key::Image(*k)));
+
+            if !retain {
+                if let Memory::Device(entry) = memory {
+                    storage.remove(entry, state);
+                }
+            }
+
+            retain
+        });
+
+        self.filtered.retain(|k, memory| {
+            let retain = filtered_hits.contains(k)
+                || (matches!(memory, Memory::Device(_))
+                    && !evicted.contains(&Key::Filtered(k.0, k.1)));
 
             if !retain {
                 if let Memory::Device(entry) = memory {
@@ -141,7 +344,25 @@ impl<T: Storage> Cache<T> {
             retain
         });
 
+        let (map, filtered) = (&self.map, &self.filtered);
+
+        self.last_used.retain(|key, _| match key {
+            Key::Image(id) => map.contains_key(id),
+            Key::Filtered(id, filter) => filtered.contains_key(&(*id, *filter)),
+        });
+
         self.hits.clear();
+        self.filtered_hits.clear();
+    }
+
+    fn frames(&mut self, handle: &image::Handle) -> Option<&[Frame]> {
+        let id = handle.with_frame(0).id();
+        let _ = self.hits.insert(id);
+
+        self.animations
+            .entry(id)
+            .or_insert_with(|| decode_frames(handle.data()))
+            .as_deref()
     }
 
     fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory<T>> {
@@ -163,9 +384,143 @@ impl<T: Storage> Default for Cache<T> {
     fn default() -> Self {
         Self {
             map: HashMap::new(),
+            animations: HashMap::new(),
+            filtered: HashMap::new(),
             hits: HashSet::new(),
+            filtered_hits: HashSet::new(),
+            last_used: HashMap::new(),
+            generation: 0,
+        }
+    }
+}
+
+/// Hashes the parameters of an [`image::Filter`].
+fn filter_key(filter: &image::Filter) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+
+    filter.blur.to_bits().hash(&mut hasher);
+    filter.grayscale.to_bits().hash(&mut hasher);
+    filter.brightness.to_bits().hash(&mut hasher);
+    filter.contrast.to_bits().hash(&mut hasher);
+    filter.tint.map(|tint| tint.into_rgba8()).hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Applies the [`image::Filter`] to the image, like the image shader of
+/// GPU backends does.
+fn apply_filter(
+    image: ::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>,
+    filter: image::Filter,
+) -> ::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>> {
+    // The blur is a gaussian spanning twice its standard deviation
+    let mut image = if filter.blur > 0.0 {
+        image_rs::imageops::blur(&image, filter.blur / 2.0)
+    } else {
+        image
+    };
+
+    for pixel in image.pixels_mut() {
+        let [r, g, b, a] = pixel.0;
+        let mut rgb = [r, g, b].map(|channel| f32::from(channel) / 255.0);
+
+        let luma = 0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2];
+
+        rgb = rgb.map(|channel| {
+            let channel = channel + (luma - channel) * filter.grayscale;
+
+            (channel * filter.brightness - 0.5) * filter.contrast + 0.5
+        });
+
+        if let Some(tint) = filter.tint {
+            for (channel, target) in
+                rgb.iter_mut().zip([tint.r, tint.g, tint.b])
+            {
+                *channel += (target - *channel) * tint.a;
+            }
         }
+
+        let [r, g, b] =
+            rgb.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+        pixel.0 = [r, g, b, a];
     }
+
+    image
+}
+
+/// A decoded frame of an animated image.
+#[derive(Debug)]
+struct Frame {
+    image: ::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>,
+    delay: Duration,
+}
+
+/// Decodes the frames of an animated GIF or PNG image.
+#[cfg(any(feature = "gif", feature = "png"))]
+fn decode_frames(data: &image::Data) -> Option<Vec<Frame>> {
+    use image_rs::AnimationDecoder;
+    use std::borrow::Cow;
+
+    // Like browsers do, frames without a proper delay are slowed down
+    const MINIMUM_DELAY: Duration = Duration::from_millis(10);
+    const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    let bytes = match data {
+        image::Data::Path(path) => Cow::Owned(std::fs::read(path).ok()?),
+        image::Data::Bytes(bytes) => Cow::Borrowed(bytes.as_ref()),
+        image::Data::Rgba { .. } => return None,
+    };
+
+    let reader = std::io::Cursor::new(bytes.as_ref());
+
+    let frames = match image_rs::guess_format(&bytes).ok()? {
+        #[cfg(feature = "gif")]
+        image_rs::ImageFormat::Gif => {
+            image_rs::codecs::gif::GifDecoder::new(reader)
+                .ok()?
+                .into_frames()
+        }
+        #[cfg(feature = "png")]
+        image_rs::ImageFormat::Png => {
+            let decoder =
+                image_rs::codecs::png::PngDecoder::new(reader).ok()?;
+
+            if !decoder.is_apng() {
+                return None;
+            }
+
+            decoder.apng().into_frames()
+        }
+        _ => return None,
+    };
+
+    let frames = frames.collect_frames().ok()?;
+
+    Some(
+        frames
+            .into_iter()
+            .map(|frame| {
+                let delay = Duration::from(frame.delay());
+
+                Frame {
+                    delay: if delay <= MINIMUM_DELAY {
+                        DEFAULT_DELAY
+                    } else {
+                        delay
+                    },
+                    image: frame.into_buffer(),
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(not(any(feature = "gif", feature = "png")))]
+fn decode_frames(_data: &image::Data) -> Option<Vec<Frame>> {
+    None
 }
 
 bitflags! {
@@ -2,11 +2,13 @@
 use crate::image::Storage;
 use crate::Color;
 
+use iced_native::image;
 use iced_native::svg;
 use iced_native::Size;
 
 use resvg::tiny_skia;
 use resvg::usvg;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 
@@ -36,13 +38,17 @@ impl Svg {
 #[derive(Debug)]
 pub struct Cache<T: Storage> {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32, ColorFilter), T::Entry>,
+    rasterized: HashMap<RasterKey, T::Entry>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32, ColorFilter)>,
+    rasterized_hits: HashSet<RasterKey>,
+    last_used: HashMap<RasterKey, u64>,
+    generation: u64,
 }
 
 type ColorFilter = Option<[u8; 4]>;
 
+type RasterKey = (u64, u32, u32, ColorFilter);
+
 impl<T: Storage> Cache<T> {
     /// Load svg
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
@@ -50,17 +56,35 @@ impl<T: Storage> Cache<T> {
             return self.svgs.get(&handle.id()).unwrap();
         }
 
+        let variables = handle.variables();
+
         let svg = match handle.data() {
             svg::Data::Path(path) => {
                 let tree = fs::read_to_string(path).ok().and_then(|contents| {
-                    usvg::Tree::from_str(&contents, &usvg::Options::default())
-                        .ok()
+                    usvg::Tree::from_str(
+                        &replace_variables(&contents, variables),
+                        &usvg::Options::default(),
+                    )
+                    .ok()
                 });
 
                 tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
             }
             svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(bytes, &usvg::Options::default()) {
+                // Compressed data cannot contain variables to replace
+                let tree = match std::str::from_utf8(bytes) {
+                    Ok(contents) if !variables.is_empty() => {
+                        usvg::Tree::from_str(
+                            &replace_variables(contents, variables),
+                            &usvg::Options::default(),
+                        )
+                    }
+                    _ => {
+                        usvg::Tree::from_data(bytes, &usvg::Options::default())
+                    }
+                };
+
+                match tree {
                     Ok(tree) => Svg::Loaded(tree),
                     Err(_) => Svg::NotFound,
                 }
@@ -150,14 +174,57 @@ impl<T: Storage> Cache<T> {
         }
     }
 
-    /// Load svg and upload raster data
-    pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
+    /// Returns the amount of rasterized svgs uploaded to the storage and the
+    /// bytes they use
+    pub fn usage(&self) -> image::Usage {
+        image::Usage {
+            vectors: self.rasterized.len(),
+            bytes: self.rasterized.values().map(super::bytes).sum(),
+            ..image::Usage::default()
+        }
+    }
+
+    /// Trim cache misses from cache, keeping the rasterized svgs that were
+    /// not used if the [`image::Budget`] allows it
+    pub fn trim(
+        &mut self,
+        budget: &image::Budget,
+        storage: &mut T,
+        state: &mut T::State<'_>,
+    ) {
+        self.generation += 1;
+
+        for key in &self.rasterized_hits {
+            let _ = self.last_used.insert(*key, self.generation);
+        }
+
+        let mut in_use = 0;
+        let mut unused = Vec::new();
+
+        for (key, entry) in &self.rasterized {
+            let bytes = super::bytes(entry);
+
+            if self.rasterized_hits.contains(key) {
+                in_use += bytes;
+            } else {
+                let last_used =
+                    self.last_used.get(key).copied().unwrap_or_default();
+
+                unused.push((*key, last_used, bytes));
+            }
+        }
+
+        let evicted: HashSet<RasterKey> =
+            super::evictions(budget, in_use, unused)
+                .into_iter()
+                .collect();
+
         let svg_hits = &self.svg_hits;
         let rasterized_hits = &self.rasterized_hits;
 
         self.svgs.retain(|k, _| svg_hits.contains(k));
         self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+            let retain = rasterized_hits.contains(k) || !evicted.contains(k);
 
             if !retain {
                 storage.remove(entry, state);
@@ -165,6 +232,10 @@ impl<T: Storage> Cache<T> {
 
             retain
         });
+
+        let rasterized = &self.rasterized;
+        self.last_used.retain(|k, _| rasterized.contains_key(k));
+
         self.svg_hits.clear();
         self.rasterized_hits.clear();
     }
@@ -177,10 +248,93 @@ impl<T: Storage> Default for Cache<T> {
             rasterized: HashMap::new(),
             svg_hits: HashSet::new(),
             rasterized_hits: HashSet::new(),
+            last_used: HashMap::new(),
+            generation: 0,
         }
     }
 }
 
+/// Replaces every `var(--name)` in the SVG source with the color of the
+/// variable with the same name.
+///
+/// Unknown variables are replaced by their fallback value, if any, and left
+/// untouched otherwise.
+fn replace_variables<'a>(
+    source: &'a str,
+    variables: &[(String, Color)],
+) -> Cow<'a, str> {
+    if variables.is_empty() || !source.contains("var(") {
+        return Cow::Borrowed(source);
+    }
+
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find("var(") {
+        result.push_str(&rest[..start]);
+
+        let arguments = &rest[start + 4..];
+
+        // Fallback values may contain parentheses themselves
+        let mut depth = 0;
+        let end = arguments.char_indices().find_map(|(index, c)| match c {
+            '(' => {
+                depth += 1;
+                None
+            }
+            ')' if depth == 0 => Some(index),
+            ')' => {
+                depth -= 1;
+                None
+            }
+            _ => None,
+        });
+
+        let end = match end {
+            Some(end) => end,
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+                break;
+            }
+        };
+
+        let (name, fallback) = match arguments[..end].split_once(',') {
+            Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+            None => (arguments[..end].trim(), None),
+        };
+
+        let color = name.strip_prefix("--").and_then(|name| {
+            variables
+                .iter()
+                .find(|(variable, _)| variable == name)
+                .map(|(_, color)| *color)
+        });
+
+        match (color, fallback) {
+            (Some(color), _) => result.push_str(&css_color(color)),
+            (None, Some(fallback)) => result.push_str(fallback),
+            (None, None) => result.push_str(&rest[start..start + 4 + end + 1]),
+        }
+
+        rest = &arguments[end + 1..];
+    }
+
+    result.push_str(rest);
+
+    Cow::Owned(result)
+}
+
+fn css_color(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("rgba({r}, {g}, {b}, {})", color.a)
+    }
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
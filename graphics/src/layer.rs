@@ -1,6 +1,7 @@
 //! Organize rendering primitives into a flattened list of layers.
 mod image;
 mod quad;
+mod shader;
 mod text;
 
 pub mod mesh;
@@ -8,11 +9,13 @@ pub mod mesh;
 pub use image::Image;
 pub use mesh::Mesh;
 pub use quad::Quad;
+pub use shader::Shader;
 pub use text::Text;
 
 use crate::alignment;
 use crate::{
-    Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
+    Background, BlendMode, Font, Point, Primitive, Rectangle, Size, Vector,
+    Viewport,
 };
 
 /// A group of primitives that should be clipped together.
@@ -32,6 +35,12 @@ pub struct Layer<'a> {
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
+
+    /// The custom shaders of the [`Layer`].
+    pub shaders: Vec<Shader>,
+
+    /// The [`BlendMode`] used to draw the meshes of the [`Layer`].
+    pub blend_mode: BlendMode,
 }
 
 impl<'a> Layer<'a> {
@@ -43,6 +52,8 @@ impl<'a> Layer<'a> {
             meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            shaders: Vec::new(),
+            blend_mode: BlendMode::Normal,
         }
     }
 
@@ -65,6 +76,7 @@ impl<'a> Layer<'a> {
                 font: Font::Default,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                rotation: 0.0,
             };
 
             overlay.text.push(text);
@@ -85,8 +97,21 @@ impl<'a> Layer<'a> {
         primitives: &'a [Primitive],
         viewport: &Viewport,
     ) -> Vec<Self> {
-        let first_layer =
-            Layer::new(Rectangle::with_size(viewport.logical_size()));
+        Self::generate_within(
+            primitives,
+            Rectangle::with_size(viewport.logical_size()),
+        )
+    }
+
+    /// Distributes the given [`Primitive`] and generates a list of layers
+    /// clipped to the given bounds.
+    ///
+    /// It can be used to redraw only a region of the viewport.
+    pub fn generate_within(
+        primitives: &'a [Primitive],
+        bounds: Rectangle,
+    ) -> Vec<Self> {
+        let first_layer = Layer::new(bounds);
 
         let mut layers = vec![first_layer];
 
@@ -129,6 +154,7 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                rotation,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -140,6 +166,7 @@ impl<'a> Layer<'a> {
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    rotation: *rotation,
                 });
             }
             Primitive::Quad {
@@ -148,9 +175,25 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = &mut layers[current_layer];
 
+                let (color, end_color, direction) = match background {
+                    Background::Color(color) => {
+                        let color = color.into_linear();
+
+                        (color, color, [1.0, 0.0])
+                    }
+                    Background::Gradient(
+                        iced_native::gradient::Gradient::Linear(linear),
+                    ) => (
+                        linear.start.into_linear(),
+                        linear.end.into_linear(),
+                        linear.direction(),
+                    ),
+                };
+
                 // TODO: Move some of these computations to the GPU (?)
                 layer.quads.push(Quad {
                     position: [
@@ -158,12 +201,20 @@ impl<'a> Layer<'a> {
                         bounds.y + translation.y,
                     ],
                     size: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
+                    color,
                     border_radius: *border_radius,
                     border_width: *border_width,
                     border_color: border_color.into_linear(),
+                    end_color,
+                    direction,
+                    shadow_color: if shadow.is_visible() {
+                        shadow.color.into_linear()
+                    } else {
+                        [0.0; 4]
+                    },
+                    shadow_offset: [shadow.offset.x, shadow.offset.y],
+                    shadow_blur_radius: shadow.blur_radius.max(0.0),
+                    shadow_spread_radius: shadow.spread_radius,
                 });
             }
             Primitive::SolidMesh { buffers, size } => {
@@ -213,7 +264,10 @@ impl<'a> Layer<'a> {
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
+                    let clip_layer = Layer {
+                        blend_mode: layer.blend_mode,
+                        ..Layer::new(clip_bounds)
+                    };
                     layers.push(clip_layer);
 
                     Self::process_primitive(
@@ -224,6 +278,22 @@ impl<'a> Layer<'a> {
                     );
                 }
             }
+            Primitive::Blend { mode, content } => {
+                let layer = &layers[current_layer];
+
+                let blend_layer = Layer {
+                    blend_mode: *mode,
+                    ..Layer::new(layer.bounds)
+                };
+                layers.push(blend_layer);
+
+                Self::process_primitive(
+                    layers,
+                    translation,
+                    content,
+                    layers.len() - 1,
+                );
+            }
             Primitive::Translate {
                 translation: new_translation,
                 content,
@@ -243,12 +313,23 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                rotation,
+                flip,
+                filter,
+                opacity,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    flip: *flip,
+                    filter: *filter,
+                    opacity: *opacity,
                 });
             }
             Primitive::Svg {
@@ -264,6 +345,19 @@ impl<'a> Layer<'a> {
                     bounds: *bounds + translation,
                 });
             }
+            Primitive::Shader {
+                handle,
+                bounds,
+                uniforms,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                layer.shaders.push(Shader {
+                    handle: handle.clone(),
+                    bounds: *bounds + translation,
+                    uniforms: uniforms.clone(),
+                });
+            }
         }
     }
 }
@@ -12,6 +12,18 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The rotation of the image around its center, in radians.
+        rotation: f32,
+
+        /// The mirroring of the image, applied before its rotation.
+        flip: image::Flip,
+
+        /// The filter applied to the image.
+        filter: image::Filter,
+
+        /// The opacity of the image, in [0.0, 1.0].
+        opacity: f32,
     },
     /// A vector image.
     Vector {
@@ -11,6 +11,8 @@ pub struct Quad {
     pub size: [f32; 2],
 
     /// The color of the [`Quad`], in __linear RGB__.
+    ///
+    /// It is the start color when the [`Quad`] is filled with a gradient.
     pub color: [f32; 4],
 
     /// The border color of the [`Quad`], in __linear RGB__.
@@ -21,6 +23,30 @@ pub struct Quad {
 
     /// The border width of the [`Quad`].
     pub border_width: f32,
+
+    /// The end color of the gradient filling the [`Quad`], in __linear RGB__.
+    ///
+    /// It is equal to the [`color`] of a solid [`Quad`].
+    ///
+    /// [`color`]: Self::color
+    pub end_color: [f32; 4],
+
+    /// The unit vector of the direction of the gradient filling the [`Quad`].
+    pub direction: [f32; 2],
+
+    /// The color of the shadow cast by the [`Quad`], in __linear RGB__.
+    ///
+    /// It is transparent when the [`Quad`] casts no shadow.
+    pub shadow_color: [f32; 4],
+
+    /// The offset of the shadow cast by the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the shadow cast by the [`Quad`].
+    pub shadow_blur_radius: f32,
+
+    /// The spread radius of the shadow cast by the [`Quad`].
+    pub shadow_spread_radius: f32,
 }
 
 #[allow(unsafe_code)]
@@ -0,0 +1,16 @@
+use crate::Rectangle;
+
+use iced_native::shader;
+
+/// A custom shader drawn inside some bounds.
+#[derive(Debug, Clone)]
+pub struct Shader {
+    /// The handle of the shader.
+    pub handle: shader::Handle,
+
+    /// The bounds of the shader.
+    pub bounds: Rectangle,
+
+    /// The uniforms bound to the shader.
+    pub uniforms: Vec<f32>,
+}
@@ -23,4 +23,7 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The rotation of the [`Text`] around its position, in radians.
+    pub rotation: f32,
 }
@@ -21,13 +21,14 @@
 #![forbid(rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
-mod antialiasing;
+mod blend_mode;
 mod error;
 mod primitive;
 mod transformation;
 mod viewport;
 
 pub mod backend;
+pub mod damage;
 pub mod font;
 pub mod gradient;
 pub mod image;
@@ -38,8 +39,8 @@ pub mod triangle;
 pub mod widget;
 pub mod window;
 
-pub use antialiasing::Antialiasing;
 pub use backend::Backend;
+pub use blend_mode::BlendMode;
 pub use error::Error;
 pub use gradient::Gradient;
 pub use layer::Layer;
@@ -50,6 +51,7 @@ pub use viewport::Viewport;
 pub use window::compositor;
 
 pub use iced_native::alignment;
+pub use iced_native::window::Antialiasing;
 pub use iced_native::{
-    Alignment, Background, Color, Font, Point, Rectangle, Size, Vector,
+    Alignment, Background, Color, Font, Point, Rectangle, Shadow, Size, Vector,
 };
@@ -1,8 +1,12 @@
 use iced_native::image;
+use iced_native::shader;
 use iced_native::svg;
-use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
+use iced_native::{
+    Background, Color, Font, Point, Rectangle, Shadow, Size, Vector,
+};
 
 use crate::alignment;
+use crate::blend_mode::BlendMode;
 use crate::gradient::Gradient;
 use crate::triangle;
 
@@ -34,6 +38,8 @@ pub enum Primitive {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The rotation of the text around its position, in radians
+        rotation: f32,
     },
     /// A quad primitive
     Quad {
@@ -47,6 +53,8 @@ pub enum Primitive {
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow cast by the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
@@ -54,6 +62,14 @@ pub enum Primitive {
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The rotation of the image around its center, in radians
+        rotation: f32,
+        /// The mirroring of the image, applied before its rotation
+        flip: image::Flip,
+        /// The filter applied to the image
+        filter: image::Filter,
+        /// The opacity of the image, in [0.0, 1.0]
+        opacity: f32,
     },
     /// An SVG primitive
     Svg {
@@ -66,6 +82,15 @@ pub enum Primitive {
         /// The bounds of the viewport
         bounds: Rectangle,
     },
+    /// A custom shader primitive
+    Shader {
+        /// The handle of the shader
+        handle: shader::Handle,
+        /// The bounds of the shader
+        bounds: Rectangle,
+        /// The uniforms bound to the shader
+        uniforms: Vec<f32>,
+    },
     /// A clip primitive
     Clip {
         /// The bounds of the clip
@@ -73,6 +98,13 @@ pub enum Primitive {
         /// The content of the clip
         content: Box<Primitive>,
     },
+    /// A primitive that blends its content with what is drawn behind it
+    Blend {
+        /// The [`BlendMode`] of the content
+        mode: BlendMode,
+        /// The content to blend
+        content: Box<Primitive>,
+    },
     /// A primitive that applies a translation
     Translate {
         /// The translation vector
@@ -118,6 +150,130 @@ pub enum Primitive {
     },
 }
 
+impl Primitive {
+    /// Returns the bounds of the shadows cast by the [`Primitive`], if any.
+    pub fn shadow_bounds(&self) -> Option<Rectangle> {
+        match self {
+            Self::Quad { bounds, shadow, .. } if shadow.is_visible() => {
+                Some((*bounds + shadow.offset).expand(shadow.extent() + 1.0))
+            }
+            Self::Group { primitives } => primitives
+                .iter()
+                .filter_map(Self::shadow_bounds)
+                .reduce(|a, b| a.union(&b)),
+            Self::Clip { bounds, content } => content
+                .shadow_bounds()
+                .and_then(|shadow| shadow.intersection(bounds)),
+            Self::Blend { content, .. } => content.shadow_bounds(),
+            Self::Translate {
+                translation,
+                content,
+            } => content.shadow_bounds().map(|shadow| shadow + *translation),
+            Self::Cached { cache } => cache.shadow_bounds(),
+            _ => None,
+        }
+    }
+
+    /// Returns the bounds of the [`Primitive`], including the pixels it may
+    /// touch because of antialiasing.
+    pub fn bounds(&self) -> Rectangle {
+        match self {
+            Self::None => Rectangle::with_size(Size::ZERO),
+            Self::Group { primitives } => primitives
+                .iter()
+                .map(Self::bounds)
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_else(|| Rectangle::with_size(Size::ZERO)),
+            Self::Text {
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+                rotation,
+                ..
+            } => {
+                let x = match horizontal_alignment {
+                    alignment::Horizontal::Left => bounds.x,
+                    alignment::Horizontal::Center => {
+                        bounds.x - bounds.width / 2.0
+                    }
+                    alignment::Horizontal::Right => bounds.x - bounds.width,
+                };
+
+                let y = match vertical_alignment {
+                    alignment::Vertical::Top => bounds.y,
+                    alignment::Vertical::Center => {
+                        bounds.y - bounds.height / 2.0
+                    }
+                    alignment::Vertical::Bottom => bounds.y - bounds.height,
+                };
+
+                let text = Rectangle::new(Point::new(x, y), bounds.size());
+
+                // Rotated text turns around its position
+                let text = if *rotation == 0.0 {
+                    text
+                } else {
+                    let radius = text.width.hypot(text.height);
+
+                    Rectangle {
+                        x: bounds.x - radius,
+                        y: bounds.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    }
+                };
+
+                text.expand(1.5)
+            }
+            Self::Quad { bounds, shadow, .. } => {
+                let quad = bounds.expand(1.0);
+
+                if shadow.is_visible() {
+                    let shadow =
+                        (*bounds + shadow.offset).expand(shadow.extent() + 1.0);
+
+                    quad.union(&shadow)
+                } else {
+                    quad
+                }
+            }
+            Self::Svg { bounds, .. } | Self::Shader { bounds, .. } => {
+                bounds.expand(1.0)
+            }
+            Self::Image {
+                bounds, rotation, ..
+            } => {
+                // Rotated images turn around their center
+                let image = if *rotation == 0.0 {
+                    *bounds
+                } else {
+                    let center = bounds.center();
+                    let radius = bounds.width.hypot(bounds.height) / 2.0;
+
+                    Rectangle {
+                        x: center.x - radius,
+                        y: center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    }
+                };
+
+                image.expand(1.0)
+            }
+            Self::Clip { bounds, .. } => bounds.expand(1.0),
+            Self::Blend { content, .. } => content.bounds(),
+            Self::Translate {
+                translation,
+                content,
+            } => content.bounds() + *translation,
+            Self::SolidMesh { size, .. } | Self::GradientMesh { size, .. } => {
+                Rectangle::with_size(*size)
+            }
+            Self::Cached { cache } => cache.bounds(),
+        }
+    }
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
@@ -4,9 +4,13 @@ use crate::{Primitive, Vector};
 use iced_native::image;
 use iced_native::layout;
 use iced_native::renderer;
+use iced_native::shader;
 use iced_native::svg;
 use iced_native::text::{self, Text};
-use iced_native::{Background, Color, Element, Font, Point, Rectangle, Size};
+use iced_native::time::Duration;
+use iced_native::{
+    Background, Color, Element, Font, Point, Rectangle, Shadow, Size,
+};
 
 pub use iced_native::renderer::Style;
 
@@ -42,8 +46,11 @@ impl<B: Backend, T> Renderer<B, T> {
 
     /// Runs the given closure with the [`Backend`] and the recorded primitives
     /// of the [`Renderer`].
-    pub fn with_primitives(&mut self, f: impl FnOnce(&mut B, &[Primitive])) {
-        f(&mut self.backend, &self.primitives);
+    pub fn with_primitives<O>(
+        &mut self,
+        f: impl FnOnce(&mut B, &[Primitive]) -> O,
+    ) -> O {
+        f(&mut self.backend, &self.primitives)
     }
 }
 
@@ -81,6 +88,31 @@ where
         });
     }
 
+    fn with_shadowed_layer(
+        &mut self,
+        bounds: Rectangle,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        let bounds = layer_primitives
+            .iter()
+            .filter_map(Primitive::shadow_bounds)
+            .fold(bounds, |bounds, shadow| bounds.union(&shadow));
+
+        self.primitives.push(Primitive::Clip {
+            bounds,
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
     fn with_translation(
         &mut self,
         translation: Vector,
@@ -105,6 +137,15 @@ where
         &mut self,
         quad: renderer::Quad,
         background: impl Into<Background>,
+    ) {
+        self.fill_quad_with_shadow(quad, background, Shadow::default());
+    }
+
+    fn fill_quad_with_shadow(
+        &mut self,
+        quad: renderer::Quad,
+        background: impl Into<Background>,
+        shadow: Shadow,
     ) {
         self.primitives.push(Primitive::Quad {
             bounds: quad.bounds,
@@ -112,6 +153,7 @@ where
             border_radius: quad.border_radius.into(),
             border_width: quad.border_width,
             border_color: quad.border_color,
+            shadow,
         });
     }
 
@@ -144,6 +186,10 @@ where
         self.backend().measure(content, size, font, bounds)
     }
 
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.backend().ascent(size, font)
+    }
+
     fn hit_test(
         &self,
         content: &str,
@@ -172,6 +218,7 @@ where
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            rotation: 0.0,
         });
     }
 }
@@ -187,7 +234,66 @@ where
     }
 
     fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+        self.draw_transformed(handle, bounds, 0.0, image::Flip::default())
+    }
+
+    fn draw_transformed(
+        &mut self,
+        handle: image::Handle,
+        bounds: Rectangle,
+        rotation: f32,
+        flip: image::Flip,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            bounds,
+            rotation,
+            flip,
+            filter: image::Filter::NONE,
+            opacity: 1.0,
+        })
+    }
+
+    fn draw_filtered(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        bounds: Rectangle,
+        filter: image::Filter,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle: handle.with_frame(frame),
+            bounds,
+            rotation: 0.0,
+            flip: image::Flip::default(),
+            filter,
+            opacity: 1.0,
+        })
+    }
+
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        self.backend().frame_delays(handle)
+    }
+
+    fn draw_frame(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle.with_frame(frame), bounds)
+    }
+
+    fn preload(&mut self, handle: &image::Handle) {
+        self.backend.preload(handle)
+    }
+
+    fn evict(&mut self, handle: &image::Handle) {
+        self.backend.evict(handle)
+    }
+
+    fn memory_usage(&self) -> image::Usage {
+        self.backend().memory_usage()
     }
 }
 
@@ -212,3 +318,21 @@ where
         })
     }
 }
+
+impl<B, T> shader::Renderer for Renderer<B, T>
+where
+    B: Backend + backend::Shader,
+{
+    fn draw(
+        &mut self,
+        handle: shader::Handle,
+        bounds: Rectangle,
+        uniforms: Vec<f32>,
+    ) {
+        self.draw_primitive(Primitive::Shader {
+            handle,
+            bounds,
+            uniforms,
+        })
+    }
+}
@@ -9,32 +9,41 @@ pub mod path;
 pub mod stroke;
 
 mod cache;
+mod camera;
 mod cursor;
 mod frame;
 mod geometry;
+mod gesture;
+mod image;
 mod program;
 mod style;
 mod text;
 
 pub use crate::gradient::{self, Gradient};
-pub use cache::Cache;
+pub use crate::BlendMode;
+pub use cache::{Cache, TiledCache};
+pub use camera::Camera;
 pub use cursor::Cursor;
-pub use event::Event;
+pub use event::{Event, Gesture, Tick};
 pub use fill::{Fill, FillRule};
 pub use frame::Frame;
 pub use geometry::Geometry;
+pub use image::Image;
 pub use path::Path;
 pub use program::Program;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
-pub use text::Text;
+pub use text::{Measure, Metrics, Text};
 
+use crate::backend;
 use crate::{Backend, Primitive, Renderer};
 
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::renderer;
+use iced_native::time::Instant;
 use iced_native::widget::tree::{self, Tree};
+use iced_native::window;
 use iced_native::{
     Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
@@ -66,7 +75,7 @@ use std::marker::PhantomData;
 /// impl Program<()> for Circle {
 ///     type State = ();
 ///
-///     fn draw(&self, _state: &(), _theme: &Theme, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry>{
+///     fn draw(&self, _state: &(), _renderer: &dyn canvas::Measure, _theme: &Theme, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry>{
 ///         // We prepare a new `Frame`
 ///         let mut frame = Frame::new(bounds.size());
 ///
@@ -129,15 +138,18 @@ where
 impl<Message, P, B, T> Widget<Message, Renderer<B, T>> for Canvas<Message, T, P>
 where
     P: Program<Message, T>,
-    B: Backend,
+    B: Backend + backend::Text,
 {
     fn tag(&self) -> tree::Tag {
-        struct Tag<T>(T);
-        tree::Tag::of::<Tag<P::State>>()
+        tree::Tag::of::<State<P::State>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(P::State::default())
+        tree::State::new(State {
+            program: P::State::default(),
+            last_tick: None,
+            gestures: gesture::Recognizer::default(),
+        })
     }
 
     fn width(&self) -> Length {
@@ -171,6 +183,36 @@ where
     ) -> event::Status {
         let bounds = layout.bounds();
 
+        if let iced_native::Event::Window(window::Event::RedrawRequested(now)) =
+            event
+        {
+            let state = tree.state.downcast_mut::<State<P::State>>();
+
+            let tick = Tick {
+                now,
+                delta: state
+                    .last_tick
+                    .map(|last_tick| now.saturating_duration_since(last_tick))
+                    .unwrap_or_default(),
+            };
+
+            let (redraw_request, message) =
+                self.program.tick(&mut state.program, tick, bounds);
+
+            // Only frames requested by the program are consecutive
+            state.last_tick = redraw_request.map(|_| now);
+
+            if let Some(redraw_request) = redraw_request {
+                shell.request_redraw(redraw_request);
+            }
+
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+
+            return event::Status::Ignored;
+        }
+
         let canvas_event = match event {
             iced_native::Event::Mouse(mouse_event) => {
                 Some(Event::Mouse(mouse_event))
@@ -187,15 +229,40 @@ where
         let cursor = Cursor::from_window_position(cursor_position);
 
         if let Some(canvas_event) = canvas_event {
-            let state = tree.state.downcast_mut::<P::State>();
+            let state = tree.state.downcast_mut::<State<P::State>>();
 
-            let (event_status, message) =
-                self.program.update(state, canvas_event, bounds, cursor);
+            let (event_status, message) = self.program.update(
+                &mut state.program,
+                canvas_event,
+                bounds,
+                cursor,
+            );
 
             if let Some(message) = message {
                 shell.publish(message);
             }
 
+            if let Event::Touch(touch_event) = canvas_event {
+                let mut status = event_status;
+
+                for gesture in state.gestures.update(touch_event, bounds) {
+                    let (event_status, message) = self.program.update(
+                        &mut state.program,
+                        Event::Gesture(gesture),
+                        bounds,
+                        cursor,
+                    );
+
+                    if let Some(message) = message {
+                        shell.publish(message);
+                    }
+
+                    status = status.merge(event_status);
+                }
+
+                return status;
+            }
+
             return event_status;
         }
 
@@ -212,9 +279,10 @@ where
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
         let cursor = Cursor::from_window_position(cursor_position);
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
 
-        self.program.mouse_interaction(state, bounds, cursor)
+        self.program
+            .mouse_interaction(&state.program, bounds, cursor)
     }
 
     fn draw(
@@ -237,27 +305,34 @@ where
 
         let translation = Vector::new(bounds.x, bounds.y);
         let cursor = Cursor::from_window_position(cursor_position);
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
+
+        let primitives = self
+            .program
+            .draw(&state.program, renderer, theme, bounds, cursor)
+            .into_iter()
+            .map(Geometry::into_primitive)
+            .collect();
 
         renderer.with_translation(translation, |renderer| {
-            renderer.draw_primitive(Primitive::Group {
-                primitives: self
-                    .program
-                    .draw(state, theme, bounds, cursor)
-                    .into_iter()
-                    .map(Geometry::into_primitive)
-                    .collect(),
-            });
+            renderer.draw_primitive(Primitive::Group { primitives });
         });
     }
 }
 
+/// The internal state of a [`Canvas`].
+struct State<T> {
+    program: T,
+    last_tick: Option<Instant>,
+    gestures: gesture::Recognizer,
+}
+
 impl<'a, Message, P, B, T> From<Canvas<Message, T, P>>
     for Element<'a, Message, Renderer<B, T>>
 where
     Message: 'a,
     P: Program<Message, T> + 'a,
-    B: Backend,
+    B: Backend + backend::Text,
     T: 'a,
 {
     fn from(
@@ -1,7 +1,8 @@
 use crate::widget::canvas::{Frame, Geometry};
 use crate::Primitive;
 
-use iced_native::Size;
+use iced_native::{Rectangle, Size, Vector};
+use std::collections::BTreeMap;
 use std::{cell::RefCell, sync::Arc};
 
 enum State {
@@ -86,6 +87,138 @@ impl Cache {
     }
 }
 
+/// A cache that splits its [`Geometry`] in tiles, so parts of it can be
+/// invalidated without redrawing everything.
+///
+/// A [`TiledCache`] will only redraw the tiles that have been invalidated,
+/// unless the dimensions of its layer change or it is explicitly cleared.
+#[derive(Debug)]
+pub struct TiledCache {
+    tile_size: Size,
+    state: RefCell<Tiles>,
+}
+
+#[derive(Debug)]
+struct Tiles {
+    bounds: Size,
+    filled: BTreeMap<(u32, u32), Arc<Primitive>>,
+}
+
+impl TiledCache {
+    /// Creates a new empty [`TiledCache`] with tiles of the given size.
+    pub fn new(tile_size: Size) -> Self {
+        TiledCache {
+            tile_size: Size::new(
+                tile_size.width.max(1.0),
+                tile_size.height.max(1.0),
+            ),
+            state: RefCell::new(Tiles {
+                bounds: Size::ZERO,
+                filled: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Clears the [`TiledCache`], forcing a redraw of every tile the next
+    /// time it is used.
+    pub fn clear(&self) {
+        self.state.borrow_mut().filled.clear();
+    }
+
+    /// Invalidates the tiles of the [`TiledCache`] intersecting the given
+    /// region, forcing a redraw of them the next time it is used.
+    pub fn invalidate(&self, region: Rectangle) {
+        let mut state = self.state.borrow_mut();
+
+        for tile in tiles(region, self.tile_size) {
+            let _ = state.filled.remove(&tile);
+        }
+    }
+
+    /// Draws [`Geometry`] using the provided closure and stores it in the
+    /// [`TiledCache`].
+    ///
+    /// The closure is called once for every tile that needs to be redrawn,
+    /// with a [`Frame`] clipped to the region of the tile. This region is
+    /// provided so the closure can skip drawing anything outside of it.
+    ///
+    /// The [`Frame`] keeps the coordinate system of the whole layer.
+    pub fn draw(
+        &self,
+        bounds: Size,
+        draw_fn: impl Fn(&mut Frame, Rectangle),
+    ) -> Geometry {
+        let mut state = self.state.borrow_mut();
+
+        if state.bounds != bounds {
+            state.bounds = bounds;
+            state.filled.clear();
+        }
+
+        let layer = Rectangle::with_size(bounds);
+
+        for tile in tiles(layer, self.tile_size) {
+            if state.filled.contains_key(&tile) {
+                continue;
+            }
+
+            let region = match self.region(tile).intersection(&layer) {
+                Some(region) => region,
+                None => continue,
+            };
+
+            let mut frame = Frame::new(bounds);
+
+            frame.with_clip(region, |frame| {
+                frame.translate(Vector::new(-region.x, -region.y));
+
+                draw_fn(frame, region);
+            });
+
+            let _ = state
+                .filled
+                .insert(tile, Arc::new(frame.into_geometry().into_primitive()));
+        }
+
+        Geometry::from_primitive(Primitive::Group {
+            primitives: state
+                .filled
+                .values()
+                .map(|primitive| Primitive::Cached {
+                    cache: primitive.clone(),
+                })
+                .collect(),
+        })
+    }
+
+    fn region(&self, (column, row): (u32, u32)) -> Rectangle {
+        Rectangle {
+            x: column as f32 * self.tile_size.width,
+            y: row as f32 * self.tile_size.height,
+            width: self.tile_size.width,
+            height: self.tile_size.height,
+        }
+    }
+}
+
+/// Returns the tiles of the given size intersecting the region.
+fn tiles(
+    region: Rectangle,
+    tile_size: Size,
+) -> impl Iterator<Item = (u32, u32)> {
+    let range = |start: f32, length: f32, tile: f32| {
+        let first = (start.max(0.0) / tile).floor() as u32;
+        let last = ((start + length).max(0.0) / tile).ceil() as u32;
+
+        first..last.max(first)
+    };
+
+    let columns = range(region.x, region.width, tile_size.width);
+    let rows = range(region.y, region.height, tile_size.height);
+
+    rows.flat_map(move |row| columns.clone().map(move |column| (column, row)))
+}
+
 impl std::fmt::Debug for State {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,136 @@
+use crate::widget::canvas::{Cursor, Frame};
+
+use iced_native::{Point, Rectangle, Size, Vector};
+
+/// A viewport over the world coordinates of a [`Canvas`], which can be
+/// panned and zoomed.
+///
+/// A point in the world is mapped to the screen by adding the
+/// `translation` of the [`Camera`] and then multiplying it by its `scale`.
+///
+/// [`Canvas`]: crate::widget::Canvas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// The translation applied to the world before scaling it.
+    pub translation: Vector,
+    /// The amount of screen pixels per world unit.
+    pub scale: f32,
+    min_scale: f32,
+    max_scale: f32,
+}
+
+impl Camera {
+    /// The default minimum scale of a [`Camera`].
+    pub const MIN_SCALE: f32 = 0.1;
+
+    /// The default maximum scale of a [`Camera`].
+    pub const MAX_SCALE: f32 = 10.0;
+
+    /// Creates a new [`Camera`] showing the world as is.
+    pub fn new() -> Self {
+        Self {
+            translation: Vector::new(0.0, 0.0),
+            scale: 1.0,
+            min_scale: Self::MIN_SCALE,
+            max_scale: Self::MAX_SCALE,
+        }
+    }
+
+    /// Sets the range the scale of the [`Camera`] is clamped to when
+    /// zooming.
+    pub fn scale_limits(self, min: f32, max: f32) -> Self {
+        let min = min.max(f32::EPSILON);
+
+        Self {
+            min_scale: min,
+            max_scale: max.max(min),
+            scale: self.scale.clamp(min, max.max(min)),
+            ..self
+        }
+    }
+
+    /// Moves the [`Camera`] by the given amount of screen pixels.
+    pub fn pan(&mut self, delta: Vector) {
+        self.translation = self.translation + delta * (1.0 / self.scale);
+    }
+
+    /// Multiplies the scale of the [`Camera`] by the given factor, keeping
+    /// the world point under the `anchor` in the same screen position.
+    ///
+    /// The `anchor` is relative to the bounds of the [`Canvas`].
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn zoom(&mut self, factor: f32, anchor: Point) {
+        let world = self.world_position(anchor);
+
+        self.scale =
+            (self.scale * factor).clamp(self.min_scale, self.max_scale);
+        self.translation = Vector::new(
+            anchor.x / self.scale - world.x,
+            anchor.y / self.scale - world.y,
+        );
+    }
+
+    /// Converts a point relative to the bounds of the [`Canvas`] into world
+    /// coordinates.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn world_position(&self, point: Point) -> Point {
+        Point::new(
+            point.x / self.scale - self.translation.x,
+            point.y / self.scale - self.translation.y,
+        )
+    }
+
+    /// Converts a point in world coordinates into a point relative to the
+    /// bounds of the [`Canvas`].
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn screen_position(&self, point: Point) -> Point {
+        Point::new(
+            (point.x + self.translation.x) * self.scale,
+            (point.y + self.translation.y) * self.scale,
+        )
+    }
+
+    /// Returns the position of the [`Cursor`] in world coordinates, if it
+    /// is over the given bounds.
+    pub fn cursor_position(
+        &self,
+        cursor: Cursor,
+        bounds: Rectangle,
+    ) -> Option<Point> {
+        cursor
+            .position_in(&bounds)
+            .map(|position| self.world_position(position))
+    }
+
+    /// Returns the region of the world that is visible in a [`Canvas`] of
+    /// the given size.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn visible_region(&self, size: Size) -> Rectangle {
+        let top_left = self.world_position(Point::ORIGIN);
+
+        Rectangle::new(
+            top_left,
+            Size::new(size.width / self.scale, size.height / self.scale),
+        )
+    }
+
+    /// Applies the transform of the [`Camera`] to the [`Frame`], so the
+    /// next drawing operations use world coordinates.
+    ///
+    /// Combine it with [`Frame::with_save`] to restore the previous
+    /// transform afterwards.
+    pub fn apply(&self, frame: &mut Frame) {
+        frame.scale(self.scale);
+        frame.translate(self.translation);
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,10 @@
 //! Handle events of a canvas.
 use iced_native::keyboard;
 use iced_native::mouse;
+use iced_native::time::{Duration, Instant};
 use iced_native::touch;
 
+pub use super::gesture::Gesture;
 pub use iced_native::event::Status;
 
 /// A [`Canvas`] event.
@@ -18,4 +20,28 @@ pub enum Event {
 
     /// A keyboard event.
     Keyboard(keyboard::Event),
+
+    /// A gesture synthesized from two fingers touching the [`Canvas`].
+    ///
+    /// It is produced right after the [`Event::Touch`] that caused it.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    Gesture(Gesture),
+}
+
+/// The timing of a frame of a [`Canvas`], used to advance animations.
+///
+/// [`Canvas`]: crate::widget::Canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    /// The time the frame will be drawn at.
+    pub now: Instant,
+
+    /// The time elapsed since the previous frame.
+    ///
+    /// It is zero for the first frame after the [`Canvas`] stopped
+    /// requesting new frames.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub delta: Duration,
 }
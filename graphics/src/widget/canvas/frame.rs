@@ -1,7 +1,13 @@
+mod clip;
+
+use crate::alignment;
 use crate::gradient::Gradient;
 use crate::triangle;
-use crate::widget::canvas::{path, Fill, Geometry, Path, Stroke, Style, Text};
-use crate::Primitive;
+use crate::widget::canvas::text::{self, Measure, Metrics};
+use crate::widget::canvas::{
+    path, Fill, Geometry, Image, Path, Stroke, Style, Text,
+};
+use crate::{BlendMode, Primitive};
 
 use iced_native::{Point, Rectangle, Size, Vector};
 
@@ -132,11 +138,24 @@ impl Transform {
     }
 
     fn transform_gradient(&self, mut gradient: Gradient) -> Gradient {
-        let (start, end) = match &mut gradient {
-            Gradient::Linear(linear) => (&mut linear.start, &mut linear.end),
-        };
-        self.transform_point(start);
-        self.transform_point(end);
+        match &mut gradient {
+            Gradient::Linear(linear) => {
+                self.transform_point(&mut linear.start);
+                self.transform_point(&mut linear.end);
+            }
+            Gradient::Radial(radial) => {
+                self.transform_point(&mut radial.center);
+
+                if !self.is_identity {
+                    let scale = self
+                        .raw
+                        .transform_vector(lyon::math::Vector::new(1.0, 0.0))
+                        .length();
+
+                    radial.radius *= scale;
+                }
+            }
+        }
         gradient
     }
 }
@@ -267,6 +286,9 @@ impl Frame {
         options.start_cap = stroke.line_cap.into();
         options.end_cap = stroke.line_cap.into();
         options.line_join = stroke.line_join.into();
+        options.miter_limit = stroke
+            .miter_limit
+            .max(tessellation::StrokeOptions::MINIMUM_MITER_LIMIT);
 
         let path = if stroke.line_dash.segments.is_empty() {
             Cow::Borrowed(path)
@@ -334,9 +356,177 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            rotation: 0.0,
+        });
+    }
+
+    /// Draws the characters of the given [`Text`] along a [`Path`], rotating
+    /// each of them to follow its direction.
+    ///
+    /// The horizontal alignment of the [`Text`] places it at the start, the
+    /// middle, or the end of the [`Path`], while its vertical alignment
+    /// decides whether the characters stand on the [`Path`], are centered
+    /// on it, or hang from it. The position of the [`Text`] is ignored.
+    ///
+    /// Characters that do not fit in the [`Path`] are not drawn.
+    ///
+    /// Like [`Frame::fill_text`], the characters are positioned using the
+    /// current transform, but they are not scaled.
+    pub fn fill_text_on_path(
+        &mut self,
+        renderer: &dyn Measure,
+        text: impl Into<Text>,
+        path: &Path,
+    ) {
+        let text = text.into();
+
+        let path = if self.transforms.current.is_identity {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(path.transformed(&self.transforms.current.raw))
+        };
+
+        let advance = |content: &str| {
+            renderer
+                .measure(content, text.size, text.font, Size::INFINITY)
+                .width
+        };
+
+        // Trailing whitespace is only measured when followed by a character
+        let sentinel = advance("|");
+
+        let offsets: Vec<f32> = std::iter::once(0.0)
+            .chain(text.content.char_indices().map(|(index, character)| {
+                let end = index + character.len_utf8();
+
+                advance(&format!("{}|", &text.content[..end])) - sentinel
+            }))
+            .collect();
+
+        let width = offsets.last().copied().unwrap_or(0.0);
+        let length = path.length();
+
+        let start = match text.horizontal_alignment {
+            alignment::Horizontal::Left => 0.0,
+            alignment::Horizontal::Center => (length - width) / 2.0,
+            alignment::Horizontal::Right => length - width,
+        };
+
+        let glyphs: Vec<(char, f32)> = text
+            .content
+            .chars()
+            .zip(offsets.windows(2))
+            .map(|(character, offsets)| {
+                (character, start + (offsets[0] + offsets[1]) / 2.0)
+            })
+            .filter(|(_, center)| *center >= 0.0)
+            .collect();
+
+        let centers: Vec<f32> =
+            glyphs.iter().map(|(_, center)| *center).collect();
+
+        path.walk(&centers, |index, position, angle| {
+            let character = glyphs[index].0;
+
+            if character.is_whitespace() {
+                return;
+            }
+
+            self.primitives.push(Primitive::Text {
+                content: character.to_string(),
+                bounds: Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    width: f32::INFINITY,
+                    height: f32::INFINITY,
+                },
+                color: text.color,
+                size: text.size,
+                font: text.font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: text.vertical_alignment,
+                rotation: angle,
+            });
+        });
+    }
+
+    /// Draws the given [`Image`] on the [`Frame`], stretched to fill the
+    /// provided bounds.
+    ///
+    /// The current transform of the [`Frame`] is applied to the bounds of
+    /// the [`Image`]. Non-uniform scales are not supported, and will only
+    /// affect its size.
+    ///
+    /// Like text, images are rendered on top of the paths of the layer of a
+    /// [`Canvas`].
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn draw_image(&mut self, image: impl Into<Image>, bounds: Rectangle) {
+        let image = image.into();
+
+        let (bounds, rotation) = if self.transforms.current.is_identity {
+            (bounds, image.rotation)
+        } else {
+            let transform = &self.transforms.current.raw;
+
+            let center = transform.transform_point(lyon::math::Point::new(
+                bounds.center_x(),
+                bounds.center_y(),
+            ));
+            let horizontal = transform
+                .transform_vector(lyon::math::Vector::new(bounds.width, 0.0));
+            let vertical = transform
+                .transform_vector(lyon::math::Vector::new(0.0, bounds.height));
+
+            let width = horizontal.length();
+            let height = vertical.length();
+
+            (
+                Rectangle {
+                    x: center.x - width / 2.0,
+                    y: center.y - height / 2.0,
+                    width,
+                    height,
+                },
+                image.rotation + horizontal.y.atan2(horizontal.x),
+            )
+        };
+
+        self.primitives.push(Primitive::Image {
+            handle: image.handle,
+            bounds,
+            rotation,
+            flip: Default::default(),
+            filter: image.filter,
+            opacity: image.opacity,
         });
     }
 
+    /// Measures the given [`Text`] as it would be drawn by
+    /// [`fill_text`](Self::fill_text), using the text capabilities of the
+    /// renderer given to [`Program::draw`].
+    ///
+    /// The bounds of the returned [`Metrics`] are in the coordinate system of
+    /// the [`Text`], before any transform of the [`Frame`] is applied. They
+    /// can be used to center labels, lay out the axes of a chart or wrap text
+    /// manually.
+    ///
+    /// [`Program::draw`]: crate::widget::canvas::Program::draw
+    pub fn measure_text(&self, renderer: &dyn Measure, text: &Text) -> Metrics {
+        let size = renderer.measure(
+            &text.content,
+            text.size,
+            text.font,
+            Size::INFINITY,
+        );
+
+        Metrics {
+            size,
+            bounds: text::aligned_bounds(text, size),
+            ascent: renderer.ascent(text.size, text.font),
+        }
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -365,9 +555,14 @@ impl Frame {
 
         let primitives = frame.into_primitives();
 
-        let (text, meshes) = primitives
-            .into_iter()
-            .partition(|primitive| matches!(primitive, Primitive::Text { .. }));
+        // Meshes are already clipped to the size of the frame, but text and
+        // images need to be clipped explicitly
+        let (text, meshes) = primitives.into_iter().partition(|primitive| {
+            matches!(
+                primitive,
+                Primitive::Text { .. } | Primitive::Image { .. }
+            )
+        });
 
         let translation = Vector::new(region.x, region.y);
 
@@ -390,6 +585,60 @@ impl Frame {
         });
     }
 
+    /// Executes the given drawing operations, clipping any geometry that
+    /// falls outside of the given [`Path`]. Any transformations performed
+    /// are local to the provided closure.
+    ///
+    /// The [`Path`] is filled with the [`FillRule::NonZero`] rule to define
+    /// the clipping region, using the current transform of the [`Frame`].
+    ///
+    /// Text and images can only be clipped to the bounding box of the
+    /// [`Path`].
+    ///
+    /// [`FillRule::NonZero`]: crate::widget::canvas::FillRule::NonZero
+    pub fn with_clip_path(&mut self, path: &Path, f: impl FnOnce(&mut Frame)) {
+        let region = if self.transforms.current.is_identity {
+            clip::Region::new(path)
+        } else {
+            clip::Region::new(&path.transformed(&self.transforms.current.raw))
+        };
+
+        let mut frame = Frame::new(self.size);
+        frame.transforms.current = self.transforms.current;
+
+        f(&mut frame);
+
+        let primitives = frame
+            .into_primitives()
+            .into_iter()
+            .filter_map(|primitive| {
+                region.clip(primitive, Vector::new(0.0, 0.0))
+            })
+            .collect();
+
+        self.primitives.push(Primitive::Group { primitives });
+    }
+
+    /// Executes the given drawing operations, blending the resulting
+    /// geometry with what is drawn behind it using the given [`BlendMode`].
+    /// Any transformations performed are local to the provided closure.
+    ///
+    /// This method is useful to draw glow effects or highlights. Text and
+    /// images are not blended.
+    pub fn with_blend(&mut self, mode: BlendMode, f: impl FnOnce(&mut Frame)) {
+        let mut frame = Frame::new(self.size);
+        frame.transforms.current = self.transforms.current;
+
+        f(&mut frame);
+
+        self.primitives.push(Primitive::Blend {
+            mode,
+            content: Box::new(Primitive::Group {
+                primitives: frame.into_primitives(),
+            }),
+        });
+    }
+
     /// Applies a translation to the current transform of the [`Frame`].
     #[inline]
     pub fn translate(&mut self, translation: Vector) {
@@ -0,0 +1,253 @@
+//! Clip the primitives of a [`Frame`] with an arbitrary [`Path`].
+//!
+//! [`Frame`]: crate::widget::canvas::Frame
+use crate::triangle;
+use crate::widget::canvas::path::boolean::{self, Contour};
+use crate::widget::canvas::Path;
+use crate::Primitive;
+
+use iced_native::{Point, Rectangle, Vector};
+use lyon::tessellation;
+
+/// A clipping region produced by flattening a [`Path`].
+pub(super) struct Region {
+    contours: Vec<Contour>,
+    bounds: Rectangle,
+}
+
+impl Region {
+    pub(super) fn new(path: &Path) -> Self {
+        Self {
+            contours: path.contours(),
+            bounds: path.bounding_box(),
+        }
+    }
+
+    /// Clips the [`Primitive`], translated by the given offset.
+    ///
+    /// Meshes are clipped exactly, while the rest of primitives are clipped
+    /// to the bounds of the [`Region`].
+    pub(super) fn clip(
+        &self,
+        primitive: Primitive,
+        offset: Vector,
+    ) -> Option<Primitive> {
+        match primitive {
+            Primitive::Group { primitives } => Some(Primitive::Group {
+                primitives: primitives
+                    .into_iter()
+                    .filter_map(|primitive| self.clip(primitive, offset))
+                    .collect(),
+            }),
+            Primitive::Translate {
+                translation,
+                content,
+            } => Some(Primitive::Translate {
+                translation,
+                content: Box::new(self.clip(*content, offset + translation)?),
+            }),
+            Primitive::SolidMesh { buffers, size } => {
+                let buffers = self.clip_mesh(
+                    buffers,
+                    offset,
+                    |vertex: &triangle::ColoredVertex2D| vertex.position,
+                    |triangle, weights, position| {
+                        let mut color = [0.0; 4];
+
+                        for (vertex, weight) in triangle.iter().zip(weights) {
+                            for (channel, value) in
+                                color.iter_mut().zip(vertex.color)
+                            {
+                                *channel += value * weight;
+                            }
+                        }
+
+                        triangle::ColoredVertex2D { position, color }
+                    },
+                );
+
+                (!buffers.indices.is_empty())
+                    .then_some(Primitive::SolidMesh { buffers, size })
+            }
+            Primitive::GradientMesh {
+                buffers,
+                size,
+                gradient,
+            } => {
+                let buffers = self.clip_mesh(
+                    buffers,
+                    offset,
+                    |vertex: &triangle::Vertex2D| vertex.position,
+                    |_, _, position| triangle::Vertex2D { position },
+                );
+
+                (!buffers.indices.is_empty()).then_some(
+                    Primitive::GradientMesh {
+                        buffers,
+                        size,
+                        gradient,
+                    },
+                )
+            }
+            Primitive::Clip { bounds, content } => Some(Primitive::Clip {
+                bounds,
+                content: Box::new(self.clip(*content, offset)?),
+            }),
+            Primitive::Blend { mode, content } => Some(Primitive::Blend {
+                mode,
+                content: Box::new(self.clip(*content, offset)?),
+            }),
+            primitive => Some(Primitive::Clip {
+                bounds: Rectangle {
+                    x: self.bounds.x - offset.x,
+                    y: self.bounds.y - offset.y,
+                    ..self.bounds
+                },
+                content: Box::new(primitive),
+            }),
+        }
+    }
+
+    fn clip_mesh<T: Copy>(
+        &self,
+        mesh: triangle::Mesh2D<T>,
+        offset: Vector,
+        position: impl Fn(&T) -> [f32; 2],
+        interpolate: impl Fn(&[T; 3], [f32; 3], [f32; 2]) -> T,
+    ) -> triangle::Mesh2D<T> {
+        let mut clipped = tessellation::VertexBuffers::new();
+        let mut tessellator = tessellation::FillTessellator::new();
+
+        for indices in mesh.indices.chunks_exact(3) {
+            let vertices = [
+                mesh.vertices[indices[0] as usize],
+                mesh.vertices[indices[1] as usize],
+                mesh.vertices[indices[2] as usize],
+            ];
+
+            let points = vertices.map(|vertex| {
+                let [x, y] = position(&vertex);
+
+                Point::new(x + offset.x, y + offset.y)
+            });
+
+            match self.classify(&points) {
+                Coverage::Outside => {}
+                Coverage::Inside => {
+                    let base = clipped.vertices.len() as u32;
+
+                    clipped.vertices.extend(vertices);
+                    clipped.indices.extend([base, base + 1, base + 2]);
+                }
+                Coverage::Partial => {
+                    let pieces = boolean::apply(
+                        &[points.to_vec()],
+                        &self.contours,
+                        boolean::Operation::Intersection,
+                    );
+
+                    if pieces.is_empty() {
+                        continue;
+                    }
+
+                    let path = Path::new(|builder| {
+                        for piece in &pieces {
+                            builder.move_to(piece[0]);
+
+                            for point in &piece[1..] {
+                                builder.line_to(*point);
+                            }
+
+                            builder.close();
+                        }
+                    });
+
+                    let _ = tessellator.tessellate_path(
+                        path.raw(),
+                        &tessellation::FillOptions::default(),
+                        &mut tessellation::BuffersBuilder::new(
+                            &mut clipped,
+                            |vertex: tessellation::FillVertex<'_>| {
+                                let point = vertex.position();
+                                let point = Point::new(point.x, point.y);
+
+                                interpolate(
+                                    &vertices,
+                                    barycentric(&points, point),
+                                    [point.x - offset.x, point.y - offset.y],
+                                )
+                            },
+                        ),
+                    );
+                }
+            }
+        }
+
+        triangle::Mesh2D {
+            vertices: clipped.vertices,
+            indices: clipped.indices,
+        }
+    }
+
+    fn classify(&self, triangle: &[Point; 3]) -> Coverage {
+        let (min_x, max_x) = (
+            triangle[0].x.min(triangle[1].x).min(triangle[2].x),
+            triangle[0].x.max(triangle[1].x).max(triangle[2].x),
+        );
+        let (min_y, max_y) = (
+            triangle[0].y.min(triangle[1].y).min(triangle[2].y),
+            triangle[0].y.max(triangle[1].y).max(triangle[2].y),
+        );
+
+        if max_x < self.bounds.x
+            || min_x > self.bounds.x + self.bounds.width
+            || max_y < self.bounds.y
+            || min_y > self.bounds.y + self.bounds.height
+        {
+            return Coverage::Outside;
+        }
+
+        let corners_inside = triangle
+            .iter()
+            .all(|point| boolean::contains(&self.contours, *point));
+
+        // The boundary of the region can only cut a triangle with all its
+        // corners inside if some of its points lie within the triangle
+        let boundary_inside = self.contours.iter().flatten().any(|point| {
+            (min_x..=max_x).contains(&point.x)
+                && (min_y..=max_y).contains(&point.y)
+        });
+
+        if corners_inside && !boundary_inside {
+            Coverage::Inside
+        } else {
+            Coverage::Partial
+        }
+    }
+}
+
+enum Coverage {
+    Outside,
+    Inside,
+    Partial,
+}
+
+/// Computes the barycentric coordinates of a point in a triangle.
+fn barycentric(triangle: &[Point; 3], point: Point) -> [f32; 3] {
+    let [a, b, c] = *triangle;
+
+    let area = (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y);
+
+    if area.abs() <= f32::EPSILON {
+        return [1.0, 0.0, 0.0];
+    }
+
+    let u = ((b.x - point.x) * (c.y - point.y)
+        - (c.x - point.x) * (b.y - point.y))
+        / area;
+    let v = ((c.x - point.x) * (a.y - point.y)
+        - (a.x - point.x) * (c.y - point.y))
+        / area;
+
+    [u, v, 1.0 - u - v]
+}
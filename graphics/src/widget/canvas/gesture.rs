@@ -0,0 +1,118 @@
+use iced_native::touch::{self, Finger};
+use iced_native::{Point, Rectangle};
+
+/// A gesture performed with two fingers on a [`Canvas`].
+///
+/// [`Canvas`]: crate::widget::Canvas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// The fingers moved closer to or further from each other.
+    Pinch {
+        /// The point between the fingers.
+        center: Point,
+        /// The ratio between the current and the previous distance of the
+        /// fingers.
+        scale: f32,
+    },
+
+    /// The fingers rotated around each other.
+    Rotate {
+        /// The point between the fingers.
+        center: Point,
+        /// The angle of the rotation since the previous gesture, in radians.
+        ///
+        /// Positive angles rotate clockwise on the screen.
+        angle: f32,
+    },
+}
+
+/// Synthesizes gestures from the touch events of the fingers pressed on a
+/// [`Canvas`].
+///
+/// [`Canvas`]: crate::widget::Canvas
+#[derive(Debug, Default)]
+pub(super) struct Recognizer {
+    fingers: Vec<(Finger, Point)>,
+}
+
+impl Recognizer {
+    /// Processes a touch event, returning the gestures it produces.
+    pub(super) fn update(
+        &mut self,
+        event: touch::Event,
+        bounds: Rectangle,
+    ) -> Vec<Gesture> {
+        match event {
+            touch::Event::FingerPressed { id, position } => {
+                if self.fingers.len() < 2 && bounds.contains(position) {
+                    self.fingers.push((id, position));
+                }
+
+                Vec::new()
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let previous = self.fingers.clone();
+
+                let finger = match self
+                    .fingers
+                    .iter_mut()
+                    .find(|(finger, _)| *finger == id)
+                {
+                    Some(finger) => finger,
+                    None => return Vec::new(),
+                };
+
+                finger.1 = position;
+
+                match (previous.as_slice(), self.fingers.as_slice()) {
+                    ([(_, a), (_, b)], [(_, c), (_, d)]) => {
+                        gestures((*a, *b), (*c, *d))
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Computes the gestures produced by two fingers moving from the
+/// `previous` positions to the `current` ones.
+fn gestures(previous: (Point, Point), current: (Point, Point)) -> Vec<Gesture> {
+    let before = (previous.1.x - previous.0.x, previous.1.y - previous.0.y);
+    let after = (current.1.x - current.0.x, current.1.y - current.0.y);
+
+    let distance_before = before.0.hypot(before.1);
+    let distance_after = after.0.hypot(after.1);
+
+    // Fingers on the same spot have no direction
+    if distance_before <= f32::EPSILON || distance_after <= f32::EPSILON {
+        return Vec::new();
+    }
+
+    let center = Point::new(
+        (current.0.x + current.1.x) / 2.0,
+        (current.0.y + current.1.y) / 2.0,
+    );
+
+    let scale = distance_after / distance_before;
+    let angle = (before.0 * after.1 - before.1 * after.0)
+        .atan2(before.0 * after.0 + before.1 * after.1);
+
+    let mut gestures = Vec::new();
+
+    if scale != 1.0 {
+        gestures.push(Gesture::Pinch { center, scale });
+    }
+
+    if angle != 0.0 {
+        gestures.push(Gesture::Rotate { center, angle });
+    }
+
+    gestures
+}
@@ -0,0 +1,53 @@
+use iced_native::image;
+
+/// A raster image that can be drawn on a [`Frame`].
+///
+/// [`Frame`]: crate::widget::canvas::Frame
+#[derive(Debug, Clone)]
+pub struct Image {
+    /// The handle of the image.
+    pub handle: image::Handle,
+    /// The rotation of the image around its center, in radians.
+    pub rotation: f32,
+    /// The opacity of the image, in [0.0, 1.0].
+    pub opacity: f32,
+    /// The filter applied to the image.
+    pub filter: image::Filter,
+}
+
+impl Image {
+    /// Creates a new [`Image`] with the given handle, neither rotated nor
+    /// translucent.
+    pub fn new(handle: impl Into<image::Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            rotation: 0.0,
+            opacity: 1.0,
+            filter: image::Filter::NONE,
+        }
+    }
+
+    /// Sets the rotation of the [`Image`] around its center, in radians.
+    pub fn rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], in [0.0, 1.0].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`Filter`](image::Filter) applied to the [`Image`].
+    pub fn filter(mut self, filter: image::Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+impl From<image::Handle> for Image {
+    fn from(handle: image::Handle) -> Self {
+        Self::new(handle)
+    }
+}
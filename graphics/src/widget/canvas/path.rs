@@ -1,16 +1,20 @@
 //! Build different kinds of 2D shapes.
 pub mod arc;
 
+pub(crate) mod boolean;
 mod builder;
+mod svg;
 
 #[doc(no_inline)]
 pub use arc::Arc;
 pub use builder::Builder;
+pub use svg::ParseError;
 
 use crate::widget::canvas::LineDash;
 
-use iced_native::{Point, Size};
-use lyon::algorithms::walk::{walk_along_path, RepeatedPattern, WalkerEvent};
+use iced_native::{Point, Rectangle, Size};
+use lyon::algorithms::hit_test::hit_test_path;
+use lyon::algorithms::walk::{walk_along_path, Pattern, WalkerEvent};
 use lyon::path::iterator::PathIterator;
 
 /// An immutable set of points that may or may not be connected.
@@ -55,6 +59,198 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Creates a new [`Path`] from the data of an SVG path, like the `d`
+    /// attribute of a `<path>` element.
+    pub fn from_svg(data: &str) -> Result<Self, ParseError> {
+        let segments = svg::parse(data)?;
+
+        Ok(Self::new(|builder| {
+            for segment in segments {
+                match segment {
+                    svg::Segment::MoveTo(to) => builder.move_to(to),
+                    svg::Segment::LineTo(to) => builder.line_to(to),
+                    svg::Segment::Quadratic { control, to } => {
+                        builder.quadratic_curve_to(control, to)
+                    }
+                    svg::Segment::Cubic {
+                        control_a,
+                        control_b,
+                        to,
+                    } => builder.bezier_curve_to(control_a, control_b, to),
+                    svg::Segment::Arc {
+                        radii,
+                        rotation,
+                        large_arc,
+                        sweep,
+                        to,
+                    } => builder
+                        .svg_arc_to(radii, rotation, large_arc, sweep, to),
+                    svg::Segment::Close => builder.close(),
+                }
+            }
+        }))
+    }
+
+    /// Returns true if the given point lies inside the [`Path`] when it is
+    /// filled with the [`FillRule::NonZero`] rule.
+    ///
+    /// [`FillRule::NonZero`]: crate::widget::canvas::FillRule::NonZero
+    pub fn contains(&self, point: Point) -> bool {
+        hit_test_path(
+            &lyon::math::Point::new(point.x, point.y),
+            self.raw.iter(),
+            lyon::path::FillRule::NonZero,
+            TOLERANCE,
+        )
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the [`Path`].
+    pub fn bounding_box(&self) -> Rectangle {
+        let bounds = lyon::algorithms::aabb::bounding_box(self.raw.iter());
+
+        // Empty paths have an inverted bounding box
+        if bounds.min.x > bounds.max.x {
+            return Rectangle::new(Point::ORIGIN, Size::ZERO);
+        }
+
+        Rectangle::new(
+            Point::new(bounds.min.x, bounds.min.y),
+            Size::new(bounds.width(), bounds.height()),
+        )
+    }
+
+    /// Returns the length of the [`Path`], with its curves flattened.
+    pub fn length(&self) -> f32 {
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .map(|event| match event {
+                lyon::path::Event::Line { from, to } => (to - from).length(),
+                lyon::path::Event::End {
+                    last,
+                    first,
+                    close: true,
+                } => (first - last).length(),
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Returns a new [`Path`] covering both this [`Path`] and the `other` one.
+    ///
+    /// Curves are flattened and both paths are considered closed and filled
+    /// with the [`FillRule::NonZero`] rule.
+    ///
+    /// [`FillRule::NonZero`]: crate::widget::canvas::FillRule::NonZero
+    pub fn union(&self, other: &Path) -> Path {
+        self.boolean(other, boolean::Operation::Union)
+    }
+
+    /// Returns a new [`Path`] covering the area shared by this [`Path`] and
+    /// the `other` one.
+    ///
+    /// See [`Path::union`] for how the paths are interpreted.
+    pub fn intersection(&self, other: &Path) -> Path {
+        self.boolean(other, boolean::Operation::Intersection)
+    }
+
+    /// Returns a new [`Path`] covering the area of this [`Path`] that is not
+    /// covered by the `other` one.
+    ///
+    /// See [`Path::union`] for how the paths are interpreted.
+    pub fn difference(&self, other: &Path) -> Path {
+        self.boolean(other, boolean::Operation::Difference)
+    }
+
+    fn boolean(&self, other: &Path, operation: boolean::Operation) -> Path {
+        let contours =
+            boolean::apply(&self.contours(), &other.contours(), operation);
+
+        Path::new(|builder| {
+            for contour in contours {
+                builder.move_to(contour[0]);
+
+                for point in &contour[1..] {
+                    builder.line_to(*point);
+                }
+
+                builder.close();
+            }
+        })
+    }
+
+    /// Flattens the [`Path`] into closed polygons.
+    pub(crate) fn contours(&self) -> Vec<boolean::Contour> {
+        let mut contours = Vec::new();
+        let mut contour = Vec::new();
+
+        for event in self.raw.iter().flattened(TOLERANCE) {
+            match event {
+                lyon::path::Event::Begin { at } => {
+                    contour = vec![Point::new(at.x, at.y)];
+                }
+                lyon::path::Event::Line { to, .. } => {
+                    contour.push(Point::new(to.x, to.y));
+                }
+                lyon::path::Event::End { .. } => {
+                    if contour.first() == contour.last() {
+                        let _ = contour.pop();
+                    }
+
+                    if contour.len() >= 3 {
+                        contours.push(std::mem::take(&mut contour));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        contours
+    }
+
+    /// Calls the given closure with the index, the position, and the angle
+    /// of the direction of the [`Path`] at each of the given distances from
+    /// its start.
+    ///
+    /// The distances must be non-negative and sorted in increasing order.
+    /// Distances beyond the end of the [`Path`] are skipped.
+    pub(crate) fn walk(
+        &self,
+        distances: &[f32],
+        mut f: impl FnMut(usize, Point, f32),
+    ) {
+        if distances.is_empty() {
+            return;
+        }
+
+        let intervals: Vec<f32> =
+            distances.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+        let mut index = 0;
+
+        walk_along_path(
+            self.raw.iter().flattened(TOLERANCE),
+            distances[0],
+            TOLERANCE,
+            &mut DashPattern {
+                callback: |event: WalkerEvent<'_>| {
+                    f(
+                        index,
+                        Point::new(event.position.x, event.position.y),
+                        event.tangent.y.atan2(event.tangent.x),
+                    );
+
+                    index += 1;
+
+                    index < distances.len()
+                },
+                first: None,
+                index: 0,
+                intervals: &intervals,
+            },
+        );
+    }
+
     #[inline]
     pub(crate) fn raw(&self) -> &lyon::path::Path {
         &self.raw
@@ -71,18 +267,29 @@ impl Path {
     }
 }
 
+/// The maximum distance between a curve and its flattened approximation.
+const TOLERANCE: f32 = 0.01;
+
 pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
     Path::new(|builder| {
         let segments_odd = (line_dash.segments.len() % 2 == 1)
             .then(|| [line_dash.segments, line_dash.segments].concat());
 
+        let intervals = segments_odd.as_deref().unwrap_or(line_dash.segments);
+
+        let (index, remaining) =
+            dash_phase(intervals, line_dash.offset, line_dash.phase);
+
+        // The stroke may start in the middle of a dash or of a gap
+        let starts_with_dash = (index - line_dash.offset) % 2 == 0;
+
         let mut draw_line = false;
 
         walk_along_path(
-            path.raw().iter().flattened(0.01),
-            0.0,
+            path.raw().iter().flattened(TOLERANCE),
+            if starts_with_dash { 0.0 } else { remaining },
             lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
-            &mut RepeatedPattern {
+            &mut DashPattern {
                 callback: |event: WalkerEvent<'_>| {
                     let point = Point {
                         x: event.position.x,
@@ -99,11 +306,130 @@ pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
 
                     true
                 },
-                index: line_dash.offset,
-                intervals: segments_odd
-                    .as_deref()
-                    .unwrap_or(line_dash.segments),
+                first: starts_with_dash.then_some(remaining),
+                index: index + 1,
+                intervals,
             },
         );
     })
 }
+
+/// Finds the interval of the dash pattern where a stroke with the given
+/// `phase` starts, and the length left of it.
+fn dash_phase(intervals: &[f32], offset: usize, phase: f32) -> (usize, f32) {
+    let total: f32 = intervals.iter().sum();
+
+    if !(total.is_finite() && total > 0.0 && phase.is_finite()) {
+        return (offset, intervals[offset % intervals.len()]);
+    }
+
+    let mut phase = phase.rem_euclid(total);
+    let mut index = offset;
+
+    loop {
+        let interval = intervals[index % intervals.len()];
+
+        if phase < interval {
+            return (index, interval - phase);
+        }
+
+        phase -= interval;
+        index += 1;
+    }
+}
+
+/// A dash pattern whose first interval may be shortened by its phase.
+struct DashPattern<'a, F> {
+    callback: F,
+    first: Option<f32>,
+    index: usize,
+    intervals: &'a [f32],
+}
+
+impl<'a, F> Pattern for DashPattern<'a, F>
+where
+    F: FnMut(WalkerEvent<'_>) -> bool,
+{
+    fn next(&mut self, event: WalkerEvent<'_>) -> Option<f32> {
+        if !(self.callback)(event) {
+            return None;
+        }
+
+        Some(self.first.take().unwrap_or_else(|| {
+            let interval = self.intervals[self.index % self.intervals.len()];
+            self.index += 1;
+
+            interval
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_path_has_an_empty_bounding_box() {
+        let path = Path::new(|_| {});
+
+        assert_eq!(
+            path.bounding_box(),
+            Rectangle::new(Point::ORIGIN, Size::ZERO)
+        );
+    }
+
+    #[test]
+    fn bounding_box_of_a_rectangle() {
+        let path = Path::rectangle(Point::new(1.0, 2.0), Size::new(3.0, 4.0));
+
+        assert_eq!(
+            path.bounding_box(),
+            Rectangle::new(Point::new(1.0, 2.0), Size::new(3.0, 4.0))
+        );
+    }
+
+    #[test]
+    fn contains_points_inside_the_path() {
+        let path = Path::rectangle(Point::ORIGIN, Size::new(2.0, 2.0));
+
+        assert!(path.contains(Point::new(1.0, 1.0)));
+        assert!(!path.contains(Point::new(3.0, 1.0)));
+        assert!(!Path::new(|_| {}).contains(Point::ORIGIN));
+    }
+
+    #[test]
+    fn from_svg() {
+        let path = Path::from_svg("M1 2h3v4H1z").unwrap();
+
+        assert_eq!(
+            path.bounding_box(),
+            Rectangle::new(Point::new(1.0, 2.0), Size::new(3.0, 4.0))
+        );
+        assert!(path.contains(Point::new(2.0, 4.0)));
+        assert_eq!(path.length(), 14.0);
+
+        assert_eq!(
+            Path::from_svg("h1").map(|_| ()),
+            Err(ParseError::MissingMoveTo)
+        );
+    }
+
+    #[test]
+    fn boolean_operations_keep_holes() {
+        let outer = Path::rectangle(Point::ORIGIN, Size::new(4.0, 4.0));
+        let inner = Path::rectangle(Point::new(1.0, 1.0), Size::new(2.0, 2.0));
+
+        let frame = outer.difference(&inner);
+
+        assert!(frame.contains(Point::new(0.5, 0.5)));
+        assert!(!frame.contains(Point::new(2.0, 2.0)));
+        assert_eq!(frame.bounding_box(), outer.bounding_box());
+
+        let disjoint = Path::rectangle(Point::new(10.0, 10.0), Size::UNIT);
+
+        assert_eq!(
+            outer.intersection(&disjoint).bounding_box(),
+            Rectangle::new(Point::ORIGIN, Size::ZERO)
+        );
+    }
+}
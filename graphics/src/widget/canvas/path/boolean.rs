@@ -0,0 +1,508 @@
+//! Compute boolean operations between the flattened contours of two paths.
+use iced_native::Point;
+
+use std::collections::{HashMap, HashSet};
+
+/// A closed polygon.
+pub(crate) type Contour = Vec<Point>;
+
+/// A boolean operation between two sets of contours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// The distance used to decide on which side of a shared edge the filled
+/// region of a path lies.
+const SIDE_EPSILON: f32 = 1e-3;
+
+/// Applies the [`Operation`] to the contours `a` and `b`, which are filled
+/// using the non-zero rule.
+///
+/// The resulting contours do not overlap each other; outer contours have a
+/// positive signed area and holes a negative one.
+pub(crate) fn apply(
+    a: &[Contour],
+    b: &[Contour],
+    operation: Operation,
+) -> Vec<Contour> {
+    let mut a_segments = segments(a);
+    let mut b_segments = segments(b);
+
+    split(&mut a_segments, &mut b_segments);
+
+    let a_pieces = pieces(&a_segments);
+    let b_pieces = pieces(&b_segments);
+
+    let shared: HashSet<_> = b_pieces
+        .iter()
+        .map(|(from, to)| edge_key(*from, *to))
+        .collect();
+
+    let mut edges = Vec::new();
+    let mut coincident = HashSet::new();
+
+    for (from, to) in a_pieces {
+        let key = edge_key(from, to);
+
+        let keep = if shared.contains(&key) {
+            let _ = coincident.insert(key);
+
+            // Both regions lie on the same side of a shared edge when
+            // they overlap next to it.
+            let side = side_point(from, to);
+            let same_side = contains(a, side) == contains(b, side);
+
+            match operation {
+                Operation::Union | Operation::Intersection => same_side,
+                Operation::Difference => !same_side,
+            }
+        } else {
+            let inside = contains(b, midpoint(from, to));
+
+            match operation {
+                Operation::Union | Operation::Difference => !inside,
+                Operation::Intersection => inside,
+            }
+        };
+
+        if keep {
+            edges.push((from, to));
+        }
+    }
+
+    for (from, to) in b_pieces {
+        if coincident.contains(&edge_key(from, to)) {
+            continue;
+        }
+
+        let inside = contains(a, midpoint(from, to));
+
+        let keep = match operation {
+            Operation::Union => !inside,
+            Operation::Intersection | Operation::Difference => inside,
+        };
+
+        if keep {
+            edges.push((from, to));
+        }
+    }
+
+    orient(stitch(&edges))
+}
+
+/// Returns whether the given point is inside the contours, using the
+/// non-zero rule.
+pub(crate) fn contains(contours: &[Contour], point: Point) -> bool {
+    let mut winding = 0;
+
+    for contour in contours {
+        for (from, to) in edges(contour) {
+            if from.y <= point.y {
+                if to.y > point.y && cross(from, to, point) > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && cross(from, to, point) < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding != 0
+}
+
+type Key = (u32, u32);
+
+fn key(point: Point) -> Key {
+    // Normalize negative zeros, so equal points share the same key
+    ((point.x + 0.0).to_bits(), (point.y + 0.0).to_bits())
+}
+
+fn edge_key(from: Point, to: Point) -> (Key, Key) {
+    let (from, to) = (key(from), key(to));
+
+    if from <= to {
+        (from, to)
+    } else {
+        (to, from)
+    }
+}
+
+/// A segment of a contour, with the points where it needs to be split.
+struct Segment {
+    from: Point,
+    to: Point,
+    splits: Vec<(f32, Point)>,
+}
+
+fn edges(contour: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .map(|(from, to)| (*from, *to))
+}
+
+fn segments(contours: &[Contour]) -> Vec<Segment> {
+    contours
+        .iter()
+        .flat_map(|contour| edges(contour))
+        .filter(|(from, to)| from != to)
+        .map(|(from, to)| Segment {
+            from,
+            to,
+            splits: Vec::new(),
+        })
+        .collect()
+}
+
+/// Records the intersections between every segment of `a` and `b`.
+fn split(a: &mut [Segment], b: &mut [Segment]) {
+    for p in a.iter_mut() {
+        for q in b.iter_mut() {
+            if p.from.x.max(p.to.x) < q.from.x.min(q.to.x)
+                || q.from.x.max(q.to.x) < p.from.x.min(p.to.x)
+                || p.from.y.max(p.to.y) < q.from.y.min(q.to.y)
+                || q.from.y.max(q.to.y) < p.from.y.min(p.to.y)
+            {
+                continue;
+            }
+
+            intersect(p, q);
+        }
+    }
+}
+
+fn intersect(p: &mut Segment, q: &mut Segment) {
+    const EPSILON: f32 = 1e-6;
+
+    let r = (p.to.x - p.from.x, p.to.y - p.from.y);
+    let s = (q.to.x - q.from.x, q.to.y - q.from.y);
+    let r_length = r.0.hypot(r.1);
+    let s_length = s.0.hypot(s.1);
+
+    let denominator = r.0 * s.1 - r.1 * s.0;
+    let offset = (q.from.x - p.from.x, q.from.y - p.from.y);
+
+    if denominator.abs() <= EPSILON * r_length * s_length {
+        // Parallel segments only intersect when they are collinear
+        let distance = (offset.0 * r.1 - offset.1 * r.0).abs() / r_length;
+
+        if distance > 1e-4 {
+            return;
+        }
+
+        for point in [q.from, q.to] {
+            add_split(p, point);
+        }
+
+        for point in [p.from, p.to] {
+            add_split(q, point);
+        }
+
+        return;
+    }
+
+    let t = (offset.0 * s.1 - offset.1 * s.0) / denominator;
+    let u = (offset.0 * r.1 - offset.1 * r.0) / denominator;
+
+    if !(-EPSILON..=1.0 + EPSILON).contains(&t)
+        || !(-EPSILON..=1.0 + EPSILON).contains(&u)
+    {
+        return;
+    }
+
+    // Reuse existing endpoints, so split pieces share exact coordinates
+    let point = if t <= EPSILON {
+        p.from
+    } else if t >= 1.0 - EPSILON {
+        p.to
+    } else if u <= EPSILON {
+        q.from
+    } else if u >= 1.0 - EPSILON {
+        q.to
+    } else {
+        Point::new(p.from.x + r.0 * t, p.from.y + r.1 * t)
+    };
+
+    add_split(p, point);
+    add_split(q, point);
+}
+
+/// Splits the [`Segment`] at the given point, if it lies strictly inside.
+fn add_split(segment: &mut Segment, point: Point) {
+    if point == segment.from || point == segment.to {
+        return;
+    }
+
+    let direction =
+        (segment.to.x - segment.from.x, segment.to.y - segment.from.y);
+    let length_squared = direction.0 * direction.0 + direction.1 * direction.1;
+
+    let t = ((point.x - segment.from.x) * direction.0
+        + (point.y - segment.from.y) * direction.1)
+        / length_squared;
+
+    if t > 0.0 && t < 1.0 {
+        segment.splits.push((t, point));
+    }
+}
+
+fn pieces(segments: &[Segment]) -> Vec<(Point, Point)> {
+    let mut pieces = Vec::new();
+
+    for segment in segments {
+        let mut splits = segment.splits.clone();
+        splits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut from = segment.from;
+
+        for to in splits
+            .into_iter()
+            .map(|(_, point)| point)
+            .chain(Some(segment.to))
+        {
+            if to != from {
+                pieces.push((from, to));
+                from = to;
+            }
+        }
+    }
+
+    pieces
+}
+
+/// Joins the edges sharing endpoints into closed contours.
+fn stitch(edges: &[(Point, Point)]) -> Vec<Contour> {
+    let mut endpoints: HashMap<Key, Vec<usize>> = HashMap::new();
+
+    for (index, (from, to)) in edges.iter().enumerate() {
+        endpoints.entry(key(*from)).or_default().push(index);
+        endpoints.entry(key(*to)).or_default().push(index);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut contours = Vec::new();
+
+    for (index, (from, to)) in edges.iter().enumerate() {
+        if used[index] {
+            continue;
+        }
+
+        used[index] = true;
+
+        let mut contour = vec![*from, *to];
+        let mut current = *to;
+
+        while let Some(next) = endpoints
+            .get(&key(current))
+            .and_then(|indices| indices.iter().find(|index| !used[**index]))
+        {
+            used[*next] = true;
+
+            let (a, b) = edges[*next];
+            current = if key(a) == key(current) { b } else { a };
+
+            if key(current) == key(*from) {
+                break;
+            }
+
+            contour.push(current);
+        }
+
+        if contour.len() >= 3 {
+            contours.push(contour);
+        }
+    }
+
+    contours
+}
+
+/// Orients outer contours positively and holes negatively, so the result is
+/// the same when filled with any fill rule.
+fn orient(mut contours: Vec<Contour>) -> Vec<Contour> {
+    let depths: Vec<usize> = contours
+        .iter()
+        .enumerate()
+        .map(|(index, contour)| {
+            let point = midpoint(contour[0], contour[1]);
+
+            contours
+                .iter()
+                .enumerate()
+                .filter(|(other, candidate)| {
+                    *other != index && crosses_odd(candidate, point)
+                })
+                .count()
+        })
+        .collect();
+
+    for (contour, depth) in contours.iter_mut().zip(depths) {
+        let is_hole = depth % 2 == 1;
+
+        if (area(contour) < 0.0) != is_hole {
+            contour.reverse();
+        }
+    }
+
+    contours
+}
+
+fn crosses_odd(contour: &[Point], point: Point) -> bool {
+    edges(contour)
+        .filter(|(from, to)| {
+            (from.y > point.y) != (to.y > point.y)
+                && point.x
+                    < from.x
+                        + (point.y - from.y) * (to.x - from.x) / (to.y - from.y)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+fn area(contour: &[Point]) -> f32 {
+    edges(contour)
+        .map(|(from, to)| from.x * to.y - to.x * from.y)
+        .sum::<f32>()
+        / 2.0
+}
+
+fn cross(from: Point, to: Point, point: Point) -> f32 {
+    (to.x - from.x) * (point.y - from.y) - (point.x - from.x) * (to.y - from.y)
+}
+
+fn midpoint(from: Point, to: Point) -> Point {
+    Point::new((from.x + to.x) / 2.0, (from.y + to.y) / 2.0)
+}
+
+/// Returns a point slightly to one side of the middle of an edge.
+fn side_point(from: Point, to: Point) -> Point {
+    let middle = midpoint(from, to);
+    let (x, y) = (to.x - from.x, to.y - from.y);
+    let length = x.hypot(y);
+
+    Point::new(
+        middle.x - y / length * SIDE_EPSILON,
+        middle.y + x / length * SIDE_EPSILON,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> Contour {
+        vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+        ]
+    }
+
+    fn hole(x: f32, y: f32, size: f32) -> Contour {
+        let mut contour = square(x, y, size);
+        contour.reverse();
+        contour
+    }
+
+    fn total_area(contours: &[Contour]) -> f32 {
+        contours.iter().map(|contour| area(contour)).sum()
+    }
+
+    fn assert_area(contours: &[Contour], expected: f32) {
+        let actual = total_area(contours);
+
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected an area of {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let a = [square(0.0, 0.0, 2.0)];
+        let b = [square(1.0, 1.0, 2.0)];
+
+        assert_area(&apply(&a, &b, Operation::Union), 7.0);
+        assert_area(&apply(&a, &b, Operation::Intersection), 1.0);
+        assert_area(&apply(&a, &b, Operation::Difference), 3.0);
+        assert_area(&apply(&b, &a, Operation::Difference), 3.0);
+    }
+
+    #[test]
+    fn shapes_with_holes() {
+        let frame = [square(0.0, 0.0, 4.0), hole(1.0, 1.0, 2.0)];
+        assert_area(&frame, 12.0);
+
+        let inside_hole = [square(1.5, 1.5, 1.0)];
+
+        let union = apply(&frame, &inside_hole, Operation::Union);
+        assert_area(&union, 13.0);
+        assert!(!contains(&union, Point::new(1.2, 1.2)));
+        assert!(contains(&union, Point::new(2.0, 2.0)));
+
+        assert!(apply(&frame, &inside_hole, Operation::Intersection).is_empty());
+
+        let corner = [square(0.0, 0.0, 2.0)];
+
+        assert_area(&apply(&frame, &corner, Operation::Intersection), 3.0);
+        assert_area(&apply(&frame, &corner, Operation::Difference), 9.0);
+    }
+
+    #[test]
+    fn squares_sharing_an_edge() {
+        let a = [square(0.0, 0.0, 1.0)];
+        let b = [square(1.0, 0.0, 1.0)];
+
+        let union = apply(&a, &b, Operation::Union);
+        assert_eq!(union.len(), 1);
+        assert_area(&union, 2.0);
+
+        assert_area(&apply(&a, &b, Operation::Intersection), 0.0);
+        assert_area(&apply(&a, &b, Operation::Difference), 1.0);
+    }
+
+    #[test]
+    fn squares_touching_at_a_corner() {
+        let a = [square(0.0, 0.0, 1.0)];
+        let b = [square(1.0, 1.0, 1.0)];
+
+        assert_area(&apply(&a, &b, Operation::Union), 2.0);
+        assert_area(&apply(&a, &b, Operation::Intersection), 0.0);
+        assert_area(&apply(&a, &b, Operation::Difference), 1.0);
+    }
+
+    #[test]
+    fn identical_squares() {
+        let a = [square(0.0, 0.0, 1.0)];
+
+        assert_area(&apply(&a, &a, Operation::Union), 1.0);
+        assert_area(&apply(&a, &a, Operation::Intersection), 1.0);
+        assert_area(&apply(&a, &a, Operation::Difference), 0.0);
+    }
+
+    #[test]
+    fn disjoint_squares() {
+        let a = [square(0.0, 0.0, 1.0)];
+        let b = [square(5.0, 5.0, 2.0)];
+
+        let union = apply(&a, &b, Operation::Union);
+        assert_eq!(union.len(), 2);
+        assert_area(&union, 5.0);
+
+        assert!(apply(&a, &b, Operation::Intersection).is_empty());
+        assert_area(&apply(&a, &b, Operation::Difference), 1.0);
+    }
+
+    #[test]
+    fn contains_uses_the_non_zero_rule() {
+        let overlapping = [square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)];
+
+        assert!(contains(&overlapping, Point::new(1.5, 1.5)));
+        assert!(contains(&overlapping, Point::new(0.5, 0.5)));
+        assert!(!contains(&overlapping, Point::new(2.5, 0.5)));
+    }
+}
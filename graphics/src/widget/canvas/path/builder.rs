@@ -1,6 +1,6 @@
 use crate::widget::canvas::path::{arc, Arc, Path};
 
-use iced_native::{Point, Size};
+use iced_native::{Point, Size, Vector};
 use lyon::path::builder::SvgPathBuilder;
 
 /// A [`Path`] builder.
@@ -100,6 +100,26 @@ impl Builder {
         );
     }
 
+    /// Adds an elliptical arc to the [`Path`] from the current position to
+    /// `to`, as described by the arc command of SVG paths.
+    pub(super) fn svg_arc_to(
+        &mut self,
+        radii: Vector,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    ) {
+        use lyon::{math, path};
+
+        self.raw.arc_to(
+            math::Vector::new(radii.x, radii.y),
+            math::Angle::radians(rotation),
+            path::ArcFlags { large_arc, sweep },
+            math::Point::new(to.x, to.y),
+        );
+    }
+
     /// Adds an ellipse to the [`Path`] using a clockwise direction.
     pub fn ellipse(&mut self, arc: arc::Elliptical) {
         use lyon::{geom, math};
@@ -0,0 +1,490 @@
+//! Parse the data of SVG paths.
+use iced_native::{Point, Vector};
+
+/// An error produced when parsing the data of an SVG path.
+#[derive(Debug, Clone, Copy, PartialEq, thiserror::Error)]
+pub enum ParseError {
+    /// The data does not start with a move command.
+    #[error("path data must start with a move command")]
+    MissingMoveTo,
+
+    /// An unknown character was found.
+    #[error("unexpected character {character:?} at {position}")]
+    UnexpectedCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The byte offset of the character in the data.
+        position: usize,
+    },
+
+    /// A command is missing some of its arguments.
+    #[error("expected a number at {0}")]
+    ExpectedNumber(usize),
+
+    /// An arc command has an invalid flag.
+    #[error("expected an arc flag at {0}")]
+    ExpectedFlag(usize),
+}
+
+/// A segment of a path, in absolute coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    Quadratic {
+        control: Point,
+        to: Point,
+    },
+    Cubic {
+        control_a: Point,
+        control_b: Point,
+        to: Point,
+    },
+    Arc {
+        radii: Vector,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    },
+    Close,
+}
+
+/// Parses the data of an SVG path into absolute [`Segment`]s.
+pub(super) fn parse(data: &str) -> Result<Vec<Segment>, ParseError> {
+    let mut parser = Parser {
+        data: data.as_bytes(),
+        position: 0,
+    };
+
+    let mut segments = Vec::new();
+    let mut state = State::default();
+    let mut command = None;
+
+    loop {
+        parser.skip_separators();
+
+        let next = match parser.peek() {
+            Some(next) => next,
+            None => break,
+        };
+
+        let current = if next.is_ascii_alphabetic() {
+            parser.position += 1;
+
+            next
+        } else if !matches!(next, b'0'..=b'9' | b'.' | b'+' | b'-') {
+            return Err(parser.unexpected());
+        } else {
+            // Repeated arguments reuse the previous command, but repeated
+            // move commands are treated as lines
+            match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') => return Err(parser.unexpected()),
+                Some(previous) => previous,
+                None => return Err(ParseError::MissingMoveTo),
+            }
+        };
+
+        command = Some(current);
+
+        if segments.is_empty() && !matches!(current, b'M' | b'm') {
+            return Err(ParseError::MissingMoveTo);
+        }
+
+        let relative = current.is_ascii_lowercase();
+
+        let origin = if relative {
+            Vector::new(state.current.x, state.current.y)
+        } else {
+            Vector::new(0.0, 0.0)
+        };
+
+        let segment = match current.to_ascii_uppercase() {
+            b'M' => {
+                let to = parser.point()? + origin;
+                state.start = to;
+
+                Segment::MoveTo(to)
+            }
+            b'L' => Segment::LineTo(parser.point()? + origin),
+            b'H' => {
+                let x = parser.number()? + origin.x;
+
+                Segment::LineTo(Point::new(x, state.current.y))
+            }
+            b'V' => {
+                let y = parser.number()? + origin.y;
+
+                Segment::LineTo(Point::new(state.current.x, y))
+            }
+            b'C' => Segment::Cubic {
+                control_a: parser.point()? + origin,
+                control_b: parser.point()? + origin,
+                to: parser.point()? + origin,
+            },
+            b'S' => Segment::Cubic {
+                control_a: state.reflected_cubic(),
+                control_b: parser.point()? + origin,
+                to: parser.point()? + origin,
+            },
+            b'Q' => Segment::Quadratic {
+                control: parser.point()? + origin,
+                to: parser.point()? + origin,
+            },
+            b'T' => Segment::Quadratic {
+                control: state.reflected_quadratic(),
+                to: parser.point()? + origin,
+            },
+            b'A' => Segment::Arc {
+                radii: Vector::new(parser.number()?, parser.number()?),
+                rotation: parser.number()?.to_radians(),
+                large_arc: parser.flag()?,
+                sweep: parser.flag()?,
+                to: parser.point()? + origin,
+            },
+            b'Z' => Segment::Close,
+            _ => {
+                parser.position -= 1;
+
+                return Err(parser.unexpected());
+            }
+        };
+
+        // Drawing after closing a sub-path starts a new one at the same point
+        if matches!(segments.last(), Some(Segment::Close))
+            && !matches!(segment, Segment::MoveTo(_) | Segment::Close)
+        {
+            segments.push(Segment::MoveTo(state.start));
+        }
+
+        state.advance(segment);
+        segments.push(segment);
+    }
+
+    Ok(segments)
+}
+
+#[derive(Debug, Default)]
+struct State {
+    current: Point,
+    start: Point,
+    cubic_control: Option<Point>,
+    quadratic_control: Option<Point>,
+}
+
+impl State {
+    fn reflected_cubic(&self) -> Point {
+        self.reflect(self.cubic_control)
+    }
+
+    fn reflected_quadratic(&self) -> Point {
+        self.reflect(self.quadratic_control)
+    }
+
+    fn reflect(&self, control: Option<Point>) -> Point {
+        control.map_or(self.current, |control| {
+            Point::new(
+                2.0 * self.current.x - control.x,
+                2.0 * self.current.y - control.y,
+            )
+        })
+    }
+
+    fn advance(&mut self, segment: Segment) {
+        self.cubic_control = None;
+        self.quadratic_control = None;
+
+        self.current = match segment {
+            Segment::MoveTo(to)
+            | Segment::LineTo(to)
+            | Segment::Arc { to, .. } => to,
+            Segment::Quadratic { control, to } => {
+                self.quadratic_control = Some(control);
+
+                to
+            }
+            Segment::Cubic { control_b, to, .. } => {
+                self.cubic_control = Some(control_b);
+
+                to
+            }
+            Segment::Close => self.start,
+        };
+    }
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn unexpected(&self) -> ParseError {
+        let character = std::str::from_utf8(&self.data[self.position..])
+            .ok()
+            .and_then(|rest| rest.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        ParseError::UnexpectedCharacter {
+            character,
+            position: self.position,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b',') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn point(&mut self) -> Result<Point, ParseError> {
+        Ok(Point::new(self.number()?, self.number()?))
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separators();
+
+        let start = self.position;
+
+        if let Some(b'+' | b'-') = self.peek() {
+            self.position += 1;
+        }
+
+        let integer = self.digits();
+        let mut fraction = 0;
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            fraction = self.digits();
+        }
+
+        if integer + fraction == 0 {
+            self.position = start;
+
+            return Err(ParseError::ExpectedNumber(start));
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            let mantissa_end = self.position;
+            self.position += 1;
+
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+
+            // An `e` not followed by digits is not part of the number
+            if self.digits() == 0 {
+                self.position = mantissa_end;
+            }
+        }
+
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(ParseError::ExpectedNumber(start))
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+
+        self.position - start
+    }
+
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separators();
+
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(ParseError::ExpectedFlag(self.position)),
+        };
+
+        self.position += 1;
+
+        Ok(flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f32, y: f32) -> Point {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn relative_and_absolute_commands() {
+        assert_eq!(
+            parse("M1 1l2 0L2 2"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::LineTo(point(3.0, 1.0)),
+                Segment::LineTo(point(2.0, 2.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn implicit_line_to_after_move_to() {
+        assert_eq!(
+            parse("M1 1 2 2 3 3"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::LineTo(point(2.0, 2.0)),
+                Segment::LineTo(point(3.0, 3.0)),
+            ])
+        );
+
+        assert_eq!(
+            parse("m1 1 2 2"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::LineTo(point(3.0, 3.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn horizontal_and_vertical_lines() {
+        assert_eq!(
+            parse("M1 2H5V7h-1v-1"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 2.0)),
+                Segment::LineTo(point(5.0, 2.0)),
+                Segment::LineTo(point(5.0, 7.0)),
+                Segment::LineTo(point(4.0, 7.0)),
+                Segment::LineTo(point(4.0, 6.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn smooth_cubic_reflects_the_previous_control_point() {
+        assert_eq!(
+            parse("M0 0C1 1 2 1 3 0S5-1 6 0"),
+            Ok(vec![
+                Segment::MoveTo(point(0.0, 0.0)),
+                Segment::Cubic {
+                    control_a: point(1.0, 1.0),
+                    control_b: point(2.0, 1.0),
+                    to: point(3.0, 0.0),
+                },
+                Segment::Cubic {
+                    control_a: point(4.0, -1.0),
+                    control_b: point(5.0, -1.0),
+                    to: point(6.0, 0.0),
+                },
+            ])
+        );
+
+        // Without a previous cubic, the control point is the current point
+        assert_eq!(
+            parse("M1 1s2 2 3 3"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::Cubic {
+                    control_a: point(1.0, 1.0),
+                    control_b: point(3.0, 3.0),
+                    to: point(4.0, 4.0),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn smooth_quadratic_reflects_the_previous_control_point() {
+        assert_eq!(
+            parse("M0 0Q1 1 2 0T4 0t2 0"),
+            Ok(vec![
+                Segment::MoveTo(point(0.0, 0.0)),
+                Segment::Quadratic {
+                    control: point(1.0, 1.0),
+                    to: point(2.0, 0.0),
+                },
+                Segment::Quadratic {
+                    control: point(3.0, -1.0),
+                    to: point(4.0, 0.0),
+                },
+                Segment::Quadratic {
+                    control: point(5.0, 1.0),
+                    to: point(6.0, 0.0),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn arcs_with_compact_flags() {
+        assert_eq!(
+            parse("M1 1a1 1 0 011 1"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::Arc {
+                    radii: Vector::new(1.0, 1.0),
+                    rotation: 0.0,
+                    large_arc: false,
+                    sweep: true,
+                    to: point(2.0, 2.0),
+                },
+            ])
+        );
+
+        assert_eq!(
+            parse("M0 0A1 1 0 2 0 1 1"),
+            Err(ParseError::ExpectedFlag(11))
+        );
+    }
+
+    #[test]
+    fn numbers_without_separators() {
+        assert_eq!(
+            parse("M1-2.5.5 1 1e1-1E-1"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, -2.5)),
+                Segment::LineTo(point(0.5, 1.0)),
+                Segment::LineTo(point(10.0, -0.1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn drawing_after_close_starts_at_the_sub_path_start() {
+        assert_eq!(
+            parse("M1 1L2 2zL3 3"),
+            Ok(vec![
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::LineTo(point(2.0, 2.0)),
+                Segment::Close,
+                Segment::MoveTo(point(1.0, 1.0)),
+                Segment::LineTo(point(3.0, 3.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("L1 1"), Err(ParseError::MissingMoveTo));
+        assert_eq!(parse("1 1"), Err(ParseError::MissingMoveTo));
+        assert_eq!(parse("M0 0L1"), Err(ParseError::ExpectedNumber(6)));
+        assert_eq!(parse("M0 0L1 ."), Err(ParseError::ExpectedNumber(7)));
+        assert_eq!(
+            parse("M0 0X"),
+            Err(ParseError::UnexpectedCharacter {
+                character: 'X',
+                position: 4,
+            })
+        );
+        assert_eq!(parse(""), Ok(vec![]));
+    }
+}
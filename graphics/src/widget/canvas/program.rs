@@ -1,8 +1,10 @@
-use crate::widget::canvas::event::{self, Event};
+use crate::widget::canvas::event::{self, Event, Tick};
 use crate::widget::canvas::mouse;
-use crate::widget::canvas::{Cursor, Geometry};
+use crate::widget::canvas::{Cursor, Geometry, Measure};
 use crate::Rectangle;
 
+use iced_native::window;
+
 /// The state and logic of a [`Canvas`].
 ///
 /// A [`Program`] can mutate internal state and produce messages for an
@@ -34,16 +36,40 @@ pub trait Program<Message, Theme = iced_native::Theme> {
         (event::Status::Ignored, None)
     }
 
+    /// Advances the [`State`](Self::State) of the [`Program`] right before
+    /// a new frame is drawn.
+    ///
+    /// The [`Tick`] contains the time elapsed since the previous frame, so
+    /// animations can progress independently of the frame rate.
+    ///
+    /// This method can optionally return a [`window::RedrawRequest`] to
+    /// keep the animation going, and a `Message` to notify an application.
+    ///
+    /// By default, this method does and returns nothing.
+    fn tick(
+        &self,
+        _state: &mut Self::State,
+        _tick: Tick,
+        _bounds: Rectangle,
+    ) -> (Option<window::RedrawRequest>, Option<Message>) {
+        (None, None)
+    }
+
     /// Draws the state of the [`Program`], producing a bunch of [`Geometry`].
     ///
     /// [`Geometry`] can be easily generated with a [`Frame`] or stored in a
     /// [`Cache`].
     ///
+    /// The renderer can be used to measure text with
+    /// [`Frame::measure_text`].
+    ///
     /// [`Frame`]: crate::widget::canvas::Frame
     /// [`Cache`]: crate::widget::canvas::Cache
+    /// [`Frame::measure_text`]: crate::widget::canvas::Frame::measure_text
     fn draw(
         &self,
         state: &Self::State,
+        renderer: &dyn Measure,
         theme: &Theme,
         bounds: Rectangle,
         cursor: Cursor,
@@ -81,14 +107,24 @@ where
         T::update(self, state, event, bounds, cursor)
     }
 
+    fn tick(
+        &self,
+        state: &mut Self::State,
+        tick: Tick,
+        bounds: Rectangle,
+    ) -> (Option<window::RedrawRequest>, Option<Message>) {
+        T::tick(self, state, tick, bounds)
+    }
+
     fn draw(
         &self,
         state: &Self::State,
+        renderer: &dyn Measure,
         theme: &Theme,
         bounds: Rectangle,
         cursor: Cursor,
     ) -> Vec<Geometry> {
-        T::draw(self, state, theme, bounds, cursor)
+        T::draw(self, state, renderer, theme, bounds, cursor)
     }
 
     fn mouse_interaction(
@@ -1,6 +1,8 @@
 //! Create lines from a [crate::widget::canvas::Path] and assigns them various attributes/styles.
 pub use crate::widget::canvas::Style;
 
+use crate::Gradient;
+
 use iced_native::Color;
 
 /// The style of a stroke.
@@ -17,6 +19,11 @@ pub struct Stroke<'a> {
     /// The shape to be used at the corners of paths or basic shapes when they
     /// are stroked.
     pub line_join: LineJoin,
+    /// The limit of the ratio between the length of a [`LineJoin::Miter`]
+    /// and the width of the stroke. Corners exceeding it are bevelled.
+    ///
+    /// By default, it is set to `4.0`.
+    pub miter_limit: f32,
     /// The dash pattern used when stroking the line.
     pub line_dash: LineDash<'a>,
 }
@@ -30,6 +37,14 @@ impl<'a> Stroke<'a> {
         }
     }
 
+    /// Sets the [`Gradient`] of the [`Stroke`].
+    pub fn with_gradient(self, gradient: Gradient) -> Self {
+        Stroke {
+            style: Style::Gradient(gradient),
+            ..self
+        }
+    }
+
     /// Sets the width of the [`Stroke`].
     pub fn with_width(self, width: f32) -> Self {
         Stroke { width, ..self }
@@ -44,6 +59,19 @@ impl<'a> Stroke<'a> {
     pub fn with_line_join(self, line_join: LineJoin) -> Self {
         Stroke { line_join, ..self }
     }
+
+    /// Sets the miter limit of the [`Stroke`].
+    pub fn with_miter_limit(self, miter_limit: f32) -> Self {
+        Stroke {
+            miter_limit,
+            ..self
+        }
+    }
+
+    /// Sets the [`LineDash`] of the [`Stroke`].
+    pub fn with_line_dash(self, line_dash: LineDash<'a>) -> Self {
+        Stroke { line_dash, ..self }
+    }
 }
 
 impl<'a> Default for Stroke<'a> {
@@ -53,6 +81,7 @@ impl<'a> Default for Stroke<'a> {
             width: 1.0,
             line_cap: LineCap::default(),
             line_join: LineJoin::default(),
+            miter_limit: 4.0,
             line_dash: LineDash::default(),
         }
     }
@@ -123,4 +152,9 @@ pub struct LineDash<'a> {
 
     /// The offset of [`LineDash::segments`] to start the pattern.
     pub offset: usize,
+
+    /// The distance along the pattern at which the stroke starts.
+    ///
+    /// Increasing it over time makes the dashes march along the path.
+    pub phase: f32,
 }
@@ -1,5 +1,5 @@
 use crate::alignment;
-use crate::{Color, Font, Point};
+use crate::{Color, Font, Point, Rectangle, Size};
 
 /// A bunch of text that can be drawn to a canvas
 #[derive(Debug, Clone)]
@@ -55,3 +55,78 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+/// The measurements of a [`Text`], produced by [`Frame::measure_text`].
+///
+/// [`Frame::measure_text`]: crate::widget::canvas::Frame::measure_text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// The size of the laid out [`Text`].
+    pub size: Size,
+    /// The bounds of the [`Text`] once drawn, taking its position and
+    /// alignment into account.
+    pub bounds: Rectangle,
+    /// The distance from the top of the [`Text`] to the baseline of its
+    /// first line.
+    pub ascent: f32,
+}
+
+/// A renderer able to measure the [`Text`] of a [`Canvas`].
+///
+/// [`Canvas`]: crate::widget::Canvas
+pub trait Measure {
+    /// Measures the contents with the given size and font, returning the
+    /// size of a laid out paragraph that fits in the provided bounds.
+    fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Size;
+
+    /// Returns the distance from the top of a line of text with the given
+    /// size and font to its baseline.
+    fn ascent(&self, size: f32, font: Font) -> f32;
+}
+
+impl<R> Measure for R
+where
+    R: iced_native::text::Renderer<Font = Font>,
+{
+    fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Size {
+        let (width, height) = iced_native::text::Renderer::measure(
+            self, content, size, font, bounds,
+        );
+
+        Size::new(width, height)
+    }
+
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        iced_native::text::Renderer::ascent(self, size, font)
+    }
+}
+
+/// Returns the bounds of a [`Text`] of the given size, placed at its position
+/// according to its alignment.
+pub(super) fn aligned_bounds(text: &Text, size: Size) -> Rectangle {
+    let x = match text.horizontal_alignment {
+        alignment::Horizontal::Left => text.position.x,
+        alignment::Horizontal::Center => text.position.x - size.width / 2.0,
+        alignment::Horizontal::Right => text.position.x - size.width,
+    };
+
+    let y = match text.vertical_alignment {
+        alignment::Vertical::Top => text.position.y,
+        alignment::Vertical::Center => text.position.y - size.height / 2.0,
+        alignment::Vertical::Bottom => text.position.y - size.height,
+    };
+
+    Rectangle::new(Point::new(x, y), size)
+}
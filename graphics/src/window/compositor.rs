@@ -1,6 +1,6 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
-use crate::{Color, Error, Viewport};
+use crate::{Antialiasing, Color, Error, Viewport};
 
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 use thiserror::Error;
@@ -45,6 +45,9 @@ pub trait Compositor: Sized {
 
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
+    /// The frame may not be presented at all if nothing changed since the
+    /// last one.
+    ///
     /// [`Renderer`]: Self::Renderer
     /// [`Surface`]: Self::Surface
     fn present<T: AsRef<str>>(
@@ -55,6 +58,40 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Forgets the frames presented so far, forcing the next call to
+    /// [`present`] to redraw the whole frame.
+    ///
+    /// It should be called when the contents of the window are lost.
+    ///
+    /// [`present`]: Self::present
+    fn invalidate(&mut self) {}
+
+    /// Changes the [`Antialiasing`] strategy used by the [`Renderer`].
+    ///
+    /// Only the strategies listed in the [`Information`] of the
+    /// [`Compositor`] are supported.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn change_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<Antialiasing>,
+    ) -> Result<(), Error>;
+
+    /// Renders the [`Renderer`] primitives into an offscreen frame and returns
+    /// its RGBA pixels, row by row.
+    ///
+    /// The resolution of the frame is the physical size of the [`Viewport`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, Error>;
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
@@ -85,4 +122,6 @@ pub struct Information {
     pub adapter: String,
     /// Contains the graphics backend.
     pub backend: String,
+    /// Contains the antialiasing strategies supported by the graphics adapter.
+    pub antialiasing: Vec<Antialiasing>,
 }
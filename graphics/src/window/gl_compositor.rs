@@ -1,7 +1,7 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
 use crate::compositor::Information;
-use crate::{Color, Error, Size, Viewport};
+use crate::{Antialiasing, Color, Error, Rectangle, Size, Viewport};
 
 use core::ffi::c_void;
 
@@ -58,7 +58,13 @@ pub trait GLCompositor: Sized {
     fn fetch_information(&self) -> Information;
 
     /// Presents the primitives of the [`Renderer`] to the next frame of the
-    /// [`GLCompositor`].
+    /// [`GLCompositor`], whose back buffer was presented `buffer_age` frames
+    /// ago.
+    ///
+    /// Only the regions that changed since then are redrawn, unless the age
+    /// of the buffer is unknown (`0`). Returns the redrawn regions, in
+    /// physical pixels; if it is empty, nothing changed and the frame does not
+    /// need to be presented.
     ///
     /// [`Renderer`]: crate::Renderer
     fn present<T: AsRef<str>>(
@@ -67,5 +73,38 @@ pub trait GLCompositor: Sized {
         viewport: &Viewport,
         background_color: Color,
         overlay: &[T],
-    );
+        buffer_age: u32,
+    ) -> Vec<Rectangle<u32>>;
+
+    /// Forgets the frames presented so far, forcing the next one to be
+    /// redrawn entirely.
+    ///
+    /// It should be called when the contents of the window are lost.
+    fn invalidate(&mut self) {}
+
+    /// Changes the [`Antialiasing`] strategy used by the [`Renderer`].
+    ///
+    /// Only the strategies listed in the [`Information`] of the
+    /// [`GLCompositor`] are supported.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn change_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<Antialiasing>,
+    ) -> Result<(), Error>;
+
+    /// Presents the primitives of the [`Renderer`] to the next frame of the
+    /// [`GLCompositor`] and reads back its RGBA pixels, row by row.
+    ///
+    /// The resolution of the frame is the physical size of the [`Viewport`].
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, Error>;
 }
@@ -0,0 +1,138 @@
+//! Switch between alternative layouts depending on the available space.
+use crate::Responsive;
+
+use iced_native::{Element, Size};
+
+/// A class of widths an [`Adaptive`] widget can be laid out in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// A narrow width, like a phone in portrait.
+    Compact,
+    /// A medium width, like a tablet or a small window.
+    Medium,
+    /// A wide width, like a desktop window.
+    Expanded,
+}
+
+/// The widths at which an [`Adaptive`] widget switches between
+/// [`Breakpoint`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    /// The minimum width of the [`Breakpoint::Medium`] class.
+    pub medium: f32,
+    /// The minimum width of the [`Breakpoint::Expanded`] class.
+    pub expanded: f32,
+}
+
+impl Breakpoints {
+    /// Creates new [`Breakpoints`] with the given minimum widths of the
+    /// medium and expanded classes.
+    pub fn new(medium: f32, expanded: f32) -> Self {
+        Self {
+            medium,
+            expanded: expanded.max(medium),
+        }
+    }
+
+    /// Returns the [`Breakpoint`] of the given width.
+    pub fn classify(&self, width: f32) -> Breakpoint {
+        if width >= self.expanded {
+            Breakpoint::Expanded
+        } else if width >= self.medium {
+            Breakpoint::Medium
+        } else {
+            Breakpoint::Compact
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium: 600.0,
+            expanded: 840.0,
+        }
+    }
+}
+
+/// A widget that builds its contents with a different view depending on the
+/// [`Breakpoint`] of its own width.
+///
+/// When no view is given for a [`Breakpoint`], the view of the closest
+/// narrower one is used. Like a [`Responsive`] widget, an [`Adaptive`] widget
+/// will always try to fill all the available space of its parent.
+#[allow(missing_debug_implementations)]
+pub struct Adaptive<'a, Message, Renderer> {
+    breakpoints: Breakpoints,
+    compact: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+    medium: Option<Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>>,
+    expanded: Option<Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>>,
+}
+
+impl<'a, Message, Renderer> Adaptive<'a, Message, Renderer> {
+    /// Creates a new [`Adaptive`] widget with the view of its
+    /// [`Breakpoint::Compact`] class.
+    ///
+    /// The view is provided with the current [`Size`] of the widget.
+    pub fn new(
+        compact: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            breakpoints: Breakpoints::default(),
+            compact: Box::new(compact),
+            medium: None,
+            expanded: None,
+        }
+    }
+
+    /// Sets the view of the [`Breakpoint::Medium`] class.
+    pub fn medium(
+        mut self,
+        view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        self.medium = Some(Box::new(view));
+        self
+    }
+
+    /// Sets the view of the [`Breakpoint::Expanded`] class.
+    pub fn expanded(
+        mut self,
+        view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        self.expanded = Some(Box::new(view));
+        self
+    }
+
+    /// Sets the [`Breakpoints`] of the [`Adaptive`] widget.
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> From<Adaptive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer + 'a,
+    Message: 'a,
+{
+    fn from(adaptive: Adaptive<'a, Message, Renderer>) -> Self {
+        let Adaptive {
+            breakpoints,
+            compact,
+            medium,
+            expanded,
+        } = adaptive;
+
+        Self::from(Responsive::new(move |size| {
+            let view = match breakpoints.classify(size.width) {
+                Breakpoint::Expanded => expanded.as_ref().or(medium.as_ref()),
+                Breakpoint::Medium => medium.as_ref(),
+                Breakpoint::Compact => None,
+            }
+            .unwrap_or(&compact);
+
+            view(size)
+        }))
+    }
+}
@@ -7,7 +7,7 @@ use iced_native::renderer;
 use iced_native::widget;
 use iced_native::widget::tree::{self, Tree};
 use iced_native::{
-    Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget,
+    Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 use ouroboros::self_referencing;
@@ -293,6 +293,23 @@ where
                 });
             }
 
+            fn container_with_bounds(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.container_with_bounds(
+                    id,
+                    bounds,
+                    &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    },
+                );
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -301,6 +318,31 @@ where
                 self.operation.focusable(state, id);
             }
 
+            fn scrollable(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+            ) {
+                self.operation.scrollable(state, id);
+            }
+
+            fn scrollable_with_viewport(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                self.operation.scrollable_with_viewport(
+                    state,
+                    id,
+                    bounds,
+                    content_bounds,
+                    translation,
+                );
+            }
+
             fn text_input(
                 &mut self,
                 state: &mut dyn widget::operation::TextInput,
@@ -13,6 +13,13 @@ use std::cell::RefCell;
 use std::hash::{Hash, Hasher as H};
 use std::rc::Rc;
 
+/// The last layout of the element of a [`Lazy`] widget and the limits it was
+/// computed with.
+///
+/// Since the element only changes when the dependency does, the layout can be
+/// reused until either the dependency or the limits change.
+type LayoutCache = Rc<RefCell<Option<(layout::Limits, layout::Node)>>>;
+
 #[allow(missing_debug_implementations)]
 pub struct Lazy<'a, Message, Renderer, Dependency, View> {
     dependency: Dependency,
@@ -20,6 +27,7 @@ pub struct Lazy<'a, Message, Renderer, Dependency, View> {
     element: RefCell<
         Option<Rc<RefCell<Option<Element<'static, Message, Renderer>>>>>,
     >,
+    layout: RefCell<Option<LayoutCache>>,
 }
 
 impl<'a, Message, Renderer, Dependency, View>
@@ -36,6 +44,7 @@ where
             dependency,
             view: Box::new(view),
             element: RefCell::new(None),
+            layout: RefCell::new(None),
         }
     }
 
@@ -53,6 +62,14 @@ where
             .unwrap())
     }
 
+    /// Forgets the cached layout, since the element may have changed without
+    /// the dependency changing; for instance, when it contains a component.
+    fn clear_layout(&self) {
+        if let Some(cache) = self.layout.borrow().as_ref() {
+            *cache.borrow_mut() = None;
+        }
+    }
+
     fn with_element_mut<T>(
         &self,
         f: impl FnOnce(&mut Element<Message, Renderer>) -> T,
@@ -70,6 +87,7 @@ where
 
 struct Internal<Message, Renderer> {
     element: Rc<RefCell<Option<Element<'static, Message, Renderer>>>>,
+    layout: LayoutCache,
     hash: u64,
 }
 
@@ -94,9 +112,16 @@ where
         let element =
             Rc::new(RefCell::new(Some((self.view)(&self.dependency).into())));
 
+        let layout = LayoutCache::default();
+
         (*self.element.borrow_mut()) = Some(element.clone());
+        (*self.layout.borrow_mut()) = Some(layout.clone());
 
-        tree::State::new(Internal { element, hash })
+        tree::State::new(Internal {
+            element,
+            layout,
+            hash,
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -115,13 +140,16 @@ where
 
             let element = (self.view)(&self.dependency).into();
             current.element = Rc::new(RefCell::new(Some(element)));
+            current.layout = LayoutCache::default();
 
             (*self.element.borrow_mut()) = Some(current.element.clone());
+            (*self.layout.borrow_mut()) = Some(current.layout.clone());
             self.with_element(|element| {
                 tree.diff_children(std::slice::from_ref(&element.as_widget()))
             });
         } else {
             (*self.element.borrow_mut()) = Some(current.element.clone());
+            (*self.layout.borrow_mut()) = Some(current.layout.clone());
         }
     }
 
@@ -138,9 +166,25 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.with_element(|element| {
+        let cache = self.layout.borrow().clone();
+
+        if let Some(cache) = &cache {
+            if let Some((cached_limits, node)) = &*cache.borrow() {
+                if cached_limits == limits {
+                    return node.clone();
+                }
+            }
+        }
+
+        let node = self.with_element(|element| {
             element.as_widget().layout(renderer, limits)
-        })
+        });
+
+        if let Some(cache) = cache {
+            *cache.borrow_mut() = Some((*limits, node.clone()));
+        }
+
+        node
     }
 
     fn operate(
@@ -170,7 +214,7 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.with_element_mut(|element| {
+        let status = self.with_element_mut(|element| {
             element.as_widget_mut().on_event(
                 &mut tree.children[0],
                 event,
@@ -180,7 +224,13 @@ where
                 clipboard,
                 shell,
             )
-        })
+        });
+
+        if shell.is_layout_invalid() {
+            self.clear_layout();
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -255,6 +305,12 @@ where
         let has_overlay =
             overlay.with_overlay_maybe(|overlay| overlay.position());
 
+        // The events of the overlay may change the element without going
+        // through the widget
+        if has_overlay.is_some() {
+            self.clear_layout();
+        }
+
         has_overlay
             .map(|position| overlay::Element::new(position, Box::new(overlay)))
     }
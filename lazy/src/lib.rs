@@ -19,9 +19,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 mod lazy;
 
+pub mod adaptive;
 pub mod component;
 pub mod responsive;
 
+pub use adaptive::Adaptive;
 pub use component::Component;
 pub use lazy::Lazy;
 pub use responsive::Responsive;
@@ -64,3 +66,11 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`Adaptive`] widget with the view of its compact
+/// [`Breakpoint`](adaptive::Breakpoint).
+pub fn adaptive<'a, Message, Renderer>(
+    compact: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+) -> Adaptive<'a, Message, Renderer> {
+    Adaptive::new(compact)
+}
@@ -0,0 +1,246 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use iced_native::benchmark;
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::user_interface;
+use iced_native::widget::{column, container, pick_list, scrollable, text};
+use iced_native::{
+    Background, Element, Event, Font, Length, Point, Rectangle, Size, Theme,
+    Vector,
+};
+
+const BOUNDS: Size = Size::new(1024.0, 768.0);
+
+/// A renderer that does nothing, estimating the size of text from its
+/// length.
+///
+/// The `Null` renderer of the crate is only available in debug builds, while
+/// benchmarks are built with optimizations.
+#[derive(Debug, Clone, Copy, Default)]
+struct Renderer;
+
+impl iced_native::Renderer for Renderer {
+    type Theme = Theme;
+
+    fn with_layer(&mut self, _bounds: Rectangle, _f: impl FnOnce(&mut Self)) {}
+
+    fn with_translation(
+        &mut self,
+        _translation: Vector,
+        _f: impl FnOnce(&mut Self),
+    ) {
+    }
+
+    fn clear(&mut self) {}
+
+    fn fill_quad(
+        &mut self,
+        _quad: renderer::Quad,
+        _background: impl Into<Background>,
+    ) {
+    }
+}
+
+impl iced_native::text::Renderer for Renderer {
+    type Font = Font;
+
+    const ICON_FONT: Font = Font::Default;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_size(&self) -> f32 {
+        20.0
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        _font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let width = content.chars().count() as f32 * size * 0.5;
+        let lines = (width / bounds.width.max(size)).ceil().max(1.0);
+
+        (width.min(bounds.width), lines * size)
+    }
+
+    fn hit_test(
+        &self,
+        _contents: &str,
+        _size: f32,
+        _font: Font,
+        _bounds: Size,
+        _point: Point,
+        _nearest_only: bool,
+    ) -> Option<iced_native::text::Hit> {
+        None
+    }
+
+    fn fill_text(&mut self, _text: iced_native::text::Text<'_, Font>) {}
+}
+
+fn menu_scroll(c: &mut Criterion) {
+    let options: Vec<String> =
+        (0..10_000).map(|i| format!("Option {i}")).collect();
+
+    let view = || -> Element<'_, String, Renderer> {
+        pick_list(options.as_slice(), None, |option| option)
+            .width(Length::Fill)
+            .into()
+    };
+
+    let mut renderer = Renderer;
+    let mut cache = user_interface::Cache::new();
+    let mut messages = Vec::new();
+
+    // Open the menu
+    let click = Point::new(10.0, 10.0);
+
+    for events in [
+        vec![Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))],
+        vec![],
+    ] {
+        let _ = benchmark::frame(
+            view(),
+            BOUNDS,
+            &mut cache,
+            &events,
+            click,
+            &mut renderer,
+            &Theme::Light,
+            &mut messages,
+        );
+    }
+
+    let cursor = Point::new(10.0, 100.0);
+    let scroll = [Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Lines { x: 0.0, y: -3.0 },
+    })];
+
+    let _ = c.bench_function("menu_scroll_10k", |b| {
+        b.iter(|| {
+            black_box(benchmark::frame(
+                view(),
+                BOUNDS,
+                &mut cache,
+                &scroll,
+                cursor,
+                &mut renderer,
+                &Theme::Light,
+                &mut messages,
+            ))
+        })
+    });
+}
+
+fn nested_overlays(c: &mut Criterion) {
+    const DEPTH: usize = 64;
+
+    let options: Vec<String> =
+        (0..100).map(|i| format!("Option {i}")).collect();
+
+    let view = || -> Element<'_, String, Renderer> {
+        let mut content: Element<'_, String, Renderer> =
+            pick_list(options.as_slice(), None, |option| option)
+                .width(Length::Fill)
+                .into();
+
+        for _ in 0..DEPTH {
+            content = container(column(vec![content])).padding(1).into();
+        }
+
+        content
+    };
+
+    let mut renderer = Renderer;
+    let mut cache = user_interface::Cache::new();
+    let mut messages = Vec::new();
+
+    let click = Point::new(DEPTH as f32 + 10.0, DEPTH as f32 + 10.0);
+
+    for events in [
+        vec![Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))],
+        vec![],
+    ] {
+        let _ = benchmark::frame(
+            view(),
+            BOUNDS,
+            &mut cache,
+            &events,
+            click,
+            &mut renderer,
+            &Theme::Light,
+            &mut messages,
+        );
+    }
+
+    let position = Point::new(click.x, click.y + 200.0);
+    let movement = [Event::Mouse(mouse::Event::CursorMoved { position })];
+
+    let _ = c.bench_function("nested_overlays_64", |b| {
+        b.iter(|| {
+            black_box(benchmark::frame(
+                view(),
+                BOUNDS,
+                &mut cache,
+                &movement,
+                position,
+                &mut renderer,
+                &Theme::Light,
+                &mut messages,
+            ))
+        })
+    });
+}
+
+fn text_relayout(c: &mut Criterion) {
+    let paragraphs: Vec<String> = (0..2_000)
+        .map(|i| format!("Paragraph {i}: {}", "lorem ipsum ".repeat(20)))
+        .collect();
+
+    let view = || -> Element<'_, (), Renderer> {
+        scrollable(column(
+            paragraphs
+                .iter()
+                .map(|paragraph| text(paragraph).into())
+                .collect(),
+        ))
+        .into()
+    };
+
+    let mut renderer = Renderer;
+    let mut cache = user_interface::Cache::new();
+    let mut messages = Vec::new();
+    let mut width = BOUNDS.width;
+
+    let _ = c.bench_function("text_relayout_2k", |b| {
+        b.iter(|| {
+            // Simulate a window being resized
+            width = if width > 400.0 {
+                width - 1.0
+            } else {
+                BOUNDS.width
+            };
+
+            black_box(benchmark::frame(
+                view(),
+                Size::new(width, BOUNDS.height),
+                &mut cache,
+                &[],
+                Point::ORIGIN,
+                &mut renderer,
+                &Theme::Light,
+                &mut messages,
+            ))
+        })
+    });
+}
+
+criterion_group!(benches, menu_scroll, nested_overlays, text_relayout);
+criterion_main!(benches);
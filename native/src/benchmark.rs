@@ -0,0 +1,93 @@
+//! Measure the performance of a user interface.
+//!
+//! This module offers a small API to time the different stages of a frame of
+//! a [`UserInterface`] without a windowing system, so performance-sensitive
+//! changes can be validated with any renderer.
+//!
+//! The `benches` of this crate use it to track performance regressions.
+use crate::application;
+use crate::clipboard;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::user_interface::{self, UserInterface};
+use crate::{Color, Element, Event, Point, Size};
+
+/// The time spent in each stage of a frame of a [`UserInterface`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Frame {
+    /// The time spent building the [`UserInterface`], including the diffing
+    /// of its widget tree and its layout.
+    pub layout: Duration,
+
+    /// The time spent processing the events of the frame.
+    pub update: Duration,
+
+    /// The time spent drawing the [`UserInterface`].
+    pub draw: Duration,
+}
+
+impl Frame {
+    /// Returns the total time spent in the [`Frame`].
+    pub fn total(&self) -> Duration {
+        self.layout + self.update + self.draw
+    }
+}
+
+/// Runs and times a whole frame of the given root [`Element`].
+///
+/// The [`UserInterface`] is built using the provided [`Cache`], which is
+/// replaced with the new cache after the frame. The given `events` are then
+/// processed and any produced messages are pushed to `messages`. Finally, the
+/// [`UserInterface`] is drawn.
+///
+/// [`Cache`]: user_interface::Cache
+pub fn frame<'a, Message, Renderer>(
+    root: impl Into<Element<'a, Message, Renderer>>,
+    bounds: Size,
+    cache: &mut user_interface::Cache,
+    events: &[Event],
+    cursor_position: Point,
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    messages: &mut Vec<Message>,
+) -> Frame
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: application::StyleSheet,
+{
+    let started_at = Instant::now();
+
+    let mut user_interface =
+        UserInterface::build(root, bounds, std::mem::take(cache), renderer);
+
+    let laid_out_at = Instant::now();
+
+    let _ = user_interface.update(
+        events,
+        cursor_position,
+        renderer,
+        &mut clipboard::Null,
+        messages,
+    );
+
+    let updated_at = Instant::now();
+
+    let _ = user_interface.draw(
+        renderer,
+        theme,
+        &renderer::Style {
+            text_color: Color::BLACK,
+        },
+        cursor_position,
+    );
+
+    let drawn_at = Instant::now();
+
+    *cache = user_interface.into_cache();
+
+    Frame {
+        layout: laid_out_at - started_at,
+        update: updated_at - laid_out_at,
+        draw: drawn_at - updated_at,
+    }
+}
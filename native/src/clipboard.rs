@@ -2,6 +2,7 @@
 use iced_futures::MaybeSend;
 
 use std::fmt;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -11,6 +12,91 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current [`Content`] of the [`Clipboard`], whatever its type.
+    ///
+    /// By default, the text of the [`Clipboard`] is read and parsed with
+    /// [`Content::from_text`].
+    fn read_content(&self) -> Option<Content> {
+        self.read().map(Content::from_text)
+    }
+}
+
+/// The content of a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content {
+    /// Some text.
+    Text(String),
+
+    /// A list of paths to files.
+    Files(Vec<PathBuf>),
+}
+
+impl Content {
+    /// Parses the given text copied to a clipboard.
+    ///
+    /// File managers copy files as a list of `file://` URIs, one per line,
+    /// which is parsed as [`Content::Files`]. Any other text is kept as
+    /// [`Content::Text`].
+    pub fn from_text(text: String) -> Self {
+        let uris = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let files: Option<Vec<_>> = uris.map(file_path).collect();
+
+        match files {
+            Some(files) if !files.is_empty() => Self::Files(files),
+            _ => Self::Text(text),
+        }
+    }
+}
+
+/// Returns the path of the given `file://` URI, if local.
+fn file_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+
+    let path = match rest.strip_prefix("localhost") {
+        Some(path) => path,
+        None => rest,
+    };
+
+    if !path.starts_with('/') {
+        return None;
+    }
+
+    let path = percent_decode(path)?;
+
+    // Windows paths are written like `/C:/Users`
+    let is_drive = path.len() > 2
+        && path.as_bytes()[1].is_ascii_alphabetic()
+        && path.as_bytes()[2] == b':';
+
+    Some(PathBuf::from(if cfg!(windows) && is_drive {
+        &path[1..]
+    } else {
+        &path[..]
+    }))
+}
+
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
 }
 
 /// A null implementation of the [`Clipboard`] trait.
@@ -60,3 +146,31 @@ impl<T> fmt::Debug for Action<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uri_lists_are_parsed_as_files() {
+        assert_eq!(
+            Content::from_text(String::from(
+                "# copied\nfile:///home/iced/My%20File.txt\r\nfile://localhost/tmp/a\n"
+            )),
+            Content::Files(vec![
+                PathBuf::from("/home/iced/My File.txt"),
+                PathBuf::from("/tmp/a"),
+            ])
+        );
+    }
+
+    #[test]
+    fn other_text_is_kept() {
+        for text in ["", "Hello", "file:///tmp/a\nHello", "file://host/a"] {
+            assert_eq!(
+                Content::from_text(String::from(text)),
+                Content::Text(String::from(text))
+            );
+        }
+    }
+}
@@ -6,7 +6,8 @@ use crate::renderer;
 use crate::widget;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Color, Layout, Length, Point, Rectangle, Shell, Widget,
+    Alignment, Clipboard, Color, Layout, Length, Point, Rectangle, Shell,
+    Vector, Widget,
 };
 
 use std::any::Any;
@@ -287,6 +288,14 @@ where
         self.widget.layout(renderer, limits)
     }
 
+    fn height_for_width(&self, renderer: &Renderer, width: f32) -> f32 {
+        self.widget.height_for_width(renderer, width)
+    }
+
+    fn align_self(&self) -> Option<Alignment> {
+        self.widget.align_self()
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -311,6 +320,23 @@ where
                 });
             }
 
+            fn container_with_bounds(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.container_with_bounds(
+                    id,
+                    bounds,
+                    &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    },
+                );
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -327,6 +353,23 @@ where
                 self.operation.scrollable(state, id);
             }
 
+            fn scrollable_with_viewport(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                self.operation.scrollable_with_viewport(
+                    state,
+                    id,
+                    bounds,
+                    content_bounds,
+                    translation,
+                );
+            }
+
             fn text_input(
                 &mut self,
                 state: &mut dyn widget::operation::TextInput,
@@ -479,6 +522,14 @@ where
         self.element.widget.layout(renderer, limits)
     }
 
+    fn height_for_width(&self, renderer: &Renderer, width: f32) -> f32 {
+        self.element.widget.height_for_width(renderer, width)
+    }
+
+    fn align_self(&self) -> Option<Alignment> {
+        self.element.widget.align_self()
+    }
+
     fn operate(
         &self,
         state: &mut Tree,
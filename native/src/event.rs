@@ -1,4 +1,5 @@
 //! Handle events of a user interface.
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -24,6 +25,9 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
@@ -0,0 +1,221 @@
+//! Match and rank text against a search pattern.
+//!
+//! A [`TagsInput`] uses them to decide which of its suggestions match what the
+//! user has typed and in which order they should be listed, while a [`Menu`]
+//! uses them to highlight the parts of its options matching a pattern.
+//!
+//! A pattern matches some text if all of its characters appear in the text in
+//! the same order, ignoring case. Consecutive characters and characters at the
+//! start of a word are rewarded, while gaps between matched characters are
+//! penalized.
+//!
+//! [`TagsInput`]: crate::widget::TagsInput
+//! [`Menu`]: crate::overlay::Menu
+use std::cmp::Reverse;
+use std::ops::Range;
+
+/// A successful match of a pattern against some text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The score of the [`Match`]. Higher is better.
+    pub score: i32,
+
+    /// The byte ranges of the text that matched the pattern, in order.
+    ///
+    /// Adjacent matched characters are merged into a single range.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Matches the given `pattern` against the given `text`.
+///
+/// Returns `None` if some character of the `pattern` could not be found in
+/// the `text`. An empty `pattern` matches any `text` with a score of zero.
+pub fn find(pattern: &str, text: &str) -> Option<Match> {
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    if pattern.is_empty() {
+        return Some(Match {
+            score: 0,
+            ranges: Vec::new(),
+        });
+    }
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, c))| eq_ignore_case(*c, pattern[0]))
+        .filter_map(|(start, _)| find_from(&pattern, &chars, start))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(score, indices)| Match {
+            score,
+            ranges: ranges(&chars, text.len(), &indices),
+        })
+}
+
+/// Returns the score of the given `pattern` against the given `text`, if
+/// they match.
+///
+/// This is a shorthand for calling [`find`] and discarding the ranges.
+pub fn score(pattern: &str, text: &str) -> Option<i32> {
+    find(pattern, text).map(|found| found.score)
+}
+
+/// Filters the given `options` by the `pattern` and sorts them by
+/// descending score.
+///
+/// Options with the same score keep their original relative order.
+pub fn filter<'a, T>(
+    pattern: &str,
+    options: impl IntoIterator<Item = &'a T>,
+) -> Vec<(&'a T, Match)>
+where
+    T: ToString + ?Sized + 'a,
+{
+    let mut matches: Vec<_> = options
+        .into_iter()
+        .filter_map(|option| {
+            find(pattern, &option.to_string()).map(|found| (option, found))
+        })
+        .collect();
+
+    matches.sort_by_key(|(_, found)| Reverse(found.score));
+
+    matches
+}
+
+const MATCH_SCORE: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 16;
+const WORD_START_BONUS: i32 = 24;
+const CASE_BONUS: i32 = 1;
+const GAP_PENALTY: i32 = 2;
+const LEADING_GAP_PENALTY: i32 = 1;
+const MAX_LEADING_GAP_PENALTY: i32 = 12;
+
+/// Greedily matches the `pattern` starting at the character index `start`,
+/// returning the score and the matched character indices.
+fn find_from(
+    pattern: &[char],
+    chars: &[(usize, char)],
+    start: usize,
+) -> Option<(i32, Vec<usize>)> {
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut next = start;
+
+    for p in pattern {
+        let offset = chars[next..]
+            .iter()
+            .position(|(_, c)| eq_ignore_case(*c, *p))?;
+
+        indices.push(next + offset);
+        next += offset + 1;
+    }
+
+    let mut score =
+        -(start as i32 * LEADING_GAP_PENALTY).min(MAX_LEADING_GAP_PENALTY);
+
+    for (i, (&index, p)) in indices.iter().zip(pattern).enumerate() {
+        let (_, c) = chars[index];
+
+        score += MATCH_SCORE;
+
+        if c == *p {
+            score += CASE_BONUS;
+        }
+
+        if is_word_start(chars, index) {
+            score += WORD_START_BONUS;
+        }
+
+        if i > 0 {
+            let gap = index - indices[i - 1] - 1;
+
+            if gap == 0 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= gap as i32 * GAP_PENALTY;
+            }
+        }
+    }
+
+    Some((score, indices))
+}
+
+fn ranges(
+    chars: &[(usize, char)],
+    len: usize,
+    indices: &[usize],
+) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    for &index in indices {
+        let start = chars[index].0;
+        let end = chars.get(index + 1).map(|(i, _)| *i).unwrap_or(len);
+
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+
+    ranges
+}
+
+fn is_word_start(chars: &[(usize, char)], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+
+    let (_, previous) = chars[index - 1];
+    let (_, current) = chars[index];
+
+    (!previous.is_alphanumeric() && current.is_alphanumeric())
+        || (previous.is_lowercase() && current.is_uppercase())
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_pattern_matches_everything() {
+        assert_eq!(
+            find("", "anything"),
+            Some(Match {
+                score: 0,
+                ranges: Vec::new()
+            })
+        );
+    }
+
+    #[test]
+    fn subsequence_ignoring_case() {
+        let found = find("PkL", "pick_list").unwrap();
+
+        assert_eq!(found.ranges, vec![0..1, 3..4, 5..6]);
+        assert_eq!(find("lp", "pick_list"), None);
+    }
+
+    #[test]
+    fn ranges_are_merged_and_use_byte_offsets() {
+        let found = find("éd", "Éditeur").unwrap();
+
+        assert_eq!(found.ranges, vec![0..3]);
+    }
+
+    #[test]
+    fn ranks_word_starts_first() {
+        let options = ["TextInput", "Toggler", "Container", "Text"];
+        let filtered: Vec<_> = filter("ti", options.iter())
+            .into_iter()
+            .map(|(option, _)| *option)
+            .collect();
+
+        assert_eq!(filtered, vec!["TextInput", "Container"]);
+    }
+}
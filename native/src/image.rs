@@ -1,5 +1,6 @@
 //! Load and draw raster graphics.
-use crate::{Hasher, Rectangle, Size};
+use crate::time::Duration;
+use crate::{Color, Hasher, Rectangle, Size};
 
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
@@ -10,6 +11,8 @@ use std::sync::Arc;
 pub struct Handle {
     id: u64,
     data: Data,
+    frame: usize,
+    exif_orientation: bool,
 }
 
 impl Handle {
@@ -56,12 +59,58 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data,
+            frame: 0,
+            exif_orientation: true,
         }
     }
 
+    /// Returns a [`Handle`] pointing to the given frame of the animated
+    /// image of this [`Handle`].
+    ///
+    /// Still images only have a single frame, with index `0`.
+    pub fn with_frame(&self, frame: usize) -> Handle {
+        Handle {
+            frame,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the index of the frame the [`Handle`] points to.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Sets whether the EXIF orientation of the image is applied when it is
+    /// decoded, which is the default.
+    ///
+    /// Disable it if you handle the orientation of the image yourself, so
+    /// the pixels are displayed as they are stored.
+    pub fn with_exif_orientation(self, apply: bool) -> Handle {
+        Handle {
+            exif_orientation: apply,
+            ..self
+        }
+    }
+
+    /// Returns whether the EXIF orientation of the image is applied when it
+    /// is decoded.
+    pub fn applies_exif_orientation(&self) -> bool {
+        self.exif_orientation
+    }
+
     /// Returns the unique identifier of the [`Handle`].
+    ///
+    /// Every frame of an animated image has a different identifier, and so
+    /// does an image decoded without its EXIF orientation.
     pub fn id(&self) -> u64 {
-        self.id
+        if self.frame == 0 && self.exif_orientation {
+            return self.id;
+        }
+
+        let mut hasher = Hasher::default();
+        (self.id, self.frame, self.exif_orientation).hash(&mut hasher);
+
+        hasher.finish()
     }
 
     /// Returns a reference to the image [`Data`].
@@ -81,7 +130,7 @@ where
 
 impl Hash for Handle {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+        self.id().hash(state);
     }
 }
 
@@ -156,6 +205,155 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The mirroring of an image, applied before it is rotated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Flip {
+    /// Whether the image is mirrored from left to right.
+    pub horizontal: bool,
+    /// Whether the image is mirrored from top to bottom.
+    pub vertical: bool,
+}
+
+/// A set of effects applied to an image when it is drawn.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::Filter;
+/// #
+/// let disabled = Filter::NONE.grayscale(1.0).brightness(1.2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Filter {
+    /// The radius of the blur applied to the image, in pixels of the image.
+    pub blur: f32,
+    /// The amount of the image turned into grayscale, in [0.0, 1.0].
+    pub grayscale: f32,
+    /// The factor multiplying the brightness of the image.
+    pub brightness: f32,
+    /// The factor multiplying the contrast of the image.
+    pub contrast: f32,
+    /// The [`Color`] mixed into the image, by the amount of its alpha.
+    pub tint: Option<Color>,
+}
+
+impl Filter {
+    /// A [`Filter`] that leaves the image untouched.
+    pub const NONE: Self = Self {
+        blur: 0.0,
+        grayscale: 0.0,
+        brightness: 1.0,
+        contrast: 1.0,
+        tint: None,
+    };
+
+    /// Sets the radius of the blur of the [`Filter`], in pixels of the image.
+    pub fn blur(self, radius: f32) -> Self {
+        Self {
+            blur: radius.max(0.0),
+            ..self
+        }
+    }
+
+    /// Sets the amount of grayscale of the [`Filter`], in [0.0, 1.0].
+    pub fn grayscale(self, amount: f32) -> Self {
+        Self {
+            grayscale: amount.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Sets the factor multiplying the brightness of the image.
+    pub fn brightness(self, factor: f32) -> Self {
+        Self {
+            brightness: factor.max(0.0),
+            ..self
+        }
+    }
+
+    /// Sets the factor multiplying the contrast of the image.
+    pub fn contrast(self, factor: f32) -> Self {
+        Self {
+            contrast: factor.max(0.0),
+            ..self
+        }
+    }
+
+    /// Sets the [`Color`] mixed into the image, by the amount of its alpha.
+    pub fn tint(self, color: Color) -> Self {
+        Self {
+            tint: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns whether the [`Filter`] leaves the image untouched.
+    pub fn is_none(&self) -> bool {
+        self.blur <= 0.0
+            && self.grayscale <= 0.0
+            && self.brightness == 1.0
+            && self.contrast == 1.0
+            && self.tint.map_or(true, |tint| tint.a <= 0.0)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// A limit on the GPU memory used by the images cached by a renderer.
+///
+/// Images drawn in the current frame are never evicted, so the memory used
+/// may exceed the limit when a lot of them are visible at once.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::{Budget, Eviction};
+/// #
+/// let budget = Budget {
+///     memory: Some(256 * 1024 * 1024),
+///     eviction: Eviction::LeastRecentlyUsed,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// The maximum amount of bytes used by cached images, if any.
+    pub memory: Option<u64>,
+    /// The [`Eviction`] policy of the cache.
+    pub eviction: Eviction,
+}
+
+/// The policy used to evict images from the cache of a renderer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Eviction {
+    /// Evict the images that were not drawn in the last frame.
+    #[default]
+    Unused,
+    /// Keep the images that were not drawn in the last frame, evicting the
+    /// least recently used ones first when the memory of the [`Budget`] is
+    /// exceeded.
+    ///
+    /// Images are never evicted if the [`Budget`] has no memory limit.
+    LeastRecentlyUsed,
+}
+
+/// The GPU memory used by the images cached by a renderer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The amount of raster images uploaded.
+    pub images: usize,
+    /// The amount of rasterized vector images uploaded.
+    pub vectors: usize,
+    /// The amount of bytes used by the uploaded images.
+    pub bytes: u64,
+    /// The amount of bytes allocated by the renderer to store images, which
+    /// may be larger than the bytes used.
+    pub allocated: u64,
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -171,4 +369,71 @@ pub trait Renderer: crate::Renderer {
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
     fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+
+    /// Draws an image with the given [`Handle`] inside the provided `bounds`,
+    /// mirrored by the given [`Flip`] and then rotated around its center by
+    /// the given angle, in radians.
+    ///
+    /// The image is drawn untransformed if the [`Renderer`] does not support
+    /// transformations.
+    fn draw_transformed(
+        &mut self,
+        handle: Self::Handle,
+        bounds: Rectangle,
+        _rotation: f32,
+        _flip: Flip,
+    ) {
+        self.draw(handle, bounds)
+    }
+
+    /// Draws the given frame of the image of the [`Handle`] inside the
+    /// provided `bounds`, applying the given [`Filter`].
+    ///
+    /// The image is drawn unfiltered if the [`Renderer`] does not support
+    /// filters.
+    fn draw_filtered(
+        &mut self,
+        handle: Self::Handle,
+        frame: usize,
+        bounds: Rectangle,
+        _filter: Filter,
+    ) {
+        self.draw_frame(handle, frame, bounds)
+    }
+
+    /// Returns how long each frame of the animated image of the given
+    /// [`Handle`] is displayed.
+    ///
+    /// Still images have a single frame, or none if the [`Renderer`] does
+    /// not support animated images.
+    fn frame_delays(&self, _handle: &Self::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
+
+    /// Draws the given frame of the animated image of the [`Handle`] inside
+    /// the provided `bounds`.
+    fn draw_frame(
+        &mut self,
+        handle: Self::Handle,
+        _frame: usize,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle, bounds)
+    }
+
+    /// Uploads the image of the given [`Handle`] ahead of time, so it is
+    /// ready when it is drawn.
+    ///
+    /// The image is kept at least until the next frame is drawn.
+    fn preload(&mut self, _handle: &Self::Handle) {}
+
+    /// Evicts the image of the given [`Handle`] from the cache of the
+    /// [`Renderer`], releasing its memory.
+    fn evict(&mut self, _handle: &Self::Handle) {}
+
+    /// Returns the [`Usage`] of the memory used by the images cached by the
+    /// [`Renderer`].
+    fn memory_usage(&self) -> Usage {
+        Usage::default()
+    }
 }
@@ -0,0 +1,60 @@
+//! Compose text with the input method editor of the system.
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input method editors (IMEs) let users compose text that cannot be typed
+/// directly with a keyboard, like CJK characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was enabled.
+    Opened,
+
+    /// A new composition is in progress.
+    ///
+    /// An empty `String` means that the current composition was cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// The composition was finished and the given text must be inserted.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
+
+/// The state of the input method requested by the widgets of a user
+/// interface.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// No widget is expecting text input.
+    #[default]
+    Disabled,
+
+    /// A widget is expecting text input.
+    Enabled {
+        /// The bounds of the text cursor of the widget.
+        ///
+        /// The candidate window of the input method editor should be placed
+        /// right below it.
+        cursor: Rectangle,
+    },
+}
+
+impl InputMethod {
+    /// Returns whether the [`InputMethod`] is enabled or not.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+
+    /// Merges two [`InputMethod`] requests into one.
+    ///
+    /// An enabled request takes precedence over a disabled one.
+    pub fn merge(self, other: Self) -> Self {
+        match other {
+            Self::Enabled { .. } => other,
+            Self::Disabled => self,
+        }
+    }
+}
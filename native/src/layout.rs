@@ -1,9 +1,11 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
 pub mod flex;
 
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -53,6 +55,12 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Direction`] the [`Node`] of the [`Layout`] was laid out
+    /// with.
+    pub fn direction(&self) -> Direction {
+        self.node.direction()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
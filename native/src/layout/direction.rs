@@ -0,0 +1,20 @@
+/// The direction in which content flows horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content starts at the left, like in English.
+    #[default]
+    LeftToRight,
+
+    /// Content starts at the right, like in Arabic or Hebrew.
+    ///
+    /// Containers mirror the positions of their children horizontally, which
+    /// reverses the order of a row and swaps left and right paddings and
+    /// alignments. Sliders, pick lists, menus and the adornments of a text
+    /// input are mirrored as well, and horizontal scrollables start at their
+    /// right edge.
+    ///
+    /// The text inside of a text input, the editors of a data grid and the
+    /// overlays of tooltips, floating action buttons, toolbars, date pickers
+    /// and draggables are still laid out from left to right.
+    RightToLeft,
+}
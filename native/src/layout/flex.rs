@@ -54,8 +54,57 @@ impl Axis {
     }
 }
 
+/// The way the free space on the main axis of a flex layout is distributed
+/// between its items.
+///
+/// The space is only free when none of the items fill the main axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Distribution {
+    /// Pack the items at the start of the axis.
+    #[default]
+    Start,
+
+    /// Place the first and last items at the edges of the axis and
+    /// distribute the free space evenly between the items.
+    SpaceBetween,
+
+    /// Give every item the same amount of free space on both of its sides,
+    /// so the space at the edges is half the space between the items.
+    SpaceAround,
+
+    /// Distribute the free space evenly between the items and the edges of
+    /// the axis.
+    SpaceEvenly,
+}
+
+impl Distribution {
+    /// Returns the space before the first item and the extra space between
+    /// consecutive items, given the free space and the amount of items.
+    fn spread(self, free: f32, items: usize) -> (f32, f32) {
+        if items == 0 || !free.is_finite() || free <= 0.0 {
+            return (0.0, 0.0);
+        }
+
+        match self {
+            Distribution::Start => (0.0, 0.0),
+            Distribution::SpaceBetween if items == 1 => (0.0, 0.0),
+            Distribution::SpaceBetween => (0.0, free / (items - 1) as f32),
+            Distribution::SpaceAround => {
+                let gap = free / items as f32;
+
+                (gap / 2.0, gap)
+            }
+            Distribution::SpaceEvenly => {
+                let gap = free / (items + 1) as f32;
+
+                (gap, gap)
+            }
+        }
+    }
+}
+
 /// Computes the flex layout with the given axis and limits, applying spacing,
-/// padding and alignment to the items as needed.
+/// padding, alignment and distribution to the items as needed.
 ///
 /// It returns a new layout [`Node`].
 pub fn resolve<Message, Renderer>(
@@ -65,6 +114,7 @@ pub fn resolve<Message, Renderer>(
     padding: Padding,
     spacing: f32,
     align_items: Alignment,
+    distribution: Distribution,
     items: &[Element<'_, Message, Renderer>],
 ) -> Node
 where
@@ -84,25 +134,79 @@ where
     if align_items == Alignment::Fill {
         let mut fill_cross = axis.cross(limits.min());
 
-        items.iter().for_each(|child| {
-            let cross_fill_factor = match axis {
-                Axis::Horizontal => child.as_widget().height(),
-                Axis::Vertical => child.as_widget().width(),
+        match axis {
+            Axis::Horizontal => {
+                // The height of some content, like wrapping text, depends on
+                // its width; so the widths the items will be given are
+                // resolved first, and then their heights measured with them.
+                // Items filling the cross axis do not take part in it.
+                let mut remaining = available;
+
+                let fill_portions: u16 = items
+                    .iter()
+                    .map(|child| child.as_widget().width().fill_factor())
+                    .sum();
+
+                let widths: Vec<_> = items
+                    .iter()
+                    .filter(|child| {
+                        child.as_widget().height().fill_factor() == 0
+                    })
+                    .map(|child| {
+                        if child.as_widget().width().fill_factor() == 0 {
+                            let child_limits = Limits::new(
+                                Size::ZERO,
+                                Size::new(remaining, max_cross),
+                            )
+                            .with_direction(limits.direction());
+
+                            let width = child
+                                .as_widget()
+                                .layout(renderer, &child_limits)
+                                .size()
+                                .width;
+
+                            remaining -= width;
+
+                            (child, Some(width))
+                        } else {
+                            (child, None)
+                        }
+                    })
+                    .collect();
+
+                let remaining = remaining.max(0.0);
+
+                for (child, width) in widths {
+                    let width = width.unwrap_or_else(|| {
+                        remaining
+                            * child.as_widget().width().fill_factor() as f32
+                            / fill_portions as f32
+                    });
+
+                    let height =
+                        child.as_widget().height_for_width(renderer, width);
+
+                    fill_cross = fill_cross.max(height.min(max_cross));
+                }
             }
-            .fill_factor();
-
-            if cross_fill_factor == 0 {
-                let (max_width, max_height) = axis.pack(available, max_cross);
-
-                let child_limits =
-                    Limits::new(Size::ZERO, Size::new(max_width, max_height));
-
-                let layout = child.as_widget().layout(renderer, &child_limits);
-                let size = layout.size();
-
-                fill_cross = fill_cross.max(axis.cross(size));
+            Axis::Vertical => {
+                items.iter().for_each(|child| {
+                    if child.as_widget().width().fill_factor() == 0 {
+                        let child_limits = Limits::new(
+                            Size::ZERO,
+                            Size::new(max_cross, available),
+                        )
+                        .with_direction(limits.direction());
+
+                        let layout =
+                            child.as_widget().layout(renderer, &child_limits);
+
+                        fill_cross = fill_cross.max(layout.size().width);
+                    }
+                });
             }
-        });
+        }
 
         cross = fill_cross;
     }
@@ -130,7 +234,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.as_widget().layout(renderer, &child_limits);
             let size = layout.size();
@@ -179,7 +284,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.as_widget().layout(renderer, &child_limits);
 
@@ -191,32 +297,99 @@ where
         }
     }
 
+    // Items may override the alignment of the layout
+    let alignments: Vec<Alignment> = items
+        .iter()
+        .map(|child| child.as_widget().align_self().unwrap_or(align_items))
+        .collect();
+
+    // The text baselines of the items of a horizontal layout aligned to them
+    // are lined up with the lowest one, which may push other items down
+    let ascent = match axis {
+        Axis::Horizontal => nodes
+            .iter()
+            .zip(&alignments)
+            .filter(|(_, alignment)| **alignment == Alignment::Baseline)
+            .filter_map(|(node, _)| node.baseline())
+            .reduce(f32::max),
+        Axis::Vertical => None,
+    };
+
+    if let Some(ascent) = ascent {
+        for (node, alignment) in nodes.iter().zip(&alignments) {
+            let shift = match (alignment, node.baseline()) {
+                (Alignment::Baseline, Some(baseline)) => ascent - baseline,
+                _ => 0.0,
+            };
+
+            cross = cross.max(shift + node.size().height);
+        }
+    }
+
+    // Items that fill the cross axis on their own, or that opt out of
+    // filling it, are laid out again now that the cross axis is known
+    for ((child, node), alignment) in
+        items.iter().zip(&mut nodes).zip(&alignments)
+    {
+        if *alignment != align_items
+            && (*alignment == Alignment::Fill || align_items == Alignment::Fill)
+        {
+            let main = axis.main(node.size());
+            let min_cross = if *alignment == Alignment::Fill {
+                cross
+            } else {
+                0.0
+            };
+
+            let (min_width, min_height) = axis.pack(main, min_cross);
+            let (max_width, max_height) = axis.pack(main, cross);
+
+            let child_limits = Limits::new(
+                Size::new(min_width, min_height),
+                Size::new(max_width, max_height),
+            )
+            .with_direction(limits.direction());
+
+            *node = child.as_widget().layout(renderer, &child_limits);
+        }
+    }
+
+    let intrinsic_main =
+        nodes.iter().map(|node| axis.main(node.size())).sum::<f32>()
+            + total_spacing;
+
+    let (width, height) = axis.pack(intrinsic_main, cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    let (leading, gap) =
+        distribution.spread(axis.main(size) - intrinsic_main, nodes.len());
+
     let pad = axis.pack(padding.left, padding.top);
-    let mut main = pad.0;
+    let mut main = pad.0 + leading;
 
-    for (i, node) in nodes.iter_mut().enumerate() {
+    for (i, (node, alignment)) in nodes.iter_mut().zip(&alignments).enumerate()
+    {
         if i > 0 {
-            main += spacing;
+            main += spacing + gap;
         }
 
         let (x, y) = axis.pack(main, pad.1);
 
+        let y = match (ascent, alignment, node.baseline()) {
+            (Some(ascent), Alignment::Baseline, Some(baseline)) => {
+                y + ascent - baseline
+            }
+            _ => y,
+        };
+
         node.move_to(Point::new(x, y));
 
         match axis {
             Axis::Horizontal => {
-                node.align(
-                    Alignment::Start,
-                    align_items,
-                    Size::new(0.0, cross),
-                );
+                node.align(Alignment::Start, *alignment, Size::new(0.0, cross));
             }
             Axis::Vertical => {
-                node.align(
-                    align_items,
-                    Alignment::Start,
-                    Size::new(cross, 0.0),
-                );
+                node.align(*alignment, Alignment::Start, Size::new(cross, 0.0));
             }
         }
 
@@ -225,8 +398,139 @@ where
         main += axis.main(size);
     }
 
-    let (width, height) = axis.pack(main - pad.0, cross);
-    let size = limits.resolve(Size::new(width, height));
+    let baseline = nodes.iter().find_map(|node| {
+        node.baseline().map(|baseline| node.bounds().y + baseline)
+    });
+    let mut node = Node::with_children(size.pad(padding), nodes);
+    node.mirror(limits.direction());
+
+    match baseline {
+        Some(baseline) => node.with_baseline(baseline),
+        None => node,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::{self, Null};
+    use crate::widget::{Space, Tree, Widget};
+    use crate::{Layout, Length, Rectangle};
+
+    use std::cell::Cell;
+
+    /// A line of text, 100 units wide, that wraps to fit its width.
+    struct Paragraph;
+
+    impl Widget<(), Null> for Paragraph {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn layout(&self, _renderer: &Null, limits: &Limits) -> Node {
+            let limits = limits.width(self.width()).height(self.height());
+            let width = limits.max().width.min(100.0);
+            let lines = (100.0 / width).ceil();
+
+            Node::new(limits.resolve(Size::new(width, lines * 20.0)))
+        }
+
+        fn draw(
+            &self,
+            _state: &Tree,
+            _renderer: &mut Null,
+            _theme: &crate::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    /// A widget filling the height of its container, counting its layouts.
+    struct Column<'a> {
+        layouts: &'a Cell<usize>,
+    }
 
-    Node::with_children(size.pad(padding), nodes)
+    impl<'a> Widget<(), Null> for Column<'a> {
+        fn width(&self) -> Length {
+            Length::Fixed(10.0)
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(&self, _renderer: &Null, limits: &Limits) -> Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            let limits = limits.width(self.width()).height(self.height());
+
+            Node::new(limits.resolve(Size::ZERO))
+        }
+
+        fn draw(
+            &self,
+            _state: &Tree,
+            _renderer: &mut Null,
+            _theme: &crate::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn row(items: &[Element<'_, (), Null>]) -> Node {
+        resolve(
+            Axis::Horizontal,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY))
+                .width(Length::Fill)
+                .height(Length::Shrink),
+            Padding::ZERO,
+            0.0,
+            Alignment::Fill,
+            Distribution::Start,
+            items,
+        )
+    }
+
+    #[test]
+    fn filled_row_measures_wrapping_content_with_its_width() {
+        let items = [
+            Element::new(Space::new(Length::Fixed(60.0), Length::Shrink)),
+            Element::new(Paragraph),
+        ];
+
+        let node = row(&items);
+        let paragraph = &node.children()[1];
+
+        // The paragraph is given 40 units, so it wraps into three lines
+        assert_eq!(paragraph.bounds().width, 40.0);
+        assert_eq!(paragraph.bounds().height, 60.0);
+        assert_eq!(node.size().height, 60.0);
+    }
+
+    #[test]
+    fn filled_row_does_not_measure_items_filling_it() {
+        let layouts = Cell::new(0);
+
+        let items = [
+            Element::new(Paragraph),
+            Element::new(Column { layouts: &layouts }),
+        ];
+
+        let node = row(&items);
+
+        assert_eq!(node.children()[1].bounds().height, 20.0);
+        assert_eq!(layouts.get(), 1);
+    }
 }
@@ -1,12 +1,14 @@
 #![allow(clippy::manual_clamp)]
+use crate::layout::Direction;
 use crate::{Length, Padding, Size};
 
 /// A set of size constraints for layouting.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Limits {
     min: Size,
     max: Size,
     fill: Size,
+    direction: Direction,
 }
 
 impl Limits {
@@ -15,6 +17,7 @@ impl Limits {
         min: Size::ZERO,
         max: Size::INFINITY,
         fill: Size::INFINITY,
+        direction: Direction::LeftToRight,
     };
 
     /// Creates new [`Limits`] with the given minimum and maximum [`Size`].
@@ -23,6 +26,7 @@ impl Limits {
             min,
             max,
             fill: Size::INFINITY,
+            direction: Direction::LeftToRight,
         }
     }
 
@@ -41,6 +45,18 @@ impl Limits {
         self.fill
     }
 
+    /// Returns the [`Direction`] of the [`Limits`].
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets the [`Direction`] of the content laid out with the [`Limits`].
+    pub fn with_direction(mut self, direction: Direction) -> Limits {
+        self.direction = direction;
+
+        self
+    }
+
     /// Applies a width constraint to the current [`Limits`].
     pub fn width(mut self, width: impl Into<Length>) -> Limits {
         match width.into() {
@@ -134,7 +150,12 @@ impl Limits {
             (self.fill.height - size.height).max(0.0),
         );
 
-        Limits { min, max, fill }
+        Limits {
+            min,
+            max,
+            fill,
+            direction: self.direction,
+        }
     }
 
     /// Removes the minimum width constraint for the current [`Limits`].
@@ -143,6 +164,7 @@ impl Limits {
             min: Size::ZERO,
             max: self.max,
             fill: self.fill,
+            direction: self.direction,
         }
     }
 
@@ -1,9 +1,12 @@
+use crate::layout::Direction;
 use crate::{Alignment, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
+    baseline: Option<f32>,
+    direction: Direction,
     children: Vec<Node>,
 }
 
@@ -22,6 +25,8 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            baseline: None,
+            direction: Direction::LeftToRight,
             children,
         }
     }
@@ -36,6 +41,45 @@ impl Node {
         self.bounds
     }
 
+    /// Sets the baseline of the [`Node`], measured from its top.
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Returns the baseline of the text of the [`Node`], if any, measured from
+    /// its top.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Records the [`Direction`] the [`Node`] was laid out with, so it can be
+    /// mirrored when drawn.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the [`Direction`] the [`Node`] was laid out with.
+    ///
+    /// Only widgets that draw differently in a right-to-left layout record
+    /// it; it is [`Direction::LeftToRight`] otherwise.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Mirrors the positions of the children of the [`Node`] horizontally
+    /// if the given [`Direction`] is right-to-left.
+    pub fn mirror(&mut self, direction: Direction) {
+        if direction == Direction::RightToLeft {
+            let width = self.bounds.width;
+
+            for child in &mut self.children {
+                child.bounds.x = width - child.bounds.x - child.bounds.width;
+            }
+        }
+    }
+
     /// Returns the children of the [`Node`].
     pub fn children(&self) -> &[Node] {
         &self.children
@@ -49,7 +93,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -62,7 +106,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
@@ -44,16 +44,20 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod benchmark;
 pub mod clipboard;
 pub mod command;
 pub mod event;
+pub mod fuzzy;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod program;
 pub mod renderer;
+pub mod shader;
 pub mod subscription;
 pub mod svg;
 pub mod system;
@@ -78,10 +82,11 @@ mod debug;
 mod debug;
 
 pub use iced_core::alignment;
+pub use iced_core::gradient;
 pub use iced_core::time;
 pub use iced_core::{
     color, Alignment, Background, Color, ContentFit, Font, Length, Padding,
-    Pixels, Point, Rectangle, Size, Vector,
+    Pixels, Point, Rectangle, Shadow, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 pub use iced_style::application;
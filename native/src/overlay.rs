@@ -1,12 +1,14 @@
 //! Display interactive elements on top of other widgets.
 mod element;
 mod group;
+mod memory;
 mod nested;
 
 pub mod menu;
 
 pub use element::Element;
 pub use group::Group;
+pub use memory::Memory;
 pub use menu::Menu;
 pub use nested::Nested;
 
@@ -192,6 +192,23 @@ where
                 });
             }
 
+            fn container_with_bounds(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.container_with_bounds(
+                    id,
+                    bounds,
+                    &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    },
+                );
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -208,6 +225,23 @@ where
                 self.operation.scrollable(state, id);
             }
 
+            fn scrollable_with_viewport(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+            ) {
+                self.operation.scrollable_with_viewport(
+                    state,
+                    id,
+                    bounds,
+                    content_bounds,
+                    translation,
+                );
+            }
+
             fn text_input(
                 &mut self,
                 state: &mut dyn widget::operation::TextInput,
@@ -0,0 +1,162 @@
+use crate::widget;
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// A cache remembering the state of the overlays of identified widgets, like
+/// the scroll position of the menu of a `PickList` or the open submenus of a
+/// `MenuBar`.
+///
+/// A widget given a [`Memory`] stores the state of its overlay in it when it
+/// is removed from the user interface, and resumes from it when added back
+/// with the same [`Id`]. The least recently stored states are evicted once the
+/// [`Memory`] is full.
+///
+/// A [`Memory`] is owned by the caller, usually in the state of the
+/// application. Cloning it produces a handle to the same cache.
+///
+/// [`Id`]: widget::Id
+#[derive(Clone)]
+pub struct Memory {
+    inner: Rc<RefCell<Inner>>,
+}
+
+struct Inner {
+    capacity: usize,
+    // From the least to the most recently stored
+    entries: Vec<(widget::Id, Box<dyn Any>)>,
+}
+
+impl Memory {
+    /// The default capacity of a [`Memory`].
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    /// Creates an empty [`Memory`] with the [`DEFAULT_CAPACITY`].
+    ///
+    /// [`DEFAULT_CAPACITY`]: Self::DEFAULT_CAPACITY
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates an empty [`Memory`] remembering the state of up to `capacity`
+    /// widgets.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                capacity,
+                entries: Vec::new(),
+            })),
+        }
+    }
+
+    /// Returns the amount of widgets remembered by the [`Memory`].
+    pub fn len(&self) -> usize {
+        self.inner.borrow().entries.len()
+    }
+
+    /// Returns true if the [`Memory`] does not remember any widget.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets the state of the widget with the given [`Id`](widget::Id).
+    pub fn forget(&self, id: &widget::Id) {
+        let mut inner = self.inner.borrow_mut();
+
+        inner.entries.retain(|(entry, _)| entry != id);
+    }
+
+    /// Forgets the state of every widget.
+    pub fn clear(&self) {
+        self.inner.borrow_mut().entries.clear();
+    }
+
+    /// Takes the state of type `T` remembered for the widget with the given
+    /// [`Id`](widget::Id), if any.
+    pub(crate) fn take<T: 'static>(&self, id: &widget::Id) -> Option<T> {
+        let mut inner = self.inner.borrow_mut();
+
+        let index = inner
+            .entries
+            .iter()
+            .position(|(entry, state)| entry == id && state.is::<T>())?;
+
+        let (_, state) = inner.entries.remove(index);
+
+        state.downcast().ok().map(|state| *state)
+    }
+
+    /// Remembers the given state for the widget with the given
+    /// [`Id`](widget::Id), evicting the least recently stored state if the
+    /// [`Memory`] is full.
+    pub(crate) fn store<T: 'static>(&self, id: widget::Id, state: T) {
+        // The memory may be already borrowed if a state is dropped while
+        // another one is being taken
+        let mut inner = match self.inner.try_borrow_mut() {
+            Ok(inner) => inner,
+            Err(_) => return,
+        };
+
+        inner.entries.retain(|(entry, _)| *entry != id);
+
+        if inner.capacity == 0 {
+            return;
+        }
+
+        while inner.entries.len() >= inner.capacity {
+            let _ = inner.entries.remove(0);
+        }
+
+        inner.entries.push((id, Box::new(state)));
+    }
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.borrow();
+
+        f.debug_struct("Memory")
+            .field("capacity", &inner.capacity)
+            .field("len", &inner.entries.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_recently_stored_states_are_evicted() {
+        let memory = Memory::with_capacity(2);
+
+        memory.store(widget::Id::new("a"), 1_usize);
+        memory.store(widget::Id::new("b"), 2_usize);
+        memory.store(widget::Id::new("a"), 3_usize);
+        memory.store(widget::Id::new("c"), 4_usize);
+
+        assert_eq!(memory.len(), 2);
+        assert_eq!(memory.take::<usize>(&widget::Id::new("b")), None);
+        assert_eq!(memory.take::<usize>(&widget::Id::new("a")), Some(3));
+        assert_eq!(memory.take::<usize>(&widget::Id::new("c")), Some(4));
+        assert!(memory.is_empty());
+    }
+
+    #[test]
+    fn states_of_another_type_are_not_taken() {
+        let memory = Memory::new();
+
+        memory.store(widget::Id::new("a"), 1_usize);
+
+        assert_eq!(memory.take::<String>(&widget::Id::new("a")), None);
+        assert_eq!(memory.take::<usize>(&widget::Id::new("a")), Some(1));
+    }
+}
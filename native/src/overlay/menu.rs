@@ -1,6 +1,7 @@
 //! Build and show dropdown menus.
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::fuzzy;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
@@ -9,7 +10,7 @@ use crate::text::{self, Text};
 use crate::touch;
 use crate::widget::container::{self, Container};
 use crate::widget::scrollable::{self, Scrollable};
-use crate::widget::Tree;
+use crate::widget::tree::{self, Tree};
 use crate::{
     Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, Vector, Widget,
@@ -30,8 +31,10 @@ where
     on_selected: &'a dyn Fn(T) -> Message,
     width: f32,
     padding: Padding,
+    direction: layout::Direction,
     text_size: Option<f32>,
     font: Renderer::Font,
+    highlight: Option<&'a str>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -57,8 +60,10 @@ where
             on_selected,
             width: 0.0,
             padding: Padding::ZERO,
+            direction: layout::Direction::LeftToRight,
             text_size: None,
             font: Default::default(),
+            highlight: None,
             style: Default::default(),
         }
     }
@@ -75,6 +80,15 @@ where
         self
     }
 
+    /// Sets the [`layout::Direction`] of the [`Menu`].
+    ///
+    /// In a right-to-left [`Menu`], the options are aligned to the right and
+    /// the scrollbar is placed on the left.
+    pub fn direction(mut self, direction: layout::Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the text size of the [`Menu`].
     pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
         self.text_size = Some(text_size.into().0);
@@ -87,6 +101,17 @@ where
         self
     }
 
+    /// Highlights the characters of each option that match the given
+    /// pattern.
+    ///
+    /// This is meant to be used when the options of the [`Menu`] have been
+    /// filtered with the same pattern. See the [`fuzzy`] module for the
+    /// matching rules.
+    pub fn highlight(mut self, pattern: &'a str) -> Self {
+        self.highlight = Some(pattern);
+        self
+    }
+
     /// Sets the style of the [`Menu`].
     pub fn style(
         mut self,
@@ -158,6 +183,21 @@ impl State {
     pub fn close(&mut self) {
         self.status = Status::Closed;
     }
+
+    /// Closes the [`Menu`] and stops any scrolling in progress, keeping its
+    /// scroll position for the next time it is opened.
+    pub(crate) fn rest(&mut self) {
+        self.close();
+
+        // The tree of the container holds the one of its scrollable
+        if let Some(scrollable) = self.tree.children.first_mut() {
+            if let tree::State::Some(state) = &mut scrollable.state {
+                if let Some(state) = state.downcast_mut::<scrollable::State>() {
+                    state.settle();
+                }
+            }
+        }
+    }
 }
 
 impl Default for State {
@@ -169,6 +209,44 @@ impl Default for State {
     }
 }
 
+/// Lays out an overlay attached to a target at the given position, like the
+/// one of a [`Menu`].
+///
+/// The overlay is placed under the target when there is more space below it
+/// than above it, and on top of it otherwise. The `layout` closure receives
+/// the [`layout::Limits`] of the chosen space.
+pub fn layout_attached(
+    bounds: Size,
+    position: Point,
+    target_height: f32,
+    layout: impl FnOnce(&layout::Limits) -> layout::Node,
+) -> layout::Node {
+    let space_below = bounds.height - (position.y + target_height);
+    let space_above = position.y;
+
+    let limits = layout::Limits::new(
+        Size::ZERO,
+        Size::new(
+            bounds.width - position.x,
+            if space_below > space_above {
+                space_below
+            } else {
+                space_above
+            },
+        ),
+    );
+
+    let mut node = layout(&limits);
+
+    node.move_to(if space_below > space_above {
+        position + Vector::new(0.0, target_height)
+    } else {
+        position - Vector::new(0.0, node.size().height)
+    });
+
+    node
+}
+
 struct Overlay<'a, Message, Renderer>
 where
     Renderer: crate::Renderer,
@@ -177,6 +255,7 @@ where
     state: &'a mut Tree,
     container: Container<'a, Message, Renderer>,
     width: f32,
+    direction: layout::Direction,
     target_height: f32,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
@@ -203,8 +282,10 @@ where
             on_selected,
             width,
             padding,
+            direction,
             font,
             text_size,
+            highlight,
             style,
         } = menu;
 
@@ -216,6 +297,7 @@ where
             font,
             text_size,
             padding,
+            highlight,
             style: style.clone(),
         }));
 
@@ -225,6 +307,7 @@ where
             state: &mut state.tree,
             container,
             width,
+            direction,
             target_height,
             style,
         }
@@ -243,31 +326,12 @@ where
         bounds: Size,
         position: Point,
     ) -> layout::Node {
-        let space_below = bounds.height - (position.y + self.target_height);
-        let space_above = position.y;
-
-        let limits = layout::Limits::new(
-            Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
-        )
-        .width(self.width);
-
-        let mut node = self.container.layout(renderer, &limits);
-
-        node.move_to(if space_below > space_above {
-            position + Vector::new(0.0, self.target_height)
-        } else {
-            position - Vector::new(0.0, node.size().height)
-        });
-
-        node
+        layout_attached(bounds, position, self.target_height, |limits| {
+            self.container.layout(
+                renderer,
+                &limits.width(self.width).with_direction(self.direction),
+            )
+        })
     }
 
     fn on_event(
@@ -317,7 +381,7 @@ where
         let appearance = theme.appearance(&self.style);
         let bounds = layout.bounds();
 
-        renderer.fill_quad(
+        renderer.fill_quad_with_shadow(
             renderer::Quad {
                 bounds,
                 border_color: appearance.border_color,
@@ -325,6 +389,7 @@ where
                 border_radius: appearance.border_radius.into(),
             },
             appearance.background,
+            appearance.shadow,
         );
 
         self.container.draw(
@@ -351,6 +416,7 @@ where
     padding: Padding,
     text_size: Option<f32>,
     font: Renderer::Font,
+    highlight: Option<&'a str>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -390,7 +456,7 @@ where
             limits.resolve(intrinsic)
         };
 
-        layout::Node::new(size)
+        layout::Node::new(size).with_direction(limits.direction())
     }
 
     fn on_event(
@@ -529,24 +595,75 @@ where
                 );
             }
 
-            renderer.fill_text(Text {
-                content: &option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x + self.padding.left,
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
-                },
-                size: text_size,
-                font: self.font.clone(),
-                color: if is_selected {
-                    appearance.selected_text_color
-                } else {
-                    appearance.text_color
-                },
-                horizontal_alignment: alignment::Horizontal::Left,
-                vertical_alignment: alignment::Vertical::Center,
-            });
+            let label = option.to_string();
+            let matches = self
+                .highlight
+                .and_then(|pattern| fuzzy::find(pattern, &label))
+                .map(|found| found.ranges)
+                .unwrap_or_default();
+
+            let (color, highlighted_color) = if is_selected {
+                (
+                    appearance.selected_text_color,
+                    appearance.selected_highlighted_text_color,
+                )
+            } else {
+                (appearance.text_color, appearance.highlighted_text_color)
+            };
+
+            let mut segments = Vec::with_capacity(matches.len() * 2 + 1);
+            let mut start = 0;
+
+            for range in matches {
+                segments.push((start..range.start, false));
+                start = range.end;
+                segments.push((range, true));
+            }
+
+            segments.push((start..label.len(), false));
+
+            let x = if layout.direction() == layout::Direction::RightToLeft {
+                bounds.x + bounds.width
+                    - self.padding.right
+                    - renderer.measure_width(
+                        &label,
+                        text_size,
+                        self.font.clone(),
+                    )
+            } else {
+                bounds.x + self.padding.left
+            };
+
+            for (range, is_highlighted) in
+                segments.into_iter().filter(|(range, _)| !range.is_empty())
+            {
+                // Measuring the whole prefix keeps the kerning between
+                // segments
+                let offset = renderer.measure_width(
+                    &label[..range.start],
+                    text_size,
+                    self.font.clone(),
+                );
+
+                renderer.fill_text(Text {
+                    content: &label[range],
+                    bounds: Rectangle {
+                        x: x + offset,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: text_size,
+                    font: self.font.clone(),
+                    color: if is_highlighted {
+                        highlighted_color
+                    } else {
+                        color
+                    },
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
         }
     }
 }
@@ -99,7 +99,7 @@ where
                     })
                     .unwrap_or_default();
 
-                renderer.with_layer(layout.bounds(), |renderer| {
+                renderer.with_shadowed_layer(layout.bounds(), |renderer| {
                     let cursor_position = if is_over {
                         Point::new(-1.0, -1.0)
                     } else {
@@ -5,7 +5,7 @@ mod null;
 pub use null::Null;
 
 use crate::layout;
-use crate::{Background, Color, Element, Rectangle, Vector};
+use crate::{Background, Color, Element, Rectangle, Shadow, Vector};
 
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer: Sized {
@@ -29,6 +29,21 @@ pub trait Renderer: Sized {
     /// The layer will clip its contents to the provided `bounds`.
     fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self));
 
+    /// Draws the primitives recorded in the given closure in a new layer,
+    /// like [`with_layer`], but lets the shadows cast inside of the layer
+    /// spill out of the provided `bounds`.
+    ///
+    /// By default, the shadows are clipped to the `bounds` as well.
+    ///
+    /// [`with_layer`]: Self::with_layer
+    fn with_shadowed_layer(
+        &mut self,
+        bounds: Rectangle,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.with_layer(bounds, f);
+    }
+
     /// Applies a `translation` to the primitives recorded in the given closure.
     fn with_translation(
         &mut self,
@@ -39,6 +54,19 @@ pub trait Renderer: Sized {
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
+    /// Fills a [`Quad`] with the provided [`Background`], casting the given
+    /// [`Shadow`] behind it.
+    ///
+    /// By default, the [`Shadow`] is ignored.
+    fn fill_quad_with_shadow(
+        &mut self,
+        quad: Quad,
+        background: impl Into<Background>,
+        _shadow: Shadow,
+    ) {
+        self.fill_quad(quad, background);
+    }
+
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
 }
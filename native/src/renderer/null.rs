@@ -58,6 +58,10 @@ impl text::Renderer for Null {
         (0.0, 20.0)
     }
 
+    fn ascent(&self, _size: f32, _font: Font) -> f32 {
+        16.0
+    }
+
     fn hit_test(
         &self,
         _contents: &str,
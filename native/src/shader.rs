@@ -0,0 +1,97 @@
+//! Draw custom shaders.
+use crate::{Hasher, Rectangle};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher as _};
+use std::sync::Arc;
+
+/// A handle of the source code of a custom shader.
+///
+/// The source is the WGSL code of a fragment shader with an `fs_main` entry
+/// point. The renderer supplies the vertex stage, which covers the bounds of
+/// the shader, together with the following declarations:
+///
+/// ```wgsl
+/// struct Globals {
+///     // The projection of the viewport
+///     transform: mat4x4<f32>,
+///     // The bounds of the shader, in physical pixels
+///     bounds: vec4<f32>,
+///     // The scale factor of the viewport
+///     scale: f32,
+/// }
+///
+/// @group(0) @binding(0) var<uniform> globals: Globals;
+///
+/// struct VertexOutput {
+///     @builtin(position) position: vec4<f32>,
+///     // The position inside the bounds, in [0.0, 1.0]
+///     @location(0) uv: vec2<f32>,
+/// }
+/// ```
+///
+/// The uniforms of the shader are bound to `@group(0) @binding(1)`, and can
+/// be declared with any type matching their layout.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::shader::Handle;
+/// #
+/// let gradient = Handle::from_wgsl(
+///     "
+///     @group(0) @binding(1) var<uniform> color: vec4<f32>;
+///
+///     @fragment
+///     fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+///         return vec4<f32>(color.rgb * input.uv.x, color.a);
+///     }
+///     ",
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    source: Arc<Cow<'static, str>>,
+}
+
+impl Handle {
+    /// Creates a shader [`Handle`] from the WGSL source code of a fragment
+    /// shader.
+    pub fn from_wgsl(source: impl Into<Cow<'static, str>>) -> Handle {
+        let source = source.into();
+
+        let mut hasher = Hasher::default();
+        source.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            source: Arc::new(source),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the WGSL source code of the [`Handle`].
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl Hash for Handle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// A [`Renderer`] that can draw custom shaders.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws the shader of the given [`Handle`] inside the provided `bounds`,
+    /// binding the given uniforms.
+    fn draw(&mut self, handle: Handle, bounds: Rectangle, uniforms: Vec<f32>);
+}
@@ -1,4 +1,6 @@
+use crate::input_method::InputMethod;
 use crate::window;
+use crate::Rectangle;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +12,7 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +23,7 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +57,17 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the input method of the system to be enabled, with its
+    /// candidate window placed next to the given text cursor bounds.
+    pub fn request_input_method(&mut self, cursor: Rectangle) {
+        self.input_method = InputMethod::Enabled { cursor };
+    }
+
+    /// Returns the [`InputMethod`] requested by the widgets.
+    pub fn input_method(&self) -> InputMethod {
+        self.input_method
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +114,8 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.input_method = self.input_method.merge(other.input_method);
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
@@ -11,6 +11,7 @@ use std::sync::Arc;
 pub struct Handle {
     id: u64,
     data: Arc<Data>,
+    variables: Vec<(String, Color)>,
 }
 
 impl Handle {
@@ -36,6 +37,29 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data: Arc::new(data),
+            variables: Vec::new(),
+        }
+    }
+
+    /// Returns a [`Handle`] to the same SVG that replaces its CSS variables
+    /// with the given colors, by name.
+    ///
+    /// For instance, a `("accent", color)` pair replaces every
+    /// `var(--accent)` of the SVG. The variables are replaced before the SVG
+    /// is parsed, so they can be used in attributes and stylesheets alike.
+    pub fn with_variables(&self, variables: &[(String, Color)]) -> Handle {
+        let mut hasher = Hasher::default();
+        self.data.hash(&mut hasher);
+
+        for (name, color) in variables {
+            name.hash(&mut hasher);
+            color.into_rgba8().hash(&mut hasher);
+        }
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::clone(&self.data),
+            variables: variables.to_vec(),
         }
     }
 
@@ -48,6 +72,11 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the colors replacing the CSS variables of the SVG, by name.
+    pub fn variables(&self) -> &[(String, Color)] {
+        &self.variables
+    }
 }
 
 impl Hash for Handle {
@@ -85,6 +85,16 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of text with the given size
+    /// and font to its baseline.
+    ///
+    /// By default, it is approximated as 80% of the size of the text.
+    fn ascent(&self, size: f32, font: Self::Font) -> f32 {
+        let _ = font;
+
+        size * 0.8
+    }
+
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: f32, font: Self::Font) -> f32 {
         let (width, _) = self.measure(content, size, font, Size::INFINITY);
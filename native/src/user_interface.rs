@@ -1,5 +1,6 @@
 //! Implement your own event loop to drive a user interface.
 use crate::event::{self, Event};
+use crate::input_method::InputMethod;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -191,6 +192,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -230,6 +232,8 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -327,6 +331,8 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 shell.revalidate_layout(|| {
                     self.base = renderer.layout(
                         &self.root,
@@ -348,7 +354,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -513,15 +522,18 @@ where
 
                         let overlay_bounds = layout.bounds();
 
-                        renderer.with_layer(overlay_bounds, |renderer| {
-                            overlay.draw(
-                                renderer,
-                                theme,
-                                style,
-                                Layout::new(layout),
-                                cursor_position,
-                            );
-                        });
+                        renderer.with_shadowed_layer(
+                            overlay_bounds,
+                            |renderer| {
+                                overlay.draw(
+                                    renderer,
+                                    theme,
+                                    style,
+                                    Layout::new(layout),
+                                    cursor_position,
+                                );
+                            },
+                        );
 
                         if overlay.is_over(
                             Layout::new(layout),
@@ -617,5 +629,8 @@ pub enum State {
     Updated {
         /// The [`Instant`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the widgets.
+        input_method: InputMethod,
     },
 }
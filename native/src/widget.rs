@@ -11,76 +11,188 @@
 //! source of inspiration.
 //!
 //! [renderer]: crate::renderer
+pub mod align;
+pub mod aspect_ratio;
+pub mod badge;
 pub mod button;
+pub mod calendar;
+pub mod card;
 pub mod checkbox;
+pub mod chip;
+pub mod code_editor;
+pub mod color_picker;
 pub mod column;
 pub mod container;
+pub mod data_grid;
+pub mod date_picker;
+pub mod draggable;
+pub mod drop_zone;
+pub mod fab;
+pub mod grid;
 pub mod helpers;
 pub mod image;
+pub mod knob;
+pub mod lazy_list;
+pub mod menu_bar;
+pub mod number_input;
 pub mod operation;
+pub mod pagination;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod positioned;
 pub mod progress_bar;
 pub mod radio;
+pub mod rating;
+pub mod reorderable;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented_button;
+pub mod selectable_text;
+pub mod shader;
+pub mod skeleton;
 pub mod slider;
 pub mod space;
+pub mod spinner;
+pub mod split;
+pub mod stack;
+pub mod stepper;
 pub mod svg;
+pub mod swipeable;
+pub mod table;
+pub mod tabs;
+pub mod tags_input;
 pub mod text;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
+pub mod toolbar;
 pub mod tooltip;
 pub mod tree;
+pub mod tree_view;
 pub mod vertical_slider;
 
 mod action;
+mod dnd;
 mod id;
 
+#[doc(no_inline)]
+pub use align::Align;
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
+#[doc(no_inline)]
+pub use badge::Badge;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use calendar::Calendar;
+#[doc(no_inline)]
+pub use card::Card;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use chip::Chip;
+#[doc(no_inline)]
+pub use code_editor::CodeEditor;
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use data_grid::DataGrid;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
+pub use fab::Fab;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use helpers::*;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use knob::Knob;
+#[doc(no_inline)]
+pub use lazy_list::LazyList;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use pagination::Pagination;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
+pub use positioned::Positioned;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rating::Rating;
+#[doc(no_inline)]
+pub use reorderable::Reorderable;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use segmented_button::SegmentedButton;
+#[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
+pub use shader::Shader;
+#[doc(no_inline)]
+pub use skeleton::Skeleton;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use split::Split;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
+pub use stepper::Stepper;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use swipeable::Swipeable;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tags_input::TagsInput;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
+pub use toolbar::Toolbar;
+#[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
 pub use tree::Tree;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
 
 pub use action::Action;
@@ -92,7 +204,9 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::{Clipboard, Layout, Length, Point, Rectangle, Shell};
+use crate::{
+    Alignment, Clipboard, Layout, Length, Point, Rectangle, Shell, Size,
+};
 
 /// A component that displays information and allows interaction.
 ///
@@ -136,6 +250,27 @@ where
         limits: &layout::Limits,
     ) -> layout::Node;
 
+    /// Returns the height of the [`Widget`] when it is given the provided
+    /// width.
+    ///
+    /// Layouts use this intrinsic measurement to size content whose height
+    /// depends on the width it is allocated, like wrapping text, before
+    /// laying it out. By default, the [`Widget`] is laid out to find out.
+    fn height_for_width(&self, renderer: &Renderer, width: f32) -> f32 {
+        let limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+
+        self.layout(renderer, &limits).size().height
+    }
+
+    /// Returns the [`Alignment`] of the [`Widget`] on the cross axis of a
+    /// [`Row`] or a [`Column`], overriding the alignment of the container.
+    ///
+    /// By default, a [`Widget`] follows the alignment of its container.
+    fn align_self(&self) -> Option<Alignment> {
+        None
+    }
+
     /// Draws the [`Widget`] using the associated `Renderer`.
     fn draw(
         &self,
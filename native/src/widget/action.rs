@@ -2,6 +2,7 @@ use crate::widget::operation::{
     self, Focusable, Operation, Scrollable, TextInput,
 };
 use crate::widget::Id;
+use crate::{Rectangle, Vector};
 
 use iced_futures::MaybeSend;
 
@@ -55,58 +56,26 @@ where
         id: Option<&Id>,
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
     ) {
-        struct MapRef<'a, A> {
-            operation: &'a mut dyn Operation<A>,
-        }
-
-        impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-            fn container(
-                &mut self,
-                id: Option<&Id>,
-                operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-            ) {
-                let Self { operation, .. } = self;
-
-                operation.container(id, &mut |operation| {
-                    operate_on_children(&mut MapRef { operation });
-                });
-            }
-
-            fn scrollable(
-                &mut self,
-                state: &mut dyn Scrollable,
-                id: Option<&Id>,
-            ) {
-                self.operation.scrollable(state, id);
-            }
-
-            fn focusable(
-                &mut self,
-                state: &mut dyn Focusable,
-                id: Option<&Id>,
-            ) {
-                self.operation.focusable(state, id);
-            }
-
-            fn text_input(
-                &mut self,
-                state: &mut dyn TextInput,
-                id: Option<&Id>,
-            ) {
-                self.operation.text_input(state, id);
-            }
+        let Self { operation, .. } = self;
 
-            fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                self.operation.custom(state, id);
-            }
+        MapRef {
+            operation: operation.as_mut(),
         }
+        .container(id, operate_on_children);
+    }
 
+    fn container_with_bounds(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
         let Self { operation, .. } = self;
 
         MapRef {
             operation: operation.as_mut(),
         }
-        .container(id, operate_on_children);
+        .container_with_bounds(id, bounds, operate_on_children);
     }
 
     fn focusable(
@@ -125,6 +94,23 @@ where
         self.operation.scrollable(state, id);
     }
 
+    fn scrollable_with_viewport(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable_with_viewport(
+            state,
+            id,
+            bounds,
+            content_bounds,
+            translation,
+        );
+    }
+
     fn text_input(
         &mut self,
         state: &mut dyn operation::TextInput,
@@ -152,3 +138,68 @@ where
         }
     }
 }
+
+/// Maps the children of a container operated on by a [`Map`].
+struct MapRef<'a, A> {
+    operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.container(id, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn container_with_bounds(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.container_with_bounds(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn scrollable_with_viewport(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable_with_viewport(
+            state,
+            id,
+            bounds,
+            content_bounds,
+            translation,
+        );
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+}
@@ -0,0 +1,176 @@
+//! Override the alignment of an element inside of a row or a column.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
+    Widget,
+};
+
+/// An element that overrides the cross axis alignment of its [`Row`] or
+/// [`Column`] for its content.
+///
+/// Other than that, the [`Align`] wrapper is transparent: it keeps the state
+/// of its content and does not change its layout.
+///
+/// [`Row`]: crate::widget::Row
+/// [`Column`]: crate::widget::Column
+#[allow(missing_debug_implementations)]
+pub struct Align<'a, Message, Renderer> {
+    alignment: Alignment,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Align<'a, Message, Renderer> {
+    /// Creates an [`Align`] wrapper with the given content and [`Alignment`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        alignment: Alignment,
+    ) -> Self {
+        Align {
+            alignment,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Align<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree)
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn height_for_width(&self, renderer: &Renderer, width: f32) -> f32 {
+        self.content.as_widget().height_for_width(renderer, width)
+    }
+
+    fn align_self(&self) -> Option<Alignment> {
+        Some(self.alignment)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            tree,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            tree,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Align<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(align: Align<'a, Message, Renderer>) -> Self {
+        Self::new(align)
+    }
+}
@@ -0,0 +1,270 @@
+//! Constrain content to a fixed aspect ratio.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+/// An element that constrains its content to a fixed width to height ratio.
+///
+/// The content is made as large as possible within the available space while
+/// keeping the ratio, and aligned inside of the remaining space.
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: f32,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer> {
+    /// Creates an [`AspectRatio`] with the given width to height ratio and
+    /// content.
+    ///
+    /// For instance, a ratio of `16.0 / 9.0` produces a wide content.
+    pub fn new(
+        ratio: f32,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        AspectRatio {
+            ratio: if ratio.is_finite() && ratio > 0.0 {
+                ratio
+            } else {
+                1.0
+            },
+            width: Length::Fill,
+            height: Length::Fill,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`AspectRatio`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`AspectRatio`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Centers the content of the [`AspectRatio`] horizontally.
+    pub fn center_x(mut self) -> Self {
+        self.horizontal_alignment = alignment::Horizontal::Center;
+        self
+    }
+
+    /// Centers the content of the [`AspectRatio`] vertically.
+    pub fn center_y(mut self) -> Self {
+        self.vertical_alignment = alignment::Vertical::Center;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+
+        let fitted = match (max.width.is_finite(), max.height.is_finite()) {
+            (true, true) => fit(self.ratio, max),
+            (true, false) => Size::new(max.width, max.width / self.ratio),
+            (false, true) => Size::new(max.height * self.ratio, max.height),
+            (false, false) => {
+                // Without any bounds, the intrinsic width of the content
+                // decides the size
+                let width = self
+                    .content
+                    .as_widget()
+                    .layout(renderer, &layout::Limits::NONE)
+                    .size()
+                    .width;
+
+                Size::new(width, width / self.ratio)
+            }
+        };
+
+        let size = limits.resolve(fitted);
+        let content_size = fit(
+            self.ratio,
+            Size::new(
+                fitted.width.min(size.width),
+                fitted.height.min(size.height),
+            ),
+        );
+
+        let mut content = self.content.as_widget().layout(
+            renderer,
+            &layout::Limits::new(content_size, content_size)
+                .with_direction(limits.direction()),
+        );
+
+        content.align(
+            Alignment::from(self.horizontal_alignment),
+            Alignment::from(self.vertical_alignment),
+            size,
+        );
+
+        let mut node = layout::Node::with_children(size, vec![content]);
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(aspect_ratio: AspectRatio<'a, Message, Renderer>) -> Self {
+        Self::new(aspect_ratio)
+    }
+}
+
+/// Returns the largest size with the given ratio that fits in `space`.
+fn fit(ratio: f32, space: Size) -> Size {
+    if space.width / space.height > ratio {
+        Size::new(space.height * ratio, space.height)
+    } else {
+        Size::new(space.width, space.width / ratio)
+    }
+}
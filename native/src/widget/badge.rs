@@ -0,0 +1,315 @@
+//! Attach a small count bubble to a corner of some content.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::widget::operation::Operation;
+use crate::widget::Tree;
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+pub use crate::widget::positioned::Anchor;
+pub use iced_style::badge::{Appearance, StyleSheet};
+
+/// Some content with a small bubble attached to one of its corners, usually
+/// displaying a count.
+///
+/// The bubble is centered on the [`Anchor`] and may overflow the bounds of
+/// the content. An empty label shows a plain dot.
+///
+/// # Example
+/// ```
+/// # type Badge<'a, Message> = iced_native::widget::Badge<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// # enum Message {}
+/// let unread = 3;
+///
+/// let badge: Badge<'_, Message> = Badge::new(Text::new("Inbox"), unread);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Badge<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    label: String,
+    anchor: Anchor,
+    offset: Vector,
+    text_size: Option<f32>,
+    padding: Padding,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Badge<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of the label of a [`Badge`].
+    pub const DEFAULT_TEXT_SIZE: f32 = 12.0;
+
+    /// The size of the dot displayed by a [`Badge`] without label.
+    pub const DOT_SIZE: f32 = 8.0;
+
+    /// Creates a new [`Badge`] attached to the given content, with the given
+    /// label.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        label: impl ToString,
+    ) -> Self {
+        Badge {
+            content: content.into(),
+            label: label.to_string(),
+            anchor: Anchor::TopRight,
+            offset: Vector::ZERO,
+            text_size: None,
+            padding: Padding::from([1.0, 5.0]),
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Anchor`] of the content the [`Badge`] is centered on.
+    ///
+    /// By default, it is the top right corner.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the offset of the [`Badge`] from its [`Anchor`].
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Badge`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the [`Padding`] of the label of the [`Badge`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Badge`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Badge`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label_size(&self) -> f32 {
+        self.text_size.unwrap_or(Self::DEFAULT_TEXT_SIZE)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Badge<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+
+        let bubble = if self.label.is_empty() {
+            Size::new(Self::DOT_SIZE, Self::DOT_SIZE)
+        } else {
+            let text_size = self.label_size();
+            let height = text_size + self.padding.vertical();
+            let width = renderer.measure_width(
+                &self.label,
+                text_size,
+                self.font.clone(),
+            ) + self.padding.horizontal();
+
+            // A single digit makes a circle
+            Size::new(width.max(height), height)
+        };
+
+        let corner = self.anchor.position(content.size(), Size::ZERO);
+
+        let mut bubble = layout::Node::new(bubble);
+        bubble.move_to(
+            corner
+                + self.offset
+                + Vector::new(-bubble.size().width, -bubble.size().height)
+                    * 0.5,
+        );
+
+        layout::Node::with_children(content.size(), vec![content, bubble])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let bubble = children.next().unwrap().bounds();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        let appearance = theme.appearance(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bubble,
+                border_radius: (bubble.height / 2.0).into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        if !self.label.is_empty() {
+            renderer.fill_text(Text {
+                content: &self.label,
+                bounds: Rectangle {
+                    x: bubble.center_x(),
+                    y: bubble.center_y(),
+                    ..bubble
+                },
+                size: self.label_size(),
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Badge<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(badge: Badge<'a, Message, Renderer>) -> Self {
+        Element::new(badge)
+    }
+}
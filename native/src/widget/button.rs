@@ -436,7 +436,16 @@ pub fn layout<Renderer>(
 
     content.move_to(Point::new(padding.left, padding.top));
 
-    layout::Node::with_children(size, vec![content])
+    let baseline = content
+        .baseline()
+        .map(|baseline| content.bounds().y + baseline);
+    let mut node = layout::Node::with_children(size, vec![content]);
+    node.mirror(limits.direction());
+
+    match baseline {
+        Some(baseline) => node.with_baseline(baseline),
+        None => node,
+    }
 }
 
 /// Returns the [`mouse::Interaction`] of a [`Button`].
@@ -0,0 +1,910 @@
+//! Display a month as a grid of days that can be selected.
+//!
+//! A [`Calendar`] has some local [`State`].
+pub use crate::widget::date_picker::{Date, Locale, Weekday};
+pub use iced_style::calendar::{Appearance, StyleSheet};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+/// The amount of rows of a [`Calendar`]: its header, the names of the
+/// weekdays and six weeks.
+const ROWS: usize = 8;
+
+/// A grid displaying the days of a month, which can be selected one at a time
+/// or as a range.
+///
+/// The displayed month can be changed with the arrows of its header, the
+/// mouse wheel, `PageUp` and `PageDown`. Once clicked, the days can also be
+/// navigated with the arrow keys and chosen with `Enter`.
+///
+/// # Example
+/// ```
+/// # pub type Calendar<'a, Message> = iced_native::widget::Calendar<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::calendar::{Date, Decoration};
+/// use iced_native::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DaySelected(Date),
+/// }
+///
+/// let today = Date::new(2023, 4, 12).unwrap();
+///
+/// let calendar = Calendar::new()
+///     .selected(Some(today))
+///     .on_select(Message::DaySelected)
+///     .week_numbers(true)
+///     .decorations(|date| {
+///         (date.day() == 20).then(|| Decoration::default().dot(Color::BLACK))
+///     });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    month: Option<Date>,
+    selected: Option<Date>,
+    on_select: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    range: Option<RangeInclusive<Date>>,
+    on_range: Option<Box<dyn Fn(RangeInclusive<Date>) -> Message + 'a>>,
+    on_navigate: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    decorations: Option<Box<dyn Fn(Date) -> Option<Decoration> + 'a>>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    today: Option<Date>,
+    week_numbers: bool,
+    locale: Locale,
+    font: Renderer::Font,
+    padding: Padding,
+    text_size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Calendar`].
+    ///
+    /// It displays the month of the selected day, or of the current day,
+    /// unless told otherwise with [`Calendar::month`].
+    pub fn new() -> Self {
+        Calendar {
+            month: None,
+            selected: None,
+            on_select: None,
+            range: None,
+            on_range: None,
+            on_navigate: None,
+            decorations: None,
+            min: None,
+            max: None,
+            is_disabled: None,
+            today: None,
+            week_numbers: false,
+            locale: Locale::default(),
+            font: Default::default(),
+            padding: Padding::new(5.0),
+            text_size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the selected day of the [`Calendar`].
+    pub fn selected(mut self, selected: Option<Date>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that should be produced when a day of the
+    /// [`Calendar`] is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the selected range of days of the [`Calendar`].
+    pub fn range(mut self, range: Option<RangeInclusive<Date>>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the message that should be produced when a range of days of the
+    /// [`Calendar`] is selected.
+    ///
+    /// This puts the [`Calendar`] in range mode: the first click picks one end
+    /// of the range and the second click the other one, while hovering the
+    /// days in between previews the range.
+    pub fn on_range(
+        mut self,
+        on_range: impl Fn(RangeInclusive<Date>) -> Message + 'a,
+    ) -> Self {
+        self.on_range = Some(Box::new(on_range));
+        self
+    }
+
+    /// Sets the month displayed by the [`Calendar`], given any of its days.
+    ///
+    /// When set, the displayed month is controlled by the application, which
+    /// should update it with the messages of [`Calendar::on_navigate`].
+    pub fn month(mut self, month: Date) -> Self {
+        self.month = Some(month.first_of_month());
+        self
+    }
+
+    /// Sets the message that should be produced when the user navigates to
+    /// another month, given its first day.
+    pub fn on_navigate(
+        mut self,
+        on_navigate: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+
+    /// Sets the function that decorates the days of the [`Calendar`], to
+    /// mark the days with events, for instance.
+    pub fn decorations(
+        mut self,
+        decorations: impl Fn(Date) -> Option<Decoration> + 'a,
+    ) -> Self {
+        self.decorations = Some(Box::new(decorations));
+        self
+    }
+
+    /// Sets the earliest day that can be selected in the [`Calendar`].
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest day that can be selected in the [`Calendar`].
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that tells whether a day cannot be selected.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the current day, which is highlighted in the [`Calendar`].
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Sets whether the [`Calendar`] displays the ISO week number of every
+    /// week.
+    pub fn week_numbers(mut self, week_numbers: bool) -> Self {
+        self.week_numbers = week_numbers;
+        self
+    }
+
+    /// Sets the [`Locale`] of the [`Calendar`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Calendar`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Calendar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Calendar`].
+    ///
+    /// Every day of the [`Calendar`] is twice as large as its text.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the [`Calendar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn is_selectable(&self, date: Date) -> bool {
+        !matches!(self.min, Some(min) if date < min)
+            && !matches!(self.max, Some(max) if date > max)
+            && !self.is_disabled.as_ref().map_or(false, |f| f(date))
+    }
+
+    /// Returns the month displayed when the [`Calendar`] is created.
+    fn initial_month(&self) -> Date {
+        self.month
+            .or(self.selected)
+            .or_else(|| self.range.as_ref().map(|range| *range.start()))
+            .or(self.today)
+            .unwrap_or(Date::EPOCH)
+            .first_of_month()
+    }
+
+    fn displayed_month(&self, state: &State) -> Date {
+        self.month.unwrap_or(state.month)
+    }
+
+    fn resolve_text_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn columns(&self) -> usize {
+        if self.week_numbers {
+            8
+        } else {
+            7
+        }
+    }
+
+    /// Returns the first day displayed in the given month, which may belong
+    /// to the previous month.
+    fn first_day(&self, month: Date) -> Date {
+        let offset = month.weekday().days_since(self.locale.first_weekday);
+
+        month.add_days(-(offset as i64))
+    }
+
+    /// Returns the bounds of the cell at the given row and column.
+    fn cell(
+        &self,
+        bounds: Rectangle,
+        cell_size: f32,
+        row: usize,
+        column: usize,
+    ) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left + column as f32 * cell_size,
+            y: bounds.y + self.padding.top + row as f32 * cell_size,
+            width: cell_size,
+            height: cell_size,
+        }
+    }
+
+    fn target_at(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cell_size: f32,
+        point: Point,
+    ) -> Option<Target> {
+        let x = point.x - bounds.x - self.padding.left;
+        let y = point.y - bounds.y - self.padding.top;
+
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let columns = self.columns();
+        let column = (x / cell_size) as usize;
+        let row = (y / cell_size) as usize;
+
+        if column >= columns || row >= ROWS {
+            return None;
+        }
+
+        let first_day = self.first_day(self.displayed_month(state));
+
+        match (row, column) {
+            (0, 0) => Some(Target::PreviousMonth),
+            (0, _) if column == columns - 1 => Some(Target::NextMonth),
+            (0 | 1, _) => None,
+            (_, 0) if self.week_numbers => None,
+            (week, column) => Some(Target::Day(
+                first_day
+                    .add_days(((week - 2) * 7 + column - (columns - 7)) as i64),
+            )),
+        }
+    }
+
+    /// Returns the focused day, moved to the displayed month if it is not
+    /// in it anymore.
+    fn focused(&self, state: &State) -> Date {
+        let month = self.displayed_month(state);
+
+        if state.focused.first_of_month() == month {
+            state.focused
+        } else {
+            month
+        }
+    }
+
+    /// Displays the month of the given day, notifying the application.
+    fn navigate(
+        &self,
+        state: &mut State,
+        month: Date,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let month = month.first_of_month();
+
+        if month == self.displayed_month(state) {
+            return;
+        }
+
+        state.month = month;
+
+        if let Some(on_navigate) = &self.on_navigate {
+            shell.publish(on_navigate(month));
+        }
+    }
+
+    /// Moves the focus to the given day, displaying its month.
+    fn focus(
+        &self,
+        state: &mut State,
+        date: Date,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.focused = date;
+        self.navigate(state, date, shell);
+    }
+
+    fn select(
+        &self,
+        state: &mut State,
+        date: Date,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.focused = date;
+
+        if let Some(on_range) = &self.on_range {
+            match state.anchor.take() {
+                Some(anchor) => {
+                    shell.publish(on_range(anchor.min(date)..=anchor.max(date)))
+                }
+                None => state.anchor = Some(date),
+            }
+        } else if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(date));
+        }
+    }
+
+    /// Returns the range of days to highlight, previewing the range being
+    /// selected if there is one.
+    fn highlighted_range(
+        &self,
+        state: &State,
+        hovered: Option<Date>,
+    ) -> Option<RangeInclusive<Date>> {
+        match state.anchor {
+            Some(anchor) => {
+                let end = hovered.unwrap_or(anchor);
+
+                Some(anchor.min(end)..=anchor.max(end))
+            }
+            None => self.range.clone(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Default for Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The marks drawn on a day of a [`Calendar`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Decoration {
+    /// The colors of the dots drawn under the day.
+    pub dots: Vec<Color>,
+    /// The [`Background`] of the day, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the day, if any.
+    pub text_color: Option<Color>,
+}
+
+impl Decoration {
+    /// Adds a dot of the given [`Color`] under the day.
+    pub fn dot(mut self, color: Color) -> Self {
+        self.dots.push(color);
+        self
+    }
+
+    /// Sets the [`Background`] of the day.
+    pub fn background(mut self, background: impl Into<Background>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    /// Sets the text [`Color`] of the day.
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+}
+
+/// The local state of a [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    month: Date,
+    focused: Date,
+    anchor: Option<Date>,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], displaying the month of the given day.
+    pub fn new(month: Date) -> Self {
+        Self {
+            month: month.first_of_month(),
+            focused: month,
+            anchor: None,
+            is_focused: false,
+        }
+    }
+
+    /// Returns whether the [`Calendar`] is focused.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the first end of the range being selected, if any.
+    pub fn anchor(&self) -> Option<Date> {
+        self.anchor
+    }
+}
+
+/// A part of a [`Calendar`] that can be clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    PreviousMonth,
+    NextMonth,
+    Day(Date),
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let month = self.initial_month();
+        let mut state = State::new(month);
+
+        state.focused = self.selected.unwrap_or(month);
+
+        tree::State::new(state)
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let cell_size = self.resolve_text_size(renderer) * 2.0;
+
+        let size = limits.resolve(Size::new(
+            cell_size * self.columns() as f32 + self.padding.horizontal(),
+            cell_size * ROWS as f32 + self.padding.vertical(),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let cell_size = self.resolve_text_size(renderer) * 2.0;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = bounds.contains(cursor_position);
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let month = self.displayed_month(state);
+
+                match self.target_at(state, bounds, cell_size, cursor_position)
+                {
+                    Some(Target::PreviousMonth) => {
+                        let date = self.focused(state).add_months(-1);
+
+                        self.focus(state, date, shell);
+                    }
+                    Some(Target::NextMonth) => {
+                        let date = self.focused(state).add_months(1);
+
+                        self.focus(state, date, shell);
+                    }
+                    Some(Target::Day(date)) if self.is_selectable(date) => {
+                        self.select(state, date, shell);
+
+                        // Clicking the days of other months displays them
+                        if date.first_of_month() != month {
+                            self.navigate(state, date, shell);
+                        }
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    let months = if y > 0.0 { -1 } else { 1 };
+
+                    let date = self.focused(state).add_months(months);
+
+                    self.focus(state, date, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                let focused = self.focused(state);
+
+                let date = match key_code {
+                    keyboard::KeyCode::Left => focused.add_days(-1),
+                    keyboard::KeyCode::Right => focused.add_days(1),
+                    keyboard::KeyCode::Up => focused.add_days(-7),
+                    keyboard::KeyCode::Down => focused.add_days(7),
+                    keyboard::KeyCode::PageUp => focused.add_months(-1),
+                    keyboard::KeyCode::PageDown => focused.add_months(1),
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if self.is_selectable(focused) {
+                            self.select(state, focused, shell);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Escape if state.anchor.is_some() => {
+                        state.anchor = None;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.focus(state, date, shell);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let cell_size = self.resolve_text_size(renderer) * 2.0;
+
+        match self.target_at(state, layout.bounds(), cell_size, cursor_position)
+        {
+            Some(Target::PreviousMonth | Target::NextMonth) => {
+                mouse::Interaction::Pointer
+            }
+            Some(Target::Day(date)) if self.is_selectable(date) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let text_size = self.resolve_text_size(renderer);
+        let cell_size = text_size * 2.0;
+        let columns = self.columns();
+        let offset = columns - 7;
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let label = |renderer: &mut Renderer,
+                     content: &str,
+                     bounds: Rectangle,
+                     color: Color| {
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        // Header
+        let month = self.displayed_month(state);
+        let title = self.cell(bounds, cell_size, 0, 1);
+
+        label(
+            renderer,
+            "<",
+            self.cell(bounds, cell_size, 0, 0),
+            appearance.text_color,
+        );
+        label(
+            renderer,
+            &format!(
+                "{} {}",
+                self.locale.months[usize::from(month.month() - 1)],
+                month.year()
+            ),
+            Rectangle {
+                width: cell_size * (columns - 2) as f32,
+                ..title
+            },
+            appearance.text_color,
+        );
+        label(
+            renderer,
+            ">",
+            self.cell(bounds, cell_size, 0, columns - 1),
+            appearance.text_color,
+        );
+
+        // Weekdays
+        for column in 0..7 {
+            let weekday =
+                Weekday::ALL[(self.locale.first_weekday.index() + column) % 7];
+
+            label(
+                renderer,
+                &self.locale.weekdays[weekday.index()],
+                self.cell(bounds, cell_size, 1, offset + column),
+                appearance.weekday_color,
+            );
+        }
+
+        // Days
+        let first_day = self.first_day(month);
+
+        let hovered =
+            match self.target_at(state, bounds, cell_size, cursor_position) {
+                Some(Target::Day(date)) if self.is_selectable(date) => {
+                    Some(date)
+                }
+                _ => None,
+            };
+
+        let range = self.highlighted_range(state, hovered);
+
+        for week in 0..6 {
+            let row_start = first_day.add_days(week as i64 * 7);
+
+            if self.week_numbers {
+                // Weeks are numbered after their thursday
+                let thursday = row_start.add_days(
+                    Weekday::Thursday.days_since(self.locale.first_weekday)
+                        as i64,
+                );
+
+                label(
+                    renderer,
+                    &thursday.iso_week().to_string(),
+                    self.cell(bounds, cell_size, 2 + week, 0),
+                    appearance.week_number_color,
+                );
+            }
+
+            for day in 0..7 {
+                let date = row_start.add_days(day as i64);
+                let cell = self.cell(bounds, cell_size, 2 + week, offset + day);
+                let decoration =
+                    self.decorations.as_ref().and_then(|f| f(date));
+
+                let is_selectable = self.is_selectable(date);
+                let is_today = self.today == Some(date);
+                let is_hovered = hovered == Some(date)
+                    || state.is_focused && self.focused(state) == date;
+
+                let (is_selected, is_in_range) = match &range {
+                    Some(range) => (
+                        *range.start() == date || *range.end() == date,
+                        range.contains(&date),
+                    ),
+                    None => (self.selected == Some(date), false),
+                };
+
+                if is_in_range && !is_selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: cell,
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.range_background,
+                    );
+                }
+
+                let background = if is_selected {
+                    Some(appearance.selected_background)
+                } else if is_hovered && is_selectable {
+                    Some(appearance.hovered_background)
+                } else {
+                    decoration
+                        .as_ref()
+                        .and_then(|decoration| decoration.background)
+                };
+
+                if background.is_some() || is_today {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: cell,
+                            border_radius: (cell_size / 2.0).into(),
+                            border_width: if is_today { 1.0 } else { 0.0 },
+                            border_color: appearance.today_border_color,
+                        },
+                        background.unwrap_or(Color::TRANSPARENT.into()),
+                    );
+                }
+
+                let color = if is_selected {
+                    appearance.selected_text_color
+                } else if !is_selectable {
+                    appearance.disabled_text_color
+                } else if let Some(color) = decoration
+                    .as_ref()
+                    .and_then(|decoration| decoration.text_color)
+                {
+                    color
+                } else if is_in_range {
+                    appearance.range_text_color
+                } else if date.first_of_month() != month {
+                    appearance.weekday_color
+                } else {
+                    appearance.text_color
+                };
+
+                label(renderer, &date.day().to_string(), cell, color);
+
+                if let Some(decoration) = &decoration {
+                    draw_dots(renderer, &decoration.dots, cell);
+                }
+            }
+        }
+    }
+}
+
+/// Draws the dots of a [`Decoration`], centered at the bottom of the given
+/// cell.
+fn draw_dots<Renderer>(renderer: &mut Renderer, dots: &[Color], cell: Rectangle)
+where
+    Renderer: crate::Renderer,
+{
+    let size = (cell.height / 8.0).max(2.0);
+    let width = size * (2 * dots.len()) as f32 - size;
+    let x = cell.center_x() - width / 2.0;
+    let y = cell.y + cell.height - size * 1.5;
+
+    for (i, color) in dots.iter().enumerate() {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: x + (2 * i) as f32 * size,
+                    y,
+                    width: size,
+                    height: size,
+                },
+                border_radius: (size / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            *color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Calendar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(calendar: Calendar<'a, Message, Renderer>) -> Self {
+        Element::new(calendar)
+    }
+}
@@ -0,0 +1,475 @@
+//! Group related content in a card with an optional header and footer.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::operation::Operation;
+use crate::widget::Tree;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::card::{Appearance, StyleSheet};
+
+/// A container with a body and an optional header and footer, casting a
+/// shadow.
+///
+/// The card is elevated while hovered, depending on its style.
+///
+/// # Example
+/// ```
+/// # type Card<'a, Message> = iced_native::widget::Card<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// # enum Message {}
+/// let card: Card<'_, Message> = Card::new(Text::new("It is sunny today."))
+///     .header(Text::new("Weather"))
+///     .footer(Text::new("Updated a minute ago"))
+///     .max_width(300);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Card<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    children: Vec<Element<'a, Message, Renderer>>,
+    has_header: bool,
+    has_footer: bool,
+    width: Length,
+    height: Length,
+    max_width: f32,
+    padding: Padding,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Card<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Card`] with the given body.
+    pub fn new(body: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Card {
+            children: vec![body.into()],
+            has_header: false,
+            has_footer: false,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: f32::INFINITY,
+            padding: Padding::new(10.0),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the header of the [`Card`].
+    pub fn header(
+        mut self,
+        header: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        if self.has_header {
+            self.children[0] = header.into();
+        } else {
+            self.children.insert(0, header.into());
+            self.has_header = true;
+        }
+
+        self
+    }
+
+    /// Sets the footer of the [`Card`].
+    pub fn footer(
+        mut self,
+        footer: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        if self.has_footer {
+            let last = self.children.len() - 1;
+            self.children[last] = footer.into();
+        } else {
+            self.children.push(footer.into());
+            self.has_footer = true;
+        }
+
+        self
+    }
+
+    /// Sets the width of the [`Card`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Card`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Card`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of every section of the [`Card`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Card`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn body_index(&self) -> usize {
+        usize::from(self.has_header)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Card<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .loose()
+            .max_width(self.max_width)
+            .width(self.width)
+            .height(self.height);
+
+        let padding = self.padding;
+        let content_limits = limits.pad(padding).loose();
+        let body_index = self.body_index();
+
+        // The header and the footer shrink, while the body takes the
+        // remaining space
+        let mut nodes: Vec<Option<layout::Node>> = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| {
+                (i != body_index).then(|| {
+                    child.as_widget().layout(renderer, &content_limits)
+                })
+            })
+            .collect();
+
+        let sections_height: f32 = nodes
+            .iter()
+            .flatten()
+            .map(|node| node.size().height + padding.vertical())
+            .sum();
+
+        let body_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                content_limits.max().width,
+                (limits.max().height - sections_height - padding.vertical())
+                    .max(0.0),
+            ),
+        )
+        .with_direction(limits.direction());
+
+        nodes[body_index] = Some(
+            self.children[body_index]
+                .as_widget()
+                .layout(renderer, &body_limits),
+        );
+
+        let mut nodes: Vec<layout::Node> =
+            nodes.into_iter().flatten().collect();
+
+        let content_width = nodes
+            .iter()
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max);
+
+        let content_height: f32 = nodes
+            .iter()
+            .map(|node| node.size().height + padding.vertical())
+            .sum();
+
+        let size = limits.resolve(Size::new(
+            content_width + padding.horizontal(),
+            content_height,
+        ));
+
+        // The footer sticks to the bottom when the card is taller than its
+        // contents
+        let mut y: f32 = 0.0;
+
+        for (i, node) in nodes.iter_mut().enumerate() {
+            let height = node.size().height + padding.vertical();
+
+            if self.has_footer && i == self.children.len() - 1 {
+                y = y.max(size.height - height);
+            }
+
+            node.move_to(Point::new(padding.left, y + padding.top));
+
+            y += height;
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = if bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let radius = appearance.border_radius;
+
+        renderer.fill_quad_with_shadow(
+            renderer::Quad {
+                bounds,
+                border_radius: radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+            appearance.shadow,
+        );
+
+        // The bounds of every section, including its padding
+        let sections: Vec<Rectangle> = layout
+            .children()
+            .map(|layout| {
+                let content = layout.bounds();
+
+                Rectangle {
+                    x: bounds.x,
+                    y: content.y - self.padding.top,
+                    width: bounds.width,
+                    height: content.height + self.padding.vertical(),
+                }
+            })
+            .collect();
+
+        let divider = |renderer: &mut Renderer, y: f32| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y,
+                        width: bounds.width,
+                        height: 1.0,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.divider_color,
+            );
+        };
+
+        if self.has_header {
+            let header = sections[0];
+
+            if let Some(background) = appearance.header_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: header,
+                        border_radius: [radius, radius, 0.0, 0.0].into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            divider(renderer, header.y + header.height - 1.0);
+        }
+
+        if self.has_footer {
+            let footer = Rectangle {
+                height: bounds.y + bounds.height
+                    - sections[sections.len() - 1].y,
+                ..sections[sections.len() - 1]
+            };
+
+            if let Some(background) = appearance.footer_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: footer,
+                        border_radius: [0.0, 0.0, radius, radius].into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            divider(renderer, footer.y);
+        }
+
+        if appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                Background::Color(Color::TRANSPARENT),
+            );
+        }
+
+        let style = renderer::Style {
+            text_color: appearance.text_color.unwrap_or(style.text_color),
+        };
+
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Card<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(card: Card<'a, Message, Renderer>) -> Self {
+        Element::new(card)
+    }
+}
@@ -0,0 +1,490 @@
+//! Display compact pills, optionally selectable or deletable.
+//!
+//! A [`Chip`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::chip::{Appearance, StyleSheet};
+
+/// A pill with a label, an optional leading icon and an optional delete
+/// button.
+///
+/// # Example
+/// ```
+/// # type Chip<'a, Message> = iced_native::widget::Chip<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToggleFilter,
+///     RemoveFilter,
+/// }
+///
+/// let chip = Chip::new("Rust")
+///     .selected(true)
+///     .on_press(Message::ToggleFilter)
+///     .on_delete(Message::RemoveFilter);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chip<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    label: String,
+    icon: Option<Element<'a, Message, Renderer>>,
+    on_press: Option<Message>,
+    on_delete: Option<Message>,
+    is_selected: bool,
+    text_size: Option<f32>,
+    padding: Padding,
+    spacing: f32,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Chip<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of a [`Chip`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 10.0,
+        bottom: 4.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Chip`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Chip {
+            label: label.into(),
+            icon: None,
+            on_press: None,
+            on_delete: None,
+            is_selected: false,
+            text_size: None,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 6.0,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the icon displayed before the label of the [`Chip`].
+    pub fn icon(
+        mut self,
+        icon: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Chip`] is pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the delete button of the
+    /// [`Chip`] is pressed.
+    ///
+    /// The delete button is only displayed when this message is set.
+    pub fn on_delete(mut self, message: Message) -> Self {
+        self.on_delete = Some(message);
+        self
+    }
+
+    /// Sets whether the [`Chip`] is selected.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Chip`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Chip`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the icon, the label and the delete button
+    /// of the [`Chip`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the label of the [`Chip`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Chip`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the bounds of the delete button, if any.
+    fn delete_bounds(
+        &self,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        if self.on_delete.is_none() {
+            return None;
+        }
+
+        let size = self.label_size(renderer);
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - size,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        })
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Chip<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.icon.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.icon {
+            Some(icon) => tree.diff_children(std::slice::from_ref(icon)),
+            None => tree.children.clear(),
+        }
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.label_size(renderer);
+        let limits = limits.width(Length::Shrink).height(Length::Shrink);
+
+        let icon = self.icon.as_ref().map(|icon| {
+            icon.as_widget()
+                .layout(renderer, &limits.pad(self.padding).loose())
+        });
+
+        let content_height = icon
+            .as_ref()
+            .map_or(text_size, |icon| icon.size().height.max(text_size));
+
+        let label_width =
+            renderer.measure_width(&self.label, text_size, self.font.clone());
+
+        let mut x = self.padding.left;
+        let mut children = Vec::new();
+
+        if let Some(mut icon) = icon {
+            let size = icon.size();
+
+            icon.move_to(Point::new(
+                x,
+                self.padding.top + (content_height - size.height) / 2.0,
+            ));
+
+            x += size.width + self.spacing;
+            children.push(icon);
+        }
+
+        x += label_width;
+
+        if self.on_delete.is_some() {
+            x += self.spacing + text_size;
+        }
+
+        let size = limits.resolve(Size::new(
+            x + self.padding.right,
+            content_height + self.padding.vertical(),
+        ));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let (Some(icon), Some(icon_layout)) =
+            (&self.icon, layout.children().next())
+        {
+            icon.as_widget().operate(
+                &mut tree.children[0],
+                icon_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let target = if self
+                    .delete_bounds(bounds, renderer)
+                    .map_or(false, |delete| delete.contains(cursor_position))
+                {
+                    Some(Target::Delete)
+                } else if self.on_press.is_some() {
+                    Some(Target::Chip)
+                } else {
+                    None
+                };
+
+                if target.is_some() {
+                    state.pressed = target;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(target) = state.pressed.take() {
+                    let is_over = match target {
+                        Target::Chip => bounds.contains(cursor_position),
+                        Target::Delete => self
+                            .delete_bounds(bounds, renderer)
+                            .map_or(false, |delete| {
+                                delete.contains(cursor_position)
+                            }),
+                    };
+
+                    let message = match target {
+                        Target::Chip => &self.on_press,
+                        Target::Delete => &self.on_delete,
+                    };
+
+                    if let (true, Some(message)) = (is_over, message) {
+                        shell.publish(message.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        let is_over_delete = self
+            .delete_bounds(bounds, renderer)
+            .map_or(false, |delete| delete.contains(cursor_position));
+
+        if is_over_delete
+            || (self.on_press.is_some() && bounds.contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let is_interactive =
+            self.on_press.is_some() || self.on_delete.is_some();
+
+        let appearance = if is_interactive && bounds.contains(cursor_position) {
+            StyleSheet::hovered(theme, &self.style, self.is_selected)
+        } else {
+            StyleSheet::active(theme, &self.style, self.is_selected)
+        };
+
+        if appearance.background.is_some() || appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: (bounds.height / 2.0).into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        }
+
+        let mut label_x = bounds.x + self.padding.left;
+
+        if let (Some(icon), Some(icon_layout)) =
+            (&self.icon, layout.children().next())
+        {
+            icon.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text_color,
+                },
+                icon_layout,
+                cursor_position,
+                viewport,
+            );
+
+            label_x = icon_layout.bounds().x
+                + icon_layout.bounds().width
+                + self.spacing;
+        }
+
+        let text_size = self.label_size(renderer);
+
+        renderer.fill_text(Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: label_x,
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: text_size,
+            color: appearance.text_color,
+            font: self.font.clone(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+
+        if let Some(delete) = self.delete_bounds(bounds, renderer) {
+            renderer.fill_text(Text {
+                content: "×",
+                bounds: Rectangle {
+                    x: delete.center_x(),
+                    y: delete.center_y(),
+                    ..delete
+                },
+                size: text_size,
+                color: appearance.delete_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Chip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(chip: Chip<'a, Message, Renderer>) -> Self {
+        Element::new(chip)
+    }
+}
+
+/// The local state of a [`Chip`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed: Option<Target>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Chip,
+    Delete,
+}
@@ -0,0 +1,789 @@
+//! Edit source code with syntax highlighting and line numbers.
+//!
+//! A [`CodeEditor`] has some local [`State`].
+pub mod content;
+pub mod highlighter;
+
+pub use content::{Action, Content, Edit, Motion, Position};
+pub use highlighter::Highlighter;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::code_editor::{Appearance, StyleSheet};
+
+/// A multi-line text editor for source code.
+///
+/// Every line is drawn with a monospace layout: each character is assumed to
+/// have the same advance as the letter `M` of the [`Font`] in use, so you will
+/// want to set a monospace font.
+///
+/// # Example
+/// ```
+/// # pub type CodeEditor<'a, Message> = iced_native::widget::CodeEditor<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::code_editor::{Action, Content};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Edit(Action),
+/// }
+///
+/// let content = Content::with_text("fn main() {}");
+///
+/// let editor = CodeEditor::new(&content).on_action(Message::Edit);
+/// ```
+///
+/// [`Font`]: text::Renderer::Font
+#[allow(missing_debug_implementations)]
+pub struct CodeEditor<
+    'a,
+    Message,
+    Renderer,
+    Highlighter = highlighter::PlainText,
+> where
+    Highlighter: self::Highlighter,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: &'a Content,
+    font: Renderer::Font,
+    size: Option<f32>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    line_numbers: bool,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    highlighter: Highlighter::Settings,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> CodeEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`CodeEditor`] showing the given [`Content`].
+    pub fn new(content: &'a Content) -> Self {
+        CodeEditor {
+            content,
+            font: Default::default(),
+            size: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(5.0),
+            line_numbers: true,
+            on_action: None,
+            highlighter: (),
+            style: Default::default(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer, Highlighter>
+    CodeEditor<'a, Message, Renderer, Highlighter>
+where
+    Highlighter: self::Highlighter,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Sets the function that produces a message when the user interacts
+    /// with the [`CodeEditor`].
+    ///
+    /// If this method is not called, the [`CodeEditor`] is read-only.
+    pub fn on_action(
+        mut self,
+        on_action: impl Fn(Action) -> Message + 'a,
+    ) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Highlights the [`CodeEditor`] with the given [`Highlighter`] and its
+    /// settings.
+    pub fn highlight<H: self::Highlighter>(
+        self,
+        settings: H::Settings,
+    ) -> CodeEditor<'a, Message, Renderer, H> {
+        CodeEditor {
+            content: self.content,
+            font: self.font,
+            size: self.size,
+            width: self.width,
+            height: self.height,
+            padding: self.padding,
+            line_numbers: self.line_numbers,
+            on_action: self.on_action,
+            highlighter: settings,
+            style: self.style,
+        }
+    }
+
+    /// Sets the [`Font`] of the [`CodeEditor`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the text size of the [`CodeEditor`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the width of the [`CodeEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`CodeEditor`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`CodeEditor`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether the gutter with line numbers is shown.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the style of the [`CodeEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer, bounds: Rectangle) -> Metrics {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let (char_width, line_height) =
+            renderer.measure("M", size, self.font.clone(), Size::INFINITY);
+
+        let gutter_width = if self.line_numbers {
+            let digits = self.content.line_count().to_string().len();
+
+            (digits as f32 + 2.0) * char_width
+        } else {
+            0.0
+        };
+
+        let text_bounds = Rectangle {
+            x: bounds.x + gutter_width + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: (bounds.width - gutter_width - self.padding.horizontal())
+                .max(0.0),
+            height: (bounds.height - self.padding.vertical()).max(0.0),
+        };
+
+        Metrics {
+            size,
+            char_width: char_width.max(1.0),
+            line_height: line_height.max(1.0),
+            gutter_width,
+            text_bounds,
+        }
+    }
+}
+
+struct Metrics {
+    size: f32,
+    char_width: f32,
+    line_height: f32,
+    gutter_width: f32,
+    text_bounds: Rectangle,
+}
+
+impl Metrics {
+    fn position(&self, point: Point, scroll: Vector) -> Position {
+        let x = point.x - self.text_bounds.x + scroll.x;
+        let y = point.y - self.text_bounds.y + scroll.y;
+
+        Position {
+            line: (y / self.line_height).max(0.0) as usize,
+            column: (x / self.char_width).round().max(0.0) as usize,
+        }
+    }
+
+    fn max_scroll(&self, content: &Content) -> Vector {
+        let longest = content
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Vector::new(
+            (longest as f32 * self.char_width + self.char_width
+                - self.text_bounds.width)
+                .max(0.0),
+            (content.line_count() as f32 * self.line_height
+                - self.text_bounds.height)
+                .max(0.0),
+        )
+    }
+
+    /// Returns the scroll offset that keeps the cursor of the [`Content`]
+    /// visible, starting from the given offset.
+    fn follow_cursor(&self, content: &Content, scroll: Vector) -> Vector {
+        let cursor = content.cursor();
+
+        let x = cursor.column as f32 * self.char_width;
+        let y = cursor.line as f32 * self.line_height;
+
+        Vector::new(
+            scroll
+                .x
+                .min(x)
+                .max(x + self.char_width - self.text_bounds.width),
+            scroll
+                .y
+                .min(y)
+                .max(y + self.line_height - self.text_bounds.height),
+        )
+    }
+}
+
+/// The local state of a [`CodeEditor`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    last_cursor: Position,
+    scroll: Vector,
+    keyboard_modifiers: keyboard::Modifiers,
+    highlights: highlighter::Cache,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`CodeEditor`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`CodeEditor`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the current scroll offset of the [`CodeEditor`].
+    pub fn scroll(&self) -> Vector {
+        self.scroll
+    }
+
+    /// Returns the scroll offset to use with the given [`Content`], which
+    /// follows its cursor whenever it moves.
+    fn offset(&self, content: &Content, metrics: &Metrics) -> Vector {
+        let scroll = if content.cursor() == self.last_cursor {
+            self.scroll
+        } else {
+            metrics.follow_cursor(content, self.scroll)
+        };
+
+        let max = metrics.max_scroll(content);
+
+        Vector::new(scroll.x.clamp(0.0, max.x), scroll.y.clamp(0.0, max.y))
+    }
+}
+
+impl<'a, Message, Renderer, Highlighter> Widget<Message, Renderer>
+    for CodeEditor<'a, Message, Renderer, Highlighter>
+where
+    Highlighter: self::Highlighter,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let (_, line_height) =
+            renderer.measure("M", size, self.font.clone(), Size::INFINITY);
+
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::new(
+            0.0,
+            self.content.line_count() as f32 * line_height
+                + self.padding.vertical(),
+        )))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer, bounds);
+
+        state.scroll = state.offset(self.content, &metrics);
+        state.last_cursor = self.content.cursor();
+
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+            if !bounds.contains(cursor_position) {
+                return event::Status::Ignored;
+            }
+
+            let delta = match delta {
+                mouse::ScrollDelta::Lines { x, y } => Vector::new(
+                    x * metrics.char_width * 3.0,
+                    y * metrics.line_height * 3.0,
+                ),
+                mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+            };
+
+            let max = metrics.max_scroll(self.content);
+
+            state.scroll = Vector::new(
+                (state.scroll.x - delta.x).clamp(0.0, max.x),
+                (state.scroll.y - delta.y).clamp(0.0, max.y),
+            );
+
+            return event::Status::Captured;
+        }
+
+        let on_action = match &self.on_action {
+            Some(on_action) => on_action,
+            None => return event::Status::Ignored,
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = bounds.contains(cursor_position);
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let position = metrics.position(cursor_position, state.scroll);
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        shell.publish(on_action(
+                            if state.keyboard_modifiers.shift() {
+                                Action::Drag(position)
+                            } else {
+                                Action::Click(position)
+                            },
+                        ));
+
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        shell.publish(on_action(Action::Click(position)));
+                        shell.publish(on_action(Action::SelectWord));
+                    }
+                    click::Kind::Triple => {
+                        shell.publish(on_action(Action::Click(position)));
+                        shell.publish(on_action(Action::SelectLine));
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                let position = metrics.position(position, state.scroll);
+
+                shell.publish(on_action(Action::Drag(position)));
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused
+                    && !state.keyboard_modifiers.command()
+                    && !c.is_control() =>
+            {
+                shell.publish(on_action(Action::Edit(Edit::Insert(c))));
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                let modifiers = state.keyboard_modifiers;
+
+                let action = match key_code {
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        state.is_dragging = false;
+
+                        None
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        Some(Action::Edit(Edit::Enter))
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        Some(Action::Edit(Edit::Backspace))
+                    }
+                    keyboard::KeyCode::Delete => {
+                        Some(Action::Edit(Edit::Delete))
+                    }
+                    keyboard::KeyCode::Tab => {
+                        Some(Action::Edit(Edit::Paste(" ".repeat(TAB_WIDTH))))
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some(text) = self.content.selected_text() {
+                            clipboard.write(text);
+                        }
+
+                        None
+                    }
+                    keyboard::KeyCode::X if modifiers.command() => {
+                        self.content.selected_text().map(|text| {
+                            clipboard.write(text);
+
+                            Action::Edit(Edit::Delete)
+                        })
+                    }
+                    keyboard::KeyCode::V if modifiers.command() => clipboard
+                        .read()
+                        .map(|text| Action::Edit(Edit::Paste(text))),
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        Some(Action::SelectAll)
+                    }
+                    _ => motion(key_code, modifiers).map(|motion| {
+                        if modifiers.shift() {
+                            Action::Select(motion)
+                        } else {
+                            Action::Move(motion)
+                        }
+                    }),
+                };
+
+                if let Some(action) = action {
+                    shell.publish(on_action(action));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer, bounds);
+        let scroll = state.offset(self.content, &metrics);
+
+        let appearance = if state.is_focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let first_line = (scroll.y / metrics.line_height) as usize;
+        let visible_lines = (metrics.text_bounds.height / metrics.line_height)
+            .ceil() as usize
+            + 1;
+        let line_y = |line: usize| {
+            metrics.text_bounds.y + line as f32 * metrics.line_height - scroll.y
+        };
+
+        if self.line_numbers {
+            let gutter = Rectangle {
+                width: metrics.gutter_width,
+                ..bounds
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: gutter,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.gutter_background,
+            );
+
+            renderer.with_layer(gutter, |renderer| {
+                for line in
+                    (first_line..self.content.line_count()).take(visible_lines)
+                {
+                    renderer.fill_text(Text {
+                        content: &(line + 1).to_string(),
+                        bounds: Rectangle {
+                            x: gutter.x + gutter.width - metrics.char_width,
+                            y: line_y(line),
+                            width: gutter.width,
+                            height: metrics.line_height,
+                        },
+                        size: metrics.size,
+                        color: appearance.line_number_color,
+                        font: self.font.clone(),
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Top,
+                    });
+                }
+            });
+        }
+
+        let text_viewport = Rectangle {
+            x: bounds.x + metrics.gutter_width,
+            width: bounds.width - metrics.gutter_width,
+            ..bounds
+        };
+
+        renderer.with_layer(text_viewport, |renderer| {
+            let column_x = |column: usize| {
+                metrics.text_bounds.x + column as f32 * metrics.char_width
+                    - scroll.x
+            };
+
+            if let Some((start, end)) = self.content.selection() {
+                for (line, text) in self
+                    .content
+                    .lines()
+                    .enumerate()
+                    .skip(first_line)
+                    .take(visible_lines)
+                    .filter(|(line, _)| (start.line..=end.line).contains(line))
+                {
+                    let from =
+                        if line == start.line { start.column } else { 0 };
+                    let to = if line == end.line {
+                        end.column
+                    } else {
+                        // Include the line break in the selection
+                        text.chars().count() + 1
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: column_x(from),
+                                y: line_y(line),
+                                width: (to - from) as f32 * metrics.char_width,
+                                height: metrics.line_height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.selection_color,
+                    );
+                }
+            } else if state.is_focused {
+                let cursor = self.content.cursor();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: column_x(cursor.column),
+                            y: line_y(cursor.line),
+                            width: 1.0,
+                            height: metrics.line_height,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.text_color,
+                );
+            }
+
+            state.highlights.highlight::<Highlighter>(
+                &self.highlighter,
+                self.content.lines(),
+                first_line..first_line + visible_lines,
+                |line, text, highlights| {
+                    for (range, color) in spans(text, highlights) {
+                        renderer.fill_text(Text {
+                            content: &text[range.clone()],
+                            bounds: Rectangle {
+                                x: column_x(
+                                    text[..range.start].chars().count(),
+                                ),
+                                y: line_y(line),
+                                width: f32::INFINITY,
+                                height: metrics.line_height,
+                            },
+                            size: metrics.size,
+                            color: color.unwrap_or(appearance.text_color),
+                            font: self.font.clone(),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Top,
+                        });
+                    }
+                },
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer, Highlighter>
+    From<CodeEditor<'a, Message, Renderer, Highlighter>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Highlighter: self::Highlighter,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        code_editor: CodeEditor<'a, Message, Renderer, Highlighter>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(code_editor)
+    }
+}
+
+/// Splits a line into the byte ranges to draw, filling the gaps between the
+/// given highlights with the default color.
+fn spans(
+    line: &str,
+    highlights: &[(std::ops::Range<usize>, Color)],
+) -> Vec<(std::ops::Range<usize>, Option<Color>)> {
+    let mut spans = Vec::with_capacity(highlights.len() * 2 + 1);
+    let mut end = 0;
+
+    for (range, color) in highlights.iter().cloned() {
+        let range = range.start.max(end)..range.end.min(line.len());
+
+        if range.is_empty()
+            || !line.is_char_boundary(range.start)
+            || !line.is_char_boundary(range.end)
+        {
+            continue;
+        }
+
+        if range.start > end {
+            spans.push((end..range.start, None));
+        }
+
+        end = range.end;
+        spans.push((range, Some(color)));
+    }
+
+    if end < line.len() {
+        spans.push((end..line.len(), None));
+    }
+
+    spans
+}
+
+fn motion(
+    key_code: keyboard::KeyCode,
+    modifiers: keyboard::Modifiers,
+) -> Option<Motion> {
+    let jump = if cfg!(target_os = "macos") {
+        modifiers.alt()
+    } else {
+        modifiers.control()
+    };
+
+    Some(match key_code {
+        keyboard::KeyCode::Left if jump => Motion::WordLeft,
+        keyboard::KeyCode::Right if jump => Motion::WordRight,
+        keyboard::KeyCode::Left => Motion::Left,
+        keyboard::KeyCode::Right => Motion::Right,
+        keyboard::KeyCode::Up => Motion::Up,
+        keyboard::KeyCode::Down => Motion::Down,
+        keyboard::KeyCode::Home if modifiers.command() => Motion::DocumentStart,
+        keyboard::KeyCode::End if modifiers.command() => Motion::DocumentEnd,
+        keyboard::KeyCode::Home => Motion::Home,
+        keyboard::KeyCode::End => Motion::End,
+        _ => return None,
+    })
+}
+
+const TAB_WIDTH: usize = 4;
@@ -0,0 +1,523 @@
+//! Store and edit the text of a [`CodeEditor`].
+//!
+//! [`CodeEditor`]: super::CodeEditor
+
+/// The multi-line content of a [`CodeEditor`], including its cursor.
+///
+/// A [`Content`] is owned by your application. The [`CodeEditor`] produces
+/// [`Action`]s which must be applied with [`Content::perform`].
+///
+/// [`CodeEditor`]: super::CodeEditor
+#[derive(Debug, Clone)]
+pub struct Content {
+    lines: Vec<String>,
+    cursor: Position,
+    anchor: Option<Position>,
+}
+
+/// A position inside a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The index of the character inside the line.
+    pub column: usize,
+}
+
+/// An interaction with a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Move the cursor with the given [`Motion`], discarding the selection.
+    Move(Motion),
+    /// Extend the selection with the given [`Motion`].
+    Select(Motion),
+    /// Select the word under the cursor.
+    SelectWord,
+    /// Select the line under the cursor.
+    SelectLine,
+    /// Select all the content.
+    SelectAll,
+    /// Place the cursor at the given [`Position`].
+    Click(Position),
+    /// Extend the selection up to the given [`Position`].
+    Drag(Position),
+    /// Apply an [`Edit`] at the cursor.
+    Edit(Edit),
+}
+
+impl Action {
+    /// Returns whether the [`Action`] changes the text of a [`Content`].
+    pub fn is_edit(&self) -> bool {
+        matches!(self, Self::Edit(_))
+    }
+}
+
+/// A movement of the cursor of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// One character to the left.
+    Left,
+    /// One character to the right.
+    Right,
+    /// One line up.
+    Up,
+    /// One line down.
+    Down,
+    /// To the start of the previous word.
+    WordLeft,
+    /// To the end of the next word.
+    WordRight,
+    /// To the start of the line.
+    Home,
+    /// To the end of the line.
+    End,
+    /// To the start of the content.
+    DocumentStart,
+    /// To the end of the content.
+    DocumentEnd,
+}
+
+/// A change to the text of a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit {
+    /// Insert a character.
+    Insert(char),
+    /// Insert some text, which may span multiple lines.
+    Paste(String),
+    /// Break the line.
+    Enter,
+    /// Delete the selection or the character before the cursor.
+    Backspace,
+    /// Delete the selection or the character after the cursor.
+    Delete,
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Content {
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_text("")
+    }
+
+    /// Creates a [`Content`] with the given text.
+    pub fn with_text(text: &str) -> Self {
+        Self {
+            lines: text
+                .split('\n')
+                .map(|line| line.strip_suffix('\r').unwrap_or(line))
+                .map(String::from)
+                .collect(),
+            cursor: Position::default(),
+            anchor: None,
+        }
+    }
+
+    /// Returns the full text of the [`Content`].
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the lines of the [`Content`].
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the amount of lines of the [`Content`].
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the [`Position`] of the cursor.
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the current selection, if any.
+    ///
+    /// The first [`Position`] is guaranteed to come before the second one.
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        let anchor = self.anchor.filter(|anchor| *anchor != self.cursor)?;
+
+        Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Returns the currently selected text, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+
+        if start.line == end.line {
+            let line = &self.lines[start.line];
+
+            return Some(line[self.byte(start)..self.byte(end)].to_string());
+        }
+
+        let mut text = self.lines[start.line][self.byte(start)..].to_string();
+
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+
+        text.push('\n');
+        text.push_str(&self.lines[end.line][..self.byte(end)]);
+
+        Some(text)
+    }
+
+    /// Applies the given [`Action`] to the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Move(motion) => {
+                if let (Some((start, end)), Motion::Left | Motion::Right) =
+                    (self.selection(), motion)
+                {
+                    self.cursor =
+                        if motion == Motion::Left { start } else { end };
+                } else {
+                    self.cursor = self.moved(self.cursor, motion);
+                }
+
+                self.anchor = None;
+            }
+            Action::Select(motion) => {
+                let _ = self.anchor.get_or_insert(self.cursor);
+
+                self.cursor = self.moved(self.cursor, motion);
+            }
+            Action::SelectWord => {
+                let line = &self.lines[self.cursor.line];
+                let chars: Vec<char> = line.chars().collect();
+                let column = self.cursor.column;
+
+                let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+
+                let start = chars[..column]
+                    .iter()
+                    .rposition(|c| !is_word(c))
+                    .map_or(0, |i| i + 1);
+
+                let end = chars[column..]
+                    .iter()
+                    .position(|c| !is_word(c))
+                    .map_or(chars.len(), |i| column + i);
+
+                self.anchor = Some(Position {
+                    column: start,
+                    ..self.cursor
+                });
+                self.cursor.column = end;
+            }
+            Action::SelectLine => {
+                let line = self.cursor.line;
+
+                self.anchor = Some(Position { line, column: 0 });
+                self.cursor = if line + 1 < self.lines.len() {
+                    Position {
+                        line: line + 1,
+                        column: 0,
+                    }
+                } else {
+                    self.line_end(line)
+                };
+            }
+            Action::SelectAll => {
+                self.anchor = Some(Position::default());
+                self.cursor = self.line_end(self.lines.len() - 1);
+            }
+            Action::Click(position) => {
+                self.anchor = None;
+                self.cursor = self.clamped(position);
+            }
+            Action::Drag(position) => {
+                let _ = self.anchor.get_or_insert(self.cursor);
+
+                self.cursor = self.clamped(position);
+            }
+            Action::Edit(edit) => self.edit(edit),
+        }
+    }
+
+    fn edit(&mut self, edit: Edit) {
+        let had_selection = self.delete_selection();
+
+        match edit {
+            Edit::Insert(c) => {
+                let byte = self.byte(self.cursor);
+
+                self.lines[self.cursor.line].insert(byte, c);
+                self.cursor.column += 1;
+            }
+            Edit::Paste(text) => {
+                let byte = self.byte(self.cursor);
+                let line = &mut self.lines[self.cursor.line];
+                let tail = line.split_off(byte);
+
+                let mut pasted = text.split('\n').map(|line| {
+                    line.strip_suffix('\r').unwrap_or(line).to_string()
+                });
+
+                line.push_str(&pasted.next().unwrap_or_default());
+
+                let mut position = self.cursor;
+                position.column = line.chars().count();
+
+                for pasted_line in pasted {
+                    position.line += 1;
+                    position.column = pasted_line.chars().count();
+
+                    self.lines.insert(position.line, pasted_line);
+                }
+
+                self.lines[position.line].push_str(&tail);
+                self.cursor = position;
+            }
+            Edit::Enter => {
+                let byte = self.byte(self.cursor);
+                let tail = self.lines[self.cursor.line].split_off(byte);
+
+                self.cursor = Position {
+                    line: self.cursor.line + 1,
+                    column: 0,
+                };
+                self.lines.insert(self.cursor.line, tail);
+            }
+            Edit::Backspace if !had_selection => {
+                if self.cursor.column > 0 {
+                    self.cursor.column -= 1;
+
+                    let byte = self.byte(self.cursor);
+                    let _ = self.lines[self.cursor.line].remove(byte);
+                } else if self.cursor.line > 0 {
+                    let line = self.lines.remove(self.cursor.line);
+
+                    self.cursor = self.line_end(self.cursor.line - 1);
+                    self.lines[self.cursor.line].push_str(&line);
+                }
+            }
+            Edit::Delete if !had_selection => {
+                let end = self.line_end(self.cursor.line);
+
+                if self.cursor.column < end.column {
+                    let byte = self.byte(self.cursor);
+                    let _ = self.lines[self.cursor.line].remove(byte);
+                } else if self.cursor.line + 1 < self.lines.len() {
+                    let line = self.lines.remove(self.cursor.line + 1);
+
+                    self.lines[self.cursor.line].push_str(&line);
+                }
+            }
+            Edit::Backspace | Edit::Delete => {}
+        }
+    }
+
+    /// Deletes the current selection, returning whether there was one.
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+
+        let (start, end) = match selection {
+            Some(selection) => selection,
+            None => return false,
+        };
+
+        let tail = self.lines[end.line][self.byte(end)..].to_string();
+        let start_byte = self.byte(start);
+
+        self.lines[start.line].truncate(start_byte);
+        self.lines[start.line].push_str(&tail);
+
+        let _ = self.lines.drain(start.line + 1..=end.line);
+
+        self.cursor = start;
+
+        true
+    }
+
+    fn moved(&self, position: Position, motion: Motion) -> Position {
+        match motion {
+            Motion::Left => {
+                if position.column > 0 {
+                    Position {
+                        column: position.column - 1,
+                        ..position
+                    }
+                } else if position.line > 0 {
+                    self.line_end(position.line - 1)
+                } else {
+                    position
+                }
+            }
+            Motion::Right => {
+                if position.column < self.line_end(position.line).column {
+                    Position {
+                        column: position.column + 1,
+                        ..position
+                    }
+                } else if position.line + 1 < self.lines.len() {
+                    Position {
+                        line: position.line + 1,
+                        column: 0,
+                    }
+                } else {
+                    position
+                }
+            }
+            Motion::Up if position.line > 0 => self.clamped(Position {
+                line: position.line - 1,
+                ..position
+            }),
+            Motion::Up => Position::default(),
+            Motion::Down if position.line + 1 < self.lines.len() => self
+                .clamped(Position {
+                    line: position.line + 1,
+                    ..position
+                }),
+            Motion::Down => self.line_end(position.line),
+            Motion::WordLeft => {
+                let chars: Vec<char> =
+                    self.lines[position.line].chars().collect();
+
+                if position.column == 0 {
+                    return self.moved(position, Motion::Left);
+                }
+
+                let column = chars[..position.column]
+                    .iter()
+                    .rposition(|c| c.is_alphanumeric() || *c == '_')
+                    .and_then(|end| {
+                        chars[..end]
+                            .iter()
+                            .rposition(|c| !(c.is_alphanumeric() || *c == '_'))
+                            .map(|i| i + 1)
+                            .or(Some(0))
+                    })
+                    .unwrap_or(0);
+
+                Position { column, ..position }
+            }
+            Motion::WordRight => {
+                let chars: Vec<char> =
+                    self.lines[position.line].chars().collect();
+
+                if position.column >= chars.len() {
+                    return self.moved(position, Motion::Right);
+                }
+
+                let column = chars[position.column..]
+                    .iter()
+                    .position(|c| c.is_alphanumeric() || *c == '_')
+                    .map(|start| position.column + start)
+                    .map(|start| {
+                        chars[start..]
+                            .iter()
+                            .position(|c| !(c.is_alphanumeric() || *c == '_'))
+                            .map_or(chars.len(), |i| start + i)
+                    })
+                    .unwrap_or(chars.len());
+
+                Position { column, ..position }
+            }
+            Motion::Home => Position {
+                column: 0,
+                ..position
+            },
+            Motion::End => self.line_end(position.line),
+            Motion::DocumentStart => Position::default(),
+            Motion::DocumentEnd => self.line_end(self.lines.len() - 1),
+        }
+    }
+
+    fn line_end(&self, line: usize) -> Position {
+        Position {
+            line,
+            column: self.lines[line].chars().count(),
+        }
+    }
+
+    fn clamped(&self, position: Position) -> Position {
+        let line = position.line.min(self.lines.len() - 1);
+
+        Position {
+            line,
+            column: position.column.min(self.line_end(line).column),
+        }
+    }
+
+    /// Returns the byte offset of the given [`Position`] inside its line.
+    fn byte(&self, position: Position) -> usize {
+        let line = &self.lines[position.line];
+
+        line.char_indices()
+            .nth(position.column)
+            .map_or(line.len(), |(byte, _)| byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_at(text: &str, line: usize, column: usize) -> Content {
+        let mut content = Content::with_text(text);
+        content.perform(Action::Click(Position { line, column }));
+        content
+    }
+
+    #[test]
+    fn enter_and_backspace_join_lines() {
+        let mut content = content_at("fn main() {}", 0, 11);
+
+        content.perform(Action::Edit(Edit::Enter));
+        assert_eq!(content.text(), "fn main() {\n}");
+        assert_eq!(content.cursor(), Position { line: 1, column: 0 });
+
+        content.perform(Action::Edit(Edit::Backspace));
+        assert_eq!(content.text(), "fn main() {}");
+        assert_eq!(
+            content.cursor(),
+            Position {
+                line: 0,
+                column: 11
+            }
+        );
+    }
+
+    #[test]
+    fn paste_replaces_multiline_selection() {
+        let mut content = content_at("let a = 1;\nlet b = 2;", 0, 4);
+
+        content.perform(Action::Drag(Position { line: 1, column: 5 }));
+        assert_eq!(content.selected_text().as_deref(), Some("a = 1;\nlet b"));
+
+        content.perform(Action::Edit(Edit::Paste(String::from("x\nlet y"))));
+        assert_eq!(content.text(), "let x\nlet y = 2;");
+        assert_eq!(content.cursor(), Position { line: 1, column: 5 });
+    }
+
+    #[test]
+    fn select_word_under_cursor() {
+        let mut content = content_at("let value = 42;", 0, 6);
+
+        content.perform(Action::SelectWord);
+        assert_eq!(content.selected_text().as_deref(), Some("value"));
+    }
+
+    #[test]
+    fn word_motions_skip_punctuation() {
+        let mut content = content_at("foo(bar, baz)", 0, 0);
+
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor().column, 3);
+
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor().column, 7);
+
+        content.perform(Action::Move(Motion::WordLeft));
+        assert_eq!(content.cursor().column, 4);
+    }
+}
@@ -0,0 +1,241 @@
+//! Colorize the lines of a [`CodeEditor`].
+//!
+//! [`CodeEditor`]: super::CodeEditor
+#[cfg(feature = "syntect")]
+pub mod syntect;
+
+use crate::{Color, Hasher};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::{Hash, Hasher as _};
+use std::ops::Range;
+
+/// A syntax highlighter for the lines of a [`CodeEditor`].
+///
+/// A [`Highlighter`] is fed every line of the content in order, so it can keep
+/// track of multi-line constructs. The [`CodeEditor`] keeps the highlights of
+/// every line and a clone of the [`Highlighter`] every few lines, so only the
+/// lines after the first edited one are highlighted again.
+///
+/// [`CodeEditor`]: super::CodeEditor
+pub trait Highlighter: Clone + 'static {
+    /// The settings used to create the [`Highlighter`].
+    ///
+    /// Every line is highlighted again when the settings change.
+    type Settings: Clone + PartialEq;
+
+    /// Creates a new [`Highlighter`] with the given settings.
+    fn new(settings: &Self::Settings) -> Self;
+
+    /// Highlights the next line, returning the [`Color`] of some of its byte
+    /// ranges.
+    ///
+    /// The ranges must be sorted and must not overlap. Any text outside of
+    /// them is drawn with the default text color.
+    fn highlight_line(&mut self, line: &str) -> Vec<(Range<usize>, Color)>;
+}
+
+/// A [`Highlighter`] that leaves the text as-is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainText;
+
+impl Highlighter for PlainText {
+    type Settings = ();
+
+    fn new(_settings: &Self::Settings) -> Self {
+        PlainText
+    }
+
+    fn highlight_line(&mut self, _line: &str) -> Vec<(Range<usize>, Color)> {
+        Vec::new()
+    }
+}
+
+/// The amount of lines between two clones of a [`Highlighter`] kept in a
+/// [`Cache`].
+const CHECKPOINT_INTERVAL: usize = 32;
+
+/// The highlights of the lines of a [`CodeEditor`], kept across frames.
+///
+/// [`CodeEditor`]: super::CodeEditor
+#[derive(Default)]
+pub(super) struct Cache {
+    lines: RefCell<Option<Box<dyn Any>>>,
+}
+
+struct Lines<H: Highlighter> {
+    settings: H::Settings,
+    // The hash and the highlights of each highlighted line
+    highlights: Vec<(u64, Vec<(Range<usize>, Color)>)>,
+    // The highlighter before every `CHECKPOINT_INTERVAL` lines
+    checkpoints: Vec<H>,
+}
+
+impl Cache {
+    /// Calls `f` with the highlights of each line in the given range,
+    /// highlighting only the lines after the first one that changed since
+    /// the last call.
+    pub(super) fn highlight<'a, H: Highlighter>(
+        &self,
+        settings: &H::Settings,
+        lines: impl Iterator<Item = &'a str>,
+        range: Range<usize>,
+        mut f: impl FnMut(usize, &'a str, &[(Range<usize>, Color)]),
+    ) {
+        let mut cache = self.lines.borrow_mut();
+
+        let is_stale = cache
+            .as_ref()
+            .and_then(|lines| lines.downcast_ref::<Lines<H>>())
+            .map_or(true, |lines| lines.settings != *settings);
+
+        if is_stale {
+            *cache = Some(Box::new(Lines::<H> {
+                settings: settings.clone(),
+                highlights: Vec::new(),
+                checkpoints: Vec::new(),
+            }));
+        }
+
+        let cache = match cache
+            .as_mut()
+            .and_then(|lines| lines.downcast_mut::<Lines<H>>())
+        {
+            Some(cache) => cache,
+            None => return,
+        };
+
+        let lines: Vec<&str> = lines.take(range.end).collect();
+
+        let first_edited = cache
+            .highlights
+            .iter()
+            .zip(&lines)
+            .position(|((hash, _), line)| *hash != hash_line(line))
+            .unwrap_or_else(|| cache.highlights.len().min(lines.len()));
+
+        // Clones taken before the first edited line are still valid
+        cache.highlights.truncate(first_edited);
+        cache
+            .checkpoints
+            .truncate(first_edited / CHECKPOINT_INTERVAL + 1);
+
+        if cache.highlights.len() < lines.len() {
+            let checkpoint = (cache.highlights.len() / CHECKPOINT_INTERVAL)
+                .min(cache.checkpoints.len().saturating_sub(1));
+
+            let mut highlighter = match cache.checkpoints.get(checkpoint) {
+                Some(highlighter) => highlighter.clone(),
+                None => H::new(settings),
+            };
+
+            for (line, text) in lines
+                .iter()
+                .enumerate()
+                .skip(checkpoint * CHECKPOINT_INTERVAL)
+            {
+                if line % CHECKPOINT_INTERVAL == 0
+                    && cache.checkpoints.len() == line / CHECKPOINT_INTERVAL
+                {
+                    cache.checkpoints.push(highlighter.clone());
+                }
+
+                let highlights = highlighter.highlight_line(text);
+
+                if line >= cache.highlights.len() {
+                    cache.highlights.push((hash_line(text), highlights));
+                }
+            }
+        }
+
+        for (line, (text, (_, highlights))) in lines
+            .iter()
+            .zip(&cache.highlights)
+            .enumerate()
+            .skip(range.start)
+        {
+            f(line, text, highlights);
+        }
+    }
+}
+
+impl Clone for Cache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache").finish_non_exhaustive()
+    }
+}
+
+fn hash_line(line: &str) -> u64 {
+    let mut hasher = Hasher::default();
+    line.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static HIGHLIGHTED_LINES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Highlights every line with the amount of lines fed so far.
+    #[derive(Debug, Clone)]
+    struct Counter(usize);
+
+    impl Highlighter for Counter {
+        type Settings = ();
+
+        fn new(_settings: &Self::Settings) -> Self {
+            Counter(0)
+        }
+
+        fn highlight_line(
+            &mut self,
+            _line: &str,
+        ) -> Vec<(Range<usize>, Color)> {
+            let _ = HIGHLIGHTED_LINES.fetch_add(1, Ordering::SeqCst);
+            self.0 += 1;
+
+            vec![(0..self.0, Color::BLACK)]
+        }
+    }
+
+    fn highlight(cache: &Cache, text: &str) -> Vec<usize> {
+        let mut counts = Vec::new();
+
+        cache.highlight::<Counter>(
+            &(),
+            text.lines(),
+            0..usize::MAX,
+            |_, _, highlights| counts.push(highlights[0].0.end),
+        );
+
+        counts
+    }
+
+    #[test]
+    fn lines_are_highlighted_again_from_the_first_edited_one() {
+        let cache = Cache::default();
+        let text: String = (0..100).map(|i| format!("{i}\n")).collect();
+
+        assert_eq!(highlight(&cache, &text), (1..=100).collect::<Vec<_>>());
+        assert_eq!(HIGHLIGHTED_LINES.swap(0, Ordering::SeqCst), 100);
+
+        let edited = text.replacen("70\n", "seventy\n", 1);
+
+        assert_eq!(highlight(&cache, &edited), (1..=100).collect::<Vec<_>>());
+
+        // The highlighter resumes from its clone before line 64
+        assert_eq!(HIGHLIGHTED_LINES.swap(0, Ordering::SeqCst), 36);
+    }
+}
@@ -0,0 +1,125 @@
+//! Highlight code using [`syntect`](https://docs.rs/syntect).
+use crate::Color;
+
+use once_cell::sync::Lazy;
+use std::ops::Range;
+use std::sync::Arc;
+use syntect::highlighting::{
+    self, HighlightIterator, HighlightState, ThemeSet,
+};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
+
+static SYNTAXES: Lazy<SyntaxSet> =
+    Lazy::new(SyntaxSet::load_defaults_nonewlines);
+
+static THEMES: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
+
+/// The settings of a [`Highlighter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// The [`Theme`] of the [`Highlighter`].
+    pub theme: Theme,
+    /// The extension of the file or the name of the language to highlight.
+    pub token: String,
+}
+
+/// A built-in theme of a [`Highlighter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// The "InspiredGitHub" theme.
+    InspiredGitHub,
+    /// The "base16-ocean.dark" theme.
+    Base16Ocean,
+    /// The "base16-mocha.dark" theme.
+    Base16Mocha,
+    /// The "base16-eighties.dark" theme.
+    Base16Eighties,
+    /// The "Solarized (dark)" theme.
+    SolarizedDark,
+}
+
+impl Theme {
+    /// All the available [`Theme`] variants.
+    pub const ALL: &'static [Self] = &[
+        Self::InspiredGitHub,
+        Self::Base16Ocean,
+        Self::Base16Mocha,
+        Self::Base16Eighties,
+        Self::SolarizedDark,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::InspiredGitHub => "InspiredGitHub",
+            Self::Base16Ocean => "base16-ocean.dark",
+            Self::Base16Mocha => "base16-mocha.dark",
+            Self::Base16Eighties => "base16-eighties.dark",
+            Self::SolarizedDark => "Solarized (dark)",
+        }
+    }
+}
+
+/// A [`Highlighter`](super::Highlighter) backed by `syntect` and its default
+/// syntaxes and themes.
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct Highlighter {
+    highlighter: Arc<highlighting::Highlighter<'static>>,
+    parse_state: ParseState,
+    highlight_state: HighlightState,
+}
+
+impl super::Highlighter for Highlighter {
+    type Settings = Settings;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let syntax = SYNTAXES
+            .find_syntax_by_token(&settings.token)
+            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+
+        let highlighter = highlighting::Highlighter::new(
+            &THEMES.themes[settings.theme.key()],
+        );
+        let highlight_state =
+            HighlightState::new(&highlighter, ScopeStack::new());
+
+        Self {
+            highlighter: Arc::new(highlighter),
+            parse_state: ParseState::new(syntax),
+            highlight_state,
+        }
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Vec<(Range<usize>, Color)> {
+        let ops = match self.parse_state.parse_line(line, &SYNTAXES) {
+            Ok(ops) => ops,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut start = 0;
+
+        HighlightIterator::new(
+            &mut self.highlight_state,
+            &ops,
+            line,
+            &self.highlighter,
+        )
+        .map(|(style, text)| {
+            let range = start..start + text.len();
+            start = range.end;
+
+            let color = style.foreground;
+
+            (
+                range,
+                Color::from_rgba8(
+                    color.r,
+                    color.g,
+                    color.b,
+                    color.a as f32 / 255.0,
+                ),
+            )
+        })
+        .collect()
+    }
+}
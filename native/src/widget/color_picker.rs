@@ -0,0 +1,944 @@
+//! Let your users pick a color with the mouse, by typing its hexadecimal
+//! code or by choosing one of a set of swatches.
+//!
+//! A [`ColorPicker`] has some local [`State`].
+mod hsv;
+
+pub use hsv::{parse_hex, to_hex, Hsv, ParseError};
+pub use iced_style::color_picker::{Appearance, StyleSheet};
+
+use crate::event::{self, Event};
+use crate::gradient::Linear;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+/// The height of the hue and alpha sliders of a [`ColorPicker`].
+const SLIDER_HEIGHT: f32 = 12.0;
+
+/// The size of the swatches of a [`ColorPicker`].
+const SWATCH_SIZE: f32 = 20.0;
+
+/// The radius of the handle of the saturation and value area.
+const HANDLE_RADIUS: f32 = 6.0;
+
+/// A color picker.
+///
+/// It is made of an area to pick the saturation and value of a color, a hue
+/// slider, an alpha slider and a text field with the hexadecimal code of
+/// the color. It may also display a set of swatches and an eyedropper
+/// button.
+///
+/// # Example
+/// ```
+/// # pub type ColorPicker<'a, Message> = iced_native::widget::ColorPicker<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColorChanged(Color),
+///     PickFromScreen,
+/// }
+///
+/// let color = Color::from_rgb(0.2, 0.4, 0.8);
+///
+/// let picker = ColorPicker::new(color, Message::ColorChanged)
+///     .swatches(vec![Color::BLACK, Color::WHITE])
+///     .on_eyedropper(Message::PickFromScreen);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    value: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_eyedropper: Option<Message>,
+    swatches: Vec<Color>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    font: Renderer::Font,
+    size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The default width of a [`ColorPicker`].
+    pub const DEFAULT_WIDTH: f32 = 220.0;
+
+    /// The default padding of a [`ColorPicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(8.0);
+
+    /// Creates a new [`ColorPicker`].
+    ///
+    /// It expects:
+    /// - the current [`Color`], and
+    /// - a function that produces a message when a new [`Color`] is picked.
+    pub fn new<F>(value: Color, on_change: F) -> Self
+    where
+        F: 'a + Fn(Color) -> Message,
+    {
+        ColorPicker {
+            value,
+            on_change: Box::new(on_change),
+            on_eyedropper: None,
+            swatches: Vec::new(),
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            padding: Self::DEFAULT_PADDING,
+            spacing: 8.0,
+            font: Default::default(),
+            size: None,
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the swatches of the [`ColorPicker`], which pick their [`Color`]
+    /// when clicked.
+    pub fn swatches(mut self, swatches: impl Into<Vec<Color>>) -> Self {
+        self.swatches = swatches.into();
+        self
+    }
+
+    /// Displays an eyedropper button in the [`ColorPicker`], which produces
+    /// the given message when pressed.
+    ///
+    /// The application is then expected to let the user sample a [`Color`]
+    /// from the screen and to update the value of the [`ColorPicker`].
+    pub fn on_eyedropper(mut self, message: Message) -> Self {
+        self.on_eyedropper = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ColorPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the parts of the [`ColorPicker`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the text field of the [`ColorPicker`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the text size of the text field of the [`ColorPicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the text field of the [`ColorPicker`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    fn change(
+        &mut self,
+        state: &mut State,
+        hsv: Hsv,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let color = hsv.to_color();
+
+        state.hsv = hsv;
+        state.color = color;
+
+        if color != self.value {
+            self.value = color;
+
+            shell.publish((self.on_change)(color));
+        }
+    }
+
+    /// Picks the [`Hsv`] color under the cursor in the given [`Drag`] area.
+    fn drag(
+        &mut self,
+        state: &mut State,
+        drag: Drag,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = drag.bounds(layout);
+
+        let x = ((cursor_position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let y =
+            ((cursor_position.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+        let hsv = match drag {
+            Drag::Area => Hsv {
+                saturation: x,
+                value: 1.0 - y,
+                ..state.hsv
+            },
+            Drag::Hue => Hsv {
+                hue: x * 360.0,
+                ..state.hsv
+            },
+            Drag::Alpha => Hsv {
+                alpha: x,
+                ..state.hsv
+            },
+        };
+
+        self.change(state, hsv, shell);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ColorPicker<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.value))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.resolve(Size::new(Self::DEFAULT_WIDTH, 0.0)).width;
+        let inner_width = (width - self.padding.horizontal()).max(0.0);
+
+        let mut y = self.padding.top;
+        let mut children = Vec::new();
+
+        let place = |size: Size, x: f32, y: f32| {
+            let mut node = layout::Node::new(size);
+            node.move_to(Point::new(self.padding.left + x, y));
+
+            node
+        };
+
+        // Saturation and value area, hue slider and alpha slider
+        children.push(place(Size::new(inner_width, inner_width), 0.0, y));
+        y += inner_width + self.spacing;
+
+        children.push(place(Size::new(inner_width, SLIDER_HEIGHT), 0.0, y));
+        y += SLIDER_HEIGHT + self.spacing;
+
+        children.push(place(Size::new(inner_width, SLIDER_HEIGHT), 0.0, y));
+        y += SLIDER_HEIGHT + self.spacing;
+
+        // Preview, text field and eyedropper
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let input_padding = Padding::new(5.0);
+        let row_height = text_size + input_padding.vertical();
+
+        let buttons = if self.on_eyedropper.is_some() {
+            2.0
+        } else {
+            1.0
+        };
+        let input_width =
+            (inner_width - buttons * (row_height + self.spacing)).max(0.0);
+
+        children.push(place(Size::new(row_height, row_height), 0.0, y));
+
+        let mut input = text_input::layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(input_width, f32::INFINITY),
+            )
+            .with_direction(limits.direction()),
+            Length::Fill,
+            input_padding,
+            self.size,
+            None,
+        );
+        input.move_to(Point::new(
+            self.padding.left + row_height + self.spacing,
+            y,
+        ));
+        children.push(input);
+
+        if self.on_eyedropper.is_some() {
+            children.push(place(
+                Size::new(row_height, row_height),
+                inner_width - row_height,
+                y,
+            ));
+        }
+
+        y += row_height;
+
+        // Swatches
+        if !self.swatches.is_empty() {
+            let gap = self.spacing / 2.0;
+            let columns =
+                (((inner_width + gap) / (SWATCH_SIZE + gap)) as usize).max(1);
+
+            y += self.spacing;
+
+            for i in 0..self.swatches.len() {
+                children.push(place(
+                    Size::new(SWATCH_SIZE, SWATCH_SIZE),
+                    (i % columns) as f32 * (SWATCH_SIZE + gap),
+                    y + (i / columns) as f32 * (SWATCH_SIZE + gap),
+                ));
+            }
+
+            let rows = (self.swatches.len() + columns - 1) / columns;
+
+            y += rows as f32 * (SWATCH_SIZE + gap) - gap;
+        }
+
+        let size = limits.resolve(Size::new(width, y + self.padding.bottom));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let parts = Parts::new(layout, self.on_eyedropper.is_some());
+
+        state.sync(self.value);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let drag = [Drag::Area, Drag::Hue, Drag::Alpha]
+                    .into_iter()
+                    .find(|drag| drag.bounds(layout).contains(cursor_position));
+
+                if let Some(drag) = drag {
+                    state.input.unfocus();
+                    state.drag = Some(drag);
+                    self.drag(state, drag, layout, cursor_position, shell);
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(eyedropper) = parts.eyedropper {
+                    if eyedropper.bounds().contains(cursor_position) {
+                        if let Some(message) = self.on_eyedropper.clone() {
+                            shell.publish(message);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                let swatch = self
+                    .swatches
+                    .iter()
+                    .zip(parts.swatches)
+                    .find(|(_, layout)| {
+                        layout.bounds().contains(cursor_position)
+                    })
+                    .map(|(color, _)| *color);
+
+                if let Some(color) = swatch {
+                    state.input.unfocus();
+                    self.change(state, Hsv::from_color(color), shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = state.drag {
+                    self.drag(state, drag, layout, cursor_position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        let mut value = Value::new(&state.text);
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            parts.input,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options {
+                max_length: Some(9),
+                filter: Some(&|c: char| c == '#' || c.is_ascii_hexdigit()),
+                ..text_input::Options::default()
+            },
+            &|text| text,
+            None,
+            None,
+            &None,
+            || &mut state.input,
+        );
+
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(text) = edits.pop() {
+            if let Ok(color) = parse_hex(&text) {
+                self.change(state, Hsv::from_color(color), shell);
+            }
+
+            state.text = text;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = StyleSheet::appearance(theme, &self.style);
+        let parts = Parts::new(layout, self.on_eyedropper.is_some());
+        let hsv = state.hsv(self.value);
+        let opaque = Color {
+            a: 1.0,
+            ..self.value
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // Saturation and value area
+        let area = parts.area.bounds();
+
+        fill(
+            renderer,
+            area,
+            Linear::horizontal(Color::WHITE, hsv.pure_hue()),
+        );
+        fill(
+            renderer,
+            area,
+            Linear::vertical(Color::TRANSPARENT, Color::BLACK),
+        );
+
+        let handle = Point::new(
+            area.x + hsv.saturation * area.width,
+            area.y + (1.0 - hsv.value) * area.height,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle.x - HANDLE_RADIUS,
+                    y: handle.y - HANDLE_RADIUS,
+                    width: HANDLE_RADIUS * 2.0,
+                    height: HANDLE_RADIUS * 2.0,
+                },
+                border_radius: HANDLE_RADIUS.into(),
+                border_width: 2.0,
+                border_color: appearance.handle_color,
+            },
+            opaque,
+        );
+
+        // Hue slider
+        let hue = parts.hue.bounds();
+        let segment = hue.width / 6.0;
+
+        for i in 0..6 {
+            let color = |i: usize| {
+                Hsv {
+                    hue: i as f32 * 60.0,
+                    ..hsv
+                }
+                .pure_hue()
+            };
+
+            fill(
+                renderer,
+                Rectangle {
+                    x: hue.x + i as f32 * segment,
+                    width: segment,
+                    ..hue
+                },
+                Linear::horizontal(color(i), color(i + 1)),
+            );
+        }
+
+        slider_handle(
+            renderer,
+            hue,
+            hsv.hue / 360.0,
+            hsv.pure_hue(),
+            &appearance,
+        );
+
+        // Alpha slider
+        let alpha = parts.alpha.bounds();
+
+        checkerboard(renderer, alpha, &appearance);
+        fill(
+            renderer,
+            alpha,
+            Linear::horizontal(Color { a: 0.0, ..opaque }, opaque),
+        );
+        slider_handle(renderer, alpha, hsv.alpha, self.value, &appearance);
+
+        // Preview
+        let preview = parts.preview.bounds();
+
+        checkerboard(renderer, preview, &appearance);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: preview,
+                border_radius: 0.0.into(),
+                border_width: 1.0,
+                border_color: appearance.swatch_border_color,
+            },
+            self.value,
+        );
+
+        // Text field
+        let text = if state.input.is_focused() {
+            state.text.clone()
+        } else {
+            to_hex(self.value)
+        };
+
+        text_input::draw(
+            renderer,
+            theme,
+            parts.input,
+            cursor_position,
+            &state.input,
+            &Value::new(&text),
+            "",
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &self.input_style,
+        );
+
+        // Eyedropper
+        if let Some(eyedropper) = parts.eyedropper {
+            let bounds = eyedropper.bounds();
+            let is_hovered = bounds.contains(cursor_position);
+            let center = bounds.center();
+            let radius = bounds.width.min(bounds.height) / 4.0;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: 1.0,
+                    border_color: if is_hovered {
+                        appearance.icon_color
+                    } else {
+                        appearance.swatch_border_color
+                    },
+                },
+                Color::TRANSPARENT,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - radius,
+                        y: center.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border_radius: radius.into(),
+                    border_width: 1.5,
+                    border_color: appearance.icon_color,
+                },
+                Color::TRANSPARENT,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - 1.5,
+                        y: center.y - 1.5,
+                        width: 3.0,
+                        height: 3.0,
+                    },
+                    border_radius: 1.5.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.icon_color,
+            );
+        }
+
+        // Swatches
+        for (color, layout) in self.swatches.iter().zip(parts.swatches) {
+            let bounds = layout.bounds();
+
+            if color.a < 1.0 {
+                checkerboard(renderer, bounds, &appearance);
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 2.0.into(),
+                    border_width: if *color == self.value { 2.0 } else { 1.0 },
+                    border_color: if *color == self.value {
+                        appearance.handle_color
+                    } else {
+                        appearance.swatch_border_color
+                    },
+                },
+                *color,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout, self.on_eyedropper.is_some());
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if parts.area.bounds().contains(cursor_position) {
+            mouse::Interaction::Crosshair
+        } else if parts.hue.bounds().contains(cursor_position)
+            || parts.alpha.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else if parts
+            .eyedropper
+            .into_iter()
+            .chain(parts.swatches)
+            .any(|layout| layout.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            text_input::mouse_interaction(parts.input, cursor_position)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ColorPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(
+        color_picker: ColorPicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(color_picker)
+    }
+}
+
+/// The local state of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+pub struct State {
+    hsv: Hsv,
+    color: Color,
+    drag: Option<Drag>,
+    input: text_input::State,
+    text: String,
+}
+
+impl State {
+    /// Creates a new [`State`] for the given [`Color`].
+    pub fn new(color: Color) -> Self {
+        Self {
+            hsv: Hsv::from_color(color),
+            color,
+            drag: None,
+            input: text_input::State::new(),
+            text: to_hex(color),
+        }
+    }
+
+    /// Returns whether the text field of the [`ColorPicker`] is currently
+    /// focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Returns the [`Hsv`] color displayed for the given [`Color`].
+    ///
+    /// The last picked hue is kept while the [`Color`] does not change.
+    fn hsv(&self, color: Color) -> Hsv {
+        if color == self.color {
+            self.hsv
+        } else {
+            Hsv::from_color(color)
+        }
+    }
+
+    fn sync(&mut self, color: Color) {
+        self.hsv = self.hsv(color);
+        self.color = color;
+
+        if !self.input.is_focused() {
+            self.text = to_hex(color);
+        }
+    }
+}
+
+/// An area of a [`ColorPicker`] that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    Area,
+    Hue,
+    Alpha,
+}
+
+impl Drag {
+    fn bounds(self, layout: Layout<'_>) -> Rectangle {
+        let index = match self {
+            Drag::Area => 0,
+            Drag::Hue => 1,
+            Drag::Alpha => 2,
+        };
+
+        layout
+            .children()
+            .nth(index)
+            .map(|layout| layout.bounds())
+            .unwrap_or_default()
+    }
+}
+
+/// The layouts of the parts of a [`ColorPicker`].
+struct Parts<'a> {
+    area: Layout<'a>,
+    hue: Layout<'a>,
+    alpha: Layout<'a>,
+    preview: Layout<'a>,
+    input: Layout<'a>,
+    eyedropper: Option<Layout<'a>>,
+    swatches: Vec<Layout<'a>>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(layout: Layout<'a>, has_eyedropper: bool) -> Self {
+        let mut children = layout.children();
+        let mut next = || children.next().expect("Color picker part");
+
+        let area = next();
+        let hue = next();
+        let alpha = next();
+        let preview = next();
+        let input = next();
+        let eyedropper = has_eyedropper.then(next);
+
+        Parts {
+            area,
+            hue,
+            alpha,
+            preview,
+            input,
+            eyedropper,
+            swatches: children.collect(),
+        }
+    }
+}
+
+/// Fills the given bounds with a [`Linear`] gradient.
+fn fill<Renderer>(renderer: &mut Renderer, bounds: Rectangle, linear: Linear)
+where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        linear,
+    );
+}
+
+/// Draws a checkerboard, displayed behind transparent colors.
+fn checkerboard<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    appearance: &Appearance,
+) where
+    Renderer: crate::Renderer,
+{
+    let cell = SLIDER_HEIGHT / 2.0;
+    let (even, odd) = appearance.checkerboard_colors;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        even,
+    );
+
+    let columns = (bounds.width / cell).ceil() as usize;
+    let rows = (bounds.height / cell).ceil() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * cell;
+            let y = row as f32 * cell;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + x,
+                        y: bounds.y + y,
+                        width: cell.min(bounds.width - x),
+                        height: cell.min(bounds.height - y),
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                odd,
+            );
+        }
+    }
+}
+
+/// Draws the handle of a slider at the given ratio of its bounds.
+fn slider_handle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    ratio: f32,
+    color: Color,
+    appearance: &Appearance,
+) where
+    Renderer: crate::Renderer,
+{
+    let width = SLIDER_HEIGHT / 2.0;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x + ratio * bounds.width - width / 2.0,
+                y: bounds.y - 2.0,
+                width,
+                height: bounds.height + 4.0,
+            },
+            border_radius: 2.0.into(),
+            border_width: 2.0,
+            border_color: appearance.handle_color,
+        },
+        color,
+    );
+}
@@ -0,0 +1,190 @@
+use crate::Color;
+
+use std::fmt;
+
+/// A [`Color`] in the HSV color space, with an alpha channel.
+///
+/// Unlike a [`Color`], it keeps its hue when it is desaturated or black.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    /// The hue, in degrees from 0 to 360.
+    pub hue: f32,
+    /// The saturation, from 0 to 1.
+    pub saturation: f32,
+    /// The value, from 0 to 1.
+    pub value: f32,
+    /// The alpha channel, from 0 to 1.
+    pub alpha: f32,
+}
+
+impl Hsv {
+    /// Converts the given [`Color`] to the HSV color space.
+    pub fn from_color(color: Color) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Self {
+            hue,
+            saturation: if max == 0.0 { 0.0 } else { delta / max },
+            value: max,
+            alpha: color.a,
+        }
+    }
+
+    /// Converts the [`Hsv`] color to a [`Color`].
+    pub fn to_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = self.hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.value - chroma;
+
+        Color::from_rgba(r + m, g + m, b + m, self.alpha)
+    }
+
+    /// Returns the fully saturated and opaque [`Color`] with the hue of the
+    /// [`Hsv`] color.
+    pub fn pure_hue(self) -> Color {
+        Hsv {
+            saturation: 1.0,
+            value: 1.0,
+            alpha: 1.0,
+            ..self
+        }
+        .to_color()
+    }
+}
+
+/// Formats the given [`Color`] in the `#RRGGBB` format, or `#RRGGBBAA` if it
+/// is not opaque.
+pub fn to_hex(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02X}{g:02X}{b:02X}")
+    } else {
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+}
+
+/// Parses a [`Color`] in the `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`
+/// formats. The leading `#` is optional.
+pub fn parse_hex(hex: &str) -> Result<Color, ParseError> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !hex.is_ascii() {
+        return Err(ParseError);
+    }
+
+    let channel =
+        |digits: &str| u8::from_str_radix(digits, 16).map_err(|_| ParseError);
+
+    let short = |i: usize| channel(&hex[i..=i]).map(|digit| digit * 17);
+    let long = |i: usize| channel(&hex[i * 2..i * 2 + 2]);
+
+    let [r, g, b, a] = match hex.len() {
+        3 => [short(0)?, short(1)?, short(2)?, u8::MAX],
+        4 => [short(0)?, short(1)?, short(2)?, short(3)?],
+        6 => [long(0)?, long(1)?, long(2)?, u8::MAX],
+        8 => [long(0)?, long(1)?, long(2)?, long(3)?],
+        _ => return Err(ParseError),
+    };
+
+    Ok(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+}
+
+/// An error produced when parsing an invalid hexadecimal [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color, expected the #RRGGBB format")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let [a, b] = [a.into_rgba8(), b.into_rgba8()];
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        for color in [
+            Color::BLACK,
+            Color::WHITE,
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.2, 0.6, 0.4),
+            Color::from_rgba(0.9, 0.1, 0.7, 0.5),
+            Color::from_rgb(0.0, 0.0, 0.8),
+        ] {
+            assert_close(Hsv::from_color(color).to_color(), color);
+        }
+    }
+
+    #[test]
+    fn hsv_components() {
+        let hsv = Hsv::from_color(Color::from_rgb(0.0, 0.5, 1.0));
+
+        assert!((hsv.hue - 210.0).abs() < 1e-3);
+        assert_eq!(hsv.saturation, 1.0);
+        assert_eq!(hsv.value, 1.0);
+
+        let gray = Hsv {
+            hue: 120.0,
+            saturation: 0.0,
+            value: 0.5,
+            alpha: 1.0,
+        };
+
+        assert_close(gray.to_color(), Color::from_rgb(0.5, 0.5, 0.5));
+        assert_close(gray.pure_hue(), Color::from_rgb(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn parse_and_format_hex() {
+        assert_eq!(parse_hex("#FF8000"), Ok(Color::from_rgb8(255, 128, 0)));
+        assert_eq!(parse_hex("f80"), Ok(Color::from_rgb8(255, 136, 0)));
+        assert_eq!(
+            parse_hex("#00000080"),
+            Ok(Color::from_rgba8(0, 0, 0, 128.0 / 255.0))
+        );
+        assert_eq!(parse_hex("#12345"), Err(ParseError));
+        assert_eq!(parse_hex("#GGGGGG"), Err(ParseError));
+        assert_eq!(parse_hex("#ÿÿÿ"), Err(ParseError));
+
+        assert_eq!(to_hex(Color::from_rgb8(18, 52, 86)), "#123456");
+        assert_eq!(
+            to_hex(Color::from_rgba8(18, 52, 86, 128.0 / 255.0)),
+            "#12345680"
+        );
+    }
+}
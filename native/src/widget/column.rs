@@ -1,6 +1,6 @@
 //! Distribute content vertically.
 use crate::event::{self, Event};
-use crate::layout;
+use crate::layout::{self, flex::Distribution};
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
@@ -19,6 +19,7 @@ pub struct Column<'a, Message, Renderer> {
     height: Length,
     max_width: f32,
     align_items: Alignment,
+    distribution: Distribution,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -39,6 +40,7 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
             height: Length::Shrink,
             max_width: f32::INFINITY,
             align_items: Alignment::Start,
+            distribution: Distribution::Start,
             children,
         }
     }
@@ -83,6 +85,13 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Distribution`] of the free vertical space between the
+    /// contents of the [`Column`].
+    pub fn distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -137,6 +146,7 @@ where
             self.padding,
             self.spacing,
             self.align_items,
+            self.distribution,
             &self.children,
         )
     }
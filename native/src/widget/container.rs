@@ -8,9 +8,11 @@ use crate::renderer;
 use crate::widget::{self, Operation, Tree};
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
-    Point, Rectangle, Shell, Widget,
+    Point, Rectangle, Shell, Size, Widget,
 };
 
+use std::ops::RangeInclusive;
+
 pub use iced_style::container::{Appearance, StyleSheet};
 
 /// An element decorating some content.
@@ -26,8 +28,11 @@ where
     padding: Padding,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
     max_width: f32,
     max_height: f32,
+    direction: Option<layout::Direction>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -49,8 +54,11 @@ where
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0.0,
+            min_height: 0.0,
             max_width: f32::INFINITY,
             max_height: f32::INFINITY,
+            direction: None,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: Default::default(),
@@ -82,6 +90,18 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Container`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Container`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = min_height.into().0;
+        self
+    }
+
     /// Sets the maximum width of the [`Container`].
     pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
         self.max_width = max_width.into().0;
@@ -94,6 +114,33 @@ where
         self
     }
 
+    /// Clamps the width of the [`Container`] between the bounds of the given
+    /// range, in pixels.
+    pub fn width_range(self, range: RangeInclusive<f32>) -> Self {
+        let (min, max) = range.into_inner();
+
+        self.min_width(min).max_width(max)
+    }
+
+    /// Clamps the height of the [`Container`] between the bounds of the given
+    /// range, in pixels.
+    pub fn height_range(self, range: RangeInclusive<f32>) -> Self {
+        let (min, max) = range.into_inner();
+
+        self.min_height(min).max_height(max)
+    }
+
+    /// Sets the [`layout::Direction`] of the [`Container`] and its contents.
+    ///
+    /// By default, a [`Container`] inherits the direction of its parent.
+    /// Setting it on the root of an application mirrors the whole interface,
+    /// and setting it back to [`layout::Direction::LeftToRight`] opts some
+    /// content out of the mirroring.
+    pub fn direction(mut self, direction: layout::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Container`].
     pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
         self.horizontal_alignment = alignment;
@@ -155,11 +202,18 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let limits = match self.direction {
+            Some(direction) => limits.with_direction(direction),
+            None => *limits,
+        };
+
         layout(
             renderer,
-            limits,
+            &limits,
             self.width,
             self.height,
+            self.min_width,
+            self.min_height,
             self.max_width,
             self.max_height,
             self.padding,
@@ -178,8 +232,9 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(
+        operation.container_with_bounds(
             self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
             &mut |operation| {
                 self.content.as_widget().operate(
                     &mut tree.children[0],
@@ -292,6 +347,8 @@ pub fn layout<Renderer>(
     limits: &layout::Limits,
     width: Length,
     height: Length,
+    min_width: f32,
+    min_height: f32,
     max_width: f32,
     max_height: f32,
     padding: Padding,
@@ -303,12 +360,21 @@ pub fn layout<Renderer>(
         .loose()
         .max_width(max_width)
         .max_height(max_height)
+        .min_width(min_width)
+        .min_height(min_height)
         .width(width)
         .height(height);
 
     let mut content = layout_content(renderer, &limits.pad(padding).loose());
     let padding = padding.fit(content.size(), limits.max());
-    let size = limits.pad(padding).resolve(content.size());
+    let limits = limits.pad(padding);
+    let size = limits.resolve(content.size());
+
+    // Filling the parent must not shrink the container below its minimum
+    let size = Size::new(
+        size.width.max(limits.min().width),
+        size.height.max(limits.min().height),
+    );
 
     content.move_to(Point::new(padding.left, padding.top));
     content.align(
@@ -317,7 +383,17 @@ pub fn layout<Renderer>(
         size,
     );
 
-    layout::Node::with_children(size.pad(padding), vec![content])
+    let baseline = content
+        .baseline()
+        .map(|baseline| content.bounds().y + baseline);
+    let mut node =
+        layout::Node::with_children(size.pad(padding), vec![content]);
+    node.mirror(limits.direction());
+
+    match baseline {
+        Some(baseline) => node.with_baseline(baseline),
+        None => node,
+    }
 }
 
 /// Draws the background of a [`Container`] given its [`Appearance`] and its `bounds`.
@@ -328,8 +404,11 @@ pub fn draw_background<Renderer>(
 ) where
     Renderer: crate::Renderer,
 {
-    if appearance.background.is_some() || appearance.border_width > 0.0 {
-        renderer.fill_quad(
+    if appearance.background.is_some()
+        || appearance.border_width > 0.0
+        || appearance.shadow.is_visible()
+    {
+        renderer.fill_quad_with_shadow(
             renderer::Quad {
                 bounds,
                 border_radius: appearance.border_radius.into(),
@@ -339,6 +418,7 @@ pub fn draw_background<Renderer>(
             appearance
                 .background
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            appearance.shadow,
         );
     }
 }
@@ -0,0 +1,1154 @@
+//! Display and edit large tables of data.
+//!
+//! A [`DataGrid`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+pub use iced_style::data_grid::{Appearance, StyleSheet};
+
+/// A grid of text cells with a fixed header, made for large datasets.
+///
+/// The cells are produced on demand by a function that takes their row and
+/// column, so only the visible ones are ever built. The first columns can be
+/// frozen, staying in place while the others scroll horizontally.
+///
+/// Clicking on a row selects it, and double-clicking on a cell of an
+/// editable [`Column`] edits it in place. The selection and the data are
+/// owned by the application, which is notified through messages.
+///
+/// # Example
+/// ```
+/// # type DataGrid<'a, Message> = iced_native::widget::DataGrid<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::data_grid::Column;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RowSelected(usize),
+///     CellEdited(usize, usize, String),
+/// }
+///
+/// let columns = vec![
+///     Column::new("Id", 60).editable(false),
+///     Column::new("Name", 200),
+///     Column::new("Email", 260),
+/// ];
+///
+/// let grid = DataGrid::new(columns, 100_000, |row, column| {
+///     format!("{row}:{column}")
+/// })
+/// .frozen(1)
+/// .on_select(|row, _modifiers| Message::RowSelected(row))
+/// .on_edit(Message::CellEdited);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DataGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    columns: Vec<Column>,
+    row_count: usize,
+    cell: Box<dyn Fn(usize, usize) -> String + 'a>,
+    frozen: usize,
+    selected: Cow<'a, [usize]>,
+    on_select: Option<Box<dyn Fn(usize, keyboard::Modifiers) -> Message + 'a>>,
+    on_edit: Option<Box<dyn Fn(usize, usize, String) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+/// A column of a [`DataGrid`].
+#[derive(Debug, Clone)]
+pub struct Column {
+    title: String,
+    width: f32,
+    editable: bool,
+    align_x: alignment::Horizontal,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given title and width.
+    ///
+    /// By default, the cells of the [`Column`] are editable.
+    pub fn new(title: impl Into<String>, width: impl Into<Pixels>) -> Self {
+        Self {
+            title: title.into(),
+            width: width.into().0,
+            editable: true,
+            align_x: alignment::Horizontal::Left,
+        }
+    }
+
+    /// Sets whether the cells of the [`Column`] can be edited.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Sets the horizontal alignment of the title and the cells of the
+    /// [`Column`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.align_x = alignment;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> DataGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The default height of the rows of a [`DataGrid`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 28.0;
+
+    /// The default [`Padding`] of the cells of a [`DataGrid`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 0.0,
+        right: 6.0,
+        bottom: 0.0,
+        left: 6.0,
+    };
+
+    /// Creates a new [`DataGrid`] with the given columns, the amount of rows,
+    /// and the function that produces the contents of the cell at a given row
+    /// and column.
+    pub fn new(
+        columns: Vec<Column>,
+        row_count: usize,
+        cell: impl Fn(usize, usize) -> String + 'a,
+    ) -> Self {
+        DataGrid {
+            columns,
+            row_count,
+            cell: Box::new(cell),
+            frozen: 0,
+            selected: Cow::Borrowed(&[]),
+            on_select: None,
+            on_edit: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the amount of leading columns of the [`DataGrid`] that stay in
+    /// place while scrolling horizontally.
+    pub fn frozen(mut self, frozen: usize) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Sets the indices of the selected rows of the [`DataGrid`].
+    pub fn selected(mut self, rows: impl Into<Cow<'a, [usize]>>) -> Self {
+        self.selected = rows.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a row is clicked, or
+    /// reached with the arrow keys.
+    ///
+    /// The function takes the index of the row and the keyboard modifiers
+    /// held at the time, so the application can extend the selection.
+    pub fn on_select(
+        mut self,
+        f: impl Fn(usize, keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the edition of a cell
+    /// is committed.
+    ///
+    /// The function takes the row, the column, and the new contents of the
+    /// cell. If it is not set, the cells cannot be edited.
+    pub fn on_edit(
+        mut self,
+        f: impl Fn(usize, usize, String) -> Message + 'a,
+    ) -> Self {
+        self.on_edit = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`DataGrid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`DataGrid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`DataGrid`], including its header.
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`DataGrid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DataGrid`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`DataGrid`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DataGrid`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the input used to edit the cells of the
+    /// [`DataGrid`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    fn geometry(&self, bounds: Rectangle, offset: Vector) -> Geometry {
+        let frozen = self.frozen.min(self.columns.len());
+        let frozen_width: f32 = self.columns[..frozen]
+            .iter()
+            .map(|column| column.width)
+            .sum();
+
+        let body = Rectangle {
+            y: bounds.y + self.row_height,
+            height: (bounds.height - self.row_height).max(0.0),
+            ..bounds
+        };
+
+        let content = Size::new(
+            self.columns.iter().map(|column| column.width).sum(),
+            self.row_count as f32 * self.row_height,
+        );
+
+        let max_offset = Vector::new(
+            (content.width - body.width).max(0.0),
+            (content.height - body.height).max(0.0),
+        );
+
+        let offset = Vector::new(
+            offset.x.clamp(0.0, max_offset.x),
+            offset.y.clamp(0.0, max_offset.y),
+        );
+
+        let mut x = bounds.x;
+
+        let columns = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let left = if index < frozen { x } else { x - offset.x };
+                x += column.width;
+
+                (left, column.width)
+            })
+            .collect();
+
+        Geometry {
+            bounds,
+            body,
+            frozen_width,
+            frozen,
+            content,
+            offset,
+            columns,
+        }
+    }
+
+    fn is_editable(&self, column: usize) -> bool {
+        self.on_edit.is_some()
+            && self
+                .columns
+                .get(column)
+                .map_or(false, |column| column.editable)
+    }
+
+    /// Lays out the input editing the given cell.
+    fn input_layout(
+        &self,
+        renderer: &Renderer,
+        geometry: &Geometry,
+        row: usize,
+        column: usize,
+    ) -> layout::Node {
+        let cell = geometry.cell(row, column, self.row_height);
+
+        let mut node = text_input::layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, cell.size()),
+            Length::Fixed(cell.width),
+            Padding {
+                top: ((cell.height
+                    - self
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size()))
+                    / 2.0)
+                    .max(0.0),
+                bottom: 0.0,
+                ..self.padding
+            },
+            self.text_size,
+            None,
+        );
+        node.move_to(cell.position());
+
+        node
+    }
+
+    fn commit(&self, editing: Editing, shell: &mut Shell<'_, Message>) {
+        if let Some(on_edit) = &self.on_edit {
+            if editing.text != editing.original {
+                shell.publish(on_edit(
+                    editing.row,
+                    editing.column,
+                    editing.text,
+                ));
+            }
+        }
+    }
+}
+
+/// The positions of the parts of a [`DataGrid`].
+#[derive(Debug, Clone)]
+struct Geometry {
+    bounds: Rectangle,
+    body: Rectangle,
+    frozen_width: f32,
+    frozen: usize,
+    content: Size,
+    offset: Vector,
+    /// The horizontal position and width of every column, scrolled.
+    columns: Vec<(f32, f32)>,
+}
+
+impl Geometry {
+    /// Returns the region where the columns that are not frozen are visible.
+    fn scrolled_region(&self, area: Rectangle) -> Rectangle {
+        Rectangle {
+            x: area.x + self.frozen_width,
+            width: (area.width - self.frozen_width).max(0.0),
+            ..area
+        }
+    }
+
+    /// Returns the visible part of a column within the given area.
+    fn column_clip(&self, column: usize, area: Rectangle) -> Option<Rectangle> {
+        let (x, width) = self.columns[column];
+
+        let region = if column < self.frozen {
+            area
+        } else {
+            self.scrolled_region(area)
+        };
+
+        Rectangle { x, width, ..area }.intersection(&region)
+    }
+
+    fn visible_rows(&self, row_count: usize, row_height: f32) -> Range<usize> {
+        visible_rows(row_count, row_height, self.offset.y, self.body.height)
+    }
+
+    fn cell(&self, row: usize, column: usize, row_height: f32) -> Rectangle {
+        let (x, width) = self.columns[column];
+
+        Rectangle {
+            x,
+            y: self.body.y + row as f32 * row_height - self.offset.y,
+            width,
+            height: row_height,
+        }
+    }
+
+    /// Returns the row and column of the cell under the given point.
+    fn cell_at(
+        &self,
+        point: Point,
+        row_count: usize,
+        row_height: f32,
+    ) -> Option<(usize, usize)> {
+        if !self.body.contains(point) || row_height <= 0.0 {
+            return None;
+        }
+
+        let row = ((point.y - self.body.y + self.offset.y) / row_height).floor()
+            as usize;
+
+        if row >= row_count {
+            return None;
+        }
+
+        let column = (0..self.columns.len()).find(|column| {
+            self.column_clip(*column, self.body)
+                .map_or(false, |clip| clip.contains(point))
+        })?;
+
+        Some((row, column))
+    }
+
+    /// Returns the thumbs of the vertical and horizontal scrollbars, if the
+    /// content overflows.
+    fn scrollbars(&self) -> (Option<Rectangle>, Option<Rectangle>) {
+        let vertical = (self.content.height > self.body.height).then(|| {
+            let ratio = self.body.height / self.content.height;
+
+            Rectangle {
+                x: self.body.x + self.body.width - SCROLLBAR_WIDTH,
+                y: self.body.y + self.offset.y * ratio,
+                width: SCROLLBAR_WIDTH,
+                height: self.body.height * ratio,
+            }
+        });
+
+        let track = self.scrolled_region(self.body);
+        let scrolled_width = self.content.width - self.frozen_width;
+
+        let horizontal = (scrolled_width > track.width).then(|| {
+            let ratio = track.width / scrolled_width;
+
+            Rectangle {
+                x: track.x + self.offset.x * ratio,
+                y: track.y + track.height - SCROLLBAR_WIDTH,
+                width: track.width * ratio,
+                height: SCROLLBAR_WIDTH,
+            }
+        });
+
+        (vertical, horizontal)
+    }
+}
+
+/// The local state of a [`DataGrid`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    offset: Vector,
+    is_focused: bool,
+    cursor_row: Option<usize>,
+    editing: Option<Editing>,
+    dragging: Option<Dragging>,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a cell of the [`DataGrid`] is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+/// A cell being edited.
+#[derive(Debug, Clone)]
+struct Editing {
+    row: usize,
+    column: usize,
+    original: String,
+    text: String,
+    input: text_input::State,
+}
+
+/// A scrollbar being dragged.
+#[derive(Debug, Clone, Copy)]
+struct Dragging {
+    is_vertical: bool,
+    origin: Point,
+    offset: Vector,
+}
+
+/// An edit of the input of a [`DataGrid`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DataGrid<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(editing) = &state.editing {
+            if editing.row >= self.row_count
+                || editing.column >= self.columns.len()
+            {
+                state.editing = None;
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::new(
+            self.columns.iter().map(|column| column.width).sum(),
+            (self.row_count + 1) as f32 * self.row_height,
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(editing) = &mut state.editing {
+            operation.focusable(&mut editing.input, None);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let geometry = self.geometry(layout.bounds(), state.offset);
+        state.offset = geometry.offset;
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.modifiers = modifiers;
+        }
+
+        if let Some(dragging) = state.dragging {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    let delta = cursor_position - dragging.origin;
+
+                    let offset = if dragging.is_vertical {
+                        Vector::new(
+                            dragging.offset.x,
+                            dragging.offset.y
+                                + delta.y * geometry.content.height
+                                    / geometry.body.height.max(1.0),
+                        )
+                    } else {
+                        let track = geometry.scrolled_region(geometry.body);
+
+                        Vector::new(
+                            dragging.offset.x
+                                + delta.x
+                                    * (geometry.content.width
+                                        - geometry.frozen_width)
+                                    / track.width.max(1.0),
+                            dragging.offset.y,
+                        )
+                    };
+
+                    state.offset =
+                        self.geometry(geometry.bounds, offset).offset;
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.dragging = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(mut editing) = state.editing.take() {
+            let is_outside = !geometry
+                .cell(editing.row, editing.column, self.row_height)
+                .contains(cursor_position);
+
+            match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    ..
+                }) => {
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if is_outside =>
+                {
+                    // Leaving the cell commits the edition, and the press
+                    // goes on as usual
+                    self.commit(editing, shell);
+                }
+                _ => {
+                    let node = self.input_layout(
+                        renderer,
+                        &geometry,
+                        editing.row,
+                        editing.column,
+                    );
+
+                    let mut value = Value::new(&editing.text);
+                    let mut edits = Vec::new();
+                    let mut input_shell = Shell::new(&mut edits);
+
+                    let status = text_input::update(
+                        event,
+                        Layout::new(&node),
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        &mut input_shell,
+                        &mut value,
+                        self.text_size,
+                        &self.font,
+                        false,
+                        &text_input::Options::default(),
+                        &Edit::Changed,
+                        None,
+                        None,
+                        &Some(Edit::Submitted),
+                        || &mut editing.input,
+                    );
+
+                    if let Some(redraw_request) = input_shell.redraw_request() {
+                        shell.request_redraw(redraw_request);
+                    }
+
+                    let mut is_submitted = false;
+
+                    for edit in edits {
+                        match edit {
+                            Edit::Changed(text) => editing.text = text,
+                            Edit::Submitted => is_submitted = true,
+                        }
+                    }
+
+                    if is_submitted {
+                        self.commit(editing, shell);
+                    } else {
+                        state.editing = Some(editing);
+                    }
+
+                    return status;
+                }
+            }
+        }
+
+        let is_over = geometry.bounds.contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if is_over => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        if state.modifiers.shift() {
+                            Vector::new(y, x) * 60.0
+                        } else {
+                            Vector::new(x, y) * 60.0
+                        }
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+
+                state.offset =
+                    self.geometry(geometry.bounds, state.offset - delta).offset;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = is_over;
+
+                if !is_over {
+                    return event::Status::Ignored;
+                }
+
+                let (vertical, horizontal) = geometry.scrollbars();
+
+                for (thumb, is_vertical) in
+                    [(vertical, true), (horizontal, false)]
+                {
+                    if thumb
+                        .map_or(false, |thumb| thumb.contains(cursor_position))
+                    {
+                        state.dragging = Some(Dragging {
+                            is_vertical,
+                            origin: cursor_position,
+                            offset: state.offset,
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                let (row, column) = match geometry.cell_at(
+                    cursor_position,
+                    self.row_count,
+                    self.row_height,
+                ) {
+                    Some(cell) => cell,
+                    None => return event::Status::Captured,
+                };
+
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+                state.last_click = Some(click);
+                state.cursor_row = Some(row);
+
+                if matches!(click.kind(), click::Kind::Double)
+                    && self.is_editable(column)
+                {
+                    let text = (self.cell)(row, column);
+                    let mut input = text_input::State::new();
+                    input.focus();
+
+                    state.editing = Some(Editing {
+                        row,
+                        column,
+                        original: text.clone(),
+                        text,
+                        input,
+                    });
+                } else if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(row, state.modifiers));
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused && self.row_count > 0 => {
+                let row = match (key_code, state.cursor_row) {
+                    (keyboard::KeyCode::Up, Some(row)) => row.saturating_sub(1),
+                    (keyboard::KeyCode::Down, Some(row)) => {
+                        (row + 1).min(self.row_count - 1)
+                    }
+                    (keyboard::KeyCode::Up | keyboard::KeyCode::Down, None) => {
+                        0
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                state.cursor_row = Some(row);
+
+                // Scroll the row into view
+                let top = row as f32 * self.row_height;
+                let offset = Vector::new(
+                    state.offset.x,
+                    state
+                        .offset
+                        .y
+                        .min(top)
+                        .max(top + self.row_height - geometry.body.height),
+                );
+                state.offset = self.geometry(geometry.bounds, offset).offset;
+
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(row, state.modifiers));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let geometry = self.geometry(layout.bounds(), state.offset);
+
+        if let Some(editing) = &state.editing {
+            let node = self.input_layout(
+                renderer,
+                &geometry,
+                editing.row,
+                editing.column,
+            );
+
+            if node.bounds().contains(cursor_position) {
+                return text_input::mouse_interaction(
+                    Layout::new(&node),
+                    cursor_position,
+                );
+            }
+        }
+
+        if state.dragging.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let geometry = self.geometry(layout.bounds(), state.offset);
+        let rows = geometry.visible_rows(self.row_count, self.row_height);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let fill = |renderer: &mut Renderer,
+                    bounds: Rectangle,
+                    background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        };
+
+        let draw_text = |renderer: &mut Renderer,
+                         content: &str,
+                         cell: Rectangle,
+                         column: &Column,
+                         color: Color| {
+            let x = match column.align_x {
+                alignment::Horizontal::Left => cell.x + self.padding.left,
+                alignment::Horizontal::Center => cell.center_x(),
+                alignment::Horizontal::Right => {
+                    cell.x + cell.width - self.padding.right
+                }
+            };
+
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x,
+                    y: cell.center_y(),
+                    ..cell
+                },
+                size: text_size,
+                color,
+                font: self.font.clone(),
+                horizontal_alignment: column.align_x,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        if let Some(background) = appearance.background {
+            fill(renderer, geometry.bounds, background);
+        }
+
+        let hovered = geometry
+            .cell_at(cursor_position, self.row_count, self.row_height)
+            .map(|(row, _)| row);
+
+        renderer.with_layer(geometry.body, |renderer| {
+            for row in rows.clone() {
+                let bounds = Rectangle {
+                    y: geometry.body.y + row as f32 * self.row_height
+                        - geometry.offset.y,
+                    height: self.row_height,
+                    ..geometry.body
+                };
+
+                if self.selected.contains(&row) {
+                    fill(renderer, bounds, appearance.selected_background);
+                } else if let (Some(background), true) =
+                    (appearance.hovered_background, hovered == Some(row))
+                {
+                    fill(renderer, bounds, background);
+                }
+
+                if appearance.divider_width > 0.0 {
+                    fill(
+                        renderer,
+                        Rectangle {
+                            y: bounds.y + bounds.height
+                                - appearance.divider_width,
+                            height: appearance.divider_width,
+                            ..bounds
+                        },
+                        appearance.divider_color.into(),
+                    );
+                }
+            }
+
+            for (index, column) in self.columns.iter().enumerate() {
+                let clip = match geometry.column_clip(index, geometry.body) {
+                    Some(clip) => clip,
+                    None => continue,
+                };
+
+                renderer.with_layer(clip, |renderer| {
+                    for row in rows.clone() {
+                        let is_edited =
+                            state.editing.as_ref().map_or(false, |editing| {
+                                editing.row == row && editing.column == index
+                            });
+
+                        if is_edited {
+                            continue;
+                        }
+
+                        let color = if self.selected.contains(&row) {
+                            appearance.selected_text_color
+                        } else {
+                            appearance.text_color
+                        };
+
+                        draw_text(
+                            renderer,
+                            &(self.cell)(row, index),
+                            geometry.cell(row, index, self.row_height),
+                            column,
+                            color,
+                        );
+                    }
+                });
+            }
+
+            if let Some(editing) = &state.editing {
+                let node = self.input_layout(
+                    renderer,
+                    &geometry,
+                    editing.row,
+                    editing.column,
+                );
+
+                text_input::draw(
+                    renderer,
+                    theme,
+                    Layout::new(&node),
+                    cursor_position,
+                    &editing.input,
+                    &Value::new(&editing.text),
+                    "",
+                    self.text_size,
+                    &self.font,
+                    false,
+                    &text_input::Options::default(),
+                    &self.input_style,
+                );
+            }
+        });
+
+        let header = Rectangle {
+            height: self.row_height.min(geometry.bounds.height),
+            ..geometry.bounds
+        };
+
+        if let Some(background) = appearance.header_background {
+            fill(renderer, header, background);
+        }
+
+        for (index, column) in self.columns.iter().enumerate() {
+            let clip = match geometry.column_clip(index, header) {
+                Some(clip) => clip,
+                None => continue,
+            };
+
+            let (x, width) = geometry.columns[index];
+
+            renderer.with_layer(clip, |renderer| {
+                draw_text(
+                    renderer,
+                    &column.title,
+                    Rectangle { x, width, ..header },
+                    column,
+                    appearance.header_text_color,
+                );
+            });
+
+            if appearance.divider_width > 0.0 {
+                let divider = Rectangle {
+                    x: x + width - appearance.divider_width,
+                    width: appearance.divider_width,
+                    ..geometry.bounds
+                };
+
+                let region = if index < geometry.frozen {
+                    geometry.bounds
+                } else {
+                    geometry.scrolled_region(geometry.bounds)
+                };
+
+                if let Some(divider) = divider.intersection(&region) {
+                    fill(renderer, divider, appearance.divider_color.into());
+                }
+            }
+        }
+
+        if geometry.frozen > 0 && geometry.frozen < self.columns.len() {
+            fill(
+                renderer,
+                Rectangle {
+                    x: geometry.bounds.x + geometry.frozen_width
+                        - FROZEN_DIVIDER_WIDTH / 2.0,
+                    width: FROZEN_DIVIDER_WIDTH,
+                    ..geometry.bounds
+                },
+                appearance.frozen_divider_color.into(),
+            );
+        }
+
+        let (vertical, horizontal) = geometry.scrollbars();
+
+        for thumb in [vertical, horizontal].into_iter().flatten() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: thumb,
+                    border_radius: (SCROLLBAR_WIDTH / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.scrollbar_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<DataGrid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(data_grid: DataGrid<'a, Message, Renderer>) -> Self {
+        Element::new(data_grid)
+    }
+}
+
+/// Returns the indices of the rows that intersect a viewport of the given
+/// height, scrolled by the given offset.
+fn visible_rows(
+    row_count: usize,
+    row_height: f32,
+    offset: f32,
+    height: f32,
+) -> Range<usize> {
+    if row_count == 0 || row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let start = (offset.max(0.0) / row_height).floor() as usize;
+    let end = ((offset.max(0.0) + height) / row_height).ceil() as usize;
+
+    start.min(row_count)..end.min(row_count)
+}
+
+/// The width of the scrollbars of a [`DataGrid`].
+const SCROLLBAR_WIDTH: f32 = 6.0;
+
+/// The width of the line after the frozen columns.
+const FROZEN_DIVIDER_WIDTH: f32 = 2.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_rows_cover_the_viewport() {
+        assert_eq!(visible_rows(1_000, 20.0, 0.0, 100.0), 0..5);
+        assert_eq!(visible_rows(1_000, 20.0, 10.0, 100.0), 0..6);
+        assert_eq!(visible_rows(1_000, 20.0, 19_950.0, 100.0), 997..1_000);
+        assert_eq!(visible_rows(0, 20.0, 0.0, 100.0), 0..0);
+    }
+}
@@ -0,0 +1,525 @@
+//! Let your users pick a date by typing it or choosing it in a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+mod date;
+mod overlay;
+
+pub use date::{days_in_month, is_leap_year, Date, ParseError, Weekday};
+pub use iced_style::date_picker::{Appearance, StyleSheet};
+
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay as native_overlay;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+/// The names of the months and weekdays displayed by a [`DatePicker`], and
+/// the first day of its weeks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    /// The names of the months, starting on January.
+    pub months: [String; 12],
+    /// The short names of the weekdays, starting on Monday.
+    pub weekdays: [String; 7],
+    /// The first day of the week.
+    pub first_weekday: Weekday,
+}
+
+impl Locale {
+    /// Creates a new [`Locale`] with the given names of the months, starting
+    /// on January, the short names of the weekdays, starting on Monday, and
+    /// the first day of the week.
+    pub fn new(
+        months: [&str; 12],
+        weekdays: [&str; 7],
+        first_weekday: Weekday,
+    ) -> Self {
+        Self {
+            months: months.map(String::from),
+            weekdays: weekdays.map(String::from),
+            first_weekday,
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new(
+            [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+            Weekday::Monday,
+        )
+    }
+}
+
+/// A field that can be filled with a date, either by typing it in the
+/// `YYYY-MM-DD` format or by choosing it in a calendar that opens under the
+/// field.
+///
+/// The calendar can be navigated with the arrow keys, `PageUp` and
+/// `PageDown`, and the focused day is chosen with `Enter`.
+///
+/// # Example
+/// ```
+/// # pub type DatePicker<'a, Message> = iced_native::widget::DatePicker<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::date_picker::Date;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DateChanged(Date),
+/// }
+///
+/// let date = Date::new(2023, 4, 12);
+///
+/// let picker = DatePicker::new(date, Message::DateChanged)
+///     .min(Date::new(2023, 1, 1).unwrap())
+///     .disabled(|date| date.weekday().index() >= 5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    value: Option<Date>,
+    on_change: Box<dyn Fn(Date) -> Message + 'a>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    today: Option<Date>,
+    locale: Locale,
+    placeholder: String,
+    font: Renderer::Font,
+    width: Length,
+    padding: Padding,
+    size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`DatePicker`].
+    ///
+    /// It expects:
+    /// - the current date, if any, and
+    /// - a function that produces a message when a new date is picked.
+    pub fn new<F>(value: Option<Date>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        DatePicker {
+            value,
+            on_change: Box::new(on_change),
+            min: None,
+            max: None,
+            is_disabled: None,
+            today: None,
+            locale: Locale::default(),
+            placeholder: String::from("YYYY-MM-DD"),
+            font: Default::default(),
+            width: Length::Fill,
+            padding: Padding::new(5.0),
+            size: None,
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the earliest date that can be picked in the [`DatePicker`].
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest date that can be picked in the [`DatePicker`].
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets both the earliest and latest dates that can be picked in the
+    /// [`DatePicker`].
+    pub fn bounds(self, bounds: RangeInclusive<Date>) -> Self {
+        let (min, max) = bounds.into_inner();
+
+        self.min(min).max(max)
+    }
+
+    /// Sets a function that returns whether a date cannot be picked in the
+    /// [`DatePicker`], like weekends or holidays.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the current date, which is marked in the calendar of the
+    /// [`DatePicker`] and shown when no date is picked yet.
+    pub fn today(mut self, today: Date) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Sets the [`Locale`] of the calendar of the [`DatePicker`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the placeholder of the [`DatePicker`].
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = String::from(placeholder);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`DatePicker`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the calendar of the [`DatePicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the text field of the [`DatePicker`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    /// Returns whether the given date can be picked.
+    fn is_selectable(&self, date: Date) -> bool {
+        !matches!(self.min, Some(min) if date < min)
+            && !matches!(self.max, Some(max) if date > max)
+            && !self.is_disabled.as_ref().map_or(false, |f| f(date))
+    }
+
+    /// Returns the given date moved inside of the bounds.
+    fn clamp(&self, date: Date) -> Date {
+        match (self.min, self.max) {
+            (Some(min), _) if date < min => min,
+            (_, Some(max)) if date > max => max,
+            _ => date,
+        }
+    }
+
+    /// Returns the day focused when the calendar is opened.
+    fn initial_focus(&self) -> Date {
+        self.clamp(self.value.or(self.today).unwrap_or(Date::EPOCH))
+    }
+
+    fn text(&self) -> String {
+        self.value.map(|date| date.to_string()).unwrap_or_default()
+    }
+
+    fn select(
+        &self,
+        date: Date,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.text = date.to_string();
+        state.input.move_cursor_to_end();
+        state.focused = date;
+        state.is_open = false;
+
+        shell.publish((self.on_change)(date));
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.text(), self.initial_focus()))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_input::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+            None,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.input.is_focused() {
+            state.text = self.text();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !state.is_open
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                // The text field still gets the event, so it is focused
+                state.is_open = true;
+                state.focused = self.initial_focus();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if !state.is_open && state.input.is_focused() => {
+                state.is_open = true;
+                state.focused = self.initial_focus();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let mut value = Value::new(&state.text);
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &|text| text,
+            None,
+            None,
+            &None,
+            || &mut state.input,
+        );
+
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(text) = edits.pop() {
+            if let Ok(date) = text.parse::<Date>() {
+                if self.is_selectable(date) {
+                    state.focused = date;
+
+                    shell.publish((self.on_change)(date));
+                }
+            }
+
+            state.text = text;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let value = if state.input.is_focused() {
+            Value::new(&state.text)
+        } else {
+            Value::new(&self.text())
+        };
+
+        text_input::draw(
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            &state.input,
+            &value,
+            &self.placeholder,
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &self.input_style,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_input::mouse_interaction(layout, cursor_position)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<native_overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_open {
+            Some(native_overlay::Element::new(
+                layout.position(),
+                Box::new(overlay::Overlay::new(self, state, layout.bounds())),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(date_picker)
+    }
+}
+
+/// The local state of a [`DatePicker`].
+#[derive(Debug, Clone)]
+pub struct State {
+    input: text_input::State,
+    text: String,
+    is_open: bool,
+    focused: Date,
+}
+
+impl State {
+    /// Creates a new [`State`] displaying the given text, with the given day
+    /// focused in its calendar.
+    pub fn new(text: String, focused: Date) -> Self {
+        Self {
+            input: text_input::State::new(),
+            text,
+            is_open: false,
+            focused,
+        }
+    }
+
+    /// Returns whether the [`DatePicker`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Returns whether the calendar of the [`DatePicker`] is open or not.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The first day of 1970, the origin of the days returned by
+    /// [`Date::days_since_epoch`].
+    pub const EPOCH: Date = Date {
+        year: 1970,
+        month: 1,
+        day: 1,
+    };
+
+    /// Creates a new [`Date`] with the given year, month (from 1 to 12) and
+    /// day (from 1), if it exists.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(&self) -> Weekday {
+        // The epoch was a Thursday
+        Weekday::ALL[(self.days_since_epoch() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the amount of days in the month of the [`Date`].
+    pub fn days_in_month(&self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(self) -> Self {
+        Date { day: 1, ..self }
+    }
+
+    /// Returns the [`Date`] the given amount of days after this one.
+    ///
+    /// The amount may be negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the [`Date`] the given amount of months after this one.
+    ///
+    /// The day is clamped to the length of the resulting month. The amount
+    /// may be negative.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = i64::from(self.year) * 12
+            + i64::from(self.month - 1)
+            + i64::from(months);
+
+        let year = index.div_euclid(12) as i32;
+        let month = index.rem_euclid(12) as u8 + 1;
+
+        Date {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the ISO 8601 week number of the [`Date`], from 1 to 53.
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with
+    /// its first Thursday.
+    pub fn iso_week(&self) -> u8 {
+        // A week belongs to the year of its Thursday
+        let thursday = self.add_days(3 - self.weekday().index() as i64);
+        let first_of_year = Date {
+            year: thursday.year,
+            month: 1,
+            day: 1,
+        };
+
+        let day_of_year =
+            thursday.days_since_epoch() - first_of_year.days_since_epoch();
+
+        (day_of_year / 7 + 1) as u8
+    }
+
+    /// Returns the amount of days between [`Date::EPOCH`] and this
+    /// [`Date`].
+    pub fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's `days_from_civil`
+        let month = i64::from(self.month);
+        let day = i64::from(self.day);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+                + day
+                - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the [`Date`] the given amount of days after
+    /// [`Date::EPOCH`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // Howard Hinnant's `civil_from_days`
+        let days = days + 719_468;
+        let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseError;
+
+    /// Parses a [`Date`] in the `YYYY-MM-DD` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().rsplitn(3, '-');
+
+        let day = parts.next().ok_or(ParseError)?;
+        let month = parts.next().ok_or(ParseError)?;
+        let year = parts.next().ok_or(ParseError)?;
+
+        Date::new(
+            year.parse().map_err(|_| ParseError)?,
+            month.parse().map_err(|_| ParseError)?,
+            day.parse().map_err(|_| ParseError)?,
+        )
+        .ok_or(ParseError)
+    }
+}
+
+/// An error produced when parsing an invalid [`Date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date, expected the YYYY-MM-DD format")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All the days of the week, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the index of the [`Weekday`], starting at 0 on Monday.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the amount of days from the given [`Weekday`] until this one.
+    pub fn days_since(self, other: Weekday) -> usize {
+        (self.index() + 7 - other.index()) % 7
+    }
+}
+
+/// Returns whether the given year is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the amount of days in the given month of the given year.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(2023, 13, 1), None);
+        assert_eq!(Date::new(2023, 4, 31), None);
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert_eq!(Date::new(1900, 2, 29), None);
+    }
+
+    #[test]
+    fn days_since_epoch_round_trip() {
+        assert_eq!(Date::EPOCH.days_since_epoch(), 0);
+        assert_eq!(date(2000, 3, 1).days_since_epoch(), 11_017);
+        assert_eq!(date(1969, 12, 31).days_since_epoch(), -1);
+
+        for days in -800_000..800_000 {
+            let days = days * 3;
+
+            assert_eq!(
+                Date::from_days_since_epoch(days).days_since_epoch(),
+                days
+            );
+        }
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(Date::EPOCH.weekday(), Weekday::Thursday);
+        assert_eq!(date(2023, 4, 3).weekday(), Weekday::Monday);
+        assert_eq!(date(1900, 1, 1).weekday(), Weekday::Monday);
+        assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+        assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+    }
+
+    #[test]
+    fn add_days_and_months() {
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+    }
+
+    #[test]
+    fn iso_week() {
+        assert_eq!(date(2021, 1, 3).iso_week(), 53);
+        assert_eq!(date(2021, 1, 4).iso_week(), 1);
+        assert_eq!(date(2024, 12, 30).iso_week(), 1);
+        assert_eq!(date(2026, 10, 17).iso_week(), 42);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("2024-02-29".parse(), Ok(date(2024, 2, 29)));
+        assert_eq!(" 2024-2-9 ".parse(), Ok(date(2024, 2, 9)));
+        assert_eq!("-44-03-15".parse(), Ok(date(-44, 3, 15)));
+        assert_eq!("2023-02-29".parse::<Date>(), Err(ParseError));
+        assert_eq!("2023-02".parse::<Date>(), Err(ParseError));
+        assert_eq!(date(812, 12, 25).to_string(), "0812-12-25");
+    }
+}
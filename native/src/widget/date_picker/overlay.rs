@@ -0,0 +1,401 @@
+use super::{Date, DatePicker, State, StyleSheet, Weekday};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay::menu;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input;
+use crate::{Clipboard, Color, Layout, Point, Rectangle, Shell, Size};
+
+/// The amount of rows of the calendar: its header, the names of the
+/// weekdays and six weeks.
+const ROWS: usize = 8;
+
+/// The calendar of an open [`DatePicker`].
+pub(super) struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    picker: &'a DatePicker<'b, Message, Renderer>,
+    state: &'a mut State,
+    target: Rectangle,
+}
+
+/// A part of the calendar that can be clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    PreviousMonth,
+    NextMonth,
+    Day(Date),
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates the calendar of the given [`DatePicker`], attached to its
+    /// bounds.
+    pub(super) fn new(
+        picker: &'a DatePicker<'b, Message, Renderer>,
+        state: &'a mut State,
+        target: Rectangle,
+    ) -> Self {
+        Self {
+            picker,
+            state,
+            target,
+        }
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> f32 {
+        self.picker.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the first day displayed by the calendar, which may belong to
+    /// the previous month.
+    fn first_day(&self) -> Date {
+        let month = self.state.focused.first_of_month();
+        let offset =
+            month.weekday().days_since(self.picker.locale.first_weekday);
+
+        month.add_days(-(offset as i64))
+    }
+
+    /// Returns the bounds of the cell at the given row and column.
+    fn cell(
+        &self,
+        bounds: Rectangle,
+        cell_size: f32,
+        row: usize,
+        column: usize,
+    ) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.picker.padding.left + column as f32 * cell_size,
+            y: bounds.y + self.picker.padding.top + row as f32 * cell_size,
+            width: cell_size,
+            height: cell_size,
+        }
+    }
+
+    fn target_at(
+        &self,
+        bounds: Rectangle,
+        cell_size: f32,
+        point: Point,
+    ) -> Option<Target> {
+        let x = point.x - bounds.x - self.picker.padding.left;
+        let y = point.y - bounds.y - self.picker.padding.top;
+
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let column = (x / cell_size) as usize;
+        let row = (y / cell_size) as usize;
+
+        if column >= 7 || row >= ROWS {
+            return None;
+        }
+
+        match (row, column) {
+            (0, 0) => Some(Target::PreviousMonth),
+            (0, 6) => Some(Target::NextMonth),
+            (0 | 1, _) => None,
+            (week, day) => Some(Target::Day(
+                self.first_day().add_days(((week - 2) * 7 + day) as i64),
+            )),
+        }
+    }
+
+    fn focus(&mut self, date: Date) {
+        self.state.focused = self.picker.clamp(date);
+    }
+}
+
+impl<'a, 'b, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let cell_size = self.text_size(renderer) * 2.0;
+        let size = Size::new(
+            cell_size * 7.0 + self.picker.padding.horizontal(),
+            cell_size * ROWS as f32 + self.picker.padding.vertical(),
+        );
+
+        menu::layout_attached(bounds, position, self.target.height, |_| {
+            layout::Node::new(size)
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let cell_size = self.text_size(renderer) * 2.0;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if bounds.contains(cursor_position) {
+                    match self.target_at(bounds, cell_size, cursor_position) {
+                        Some(Target::PreviousMonth) => {
+                            self.focus(self.state.focused.add_months(-1));
+                        }
+                        Some(Target::NextMonth) => {
+                            self.focus(self.state.focused.add_months(1));
+                        }
+                        Some(Target::Day(date))
+                            if self.picker.is_selectable(date) =>
+                        {
+                            self.picker.select(date, self.state, shell);
+                        }
+                        _ => {}
+                    }
+
+                    return event::Status::Captured;
+                } else if !self.target.contains(cursor_position) {
+                    self.state.is_open = false;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(Target::Day(date)) =
+                    self.target_at(bounds, cell_size, cursor_position)
+                {
+                    // Hovering the days of other months does not change the
+                    // displayed month
+                    if date.first_of_month()
+                        == self.state.focused.first_of_month()
+                    {
+                        self.state.focused = date;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    self.focus(self.state.focused.add_months(if y > 0.0 {
+                        -1
+                    } else {
+                        1
+                    }));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.input.is_focused() => {
+                let focused = self.state.focused;
+
+                let date = match key_code {
+                    keyboard::KeyCode::Left => focused.add_days(-1),
+                    keyboard::KeyCode::Right => focused.add_days(1),
+                    keyboard::KeyCode::Up => focused.add_days(-7),
+                    keyboard::KeyCode::Down => focused.add_days(7),
+                    keyboard::KeyCode::PageUp => focused.add_months(-1),
+                    keyboard::KeyCode::PageDown => focused.add_months(1),
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if self.picker.is_selectable(focused) {
+                            self.picker.select(focused, self.state, shell);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_open = false;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.focus(date);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cell_size = self.text_size(renderer) * 2.0;
+
+        match self.target_at(layout.bounds(), cell_size, cursor_position) {
+            Some(Target::PreviousMonth | Target::NextMonth) => {
+                mouse::Interaction::Pointer
+            }
+            Some(Target::Day(date)) if self.picker.is_selectable(date) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(&self.picker.style);
+        let bounds = layout.bounds();
+        let text_size = self.text_size(renderer);
+        let cell_size = text_size * 2.0;
+        let locale = &self.picker.locale;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let label = |renderer: &mut Renderer,
+                     content: &str,
+                     bounds: Rectangle,
+                     color: Color| {
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color,
+                font: self.picker.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        // Header
+        let month = self.state.focused.first_of_month();
+        let title = self.cell(bounds, cell_size, 0, 1);
+
+        label(
+            renderer,
+            "<",
+            self.cell(bounds, cell_size, 0, 0),
+            appearance.text_color,
+        );
+        label(
+            renderer,
+            &format!(
+                "{} {}",
+                locale.months[usize::from(month.month() - 1)],
+                month.year()
+            ),
+            Rectangle {
+                width: cell_size * 5.0,
+                ..title
+            },
+            appearance.text_color,
+        );
+        label(
+            renderer,
+            ">",
+            self.cell(bounds, cell_size, 0, 6),
+            appearance.text_color,
+        );
+
+        // Weekdays
+        for column in 0..7 {
+            let weekday =
+                Weekday::ALL[(locale.first_weekday.index() + column) % 7];
+
+            label(
+                renderer,
+                &locale.weekdays[weekday.index()],
+                self.cell(bounds, cell_size, 1, column),
+                appearance.weekday_color,
+            );
+        }
+
+        // Days
+        let first_day = self.first_day();
+
+        for index in 0..42 {
+            let date = first_day.add_days(index as i64);
+            let cell = self.cell(bounds, cell_size, 2 + index / 7, index % 7);
+
+            let is_selected = self.picker.value == Some(date);
+            let is_selectable = self.picker.is_selectable(date);
+            let is_focused = date == self.state.focused;
+            let is_today = self.picker.today == Some(date);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if is_focused && is_selectable {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+
+            if background.is_some() || is_today {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell,
+                        border_radius: (cell_size / 2.0).into(),
+                        border_width: if is_today { 1.0 } else { 0.0 },
+                        border_color: appearance.today_border_color,
+                    },
+                    background.unwrap_or(Color::TRANSPARENT.into()),
+                );
+            }
+
+            let color = if is_selected {
+                appearance.selected_text_color
+            } else if !is_selectable {
+                appearance.disabled_text_color
+            } else if date.first_of_month() != month {
+                appearance.weekday_color
+            } else {
+                appearance.text_color
+            };
+
+            label(renderer, &date.day().to_string(), cell, color);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Hand the payload of a [`Draggable`] over to a [`DropZone`].
+//!
+//! Both widgets can live anywhere in the widget tree, so the payload of the
+//! current drag is kept aside until a drop zone claims it.
+//!
+//! [`Draggable`]: crate::widget::Draggable
+//! [`DropZone`]: crate::widget::DropZone
+use std::any::Any;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The drag in progress, if any.
+    static SESSION: RefCell<Option<Session>> = RefCell::new(None);
+}
+
+struct Session {
+    payload: Box<dyn Any>,
+    is_released: bool,
+}
+
+/// Starts a drag carrying the given payload, replacing any previous one.
+pub(crate) fn start<P: 'static>(payload: P) {
+    SESSION.with(|session| {
+        *session.borrow_mut() = Some(Session {
+            payload: Box::new(payload),
+            is_released: false,
+        });
+    });
+}
+
+/// Marks the current drag as released, so a drop zone under the cursor can
+/// claim its payload while the release event is processed.
+pub(crate) fn release() {
+    SESSION.with(|session| {
+        if let Some(session) = session.borrow_mut().as_mut() {
+            session.is_released = true;
+        }
+    });
+}
+
+/// Cancels the current drag, if any.
+pub(crate) fn cancel() {
+    SESSION.with(|session| {
+        *session.borrow_mut() = None;
+    });
+}
+
+/// Forgets a released drag that no drop zone claimed.
+///
+/// This is called on every event that is not a release, so a drop zone
+/// cannot claim a payload dropped somewhere else in the past.
+pub(crate) fn forget_released() {
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+
+        if session
+            .as_ref()
+            .map_or(false, |session| session.is_released)
+        {
+            *session = None;
+        }
+    });
+}
+
+/// Returns whether a payload of the given type is being dragged.
+pub(crate) fn is_dragging<P: 'static>() -> bool {
+    SESSION.with(|session| {
+        session.borrow().as_ref().map_or(false, |session| {
+            !session.is_released && session.payload.is::<P>()
+        })
+    })
+}
+
+/// Claims the payload of the current drag, if it has the given type.
+pub(crate) fn take<P: 'static>() -> Option<P> {
+    SESSION.with(|session| {
+        let mut session = session.borrow_mut();
+
+        if !session.as_ref()?.payload.is::<P>() {
+            return None;
+        }
+
+        session
+            .take()
+            .and_then(|session| session.payload.downcast().ok())
+            .map(|payload| *payload)
+    })
+}
+
+/// Returns whether the given event drops what is being dragged.
+pub(crate) fn is_release(event: &crate::Event) -> bool {
+    use crate::{mouse, touch, Event};
+
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_is_claimed_once_by_its_type() {
+        start(42_u32);
+
+        assert!(is_dragging::<u32>());
+        assert!(!is_dragging::<String>());
+
+        release();
+
+        assert!(!is_dragging::<u32>());
+        assert_eq!(take::<String>(), None);
+        assert_eq!(take::<u32>(), Some(42));
+        assert_eq!(take::<u32>(), None);
+
+        start(7_u32);
+        release();
+        forget_released();
+
+        assert_eq!(take::<u32>(), None);
+    }
+}
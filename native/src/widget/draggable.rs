@@ -0,0 +1,427 @@
+//! Drag some content, with a payload, over a [`DropZone`].
+//!
+//! A [`Draggable`] has some local [`State`].
+//!
+//! [`DropZone`]: crate::widget::DropZone
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::dnd;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// Some content that can be dragged around, carrying a payload.
+///
+/// The drag starts once the cursor moves a few pixels away from where the
+/// content was pressed, unless the content captured the press itself. A
+/// preview follows the cursor in an overlay, and the payload goes to the
+/// [`DropZone`] accepting payloads of its type under the cursor when it is
+/// released.
+///
+/// [`DropZone`]: crate::widget::DropZone
+///
+/// # Example
+/// ```
+/// # type Draggable<'a, T, Message> = iced_native::widget::Draggable<'a, T, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// struct Card(usize);
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardPicked(Card),
+/// }
+///
+/// let card = Draggable::new(Text::new("Write the docs"), Card(3))
+///     .on_drag(Message::CardPicked(Card(3)));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, T, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    // The content, followed by the preview, if any
+    children: Vec<Element<'a, Message, Renderer>>,
+    payload: T,
+    on_drag: Option<Message>,
+}
+
+impl<'a, T, Message, Renderer> Draggable<'a, T, Message, Renderer>
+where
+    T: Clone + 'static,
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Draggable`] with the given content, carrying the given
+    /// payload.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        payload: T,
+    ) -> Self {
+        Draggable {
+            children: vec![content.into()],
+            payload,
+            on_drag: None,
+        }
+    }
+
+    /// Sets the preview following the cursor while the [`Draggable`] is
+    /// dragged.
+    ///
+    /// By default, the content itself is drawn under the cursor.
+    pub fn preview(
+        mut self,
+        preview: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.truncate(1);
+        self.children.push(preview.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Draggable`] starts
+    /// being dragged.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`Draggable`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    /// Where the content was pressed, if it was.
+    origin: Option<Point>,
+    /// The position of the cursor relative to the content when pressed.
+    grab: Vector,
+    position: Point,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Draggable`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, T, Message, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.children[0].as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.children[0].as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.children[0].as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.children[0].as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !dnd::is_release(&event) {
+            dnd::forget_released();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(origin) = state.origin {
+                    if !state.is_dragging
+                        && origin.distance(cursor_position) >= DRAG_THRESHOLD
+                    {
+                        state.is_dragging = true;
+                        dnd::start(self.payload.clone());
+
+                        if let Some(on_drag) = self.on_drag.clone() {
+                            shell.publish(on_drag);
+                        }
+                    }
+                }
+
+                if state.is_dragging {
+                    state.position = cursor_position;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                state.origin = None;
+
+                if state.is_dragging {
+                    state.is_dragging = false;
+
+                    // A drop zone may claim the payload later on, while
+                    // processing this same event
+                    dnd::release();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.origin = None;
+
+                if state.is_dragging {
+                    state.is_dragging = false;
+                    dnd::cancel();
+                }
+            }
+            _ => {}
+        }
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        let status = self.children[0].as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let bounds = layout.bounds();
+
+        if is_press
+            && status == event::Status::Ignored
+            && bounds.contains(cursor_position)
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.origin = Some(cursor_position);
+            state.grab = cursor_position - bounds.position();
+            state.position = cursor_position;
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.children[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = *tree.state.downcast_ref::<State>();
+
+        if !state.is_dragging {
+            return self.children[0].as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            );
+        }
+
+        // The content keeps its size when it is its own preview
+        let size = (self.children.len() == 1).then(|| layout.bounds().size());
+
+        let index = self.children.len() - 1;
+
+        Some(overlay::Element::new(
+            state.position - state.grab,
+            Box::new(Preview {
+                element: &mut self.children[index],
+                tree: &mut tree.children[index],
+                size,
+            }),
+        ))
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Draggable<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + 'static,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(draggable: Draggable<'a, T, Message, Renderer>) -> Self {
+        Element::new(draggable)
+    }
+}
+
+/// The preview of a [`Draggable`] following the cursor.
+struct Preview<'a, 'b, Message, Renderer> {
+    element: &'a mut Element<'b, Message, Renderer>,
+    tree: &'a mut Tree,
+    size: Option<Size>,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Preview<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = match self.size {
+            Some(size) => layout::Limits::new(size, size),
+            None => layout::Limits::new(Size::ZERO, bounds),
+        };
+
+        let mut node = self.element.as_widget().layout(renderer, &limits);
+        node.move_to(position);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.element.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The preview never hides what is under the cursor
+        false
+    }
+}
+
+/// The distance the cursor has to move before the content starts being
+/// dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
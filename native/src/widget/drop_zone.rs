@@ -0,0 +1,319 @@
+//! Receive the payload of a [`Draggable`] dropped over some content.
+//!
+//! A [`DropZone`] has some local [`State`].
+//!
+//! [`Draggable`]: crate::widget::Draggable
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::dnd;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Widget,
+};
+
+use std::marker::PhantomData;
+
+pub use iced_style::drop_zone::{Appearance, StyleSheet};
+
+/// Some content that accepts the payloads of type `T` dropped over it.
+///
+/// A [`DropZone`] highlights itself while a payload it accepts is dragged,
+/// and more so when the payload is right over it. When drop zones are
+/// nested, the innermost one under the cursor receives the payload.
+///
+/// # Example
+/// ```
+/// # type DropZone<'a, T, Message> = iced_native::widget::DropZone<'a, T, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// struct Card(usize);
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CardDropped(Card),
+/// }
+///
+/// let done = DropZone::new(Text::new("Done"), |card: Card, _position| {
+///     Message::CardDropped(card)
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DropZone<'a, T, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    on_drop: Box<dyn Fn(T, Point) -> Message + 'a>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    payload: PhantomData<T>,
+}
+
+impl<'a, T, Message, Renderer> DropZone<'a, T, Message, Renderer>
+where
+    T: 'static,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`DropZone`] with the given content, and the message to
+    /// produce when a payload is dropped over it.
+    ///
+    /// The function takes the payload and the position of the cursor,
+    /// relative to the [`DropZone`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_drop: impl Fn(T, Point) -> Message + 'a,
+    ) -> Self {
+        DropZone {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            style: Default::default(),
+            payload: PhantomData,
+        }
+    }
+
+    /// Sets the style of the [`DropZone`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`DropZone`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DropZone<'a, T, Message, Renderer>
+where
+    T: 'static,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let is_release = dnd::is_release(&event);
+
+        if !is_release {
+            dnd::forget_released();
+        }
+
+        // Nested drop zones get the chance to claim the payload first
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                state.is_hovered = bounds.contains(cursor_position);
+            }
+            _ if is_release => {
+                state.is_hovered = false;
+
+                if bounds.contains(cursor_position) {
+                    if let Some(payload) = dnd::take::<T>() {
+                        shell.publish((self.on_drop)(
+                            payload,
+                            Point::ORIGIN
+                                + (cursor_position - bounds.position()),
+                        ));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = dnd::is_dragging::<T>().then(|| {
+            if state.is_hovered {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            }
+        });
+
+        if let Some(background) =
+            appearance.and_then(|appearance| appearance.background)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance
+                        .map_or(0.0, |appearance| appearance.border_radius)
+                        .into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+
+        if let Some(appearance) = appearance {
+            if appearance.border_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    Background::Color(Color::TRANSPARENT),
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropZone<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static,
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(drop_zone: DropZone<'a, T, Message, Renderer>) -> Self {
+        Element::new(drop_zone)
+    }
+}
@@ -0,0 +1,327 @@
+//! Pin a floating action button to a corner of some content.
+//!
+//! A [`Fab`] has some local [`State`].
+mod overlay;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay as native_overlay;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+pub use crate::widget::button::{Appearance, StyleSheet};
+pub use crate::widget::positioned::Anchor;
+
+/// Some content with a round button floating over one of its corners.
+///
+/// The button is displayed in an overlay, so it stays in place while the
+/// content scrolls underneath. A [`Fab`] with actions expands into a menu
+/// of them when its button is pressed.
+///
+/// # Example
+/// ```
+/// # type Fab<'a, Message> = iced_native::widget::Fab<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// # type Button<'a, Message> = iced_native::widget::Button<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NewNote,
+///     NewList,
+/// }
+///
+/// let fab = Fab::new(Text::new("Notes"), Text::new("+"))
+///     .action(Button::new(Text::new("Note")).on_press(Message::NewNote))
+///     .action(Button::new(Text::new("List")).on_press(Message::NewList));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Fab<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    // The content, the icon of the button and its actions, in this order
+    children: Vec<Element<'a, Message, Renderer>>,
+    on_press: Option<Message>,
+    anchor: Anchor,
+    margin: f32,
+    size: f32,
+    spacing: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Fab<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of the button of a [`Fab`].
+    pub const DEFAULT_SIZE: f32 = 56.0;
+
+    /// Creates a new [`Fab`] floating over the given content, with the given
+    /// icon inside its button.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        icon: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Fab {
+            children: vec![content.into(), icon.into()],
+            on_press: None,
+            anchor: Anchor::BottomRight,
+            margin: 16.0,
+            size: Self::DEFAULT_SIZE,
+            spacing: 8.0,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the button of the
+    /// [`Fab`] is pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Adds an action to the menu of the [`Fab`].
+    ///
+    /// The menu opens when the button is pressed and closes as soon as
+    /// anything is pressed.
+    pub fn action(
+        mut self,
+        action: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(action.into());
+        self
+    }
+
+    /// Sets the corner of the content the button of the [`Fab`] is pinned
+    /// to.
+    ///
+    /// By default, it is the bottom right corner.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the distance between the button of the [`Fab`] and the edges of
+    /// the content.
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the size of the button of the [`Fab`].
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the spacing between the actions of the [`Fab`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the button of the [`Fab`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Fab<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.children[0].as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.children[0].as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.children[0].as_widget().layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.children[0].as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children[0].as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<native_overlay::Element<'b, Message, Renderer>> {
+        let (content, button) = self.children.split_at_mut(1);
+        let (content_tree, button_trees) = tree.children.split_at_mut(1);
+
+        let content = content[0].as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout.children().next().unwrap(),
+            renderer,
+        );
+
+        let fab = native_overlay::Element::new(
+            layout.position(),
+            Box::new(overlay::Overlay::new(
+                button,
+                button_trees,
+                tree.state.downcast_mut::<State>(),
+                &self.on_press,
+                overlay::Placement {
+                    anchor: self.anchor,
+                    margin: self.margin,
+                    size: self.size,
+                    spacing: self.spacing,
+                    target: layout.bounds().size(),
+                },
+                &self.style,
+            )),
+        );
+
+        Some(match content {
+            Some(content) => {
+                native_overlay::Group::with_children(vec![content, fab])
+                    .overlay()
+            }
+            None => fab,
+        })
+    }
+}
+
+impl<'a, Message, Renderer> From<Fab<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(fab: Fab<'a, Message, Renderer>) -> Self {
+        Element::new(fab)
+    }
+}
+
+/// The local state of a [`Fab`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_pressed: bool,
+    is_expanded: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu of the [`Fab`] is open.
+    pub fn is_expanded(&self) -> bool {
+        self.is_expanded
+    }
+}
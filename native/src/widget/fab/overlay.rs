@@ -0,0 +1,387 @@
+use super::{Anchor, State, StyleSheet};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::touch;
+use crate::widget::Tree;
+use crate::{
+    Alignment, Background, Clipboard, Color, Element, Layout, Point, Rectangle,
+    Shell, Size, Vector,
+};
+
+/// Where the button of a [`Fab`] floats.
+///
+/// [`Fab`]: super::Fab
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Placement {
+    pub anchor: Anchor,
+    pub margin: f32,
+    pub size: f32,
+    pub spacing: f32,
+    /// The size of the content the button floats over.
+    pub target: Size,
+}
+
+impl Placement {
+    /// Returns the position of the button, relative to the content.
+    fn button(&self) -> Point {
+        let margin = Vector::new(self.margin, self.margin);
+
+        self.anchor.position(
+            Size::new(
+                self.target.width - self.margin * 2.0,
+                self.target.height - self.margin * 2.0,
+            ),
+            Size::new(self.size, self.size),
+        ) + margin
+    }
+
+    /// Returns whether the actions open upwards, away from the bottom edge.
+    fn opens_upwards(&self) -> bool {
+        matches!(
+            self.anchor,
+            Anchor::BottomLeft | Anchor::Bottom | Anchor::BottomRight
+        )
+    }
+}
+
+/// The button of a [`Fab`] and its menu of actions.
+///
+/// [`Fab`]: super::Fab
+pub(super) struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    // The icon of the button, followed by the actions
+    children: &'a mut [Element<'b, Message, Renderer>],
+    trees: &'a mut [Tree],
+    state: &'a mut State,
+    on_press: &'a Option<Message>,
+    placement: Placement,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(
+        children: &'a mut [Element<'b, Message, Renderer>],
+        trees: &'a mut [Tree],
+        state: &'a mut State,
+        on_press: &'a Option<Message>,
+        placement: Placement,
+        style: &'a <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            children,
+            trees,
+            state,
+            on_press,
+            placement,
+            style,
+        }
+    }
+
+    fn has_actions(&self) -> bool {
+        self.children.len() > 1
+    }
+}
+
+impl<'a, 'b, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let placement = self.placement;
+        let button_size = Size::new(placement.size, placement.size);
+        let button_position = placement.button();
+
+        let mut icon = self.children[0]
+            .as_widget()
+            .layout(renderer, &layout::Limits::new(Size::ZERO, button_size));
+        icon.align(Alignment::Center, Alignment::Center, button_size);
+
+        let mut button = layout::Node::with_children(button_size, vec![icon]);
+        button.move_to(button_position);
+
+        let mut children = vec![button];
+
+        if self.state.is_expanded {
+            let limits = layout::Limits::new(Size::ZERO, bounds);
+            let center_x = button_position.x + placement.size / 2.0;
+
+            let mut y = if placement.opens_upwards() {
+                button_position.y - placement.spacing
+            } else {
+                button_position.y + placement.size + placement.spacing
+            };
+
+            for action in &self.children[1..] {
+                let mut node = action.as_widget().layout(renderer, &limits);
+                let size = node.size();
+
+                if placement.opens_upwards() {
+                    y -= size.height;
+                    node.move_to(Point::new(center_x - size.width / 2.0, y));
+                    y -= placement.spacing;
+                } else {
+                    node.move_to(Point::new(center_x - size.width / 2.0, y));
+                    y += size.height + placement.spacing;
+                }
+
+                children.push(node);
+            }
+        }
+
+        let mut node = layout::Node::with_children(placement.target, children);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let button = children.next().unwrap().bounds();
+        let is_over_button = button.contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over_button =>
+            {
+                self.state.is_pressed = true;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if self.state.is_pressed =>
+            {
+                self.state.is_pressed = false;
+
+                if is_over_button {
+                    if let Some(on_press) = self.on_press.clone() {
+                        shell.publish(on_press);
+                    }
+
+                    if self.has_actions() {
+                        self.state.is_expanded = !self.state.is_expanded;
+                        shell.invalidate_layout();
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_pressed = false;
+            }
+            _ => {}
+        }
+
+        if !self.state.is_expanded {
+            return event::Status::Ignored;
+        }
+
+        let is_release = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        );
+
+        let status = self.children[1..]
+            .iter_mut()
+            .zip(&mut self.trees[1..])
+            .zip(children)
+            .map(|((action, tree), layout)| {
+                action.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        // Actions react on release, so the menu closes only once they had
+        // the chance to
+        if is_release {
+            self.state.is_expanded = false;
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let button = children.next().unwrap().bounds();
+
+        if button.contains(cursor_position) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.children[1..]
+            .iter()
+            .zip(&self.trees[1..])
+            .zip(children)
+            .map(|((action, tree), layout)| {
+                action.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let mut children = layout.children();
+        let button_layout = children.next().unwrap();
+        let button = button_layout.bounds();
+
+        let appearance = if button.contains(cursor_position) {
+            if self.state.is_pressed {
+                theme.pressed(self.style)
+            } else {
+                theme.hovered(self.style)
+            }
+        } else {
+            theme.active(self.style)
+        };
+
+        let radius = button.width.min(button.height) / 2.0;
+
+        if appearance.shadow_offset != Vector::default() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: button.x + appearance.shadow_offset.x,
+                        y: button.y + appearance.shadow_offset.y,
+                        ..button
+                    },
+                    border_radius: radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color([0.0, 0.0, 0.0, 0.5].into()),
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: button,
+                border_radius: radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        self.children[0].as_widget().draw(
+            &self.trees[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            button_layout.children().next().unwrap(),
+            cursor_position,
+            &button,
+        );
+
+        for ((action, tree), layout) in self.children[1..]
+            .iter()
+            .zip(&self.trees[1..])
+            .zip(children)
+        {
+            action.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                &layout.bounds(),
+            );
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|child| child.bounds().contains(cursor_position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn button_keeps_its_margin_from_the_anchor() {
+        let placement = |anchor| Placement {
+            anchor,
+            margin: 16.0,
+            size: 56.0,
+            spacing: 8.0,
+            target: Size::new(400.0, 300.0),
+        };
+
+        assert_eq!(
+            placement(Anchor::BottomRight).button(),
+            Point::new(328.0, 228.0)
+        );
+        assert_eq!(placement(Anchor::TopLeft).button(), Point::new(16.0, 16.0));
+        assert_eq!(
+            placement(Anchor::Bottom).button(),
+            Point::new(172.0, 228.0)
+        );
+    }
+}
@@ -0,0 +1,609 @@
+//! Distribute content in rows and columns.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+/// A container that distributes its contents in rows and columns.
+///
+/// Unlike nesting rows inside a column, the columns of a [`Grid`] are aligned
+/// across all of its rows.
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align_x: Alignment,
+    align_y: Alignment,
+    cells: Vec<Cell>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+/// The sizing rule of a row or a column of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Track {
+    /// A fixed amount of pixels.
+    Fixed(f32),
+    /// A portion of the remaining space, relative to the other filling
+    /// tracks.
+    FillPortion(u16),
+    /// The size of the largest element in the track.
+    #[default]
+    Auto,
+}
+
+impl From<f32> for Track {
+    fn from(amount: f32) -> Self {
+        Track::Fixed(amount)
+    }
+}
+
+/// The position of an element in a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given column and row [`Track`]s.
+    ///
+    /// Elements placed outside of the given tracks are laid out in
+    /// [`Track::Auto`] tracks.
+    pub fn new(
+        columns: impl IntoIterator<Item = Track>,
+        rows: impl IntoIterator<Item = Track>,
+    ) -> Self {
+        Grid {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+            cells: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets both the horizontal and vertical spacing _between_ cells.
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.column_spacing(amount).row_spacing(amount)
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents inside of their cells.
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents inside of their cells.
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.align_y = alignment;
+        self
+    }
+
+    /// Adds an element to the cell of the [`Grid`] at the given row and
+    /// column.
+    pub fn push(
+        self,
+        row: usize,
+        column: usize,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.push_spanned(row, column, 1, 1, child)
+    }
+
+    /// Adds an element to the [`Grid`] spanning the given amount of rows and
+    /// columns, starting at the given row and column.
+    ///
+    /// Spans are at least 1.
+    pub fn push_spanned(
+        mut self,
+        row: usize,
+        column: usize,
+        row_span: usize,
+        column_span: usize,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.cells.push(Cell {
+            row,
+            column,
+            row_span: row_span.max(1),
+            column_span: column_span.max(1),
+        });
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.pad(self.padding).max();
+
+        let column_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.column + cell.column_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.columns.len());
+
+        let row_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.rows.len());
+
+        let columns: Vec<Track> = (0..column_count)
+            .map(|i| self.columns.get(i).copied().unwrap_or_default())
+            .collect();
+
+        let rows: Vec<Track> = (0..row_count)
+            .map(|i| self.rows.get(i).copied().unwrap_or_default())
+            .collect();
+
+        // Measure the widths of the elements that do not fill their cells
+        let widths: Vec<Option<f32>> = self
+            .children
+            .iter()
+            .map(|child| {
+                (child.as_widget().width().fill_factor() == 0).then(|| {
+                    child
+                        .as_widget()
+                        .layout(
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, max)
+                                .with_direction(limits.direction()),
+                        )
+                        .size()
+                        .width
+                })
+            })
+            .collect();
+
+        let column_sizes = resolve(
+            &columns,
+            self.cells.iter().zip(&widths).filter_map(|(cell, width)| {
+                width.map(|width| (cell.column, cell.column_span, width))
+            }),
+            max.width,
+            self.column_spacing,
+        );
+
+        // Measure the heights of the elements given the width of their cells
+        let heights: Vec<Option<f32>> = self
+            .children
+            .iter()
+            .zip(&self.cells)
+            .map(|(child, cell)| {
+                (child.as_widget().height().fill_factor() == 0).then(|| {
+                    let width = span(
+                        &column_sizes,
+                        cell.column,
+                        cell.column_span,
+                        self.column_spacing,
+                    );
+
+                    child
+                        .as_widget()
+                        .layout(
+                            renderer,
+                            &layout::Limits::new(
+                                Size::ZERO,
+                                Size::new(width, max.height),
+                            )
+                            .with_direction(limits.direction()),
+                        )
+                        .size()
+                        .height
+                })
+            })
+            .collect();
+
+        let row_sizes = resolve(
+            &rows,
+            self.cells
+                .iter()
+                .zip(&heights)
+                .filter_map(|(cell, height)| {
+                    height.map(|height| (cell.row, cell.row_span, height))
+                }),
+            max.height,
+            self.row_spacing,
+        );
+
+        let children = self
+            .children
+            .iter()
+            .zip(&self.cells)
+            .map(|(child, cell)| {
+                let position = Point::new(
+                    self.padding.left
+                        + offset(
+                            &column_sizes,
+                            cell.column,
+                            self.column_spacing,
+                        ),
+                    self.padding.top
+                        + offset(&row_sizes, cell.row, self.row_spacing),
+                );
+
+                let size = Size::new(
+                    span(
+                        &column_sizes,
+                        cell.column,
+                        cell.column_span,
+                        self.column_spacing,
+                    ),
+                    span(&row_sizes, cell.row, cell.row_span, self.row_spacing),
+                );
+
+                let mut node = child.as_widget().layout(
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, size)
+                        .with_direction(limits.direction()),
+                );
+
+                node.move_to(position);
+                node.align(self.align_x, self.align_y, size);
+
+                node
+            })
+            .collect();
+
+        let content = Size::new(
+            span(&column_sizes, 0, column_sizes.len(), self.column_spacing),
+            span(&row_sizes, 0, row_sizes.len(), self.row_spacing),
+        );
+
+        let size = limits.resolve(Size::new(
+            content.width + self.padding.horizontal(),
+            content.height + self.padding.vertical(),
+        ));
+
+        let mut node = layout::Node::with_children(size, children);
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+/// Computes the sizes of the given [`Track`]s, given the measured size of
+/// every element as `(start, span, size)` and the available space.
+fn resolve(
+    tracks: &[Track],
+    measurements: impl Iterator<Item = (usize, usize, f32)> + Clone,
+    available: f32,
+    spacing: f32,
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(amount) => *amount,
+            Track::FillPortion(_) | Track::Auto => 0.0,
+        })
+        .collect();
+
+    let fill_sum: u32 = tracks
+        .iter()
+        .map(|track| match track {
+            Track::FillPortion(portion) => u32::from(*portion),
+            _ => 0,
+        })
+        .sum();
+
+    // Filling tracks behave like automatic ones when the space is unbounded
+    let is_auto = |track: &Track| match track {
+        Track::Auto => true,
+        Track::FillPortion(_) => !available.is_finite() || fill_sum == 0,
+        Track::Fixed(_) => false,
+    };
+
+    for (start, _, size) in
+        measurements.clone().filter(|(_, count, _)| *count == 1)
+    {
+        if tracks.get(start).map_or(false, is_auto) {
+            sizes[start] = sizes[start].max(size);
+        }
+    }
+
+    // Spanning elements grow the automatic tracks they span, if needed
+    for (start, count, size) in measurements.filter(|(_, count, _)| *count > 1)
+    {
+        let end = (start + count).min(tracks.len());
+
+        let current = span(&sizes, start, end - start, spacing);
+        let auto: Vec<usize> =
+            (start..end).filter(|i| is_auto(&tracks[*i])).collect();
+
+        if size > current && !auto.is_empty() {
+            let extra = (size - current) / auto.len() as f32;
+
+            for i in auto {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    if available.is_finite() && fill_sum > 0 {
+        let used: f32 = sizes.iter().sum::<f32>()
+            + spacing * tracks.len().saturating_sub(1) as f32;
+
+        let remaining = (available - used).max(0.0);
+
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let Track::FillPortion(portion) = track {
+                *size = remaining * f32::from(*portion) / fill_sum as f32;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the offset of the track at the given index.
+fn offset(sizes: &[f32], index: usize, spacing: f32) -> f32 {
+    sizes[..index.min(sizes.len())]
+        .iter()
+        .map(|size| size + spacing)
+        .sum()
+}
+
+/// Returns the total size of `count` tracks starting at the given index,
+/// including the spacing between them.
+fn span(sizes: &[f32], start: usize, count: usize, spacing: f32) -> f32 {
+    let end = (start + count).min(sizes.len());
+    let start = start.min(end);
+
+    sizes[start..end].iter().sum::<f32>()
+        + spacing * (end - start).saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_fixed_and_auto_tracks() {
+        let sizes = resolve(
+            &[Track::Fixed(50.0), Track::Auto],
+            [(1, 1, 30.0), (1, 1, 40.0)].into_iter(),
+            f32::INFINITY,
+            10.0,
+        );
+
+        assert_eq!(sizes, vec![50.0, 40.0]);
+    }
+
+    #[test]
+    fn resolve_fill_portions() {
+        let sizes = resolve(
+            &[
+                Track::Fixed(40.0),
+                Track::FillPortion(1),
+                Track::FillPortion(2),
+            ],
+            std::iter::empty(),
+            210.0,
+            10.0,
+        );
+
+        assert_eq!(sizes, vec![40.0, 50.0, 100.0]);
+    }
+
+    #[test]
+    fn resolve_spanning_element_grows_auto_tracks() {
+        let sizes = resolve(
+            &[Track::Fixed(20.0), Track::Auto, Track::Auto],
+            [(1, 1, 10.0), (0, 3, 100.0)].into_iter(),
+            f32::INFINITY,
+            0.0,
+        );
+
+        assert_eq!(sizes, vec![20.0, 45.0, 35.0]);
+    }
+
+    #[test]
+    fn offset_and_span_include_spacing() {
+        let sizes = [10.0, 20.0, 30.0];
+
+        assert_eq!(offset(&sizes, 2, 5.0), 40.0);
+        assert_eq!(span(&sizes, 1, 2, 5.0), 55.0);
+    }
+}
@@ -1,7 +1,7 @@
 //! Helper functions to create pure widgets.
 use crate::overlay;
 use crate::widget;
-use crate::{Element, Length, Pixels};
+use crate::{Element, Length, Pixels, Point};
 
 use std::borrow::Cow;
 use std::ops::RangeInclusive;
@@ -45,6 +45,30 @@ where
     widget::Container::new(content)
 }
 
+/// Overrides the cross axis [`Alignment`] of a [`Row`] or a [`Column`] for
+/// the given content.
+///
+/// [`Alignment`]: crate::Alignment
+/// [`Row`]: widget::Row
+/// [`Column`]: widget::Column
+pub fn align<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    alignment: crate::Alignment,
+) -> widget::Align<'a, Message, Renderer> {
+    widget::Align::new(content, alignment)
+}
+
+/// Creates a new [`AspectRatio`] with the given width to height ratio and
+/// content.
+///
+/// [`AspectRatio`]: widget::AspectRatio
+pub fn aspect_ratio<'a, Message, Renderer>(
+    ratio: f32,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::AspectRatio<'a, Message, Renderer> {
+    widget::AspectRatio::new(ratio, content)
+}
+
 /// Creates a new [`Column`] with the given children.
 ///
 /// [`Column`]: widget::Column
@@ -54,6 +78,16 @@ pub fn column<Message, Renderer>(
     widget::Column::with_children(children)
 }
 
+/// Creates a new empty [`Grid`] with the given column and row tracks.
+///
+/// [`Grid`]: widget::Grid
+pub fn grid<'a, Message, Renderer>(
+    columns: impl IntoIterator<Item = widget::grid::Track>,
+    rows: impl IntoIterator<Item = widget::grid::Track>,
+) -> widget::Grid<'a, Message, Renderer> {
+    widget::Grid::new(columns, rows)
+}
+
 /// Creates a new [`Row`] with the given children.
 ///
 /// [`Row`]: widget::Row
@@ -63,6 +97,41 @@ pub fn row<Message, Renderer>(
     widget::Row::with_children(children)
 }
 
+/// Creates a new [`Reorderable`] with the given children.
+///
+/// [`Reorderable`]: widget::Reorderable
+pub fn reorderable<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> widget::Reorderable<'_, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme:
+        widget::reorderable::StyleSheet + widget::scrollable::StyleSheet,
+{
+    widget::Reorderable::with_children(children)
+}
+
+/// Creates a new [`Toolbar`] with the given actions.
+///
+/// [`Toolbar`]: widget::Toolbar
+pub fn toolbar<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> widget::Toolbar<'_, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::toolbar::StyleSheet,
+{
+    widget::Toolbar::with_children(children)
+}
+
+/// Creates a new empty [`Positioned`] container.
+///
+/// [`Positioned`]: widget::Positioned
+pub fn positioned<'a, Message, Renderer>(
+) -> widget::Positioned<'a, Message, Renderer> {
+    widget::Positioned::new()
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: widget::Scrollable
@@ -76,6 +145,213 @@ where
     widget::Scrollable::new(content)
 }
 
+/// Creates a new [`Stack`] with the given children, from bottom to top.
+///
+/// [`Stack`]: widget::Stack
+pub fn stack<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> widget::Stack<'_, Message, Renderer> {
+    widget::Stack::with_children(children)
+}
+
+/// Creates a new [`Table`] with the given columns and rows of cells.
+///
+/// [`Table`]: widget::Table
+pub fn table<'a, Message, Renderer>(
+    columns: Vec<widget::table::Column<'a, Message, Renderer>>,
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+) -> widget::Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: widget::table::StyleSheet
+        + widget::container::StyleSheet
+        + widget::scrollable::StyleSheet,
+{
+    widget::Table::new(columns, rows)
+}
+
+/// Creates a new [`Tabs`] widget with the given selected tab and its
+/// content.
+///
+/// [`Tabs`]: widget::Tabs
+pub fn tabs<'a, Id, Message, Renderer>(
+    selected: Id,
+    content: impl Into<Element<'a, Message, Renderer>>,
+    on_select: impl Fn(Id) -> Message + 'a,
+) -> widget::Tabs<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::tabs::StyleSheet + crate::overlay::menu::StyleSheet,
+{
+    widget::Tabs::new(selected, content, on_select)
+}
+
+/// Creates a new [`LazyList`] with the given amount of rows, the height of
+/// every row, and the function that produces the row at a given index.
+///
+/// [`LazyList`]: widget::LazyList
+pub fn lazy_list<'a, Message, Renderer>(
+    count: usize,
+    row_height: impl Into<Pixels>,
+    view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> widget::LazyList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::scrollable::StyleSheet,
+{
+    widget::LazyList::new(count, row_height, view)
+}
+
+/// Creates a new [`Calendar`].
+///
+/// [`Calendar`]: widget::Calendar
+pub fn calendar<'a, Message, Renderer>(
+) -> widget::Calendar<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::calendar::StyleSheet,
+{
+    widget::Calendar::new()
+}
+
+/// Creates a new [`Card`] with the given body.
+///
+/// [`Card`]: widget::Card
+pub fn card<'a, Message, Renderer>(
+    body: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::Card<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::card::StyleSheet,
+{
+    widget::Card::new(body)
+}
+
+/// Creates a new [`Badge`] attached to the given content, with the given
+/// label.
+///
+/// [`Badge`]: widget::Badge
+pub fn badge<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    label: impl ToString,
+) -> widget::Badge<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::badge::StyleSheet,
+{
+    widget::Badge::new(content, label)
+}
+
+/// Creates a new [`Chip`] with the given label.
+///
+/// [`Chip`]: widget::Chip
+pub fn chip<'a, Message, Renderer>(
+    label: impl Into<String>,
+) -> widget::Chip<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::chip::StyleSheet,
+{
+    widget::Chip::new(label)
+}
+
+/// Creates a new [`TagsInput`] with the given tags, and the messages to
+/// produce when a tag is added or removed.
+///
+/// [`TagsInput`]: widget::TagsInput
+pub fn tags_input<'a, Message, Renderer>(
+    tags: impl Into<Cow<'a, [String]>>,
+    on_add: impl Fn(String) -> Message + 'a,
+    on_remove: impl Fn(usize) -> Message + 'a,
+) -> widget::TagsInput<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet
+        + widget::chip::StyleSheet
+        + overlay::menu::StyleSheet,
+{
+    widget::TagsInput::new(tags, on_add, on_remove)
+}
+
+/// Creates a new [`Fab`] floating over the given content, with the given
+/// icon inside its button.
+///
+/// [`Fab`]: widget::Fab
+pub fn fab<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    icon: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::Fab<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::button::StyleSheet,
+{
+    widget::Fab::new(content, icon)
+}
+
+/// Creates a new [`Draggable`] with the given content, carrying the given
+/// payload.
+///
+/// [`Draggable`]: widget::Draggable
+pub fn draggable<'a, T, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    payload: T,
+) -> widget::Draggable<'a, T, Message, Renderer>
+where
+    T: Clone + 'static,
+    Renderer: crate::Renderer,
+{
+    widget::Draggable::new(content, payload)
+}
+
+/// Creates a new [`DropZone`] with the given content, and the message to
+/// produce when a payload is dropped over it.
+///
+/// [`DropZone`]: widget::DropZone
+pub fn drop_zone<'a, T, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    on_drop: impl Fn(T, Point) -> Message + 'a,
+) -> widget::DropZone<'a, T, Message, Renderer>
+where
+    T: 'static,
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::drop_zone::StyleSheet,
+{
+    widget::DropZone::new(content, on_drop)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: widget::MenuBar
+pub fn menu_bar<Message, Renderer>(
+    menus: Vec<widget::menu_bar::Menu<Message>>,
+) -> widget::MenuBar<Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::menu_bar::StyleSheet,
+{
+    widget::MenuBar::new(menus)
+}
+
+/// Creates a new [`Pagination`] with the given amount of pages, the index of
+/// the current page, and the message to produce when a page is selected.
+///
+/// [`Pagination`]: widget::Pagination
+pub fn pagination<'a, Message, Renderer>(
+    page_count: usize,
+    current: usize,
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> widget::Pagination<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::pagination::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::Pagination::new(page_count, current, on_select)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: widget::Button
@@ -117,6 +393,19 @@ where
     widget::Text::new(text.to_string())
 }
 
+/// Creates a new [`SelectableText`] widget with the provided content.
+///
+/// [`SelectableText`]: widget::SelectableText
+pub fn selectable_text<'a, Renderer>(
+    text: impl ToString,
+) -> widget::SelectableText<'a, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::selectable_text::StyleSheet,
+{
+    widget::SelectableText::new(text.to_string())
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: widget::Checkbox
@@ -150,6 +439,38 @@ where
     widget::Radio::new(value, label, selected, on_click)
 }
 
+/// Creates a new [`SegmentedButton`].
+///
+/// [`SegmentedButton`]: widget::SegmentedButton
+pub fn segmented_button<'a, Message, Renderer, T>(
+    options: impl Into<Cow<'a, [T]>>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> widget::SegmentedButton<'a, T, Message, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::segmented_button::StyleSheet,
+{
+    widget::SegmentedButton::new(options, selected, on_select)
+}
+
+/// Creates a new [`Rating`] with the given amount of icons, displaying the
+/// given value.
+///
+/// [`Rating`]: widget::Rating
+pub fn rating<'a, Message, Renderer>(
+    max: u8,
+    value: f32,
+) -> widget::Rating<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::rating::StyleSheet,
+{
+    widget::Rating::new(max, value)
+}
+
 /// Creates a new [`Toggler`].
 ///
 /// [`Toggler`]: widget::Toggler
@@ -165,6 +486,65 @@ where
     widget::Toggler::new(label, is_checked, f)
 }
 
+/// Creates a new [`Split`] with the given panes, dividing the available space
+/// at the given ratio.
+///
+/// [`Split`]: widget::Split
+pub fn split<'a, Message, Renderer>(
+    first: impl Into<Element<'a, Message, Renderer>>,
+    second: impl Into<Element<'a, Message, Renderer>>,
+    ratio: f32,
+    on_resize: impl Fn(f32) -> Message + 'a,
+) -> widget::Split<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::split::StyleSheet,
+{
+    widget::Split::new(first, second, ratio, on_resize)
+}
+
+/// Creates a new [`Stepper`].
+///
+/// [`Stepper`]: widget::Stepper
+pub fn stepper<'a, T, Message, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::Stepper<'a, T, Message, Renderer>
+where
+    T: num_traits::Num + PartialOrd + Copy + std::fmt::Display,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::stepper::StyleSheet,
+{
+    widget::Stepper::new(value, on_change)
+}
+
+/// Creates a new [`Swipeable`] with the given content.
+///
+/// [`Swipeable`]: widget::Swipeable
+pub fn swipeable<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::Swipeable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    widget::Swipeable::new(content)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: widget::TreeView
+pub fn tree_view<'a, Id, Message, Renderer>(
+    roots: Vec<widget::tree_view::Node<Id>>,
+    on_toggle: impl Fn(Id, bool) -> Message + 'a,
+) -> widget::TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::tree_view::StyleSheet,
+{
+    widget::TreeView::new(roots, on_toggle)
+}
+
 /// Creates a new [`TextInput`].
 ///
 /// [`TextInput`]: widget::TextInput
@@ -181,6 +561,101 @@ where
     widget::TextInput::new(placeholder, value, on_change)
 }
 
+/// Creates a new [`CodeEditor`] showing the given [`Content`].
+///
+/// [`CodeEditor`]: widget::CodeEditor
+/// [`Content`]: widget::code_editor::Content
+pub fn code_editor<'a, Message, Renderer>(
+    content: &'a widget::code_editor::Content,
+) -> widget::CodeEditor<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::code_editor::StyleSheet,
+{
+    widget::CodeEditor::new(content)
+}
+
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: widget::NumberInput
+pub fn number_input<'a, T, Message, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::NumberInput<'a, T, Message, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + PartialOrd
+        + Copy
+        + std::fmt::Display
+        + std::str::FromStr,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet,
+{
+    widget::NumberInput::new(value, on_change)
+}
+
+/// Creates a new [`ColorPicker`].
+///
+/// [`ColorPicker`]: widget::ColorPicker
+pub fn color_picker<'a, Message, Renderer>(
+    value: crate::Color,
+    on_change: impl Fn(crate::Color) -> Message + 'a,
+) -> widget::ColorPicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::color_picker::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::ColorPicker::new(value, on_change)
+}
+
+/// Creates a new [`DataGrid`] with the given columns, the amount of rows, and
+/// the function that produces the contents of a cell.
+///
+/// [`DataGrid`]: widget::DataGrid
+pub fn data_grid<'a, Message, Renderer>(
+    columns: Vec<widget::data_grid::Column>,
+    row_count: usize,
+    cell: impl Fn(usize, usize) -> String + 'a,
+) -> widget::DataGrid<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::data_grid::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::DataGrid::new(columns, row_count, cell)
+}
+
+/// Creates a new [`DatePicker`].
+///
+/// [`DatePicker`]: widget::DatePicker
+pub fn date_picker<'a, Message, Renderer>(
+    value: Option<widget::date_picker::Date>,
+    on_change: impl Fn(widget::date_picker::Date) -> Message + 'a,
+) -> widget::DatePicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::date_picker::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::DatePicker::new(value, on_change)
+}
+
+/// Creates a new [`TimePicker`].
+///
+/// [`TimePicker`]: widget::TimePicker
+pub fn time_picker<'a, Message, Renderer>(
+    value: widget::time_picker::Time,
+    on_change: impl Fn(widget::time_picker::Time) -> Message + 'a,
+) -> widget::TimePicker<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet,
+{
+    widget::TimePicker::new(value, on_change)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: widget::Slider
@@ -215,6 +690,23 @@ where
     widget::VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new [`Knob`].
+///
+/// [`Knob`]: widget::Knob
+pub fn knob<'a, T, Message, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::Knob<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::knob::StyleSheet,
+{
+    widget::Knob::new(range, value, on_change)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// [`PickList`]: widget::PickList
@@ -302,6 +794,36 @@ where
     widget::ProgressBar::new(range, value)
 }
 
+/// Creates a new [`Skeleton`] without any shapes.
+///
+/// [`Skeleton`]: widget::Skeleton
+pub fn skeleton<Renderer>() -> widget::Skeleton<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::skeleton::StyleSheet,
+{
+    widget::Skeleton::new()
+}
+
+/// Creates a new [`Spinner`].
+///
+/// [`Spinner`]: widget::Spinner
+pub fn spinner<Renderer>() -> widget::Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::spinner::StyleSheet,
+{
+    widget::Spinner::new()
+}
+
+/// Creates a new [`Shader`] widget from the given [`Handle`].
+///
+/// [`Shader`]: widget::Shader
+/// [`Handle`]: widget::shader::Handle
+pub fn shader(handle: impl Into<widget::shader::Handle>) -> widget::Shader {
+    widget::Shader::new(handle)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// [`Svg`]: widget::Svg
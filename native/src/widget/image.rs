@@ -1,24 +1,50 @@
 //! Display images in your user interface.
+pub mod lazy;
+pub mod nine_patch;
 pub mod viewer;
+pub use lazy::Lazy;
+pub use nine_patch::NinePatch;
 pub use viewer::Viewer;
 
+use crate::event::{self, Event};
 use crate::image;
 use crate::layout;
 use crate::renderer;
-use crate::widget::Tree;
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
 };
 
 use std::hash::Hash;
 
+/// Creates a new [`Lazy`] image loaded in the background from the given
+/// [`Source`](lazy::Source).
+pub fn lazy<'a, Message, Renderer>(
+    source: impl Into<lazy::Source>,
+) -> Lazy<'a, Message, Renderer> {
+    Lazy::new(source)
+}
+
+/// Creates a new [`NinePatch`] with the given image `Handle`.
+pub fn nine_patch<Handle>(handle: impl Into<Handle>) -> NinePatch<Handle> {
+    NinePatch::new(handle)
+}
+
 /// Creates a new [`Viewer`] with the given image `Handle`.
-pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
+pub fn viewer<'a, Message, Handle>(
+    handle: Handle,
+) -> Viewer<'a, Message, Handle> {
     Viewer::new(handle)
 }
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// Animated images are played on their own, unless they are paused with
+/// [`Image::playing`].
+///
 /// # Example
 ///
 /// ```
@@ -35,6 +61,9 @@ pub struct Image<Handle> {
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    filter: image::Filter,
+    is_playing: bool,
+    is_looping: bool,
 }
 
 impl<Handle> Image<Handle> {
@@ -45,6 +74,9 @@ impl<Handle> Image<Handle> {
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            filter: image::Filter::NONE,
+            is_playing: true,
+            is_looping: true,
         }
     }
 
@@ -69,6 +101,30 @@ impl<Handle> Image<Handle> {
             ..self
         }
     }
+
+    /// Sets the [`Filter`](image::Filter) applied to the [`Image`], like a
+    /// blur or a grayscale effect.
+    pub fn filter(mut self, filter: image::Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] is playing or paused.
+    ///
+    /// Defaults to `true`.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] starts over after its
+    /// last frame, instead of stopping on it.
+    ///
+    /// Defaults to `true`.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -111,12 +167,14 @@ where
     layout::Node::new(final_size)
 }
 
-/// Draws an [`Image`]
+/// Draws the given frame of an [`Image`], applying the given filter
 pub fn draw<Renderer, Handle>(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     handle: &Handle,
+    frame: usize,
     content_fit: ContentFit,
+    filter: image::Filter,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
@@ -128,10 +186,7 @@ pub fn draw<Renderer, Handle>(
     let adjusted_fit = content_fit.fit(image_size, bounds.size());
 
     let render = |renderer: &mut Renderer| {
-        let offset = Vector::new(
-            (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
-            (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
-        );
+        let offset = content_fit.offset(adjusted_fit, bounds.size());
 
         let drawing_bounds = Rectangle {
             width: adjusted_fit.width,
@@ -139,7 +194,16 @@ pub fn draw<Renderer, Handle>(
             ..bounds
         };
 
-        renderer.draw(handle.clone(), drawing_bounds + offset)
+        if filter.is_none() {
+            renderer.draw_frame(handle.clone(), frame, drawing_bounds + offset)
+        } else {
+            renderer.draw_filtered(
+                handle.clone(),
+                frame,
+                drawing_bounds + offset,
+                filter,
+            )
+        }
     };
 
     if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
@@ -155,6 +219,14 @@ where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -178,9 +250,51 @@ where
         )
     }
 
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let delays = renderer.frame_delays(&self.handle);
+
+            if delays.len() < 2 || !self.is_playing {
+                state.last_tick = None;
+
+                return event::Status::Ignored;
+            }
+
+            let delta = state
+                .last_tick
+                .map(|last_tick| now.saturating_duration_since(last_tick))
+                .unwrap_or_default();
+
+            match state.advance(delta, &delays, self.is_looping) {
+                Some(remaining) => {
+                    state.last_tick = Some(now);
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + remaining,
+                    ));
+                }
+                None => {
+                    state.last_tick = None;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         _theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -188,7 +302,16 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        draw(renderer, layout, &self.handle, self.content_fit)
+        let state = tree.state.downcast_ref::<State>();
+
+        draw(
+            renderer,
+            layout,
+            &self.handle,
+            state.frame,
+            self.content_fit,
+            self.filter,
+        )
     }
 }
 
@@ -202,3 +325,97 @@ where
         Element::new(image)
     }
 }
+
+/// The local state of an [`Image`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    frame: usize,
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    /// Advances the animation by the given amount of time, returning how
+    /// long the current frame is left to be displayed, or `None` if the
+    /// animation has stopped.
+    fn advance(
+        &mut self,
+        delta: Duration,
+        delays: &[Duration],
+        is_looping: bool,
+    ) -> Option<Duration> {
+        let total: Duration = delays.iter().sum();
+
+        if total.is_zero() {
+            return None;
+        }
+
+        self.frame %= delays.len();
+        self.elapsed += delta;
+
+        // Skip whole loops at once, since they end on the same frame
+        if is_looping && self.elapsed >= total {
+            self.elapsed = Duration::from_nanos(
+                (self.elapsed.as_nanos() % total.as_nanos()) as u64,
+            );
+        }
+
+        loop {
+            let delay = delays[self.frame];
+
+            if self.elapsed < delay {
+                return Some(delay - self.elapsed);
+            }
+
+            if self.frame + 1 == delays.len() && !is_looping {
+                self.elapsed = Duration::ZERO;
+
+                return None;
+            }
+
+            self.elapsed -= delay;
+            self.frame = (self.frame + 1) % delays.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_moves_through_the_frames() {
+        let delays = [Duration::from_millis(100), Duration::from_millis(50)];
+        let mut state = State::default();
+
+        assert_eq!(
+            state.advance(Duration::from_millis(40), &delays, true),
+            Some(Duration::from_millis(60))
+        );
+        assert_eq!(state.frame, 0);
+
+        assert_eq!(
+            state.advance(Duration::from_millis(70), &delays, true),
+            Some(Duration::from_millis(40))
+        );
+        assert_eq!(state.frame, 1);
+
+        assert_eq!(
+            state.advance(Duration::from_millis(330), &delays, true),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(state.frame, 1);
+    }
+
+    #[test]
+    fn advance_stops_on_the_last_frame_without_looping() {
+        let delays = [Duration::from_millis(100), Duration::from_millis(50)];
+        let mut state = State::default();
+
+        assert_eq!(
+            state.advance(Duration::from_millis(500), &delays, false),
+            None
+        );
+        assert_eq!(state.frame, 1);
+    }
+}
@@ -0,0 +1,540 @@
+//! Load images in the background while a placeholder is displayed.
+use crate::event::{self, Event};
+use crate::image::{self, Handle};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::time::Duration;
+use crate::widget::{Operation, Tree};
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use iced_futures::backend::default::Executor;
+use iced_futures::{BoxFuture, Executor as _, MaybeSend};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    /// The images loaded or being loaded, by key.
+    static CACHE: RefCell<HashMap<String, Entry>> =
+        RefCell::new(HashMap::new());
+
+    /// The executor running the loaders, created on first use.
+    static EXECUTOR: RefCell<Option<Executor>> = RefCell::new(None);
+}
+
+/// An image that is loaded in the background the first time it is
+/// displayed, showing a placeholder in the meantime.
+///
+/// Loaded images are cached by the key of their [`Source`], so the same
+/// image is only loaded once, even if it is displayed by multiple [`Lazy`]
+/// widgets or the widget is recreated on every `view`.
+///
+/// # Example
+///
+/// ```
+/// # type Lazy<'a, Message> =
+/// #     iced_native::widget::image::Lazy<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// let photo = Lazy::<()>::new("photos/landscape.jpg")
+///     .placeholder(Text::new("Loading..."));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Message, Renderer> {
+    source: Source,
+    placeholder: Option<Element<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+}
+
+impl<'a, Message, Renderer> Lazy<'a, Message, Renderer> {
+    /// The interval at which a [`Lazy`] image checks if its [`Source`] has
+    /// finished loading.
+    pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Creates a new [`Lazy`] image loaded from the given [`Source`].
+    pub fn new(source: impl Into<Source>) -> Self {
+        Lazy {
+            source: source.into(),
+            placeholder: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+        }
+    }
+
+    /// Sets the element displayed while the [`Lazy`] image is loading, or
+    /// if it fails to load.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`Lazy`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Lazy`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Lazy`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Lazy<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.placeholder.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.placeholder {
+            Some(placeholder) => {
+                tree.diff_children(std::slice::from_ref(placeholder))
+            }
+            None => tree.children.clear(),
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if let Some(handle) = loaded(&self.source.key) {
+            return super::layout(
+                renderer,
+                limits,
+                &handle,
+                self.width,
+                self.height,
+                self.content_fit,
+            );
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+
+        match &self.placeholder {
+            Some(placeholder) => {
+                let content = placeholder.as_widget().layout(renderer, &limits);
+                let size = limits.resolve(content.size());
+
+                layout::Node::with_children(size, vec![content])
+            }
+            None => layout::Node::new(limits.resolve(Size::ZERO)),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let (Some(placeholder), Some(layout)) =
+            (&self.placeholder, layout.children().next())
+        {
+            placeholder.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            match poll(&self.source) {
+                Progress::Pending => {
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Self::POLL_INTERVAL,
+                    ));
+                }
+                Progress::Completed => {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                Progress::Cached => {}
+            }
+        }
+
+        match (&mut self.placeholder, layout.children().next()) {
+            (Some(placeholder), Some(layout)) => {
+                placeholder.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&self.placeholder, layout.children().next()) {
+            (Some(placeholder), Some(layout)) => {
+                placeholder.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        // The placeholder is kept until the layout is recomputed
+        match (&self.placeholder, layout.children().next()) {
+            (Some(placeholder), Some(content_layout)) => {
+                placeholder.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor_position,
+                    viewport,
+                );
+            }
+            _ => {
+                if let Some(handle) = loaded(&self.source.key) {
+                    super::draw(
+                        renderer,
+                        layout,
+                        &handle,
+                        0,
+                        self.content_fit,
+                        image::Filter::NONE,
+                    );
+                }
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let placeholder = self.placeholder.as_mut()?;
+
+        placeholder.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Lazy<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(lazy: Lazy<'a, Message, Renderer>) -> Self {
+        Self::new(lazy)
+    }
+}
+
+/// Where the image of a [`Lazy`] widget is loaded from.
+pub struct Source {
+    key: String,
+    load: Box<dyn Fn() -> BoxFuture<Result<Handle, Error>>>,
+}
+
+impl Source {
+    /// Creates a [`Source`] that loads its image with the future returned by
+    /// the given closure, which runs in the background.
+    ///
+    /// The image is cached by the given key, so it must identify the image
+    /// uniquely. This can be used to fetch remote images with any HTTP client
+    /// or to decode images off the main thread, using their URL or path as
+    /// the key.
+    pub fn new<F>(
+        key: impl Into<String>,
+        load: impl Fn() -> F + 'static,
+    ) -> Self
+    where
+        F: Future<Output = Result<Handle, Error>> + MaybeSend + 'static,
+    {
+        Source {
+            key: key.into(),
+            load: Box::new(move || -> BoxFuture<Result<Handle, Error>> {
+                Box::pin(load())
+            }),
+        }
+    }
+
+    /// Creates a [`Source`] that reads the image file at the given path in
+    /// the background, using the path as the key.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let key = path.to_string_lossy().into_owned();
+
+        Self::new(key, move || {
+            let path = path.clone();
+
+            async move {
+                std::fs::read(path)
+                    .map(Handle::from_memory)
+                    .map_err(Error::from)
+            }
+        })
+    }
+
+    /// Returns the key of the [`Source`].
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl<T> From<T> for Source
+where
+    T: Into<PathBuf>,
+{
+    fn from(path: T) -> Self {
+        Self::path(path)
+    }
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Source").field("key", &self.key).finish()
+    }
+}
+
+/// An error produced when loading the image of a [`Source`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Error {
+    /// Creates a new [`Error`] with the given description.
+    pub fn new(description: impl Into<String>) -> Self {
+        Error(description.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error(error.to_string())
+    }
+}
+
+/// Returns the result of loading the image with the given key, if it has
+/// finished loading.
+pub fn status(key: &str) -> Option<Result<Handle, Error>> {
+    CACHE.with(|cache| match cache.borrow().get(key)? {
+        Entry::Loading(_) => None,
+        Entry::Done(result) => Some(result.clone()),
+    })
+}
+
+/// Removes the image with the given key from the cache, so it is loaded
+/// again the next time it is displayed.
+pub fn evict(key: &str) {
+    CACHE.with(|cache| {
+        let _ = cache.borrow_mut().remove(key);
+    });
+}
+
+/// The state of an image in the cache.
+enum Entry {
+    Loading(Arc<Mutex<Option<Result<Handle, Error>>>>),
+    Done(Result<Handle, Error>),
+}
+
+/// The progress made loading an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Progress {
+    Pending,
+    Completed,
+    Cached,
+}
+
+fn loaded(key: &str) -> Option<Handle> {
+    status(key)?.ok()
+}
+
+/// Starts loading the image of the [`Source`], if needed, and moves it into
+/// the cache once it has finished loading.
+fn poll(source: &Source) -> Progress {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        let result = match cache.get(&source.key) {
+            None => {
+                let _ = cache
+                    .insert(source.key.clone(), Entry::Loading(spawn(source)));
+
+                return Progress::Pending;
+            }
+            Some(Entry::Done(_)) => return Progress::Cached,
+            Some(Entry::Loading(slot)) => {
+                match slot.lock().ok().and_then(|mut slot| slot.take()) {
+                    Some(result) => result,
+                    None => return Progress::Pending,
+                }
+            }
+        };
+
+        let _ = cache.insert(source.key.clone(), Entry::Done(result));
+
+        Progress::Completed
+    })
+}
+
+/// Runs the loader of the [`Source`] in the background, returning the slot
+/// its result will be stored in.
+fn spawn(source: &Source) -> Arc<Mutex<Option<Result<Handle, Error>>>> {
+    let slot = Arc::new(Mutex::new(None));
+    let future = (source.load)();
+    let result = Arc::clone(&slot);
+
+    let is_spawned = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+
+        if executor.is_none() {
+            *executor = Executor::new().ok();
+        }
+
+        executor.as_ref().map(|executor| {
+            executor.spawn(async move {
+                let image = future.await;
+
+                if let Ok(mut result) = result.lock() {
+                    *result = Some(image);
+                }
+            });
+        })
+    });
+
+    if is_spawned.is_none() {
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(Err(Error::new("no executor is available")));
+        }
+    }
+
+    slot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    #[test]
+    fn images_are_loaded_once_and_cached() {
+        let source = Source::new("test://pixel", || async {
+            Ok(Handle::from_pixels(1, 1, vec![255; 4]))
+        });
+
+        assert_eq!(poll(&source), Progress::Pending);
+
+        while poll(&source) == Progress::Pending {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(poll(&source), Progress::Cached);
+        assert!(loaded(source.key()).is_some());
+
+        evict(source.key());
+
+        assert!(status(source.key()).is_none());
+    }
+
+    #[test]
+    fn failures_are_cached() {
+        let source = Source::new("test://missing", || async {
+            Err(Error::new("not found"))
+        });
+
+        while poll(&source) == Progress::Pending {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert!(matches!(
+            status(source.key()),
+            Some(Err(error)) if error == Error::new("not found")
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+//! Stretch an image without distorting its borders.
+use crate::image;
+use crate::layout;
+use crate::renderer;
+use crate::widget::Tree;
+use crate::{Element, Layout, Length, Padding, Point, Rectangle, Size, Widget};
+
+use std::hash::Hash;
+
+/// An image split into nine regions by a set of insets, which can be
+/// stretched to any size while keeping its corners intact.
+///
+/// The corners are drawn at their original size, the edges are stretched
+/// along a single axis and the center fills the remaining space. It is
+/// useful to draw skinned buttons, chat bubbles and game interfaces.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::widget::image::NinePatch;
+/// # use iced_native::image;
+/// #
+/// let bubble = NinePatch::<image::Handle>::new("resources/bubble.png")
+///     .insets([12, 16]);
+/// ```
+#[derive(Debug)]
+pub struct NinePatch<Handle> {
+    handle: Handle,
+    insets: Padding,
+    width: Length,
+    height: Length,
+}
+
+impl<Handle> NinePatch<Handle> {
+    /// Creates a new [`NinePatch`] with the given image handle.
+    pub fn new<T: Into<Handle>>(handle: T) -> Self {
+        NinePatch {
+            handle: handle.into(),
+            insets: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the insets of the [`NinePatch`], in pixels of the image.
+    ///
+    /// They delimit the corners and edges of the image that are not
+    /// stretched in both directions.
+    pub fn insets<P: Into<Padding>>(mut self, insets: P) -> Self {
+        self.insets = insets.into();
+        self
+    }
+
+    /// Sets the width of the [`NinePatch`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`NinePatch`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, Renderer, Handle> Widget<Message, Renderer> for NinePatch<Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = renderer.dimensions(&self.handle);
+
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::new(width as f32, height as f32));
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let Size { width, height } = renderer.dimensions(&self.handle);
+        let image_size = Size::new(width as f32, height as f32);
+
+        for (source, target) in slices(image_size, self.insets, layout.bounds())
+        {
+            if source.width <= 0.0
+                || source.height <= 0.0
+                || target.width < 1.0
+                || target.height < 1.0
+            {
+                continue;
+            }
+
+            // Stretch the whole image so the source region lands on the
+            // target, and clip the rest of it
+            let scale_x = target.width / source.width;
+            let scale_y = target.height / source.height;
+
+            let bounds = Rectangle {
+                x: target.x - source.x * scale_x,
+                y: target.y - source.y * scale_y,
+                width: image_size.width * scale_x,
+                height: image_size.height * scale_y,
+            };
+
+            renderer.with_layer(target, |renderer| {
+                renderer.draw(self.handle.clone(), bounds);
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer, Handle> From<NinePatch<Handle>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(nine_patch: NinePatch<Handle>) -> Element<'a, Message, Renderer> {
+        Element::new(nine_patch)
+    }
+}
+
+/// Returns the nine regions of an image of the given size, paired with the
+/// regions of the bounds they are drawn in.
+///
+/// The insets are clamped to the size of the image, and the borders shrink
+/// proportionally when the bounds are too small to contain them.
+fn slices(
+    image_size: Size,
+    insets: Padding,
+    bounds: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let source_x = cuts(image_size.width, insets.left, insets.right);
+    let source_y = cuts(image_size.height, insets.top, insets.bottom);
+
+    let target_x =
+        cuts(bounds.width, source_x[1], image_size.width - source_x[2])
+            .map(|x| bounds.x + x);
+    let target_y =
+        cuts(bounds.height, source_y[1], image_size.height - source_y[2])
+            .map(|y| bounds.y + y);
+
+    let region =
+        |x: [f32; 4], y: [f32; 4], column: usize, row: usize| Rectangle {
+            x: x[column],
+            y: y[row],
+            width: x[column + 1] - x[column],
+            height: y[row + 1] - y[row],
+        };
+
+    (0..3)
+        .flat_map(|row| (0..3).map(move |column| (column, row)))
+        .map(|(column, row)| {
+            (
+                region(source_x, source_y, column, row),
+                region(target_x, target_y, column, row),
+            )
+        })
+        .collect()
+}
+
+/// Splits a length in three by the given borders, shrinking them
+/// proportionally if they do not fit.
+fn cuts(length: f32, start: f32, end: f32) -> [f32; 4] {
+    let (start, end) = (start.max(0.0), end.max(0.0));
+    let borders = start + end;
+
+    let ratio = if borders > length && borders > 0.0 {
+        length / borders
+    } else {
+        1.0
+    };
+
+    [0.0, start * ratio, length - end * ratio, length]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices_keep_corners_intact() {
+        let slices = slices(
+            Size::new(30.0, 30.0),
+            Padding::new(10.0),
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(100.0, 50.0)),
+        );
+
+        let (source, target) = slices[0];
+        assert_eq!(
+            source,
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0))
+        );
+        assert_eq!(target, Rectangle::new(Point::new(5.0, 5.0), source.size()));
+
+        let (source, target) = slices[4];
+        assert_eq!(
+            source,
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(10.0, 10.0))
+        );
+        assert_eq!(
+            target,
+            Rectangle::new(Point::new(15.0, 15.0), Size::new(80.0, 30.0))
+        );
+
+        let (_, target) = slices[8];
+        assert_eq!(
+            target,
+            Rectangle::new(Point::new(95.0, 45.0), Size::new(10.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn borders_shrink_when_they_do_not_fit() {
+        assert_eq!(cuts(10.0, 10.0, 10.0), [0.0, 5.0, 5.0, 10.0]);
+        assert_eq!(cuts(10.0, 50.0, 0.0), [0.0, 10.0, 10.0, 10.0]);
+    }
+}
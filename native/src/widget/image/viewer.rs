@@ -13,18 +13,25 @@ use crate::{
 use std::hash::Hash;
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
+///
+/// The image can also be rotated in steps of 90° and mirrored, which makes
+/// the [`Viewer`] suitable for browsing photos.
 #[allow(missing_debug_implementations)]
-pub struct Viewer<Handle> {
+pub struct Viewer<'a, Message, Handle> {
     padding: f32,
     width: Length,
     height: Length,
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    rotation: Rotation,
+    flip: image::Flip,
+    fit: Fit,
+    on_zoom: Option<Box<dyn Fn(f32) -> Message + 'a>>,
     handle: Handle,
 }
 
-impl<Handle> Viewer<Handle> {
+impl<'a, Message, Handle> Viewer<'a, Message, Handle> {
     /// Creates a new [`Viewer`] with the given [`State`].
     pub fn new(handle: Handle) -> Self {
         Viewer {
@@ -34,6 +41,10 @@ impl<Handle> Viewer<Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            rotation: Rotation::default(),
+            flip: image::Flip::default(),
+            fit: Fit::default(),
+            on_zoom: None,
             handle,
         }
     }
@@ -80,9 +91,107 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Sets the [`Rotation`] of the image of the [`Viewer`].
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets whether the image of the [`Viewer`] is mirrored from left to
+    /// right, after being rotated.
+    pub fn flip_horizontal(mut self, flip: bool) -> Self {
+        self.flip.horizontal = flip;
+        self
+    }
+
+    /// Sets whether the image of the [`Viewer`] is mirrored from top to
+    /// bottom, after being rotated.
+    pub fn flip_vertical(mut self, flip: bool) -> Self {
+        self.flip.vertical = flip;
+        self
+    }
+
+    /// Sets how the image of the [`Viewer`] is fitted to its bounds before
+    /// being zoomed.
+    ///
+    /// Default is [`Fit::Contain`]
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Viewer`] is zoomed.
+    ///
+    /// The closure receives the current zoom level: the amount of screen
+    /// pixels used to display a pixel of the image.
+    pub fn on_zoom(mut self, on_zoom: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_zoom = Some(Box::new(on_zoom));
+        self
+    }
+}
+
+/// A clockwise rotation of the image of a [`Viewer`], in steps of 90°.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// The image is not rotated.
+    #[default]
+    None,
+    /// The image is rotated 90° clockwise.
+    Clockwise90,
+    /// The image is upside down.
+    Clockwise180,
+    /// The image is rotated 90° counterclockwise.
+    Clockwise270,
 }
 
-impl<Message, Renderer, Handle> Widget<Message, Renderer> for Viewer<Handle>
+impl Rotation {
+    /// Returns the [`Rotation`] after turning 90° clockwise.
+    pub fn clockwise(self) -> Self {
+        match self {
+            Rotation::None => Rotation::Clockwise90,
+            Rotation::Clockwise90 => Rotation::Clockwise180,
+            Rotation::Clockwise180 => Rotation::Clockwise270,
+            Rotation::Clockwise270 => Rotation::None,
+        }
+    }
+
+    /// Returns the [`Rotation`] after turning 90° counterclockwise.
+    pub fn counterclockwise(self) -> Self {
+        self.clockwise().clockwise().clockwise()
+    }
+
+    /// Returns the angle of the [`Rotation`], in radians.
+    pub fn radians(self) -> f32 {
+        match self {
+            Rotation::None => 0.0,
+            Rotation::Clockwise90 => std::f32::consts::FRAC_PI_2,
+            Rotation::Clockwise180 => std::f32::consts::PI,
+            Rotation::Clockwise270 => 3.0 * std::f32::consts::FRAC_PI_2,
+        }
+    }
+
+    /// Returns whether the [`Rotation`] swaps the width and the height of
+    /// the image.
+    pub fn is_sideways(self) -> bool {
+        matches!(self, Rotation::Clockwise90 | Rotation::Clockwise270)
+    }
+}
+
+/// How the image of a [`Viewer`] is fitted to its bounds before zooming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fit {
+    /// The image is shrunk to fit inside the bounds, but never enlarged.
+    #[default]
+    Contain,
+    /// The image is scaled to fill the width of the bounds.
+    Width,
+    /// The image is scaled to fill the height of the bounds.
+    Height,
+}
+
+impl<'a, Message, Renderer, Handle> Widget<Message, Renderer>
+    for Viewer<'a, Message, Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
@@ -108,7 +217,8 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let Size { width, height } = renderer.dimensions(&self.handle);
+        let Size { width, height } =
+            dimensions(renderer, &self.handle, self.rotation);
 
         let mut size = limits
             .width(self.width)
@@ -147,7 +257,7 @@ where
         cursor_position: Point,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
-        _shell: &mut Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
@@ -177,6 +287,8 @@ where
                                 &self.handle,
                                 state,
                                 bounds.size(),
+                                self.rotation,
+                                self.fit,
                             );
 
                             let factor = state.scale / previous_scale - 1.0;
@@ -199,6 +311,18 @@ where
                                     0.0
                                 },
                             );
+
+                            if let Some(on_zoom) = &self.on_zoom {
+                                let size = dimensions(
+                                    renderer,
+                                    &self.handle,
+                                    self.rotation,
+                                );
+
+                                shell.publish(on_zoom(
+                                    image_size.width / size.width as f32,
+                                ));
+                            }
                         }
                     }
                 }
@@ -235,6 +359,8 @@ where
                         &self.handle,
                         state,
                         bounds.size(),
+                        self.rotation,
+                        self.fit,
                     );
 
                     let hidden_width = (image_size.width - bounds.width / 2.0)
@@ -307,8 +433,14 @@ where
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
-        let image_size =
-            image_size(renderer, &self.handle, state, bounds.size());
+        let image_size = image_size(
+            renderer,
+            &self.handle,
+            state,
+            bounds.size(),
+            self.rotation,
+            self.fit,
+        );
 
         let translation = {
             let image_top_left = Vector::new(
@@ -319,16 +451,35 @@ where
             image_top_left - state.offset(bounds, image_size)
         };
 
+        // The image is drawn unrotated around the same center
+        let unrotated_size = if self.rotation.is_sideways() {
+            Size::new(image_size.height, image_size.width)
+        } else {
+            image_size
+        };
+
+        // Mirroring after rotating is the same as mirroring before rotating
+        // the other way around
+        let rotation = if self.flip.horizontal != self.flip.vertical {
+            -self.rotation.radians()
+        } else {
+            self.rotation.radians()
+        };
+
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(translation, |renderer| {
-                image::Renderer::draw(
+                image::Renderer::draw_transformed(
                     renderer,
                     self.handle.clone(),
                     Rectangle {
-                        x: bounds.x,
-                        y: bounds.y,
-                        ..Rectangle::with_size(image_size)
+                        x: bounds.x
+                            + (image_size.width - unrotated_size.width) / 2.0,
+                        y: bounds.y
+                            + (image_size.height - unrotated_size.height) / 2.0,
+                        ..Rectangle::with_size(unrotated_size)
                     },
+                    rotation,
+                    self.flip,
                 )
             });
         });
@@ -382,47 +533,100 @@ impl State {
     }
 }
 
-impl<'a, Message, Renderer, Handle> From<Viewer<Handle>>
+impl<'a, Message, Renderer, Handle> From<Viewer<'a, Message, Handle>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + image::Renderer<Handle = Handle>,
     Message: 'a,
     Handle: Clone + Hash + 'a,
 {
-    fn from(viewer: Viewer<Handle>) -> Element<'a, Message, Renderer> {
+    fn from(
+        viewer: Viewer<'a, Message, Handle>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(viewer)
     }
 }
 
 /// Returns the bounds of the underlying image, given the bounds of
-/// the [`Viewer`]. Scaling will be applied and original aspect ratio
-/// will be respected.
+/// the [`Viewer`]. The image is fitted and scaled, respecting its original
+/// aspect ratio once rotated.
 pub fn image_size<Renderer>(
     renderer: &Renderer,
     handle: &<Renderer as image::Renderer>::Handle,
     state: &State,
     bounds: Size,
+    rotation: Rotation,
+    fit: Fit,
 ) -> Size
+where
+    Renderer: image::Renderer,
+{
+    let Size { width, height } = dimensions(renderer, handle, rotation);
+    let dimensions = Size::new(width as f32, height as f32);
+
+    let scale = fit_ratio(dimensions, bounds, fit) * state.scale;
+
+    Size::new(dimensions.width * scale, dimensions.height * scale)
+}
+
+/// Returns the dimensions of the image once rotated.
+fn dimensions<Renderer>(
+    renderer: &Renderer,
+    handle: &<Renderer as image::Renderer>::Handle,
+    rotation: Rotation,
+) -> Size<u32>
 where
     Renderer: image::Renderer,
 {
     let Size { width, height } = renderer.dimensions(handle);
 
-    let (width, height) = {
-        let dimensions = (width as f32, height as f32);
+    if rotation.is_sideways() {
+        Size::new(height, width)
+    } else {
+        Size::new(width, height)
+    }
+}
 
-        let width_ratio = bounds.width / dimensions.0;
-        let height_ratio = bounds.height / dimensions.1;
+/// Returns the scale that fits an image of the given dimensions to the
+/// bounds, before any zoom is applied.
+fn fit_ratio(dimensions: Size, bounds: Size, fit: Fit) -> f32 {
+    let width_ratio = bounds.width / dimensions.width;
+    let height_ratio = bounds.height / dimensions.height;
 
-        let ratio = width_ratio.min(height_ratio);
-        let scale = state.scale;
+    match fit {
+        Fit::Contain => width_ratio.min(height_ratio).min(1.0),
+        Fit::Width => width_ratio,
+        Fit::Height => height_ratio,
+    }
+}
 
-        if ratio < 1.0 {
-            (dimensions.0 * ratio * scale, dimensions.1 * ratio * scale)
-        } else {
-            (dimensions.0 * scale, dimensions.1 * scale)
-        }
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Size::new(width, height)
+    #[test]
+    fn rotation_cycles_in_both_directions() {
+        let rotation = Rotation::default();
+
+        assert_eq!(rotation.clockwise(), Rotation::Clockwise90);
+        assert_eq!(rotation.counterclockwise(), Rotation::Clockwise270);
+        assert_eq!(rotation.clockwise().counterclockwise(), rotation);
+        assert!(Rotation::Clockwise270.is_sideways());
+        assert!(!Rotation::Clockwise180.is_sideways());
+    }
+
+    #[test]
+    fn fit_ratio_depends_on_fit() {
+        let dimensions = Size::new(400.0, 200.0);
+        let bounds = Size::new(200.0, 200.0);
+
+        assert_eq!(fit_ratio(dimensions, bounds, Fit::Contain), 0.5);
+        assert_eq!(fit_ratio(dimensions, bounds, Fit::Width), 0.5);
+        assert_eq!(fit_ratio(dimensions, bounds, Fit::Height), 1.0);
+
+        let large_bounds = Size::new(800.0, 800.0);
+
+        assert_eq!(fit_ratio(dimensions, large_bounds, Fit::Contain), 1.0);
+        assert_eq!(fit_ratio(dimensions, large_bounds, Fit::Width), 2.0);
+    }
 }
@@ -0,0 +1,514 @@
+//! Select a value from a range by turning a rotary knob.
+//!
+//! A [`Knob`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+pub use iced_style::knob::{Appearance, StyleSheet};
+
+/// A circular control that selects a single value from a range of values,
+/// like the knobs of audio equipment.
+///
+/// The value is changed by dragging vertically over the [`Knob`] or by
+/// scrolling the mouse wheel on it. Holding `Shift` while dragging adjusts
+/// the value more finely.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::knob;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Knob<'a, T, Message> = knob::Knob<'a, T, Message, Null>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     GainChanged(f32),
+/// }
+///
+/// let gain = 0.5;
+///
+/// Knob::new(0.0..=1.0, gain, Message::GainChanged).step(0.01);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, T, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    value: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    on_release: Option<Message>,
+    size: f32,
+    arc: RangeInclusive<f32>,
+    ticks: u16,
+    sensitivity: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> Knob<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of a [`Knob`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default amount of pixels that have to be dragged to go through
+    /// the whole range of a [`Knob`].
+    pub const DEFAULT_SENSITIVITY: f32 = 200.0;
+
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`Knob`]
+    ///   * a function that will be called when the [`Knob`] is turned.
+    ///   It receives the new value of the [`Knob`] and must produce a
+    ///   `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value >= *range.start() {
+            value
+        } else {
+            *range.start()
+        };
+
+        let value = if value <= *range.end() {
+            value
+        } else {
+            *range.end()
+        };
+
+        Knob {
+            value,
+            range,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            size: Self::DEFAULT_SIZE,
+            arc: -135.0..=135.0,
+            ticks: 11,
+            sensitivity: Self::DEFAULT_SENSITIVITY,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`Knob`].
+    /// This is called when the mouse is released from the knob.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`Knob`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the arc the indicator of the [`Knob`] travels through, as the
+    /// angles of the start and the end of the range in degrees, clockwise
+    /// from the top.
+    ///
+    /// By default, the arc goes from `-135` to `135` degrees.
+    pub fn arc(mut self, arc: RangeInclusive<f32>) -> Self {
+        self.arc = arc;
+        self
+    }
+
+    /// Sets the amount of tick marks around the [`Knob`].
+    pub fn ticks(mut self, ticks: u16) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the amount of pixels that have to be dragged to go through the
+    /// whole range of the [`Knob`].
+    pub fn sensitivity(mut self, sensitivity: impl Into<Pixels>) -> Self {
+        self.sensitivity = sensitivity.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// How much finer the value of a [`Knob`] changes while the fine-adjust
+/// modifier is held.
+const FINE_ADJUST_FACTOR: f64 = 0.1;
+
+impl<'a, T, Message, Renderer> Knob<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the value of the range closest to the given one, snapped to
+    /// the step of the [`Knob`].
+    fn snap(&self, value: f64) -> Option<T> {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let step = self.step.into();
+
+        let value = value.clamp(start.min(end), end.max(start));
+        let value = if step > 0.0 {
+            ((value - start) / step).round() * step + start
+        } else {
+            value
+        };
+
+        T::from_f64(value.clamp(start.min(end), end.max(start)))
+    }
+
+    fn change(&mut self, value: f64, shell: &mut Shell<'_, Message>) {
+        if let Some(new_value) = self.snap(value) {
+            if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
+                shell.publish((self.on_change)(new_value));
+
+                self.value = new_value;
+            }
+        }
+    }
+
+    /// Returns the angle of the given value in degrees.
+    fn angle(&self, value: f64) -> f32 {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        let percent = if start == end {
+            0.0
+        } else {
+            ((value - start) / (end - start)).clamp(0.0, 1.0) as f32
+        };
+
+        self.arc.start() + (self.arc.end() - self.arc.start()) * percent
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Knob<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.size).height(self.size);
+
+        layout::Node::new(limits.resolve(Size::new(self.size, self.size)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let factor = if state.modifiers.shift() {
+            FINE_ADJUST_FACTOR
+        } else {
+            1.0
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if bounds.contains(cursor_position) {
+                    state.drag = Some(Drag {
+                        last_y: cursor_position.y,
+                        value: self.value.into(),
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    let delta = f64::from(drag.last_y - cursor_position.y)
+                        / f64::from(self.sensitivity)
+                        * (end - start)
+                        * factor;
+
+                    // The unsnapped value is kept, so small movements add up
+                    drag.value = (drag.value + delta)
+                        .clamp(start.min(end), end.max(start));
+                    drag.last_y = cursor_position.y;
+
+                    let value = drag.value;
+                    self.change(value, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if bounds.contains(cursor_position) {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => {
+                            f64::from(y) * self.step.into().max(f64::EPSILON)
+                        }
+                        mouse::ScrollDelta::Pixels { y, .. } => {
+                            f64::from(y) / f64::from(self.sensitivity)
+                                * (end - start)
+                                * factor
+                        }
+                    };
+
+                    self.change(self.value.into() + delta, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = if state.drag.is_some() {
+            theme.dragging(&self.style)
+        } else if bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let tick_size = (radius * 0.16).max(2.0);
+        let body_radius = (radius - 2.0 * tick_size).max(0.0);
+
+        let value_angle = self.angle(self.value.into());
+
+        // Tick marks
+        if self.ticks > 0 {
+            let ring = radius - tick_size / 2.0;
+
+            for i in 0..self.ticks {
+                let percent = if self.ticks == 1 {
+                    0.0
+                } else {
+                    f32::from(i) / f32::from(self.ticks - 1)
+                };
+
+                let angle = self.arc.start()
+                    + (self.arc.end() - self.arc.start()) * percent;
+
+                let is_filled = if self.arc.end() >= self.arc.start() {
+                    angle <= value_angle + 0.01
+                } else {
+                    angle >= value_angle - 0.01
+                };
+
+                dot(
+                    renderer,
+                    polar(center, ring, angle),
+                    tick_size,
+                    if is_filled {
+                        appearance.filled_tick_color
+                    } else {
+                        appearance.tick_color
+                    },
+                );
+            }
+        }
+
+        // Body
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - body_radius,
+                    y: center.y - body_radius,
+                    width: body_radius * 2.0,
+                    height: body_radius * 2.0,
+                },
+                border_radius: body_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        // Indicator
+        dot(
+            renderer,
+            polar(center, body_radius * 0.65, value_angle),
+            tick_size * 1.25,
+            appearance.indicator_color,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Knob<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        knob: Knob<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(knob)
+    }
+}
+
+/// Returns the point at the given distance from the center in the direction
+/// of the given angle in degrees, clockwise from the top.
+fn polar(center: Point, distance: f32, angle: f32) -> Point {
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    Point::new(center.x + distance * sin, center.y - distance * cos)
+}
+
+/// Draws a circle with the given diameter centered at the given point.
+fn dot<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    diameter: f32,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: center.x - diameter / 2.0,
+                y: center.y - diameter / 2.0,
+                width: diameter,
+                height: diameter,
+            },
+            border_radius: (diameter / 2.0).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        color,
+    );
+}
+
+/// The local state of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    last_y: f32,
+    value: f64,
+}
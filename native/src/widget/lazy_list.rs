@@ -0,0 +1,503 @@
+//! Display a huge amount of rows, building only the visible ones.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable::{self, Properties};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+pub use iced_style::scrollable::StyleSheet;
+
+/// A scrollable list of rows with a fixed height that only builds, lays out,
+/// and draws the rows that are visible.
+///
+/// The rows are produced on demand by a function that takes their index. The
+/// state of the widgets of a row is kept while the row stays around the
+/// visible region, and dropped otherwise.
+///
+/// # Example
+/// ```
+/// # pub type LazyList<'a, Message> = iced_native::widget::LazyList<'a, Message, iced_native::renderer::Null>;
+/// # pub type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {}
+///
+/// let list: LazyList<'_, Message> =
+///     LazyList::new(50_000, 30, |index| Text::new(format!("Row {index}")).into());
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LazyList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    count: usize,
+    row_height: f32,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    buffer: usize,
+    scrollbar: Properties,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> LazyList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default amount of rows built around the visible ones.
+    pub const DEFAULT_BUFFER: usize = 5;
+
+    /// Creates a new [`LazyList`] with the given amount of rows, the height of
+    /// every row, and the function that produces the row at a given index.
+    pub fn new(
+        count: usize,
+        row_height: impl Into<Pixels>,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        LazyList {
+            count,
+            row_height: row_height.into().0,
+            view: Box::new(view),
+            width: Length::Fill,
+            height: Length::Fill,
+            buffer: Self::DEFAULT_BUFFER,
+            scrollbar: Properties::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`LazyList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LazyList`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the amount of rows built above and below the visible ones, so
+    /// they are ready when scrolling.
+    pub fn buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// Sets the [`Properties`] of the scrollbar of the [`LazyList`].
+    pub fn scrollbar(mut self, properties: Properties) -> Self {
+        self.scrollbar = properties;
+        self
+    }
+
+    /// Sets the style of the [`LazyList`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the indices of the rows that intersect the given viewport,
+    /// including the buffer.
+    fn visible_rows(
+        &self,
+        viewport: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Range<usize> {
+        if self.count == 0 || self.row_height <= 0.0 {
+            return 0..0;
+        }
+
+        let top = (viewport.y - content_bounds.y).max(0.0);
+
+        let start = (top / self.row_height).floor() as usize;
+        let end = ((top + viewport.height) / self.row_height).ceil() as usize;
+
+        start.saturating_sub(self.buffer).min(self.count)
+            ..end.saturating_add(self.buffer).min(self.count)
+    }
+
+    /// Lays out the row at the given index, relative to the content.
+    fn layout_row(
+        &self,
+        renderer: &Renderer,
+        row: &Element<'a, Message, Renderer>,
+        index: usize,
+        width: f32,
+        direction: layout::Direction,
+    ) -> layout::Node {
+        let size = Size::new(width, self.row_height);
+
+        let mut node = row.as_widget().layout(
+            renderer,
+            &layout::Limits::new(size, size).with_direction(direction),
+        );
+        node.move_to(Point::new(0.0, index as f32 * self.row_height));
+
+        node
+    }
+}
+
+struct State {
+    scrollable: scrollable::State,
+    rows: HashMap<usize, Tree>,
+}
+
+impl State {
+    /// Returns the visible region of the content of a [`LazyList`].
+    fn viewport(&self, layout: Layout<'_>) -> (Rectangle, Rectangle) {
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = self.scrollable.offset(bounds, content_bounds);
+
+        (
+            Rectangle {
+                x: bounds.x + offset.x,
+                y: bounds.y + offset.y,
+                ..bounds
+            },
+            content_bounds,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for LazyList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            scrollable: scrollable::State::new(),
+            rows: HashMap::new(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.rows.retain(|index, _| *index < self.count);
+
+        for (index, row) in state.rows.iter_mut() {
+            row.diff(&(self.view)(*index));
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        scrollable::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            false,
+            |_renderer, limits| {
+                layout::Node::new(Size::new(
+                    limits.max().width,
+                    self.count as f32 * self.row_height,
+                ))
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let translation = state.scrollable.offset(bounds, content_bounds);
+
+        operation.scrollable_with_viewport(
+            &mut state.scrollable,
+            None,
+            bounds,
+            content_bounds,
+            translation,
+        );
+
+        let mut indices: Vec<usize> = state.rows.keys().copied().collect();
+        indices.sort_unstable();
+
+        let offset = Vector::new(content_bounds.x, content_bounds.y);
+
+        operation.container_with_bounds(None, bounds, &mut |operation| {
+            for index in &indices {
+                if let Some(tree) = state.rows.get_mut(index) {
+                    let row = (self.view)(*index);
+                    let node = self.layout_row(
+                        renderer,
+                        &row,
+                        *index,
+                        content_bounds.width,
+                        layout.direction(),
+                    );
+
+                    row.as_widget().operate(
+                        tree,
+                        Layout::with_offset(offset, &node),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let (viewport, content_bounds) = state.viewport(layout);
+
+        let mut rows: Vec<_> = self
+            .visible_rows(viewport, content_bounds)
+            .map(|index| {
+                let row = (self.view)(index);
+                let node = self.layout_row(
+                    renderer,
+                    &row,
+                    index,
+                    content_bounds.width,
+                    layout.direction(),
+                );
+
+                (index, row, node)
+            })
+            .collect();
+
+        for (index, row, _) in &rows {
+            let _ = state
+                .rows
+                .entry(*index)
+                .and_modify(|tree| tree.diff(row))
+                .or_insert_with(|| Tree::new(row));
+        }
+
+        let State {
+            scrollable,
+            rows: trees,
+        } = state;
+
+        let status = scrollable::update(
+            scrollable,
+            event,
+            layout,
+            cursor_position,
+            clipboard,
+            shell,
+            &self.scrollbar,
+            None,
+            None,
+            false,
+            false,
+            scrollable::WheelRouting::default(),
+            &[],
+            &None,
+            |event, content_layout, cursor_position, clipboard, shell| {
+                let offset = Vector::new(
+                    content_layout.position().x,
+                    content_layout.position().y,
+                );
+
+                rows.iter_mut()
+                    .map(|(index, row, node)| {
+                        row.as_widget_mut().on_event(
+                            trees.get_mut(index).unwrap(),
+                            event.clone(),
+                            Layout::with_offset(offset, node),
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        // Recycle the state of the rows that are no longer around
+        let (viewport, content_bounds) = state.viewport(layout);
+        let visible = self.visible_rows(viewport, content_bounds);
+
+        state.rows.retain(|index, _| visible.contains(index));
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            &self.scrollbar,
+            None,
+            &[],
+            &self.style,
+            |renderer, content_layout, cursor_position, viewport| {
+                let content_bounds = content_layout.bounds();
+                let offset = Vector::new(content_bounds.x, content_bounds.y);
+
+                for index in self.visible_rows(*viewport, content_bounds) {
+                    let row = (self.view)(index);
+                    let node = self.layout_row(
+                        renderer,
+                        &row,
+                        index,
+                        content_bounds.width,
+                        layout.direction(),
+                    );
+
+                    let fallback;
+                    let tree = match state.rows.get(&index) {
+                        Some(tree) => tree,
+                        None => {
+                            fallback = Tree::new(&row);
+                            &fallback
+                        }
+                    };
+
+                    row.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        Layout::with_offset(offset, &node),
+                        cursor_position,
+                        viewport,
+                    );
+                }
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            layout,
+            cursor_position,
+            &self.scrollbar,
+            None,
+            &[],
+            |content_layout, cursor_position, viewport| {
+                let content_bounds = content_layout.bounds();
+                let offset = Vector::new(content_bounds.x, content_bounds.y);
+
+                if self.row_height <= 0.0 {
+                    return mouse::Interaction::default();
+                }
+
+                let index = ((cursor_position.y - content_bounds.y)
+                    / self.row_height)
+                    .floor();
+
+                if index < 0.0 || index as usize >= self.count {
+                    return mouse::Interaction::default();
+                }
+
+                let index = index as usize;
+
+                match state.rows.get(&index) {
+                    Some(tree) => {
+                        let row = (self.view)(index);
+                        let node = self.layout_row(
+                            renderer,
+                            &row,
+                            index,
+                            content_bounds.width,
+                            layout.direction(),
+                        );
+
+                        row.as_widget().mouse_interaction(
+                            tree,
+                            Layout::with_offset(offset, &node),
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    }
+                    None => mouse::Interaction::default(),
+                }
+            },
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<LazyList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        lazy_list: LazyList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy_list)
+    }
+}
@@ -0,0 +1,889 @@
+//! Show the menus of an application in a bar, like File, Edit or View.
+//!
+//! A [`MenuBar`] has some local [`State`].
+mod overlay;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay as native_overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::menu_bar::{Appearance, StyleSheet};
+
+/// A bar of menu titles, each opening a dropdown of [`Item`]s.
+///
+/// Once a menu is open, hovering another title switches to its menu. The
+/// menus can also be navigated with the keyboard: a `&` in a title or a
+/// label marks the next character as its mnemonic, so `"&File"` opens with
+/// Alt+F and `"&&"` shows a plain `&`.
+///
+/// # Example
+/// ```
+/// # type MenuBar<Message> = iced_native::widget::MenuBar<Message, iced_native::renderer::Null>;
+/// use iced_native::widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+///     Save,
+///     Export(&'static str),
+/// }
+///
+/// let menu_bar: MenuBar<Message> = MenuBar::new(vec![
+///     Menu::new(
+///         "&File",
+///         vec![
+///             Item::new("&Open", Message::Open).shortcut("Ctrl+O"),
+///             Item::new("&Save", Message::Save).shortcut("Ctrl+S"),
+///             Item::separator(),
+///             Item::submenu(
+///                 "&Export",
+///                 vec![
+///                     Item::new("&PDF", Message::Export("pdf")),
+///                     Item::disabled("&HTML"),
+///                 ],
+///             ),
+///         ],
+///     ),
+///     Menu::new("&Edit", vec![]),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    memory: Option<native_overlay::Memory>,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    item_padding: Padding,
+    menu_width: f32,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the titles of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 8.0,
+        bottom: 4.0,
+        left: 8.0,
+    };
+
+    /// The default [`Padding`] of the items of the menus of a [`MenuBar`].
+    pub const DEFAULT_ITEM_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 12.0,
+        bottom: 4.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: Vec<Menu<Message>>) -> Self {
+        MenuBar {
+            id: None,
+            memory: None,
+            menus,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            item_padding: Self::DEFAULT_ITEM_PADDING,
+            menu_width: 160.0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`MenuBar`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Memory`](native_overlay::Memory) of the [`MenuBar`].
+    ///
+    /// A [`MenuBar`] with an [`Id`] remembers the submenus that were open
+    /// when its last menu was closed in the
+    /// [`Memory`](native_overlay::Memory), even if it is removed from the user
+    /// interface and added back later.
+    pub fn memory(mut self, memory: native_overlay::Memory) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the menus of the [`MenuBar`].
+    pub fn item_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.item_padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the menus of the [`MenuBar`].
+    pub fn menu_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.menu_width = width.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`MenuBar`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn title_at(&self, layout: Layout<'_>, point: Point) -> Option<usize> {
+        layout
+            .children()
+            .position(|title| title.bounds().contains(point))
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(match (&self.memory, &self.id) {
+            (Some(memory), Some(id)) => {
+                State::restore(memory.clone(), id.clone())
+            }
+            _ => State::new(),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.label_size(renderer);
+        let height = text_size + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = renderer.measure_width(
+                    &menu.title.text,
+                    text_size,
+                    self.font.clone(),
+                ) + self.padding.horizontal();
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, height));
+
+        let mut node = layout::Node::with_children(size, titles)
+            .with_direction(limits.direction());
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Hovering another title switches to its menu
+                if let (Some(open), Some(hovered)) =
+                    (state.open, self.title_at(layout, cursor_position))
+                {
+                    if open != hovered {
+                        state.resume(&self.menus, hovered);
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(title) = self.title_at(layout, cursor_position) {
+                    if state.open == Some(title) {
+                        state.close();
+                    } else {
+                        state.resume(&self.menus, title);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if modifiers.alt() => {
+                let menu = letter(key_code).and_then(|letter| {
+                    self.menus
+                        .iter()
+                        .position(|menu| menu.title.mnemonic() == Some(letter))
+                });
+
+                if let Some(menu) = menu {
+                    state.open(menu, first_selectable(&self.menus[menu].items));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.title_at(layout, cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let text_size = self.label_size(renderer);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let show_mnemonics = state.modifiers.alt() || state.open.is_some();
+        let is_rtl = layout.direction() == layout::Direction::RightToLeft;
+
+        for (i, (menu, title)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let title_bounds = title.bounds();
+            let is_open = state.open == Some(i);
+
+            let is_selected = is_open
+                || (state.open.is_none()
+                    && title_bounds.contains(cursor_position));
+
+            let text_color = if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: title_bounds,
+                        border_radius: 2.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selected_background,
+                );
+
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            draw_label(
+                renderer,
+                &menu.title,
+                Point::new(
+                    title_bounds.x
+                        + if is_rtl {
+                            self.padding.right
+                        } else {
+                            self.padding.left
+                        },
+                    title_bounds.center_y(),
+                ),
+                text_size,
+                self.font.clone(),
+                text_color,
+                show_mnemonics,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<native_overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let open = state.open?;
+
+        if open >= self.menus.len() {
+            state.close();
+
+            return None;
+        }
+
+        let bar = layout.bounds();
+        let title = layout.children().nth(open)?.bounds();
+
+        Some(native_overlay::Element::new(
+            bar.position(),
+            Box::new(overlay::Overlay::new(
+                self,
+                state,
+                Rectangle {
+                    x: title.x - bar.x,
+                    y: title.y - bar.y,
+                    ..title
+                },
+                bar.size(),
+                layout.direction(),
+            )),
+        ))
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// A menu of a [`MenuBar`], with its title and items.
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    title: Label,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    pub fn new(title: impl AsRef<str>, items: Vec<Item<Message>>) -> Self {
+        Self {
+            title: Label::parse(title.as_ref()),
+            items,
+        }
+    }
+}
+
+/// An entry of a [`Menu`].
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: Label,
+    shortcut: Option<String>,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] producing the given message when selected.
+    pub fn new(label: impl AsRef<str>, message: Message) -> Self {
+        Self {
+            label: Label::parse(label.as_ref()),
+            shortcut: None,
+            kind: Kind::Action(Some(message)),
+        }
+    }
+
+    /// Creates a new [`Item`] that cannot be selected.
+    pub fn disabled(label: impl AsRef<str>) -> Self {
+        Self {
+            label: Label::parse(label.as_ref()),
+            shortcut: None,
+            kind: Kind::Action(None),
+        }
+    }
+
+    /// Creates a new [`Item`] opening a nested menu with the given items.
+    pub fn submenu(label: impl AsRef<str>, items: Vec<Item<Message>>) -> Self {
+        Self {
+            label: Label::parse(label.as_ref()),
+            shortcut: None,
+            kind: Kind::Submenu(items),
+        }
+    }
+
+    /// Creates a new [`Item`] separating groups of items.
+    pub fn separator() -> Self {
+        Self {
+            label: Label::parse(""),
+            shortcut: None,
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the keyboard shortcut displayed next to the [`Item`].
+    ///
+    /// The shortcut is only displayed; handling it is up to the
+    /// application.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    fn is_selectable(&self) -> bool {
+        match &self.kind {
+            Kind::Action(message) => message.is_some(),
+            Kind::Submenu(_) => true,
+            Kind::Separator => false,
+        }
+    }
+
+    fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// The text of a title or an item, with its mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Label {
+    text: String,
+    // The byte offset of the mnemonic in the text
+    mnemonic: Option<usize>,
+}
+
+impl Label {
+    /// Parses a label where a `&` marks the next character as the mnemonic
+    /// and `&&` is a plain `&`.
+    fn parse(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(text.len());
+                    }
+
+                    text.push(c);
+                }
+                None => {}
+            }
+        }
+
+        Self { text, mnemonic }
+    }
+
+    /// Returns the mnemonic of the [`Label`], in lowercase.
+    fn mnemonic(&self) -> Option<char> {
+        let c = self.text[self.mnemonic?..].chars().next()?;
+
+        c.to_lowercase().next()
+    }
+}
+
+/// The identifier of a [`MenuBar`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    memory: Option<(native_overlay::Memory, Id)>,
+    open: Option<usize>,
+    // The highlighted item of each open menu, from the menu of the open
+    // title to the deepest submenu
+    levels: Vec<Option<usize>>,
+    // The open menu and levels when the bar was last closed
+    last: Option<Path>,
+    modifiers: keyboard::Modifiers,
+}
+
+/// The open menu of a [`MenuBar`] and the highlighted item of each level.
+type Path = (usize, Vec<Option<usize>>);
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`] for the [`MenuBar`] with the given [`Id`],
+    /// restoring the submenus that were open when its last menu was closed
+    /// from the given [`Memory`](native_overlay::Memory), if any.
+    ///
+    /// The state is stored back in the [`Memory`](native_overlay::Memory)
+    /// when dropped.
+    pub fn restore(memory: native_overlay::Memory, id: Id) -> Self {
+        let last = memory.take::<Path>(&id.0);
+
+        Self {
+            memory: Some((memory, id)),
+            open: None,
+            levels: Vec::new(),
+            last,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+
+    /// Returns the index of the open menu, if any.
+    pub fn open_menu(&self) -> Option<usize> {
+        self.open
+    }
+
+    fn open(&mut self, menu: usize, highlighted: Option<usize>) {
+        self.remember();
+
+        self.open = Some(menu);
+        self.levels = vec![highlighted];
+    }
+
+    /// Opens the given menu, resuming from the submenus that were open when
+    /// it was last closed, if any.
+    fn resume<Message>(&mut self, menus: &[Menu<Message>], menu: usize) {
+        self.remember();
+
+        match self.last.take() {
+            Some((last, levels)) if last == menu => {
+                self.open = Some(menu);
+                self.levels = levels;
+            }
+            last => {
+                self.last = last;
+                self.open(menu, None);
+
+                return;
+            }
+        }
+
+        // The menus may have changed since
+        let open = open_menus(menus, self);
+        self.levels.truncate(open.len());
+
+        for (highlighted, items) in self.levels.iter_mut().zip(open) {
+            if highlighted.map_or(false, |index| index >= items.len()) {
+                *highlighted = None;
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        self.remember();
+
+        self.open = None;
+        self.levels.clear();
+    }
+
+    fn remember(&mut self) {
+        if let Some(open) = self.open {
+            self.last = Some((open, self.levels.clone()));
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        if let Some((memory, id)) = self.memory.take() {
+            self.remember();
+
+            if let Some(last) = self.last.take() {
+                memory.store(id.0, last);
+            }
+        }
+    }
+}
+
+/// Returns the items of every open menu, from the menu of the open title to
+/// the deepest submenu.
+fn open_menus<'a, Message>(
+    menus: &'a [Menu<Message>],
+    state: &State,
+) -> Vec<&'a [Item<Message>]> {
+    let mut items = match state.open.and_then(|open| menus.get(open)) {
+        Some(menu) => vec![menu.items.as_slice()],
+        None => return Vec::new(),
+    };
+
+    for highlighted in &state.levels[..state.levels.len().saturating_sub(1)] {
+        let submenu = highlighted
+            .and_then(|i| items.last()?.get(i))
+            .and_then(Item::submenu_items);
+
+        match submenu {
+            Some(submenu) => items.push(submenu),
+            None => break,
+        }
+    }
+
+    items
+}
+
+/// Returns the first item that can be highlighted, if any.
+fn first_selectable<Message>(items: &[Item<Message>]) -> Option<usize> {
+    items.iter().position(Item::is_selectable)
+}
+
+/// Returns the letter or digit typed with the given key, if any.
+fn letter(key_code: keyboard::KeyCode) -> Option<char> {
+    use keyboard::KeyCode;
+
+    let c = match key_code {
+        KeyCode::A => 'a',
+        KeyCode::B => 'b',
+        KeyCode::C => 'c',
+        KeyCode::D => 'd',
+        KeyCode::E => 'e',
+        KeyCode::F => 'f',
+        KeyCode::G => 'g',
+        KeyCode::H => 'h',
+        KeyCode::I => 'i',
+        KeyCode::J => 'j',
+        KeyCode::K => 'k',
+        KeyCode::L => 'l',
+        KeyCode::M => 'm',
+        KeyCode::N => 'n',
+        KeyCode::O => 'o',
+        KeyCode::P => 'p',
+        KeyCode::Q => 'q',
+        KeyCode::R => 'r',
+        KeyCode::S => 's',
+        KeyCode::T => 't',
+        KeyCode::U => 'u',
+        KeyCode::V => 'v',
+        KeyCode::W => 'w',
+        KeyCode::X => 'x',
+        KeyCode::Y => 'y',
+        KeyCode::Z => 'z',
+        KeyCode::Key0 => '0',
+        KeyCode::Key1 => '1',
+        KeyCode::Key2 => '2',
+        KeyCode::Key3 => '3',
+        KeyCode::Key4 => '4',
+        KeyCode::Key5 => '5',
+        KeyCode::Key6 => '6',
+        KeyCode::Key7 => '7',
+        KeyCode::Key8 => '8',
+        KeyCode::Key9 => '9',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// Draws a [`Label`] starting at the given point, vertically centered on
+/// it, underlining its mnemonic if requested.
+fn draw_label<Renderer>(
+    renderer: &mut Renderer,
+    label: &Label,
+    position: Point,
+    size: f32,
+    font: Renderer::Font,
+    color: Color,
+    show_mnemonic: bool,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_text(Text {
+        content: &label.text,
+        bounds: Rectangle {
+            x: position.x,
+            y: position.y,
+            width: f32::INFINITY,
+            height: size,
+        },
+        size,
+        color,
+        font: font.clone(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Center,
+    });
+
+    if let (true, Some(offset)) = (show_mnemonic, label.mnemonic) {
+        let length = label.text[offset..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+
+        let before =
+            renderer.measure_width(&label.text[..offset], size, font.clone());
+        let width = renderer.measure_width(
+            &label.text[offset..offset + length],
+            size,
+            font,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: position.x + before,
+                    y: position.y + size / 2.0 - 1.0,
+                    width,
+                    height: 1.0,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Background::Color(color),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_parses_mnemonics() {
+        let label = Label::parse("&File");
+        assert_eq!(label.text, "File");
+        assert_eq!(label.mnemonic(), Some('f'));
+
+        let label = Label::parse("Save &As");
+        assert_eq!(label.text, "Save As");
+        assert_eq!(label.mnemonic, Some(5));
+        assert_eq!(label.mnemonic(), Some('a'));
+
+        let label = Label::parse("Find && &Replace");
+        assert_eq!(label.text, "Find & Replace");
+        assert_eq!(label.mnemonic(), Some('r'));
+
+        let label = Label::parse("Trailing&");
+        assert_eq!(label.text, "Trailing");
+        assert_eq!(label.mnemonic(), None);
+    }
+}
@@ -0,0 +1,611 @@
+use super::{
+    draw_label, first_selectable, letter, open_menus, Item, Kind, MenuBar,
+    State, StyleSheet,
+};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay::menu;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{Clipboard, Color, Layout, Point, Rectangle, Shell, Size, Vector};
+
+/// The space above the first item and under the last item of a menu.
+const MENU_PADDING: f32 = 4.0;
+
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The space between a label and its shortcut or submenu arrow.
+const SHORTCUT_SPACING: f32 = 24.0;
+
+/// The open menus of a [`MenuBar`].
+pub(super) struct Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    bar: &'a MenuBar<Message, Renderer>,
+    state: &'a mut State,
+    // The bounds of the open title, relative to the bar
+    title: Rectangle,
+    bar_size: Size,
+    direction: layout::Direction,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(
+        bar: &'a MenuBar<Message, Renderer>,
+        state: &'a mut State,
+        title: Rectangle,
+        bar_size: Size,
+        direction: layout::Direction,
+    ) -> Self {
+        Self {
+            bar,
+            state,
+            title,
+            bar_size,
+            direction,
+        }
+    }
+
+    /// Lays out the items of a menu, returning its size.
+    fn layout_items(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> (Size, Vec<layout::Node>) {
+        let text_size = self.bar.label_size(renderer);
+        let padding = self.bar.item_padding;
+
+        let width = items
+            .iter()
+            .map(|item| {
+                let measure = |content: &str| {
+                    renderer.measure_width(
+                        content,
+                        text_size,
+                        self.bar.font.clone(),
+                    )
+                };
+
+                let label = measure(&item.label.text);
+                let shortcut =
+                    item.shortcut.as_deref().map_or(0.0, |shortcut| {
+                        SHORTCUT_SPACING + measure(shortcut)
+                    });
+                let arrow = match item.kind {
+                    Kind::Submenu(_) => SHORTCUT_SPACING + text_size,
+                    _ => 0.0,
+                };
+
+                padding.horizontal() + label + shortcut + arrow
+            })
+            .fold(self.bar.menu_width, f32::max);
+
+        let mut y = MENU_PADDING;
+
+        let nodes = items
+            .iter()
+            .map(|item| {
+                let height = match item.kind {
+                    Kind::Separator => SEPARATOR_HEIGHT,
+                    _ => text_size + padding.vertical(),
+                };
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        (Size::new(width, y + MENU_PADDING), nodes)
+    }
+
+    /// Returns the depth of the deepest menu under the given point and the
+    /// item of that menu under it, if any.
+    fn target_at(
+        &self,
+        layout: Layout<'_>,
+        point: Point,
+    ) -> Option<(usize, Option<usize>)> {
+        let levels: Vec<_> = layout.children().collect();
+
+        levels
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, level)| level.bounds().contains(point))
+            .map(|(depth, level)| {
+                let item = level
+                    .children()
+                    .position(|item| item.bounds().contains(point));
+
+                (depth, item)
+            })
+    }
+
+    /// Selects the item at the given depth and index, either producing its
+    /// message or opening its submenu.
+    fn activate(
+        &mut self,
+        items: &[Item<Message>],
+        depth: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match items.get(index).map(|item| &item.kind) {
+            Some(Kind::Action(Some(message))) => {
+                shell.publish(message.clone());
+                self.state.close();
+            }
+            Some(Kind::Submenu(submenu)) => {
+                self.state.levels.truncate(depth + 1);
+                self.state.levels[depth] = Some(index);
+                self.state.levels.push(first_selectable(submenu));
+            }
+            _ => return,
+        }
+
+        shell.invalidate_layout();
+    }
+
+    /// Opens the menu of the title next to the open one, in the given
+    /// direction.
+    fn switch(&mut self, forward: bool) {
+        let count = self.bar.menus.len();
+
+        if let Some(open) = self.state.open {
+            let next = if forward {
+                (open + 1) % count
+            } else {
+                (open + count - 1) % count
+            };
+
+            self.state
+                .open(next, first_selectable(&self.bar.menus[next].items));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let translation = position - Point::ORIGIN;
+        let is_rtl = self.direction == layout::Direction::RightToLeft;
+
+        let mut levels = Vec::new();
+        let mut parent: Option<(Rectangle, Rectangle)> = None;
+
+        for (depth, items) in open_menus(&self.bar.menus, self.state)
+            .into_iter()
+            .enumerate()
+        {
+            let (size, nodes) = self.layout_items(renderer, items);
+            let highlighted = self.state.levels.get(depth).copied().flatten();
+
+            let highlighted_offset = highlighted
+                .and_then(|i| nodes.get(i))
+                .map(|node| node.bounds());
+
+            let node = match parent {
+                None => menu::layout_attached(
+                    bounds,
+                    Point::new(
+                        // Right-to-left menus are aligned to the right edge
+                        // of their title
+                        if is_rtl {
+                            (position.x + self.title.x + self.title.width
+                                - size.width)
+                                .max(0.0)
+                        } else {
+                            position.x + self.title.x
+                        },
+                        position.y + self.title.y,
+                    ),
+                    self.title.height,
+                    |_| layout::Node::with_children(size, nodes),
+                ),
+                Some((parent, item)) => {
+                    // Submenus open next to their item, on the side with
+                    // enough space, preferring the end of the direction
+                    let fits_right =
+                        parent.x + parent.width + size.width <= bounds.width;
+                    let fits_left = parent.x - size.width >= 0.0;
+
+                    let x = if fits_right && !(is_rtl && fits_left) {
+                        parent.x + parent.width
+                    } else if is_rtl && !fits_left {
+                        (parent.x + parent.width)
+                            .min(bounds.width - size.width)
+                            .max(0.0)
+                    } else {
+                        (parent.x - size.width).max(0.0)
+                    };
+
+                    let y = (item.y - MENU_PADDING)
+                        .min(bounds.height - size.height)
+                        .max(0.0);
+
+                    let mut node = layout::Node::with_children(size, nodes);
+                    node.move_to(Point::new(x, y));
+
+                    node
+                }
+            };
+
+            let level = node.bounds();
+
+            parent = highlighted_offset.map(|item| {
+                (
+                    level,
+                    Rectangle {
+                        x: level.x + item.x,
+                        y: level.y + item.y,
+                        ..item
+                    },
+                )
+            });
+
+            levels.push(
+                node.translate(Vector::new(-translation.x, -translation.y)),
+            );
+
+            if parent.is_none() {
+                break;
+            }
+        }
+
+        let mut node = layout::Node::with_children(self.bar_size, levels);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bar = self.bar;
+        let menus = open_menus(&bar.menus, self.state);
+
+        if menus.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        // Levels without a menu are left behind by menus that changed
+        self.state.levels.resize(menus.len(), None);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((depth, Some(index))) =
+                    self.target_at(layout, cursor_position)
+                {
+                    if self.state.levels.get(depth) != Some(&Some(index)) {
+                        let item = &menus[depth][index];
+
+                        self.state.levels.truncate(depth + 1);
+                        self.state.levels[depth] =
+                            item.is_selectable().then_some(index);
+
+                        // Hovering a submenu item opens it
+                        if item.submenu_items().is_some() {
+                            self.state.levels.push(None);
+                        }
+
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.target_at(layout, cursor_position).is_some() {
+                    return event::Status::Captured;
+                }
+
+                // The bar toggles the menus by itself
+                if !layout.bounds().contains(cursor_position) {
+                    self.state.close();
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some((depth, Some(index))) =
+                    self.target_at(layout, cursor_position)
+                {
+                    self.activate(menus[depth], depth, index, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            // Mnemonics typed with Alt belong to the titles of the bar
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if !modifiers.alt() => {
+                let depth = menus.len() - 1;
+                let items = menus[depth];
+                let highlighted = self.state.levels[depth];
+
+                match key_code {
+                    keyboard::KeyCode::Escape => {
+                        if depth > 0 {
+                            let _ = self.state.levels.pop();
+                        } else {
+                            self.state.close();
+                        }
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        self.state.levels[depth] = step(
+                            items,
+                            highlighted,
+                            key_code == keyboard::KeyCode::Down,
+                        );
+                    }
+                    keyboard::KeyCode::Right => match highlighted {
+                        Some(index)
+                            if items[index].submenu_items().is_some() =>
+                        {
+                            self.activate(items, depth, index, shell);
+                        }
+                        _ => self.switch(true),
+                    },
+                    keyboard::KeyCode::Left => {
+                        if depth > 0 {
+                            let _ = self.state.levels.pop();
+                        } else {
+                            self.switch(false);
+                        }
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => {
+                        if let Some(index) = highlighted {
+                            self.activate(items, depth, index, shell);
+                        }
+                    }
+                    key_code => {
+                        let index = letter(key_code).and_then(|letter| {
+                            items.iter().position(|item| {
+                                item.is_selectable()
+                                    && item.label.mnemonic() == Some(letter)
+                            })
+                        });
+
+                        match index {
+                            Some(index) => {
+                                self.activate(items, depth, index, shell)
+                            }
+                            None => return event::Status::Ignored,
+                        }
+                    }
+                }
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let menus = open_menus(&self.bar.menus, self.state);
+
+        match self.target_at(layout, cursor_position) {
+            Some((depth, Some(index)))
+                if menus
+                    .get(depth)
+                    .and_then(|items| items.get(index))
+                    .map_or(false, Item::is_selectable) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(&self.bar.style);
+        let text_size = self.bar.label_size(renderer);
+        let padding = self.bar.item_padding;
+        let is_rtl = self.direction == layout::Direction::RightToLeft;
+
+        for ((depth, items), level) in open_menus(&self.bar.menus, self.state)
+            .into_iter()
+            .enumerate()
+            .zip(layout.children())
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: level.bounds(),
+                    border_radius: appearance.menu_border_radius.into(),
+                    border_width: appearance.menu_border_width,
+                    border_color: appearance.menu_border_color,
+                },
+                appearance.menu_background,
+            );
+
+            let highlighted = self.state.levels.get(depth).copied().flatten();
+
+            for ((index, item), layout) in
+                items.iter().enumerate().zip(level.children())
+            {
+                let bounds = layout.bounds();
+
+                if let Kind::Separator = item.kind {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + MENU_PADDING,
+                                y: bounds.center_y().floor(),
+                                width: bounds.width - MENU_PADDING * 2.0,
+                                height: 1.0,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.separator_color,
+                    );
+
+                    continue;
+                }
+
+                let (text_color, detail_color) = if highlighted == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.selected_background,
+                    );
+
+                    (
+                        appearance.selected_text_color,
+                        appearance.selected_text_color,
+                    )
+                } else if item.is_selectable() {
+                    (appearance.text_color, appearance.disabled_text_color)
+                } else {
+                    (
+                        appearance.disabled_text_color,
+                        appearance.disabled_text_color,
+                    )
+                };
+
+                // Labels and details swap sides in a right-to-left menu
+                let label_x = if is_rtl {
+                    bounds.x + bounds.width
+                        - padding.right
+                        - renderer.measure_width(
+                            &item.label.text,
+                            text_size,
+                            self.bar.font.clone(),
+                        )
+                } else {
+                    bounds.x + padding.left
+                };
+
+                draw_label(
+                    renderer,
+                    &item.label,
+                    Point::new(label_x, bounds.center_y()),
+                    text_size,
+                    self.bar.font.clone(),
+                    text_color,
+                    true,
+                );
+
+                let detail = match &item.kind {
+                    Kind::Submenu(_) if is_rtl => Some("◂"),
+                    Kind::Submenu(_) => Some("▸"),
+                    _ => item.shortcut.as_deref(),
+                };
+
+                if let Some(detail) = detail {
+                    let (x, horizontal_alignment) = if is_rtl {
+                        (bounds.x + padding.left, alignment::Horizontal::Left)
+                    } else {
+                        (
+                            bounds.x + bounds.width - padding.right,
+                            alignment::Horizontal::Right,
+                        )
+                    };
+
+                    renderer.fill_text(Text {
+                        content: detail,
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: text_size,
+                        color: detail_color,
+                        font: self.bar.font.clone(),
+                        horizontal_alignment,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+            }
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|level| level.bounds().contains(cursor_position))
+    }
+}
+
+/// Returns the selectable item after or before the highlighted one,
+/// wrapping around.
+fn step<Message>(
+    items: &[Item<Message>],
+    highlighted: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|offset| match (highlighted, forward) {
+            (Some(current), true) => (current + offset) % count,
+            (Some(current), false) => (current + count * 2 - offset) % count,
+            (None, true) => offset - 1,
+            (None, false) => count - offset,
+        })
+        .find(|&index| items[index].is_selectable())
+}
@@ -0,0 +1,588 @@
+//! Let your users type and step through numeric values.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use num_traits::{Bounded, Num};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A field that can be filled with a number, with a pair of buttons to
+/// decrement and increment its value.
+///
+/// The typed text is only turned into a message once it can be parsed into a
+/// number inside the bounds of the [`NumberInput`].
+///
+/// # Example
+/// ```
+/// # pub type NumberInput<'a, T, Message> = iced_native::widget::NumberInput<'a, T, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let quantity = 3;
+///
+/// let input = NumberInput::new(quantity, Message::QuantityChanged)
+///     .bounds(1..=10)
+///     .step(1);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    font: Renderer::Font,
+    width: Length,
+    padding: Padding,
+    size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Num + Bounded + PartialOrd + Copy + Display + FromStr,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    /// - the current value, and
+    /// - a function that produces a message when the value changes.
+    pub fn new<F>(value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        NumberInput {
+            value,
+            min: None,
+            max: None,
+            step: T::one(),
+            on_change: Box::new(on_change),
+            font: Default::default(),
+            width: Length::Fill,
+            padding: Padding::new(5.0),
+            size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets both the minimum and maximum values of the [`NumberInput`].
+    pub fn bounds(self, bounds: std::ops::RangeInclusive<T>) -> Self {
+        let (min, max) = bounds.into_inner();
+
+        self.min(min).max(max)
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    ///
+    /// By default, it is `1`. It should be positive.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    fn contains(&self, value: T) -> bool {
+        !matches!(self.min, Some(min) if value < min)
+            && !matches!(self.max, Some(max) if value > max)
+    }
+
+    fn can_decrement(&self) -> bool {
+        !matches!(self.min, Some(min) if self.value <= min)
+    }
+
+    fn can_increment(&self) -> bool {
+        !matches!(self.max, Some(max) if self.value >= max)
+    }
+
+    /// Returns the value one step below the current one, stopping at the
+    /// minimum instead of overflowing.
+    fn decremented(&self) -> T {
+        let lowest = T::min_value();
+
+        if self.step > T::zero() && self.value < lowest + self.step {
+            return self.min.unwrap_or(lowest);
+        }
+
+        self.clamp(self.value - self.step)
+    }
+
+    /// Returns the value one step above the current one, stopping at the
+    /// maximum instead of overflowing.
+    fn incremented(&self) -> T {
+        let highest = T::max_value();
+
+        if self.step > T::zero() && self.value > highest - self.step {
+            return self.max.unwrap_or(highest);
+        }
+
+        self.clamp(self.value + self.step)
+    }
+
+    fn step_by(
+        &self,
+        direction: Direction,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let value = match direction {
+            Direction::Decrement if self.can_decrement() => self.decremented(),
+            Direction::Increment if self.can_increment() => self.incremented(),
+            _ => return,
+        };
+
+        state.text = value.to_string();
+        state.input.move_cursor_to_end();
+
+        shell.publish((self.on_change)(value));
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Num + Bounded + PartialOrd + Copy + Display + FromStr,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.value.to_string()))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let side = text_size + self.padding.vertical();
+
+        let limits = limits.width(self.width).height(side);
+        let size = limits.resolve(Size::ZERO);
+        let buttons_width = (side * 2.0).min(size.width);
+
+        let input = text_input::layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(size.width - buttons_width, side),
+            )
+            .with_direction(limits.direction()),
+            Length::Fill,
+            self.padding,
+            self.size,
+            None,
+        );
+
+        let mut decrement = layout::Node::new(Size::new(side, side));
+        decrement.move_to(Point::new(input.size().width, 0.0));
+
+        let mut increment = layout::Node::new(Size::new(side, side));
+        increment.move_to(Point::new(input.size().width + side, 0.0));
+
+        layout::Node::with_children(
+            Size::new(input.size().width + buttons_width, side),
+            vec![input, decrement, increment],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        if !state.input.is_focused() {
+            state.text = self.value.to_string();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let direction = if decrement_layout
+                    .bounds()
+                    .contains(cursor_position)
+                {
+                    Some(Direction::Decrement)
+                } else if increment_layout.bounds().contains(cursor_position) {
+                    Some(Direction::Increment)
+                } else {
+                    None
+                };
+
+                if let Some(direction) = direction {
+                    self.step_by(direction, state, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    let direction = if y > 0.0 {
+                        Direction::Increment
+                    } else {
+                        Direction::Decrement
+                    };
+
+                    self.step_by(direction, state, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code:
+                    key_code @ (keyboard::KeyCode::Up | keyboard::KeyCode::Down),
+                ..
+            }) if state.input.is_focused() => {
+                let direction = if key_code == keyboard::KeyCode::Up {
+                    Direction::Increment
+                } else {
+                    Direction::Decrement
+                };
+
+                self.step_by(direction, state, shell);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let mut value = Value::new(&state.text);
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &|text| text,
+            None,
+            None,
+            &None,
+            || &mut state.input,
+        );
+
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if let Some(text) = edits.pop() {
+            if let Ok(value) = text.trim().parse::<T>() {
+                if self.contains(value) {
+                    shell.publish((self.on_change)(value));
+                }
+            }
+
+            state.text = text;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        let value = if state.input.is_focused() {
+            Value::new(&state.text)
+        } else {
+            Value::new(&self.value.to_string())
+        };
+
+        text_input::draw(
+            renderer,
+            theme,
+            input_layout,
+            cursor_position,
+            &state.input,
+            &value,
+            "",
+            self.size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &self.style,
+        );
+
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        for (layout, label, is_enabled) in [
+            (decrement_layout, "-", self.can_decrement()),
+            (increment_layout, "+", self.can_increment()),
+        ] {
+            let bounds = layout.bounds();
+
+            let appearance = if is_enabled && bounds.contains(cursor_position) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance.background,
+            );
+
+            renderer.fill_text(Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: if is_enabled {
+                    theme.value_color(&self.style)
+                } else {
+                    theme.placeholder_color(&self.style)
+                },
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        if (self.can_decrement()
+            && decrement_layout.bounds().contains(cursor_position))
+            || (self.can_increment()
+                && increment_layout.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            text_input::mouse_interaction(input_layout, cursor_position)
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Num + Bounded + PartialOrd + Copy + Display + FromStr,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone)]
+pub struct State {
+    input: text_input::State,
+    text: String,
+}
+
+impl State {
+    /// Creates a new [`State`] displaying the given text.
+    pub fn new(text: String) -> Self {
+        Self {
+            input: text_input::State::new(),
+            text,
+        }
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Decrement,
+    Increment,
+}
+
+#[cfg(test)]
+mod tests {
+    type NumberInput<T> =
+        super::NumberInput<'static, T, T, crate::renderer::Null>;
+
+    #[test]
+    fn steps_stop_at_the_limits_of_the_value() {
+        let input = NumberInput::new(0u32, |value| value);
+
+        assert_eq!(input.decremented(), 0);
+        assert_eq!(input.incremented(), 1);
+
+        let input = NumberInput::new(5u32, |value| value).min(3).step(10);
+
+        assert_eq!(input.decremented(), 3);
+        assert_eq!(input.incremented(), 15);
+
+        let input = NumberInput::new(i8::MAX - 1, |value| value).step(5);
+
+        assert_eq!(input.incremented(), i8::MAX);
+        assert_eq!(input.decremented(), i8::MAX - 6);
+
+        let input = NumberInput::new(0.5, |value| value).bounds(0.0..=1.0);
+
+        assert_eq!(input.incremented(), 1.0);
+        assert_eq!(input.decremented(), 0.0);
+    }
+}
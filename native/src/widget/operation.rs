@@ -8,6 +8,7 @@ pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
 use crate::widget::Id;
+use crate::{Rectangle, Vector};
 
 use std::any::Any;
 use std::fmt;
@@ -25,12 +26,40 @@ pub trait Operation<T> {
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
     );
 
+    /// Operates on a widget that contains other widgets, given its bounds.
+    ///
+    /// By default, it ignores the bounds and calls [`Operation::container`].
+    fn container_with_bounds(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.container(id, operate_on_children);
+    }
+
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(&mut self, _state: &mut dyn Scrollable, _id: Option<&Id>) {}
 
+    /// Operates on a widget that can be scrolled, given its bounds, the bounds
+    /// of its content, and its current scroll translation.
+    ///
+    /// By default, it ignores the viewport and calls
+    /// [`Operation::scrollable`].
+    fn scrollable_with_viewport(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.scrollable(state, id);
+    }
+
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
@@ -1,10 +1,30 @@
 //! Operate on widgets that can be scrolled.
+use crate::time::Duration;
+use crate::widget::operation::Outcome;
 use crate::widget::{Id, Operation};
+use crate::{Rectangle, Vector};
 
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
     /// Snaps the scroll of the widget to the given `percentage` along the horizontal & vertical axis.
     fn snap_to(&mut self, offset: RelativeOffset);
+
+    /// Scrolls the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis.
+    ///
+    /// By default, it does nothing.
+    fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        let _ = offset;
+    }
+
+    /// Scrolls the widget to the given [`AbsoluteOffset`] along the horizontal & vertical axis,
+    /// animating the change with the given [`Transition`].
+    ///
+    /// By default, it scrolls instantly.
+    fn animate_to(&mut self, offset: AbsoluteOffset, transition: Transition) {
+        let _ = transition;
+
+        self.scroll_to(offset);
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -34,6 +54,300 @@ pub fn snap_to<T>(target: Id, offset: RelativeOffset) -> impl Operation<T> {
     SnapTo { target, offset }
 }
 
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// the provided [`AbsoluteOffset`].
+pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
+    struct ScrollTo {
+        target: Id,
+        offset: AbsoluteOffset,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.scroll_to(self.offset);
+            }
+        }
+    }
+
+    ScrollTo { target, offset }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// the provided [`AbsoluteOffset`], animating the change with the given
+/// [`Transition`].
+pub fn animate_to<T>(
+    target: Id,
+    offset: AbsoluteOffset,
+    transition: Transition,
+) -> impl Operation<T> {
+    struct AnimateTo {
+        target: Id,
+        offset: AbsoluteOffset,
+        transition: Transition,
+    }
+
+    impl<T> Operation<T> for AnimateTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.animate_to(self.offset, self.transition);
+            }
+        }
+    }
+
+    AnimateTo {
+        target,
+        offset,
+        transition,
+    }
+}
+
+/// Produces an [`Operation`] that scrolls every scrollable widget containing
+/// the widget with the given [`Id`], so that it becomes visible.
+///
+/// Only widgets reporting their bounds through
+/// [`Operation::container_with_bounds`] can be found, like a `Container`, a
+/// `Scrollable` or a `TextInput` with an [`Id`]. Any other widget can be found
+/// by wrapping it in a `Container` with the [`Id`].
+pub fn ensure_visible<T>(target: Id) -> impl Operation<T> {
+    find_and_scroll(target, None)
+}
+
+/// Produces an [`Operation`] that scrolls every scrollable widget containing
+/// the widget with the given [`Id`], so that it becomes visible, animating
+/// the change with the given [`Transition`].
+pub fn ensure_visible_animated<T>(
+    target: Id,
+    transition: Transition,
+) -> impl Operation<T> {
+    find_and_scroll(target, Some(transition))
+}
+
+fn find_and_scroll<T>(
+    target: Id,
+    transition: Option<Transition>,
+) -> impl Operation<T> {
+    #[derive(Debug, Clone, Copy)]
+    struct Viewport {
+        index: usize,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    }
+
+    struct FindTarget {
+        target: Id,
+        scrollables: usize,
+        entering: Option<Viewport>,
+        ancestors: Vec<Viewport>,
+        found: Option<(Rectangle, Vec<Viewport>)>,
+        transition: Option<Transition>,
+    }
+
+    impl FindTarget {
+        fn enter<T>(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Option<Rectangle>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            // A scrollable widget operates on its content right after itself
+            let viewport = self.entering.take();
+
+            if self.found.is_some() {
+                return;
+            }
+
+            if let Some(bounds) = bounds.filter(|_| id == Some(&self.target)) {
+                self.found = Some((bounds, self.ancestors.clone()));
+                return;
+            }
+
+            self.ancestors.extend(viewport);
+            operate_on_children(self);
+
+            if viewport.is_some() {
+                let _ = self.ancestors.pop();
+            }
+        }
+    }
+
+    impl<T> Operation<T> for FindTarget {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.enter(id, None, operate_on_children);
+        }
+
+        fn container_with_bounds(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.enter(id, Some(bounds), operate_on_children);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+        ) {
+            // Scrollables without a viewport cannot be scrolled to the target,
+            // but they are still counted to find the others later
+            self.entering = None;
+            self.scrollables += 1;
+        }
+
+        fn scrollable_with_viewport(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.entering = Some(Viewport {
+                index: self.scrollables,
+                bounds,
+                content_bounds,
+                translation,
+            });
+
+            self.scrollables += 1;
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            let (mut target, ancestors) = match &self.found {
+                Some(found) => found.clone(),
+                None => return Outcome::None,
+            };
+
+            let mut offsets = Vec::with_capacity(ancestors.len());
+
+            // Scroll from the innermost scrollable outwards, keeping track of
+            // where the target ends up after each scroll
+            for viewport in ancestors.iter().rev() {
+                let offset = Vector::new(
+                    visible_offset(
+                        viewport.translation.x,
+                        target.x - viewport.content_bounds.x,
+                        target.width,
+                        viewport.bounds.width,
+                        viewport.content_bounds.width,
+                    ),
+                    visible_offset(
+                        viewport.translation.y,
+                        target.y - viewport.content_bounds.y,
+                        target.height,
+                        viewport.bounds.height,
+                        viewport.content_bounds.height,
+                    ),
+                );
+
+                offsets.push((viewport.index, offset));
+
+                target = Rectangle {
+                    x: target.x - offset.x,
+                    y: target.y - offset.y,
+                    ..target
+                };
+                target =
+                    target.intersection(&viewport.bounds).unwrap_or(target);
+            }
+
+            Outcome::Chain(Box::new(ScrollTo {
+                offsets,
+                scrollables: 0,
+                transition: self.transition,
+            }))
+        }
+    }
+
+    struct ScrollTo {
+        offsets: Vec<(usize, Vector)>,
+        scrollables: usize,
+        transition: Option<Transition>,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn scrollable(&mut self, state: &mut dyn Scrollable, _id: Option<&Id>) {
+            if let Some((_, offset)) = self
+                .offsets
+                .iter()
+                .find(|(index, _)| *index == self.scrollables)
+            {
+                let offset = AbsoluteOffset {
+                    x: offset.x,
+                    y: offset.y,
+                };
+
+                match self.transition {
+                    Some(transition) => state.animate_to(offset, transition),
+                    None => state.scroll_to(offset),
+                }
+            }
+
+            self.scrollables += 1;
+        }
+    }
+
+    FindTarget {
+        target,
+        scrollables: 0,
+        entering: None,
+        ancestors: Vec::new(),
+        found: None,
+        transition,
+    }
+}
+
+/// Returns the offset closest to `current` that fits the span starting at
+/// `start` with the given `length` inside of a `viewport`.
+fn visible_offset(
+    current: f32,
+    start: f32,
+    length: f32,
+    viewport: f32,
+    content: f32,
+) -> f32 {
+    let offset = if start < current {
+        start
+    } else if start + length > current + viewport {
+        (start + length - viewport).min(start)
+    } else {
+        current
+    };
+
+    offset.clamp(0.0, (content - viewport).max(0.0))
+}
+
 /// The amount of offset in each direction of a [`Scrollable`].
 ///
 /// A value of `0.0` means start, while `1.0` means end.
@@ -52,3 +366,72 @@ impl RelativeOffset {
     /// A relative offset that points to the bottom-right of a [`Scrollable`].
     pub const END: Self = Self { x: 1.0, y: 1.0 };
 }
+
+/// The amount of absolute offset in each direction of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AbsoluteOffset {
+    /// The amount of horizontal offset
+    pub x: f32,
+    /// The amount of vertical offset
+    pub y: f32,
+}
+
+/// The animation of a programmatic scroll of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    /// The duration of the animation.
+    pub duration: Duration,
+    /// The [`Easing`] of the animation.
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new [`Transition`] with the given duration and [`Easing`].
+    pub fn new(duration: Duration, easing: Easing) -> Self {
+        Self { duration, easing }
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(250),
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+/// The rate of change of an animated scroll over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// A constant rate of change.
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    EaseOut,
+    /// Starts slowly, accelerates, and decelerates at the end.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress of an animation, given its linear
+    /// `progress` between `0.0` and `1.0`.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t.powi(3),
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
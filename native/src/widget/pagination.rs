@@ -0,0 +1,639 @@
+//! Let your users move between the pages of some paginated content.
+//!
+//! A [`Pagination`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::pagination::{Appearance, StyleSheet};
+
+/// A row of buttons to jump to the first, previous, next and last pages, and
+/// to the pages around the current one.
+///
+/// Pages far from the current one collapse into an ellipsis. An optional
+/// [`go_to`] input lets the user type the number of a page.
+///
+/// The pages are identified by their index, starting at zero, and displayed
+/// starting at one.
+///
+/// [`go_to`]: Self::go_to
+///
+/// # Example
+/// ```
+/// # type Pagination<'a, Message> = iced_native::widget::Pagination<'a, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PageSelected(usize),
+/// }
+///
+/// let page = 4;
+///
+/// let pagination = Pagination::new(20, page, Message::PageSelected)
+///     .go_to("Page");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Pagination<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    page_count: usize,
+    current: usize,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    siblings: usize,
+    boundaries: usize,
+    go_to: Option<String>,
+    spacing: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Pagination<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The default [`Padding`] of the buttons of a [`Pagination`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 8.0,
+        bottom: 4.0,
+        left: 8.0,
+    };
+
+    /// Creates a new [`Pagination`] with the given amount of pages, the
+    /// index of the current page, and the message to produce when a page is
+    /// selected.
+    pub fn new(
+        page_count: usize,
+        current: usize,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Pagination {
+            page_count,
+            current: current.min(page_count.saturating_sub(1)),
+            on_select: Box::new(on_select),
+            siblings: 1,
+            boundaries: 1,
+            go_to: None,
+            spacing: 4.0,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the amount of pages displayed on each side of the current page
+    /// of the [`Pagination`].
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Sets the amount of pages always displayed at the start and at the end
+    /// of the [`Pagination`].
+    pub fn boundaries(mut self, boundaries: usize) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    /// Adds an input to the [`Pagination`] where the number of a page can be
+    /// typed, with the given placeholder.
+    ///
+    /// The page is selected when Enter is pressed.
+    pub fn go_to(mut self, placeholder: impl Into<String>) -> Self {
+        self.go_to = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the spacing between the buttons of the [`Pagination`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the buttons of the [`Pagination`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Pagination`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Pagination`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the buttons of the [`Pagination`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the input of the [`Pagination`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn controls(&self) -> Vec<Control> {
+        let pages = entries(
+            self.page_count,
+            self.current,
+            self.siblings,
+            self.boundaries,
+        );
+
+        [Control::First, Control::Previous]
+            .into_iter()
+            .chain(pages)
+            .chain([Control::Next, Control::Last])
+            .collect()
+    }
+
+    /// Returns the page the given [`Control`] leads to, unless it leads
+    /// nowhere.
+    fn target(&self, control: Control) -> Option<usize> {
+        let last = self.page_count.checked_sub(1)?;
+
+        let page = match control {
+            Control::First => 0,
+            Control::Previous => self.current.saturating_sub(1),
+            Control::Page(page) => page,
+            Control::Ellipsis => return None,
+            Control::Next => (self.current + 1).min(last),
+            Control::Last => last,
+        };
+
+        (page != self.current).then_some(page)
+    }
+
+    fn select(&self, page: usize, shell: &mut Shell<'_, Message>) {
+        if page != self.current && page < self.page_count {
+            shell.publish((self.on_select)(page));
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Pagination<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.label_size(renderer);
+        let height = text_size + self.padding.vertical();
+        let measure = |content: &str| {
+            renderer.measure_width(content, text_size, self.font.clone())
+        };
+
+        let mut x: f32 = 0.0;
+        let mut children = Vec::new();
+
+        for control in self.controls() {
+            let width = (measure(&control.label()) + self.padding.horizontal())
+                .max(height);
+
+            let mut node = layout::Node::new(Size::new(width, height));
+            node.move_to(Point::new(x, 0.0));
+
+            x += width + self.spacing;
+            children.push(node);
+        }
+
+        if let Some(placeholder) = &self.go_to {
+            let digits = "0".repeat(self.page_count.to_string().len().max(2));
+            let width = measure(placeholder).max(measure(&digits))
+                + self.padding.horizontal();
+
+            let mut input = text_input::layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(width, height))
+                    .with_direction(limits.direction()),
+                Length::Fixed(width),
+                self.padding,
+                Some(text_size),
+                None,
+            );
+            input.move_to(Point::new(x, 0.0));
+
+            x += width + self.spacing;
+            children.push(input);
+        }
+
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(Size::new((x - self.spacing).max(0.0), height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if self.go_to.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable(&mut state.input, None);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let target = self
+                .controls()
+                .into_iter()
+                .zip(layout.children())
+                .find(|(_, layout)| layout.bounds().contains(cursor_position))
+                .and_then(|(control, _)| self.target(control));
+
+            if let Some(page) = target {
+                self.select(page, shell);
+
+                return event::Status::Captured;
+            }
+        }
+
+        let input_layout = match (&self.go_to, layout.children().last()) {
+            (Some(_), Some(input_layout)) => input_layout,
+            _ => return event::Status::Ignored,
+        };
+
+        let is_digit: &dyn Fn(char) -> bool = &|c| c.is_ascii_digit();
+
+        let mut value = Value::new(&state.text);
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            self.text_size,
+            &self.font,
+            false,
+            &text_input::Options {
+                max_length: Some(self.page_count.to_string().len()),
+                filter: Some(is_digit),
+                ..text_input::Options::default()
+            },
+            &Edit::Changed,
+            None,
+            None,
+            &Some(Edit::Submitted),
+            || &mut state.input,
+        );
+
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for edit in edits {
+            match edit {
+                Edit::Changed(text) => state.text = text,
+                Edit::Submitted => {
+                    // The typed numbers start at one
+                    if let Some(page) = state
+                        .text
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|number| number.checked_sub(1))
+                    {
+                        self.select(page, shell);
+                    }
+
+                    state.text.clear();
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let controls = self.controls();
+        let count = controls.len();
+
+        if let Some(input_layout) = layout.children().nth(count) {
+            if input_layout.bounds().contains(cursor_position) {
+                return text_input::mouse_interaction(
+                    input_layout,
+                    cursor_position,
+                );
+            }
+        }
+
+        let is_over_target = controls.into_iter().zip(layout.children()).any(
+            |(control, layout)| {
+                layout.bounds().contains(cursor_position)
+                    && self.target(control).is_some()
+            },
+        );
+
+        if is_over_target {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text_size = self.label_size(renderer);
+
+        let controls = self.controls();
+        let count = controls.len();
+
+        for (control, layout) in controls.into_iter().zip(layout.children()) {
+            let bounds = layout.bounds();
+
+            let appearance = match (control, self.target(control)) {
+                (Control::Page(page), _) if page == self.current => {
+                    theme.selected(&self.style)
+                }
+                (Control::Ellipsis, _) => theme.active(&self.style),
+                (_, None) => theme.disabled(&self.style),
+                (_, Some(_)) if bounds.contains(cursor_position) => {
+                    theme.hovered(&self.style)
+                }
+                (_, Some(_)) => theme.active(&self.style),
+            };
+
+            if appearance.background.is_some() || appearance.border_width > 0.0
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: &control.label(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        if let (Some(placeholder), Some(input_layout)) =
+            (&self.go_to, layout.children().nth(count))
+        {
+            text_input::draw(
+                renderer,
+                theme,
+                input_layout,
+                cursor_position,
+                &state.input,
+                &Value::new(&state.text),
+                placeholder,
+                self.text_size,
+                &self.font,
+                false,
+                &text_input::Options::default(),
+                &self.input_style,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Pagination<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(pagination: Pagination<'a, Message, Renderer>) -> Self {
+        Element::new(pagination)
+    }
+}
+
+/// The local state of a [`Pagination`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    input: text_input::State,
+    text: String,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A button of a [`Pagination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    First,
+    Previous,
+    Page(usize),
+    Ellipsis,
+    Next,
+    Last,
+}
+
+impl Control {
+    fn label(self) -> String {
+        match self {
+            Control::First => String::from("«"),
+            Control::Previous => String::from("‹"),
+            Control::Page(page) => (page + 1).to_string(),
+            Control::Ellipsis => String::from("…"),
+            Control::Next => String::from("›"),
+            Control::Last => String::from("»"),
+        }
+    }
+}
+
+/// An edit of the input of a [`Pagination`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+/// Returns the pages to display, with ellipses in place of the collapsed
+/// ones.
+///
+/// The first and last `boundaries` pages are always displayed, as well as
+/// `siblings` pages on each side of the current one. An ellipsis replacing
+/// a single page would not save any room, so that page is displayed instead.
+fn entries(
+    count: usize,
+    current: usize,
+    siblings: usize,
+    boundaries: usize,
+) -> Vec<Control> {
+    // The computations are done with the displayed numbers, starting at one
+    let count = count as i64;
+    let page = current as i64 + 1;
+    let siblings = siblings as i64;
+    let boundaries = boundaries as i64;
+
+    let start_end = boundaries.min(count);
+    let end_start = (count - boundaries + 1).max(boundaries + 1);
+
+    let siblings_start = (page - siblings)
+        .min(count - boundaries - siblings * 2 - 1)
+        .max(boundaries + 2);
+    let siblings_end = (page + siblings)
+        .max(boundaries + siblings * 2 + 2)
+        .min(end_start - 2);
+
+    let mut pages: Vec<Option<i64>> = (1..=start_end).map(Some).collect();
+
+    if siblings_start > boundaries + 2 {
+        pages.push(None);
+    } else if boundaries + 1 < count - boundaries {
+        pages.push(Some(boundaries + 1));
+    }
+
+    pages.extend((siblings_start..=siblings_end).map(Some));
+
+    if siblings_end < count - boundaries - 1 {
+        pages.push(None);
+    } else if count - boundaries > boundaries {
+        pages.push(Some(count - boundaries));
+    }
+
+    pages.extend((end_start..=count).map(Some));
+
+    pages
+        .into_iter()
+        .map(|page| match page {
+            Some(page) => Control::Page(page as usize - 1),
+            None => Control::Ellipsis,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(controls: Vec<Control>) -> String {
+        controls
+            .into_iter()
+            .map(Control::label)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn entries_collapse_distant_pages() {
+        assert_eq!(labels(entries(5, 2, 1, 1)), "1 2 3 4 5");
+        assert_eq!(labels(entries(10, 0, 1, 1)), "1 2 3 4 5 … 10");
+        assert_eq!(labels(entries(10, 4, 1, 1)), "1 … 4 5 6 … 10");
+        assert_eq!(labels(entries(10, 9, 1, 1)), "1 … 6 7 8 9 10");
+        assert_eq!(labels(entries(100, 50, 1, 1)), "1 … 50 51 52 … 100");
+        assert_eq!(labels(entries(0, 0, 1, 1)), "");
+    }
+}
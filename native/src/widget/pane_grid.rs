@@ -15,6 +15,8 @@ mod draggable;
 mod node;
 mod pane;
 mod split;
+mod tab_bar;
+mod tab_group;
 mod title_bar;
 
 pub mod state;
@@ -28,24 +30,48 @@ pub use node::Node;
 pub use pane::Pane;
 pub use split::Split;
 pub use state::State;
+pub use tab_bar::TabBar;
+pub use tab_group::TabGroup;
 pub use title_bar::TitleBar;
 
-pub use iced_style::pane_grid::{Line, StyleSheet};
+pub use iced_style::pane_grid::{
+    Appearance, Easing, Line, StyleSheet, Transition,
+};
 
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::overlay::{self, Group};
 use crate::renderer;
+use crate::time::Instant;
 use crate::touch;
 use crate::widget;
 use crate::widget::container;
 use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
     Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
     Size, Vector, Widget,
 };
 
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+/// The distance a picked tab or pane needs to travel before it is dragged
+/// somewhere.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// The width of the grip along the right and bottom edges of a floating pane
+/// used to resize it.
+const FLOATING_GRIP: f32 = 6.0;
+
+/// The minimum width and height of a floating pane.
+const MIN_FLOATING_SIZE: f32 = 64.0;
+
+/// The fraction of a pane, along each of its edges, where a dropped or
+/// floating pane splits it instead of being swapped with it.
+const DOCK_RATIO: f32 = 0.25;
+
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
 ///
@@ -59,8 +85,11 @@ use crate::{
 ///
 /// * Vertical and horizontal splits
 /// * Tracking of the last active pane
-/// * Mouse-based resizing
-/// * Drag and drop to reorganize panes
+/// * Mouse-based resizing, with minimum pane sizes
+/// * Animated maximizing and restoring of panes
+/// * Drag and drop to reorganize panes, highlighting where they would land
+/// * Tabbed panes, with drag and drop of tabs between panes
+/// * Floating panes, detached from and docked back into the grid
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -108,6 +137,7 @@ where
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_float: Option<Box<dyn Fn(FloatEvent) -> Message + 'a>>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -132,12 +162,22 @@ where
                 pane,
                 view(pane, pane_state, true),
                 Node::Pane(pane),
+                &state.internal,
             )
         } else {
+            // Floating panes come last, so they are displayed on top
+            let tiled = state
+                .panes
+                .iter()
+                .filter(|(pane, _)| !state.is_floating(pane));
+
+            let floating = state
+                .floating()
+                .filter_map(|(pane, _)| state.panes.get_key_value(&pane));
+
             Contents::All(
-                state
-                    .panes
-                    .iter()
+                tiled
+                    .chain(floating)
                     .map(|(pane, pane_state)| {
                         (*pane, view(*pane, pane_state, false))
                     })
@@ -154,6 +194,7 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            on_float: None,
             style: Default::default(),
         }
     }
@@ -213,6 +254,21 @@ where
         self
     }
 
+    /// Enables the floating panes interactions of the [`PaneGrid`], which
+    /// will use the provided function to produce messages.
+    ///
+    /// A pane dragged and dropped anywhere but on another pane is detached
+    /// from the grid. Floating panes can be moved by their pick area, resized
+    /// from their right and bottom edges, and docked back by dropping them
+    /// along an edge of another pane.
+    pub fn on_float<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(FloatEvent) -> Message,
+    {
+        self.on_float = Some(Box::new(f));
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     pub fn style(
         mut self,
@@ -236,11 +292,11 @@ where
     Renderer::Theme: StyleSheet + container::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<state::Action>()
+        tree::Tag::of::<Memory>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(state::Action::Idle)
+        tree::State::new(Memory::new(self.contents.maximized()))
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -257,7 +313,7 @@ where
                 |state, (_, content)| content.diff(state),
                 |(_, content)| content.state(),
             ),
-            Contents::Maximized(_, content, _) => tree.diff_children_custom(
+            Contents::Maximized(_, content, ..) => tree.diff_children_custom(
                 &[content],
                 |state, content| content.diff(state),
                 |content| content.state(),
@@ -282,6 +338,7 @@ where
             renderer,
             limits,
             self.contents.layout(),
+            self.contents.floating(),
             self.width,
             self.height,
             self.spacing,
@@ -318,7 +375,15 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        let action = tree.state.downcast_mut::<state::Action>();
+        let memory = tree.state.downcast_mut::<Memory>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if memory.animate(self.contents.maximized(), now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let action = &mut memory.action;
 
         let on_drag = if self.drag_enabled() {
             &self.on_drag
@@ -329,6 +394,7 @@ where
         let event_status = update(
             action,
             self.contents.layout(),
+            self.contents.floating(),
             &event,
             layout,
             cursor_position,
@@ -338,9 +404,16 @@ where
             &self.on_click,
             on_drag,
             &self.on_resize,
+            &self.on_float,
         );
 
         let picked_pane = action.picked_pane().map(|(pane, _)| pane);
+        let hovered_floating = hovered_floating(
+            self.contents.floating(),
+            layout.bounds(),
+            cursor_position,
+        )
+        .map(|(pane, _)| pane);
 
         self.contents
             .iter_mut()
@@ -353,7 +426,7 @@ where
                     tree,
                     event.clone(),
                     layout,
-                    cursor_position,
+                    mask_cursor(cursor_position, pane, hovered_floating),
                     renderer,
                     clipboard,
                     shell,
@@ -372,23 +445,32 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         mouse_interaction(
-            tree.state.downcast_ref(),
+            &tree.state.downcast_ref::<Memory>().action,
             self.contents.layout(),
+            self.contents.floating(),
             layout,
             cursor_position,
             self.spacing,
             self.on_resize.as_ref().map(|(leeway, _)| *leeway),
+            self.on_float.is_some(),
         )
         .unwrap_or_else(|| {
+            let hovered_floating = hovered_floating(
+                self.contents.floating(),
+                layout.bounds(),
+                cursor_position,
+            )
+            .map(|(pane, _)| pane);
+
             self.contents
                 .iter()
                 .zip(&tree.children)
                 .zip(layout.children())
-                .map(|(((_pane, content), tree), layout)| {
+                .map(|(((pane, content), tree), layout)| {
                     content.mouse_interaction(
                         tree,
                         layout,
-                        cursor_position,
+                        mask_cursor(cursor_position, pane, hovered_floating),
                         viewport,
                         renderer,
                         self.drag_enabled(),
@@ -409,9 +491,36 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
+        let memory = tree.state.downcast_ref::<Memory>();
+        memory
+            .transition
+            .set(theme.maximize_transition(&self.style));
+
+        let bounds = layout.bounds();
+
+        // The maximized or restored pane grows from or shrinks into its
+        // region in the layout
+        let animated = memory.animation.and_then(|animation| {
+            let region = self.contents.region(
+                animation.pane,
+                self.spacing,
+                bounds.size(),
+            )? + Vector::new(bounds.x, bounds.y);
+
+            let (from, to) = if animation.is_maximizing {
+                (region, bounds)
+            } else {
+                (bounds, region)
+            };
+
+            Some((animation.pane, interpolate(from, to, animation.progress)))
+        });
+
         draw(
-            tree.state.downcast_ref(),
+            &memory.action,
             self.contents.layout(),
+            self.contents.floating(),
+            animated,
             layout,
             cursor_position,
             renderer,
@@ -441,7 +550,35 @@ where
                     rectangle,
                 );
             },
-        )
+        );
+
+        // Render dragged tab last, following the cursor
+        if let Some((pane, tab, origin)) =
+            memory.action.picked_tab().filter(|(_, _, origin)| {
+                origin.distance(cursor_position) >= DRAG_THRESHOLD
+            })
+        {
+            let dragged = self
+                .contents
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .find(|(((id, _), _), _)| *id == pane);
+
+            if let Some((((_, content), tree), layout)) = dragged {
+                renderer.with_layer(*viewport, |renderer| {
+                    renderer.with_translation(
+                        cursor_position - origin,
+                        |renderer| {
+                            content.draw_tab(
+                                tree, renderer, theme, style, layout, viewport,
+                                tab,
+                            );
+                        },
+                    );
+                });
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -483,6 +620,7 @@ pub fn layout<Renderer, T>(
     renderer: &Renderer,
     limits: &layout::Limits,
     node: &Node,
+    floating: &[(Pane, Rectangle)],
     width: Length,
     height: Length,
     spacing: f32,
@@ -495,13 +633,17 @@ pub fn layout<Renderer, T>(
     let regions = node.pane_regions(spacing, size);
     let children = contents
         .filter_map(|(pane, content)| {
-            let region = regions.get(&pane)?;
+            let region = regions.get(&pane).copied().or_else(|| {
+                floating_bounds(floating, pane)
+                    .map(|bounds| clamp_floating(bounds, size))
+            })?;
             let size = Size::new(region.width, region.height);
 
             let mut node = layout_content(
                 content,
                 renderer,
-                &layout::Limits::new(size, size),
+                &layout::Limits::new(size, size)
+                    .with_direction(limits.direction()),
             );
 
             node.move_to(Point::new(region.x, region.y));
@@ -518,6 +660,7 @@ pub fn layout<Renderer, T>(
 pub fn update<'a, Message, T: Draggable>(
     action: &mut state::Action,
     node: &Node,
+    floating: &[(Pane, Rectangle)],
     event: &Event,
     layout: Layout<'_>,
     cursor_position: Point,
@@ -527,9 +670,13 @@ pub fn update<'a, Message, T: Draggable>(
     on_click: &Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: &Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: &Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_float: &Option<Box<dyn Fn(FloatEvent) -> Message + 'a>>,
 ) -> event::Status {
     let mut event_status = event::Status::Ignored;
 
+    let is_floating =
+        |pane: &Pane| floating.iter().any(|(floating, _)| floating == pane);
+
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -538,6 +685,48 @@ pub fn update<'a, Message, T: Draggable>(
             if bounds.contains(cursor_position) {
                 event_status = event::Status::Captured;
 
+                // Floating panes are on top of everything else
+                if let Some((pane, region)) =
+                    hovered_floating(floating, bounds, cursor_position)
+                {
+                    if let Some(on_click) = on_click {
+                        shell.publish(on_click(pane));
+                    }
+
+                    if on_float.is_some() {
+                        let (horizontal, vertical) =
+                            floating_grip(region, cursor_position);
+
+                        if horizontal || vertical {
+                            *action = state::Action::ResizingFloating {
+                                pane,
+                                horizontal,
+                                vertical,
+                            };
+                        } else {
+                            let can_be_moved = contents
+                                .zip(layout.children())
+                                .find(|((id, _), _)| *id == pane)
+                                .map_or(false, |((_, content), layout)| {
+                                    content.can_be_dragged_at(
+                                        layout,
+                                        cursor_position,
+                                    )
+                                });
+
+                            if can_be_moved {
+                                *action = state::Action::Moving {
+                                    pane,
+                                    origin: cursor_position
+                                        - Vector::new(region.x, region.y),
+                                };
+                            }
+                        }
+                    }
+
+                    return event_status;
+                }
+
                 match on_resize {
                     Some((leeway, _)) => {
                         let relative_cursor = Point::new(
@@ -590,22 +779,105 @@ pub fn update<'a, Message, T: Draggable>(
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. })
         | Event::Touch(touch::Event::FingerLost { .. }) => {
-            if let Some((pane, _)) = action.picked_pane() {
+            if let Some((pane, origin)) = action.picked_pane() {
+                let mut target = None;
+                let mut picked = None;
+
+                for ((id, _), layout) in contents.zip(layout.children()) {
+                    let region = layout.bounds();
+
+                    if id == pane {
+                        picked = Some(region);
+                    }
+
+                    if target.is_none()
+                        && id != pane
+                        && !is_floating(&id)
+                        && region.contains(cursor_position)
+                    {
+                        target = Some(drop_target(id, region, cursor_position));
+                    }
+                }
+
                 if let Some(on_drag) = on_drag {
-                    let mut dropped_region = contents
+                    let event = match target {
+                        Some(target) => DragEvent::Dropped { pane, target },
+                        None => DragEvent::Canceled { pane },
+                    };
+
+                    shell.publish(on_drag(event));
+                }
+
+                // A pane dropped anywhere but on another pane is detached
+                if let (Some(on_float), Some(picked), None) =
+                    (on_float, picked, target)
+                {
+                    let picked_at =
+                        picked.position() + Vector::new(origin.x, origin.y);
+
+                    if picked_at.distance(cursor_position) >= DRAG_THRESHOLD {
+                        shell.publish(on_float(FloatEvent::Detached {
+                            pane,
+                            bounds: detached_bounds(
+                                picked,
+                                origin,
+                                layout.bounds(),
+                                cursor_position,
+                            ),
+                        }));
+                    }
+                }
+
+                *action = state::Action::Idle;
+
+                event_status = event::Status::Captured;
+            } else if let Some((pane, tab, origin)) = action.picked_tab() {
+                *action = state::Action::Idle;
+
+                // A tab that was barely moved was only selected
+                if origin.distance(cursor_position) >= DRAG_THRESHOLD {
+                    if let Some(on_drag) = on_drag {
+                        let target = contents
+                            .zip(layout.children())
+                            .filter(|(_, layout)| {
+                                layout.bounds().contains(cursor_position)
+                            })
+                            .last()
+                            .map(|((target, _), _)| target);
+
+                        if let Some(target) = target.filter(|t| *t != pane) {
+                            shell.publish(on_drag(DragEvent::TabDropped {
+                                pane,
+                                tab,
+                                target,
+                            }));
+                        }
+                    }
+
+                    event_status = event::Status::Captured;
+                }
+            } else if let Some(pane) = action.picked_floating() {
+                if let (state::Action::Moving { .. }, Some(on_float)) =
+                    (*action, on_float)
+                {
+                    let docked = contents
                         .zip(layout.children())
-                        .filter(|(_, layout)| {
+                        .filter(|((target, _), _)| !is_floating(target))
+                        .find(|(_, layout)| {
                             layout.bounds().contains(cursor_position)
+                        })
+                        .and_then(|((target, _), layout)| {
+                            dock_direction(layout.bounds(), cursor_position)
+                                .map(|direction| (target, direction))
                         });
 
-                    let event = match dropped_region.next() {
-                        Some(((target, _), _)) if pane != target => {
-                            DragEvent::Dropped { pane, target }
-                        }
-                        _ => DragEvent::Canceled { pane },
-                    };
-
-                    shell.publish(on_drag(event));
+                    if let Some((target, direction)) = docked {
+                        shell.publish(on_float(FloatEvent::Docked {
+                            pane,
+                            target,
+                            direction,
+                        }));
+                    }
                 }
 
                 *action = state::Action::Idle;
@@ -619,7 +891,50 @@ pub fn update<'a, Message, T: Draggable>(
         }
         Event::Mouse(mouse::Event::CursorMoved { .. })
         | Event::Touch(touch::Event::FingerMoved { .. }) => {
-            if let Some((_, on_resize)) = on_resize {
+            if let Some(pane) = action.picked_floating() {
+                let bounds = layout.bounds();
+
+                if let (Some(on_float), Some(current)) =
+                    (on_float, floating_bounds(floating, pane))
+                {
+                    let current = clamp_floating(current, bounds.size());
+                    let cursor =
+                        cursor_position - Vector::new(bounds.x, bounds.y);
+
+                    let moved = match *action {
+                        state::Action::Moving { origin, .. } => Rectangle {
+                            x: cursor.x - origin.x,
+                            y: cursor.y - origin.y,
+                            ..current
+                        },
+                        state::Action::ResizingFloating {
+                            horizontal,
+                            vertical,
+                            ..
+                        } => Rectangle {
+                            width: if horizontal {
+                                (cursor.x - current.x).max(MIN_FLOATING_SIZE)
+                            } else {
+                                current.width
+                            },
+                            height: if vertical {
+                                (cursor.y - current.y).max(MIN_FLOATING_SIZE)
+                            } else {
+                                current.height
+                            },
+                            ..current
+                        },
+                        _ => current,
+                    };
+
+                    shell.publish(on_float(FloatEvent::Moved {
+                        pane,
+                        bounds: clamp_floating(moved, bounds.size()),
+                    }));
+
+                    event_status = event::Status::Captured;
+                }
+            } else if let Some((_, on_resize)) = on_resize {
                 if let Some((split, _)) = action.picked_split() {
                     let bounds = layout.bounds();
 
@@ -629,18 +944,43 @@ pub fn update<'a, Message, T: Draggable>(
                     );
 
                     if let Some((axis, rectangle, _)) = splits.get(&split) {
+                        let min_sizes: BTreeMap<_, _> = contents
+                            .map(|(pane, content)| (pane, content.min_size()))
+                            .collect();
+
+                        let (min_a, min_b) = node
+                            .split_min_sizes(&split, spacing, &|pane| {
+                                min_sizes
+                                    .get(&pane)
+                                    .copied()
+                                    .unwrap_or(Size::ZERO)
+                            })
+                            .unwrap_or((Size::ZERO, Size::ZERO));
+
                         let ratio = match axis {
                             Axis::Horizontal => {
                                 let position =
                                     cursor_position.y - bounds.y - rectangle.y;
 
-                                (position / rectangle.height).clamp(0.1, 0.9)
+                                clamp_ratio(
+                                    position / rectangle.height,
+                                    rectangle.height,
+                                    spacing,
+                                    min_a.height,
+                                    min_b.height,
+                                )
                             }
                             Axis::Vertical => {
                                 let position =
                                     cursor_position.x - bounds.x - rectangle.x;
 
-                                (position / rectangle.width).clamp(0.1, 0.9)
+                                clamp_ratio(
+                                    position / rectangle.width,
+                                    rectangle.width,
+                                    spacing,
+                                    min_a.width,
+                                    min_b.width,
+                                )
                             }
                         };
 
@@ -668,15 +1008,28 @@ fn click_pane<'a, Message, T>(
 ) where
     T: Draggable,
 {
-    let mut clicked_region = contents
+    let clicked_region = contents
         .zip(layout.children())
-        .filter(|(_, layout)| layout.bounds().contains(cursor_position));
+        .filter(|(_, layout)| layout.bounds().contains(cursor_position))
+        .last();
 
-    if let Some(((pane, content), layout)) = clicked_region.next() {
+    if let Some(((pane, content), layout)) = clicked_region {
         if let Some(on_click) = &on_click {
             shell.publish(on_click(pane));
         }
 
+        if on_drag.is_some() {
+            if let Some(tab) = content.tab_at(layout, cursor_position) {
+                *action = state::Action::DraggingTab {
+                    pane,
+                    tab,
+                    origin: cursor_position,
+                };
+
+                return;
+            }
+        }
+
         if let Some(on_drag) = &on_drag {
             if content.can_be_dragged_at(layout, cursor_position) {
                 let pane_position = layout.position();
@@ -696,12 +1049,47 @@ fn click_pane<'a, Message, T>(
 pub fn mouse_interaction(
     action: &state::Action,
     node: &Node,
+    floating: &[(Pane, Rectangle)],
     layout: Layout<'_>,
     cursor_position: Point,
     spacing: f32,
     resize_leeway: Option<f32>,
+    floating_enabled: bool,
 ) -> Option<mouse::Interaction> {
-    if action.picked_pane().is_some() {
+    match *action {
+        state::Action::Moving { .. } => {
+            return Some(mouse::Interaction::Grabbing);
+        }
+        state::Action::ResizingFloating { horizontal, .. } => {
+            return Some(if horizontal {
+                mouse::Interaction::ResizingHorizontally
+            } else {
+                mouse::Interaction::ResizingVertically
+            });
+        }
+        _ => {}
+    }
+
+    if let Some((_, region)) =
+        hovered_floating(floating, layout.bounds(), cursor_position)
+    {
+        if !floating_enabled {
+            return None;
+        }
+
+        return match floating_grip(region, cursor_position) {
+            (true, _) => Some(mouse::Interaction::ResizingHorizontally),
+            (false, true) => Some(mouse::Interaction::ResizingVertically),
+            (false, false) => None,
+        };
+    }
+
+    let is_dragging_tab =
+        action.picked_tab().map_or(false, |(_, _, origin)| {
+            origin.distance(cursor_position) >= DRAG_THRESHOLD
+        });
+
+    if action.picked_pane().is_some() || is_dragging_tab {
         return Some(mouse::Interaction::Grabbing);
     }
 
@@ -736,6 +1124,8 @@ pub fn mouse_interaction(
 pub fn draw<Renderer, T>(
     action: &state::Action,
     node: &Node,
+    floating: &[(Pane, Rectangle)],
+    animated: Option<(Pane, Rectangle)>,
     layout: Layout<'_>,
     cursor_position: Point,
     renderer: &mut Renderer,
@@ -760,6 +1150,10 @@ pub fn draw<Renderer, T>(
 {
     let picked_pane = action.picked_pane();
 
+    let hovered_floating =
+        hovered_floating(floating, layout.bounds(), cursor_position)
+            .map(|(pane, _)| pane);
+
     let picked_split = action
         .picked_split()
         .and_then(|(split, axis)| {
@@ -774,7 +1168,7 @@ pub fn draw<Renderer, T>(
             Some((axis, region + Vector::new(bounds.x, bounds.y), true))
         })
         .or_else(|| match resize_leeway {
-            Some(leeway) => {
+            Some(leeway) if hovered_floating.is_none() => {
                 let bounds = layout.bounds();
 
                 let relative_cursor = Point::new(
@@ -792,7 +1186,7 @@ pub fn draw<Renderer, T>(
 
                 Some((axis, region + Vector::new(bounds.x, bounds.y), false))
             }
-            None => None,
+            _ => None,
         });
 
     let pane_cursor_position = if picked_pane.is_some() {
@@ -803,26 +1197,119 @@ pub fn draw<Renderer, T>(
         cursor_position
     };
 
+    let grid_bounds = layout.bounds();
+
+    // Floating panes can only be docked on the edges of other panes
+    let dropped = picked_pane.map(|(pane, _)| (pane, true)).or(match *action {
+        state::Action::Moving { pane, .. } => Some((pane, false)),
+        _ => None,
+    });
+
     let mut render_picked_pane = None;
+    let mut render_animated_pane = None;
+    let mut render_floating = Vec::new();
+    let mut drop_region = None;
 
     for ((id, pane), layout) in contents.zip(layout.children()) {
-        match picked_pane {
-            Some((dragging, origin)) if id == dragging => {
+        let is_floating = floating.iter().any(|(floating, _)| *floating == id);
+
+        if let Some((dropped, can_swap)) = dropped {
+            let region = layout.bounds();
+
+            if drop_region.is_none()
+                && id != dropped
+                && !is_floating
+                && region.contains(cursor_position)
+            {
+                drop_region = match drop_target(id, region, cursor_position) {
+                    DropTarget::Center(_) if !can_swap => None,
+                    target => Some(target.region(region)),
+                };
+            }
+        }
+
+        match (picked_pane, animated) {
+            (Some((dragging, origin)), _) if id == dragging => {
                 render_picked_pane = Some((pane, origin, layout));
             }
+            (_, Some((animated, region))) if id == animated => {
+                render_animated_pane = Some((pane, region, layout));
+            }
+            _ if is_floating => {
+                render_floating.push((id, pane, layout));
+            }
             _ => {
                 draw_pane(
                     pane,
                     renderer,
                     default_style,
                     layout,
-                    pane_cursor_position,
+                    mask_cursor(pane_cursor_position, id, hovered_floating),
                     viewport,
                 );
             }
         }
     }
 
+    if let Some(region) = drop_region {
+        let highlight = theme.hovered_region(style);
+
+        renderer.with_layer(region, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: region,
+                    border_radius: highlight.border_radius.into(),
+                    border_width: highlight.border_width,
+                    border_color: highlight.border_color,
+                },
+                highlight.background,
+            );
+        });
+    }
+
+    // Floating panes are drawn on top of the tiled ones, each in its own
+    // layer
+    for (id, pane, layout) in render_floating {
+        renderer.with_layer(grid_bounds, |renderer| {
+            draw_pane(
+                pane,
+                renderer,
+                default_style,
+                layout,
+                if hovered_floating == Some(id) {
+                    pane_cursor_position
+                } else {
+                    Point::new(-1.0, -1.0)
+                },
+                viewport,
+            );
+        });
+    }
+
+    // Render the maximized or restored pane on top of the others while it
+    // is animated
+    if let Some((pane, region, layout)) = render_animated_pane {
+        let bounds = layout.bounds();
+
+        renderer.with_layer(region, |renderer| {
+            renderer.with_translation(
+                Vector::new(region.x - bounds.x, region.y - bounds.y),
+                |renderer| {
+                    draw_pane(
+                        pane,
+                        renderer,
+                        default_style,
+                        layout,
+                        // TODO: Remove once cursor availability is encoded
+                        // in the type system
+                        Point::new(-1.0, -1.0),
+                        viewport,
+                    );
+                },
+            );
+        });
+    }
+
     // Render picked pane last
     if let Some((pane, origin, layout)) = render_picked_pane {
         let bounds = layout.bounds();
@@ -898,8 +1385,8 @@ pub enum DragEvent {
         /// The picked [`Pane`].
         pane: Pane,
 
-        /// The [`Pane`] where the picked one was dropped on.
-        target: Pane,
+        /// The [`DropTarget`] where the picked [`Pane`] was dropped on.
+        target: DropTarget,
     },
 
     /// A [`Pane`] was picked and then dropped outside of other [`Pane`]
@@ -908,6 +1395,105 @@ pub enum DragEvent {
         /// The picked [`Pane`].
         pane: Pane,
     },
+
+    /// A tab of a [`Pane`] was dropped on top of another [`Pane`].
+    TabDropped {
+        /// The [`Pane`] of the dragged tab.
+        pane: Pane,
+
+        /// The index of the dragged tab in its [`TabBar`].
+        tab: usize,
+
+        /// The [`Pane`] where the tab was dropped on.
+        target: Pane,
+    },
+}
+
+/// The place where a dragged [`Pane`] of a [`PaneGrid`] was dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropTarget {
+    /// The center of a [`Pane`], where both panes are swapped.
+    Center(Pane),
+
+    /// The edge of a [`Pane`], where it is split to place the dragged one on
+    /// the given side.
+    Edge(Pane, Direction),
+}
+
+impl DropTarget {
+    /// Returns the [`Pane`] where the dragged one was dropped.
+    pub fn pane(&self) -> Pane {
+        match self {
+            DropTarget::Center(pane) | DropTarget::Edge(pane, _) => *pane,
+        }
+    }
+
+    /// Returns the part of the region of the target [`Pane`] taken by the
+    /// dropped one.
+    fn region(&self, region: Rectangle) -> Rectangle {
+        match self {
+            DropTarget::Center(_) => region,
+            DropTarget::Edge(_, direction) => {
+                let half = Size::new(region.width / 2.0, region.height / 2.0);
+
+                match direction {
+                    Direction::Left => Rectangle {
+                        width: half.width,
+                        ..region
+                    },
+                    Direction::Right => Rectangle {
+                        x: region.x + half.width,
+                        width: half.width,
+                        ..region
+                    },
+                    Direction::Up => Rectangle {
+                        height: half.height,
+                        ..region
+                    },
+                    Direction::Down => Rectangle {
+                        y: region.y + half.height,
+                        height: half.height,
+                        ..region
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// An event produced when a [`Pane`] of a [`PaneGrid`] floats.
+#[derive(Debug, Clone, Copy)]
+pub enum FloatEvent {
+    /// A tiled [`Pane`] was dragged out of the grid to float on top of it.
+    Detached {
+        /// The detached [`Pane`].
+        pane: Pane,
+
+        /// The bounds of the [`Pane`], relative to the [`PaneGrid`].
+        bounds: Rectangle,
+    },
+
+    /// A floating [`Pane`] was moved or resized.
+    Moved {
+        /// The floating [`Pane`].
+        pane: Pane,
+
+        /// The new bounds of the [`Pane`], relative to the [`PaneGrid`].
+        bounds: Rectangle,
+    },
+
+    /// A floating [`Pane`] was dropped near the edge of a tiled [`Pane`] to
+    /// be docked next to it.
+    Docked {
+        /// The floating [`Pane`].
+        pane: Pane,
+
+        /// The [`Pane`] where the floating one was dropped on.
+        target: Pane,
+
+        /// The side of the target where the [`Pane`] should be docked.
+        direction: Direction,
+    },
 }
 
 /// An event produced during a resize interaction of a [`PaneGrid`].
@@ -944,13 +1530,236 @@ fn hovered_split<'a>(
         .next()
 }
 
+/// Clamps the ratio of a split of the given length, so the panes on each side
+/// of it are not smaller than their minimum length.
+///
+/// If both minimums cannot be satisfied, the split is kept in the middle of
+/// the allowed range.
+fn clamp_ratio(
+    ratio: f32,
+    length: f32,
+    spacing: f32,
+    min_a: f32,
+    min_b: f32,
+) -> f32 {
+    let lower = ((min_a + spacing / 2.0) / length).max(0.1);
+    let upper = (1.0 - (min_b + spacing / 2.0) / length).min(0.9);
+
+    if lower > upper {
+        (lower + upper) / 2.0
+    } else {
+        ratio.clamp(lower, upper)
+    }
+}
+
+fn floating_bounds(
+    floating: &[(Pane, Rectangle)],
+    pane: Pane,
+) -> Option<Rectangle> {
+    floating
+        .iter()
+        .find(|(floating, _)| *floating == pane)
+        .map(|(_, bounds)| *bounds)
+}
+
+/// Keeps the bounds of a floating pane inside of a [`PaneGrid`] of the given
+/// size.
+fn clamp_floating(bounds: Rectangle, size: Size) -> Rectangle {
+    let width = bounds.width.max(MIN_FLOATING_SIZE).min(size.width);
+    let height = bounds.height.max(MIN_FLOATING_SIZE).min(size.height);
+
+    Rectangle {
+        x: bounds.x.clamp(0.0, (size.width - width).max(0.0)),
+        y: bounds.y.clamp(0.0, (size.height - height).max(0.0)),
+        width,
+        height,
+    }
+}
+
+/// Returns the topmost floating pane under the cursor, along with its
+/// absolute bounds.
+fn hovered_floating(
+    floating: &[(Pane, Rectangle)],
+    grid_bounds: Rectangle,
+    cursor_position: Point,
+) -> Option<(Pane, Rectangle)> {
+    floating.iter().rev().find_map(|(pane, bounds)| {
+        let region = clamp_floating(*bounds, grid_bounds.size())
+            + Vector::new(grid_bounds.x, grid_bounds.y);
+
+        region.contains(cursor_position).then_some((*pane, region))
+    })
+}
+
+/// Returns whether the cursor is over the right and bottom resize grips of a
+/// floating pane.
+fn floating_grip(region: Rectangle, cursor_position: Point) -> (bool, bool) {
+    (
+        cursor_position.x >= region.x + region.width - FLOATING_GRIP,
+        cursor_position.y >= region.y + region.height - FLOATING_GRIP,
+    )
+}
+
+/// Returns the side of the region where a floating pane would be docked, if
+/// the cursor is close enough to one of its edges.
+fn dock_direction(
+    region: Rectangle,
+    cursor_position: Point,
+) -> Option<Direction> {
+    let left = (cursor_position.x - region.x) / region.width;
+    let top = (cursor_position.y - region.y) / region.height;
+
+    [
+        (Direction::Left, left),
+        (Direction::Right, 1.0 - left),
+        (Direction::Up, top),
+        (Direction::Down, 1.0 - top),
+    ]
+    .into_iter()
+    .filter(|(_, distance)| *distance <= DOCK_RATIO)
+    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    .map(|(direction, _)| direction)
+}
+
+/// Returns the [`DropTarget`] of a pane dropped on the given one, splitting it
+/// when close to one of its edges.
+fn drop_target(
+    pane: Pane,
+    region: Rectangle,
+    cursor_position: Point,
+) -> DropTarget {
+    match dock_direction(region, cursor_position) {
+        Some(direction) => DropTarget::Edge(pane, direction),
+        None => DropTarget::Center(pane),
+    }
+}
+
+/// Returns the bounds of a pane detached from the grid, shrunk to half its
+/// size around the point where it was picked.
+fn detached_bounds(
+    picked: Rectangle,
+    origin: Point,
+    grid_bounds: Rectangle,
+    cursor_position: Point,
+) -> Rectangle {
+    let position = cursor_position
+        - Vector::new(grid_bounds.x, grid_bounds.y)
+        - Vector::new(origin.x / 2.0, origin.y / 2.0);
+
+    clamp_floating(
+        Rectangle {
+            x: position.x,
+            y: position.y,
+            width: picked.width / 2.0,
+            height: picked.height / 2.0,
+        },
+        grid_bounds.size(),
+    )
+}
+
+/// Hides the cursor from every pane but the floating one under it, if any.
+fn mask_cursor(
+    cursor_position: Point,
+    pane: Pane,
+    hovered_floating: Option<Pane>,
+) -> Point {
+    match hovered_floating {
+        Some(hovered) if hovered != pane => {
+            // TODO: Remove once cursor availability is encoded in the type
+            // system
+            Point::new(-1.0, -1.0)
+        }
+        _ => cursor_position,
+    }
+}
+
+/// The local state of a [`PaneGrid`].
+#[derive(Debug)]
+struct Memory {
+    action: state::Action,
+    maximized: Option<Pane>,
+    animation: Option<Animation>,
+    // The transition is only known by the theme, which is not available
+    // outside of `draw`
+    transition: Cell<Transition>,
+}
+
+/// The animation of a pane being maximized or restored.
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    pane: Pane,
+    is_maximizing: bool,
+    started_at: Instant,
+    progress: f32,
+}
+
+impl Memory {
+    fn new(maximized: Option<Pane>) -> Self {
+        Self {
+            action: state::Action::Idle,
+            maximized,
+            animation: None,
+            transition: Cell::new(Transition::default()),
+        }
+    }
+
+    /// Advances the animation of the maximized pane, starting one if it
+    /// changed.
+    ///
+    /// It returns whether the animation needs another frame.
+    fn animate(&mut self, maximized: Option<Pane>, now: Instant) -> bool {
+        if self.maximized != maximized {
+            self.animation =
+                maximized.or(self.maximized).map(|pane| Animation {
+                    pane,
+                    is_maximizing: maximized.is_some(),
+                    started_at: now,
+                    progress: 0.0,
+                });
+
+            self.maximized = maximized;
+        }
+
+        if let Some(animation) = &mut self.animation {
+            let transition = self.transition.get();
+
+            let progress = if transition.duration.is_zero() {
+                1.0
+            } else {
+                ((now - animation.started_at).as_secs_f32()
+                    / transition.duration.as_secs_f32())
+                .min(1.0)
+            };
+
+            animation.progress = transition.easing.apply(progress);
+
+            if progress < 1.0 {
+                return true;
+            }
+
+            self.animation = None;
+        }
+
+        false
+    }
+}
+
+fn interpolate(from: Rectangle, to: Rectangle, t: f32) -> Rectangle {
+    Rectangle {
+        x: from.x + (to.x - from.x) * t,
+        y: from.y + (to.y - from.y) * t,
+        width: from.width + (to.width - from.width) * t,
+        height: from.height + (to.height - from.height) * t,
+    }
+}
+
 /// The visible contents of the [`PaneGrid`]
 #[derive(Debug)]
 pub enum Contents<'a, T> {
     /// All panes are visible
     All(Vec<(Pane, T)>, &'a state::Internal),
     /// A maximized pane is visible
-    Maximized(Pane, T, Node),
+    Maximized(Pane, T, Node, &'a state::Internal),
 }
 
 impl<'a, T> Contents<'a, T> {
@@ -958,7 +1767,15 @@ impl<'a, T> Contents<'a, T> {
     pub fn layout(&self) -> &Node {
         match self {
             Contents::All(_, state) => state.layout(),
-            Contents::Maximized(_, _, layout) => layout,
+            Contents::Maximized(_, _, layout, _) => layout,
+        }
+    }
+
+    /// Returns the floating panes of the [`Contents`] with their bounds
+    pub fn floating(&self) -> &[(Pane, Rectangle)] {
+        match self {
+            Contents::All(_, state) => state.floating(),
+            Contents::Maximized(..) => &[],
         }
     }
 
@@ -968,7 +1785,7 @@ impl<'a, T> Contents<'a, T> {
             Contents::All(contents, _) => Box::new(
                 contents.iter().map(|(pane, content)| (*pane, content)),
             ),
-            Contents::Maximized(pane, content, _) => {
+            Contents::Maximized(pane, content, ..) => {
                 Box::new(std::iter::once((*pane, content)))
             }
         }
@@ -979,7 +1796,7 @@ impl<'a, T> Contents<'a, T> {
             Contents::All(contents, _) => Box::new(
                 contents.iter_mut().map(|(pane, content)| (*pane, content)),
             ),
-            Contents::Maximized(pane, content, _) => {
+            Contents::Maximized(pane, content, ..) => {
                 Box::new(std::iter::once((*pane, content)))
             }
         }
@@ -988,4 +1805,76 @@ impl<'a, T> Contents<'a, T> {
     fn is_maximized(&self) -> bool {
         matches!(self, Self::Maximized(..))
     }
+
+    fn maximized(&self) -> Option<Pane> {
+        match self {
+            Contents::All(..) => None,
+            Contents::Maximized(pane, ..) => Some(*pane),
+        }
+    }
+
+    /// Returns the region of the given [`Pane`] when no pane is maximized.
+    fn region(
+        &self,
+        pane: Pane,
+        spacing: f32,
+        size: Size,
+    ) -> Option<Rectangle> {
+        let internal = match self {
+            Contents::All(_, internal) => internal,
+            Contents::Maximized(_, _, _, internal) => internal,
+        };
+
+        internal
+            .layout()
+            .pane_regions(spacing, size)
+            .get(&pane)
+            .copied()
+            .or_else(|| {
+                floating_bounds(internal.floating(), pane)
+                    .map(|bounds| clamp_floating(bounds, size))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floating_panes_dock_next_to_the_nearest_edge() {
+        let region =
+            Rectangle::new(Point::new(100.0, 100.0), Size::new(200.0, 100.0));
+
+        assert_eq!(
+            dock_direction(region, Point::new(110.0, 150.0)),
+            Some(Direction::Left)
+        );
+        assert_eq!(
+            dock_direction(region, Point::new(200.0, 195.0)),
+            Some(Direction::Down)
+        );
+        assert_eq!(dock_direction(region, Point::new(200.0, 150.0)), None);
+
+        assert_eq!(
+            clamp_floating(
+                Rectangle::new(Point::new(-10.0, 90.0), Size::new(20.0, 50.0)),
+                Size::new(100.0, 100.0),
+            ),
+            Rectangle::new(Point::new(0.0, 36.0), Size::new(64.0, 64.0))
+        );
+    }
+
+    #[test]
+    fn splits_keep_panes_above_their_minimum_size() {
+        // Without minimums, splits stay between 10% and 90%
+        assert_eq!(clamp_ratio(0.05, 1000.0, 0.0, 0.0, 0.0), 0.1);
+        assert_eq!(clamp_ratio(0.5, 1000.0, 0.0, 0.0, 0.0), 0.5);
+
+        assert_eq!(clamp_ratio(0.2, 1000.0, 10.0, 295.0, 0.0), 0.3);
+        assert_eq!(clamp_ratio(0.8, 1000.0, 10.0, 0.0, 395.0), 0.6);
+
+        // Conflicting minimums keep the split in the middle
+        assert_eq!(clamp_ratio(0.2, 1000.0, 0.0, 700.0, 500.0), 0.6);
+    }
 }
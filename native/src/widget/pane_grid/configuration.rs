@@ -2,8 +2,17 @@ use crate::widget::pane_grid::Axis;
 
 /// The arrangement of a [`PaneGrid`].
 ///
+/// It can be obtained from an existing [`State`] with [`State::to_layout`]
+/// and turned back into one with [`State::from_layout`], which allows
+/// persisting the layout of a [`PaneGrid`] when the `serde` feature is
+/// enabled.
+///
 /// [`PaneGrid`]: crate::widget::PaneGrid
-#[derive(Debug, Clone)]
+/// [`State`]: crate::widget::pane_grid::State
+/// [`State::to_layout`]: crate::widget::pane_grid::State::to_layout
+/// [`State::from_layout`]: crate::widget::pane_grid::State::from_layout
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -24,3 +33,27 @@ pub enum Configuration<T> {
     /// [`Pane`]: crate::widget::pane_grid::Pane
     Pane(T),
 }
+
+impl<T> Configuration<T> {
+    /// Applies a transformation to the state of every [`Pane`] of the
+    /// [`Configuration`], keeping its splits and ratios.
+    ///
+    /// [`Pane`]: crate::widget::pane_grid::Pane
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Configuration<U> {
+        self.map_with(&mut f)
+    }
+
+    fn map_with<U>(self, f: &mut impl FnMut(T) -> U) -> Configuration<U> {
+        match self {
+            Configuration::Split { axis, ratio, a, b } => {
+                Configuration::Split {
+                    axis,
+                    ratio,
+                    a: Box::new(a.map_with(f)),
+                    b: Box::new(b.map_with(f)),
+                }
+            }
+            Configuration::Pane(state) => Configuration::Pane(f(state)),
+        }
+    }
+}
@@ -4,9 +4,11 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
-use crate::widget::pane_grid::{Draggable, TitleBar};
+use crate::widget::pane_grid::{Draggable, TabBar, TitleBar};
 use crate::widget::{self, Tree};
-use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
+use crate::{
+    Clipboard, Element, Layout, Pixels, Point, Rectangle, Shell, Size,
+};
 
 /// The content of a [`Pane`].
 ///
@@ -18,7 +20,9 @@ where
     Renderer::Theme: container::StyleSheet,
 {
     title_bar: Option<TitleBar<'a, Message, Renderer>>,
+    tab_bar: Option<TabBar<'a, Message, Renderer>>,
     body: Element<'a, Message, Renderer>,
+    min_size: Size,
     style: <Renderer::Theme as container::StyleSheet>::Style,
 }
 
@@ -31,7 +35,9 @@ where
     pub fn new(body: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Self {
             title_bar: None,
+            tab_bar: None,
             body: body.into(),
+            min_size: Size::ZERO,
             style: Default::default(),
         }
     }
@@ -45,6 +51,36 @@ where
         self
     }
 
+    /// Sets the [`TabBar`] of this [`Content`].
+    ///
+    /// It is placed under the [`TitleBar`], if any.
+    pub fn tab_bar(mut self, tab_bar: TabBar<'a, Message, Renderer>) -> Self {
+        self.tab_bar = Some(tab_bar);
+        self
+    }
+
+    /// Sets the minimum width of the [`Content`].
+    ///
+    /// The splits of a [`PaneGrid`] cannot be dragged past the point where
+    /// the [`Content`] would be narrower.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn min_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_size.width = width.into().0;
+        self
+    }
+
+    /// Sets the minimum height of the [`Content`].
+    ///
+    /// The splits of a [`PaneGrid`] cannot be dragged past the point where
+    /// the [`Content`] would be shorter.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn min_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.min_size.height = height.into().0;
+        self
+    }
+
     /// Sets the style of the [`Content`].
     pub fn style(
         mut self,
@@ -61,30 +97,56 @@ where
     Renderer::Theme: container::StyleSheet,
 {
     pub(super) fn state(&self) -> Tree {
-        let children = if let Some(title_bar) = self.title_bar.as_ref() {
-            vec![Tree::new(&self.body), title_bar.state()]
-        } else {
-            vec![Tree::new(&self.body), Tree::empty()]
-        };
+        let title_bar = self
+            .title_bar
+            .as_ref()
+            .map_or_else(Tree::empty, TitleBar::state);
+
+        let tab_bar = self
+            .tab_bar
+            .as_ref()
+            .map_or_else(Tree::empty, TabBar::state);
 
         Tree {
-            children,
+            children: vec![Tree::new(&self.body), title_bar, tab_bar],
             ..Tree::empty()
         }
     }
 
     pub(super) fn diff(&self, tree: &mut Tree) {
-        if tree.children.len() == 2 {
+        if tree.children.len() == 3 {
             if let Some(title_bar) = self.title_bar.as_ref() {
                 title_bar.diff(&mut tree.children[1]);
             }
 
+            if let Some(tab_bar) = self.tab_bar.as_ref() {
+                tab_bar.diff(&mut tree.children[2]);
+            }
+
             tree.children[0].diff(&self.body);
         } else {
             *tree = self.state();
         }
     }
 
+    /// Returns the layouts of the [`TitleBar`], the [`TabBar`] and the body
+    /// of the [`Content`].
+    fn layouts<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> (Option<Layout<'b>>, Option<Layout<'b>>, Layout<'b>) {
+        if self.title_bar.is_none() && self.tab_bar.is_none() {
+            return (None, None, layout);
+        }
+
+        let mut children = layout.children();
+
+        let title_bar = self.title_bar.as_ref().and_then(|_| children.next());
+        let tab_bar = self.tab_bar.as_ref().and_then(|_| children.next());
+
+        (title_bar, tab_bar, children.next().unwrap())
+    }
+
     /// Draws the [`Content`] with the provided [`Renderer`] and [`Layout`].
     ///
     /// [`Renderer`]: crate::Renderer
@@ -108,42 +170,77 @@ where
             container::draw_background(renderer, &style, bounds);
         }
 
-        if let Some(title_bar) = &self.title_bar {
-            let mut children = layout.children();
-            let title_bar_layout = children.next().unwrap();
-            let body_layout = children.next().unwrap();
+        let (title_bar_layout, tab_bar_layout, body_layout) =
+            self.layouts(layout);
+
+        self.body.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            body_layout,
+            cursor_position,
+            viewport,
+        );
 
+        if let (Some(title_bar), Some(title_bar_layout)) =
+            (&self.title_bar, title_bar_layout)
+        {
             let show_controls = bounds.contains(cursor_position);
 
-            self.body.as_widget().draw(
-                &tree.children[0],
+            title_bar.draw(
+                &tree.children[1],
                 renderer,
                 theme,
                 style,
-                body_layout,
+                title_bar_layout,
                 cursor_position,
                 viewport,
+                show_controls,
             );
+        }
 
-            title_bar.draw(
-                &tree.children[1],
+        if let (Some(tab_bar), Some(tab_bar_layout)) =
+            (&self.tab_bar, tab_bar_layout)
+        {
+            tab_bar.draw(
+                &tree.children[2],
                 renderer,
                 theme,
                 style,
-                title_bar_layout,
+                tab_bar_layout,
                 cursor_position,
                 viewport,
-                show_controls,
             );
-        } else {
-            self.body.as_widget().draw(
-                &tree.children[0],
+        }
+    }
+
+    /// Draws the tab with the given index of the [`TabBar`] of the
+    /// [`Content`], if any.
+    pub(super) fn draw_tab(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+        index: usize,
+    ) {
+        if let (Some(tab_bar), (_, Some(tab_bar_layout), _)) =
+            (&self.tab_bar, self.layouts(layout))
+        {
+            tab_bar.draw_tab(
+                &tree.children[2],
                 renderer,
                 theme,
                 style,
-                layout,
-                cursor_position,
+                tab_bar_layout,
+                // TODO: Remove once cursor availability is encoded in the type
+                // system
+                Point::new(-1.0, -1.0),
                 viewport,
+                index,
             );
         }
     }
@@ -153,34 +250,45 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        if let Some(title_bar) = &self.title_bar {
-            let max_size = limits.max();
+        if self.title_bar.is_none() && self.tab_bar.is_none() {
+            return self.body.as_widget().layout(renderer, limits);
+        }
 
-            let title_bar_layout = title_bar
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+        let max_size = limits.max();
+        let bar_limits = layout::Limits::new(Size::ZERO, max_size)
+            .with_direction(limits.direction());
 
-            let title_bar_size = title_bar_layout.size();
+        let mut height = 0.0;
+        let mut children = Vec::new();
 
-            let mut body_layout = self.body.as_widget().layout(
-                renderer,
-                &layout::Limits::new(
-                    Size::ZERO,
-                    Size::new(
-                        max_size.width,
-                        max_size.height - title_bar_size.height,
-                    ),
-                ),
-            );
+        if let Some(title_bar) = &self.title_bar {
+            let title_bar_layout = title_bar.layout(renderer, &bar_limits);
 
-            body_layout.move_to(Point::new(0.0, title_bar_size.height));
+            height += title_bar_layout.size().height;
+            children.push(title_bar_layout);
+        }
 
-            layout::Node::with_children(
-                max_size,
-                vec![title_bar_layout, body_layout],
-            )
-        } else {
-            self.body.as_widget().layout(renderer, limits)
+        if let Some(tab_bar) = &self.tab_bar {
+            let mut tab_bar_layout = tab_bar.layout(renderer, &bar_limits);
+            tab_bar_layout.move_to(Point::new(0.0, height));
+
+            height += tab_bar_layout.size().height;
+            children.push(tab_bar_layout);
         }
+
+        let mut body_layout = self.body.as_widget().layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(max_size.width, (max_size.height - height).max(0.0)),
+            )
+            .with_direction(limits.direction()),
+        );
+
+        body_layout.move_to(Point::new(0.0, height));
+        children.push(body_layout);
+
+        layout::Node::with_children(max_size, children)
     }
 
     pub(crate) fn operate(
@@ -190,20 +298,30 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<Message>,
     ) {
-        let body_layout = if let Some(title_bar) = &self.title_bar {
-            let mut children = layout.children();
+        let (title_bar_layout, tab_bar_layout, body_layout) =
+            self.layouts(layout);
 
+        if let (Some(title_bar), Some(title_bar_layout)) =
+            (&self.title_bar, title_bar_layout)
+        {
             title_bar.operate(
                 &mut tree.children[1],
-                children.next().unwrap(),
+                title_bar_layout,
                 renderer,
                 operation,
             );
+        }
 
-            children.next().unwrap()
-        } else {
-            layout
-        };
+        if let (Some(tab_bar), Some(tab_bar_layout)) =
+            (&self.tab_bar, tab_bar_layout)
+        {
+            tab_bar.operate(
+                &mut tree.children[2],
+                tab_bar_layout,
+                renderer,
+                operation,
+            );
+        }
 
         self.body.as_widget().operate(
             &mut tree.children[0],
@@ -226,23 +344,36 @@ where
     ) -> event::Status {
         let mut event_status = event::Status::Ignored;
 
-        let body_layout = if let Some(title_bar) = &mut self.title_bar {
-            let mut children = layout.children();
+        let (title_bar_layout, tab_bar_layout, body_layout) =
+            self.layouts(layout);
 
+        if let (Some(title_bar), Some(title_bar_layout)) =
+            (&mut self.title_bar, title_bar_layout)
+        {
             event_status = title_bar.on_event(
                 &mut tree.children[1],
                 event.clone(),
-                children.next().unwrap(),
+                title_bar_layout,
                 cursor_position,
                 renderer,
                 clipboard,
                 shell,
             );
+        }
 
-            children.next().unwrap()
-        } else {
-            layout
-        };
+        if let (Some(tab_bar), Some(tab_bar_layout)) =
+            (&mut self.tab_bar, tab_bar_layout)
+        {
+            event_status = event_status.merge(tab_bar.on_event(
+                &mut tree.children[2],
+                event.clone(),
+                tab_bar_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ));
+        }
 
         let body_status = if is_picked {
             event::Status::Ignored
@@ -270,30 +401,35 @@ where
         renderer: &Renderer,
         drag_enabled: bool,
     ) -> mouse::Interaction {
-        let (body_layout, title_bar_interaction) =
-            if let Some(title_bar) = &self.title_bar {
-                let mut children = layout.children();
-                let title_bar_layout = children.next().unwrap();
-
-                let is_over_pick_area = title_bar
-                    .is_over_pick_area(title_bar_layout, cursor_position);
+        if drag_enabled && self.can_be_dragged_at(layout, cursor_position) {
+            return mouse::Interaction::Grab;
+        }
 
-                if is_over_pick_area && drag_enabled {
-                    return mouse::Interaction::Grab;
-                }
+        let (title_bar_layout, tab_bar_layout, body_layout) =
+            self.layouts(layout);
 
-                let mouse_interaction = title_bar.mouse_interaction(
+        let title_bar_interaction = match (&self.title_bar, title_bar_layout) {
+            (Some(title_bar), Some(title_bar_layout)) => title_bar
+                .mouse_interaction(
                     &tree.children[1],
                     title_bar_layout,
                     cursor_position,
                     viewport,
                     renderer,
-                );
+                ),
+            _ => mouse::Interaction::default(),
+        };
 
-                (children.next().unwrap(), mouse_interaction)
-            } else {
-                (layout, mouse::Interaction::default())
-            };
+        let tab_bar_interaction = match (&self.tab_bar, tab_bar_layout) {
+            (Some(tab_bar), Some(tab_bar_layout)) => tab_bar.mouse_interaction(
+                &tree.children[2],
+                tab_bar_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        };
 
         self.body
             .as_widget()
@@ -305,6 +441,7 @@ where
                 renderer,
             )
             .max(title_bar_interaction)
+            .max(tab_bar_interaction)
     }
 
     pub(crate) fn overlay<'b>(
@@ -313,30 +450,38 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        if let Some(title_bar) = self.title_bar.as_mut() {
-            let mut children = layout.children();
-            let title_bar_layout = children.next()?;
-
-            let mut states = tree.children.iter_mut();
-            let body_state = states.next().unwrap();
-            let title_bar_state = states.next().unwrap();
-
-            match title_bar.overlay(title_bar_state, title_bar_layout, renderer)
-            {
-                Some(overlay) => Some(overlay),
-                None => self.body.as_widget_mut().overlay(
-                    body_state,
-                    children.next()?,
-                    renderer,
-                ),
-            }
-        } else {
-            self.body.as_widget_mut().overlay(
-                &mut tree.children[0],
-                layout,
-                renderer,
-            )
-        }
+        let (title_bar_layout, tab_bar_layout, body_layout) =
+            self.layouts(layout);
+
+        let Self {
+            title_bar,
+            tab_bar,
+            body,
+            ..
+        } = self;
+
+        let mut states = tree.children.iter_mut();
+        let body_state = states.next().unwrap();
+        let title_bar_state = states.next().unwrap();
+        let tab_bar_state = states.next().unwrap();
+
+        title_bar
+            .as_mut()
+            .zip(title_bar_layout)
+            .and_then(move |(title_bar, layout)| {
+                title_bar.overlay(title_bar_state, layout, renderer)
+            })
+            .or_else(move || {
+                tab_bar.as_mut().zip(tab_bar_layout).and_then(
+                    move |(tab_bar, layout)| {
+                        tab_bar.overlay(tab_bar_state, layout, renderer)
+                    },
+                )
+            })
+            .or_else(move || {
+                body.as_widget_mut()
+                    .overlay(body_state, body_layout, renderer)
+            })
     }
 }
 
@@ -350,15 +495,41 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> bool {
-        if let Some(title_bar) = &self.title_bar {
-            let mut children = layout.children();
-            let title_bar_layout = children.next().unwrap();
+        let (title_bar_layout, tab_bar_layout, _) = self.layouts(layout);
 
-            title_bar.is_over_pick_area(title_bar_layout, cursor_position)
-        } else {
-            false
+        let over_title_bar = match (&self.title_bar, title_bar_layout) {
+            (Some(title_bar), Some(layout)) => {
+                title_bar.is_over_pick_area(layout, cursor_position)
+            }
+            _ => false,
+        };
+
+        let over_tab_bar = match (&self.tab_bar, tab_bar_layout) {
+            (Some(tab_bar), Some(layout)) => {
+                tab_bar.is_over_pick_area(layout, cursor_position)
+            }
+            _ => false,
+        };
+
+        over_title_bar || over_tab_bar
+    }
+
+    fn tab_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        match (&self.tab_bar, self.layouts(layout).1) {
+            (Some(tab_bar), Some(layout)) => {
+                tab_bar.tab_at(layout, cursor_position)
+            }
+            _ => None,
         }
     }
+
+    fn min_size(&self) -> Size {
+        self.min_size
+    }
 }
 
 impl<'a, T, Message, Renderer> From<T> for Content<'a, Message, Renderer>
@@ -1,4 +1,4 @@
-use crate::{Layout, Point};
+use crate::{Layout, Point, Size};
 
 /// A pane that can be dragged.
 pub trait Draggable {
@@ -9,4 +9,20 @@ pub trait Draggable {
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> bool;
+
+    /// Returns the index of the tab of the [`Draggable`] with the given
+    /// [`Layout`] that can be picked at the provided cursor position, if any.
+    fn tab_at(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Option<usize> {
+        None
+    }
+
+    /// Returns the minimum size of the [`Draggable`] when the splits around
+    /// it are dragged.
+    fn min_size(&self) -> Size {
+        Size::ZERO
+    }
 }
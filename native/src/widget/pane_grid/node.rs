@@ -95,6 +95,57 @@ impl Node {
         splits
     }
 
+    /// Returns the minimum size of the [`Node`] given the spacing between
+    /// panes and the minimum size of each [`Pane`].
+    pub fn min_size(
+        &self,
+        spacing: f32,
+        min_size: &impl Fn(Pane) -> Size,
+    ) -> Size {
+        match self {
+            Node::Split { axis, a, b, .. } => {
+                let a = a.min_size(spacing, min_size);
+                let b = b.min_size(spacing, min_size);
+
+                match axis {
+                    Axis::Horizontal => Size::new(
+                        a.width.max(b.width),
+                        a.height + spacing + b.height,
+                    ),
+                    Axis::Vertical => Size::new(
+                        a.width + spacing + b.width,
+                        a.height.max(b.height),
+                    ),
+                }
+            }
+            Node::Pane(pane) => min_size(*pane),
+        }
+    }
+
+    /// Returns the minimum sizes of both sides of the given [`Split`], if it
+    /// is in the [`Node`].
+    pub(crate) fn split_min_sizes(
+        &self,
+        split: &Split,
+        spacing: f32,
+        min_size: &impl Fn(Pane) -> Size,
+    ) -> Option<(Size, Size)> {
+        match self {
+            Node::Split { id, a, b, .. } => {
+                if id == split {
+                    Some((
+                        a.min_size(spacing, min_size),
+                        b.min_size(spacing, min_size),
+                    ))
+                } else {
+                    a.split_min_sizes(split, spacing, min_size)
+                        .or_else(|| b.split_min_sizes(split, spacing, min_size))
+                }
+            }
+            Node::Pane(_) => None,
+        }
+    }
+
     pub(crate) fn find(&mut self, pane: &Pane) -> Option<&mut Node> {
         match self {
             Node::Split { a, b, .. } => {
@@ -172,7 +223,7 @@ impl Node {
         }
     }
 
-    fn first_pane(&self) -> Pane {
+    pub(crate) fn first_pane(&self) -> Pane {
         match self {
             Node::Split { a, .. } => a.first_pane(),
             Node::Pane(pane) => *pane,
@@ -2,9 +2,9 @@
 //!
 //! [`PaneGrid`]: crate::widget::PaneGrid
 use crate::widget::pane_grid::{
-    Axis, Configuration, Direction, Node, Pane, Split,
+    Axis, Configuration, Direction, DropTarget, Node, Pane, Split, TabGroup,
 };
-use crate::{Point, Size};
+use crate::{Point, Rectangle, Size};
 
 use std::collections::HashMap;
 
@@ -64,6 +64,45 @@ impl<T> State<T> {
         }
     }
 
+    /// Creates a new [`State`] with the splits, ratios and panes of the given
+    /// layout, as returned by [`Self::to_layout`].
+    ///
+    /// The panes are given new identifiers.
+    pub fn from_layout(layout: Configuration<T>) -> Self {
+        Self::with_configuration(layout)
+    }
+
+    /// Returns the layout of the [`State`] as a [`Configuration`], with the
+    /// splits, their ratios and the state of each [`Pane`].
+    ///
+    /// Floating panes are not part of the layout.
+    ///
+    /// Paired with [`Configuration::map`] and [`Self::from_layout`], it
+    /// can be used to persist and restore the layout of a [`PaneGrid`] across
+    /// sessions.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn to_layout(&self) -> Configuration<&T> {
+        fn to_layout<'a, T>(
+            node: &Node,
+            panes: &'a HashMap<Pane, T>,
+        ) -> Configuration<&'a T> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(to_layout(a, panes)),
+                    b: Box::new(to_layout(b, panes)),
+                },
+                Node::Pane(pane) => Configuration::Pane(&panes[pane]),
+            }
+        }
+
+        to_layout(&self.internal.layout, &self.panes)
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -203,11 +242,25 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// The closest sibling of a floating [`Pane`] is the first [`Pane`] of
+    /// the layout.
     pub fn close(&mut self, pane: &Pane) -> Option<(T, Pane)> {
         if self.maximized == Some(*pane) {
             let _ = self.maximized.take();
         }
 
+        if self.internal.is_floating(pane) {
+            self.internal
+                .floating
+                .retain(|(floating, _)| floating != pane);
+
+            return self
+                .panes
+                .remove(pane)
+                .map(|state| (state, self.internal.layout.first_pane()));
+        }
+
         if let Some(sibling) = self.internal.layout.remove(pane) {
             self.panes.remove(pane).map(|state| (state, sibling))
         } else {
@@ -237,6 +290,191 @@ impl<T> State<T> {
     pub fn maximized(&self) -> Option<Pane> {
         self.maximized
     }
+
+    /// Detaches the given [`Pane`] from the layout, making it float over the
+    /// other panes with the given bounds, relative to the [`PaneGrid`].
+    ///
+    /// The bounds of an already floating [`Pane`] are updated. The last
+    /// [`Pane`] of the layout cannot float.
+    ///
+    /// If you want to detach panes on drag and drop in your [`PaneGrid`], you
+    /// will need to call this method when handling a [`FloatEvent`].
+    ///
+    /// It returns whether the [`Pane`] is floating.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    /// [`FloatEvent`]: crate::widget::pane_grid::FloatEvent
+    pub fn float(&mut self, pane: &Pane, bounds: Rectangle) -> bool {
+        if self.internal.is_floating(pane) {
+            self.move_floating(pane, bounds);
+
+            return true;
+        }
+
+        if self.internal.layout.remove(pane).is_none() {
+            return false;
+        }
+
+        if self.maximized == Some(*pane) {
+            let _ = self.maximized.take();
+        }
+
+        self.internal.floating.push((*pane, bounds));
+
+        true
+    }
+
+    /// Sets the bounds of the given floating [`Pane`], relative to the
+    /// [`PaneGrid`], and brings it on top of the other floating panes.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn move_floating(&mut self, pane: &Pane, bounds: Rectangle) {
+        if let Some(index) = self
+            .internal
+            .floating
+            .iter()
+            .position(|(floating, _)| floating == pane)
+        {
+            let _ = self.internal.floating.remove(index);
+
+            self.internal.floating.push((*pane, bounds));
+        }
+    }
+
+    /// Docks the given floating [`Pane`] back into the layout, splitting the
+    /// target [`Pane`] and placing it on the side of the given [`Direction`].
+    ///
+    /// It returns the new [`Split`], if the [`Pane`] was docked.
+    pub fn dock(
+        &mut self,
+        pane: &Pane,
+        target: &Pane,
+        direction: Direction,
+    ) -> Option<Split> {
+        if !self.internal.is_floating(pane) {
+            return None;
+        }
+
+        let new_split = self.split_next_to(pane, target, direction)?;
+
+        self.internal
+            .floating
+            .retain(|(floating, _)| floating != pane);
+
+        Some(new_split)
+    }
+
+    /// Drops the given [`Pane`] on the [`DropTarget`], either swapping both
+    /// panes or moving the [`Pane`] next to the target.
+    ///
+    /// If you want to rearrange panes on drag and drop in your [`PaneGrid`],
+    /// you will need to call this method when handling a [`DragEvent`].
+    ///
+    /// It returns the new [`Split`], if the target was split.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    /// [`DragEvent`]: crate::widget::pane_grid::DragEvent
+    pub fn drop(&mut self, pane: &Pane, target: DropTarget) -> Option<Split> {
+        match target {
+            DropTarget::Center(target) => {
+                self.swap(pane, &target);
+
+                None
+            }
+            DropTarget::Edge(target, direction) => {
+                if *pane == target
+                    || self.internal.is_floating(pane)
+                    || self.internal.layout.remove(pane).is_none()
+                {
+                    return None;
+                }
+
+                self.split_next_to(pane, &target, direction)
+            }
+        }
+    }
+
+    /// Splits the target [`Pane`] to place the given one, which must not be
+    /// in the layout, on the side of the given [`Direction`].
+    fn split_next_to(
+        &mut self,
+        pane: &Pane,
+        target: &Pane,
+        direction: Direction,
+    ) -> Option<Split> {
+        let node = self.internal.layout.find(target)?;
+
+        let new_split = {
+            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+
+            Split(self.internal.last_id)
+        };
+
+        let axis = match direction {
+            Direction::Left | Direction::Right => Axis::Vertical,
+            Direction::Up | Direction::Down => Axis::Horizontal,
+        };
+
+        node.split(new_split, axis, *pane);
+
+        if matches!(direction, Direction::Left | Direction::Up) {
+            if let Node::Split { a, b, .. } = node {
+                std::mem::swap(a, b);
+            }
+        }
+
+        Some(new_split)
+    }
+
+    /// Returns an iterator over the floating panes of the [`State`] and
+    /// their bounds, from the bottom to the top one.
+    pub fn floating(&self) -> impl Iterator<Item = (Pane, Rectangle)> + '_ {
+        self.internal.floating.iter().copied()
+    }
+
+    /// Returns whether the given [`Pane`] is floating.
+    pub fn is_floating(&self, pane: &Pane) -> bool {
+        self.internal.is_floating(pane)
+    }
+}
+
+impl<T> State<TabGroup<T>> {
+    /// Moves the tab at the given index of a [`Pane`] to the end of the tabs
+    /// of the target [`Pane`], making it active there.
+    ///
+    /// The source [`Pane`] is closed once its last tab is moved away.
+    ///
+    /// If you want to dock tabs on drag and drop in your [`PaneGrid`], you
+    /// will need to call this method when handling a
+    /// [`DragEvent::TabDropped`].
+    ///
+    /// It returns the index of the tab in the target [`Pane`], if it was
+    /// moved.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    /// [`DragEvent::TabDropped`]: crate::widget::pane_grid::DragEvent::TabDropped
+    pub fn move_tab(
+        &mut self,
+        pane: &Pane,
+        tab: usize,
+        target: &Pane,
+    ) -> Option<usize> {
+        if pane == target || !self.panes.contains_key(target) {
+            return None;
+        }
+
+        let source = self.panes.get_mut(pane)?;
+        let moved = source.remove(tab)?;
+        let is_source_empty = source.is_empty();
+
+        let index = self.panes.get_mut(target)?.push(moved);
+
+        if is_source_empty {
+            let _ = self.close(pane);
+        }
+
+        Some(index)
+    }
 }
 
 /// The internal state of a [`PaneGrid`].
@@ -246,6 +484,7 @@ impl<T> State<T> {
 pub struct Internal {
     layout: Node,
     last_id: usize,
+    floating: Vec<(Pane, Rectangle)>,
 }
 
 impl Internal {
@@ -291,7 +530,11 @@ impl Internal {
             }
         };
 
-        Self { layout, last_id }
+        Self {
+            layout,
+            last_id,
+            floating: Vec::new(),
+        }
     }
 }
 
@@ -313,6 +556,37 @@ pub enum Action {
         /// The starting [`Point`] of the drag interaction.
         origin: Point,
     },
+    /// A tab of a [`Pane`] in the [`PaneGrid`] is being dragged.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    DraggingTab {
+        /// The [`Pane`] of the tab being dragged.
+        pane: Pane,
+        /// The index of the tab being dragged.
+        tab: usize,
+        /// The [`Point`] where the tab was picked.
+        origin: Point,
+    },
+    /// A floating [`Pane`] in the [`PaneGrid`] is being moved.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    Moving {
+        /// The floating [`Pane`] being moved.
+        pane: Pane,
+        /// The starting [`Point`] of the move, relative to the [`Pane`].
+        origin: Point,
+    },
+    /// A floating [`Pane`] in the [`PaneGrid`] is being resized.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    ResizingFloating {
+        /// The floating [`Pane`] being resized.
+        pane: Pane,
+        /// Whether the right edge of the [`Pane`] is being dragged.
+        horizontal: bool,
+        /// Whether the bottom edge of the [`Pane`] is being dragged.
+        vertical: bool,
+    },
     /// A [`Split`] in the [`PaneGrid`] is being dragged.
     ///
     /// [`PaneGrid`]: crate::widget::PaneGrid
@@ -333,6 +607,27 @@ impl Action {
         }
     }
 
+    /// Returns the current tab that is being dragged, if any, alongside its
+    /// [`Pane`] and the [`Point`] where it was picked.
+    pub fn picked_tab(&self) -> Option<(Pane, usize, Point)> {
+        match *self {
+            Action::DraggingTab { pane, tab, origin } => {
+                Some((pane, tab, origin))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the current floating [`Pane`] that is being moved or resized,
+    /// if any.
+    pub fn picked_floating(&self) -> Option<Pane> {
+        match *self {
+            Action::Moving { pane, .. }
+            | Action::ResizingFloating { pane, .. } => Some(pane),
+            _ => None,
+        }
+    }
+
     /// Returns the current [`Split`] that is being dragged, if any.
     pub fn picked_split(&self) -> Option<(Split, Axis)> {
         match *self {
@@ -347,4 +642,80 @@ impl Internal {
     pub fn layout(&self) -> &Node {
         &self.layout
     }
+
+    /// The floating panes of the [`Internal`] state and their bounds, from
+    /// the bottom to the top one.
+    pub fn floating(&self) -> &[(Pane, Rectangle)] {
+        &self.floating
+    }
+
+    fn is_floating(&self, pane: &Pane) -> bool {
+        self.floating.iter().any(|(floating, _)| floating == pane)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_round_trip_keeps_splits_and_ratios() {
+        let (mut state, first) = State::new("first");
+        let (second, split) =
+            state.split(Axis::Vertical, &first, "second").unwrap();
+        let _ = state.split(Axis::Horizontal, &second, "third").unwrap();
+        state.resize(&split, 0.3);
+
+        let layout = state.to_layout().map(|name| name.to_string());
+        let restored = State::from_layout(layout.clone());
+
+        assert_eq!(restored.to_layout().map(|name| name.to_string()), layout);
+        assert_eq!(
+            layout,
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.3,
+                a: Box::new(Configuration::Pane(String::from("first"))),
+                b: Box::new(Configuration::Split {
+                    axis: Axis::Horizontal,
+                    ratio: 0.5,
+                    a: Box::new(Configuration::Pane(String::from("second"))),
+                    b: Box::new(Configuration::Pane(String::from("third"))),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn dropping_on_an_edge_moves_the_pane_next_to_the_target() {
+        let (mut state, first) = State::new("first");
+        let (second, _) =
+            state.split(Axis::Vertical, &first, "second").unwrap();
+        let (third, _) =
+            state.split(Axis::Horizontal, &second, "third").unwrap();
+
+        let _ = state
+            .drop(&third, DropTarget::Edge(first, Direction::Up))
+            .unwrap();
+
+        assert_eq!(
+            state.to_layout(),
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.5,
+                a: Box::new(Configuration::Split {
+                    axis: Axis::Horizontal,
+                    ratio: 0.5,
+                    a: Box::new(Configuration::Pane(&"third")),
+                    b: Box::new(Configuration::Pane(&"first")),
+                }),
+                b: Box::new(Configuration::Pane(&"second")),
+            }
+        );
+
+        assert_eq!(
+            state.drop(&third, DropTarget::Edge(third, Direction::Left)),
+            None
+        );
+    }
 }
@@ -0,0 +1,473 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay::{self, Group};
+use crate::renderer;
+use crate::touch;
+use crate::widget::container;
+use crate::widget::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Padding, Pixels, Point, Rectangle,
+    Shell, Size,
+};
+
+/// The tabs of a [`Pane`] hosting many contents, only one of them being
+/// displayed at a time.
+///
+/// The tabs can be dragged onto other panes of a [`PaneGrid`] with drag and
+/// drop enabled, producing a [`DragEvent::TabDropped`].
+///
+/// [`Pane`]: crate::widget::pane_grid::Pane
+/// [`PaneGrid`]: crate::widget::PaneGrid
+/// [`DragEvent::TabDropped`]: crate::widget::pane_grid::DragEvent::TabDropped
+#[allow(missing_debug_implementations)]
+pub struct TabBar<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    tabs: Vec<Element<'a, Message, Renderer>>,
+    active: usize,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    spacing: f32,
+    padding: Padding,
+    tab_padding: Padding,
+    style: <Renderer::Theme as container::StyleSheet>::Style,
+    tab_style: <Renderer::Theme as container::StyleSheet>::Style,
+    active_tab_style: <Renderer::Theme as container::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    /// The width of the line under the active tab of a [`TabBar`].
+    const INDICATOR_WIDTH: f32 = 2.0;
+
+    /// Creates a new empty [`TabBar`] with the given active tab.
+    pub fn new(active: usize) -> Self {
+        Self::with_tabs(Vec::new(), active)
+    }
+
+    /// Creates a new [`TabBar`] with the given tabs and active tab.
+    pub fn with_tabs(
+        tabs: Vec<Element<'a, Message, Renderer>>,
+        active: usize,
+    ) -> Self {
+        Self {
+            tabs,
+            active,
+            on_select: None,
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            tab_padding: Padding::new(5.0),
+            style: Default::default(),
+            tab_style: Default::default(),
+            active_tab_style: Default::default(),
+        }
+    }
+
+    /// Adds a tab to the [`TabBar`].
+    pub fn push(
+        mut self,
+        tab: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.tabs.push(tab.into());
+        self
+    }
+
+    /// Sets the message that will be produced when a tab of the [`TabBar`]
+    /// is pressed.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the spacing _between_ the tabs of the [`TabBar`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TabBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab of the [`TabBar`].
+    pub fn tab_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.tab_padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`TabBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as container::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the inactive tabs of the [`TabBar`].
+    pub fn tab_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as container::StyleSheet>::Style>,
+    ) -> Self {
+        self.tab_style = style.into();
+        self
+    }
+
+    /// Sets the style of the active tab of the [`TabBar`].
+    pub fn active_tab_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as container::StyleSheet>::Style>,
+    ) -> Self {
+        self.active_tab_style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    pub(super) fn state(&self) -> Tree {
+        Tree {
+            children: self.tabs.iter().map(Tree::new).collect(),
+            ..Tree::empty()
+        }
+    }
+
+    pub(super) fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.tabs);
+    }
+
+    /// Draws the [`TabBar`] with the provided [`Renderer`] and [`Layout`].
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        use container::StyleSheet;
+
+        let bounds = layout.bounds();
+        let style = theme.appearance(&self.style);
+        let inherited_style = renderer::Style {
+            text_color: style.text_color.unwrap_or(inherited_style.text_color),
+        };
+
+        container::draw_background(renderer, &style, bounds);
+
+        // The tabs that do not fit are cut off
+        renderer.with_layer(bounds, |renderer| {
+            for index in 0..self.tabs.len() {
+                self.draw_tab(
+                    tree,
+                    renderer,
+                    theme,
+                    &inherited_style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    index,
+                );
+            }
+        });
+    }
+
+    /// Draws the tab with the given index of the [`TabBar`].
+    pub(super) fn draw_tab(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        index: usize,
+    ) {
+        use container::StyleSheet;
+
+        let (tab, tree, layout) = match (
+            self.tabs.get(index),
+            tree.children.get(index),
+            layout.children().nth(index),
+        ) {
+            (Some(tab), Some(tree), Some(layout)) => (tab, tree, layout),
+            _ => return,
+        };
+
+        let is_active = index == self.active;
+        let bounds = layout.bounds();
+
+        let appearance = theme.appearance(if is_active {
+            &self.active_tab_style
+        } else {
+            &self.tab_style
+        });
+
+        let text_color =
+            appearance.text_color.unwrap_or(inherited_style.text_color);
+
+        container::draw_background(renderer, &appearance, bounds);
+
+        tab.as_widget().draw(
+            tree,
+            renderer,
+            theme,
+            &renderer::Style { text_color },
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+
+        if is_active {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - Self::INDICATOR_WIDTH,
+                        height: Self::INDICATOR_WIDTH,
+                        ..bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                text_color,
+            );
+        }
+    }
+
+    /// Returns the index of the tab of the [`TabBar`] under the mouse cursor,
+    /// if any.
+    pub fn tab_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if !layout.bounds().contains(cursor_position) {
+            return None;
+        }
+
+        layout
+            .children()
+            .position(|layout| layout.bounds().contains(cursor_position))
+    }
+
+    /// Returns whether the mouse cursor is over the pick area of the
+    /// [`TabBar`] or not.
+    ///
+    /// The whole [`TabBar`] is a pick area, except its tabs.
+    pub fn is_over_pick_area(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+            && self.tab_at(layout, cursor_position).is_none()
+    }
+
+    pub(crate) fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.pad(self.padding);
+        let max_size = limits.max();
+
+        let label_limits = layout::Limits::new(Size::ZERO, max_size)
+            .pad(self.tab_padding)
+            .with_direction(limits.direction());
+
+        let labels: Vec<_> = self
+            .tabs
+            .iter()
+            .map(|tab| tab.as_widget().layout(renderer, &label_limits))
+            .collect();
+
+        // Every tab is as tall as the tallest one
+        let height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(0.0, f32::max)
+            + self.tab_padding.vertical();
+
+        let mut x = self.padding.left;
+
+        let tabs = labels
+            .into_iter()
+            .map(|mut label| {
+                let label_size = label.size();
+
+                label.move_to(Point::new(
+                    self.tab_padding.left,
+                    (height - label_size.height) / 2.0,
+                ));
+
+                let mut tab = layout::Node::with_children(
+                    Size::new(
+                        label_size.width + self.tab_padding.horizontal(),
+                        height,
+                    ),
+                    vec![label],
+                );
+
+                tab.move_to(Point::new(x, self.padding.top));
+
+                x += tab.size().width + self.spacing;
+
+                tab
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(
+            Size::new(max_size.width, height).pad(self.padding),
+            tabs,
+        );
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    pub(crate) fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.tabs
+            .iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .for_each(|((tab, state), layout)| {
+                tab.as_widget().operate(
+                    state,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            });
+    }
+
+    pub(crate) fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((tab, state), layout)| {
+                tab.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout.children().next().unwrap(),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let (Some(on_select), Some(index)) =
+                    (&self.on_select, self.tab_at(layout, cursor_position))
+                {
+                    shell.publish(on_select(index));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    pub(crate) fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .tabs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((tab, state), layout)| {
+                tab.as_widget().mouse_interaction(
+                    state,
+                    layout.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if self.on_select.is_some()
+            && self.tab_at(layout, cursor_position).is_some()
+        {
+            interaction.max(mouse::Interaction::Pointer)
+        } else {
+            interaction
+        }
+    }
+
+    pub(crate) fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((tab, state), layout)| {
+                tab.as_widget_mut().overlay(
+                    state,
+                    layout.children().next().unwrap(),
+                    renderer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| Group::with_children(children).overlay())
+    }
+}
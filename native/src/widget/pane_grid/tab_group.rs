@@ -0,0 +1,122 @@
+/// A group of tabs hosted by a single [`Pane`], only one of them being active
+/// at a time.
+///
+/// Use it as the state of the panes of a [`State`] to dock many contents in
+/// the same [`Pane`] and move them around with [`State::move_tab`].
+///
+/// [`Pane`]: crate::widget::pane_grid::Pane
+/// [`State`]: crate::widget::pane_grid::State
+/// [`State::move_tab`]: crate::widget::pane_grid::State::move_tab
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabGroup<T> {
+    tabs: Vec<T>,
+    active: usize,
+}
+
+impl<T> TabGroup<T> {
+    /// Creates a new [`TabGroup`] with a single, active tab.
+    pub fn new(tab: T) -> Self {
+        Self {
+            tabs: vec![tab],
+            active: 0,
+        }
+    }
+
+    /// Adds a tab at the end of the [`TabGroup`] and makes it active.
+    ///
+    /// It returns the index of the new tab.
+    pub fn push(&mut self, tab: T) -> usize {
+        self.tabs.push(tab);
+        self.active = self.tabs.len() - 1;
+
+        self.active
+    }
+
+    /// Removes the tab at the given index of the [`TabGroup`], if it exists.
+    ///
+    /// The tab next to it becomes active if it was.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+
+        let tab = self.tabs.remove(index);
+
+        if self.active > index || self.active == self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+
+        Some(tab)
+    }
+
+    /// Makes the tab at the given index active, if it exists.
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = index;
+        }
+    }
+
+    /// Returns the index of the active tab of the [`TabGroup`].
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Returns the active tab of the [`TabGroup`], if any.
+    pub fn active_tab(&self) -> Option<&T> {
+        self.tabs.get(self.active)
+    }
+
+    /// Returns the active tab of the [`TabGroup`] with mutability, if any.
+    pub fn active_tab_mut(&mut self) -> Option<&mut T> {
+        self.tabs.get_mut(self.active)
+    }
+
+    /// Returns the tab at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.tabs.get(index)
+    }
+
+    /// Returns the tab at the given index with mutability, if it exists.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.tabs.get_mut(index)
+    }
+
+    /// Returns an iterator over the tabs of the [`TabGroup`].
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.tabs.iter()
+    }
+
+    /// Returns the amount of tabs in the [`TabGroup`].
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns `true` if the [`TabGroup`] has no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_tabs_keeps_a_neighbour_active() {
+        let mut group = TabGroup::new('a');
+        let _ = group.push('b');
+        let _ = group.push('c');
+
+        group.select(1);
+        assert_eq!(group.remove(0), Some('a'));
+        assert_eq!(group.active_tab(), Some(&'b'));
+
+        assert_eq!(group.remove(0), Some('b'));
+        assert_eq!(group.active_tab(), Some(&'c'));
+
+        assert_eq!(group.remove(3), None);
+        assert_eq!(group.remove(0), Some('c'));
+        assert!(group.is_empty());
+        assert_eq!(group.active_tab(), None);
+    }
+}
@@ -219,17 +219,20 @@ where
         let limits = limits.pad(self.padding);
         let max_size = limits.max();
 
-        let title_layout = self
-            .content
-            .as_widget()
-            .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+        let title_layout = self.content.as_widget().layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, max_size)
+                .with_direction(limits.direction()),
+        );
 
         let title_size = title_layout.size();
 
         let mut node = if let Some(controls) = &self.controls {
-            let mut controls_layout = controls
-                .as_widget()
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+            let mut controls_layout = controls.as_widget().layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
+            );
 
             let controls_size = controls_layout.size();
             let space_before_controls = max_size.width - controls_size.width;
@@ -249,9 +252,16 @@ where
             )
         };
 
+        node.mirror(limits.direction());
         node.move_to(Point::new(self.padding.left, self.padding.top));
 
-        layout::Node::with_children(node.size().pad(self.padding), vec![node])
+        let mut node = layout::Node::with_children(
+            node.size().pad(self.padding),
+            vec![node],
+        );
+        node.mirror(limits.direction());
+
+        node
     }
 
     pub(crate) fn operate(
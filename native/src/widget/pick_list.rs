@@ -9,6 +9,7 @@ use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
+use crate::widget;
 use crate::widget::container;
 use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
@@ -28,6 +29,8 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
+    memory: Option<overlay::Memory>,
     on_selected: Box<dyn Fn(T) -> Message + 'a>,
     options: Cow<'a, [T]>,
     placeholder: Option<String>,
@@ -63,6 +66,8 @@ where
         on_selected: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
+            id: None,
+            memory: None,
             on_selected: Box::new(on_selected),
             options: options.into(),
             placeholder: None,
@@ -76,6 +81,23 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Memory`](overlay::Memory) of the [`PickList`].
+    ///
+    /// A [`PickList`] with an [`Id`] remembers the scroll position and the
+    /// hovered option of its menu in the [`Memory`](overlay::Memory) when it
+    /// is removed from the user interface, and resumes from them when added
+    /// back later.
+    pub fn memory(mut self, memory: overlay::Memory) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -141,7 +163,12 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::new(match (&self.memory, &self.id) {
+            (Some(memory), Some(id)) => {
+                State::restore(memory.clone(), id.clone())
+            }
+            _ => State::new(),
+        })
     }
 
     fn width(&self) -> Length {
@@ -268,23 +295,96 @@ where
     }
 }
 
+/// The identifier of a [`PickList`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
 /// The local state of a [`PickList`].
 #[derive(Debug)]
 pub struct State {
+    memory: Option<(overlay::Memory, Id)>,
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     hovered_option: Option<usize>,
 }
 
+/// The state of the menu of a [`PickList`] remembered in its
+/// [`Memory`](overlay::Memory).
+#[derive(Debug)]
+struct Memento {
+    menu: menu::State,
+    hovered_option: Option<usize>,
+}
+
 impl State {
     /// Creates a new [`State`] for a [`PickList`].
     pub fn new() -> Self {
         Self {
+            memory: None,
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             hovered_option: Option::default(),
         }
     }
+
+    /// Creates a new [`State`] for the [`PickList`] with the given [`Id`],
+    /// restoring the state of its menu from the given
+    /// [`Memory`](overlay::Memory), if any.
+    ///
+    /// The state is stored back in the [`Memory`](overlay::Memory) when
+    /// dropped.
+    pub fn restore(memory: overlay::Memory, id: Id) -> Self {
+        let memento = memory.take::<Memento>(&id.0);
+
+        let (menu, hovered_option) = match memento {
+            Some(memento) => (memento.menu, memento.hovered_option),
+            None => (menu::State::default(), None),
+        };
+
+        Self {
+            memory: Some((memory, id)),
+            menu,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            hovered_option,
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        if let Some((memory, id)) = self.memory.take() {
+            let mut menu = std::mem::take(&mut self.menu);
+            menu.rest();
+
+            memory.store(
+                id.0,
+                Memento {
+                    menu,
+                    hovered_option: self.hovered_option.take(),
+                },
+            );
+        }
+    }
 }
 
 impl Default for State {
@@ -386,7 +486,7 @@ where
         limits.resolve(intrinsic).pad(padding)
     };
 
-    layout::Node::new(size)
+    layout::Node::new(size).with_direction(limits.direction())
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`PickList`]
@@ -414,9 +514,13 @@ where
                     state.menu.close();
                 } else if !state.menu.is_closing() {
                     state.menu.open();
-                    state.hovered_option = options
-                        .iter()
-                        .position(|option| Some(option) == selected);
+
+                    if state.memory.is_none() || state.hovered_option.is_none()
+                    {
+                        state.hovered_option = options
+                            .iter()
+                            .position(|option| Some(option) == selected);
+                    }
                 }
 
                 event::Status::Captured
@@ -532,6 +636,7 @@ where
         )
         .width(bounds.width)
         .padding(padding)
+        .direction(layout.direction())
         .font(font)
         .style(style);
 
@@ -603,21 +708,33 @@ pub fn draw<'a, T, Renderer>(
         Handle::None => None,
     };
 
+    // The handle and the label swap sides in a right-to-left layout
+    let is_rtl = layout.direction() == layout::Direction::RightToLeft;
+
     if let Some((font, code_point, size)) = handle {
         let size = size.unwrap_or_else(|| renderer.default_size());
 
+        let (x, horizontal_alignment) = if is_rtl {
+            (bounds.x + padding.horizontal(), alignment::Horizontal::Left)
+        } else {
+            (
+                bounds.x + bounds.width - padding.horizontal(),
+                alignment::Horizontal::Right,
+            )
+        };
+
         renderer.fill_text(Text {
             content: &code_point.to_string(),
             size,
             font,
             color: style.handle_color,
             bounds: Rectangle {
-                x: bounds.x + bounds.width - padding.horizontal(),
+                x,
                 y: bounds.center_y() - size / 2.0,
                 height: size,
                 ..bounds
             },
-            horizontal_alignment: alignment::Horizontal::Right,
+            horizontal_alignment,
             vertical_alignment: alignment::Vertical::Top,
         });
     }
@@ -627,6 +744,15 @@ pub fn draw<'a, T, Renderer>(
     if let Some(label) = label.as_deref().or(placeholder) {
         let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
+        let (x, horizontal_alignment) = if is_rtl {
+            (
+                bounds.x + bounds.width - padding.right,
+                alignment::Horizontal::Right,
+            )
+        } else {
+            (bounds.x + padding.left, alignment::Horizontal::Left)
+        };
+
         renderer.fill_text(Text {
             content: label,
             size: text_size,
@@ -637,12 +763,12 @@ pub fn draw<'a, T, Renderer>(
                 style.placeholder_color
             },
             bounds: Rectangle {
-                x: bounds.x + padding.left,
+                x,
                 y: bounds.center_y() - text_size / 2.0,
                 width: bounds.width - padding.horizontal(),
                 height: text_size,
             },
-            horizontal_alignment: alignment::Horizontal::Left,
+            horizontal_alignment,
             vertical_alignment: alignment::Vertical::Top,
         });
     }
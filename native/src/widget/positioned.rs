@@ -0,0 +1,326 @@
+//! Place content at explicit positions inside of a container.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A point of the bounds of a [`Positioned`] container that a child can be
+/// attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Anchor {
+    /// The top left corner.
+    #[default]
+    TopLeft,
+    /// The middle of the top side.
+    Top,
+    /// The top right corner.
+    TopRight,
+    /// The middle of the left side.
+    Left,
+    /// The center.
+    Center,
+    /// The middle of the right side.
+    Right,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The middle of the bottom side.
+    Bottom,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the position of content with the given size attached to the
+    /// [`Anchor`] of some bounds with the given size.
+    pub fn position(self, bounds: Size, content: Size) -> Point {
+        let (x, y) = match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::Top => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::Bottom => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        };
+
+        Point::new(
+            (bounds.width - content.width) * x,
+            (bounds.height - content.height) * y,
+        )
+    }
+}
+
+/// A container that places each of its children at an explicit [`Anchor`]
+/// and offset, instead of laying them out one after another.
+///
+/// Children may overlap; later children are drawn on top of earlier ones and
+/// receive events first.
+#[allow(missing_debug_implementations)]
+pub struct Positioned<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    positions: Vec<(Anchor, Vector)>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Positioned<'a, Message, Renderer> {
+    /// Creates an empty [`Positioned`] container.
+    pub fn new() -> Self {
+        Positioned {
+            width: Length::Fill,
+            height: Length::Fill,
+            positions: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Positioned`] container.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Positioned`] container.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Adds an element at the given offset from the top left corner of the
+    /// [`Positioned`] container.
+    pub fn push_at(
+        self,
+        position: Point,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.push_anchored(
+            Anchor::TopLeft,
+            Vector::new(position.x, position.y),
+            child,
+        )
+    }
+
+    /// Adds an element attached to the given [`Anchor`] of the [`Positioned`]
+    /// container.
+    pub fn push(
+        self,
+        anchor: Anchor,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.push_anchored(anchor, Vector::new(0.0, 0.0), child)
+    }
+
+    /// Adds an element attached to the given [`Anchor`] of the [`Positioned`]
+    /// container, and then moved by the given offset.
+    pub fn push_anchored(
+        mut self,
+        anchor: Anchor,
+        offset: Vector,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.positions.push((anchor, offset));
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Positioned<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Positioned<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+        let child_limits = layout::Limits::new(Size::ZERO, size)
+            .with_direction(limits.direction());
+
+        let children = self
+            .children
+            .iter()
+            .zip(&self.positions)
+            .map(|(child, (anchor, offset))| {
+                let mut node =
+                    child.as_widget().layout(renderer, &child_limits);
+
+                node.move_to(anchor.position(size, node.size()) + *offset);
+
+                node
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(size, children);
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        for ((child, state), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layouts)
+            .rev()
+        {
+            let status = child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if let event::Status::Captured = status {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layouts)
+            .rev()
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default())
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            renderer.with_layer(layout.bounds(), |renderer| {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Positioned<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(positioned: Positioned<'a, Message, Renderer>) -> Self {
+        Self::new(positioned)
+    }
+}
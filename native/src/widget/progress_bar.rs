@@ -1,8 +1,14 @@
 //! Provide progress feedback to your users.
+use crate::event::{self, Event};
 use crate::layout;
 use crate::renderer;
-use crate::widget::Tree;
-use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
 
 use std::ops::RangeInclusive;
 
@@ -18,6 +24,11 @@ pub use iced_style::progress_bar::{Appearance, StyleSheet};
 /// ProgressBar::new(0.0..=100.0, value);
 /// ```
 ///
+/// When the amount of work is unknown, an [`indeterminate`] progress bar
+/// sweeps a segment across itself instead.
+///
+/// [`indeterminate`]: Self::indeterminate
+///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<Renderer>
@@ -29,6 +40,7 @@ where
     value: f32,
     width: Length,
     height: Option<Length>,
+    is_indeterminate: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -40,6 +52,9 @@ where
     /// The default height of a [`ProgressBar`].
     pub const DEFAULT_HEIGHT: f32 = 30.0;
 
+    /// The duration of a sweep of an indeterminate [`ProgressBar`].
+    pub const SWEEP_DURATION: Duration = Duration::from_millis(1500);
+
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
@@ -51,10 +66,20 @@ where
             range,
             width: Length::Fill,
             height: None,
+            is_indeterminate: false,
             style: Default::default(),
         }
     }
 
+    /// Sets whether the [`ProgressBar`] is indeterminate.
+    ///
+    /// An indeterminate [`ProgressBar`] ignores its value and animates a
+    /// segment sweeping across it.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -82,6 +107,14 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -104,9 +137,35 @@ where
         layout::Node::new(size)
     }
 
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.is_indeterminate {
+                state.now = now;
+                let _ = state.started_at.get_or_insert(now);
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else {
+                state.started_at = None;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -136,7 +195,33 @@ where
             style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if self.is_indeterminate {
+            let state = tree.state.downcast_ref::<State>();
+
+            let (x, width) = sweep(
+                bounds.width,
+                state.elapsed().as_secs_f32()
+                    / Self::SWEEP_DURATION.as_secs_f32(),
+            );
+
+            if width > 0.0 {
+                renderer.with_layer(bounds, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + x,
+                                width,
+                                ..bounds
+                            },
+                            border_radius: style.border_radius.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        style.bar,
+                    );
+                });
+            }
+        } else if active_progress_width > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
                     bounds: Rectangle {
@@ -166,3 +251,70 @@ where
         Element::new(progress_bar)
     }
 }
+
+/// The local state of a [`ProgressBar`].
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    started_at: Option<Instant>,
+    now: Instant,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self {
+            started_at: None,
+            now: Instant::now(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.started_at
+            .map(|started_at| self.now.saturating_duration_since(started_at))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the offset and the width of the segment of an indeterminate
+/// [`ProgressBar`] of the given width, after the given amount of sweeps.
+///
+/// The segment enters from the left and leaves through the right, easing in
+/// and out.
+fn sweep(width: f32, sweeps: f32) -> (f32, f32) {
+    const SEGMENT: f32 = 0.3;
+
+    let t = sweeps.fract();
+    let t = t * t * (3.0 - 2.0 * t);
+
+    let segment = width * SEGMENT;
+    let start = -segment + t * (width + segment);
+
+    let left = start.max(0.0);
+    let right = (start + segment).min(width);
+
+    (left, (right - left).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweep_stays_inside_the_bar() {
+        for i in 0..=100 {
+            let (x, width) = sweep(200.0, i as f32 / 40.0);
+
+            assert!(x >= 0.0);
+            assert!(x + width <= 200.0);
+        }
+
+        assert_eq!(sweep(200.0, 0.0), (0.0, 0.0));
+        assert_eq!(sweep(200.0, 0.5), (70.0, 60.0));
+    }
+}
@@ -0,0 +1,454 @@
+//! Let your users rate something with a row of icons, like stars.
+//!
+//! A [`Rating`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+pub use iced_style::rating::{Appearance, StyleSheet};
+
+/// A row of icons displaying a value between zero and their amount.
+///
+/// Hovering the icons previews the value that would be chosen by clicking
+/// them. Once focused, the value can be adjusted with the arrow keys.
+///
+/// A [`Rating`] without [`on_change`] is read-only.
+///
+/// [`on_change`]: Self::on_change
+///
+/// # Example
+/// ```
+/// # type Rating<'a, Message> = iced_native::widget::Rating<'a, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rated(f32),
+/// }
+///
+/// let score = 3.5;
+///
+/// let rating = Rating::new(5, score)
+///     .half_steps(true)
+///     .on_change(Message::Rated);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    max: u8,
+    value: f32,
+    on_change: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    half_steps: bool,
+    icon: char,
+    size: f32,
+    spacing: f32,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Rating<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of the icons of a [`Rating`].
+    pub const DEFAULT_SIZE: f32 = 20.0;
+
+    /// The default icon of a [`Rating`].
+    pub const DEFAULT_ICON: char = '★';
+
+    /// Creates a new [`Rating`] with the given amount of icons, displaying
+    /// the given value.
+    pub fn new(max: u8, value: f32) -> Self {
+        Rating {
+            max,
+            value: value.clamp(0.0, f32::from(max)),
+            on_change: None,
+            half_steps: false,
+            icon: Self::DEFAULT_ICON,
+            size: Self::DEFAULT_SIZE,
+            spacing: 2.0,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the function producing a message when a value is chosen.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets whether halves of the icons can be chosen.
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// Sets the icon of the [`Rating`].
+    pub fn icon(mut self, icon: char) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the size of the icons of the [`Rating`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the icons of the [`Rating`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the icons of the [`Rating`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Rating`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn step(&self) -> f32 {
+        if self.half_steps {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    fn change(&self, value: f32, shell: &mut Shell<'_, Message>) {
+        if let Some(on_change) = &self.on_change {
+            let value = value.clamp(0.0, f32::from(self.max));
+
+            if value != self.value {
+                shell.publish(on_change(value));
+            }
+        }
+    }
+
+    fn value_at(&self, bounds: Rectangle, cursor_position: Point) -> f32 {
+        value_at(
+            cursor_position.x - bounds.x,
+            self.size,
+            self.spacing,
+            self.max,
+            self.half_steps,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Rating<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let count = f32::from(self.max);
+        let width = count * self.size + (count - 1.0).max(0.0) * self.spacing;
+
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(Size::new(width, self.size));
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.on_change.is_none() {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = bounds.contains(cursor_position);
+
+                if state.is_focused {
+                    self.change(self.value_at(bounds, cursor_position), shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        self.value - self.step()
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        self.value + self.step()
+                    }
+                    keyboard::KeyCode::Home => 0.0,
+                    keyboard::KeyCode::End => f32::from(self.max),
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                // A value between steps snaps to the nearest step first
+                let value = (value / self.step()).round() * self.step();
+
+                self.change(value, shell);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_change.is_some() && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        let preview = (self.on_change.is_some()
+            && bounds.contains(cursor_position))
+        .then(|| self.value_at(bounds, cursor_position));
+
+        let (value, filled_color) = match preview {
+            Some(preview) => (preview, appearance.preview_color),
+            None => (self.value, appearance.filled_color),
+        };
+
+        if state.is_focused {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x - 2.0,
+                        y: bounds.y - 2.0,
+                        width: bounds.width + 4.0,
+                        height: bounds.height + 4.0,
+                    },
+                    border_radius: 2.0.into(),
+                    border_width: 1.0,
+                    border_color: appearance.focused_border_color,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        let icon = self.icon.to_string();
+
+        for i in 0..self.max {
+            let icon_bounds = Rectangle {
+                x: bounds.x + f32::from(i) * (self.size + self.spacing),
+                y: bounds.y,
+                width: self.size,
+                height: self.size,
+            };
+
+            let fill = (value - f32::from(i)).clamp(0.0, 1.0);
+
+            let draw_icon = |renderer: &mut Renderer, color| {
+                renderer.fill_text(Text {
+                    content: &icon,
+                    bounds: Rectangle {
+                        x: icon_bounds.center_x(),
+                        y: icon_bounds.center_y(),
+                        ..icon_bounds
+                    },
+                    size: self.size,
+                    color,
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            };
+
+            if fill < 1.0 {
+                draw_icon(renderer, appearance.empty_color);
+            }
+
+            if fill >= 1.0 {
+                draw_icon(renderer, filled_color);
+            } else if fill > 0.0 {
+                // A partially filled icon is drawn on top of an empty one,
+                // clipped to the filled part
+                renderer.with_layer(
+                    Rectangle {
+                        width: icon_bounds.width * fill,
+                        ..icon_bounds
+                    },
+                    |renderer| draw_icon(renderer, filled_color),
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Rating<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        rating: Rating<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rating)
+    }
+}
+
+/// The local state of a [`Rating`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Returns the value chosen at the given horizontal offset of a [`Rating`].
+///
+/// The spacing after an icon belongs to it, so there are no gaps between
+/// the values.
+fn value_at(x: f32, size: f32, spacing: f32, max: u8, half_steps: bool) -> f32 {
+    let slot = size + spacing;
+    let index = (x / slot).floor().max(0.0);
+    let within = (x - index * slot) / size;
+
+    let value = if half_steps && within < 0.5 {
+        index + 0.5
+    } else {
+        index + 1.0
+    };
+
+    value.clamp(0.0, f32::from(max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_at_snaps_to_steps() {
+        assert_eq!(value_at(5.0, 20.0, 2.0, 5, false), 1.0);
+        assert_eq!(value_at(25.0, 20.0, 2.0, 5, false), 2.0);
+        assert_eq!(value_at(25.0, 20.0, 2.0, 5, true), 1.5);
+        assert_eq!(value_at(39.0, 20.0, 2.0, 5, true), 2.0);
+        assert_eq!(value_at(500.0, 20.0, 2.0, 5, true), 5.0);
+    }
+}
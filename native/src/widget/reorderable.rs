@@ -0,0 +1,779 @@
+//! Let your users sort a list by dragging its items around.
+//!
+//! A [`Reorderable`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::time::Instant;
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable::{self, Properties};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Alignment, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Vector, Widget,
+};
+
+pub use iced_style::reorderable::{Appearance, StyleSheet};
+
+/// A vertical list whose items can be dragged to new positions.
+///
+/// An item starts being dragged once the cursor moves a few pixels away
+/// from where it was pressed, unless the item captured the press itself.
+/// A line shows where it will be dropped. The list scrolls when it overflows,
+/// and dragging an item near its top or bottom edge scrolls it automatically.
+///
+/// The order of the items is owned by the application, which is notified
+/// through [`on_reorder`].
+///
+/// [`on_reorder`]: Self::on_reorder
+///
+/// # Example
+/// ```
+/// # type Reorderable<'a, Message> = iced_native::widget::Reorderable<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Reordered(usize, usize),
+/// }
+///
+/// let tasks = vec!["Write", "Review", "Ship"];
+///
+/// let list = Reorderable::with_children(
+///     tasks.iter().map(|task| Text::new(*task).into()).collect(),
+/// )
+/// .spacing(4)
+/// .on_reorder(Message::Reordered);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Reorderable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    children: Vec<Element<'a, Message, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    scrollbar: Properties,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    scrollable_style: <Renderer::Theme as scrollable::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Reorderable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    /// Creates an empty [`Reorderable`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Reorderable`] with the given items.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Reorderable {
+            children,
+            on_reorder: None,
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            scrollbar: Properties::default(),
+            style: Default::default(),
+            scrollable_style: Default::default(),
+        }
+    }
+
+    /// Adds an item to the [`Reorderable`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the message that should be produced when an item is dropped at
+    /// a new position.
+    ///
+    /// The function takes the index of the item and the index it should have
+    /// once moved. If it is not set, the items cannot be dragged.
+    pub fn on_reorder(
+        mut self,
+        f: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the vertical spacing between the items of the [`Reorderable`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Reorderable`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Reorderable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Reorderable`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Properties`] of the scrollbar of the [`Reorderable`].
+    pub fn scrollbar(mut self, properties: Properties) -> Self {
+        self.scrollbar = properties;
+        self
+    }
+
+    /// Sets the style of the [`Reorderable`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`Reorderable`].
+    pub fn scrollable_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as scrollable::StyleSheet>::Style>,
+    ) -> Self {
+        self.scrollable_style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Reorderable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The local state of a [`Reorderable`].
+#[derive(Debug, Clone)]
+pub struct State {
+    scrollable: scrollable::State,
+    drag: Option<Drag>,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self {
+            scrollable: scrollable::State::new(),
+            drag: None,
+            last_tick: None,
+        }
+    }
+
+    /// Returns the index of the item being dragged, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag
+            .filter(|drag| drag.is_dragging)
+            .map(|drag| drag.index)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An item that was pressed, and may be dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    /// Where the item was pressed, relative to the unscrolled content.
+    origin: Point,
+    is_dragging: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Reorderable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(drag) = state.drag {
+            if drag.index >= self.children.len() {
+                state.drag = None;
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        scrollable::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            false,
+            |renderer, limits| {
+                layout::flex::resolve(
+                    layout::flex::Axis::Vertical,
+                    renderer,
+                    &limits.width(self.width),
+                    self.padding,
+                    self.spacing,
+                    Alignment::Start,
+                    layout::flex::Distribution::Start,
+                    &self.children,
+                )
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+        let translation = state.scrollable.offset(bounds, content_bounds);
+
+        operation.scrollable_with_viewport(
+            &mut state.scrollable,
+            None,
+            bounds,
+            content_bounds,
+            translation,
+        );
+
+        operation.container_with_bounds(None, bounds, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(content.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let content_bounds = content.bounds();
+        let offset = state.scrollable.offset(bounds, content_bounds);
+        let content_cursor = cursor_position + offset;
+
+        if let Some(mut drag) = state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if !drag.is_dragging
+                        && drag.origin.distance(content_cursor)
+                            >= DRAG_THRESHOLD
+                    {
+                        drag.is_dragging = true;
+                        state.drag = Some(drag);
+                    }
+
+                    if drag.is_dragging {
+                        if auto_scroll_speed(bounds, cursor_position) != 0.0 {
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Window(window::Event::RedrawRequested(now))
+                    if drag.is_dragging =>
+                {
+                    let speed = auto_scroll_speed(bounds, cursor_position);
+
+                    if speed == 0.0 {
+                        state.last_tick = None;
+                    } else {
+                        if let Some(last_tick) = state.last_tick {
+                            let elapsed = (now - last_tick).as_secs_f32();
+
+                            state.scrollable.scroll(
+                                Vector::new(0.0, -speed * elapsed),
+                                bounds,
+                                content_bounds,
+                            );
+                        }
+
+                        state.last_tick = Some(now);
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.drag = None;
+                    state.last_tick = None;
+
+                    if drag.is_dragging {
+                        let slot = slot(
+                            content.children().map(|item| item.bounds()),
+                            content_cursor.y,
+                        );
+                        let to = destination(drag.index, slot);
+
+                        if let (Some(on_reorder), true) =
+                            (&self.on_reorder, to != drag.index)
+                        {
+                            shell.publish(on_reorder(drag.index, to));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        let status = scrollable::update(
+            &mut state.scrollable,
+            event,
+            layout,
+            cursor_position,
+            clipboard,
+            shell,
+            &self.scrollbar,
+            None,
+            None,
+            false,
+            false,
+            scrollable::WheelRouting::default(),
+            &[],
+            &None,
+            |event, content, cursor_position, clipboard, shell| {
+                self.children
+                    .iter_mut()
+                    .zip(&mut tree.children)
+                    .zip(content.children())
+                    .map(|((child, state), layout)| {
+                        child.as_widget_mut().on_event(
+                            state,
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        if is_press
+            && status == event::Status::Ignored
+            && self.on_reorder.is_some()
+            && bounds.contains(cursor_position)
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            let index = content.children().position(|item| {
+                let bounds = item.bounds();
+
+                content_cursor.y >= bounds.y
+                    && content_cursor.y < bounds.y + bounds.height
+            });
+
+            if let Some(index) = index {
+                state.drag = Some(Drag {
+                    index,
+                    origin: content_cursor,
+                    is_dragging: false,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragged().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            layout,
+            cursor_position,
+            &self.scrollbar,
+            None,
+            &[],
+            |content, cursor_position, viewport| {
+                let interaction = self
+                    .children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(content.children())
+                    .map(|((child, state), layout)| {
+                        child.as_widget().mouse_interaction(
+                            state,
+                            layout,
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default();
+
+                let is_over_item = content.children().any(|item| {
+                    let bounds = item.bounds();
+
+                    cursor_position.y >= bounds.y
+                        && cursor_position.y < bounds.y + bounds.height
+                });
+
+                if interaction == mouse::Interaction::default()
+                    && is_over_item
+                    && self.on_reorder.is_some()
+                {
+                    mouse::Interaction::Grab
+                } else {
+                    interaction
+                }
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let content_cursor =
+            cursor_position + state.scrollable.offset(bounds, content_bounds);
+
+        let dragged = state.drag.filter(|drag| drag.is_dragging);
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            &self.scrollbar,
+            None,
+            &[],
+            &self.scrollable_style,
+            |renderer, content, cursor_position, viewport| {
+                for (index, ((child, state), layout)) in self
+                    .children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(content.children())
+                    .enumerate()
+                {
+                    // The dragged item leaves a gap behind
+                    if dragged.map_or(false, |drag| drag.index == index) {
+                        continue;
+                    }
+
+                    child.as_widget().draw(
+                        state,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    );
+                }
+
+                let drag = match dragged {
+                    Some(drag) => drag,
+                    None => return,
+                };
+
+                let items: Vec<Rectangle> =
+                    content.children().map(|item| item.bounds()).collect();
+                let slot = slot(items.iter().copied(), content_cursor.y);
+
+                if appearance.indicator_width > 0.0 {
+                    let y = match (
+                        slot.checked_sub(1).and_then(|i| items.get(i)),
+                        items.get(slot),
+                    ) {
+                        (Some(above), Some(below)) => {
+                            (above.y + above.height + below.y) / 2.0
+                        }
+                        (Some(above), None) => above.y + above.height,
+                        (None, Some(below)) => below.y,
+                        (None, None) => content.bounds().y,
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: y - appearance.indicator_width / 2.0,
+                                height: appearance.indicator_width,
+                                ..content.bounds()
+                            },
+                            border_radius: (appearance.indicator_width / 2.0)
+                                .into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.indicator_color,
+                    );
+                }
+
+                let (child, state, layout) = match (
+                    self.children.get(drag.index),
+                    tree.children.get(drag.index),
+                    content.children().nth(drag.index),
+                ) {
+                    (Some(child), Some(state), Some(layout)) => {
+                        (child, state, layout)
+                    }
+                    _ => return,
+                };
+
+                let translation =
+                    Vector::new(0.0, content_cursor.y - drag.origin.y);
+
+                renderer.with_layer(*viewport, |renderer| {
+                    renderer.with_translation(translation, |renderer| {
+                        if let Some(background) = appearance.dragged_background
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: layout.bounds(),
+                                    border_radius: appearance
+                                        .dragged_border_radius
+                                        .into(),
+                                    border_width: 0.0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                background,
+                            );
+                        }
+
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        );
+                    });
+                });
+            },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let offset = state.scrollable.offset(bounds, content.bounds());
+
+        overlay::from_children(&mut self.children, tree, content, renderer)
+            .map(|overlay| overlay.translate(Vector::new(-offset.x, -offset.y)))
+    }
+}
+
+impl<'a, Message, Renderer> From<Reorderable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn from(reorderable: Reorderable<'a, Message, Renderer>) -> Self {
+        Element::new(reorderable)
+    }
+}
+
+/// Returns the position between the items where a dragged item would be
+/// dropped, given the vertical position of the cursor.
+///
+/// `0` is before the first item, and the amount of items is after the last
+/// one.
+fn slot(items: impl Iterator<Item = Rectangle>, y: f32) -> usize {
+    items.filter(|item| item.center_y() < y).count()
+}
+
+/// Returns the index an item moved from `from` to the given slot ends up at,
+/// once it is removed from its original position.
+fn destination(from: usize, slot: usize) -> usize {
+    if slot > from {
+        slot - 1
+    } else {
+        slot
+    }
+}
+
+/// Returns the speed, in pixels per second, at which a list should scroll
+/// while an item is dragged at the given position.
+///
+/// The speed is negative near the top edge, positive near the bottom edge,
+/// and grows as the cursor gets closer to the edge or past it.
+fn auto_scroll_speed(bounds: Rectangle, cursor_position: Point) -> f32 {
+    let edge = AUTO_SCROLL_EDGE.min(bounds.height / 2.0);
+
+    if edge <= 0.0 {
+        return 0.0;
+    }
+
+    let top = bounds.y + edge - cursor_position.y;
+    let bottom = cursor_position.y - (bounds.y + bounds.height - edge);
+
+    if top > 0.0 {
+        -AUTO_SCROLL_SPEED * (top / edge).min(2.0)
+    } else if bottom > 0.0 {
+        AUTO_SCROLL_SPEED * (bottom / edge).min(2.0)
+    } else {
+        0.0
+    }
+}
+
+/// The distance the cursor has to move before an item starts being dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The height of the regions near the edges of a [`Reorderable`] that scroll
+/// it while dragging.
+const AUTO_SCROLL_EDGE: f32 = 32.0;
+
+/// The scrolling speed, in pixels per second, when the cursor is right at an
+/// edge of a [`Reorderable`].
+const AUTO_SCROLL_SPEED: f32 = 400.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_between_the_items_under_the_cursor() {
+        let items = || {
+            (0..4).map(|i| Rectangle {
+                x: 0.0,
+                y: i as f32 * 20.0,
+                width: 100.0,
+                height: 20.0,
+            })
+        };
+
+        assert_eq!(slot(items(), 5.0), 0);
+        assert_eq!(slot(items(), 25.0), 1);
+        assert_eq!(slot(items(), 75.0), 4);
+
+        assert_eq!(destination(0, 4), 3);
+        assert_eq!(destination(3, 0), 0);
+        assert_eq!(destination(1, 1), 1);
+        assert_eq!(destination(1, 2), 1);
+    }
+}
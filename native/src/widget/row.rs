@@ -1,6 +1,6 @@
 //! Distribute content horizontally.
 use crate::event::{self, Event};
-use crate::layout::{self, Layout};
+use crate::layout::{self, flex::Distribution, Layout};
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
@@ -18,6 +18,7 @@ pub struct Row<'a, Message, Renderer> {
     width: Length,
     height: Length,
     align_items: Alignment,
+    distribution: Distribution,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -37,6 +38,7 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
             width: Length::Shrink,
             height: Length::Shrink,
             align_items: Alignment::Start,
+            distribution: Distribution::Start,
             children,
         }
     }
@@ -75,6 +77,13 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Distribution`] of the free horizontal space between the
+    /// contents of the [`Row`].
+    pub fn distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     pub fn push(
         mut self,
@@ -126,6 +135,7 @@ where
             self.padding,
             self.spacing,
             self.align_items,
+            self.distribution,
             &self.children,
         )
     }
@@ -5,17 +5,21 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Command, Element, Layout, Length, Pixels,
     Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 pub use iced_style::scrollable::StyleSheet;
-pub use operation::scrollable::RelativeOffset;
+pub use operation::scrollable::{
+    AbsoluteOffset, Easing, RelativeOffset, Transition,
+};
 
 pub mod style {
     //! The styles of a [`Scrollable`].
@@ -36,8 +40,14 @@ where
     height: Length,
     vertical: Properties,
     horizontal: Option<Properties>,
+    snap: Option<Snap>,
+    overscroll_bounce: bool,
+    scroll_anchoring: bool,
+    wheel_routing: WheelRouting,
+    sticky: Vec<usize>,
     content: Element<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
+    on_reach_end: Option<(f32, Box<dyn Fn() -> Message + 'a>)>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -53,8 +63,17 @@ where
             height: Length::Shrink,
             vertical: Properties::default(),
             horizontal: None,
+            snap: None,
+            overscroll_bounce: cfg!(any(
+                target_os = "macos",
+                target_os = "ios"
+            )),
+            scroll_anchoring: false,
+            wheel_routing: WheelRouting::default(),
+            sticky: Vec::new(),
             content: content.into(),
             on_scroll: None,
+            on_reach_end: None,
             style: Default::default(),
         }
     }
@@ -95,6 +114,72 @@ where
         self
     }
 
+    /// Sets a function to call when the [`Scrollable`] is scrolled within the
+    /// given `distance` of the vertical end of its content.
+    ///
+    /// The function is called once every time the end is approached, and
+    /// again only after the [`Scrollable`] leaves that distance or its content
+    /// grows. This makes it convenient to load more content on demand.
+    pub fn on_reach_end(
+        mut self,
+        distance: impl Into<Pixels>,
+        f: impl Fn() -> Message + 'a,
+    ) -> Self {
+        self.on_reach_end = Some((distance.into().0, Box::new(f)));
+        self
+    }
+
+    /// Sets the [`Snap`] points of the [`Scrollable`].
+    ///
+    /// Once a drag is released or a wheel scroll finishes, the [`Scrollable`]
+    /// animates to the nearest snap point.
+    pub fn snap(mut self, snap: Snap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] bounces back when a kinetic touch
+    /// scroll hits one of its edges.
+    ///
+    /// By default, it only bounces on platforms where it is customary.
+    pub fn overscroll_bounce(mut self, overscroll_bounce: bool) -> Self {
+        self.overscroll_bounce = overscroll_bounce;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] keeps its visible content in place
+    /// when children are inserted at the start of its content.
+    ///
+    /// This is useful for chat histories or logs that load older entries on
+    /// demand. Only the direct children of the content are tracked.
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Sets the [`WheelRouting`] of the [`Scrollable`], which decides when
+    /// wheel events reach the ancestors of a nested [`Scrollable`].
+    pub fn wheel_routing(mut self, wheel_routing: WheelRouting) -> Self {
+        self.wheel_routing = wheel_routing;
+        self
+    }
+
+    /// Marks the children of the content of the [`Scrollable`] with the given
+    /// indices as sticky headers.
+    ///
+    /// A sticky header pins to the top of the [`Scrollable`] while its section
+    /// is in view, until it is pushed out by the next one. Sticky headers are
+    /// drawn over the content, so they should have an opaque background.
+    pub fn sticky_headers(
+        mut self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        self.sticky = indices.into_iter().collect();
+        self.sticky.sort_unstable();
+        self.sticky.dedup();
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     pub fn style(
         mut self,
@@ -105,12 +190,40 @@ where
     }
 }
 
+/// The snap points of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Snap {
+    /// Snap to every multiple of the given amount of pixels.
+    Interval(f32),
+    /// Snap to the start of every child of the content.
+    Children,
+}
+
+/// The routing of wheel events in a [`Scrollable`].
+///
+/// It decides whether a [`Scrollable`] nested inside another one consumes a
+/// wheel event or leaves it to its ancestors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WheelRouting {
+    /// Consume every wheel event over the [`Scrollable`], even if it cannot
+    /// scroll any further.
+    Trap,
+    /// Consume wheel events only while the [`Scrollable`] can still scroll in
+    /// their direction, forwarding them to its ancestors at the edges.
+    #[default]
+    ForwardAtEdges,
+    /// Never scroll on wheel events, always forwarding them to the ancestors
+    /// of the [`Scrollable`].
+    Ignore,
+}
+
 /// Properties of a scrollbar within a [`Scrollable`].
 #[derive(Debug)]
 pub struct Properties {
     width: f32,
     margin: f32,
     scroller_width: f32,
+    auto_hide: bool,
 }
 
 impl Default for Properties {
@@ -119,6 +232,7 @@ impl Default for Properties {
             width: 10.0,
             margin: 0.0,
             scroller_width: 10.0,
+            auto_hide: false,
         }
     }
 }
@@ -148,6 +262,16 @@ impl Properties {
         self.scroller_width = scroller_width.into().0.max(1.0);
         self
     }
+
+    /// Sets whether the scrollbar of the [`Scrollable`] hides itself when
+    /// idle.
+    ///
+    /// An auto-hiding scrollbar fades in when the [`Scrollable`] is scrolled
+    /// or hovered, and fades out after a short period of inactivity.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -206,10 +330,21 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
-        operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let translation = state.offset(bounds, content_bounds);
 
-        operation.container(
+        operation.scrollable_with_viewport(
+            state,
             self.id.as_ref().map(|id| &id.0),
+            bounds,
+            content_bounds,
+            translation,
+        );
+
+        operation.container_with_bounds(
+            self.id.as_ref().map(|id| &id.0),
+            bounds,
             &mut |operation| {
                 self.content.as_widget().operate(
                     &mut tree.children[0],
@@ -231,7 +366,7 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        update(
+        let status = update(
             tree.state.downcast_mut::<State>(),
             event,
             layout,
@@ -240,6 +375,11 @@ where
             shell,
             &self.vertical,
             self.horizontal.as_ref(),
+            self.snap,
+            self.overscroll_bounce,
+            self.scroll_anchoring,
+            self.wheel_routing,
+            &self.sticky,
             &self.on_scroll,
             |event, layout, cursor_position, clipboard, shell| {
                 self.content.as_widget_mut().on_event(
@@ -252,7 +392,19 @@ where
                     shell,
                 )
             },
-        )
+        );
+
+        if let Some((distance, on_reach_end)) = &self.on_reach_end {
+            notify_on_reach_end(
+                tree.state.downcast_mut::<State>(),
+                layout,
+                *distance,
+                on_reach_end.as_ref(),
+                shell,
+            );
+        }
+
+        status
     }
 
     fn draw(
@@ -273,6 +425,7 @@ where
             cursor_position,
             &self.vertical,
             self.horizontal.as_ref(),
+            &self.sticky,
             &self.style,
             |renderer, layout, cursor_position, viewport| {
                 self.content.as_widget().draw(
@@ -302,6 +455,7 @@ where
             cursor_position,
             &self.vertical,
             self.horizontal.as_ref(),
+            &self.sticky,
             |layout, cursor_position, viewport| {
                 self.content.as_widget().mouse_interaction(
                     &tree.children[0],
@@ -388,6 +542,51 @@ pub fn snap_to<Message: 'static>(
     Command::widget(operation::scrollable::snap_to(id.0, offset))
 }
 
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// to the provided [`AbsoluteOffset`] along the x & y axis.
+pub fn scroll_to<Message: 'static>(
+    id: Id,
+    offset: AbsoluteOffset,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to(id.0, offset))
+}
+
+/// Produces a [`Command`] that smoothly scrolls the [`Scrollable`] with the
+/// given [`Id`] to the provided [`AbsoluteOffset`] along the x & y axis,
+/// following the given [`Transition`].
+pub fn animate_to<Message: 'static>(
+    id: Id,
+    offset: AbsoluteOffset,
+    transition: Transition,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::animate_to(id.0, offset, transition))
+}
+
+/// Produces a [`Command`] that scrolls every [`Scrollable`] containing the
+/// widget with the given [`Id`](widget::Id), so that it becomes visible.
+///
+/// The target must be a [`Container`](crate::widget::Container), a
+/// [`Scrollable`] or a [`TextInput`](crate::widget::TextInput) with the given
+/// [`Id`](widget::Id); wrap any other widget in a `Container` to find it.
+pub fn ensure_visible<Message: 'static>(
+    target: impl Into<widget::Id>,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::ensure_visible(target.into()))
+}
+
+/// Produces a [`Command`] that smoothly scrolls every [`Scrollable`]
+/// containing the widget with the given [`Id`](widget::Id), so that it
+/// becomes visible, following the given [`Transition`].
+pub fn ensure_visible_animated<Message: 'static>(
+    target: impl Into<widget::Id>,
+    transition: Transition,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::ensure_visible_animated(
+        target.into(),
+        transition,
+    ))
+}
+
 /// Computes the layout of a [`Scrollable`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -417,12 +616,14 @@ pub fn layout<Renderer>(
             },
             f32::MAX,
         ),
-    );
+    )
+    .with_direction(limits.direction());
 
     let content = layout_content(renderer, &child_limits);
     let size = limits.resolve(content.size());
 
     layout::Node::with_children(size, vec![content])
+        .with_direction(limits.direction())
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`Scrollable`]
@@ -436,6 +637,11 @@ pub fn update<Message>(
     shell: &mut Shell<'_, Message>,
     vertical: &Properties,
     horizontal: Option<&Properties>,
+    snap: Option<Snap>,
+    overscroll_bounce: bool,
+    scroll_anchoring: bool,
+    wheel_routing: WheelRouting,
+    sticky: &[usize],
     on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
     update_content: impl FnOnce(
         Event,
@@ -451,17 +657,66 @@ pub fn update<Message>(
     let content = layout.children().next().unwrap();
     let content_bounds = content.bounds();
 
-    let scrollbars =
-        Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
+    // Horizontal scrolling starts at the right edge in a right-to-left
+    // layout, unless the offset has already been set
+    if !state.is_aligned {
+        if horizontal.is_some()
+            && layout.direction() == layout::Direction::RightToLeft
+            && matches!(state.offset_x, Offset::Absolute(x) if x == 0.0)
+        {
+            state.offset_x = Offset::Relative(1.0);
+        }
+
+        state.is_aligned = true;
+    }
+
+    if scroll_anchoring {
+        anchor(state, content, bounds);
+    }
+
+    let scrollbars = Scrollbars::new(
+        state,
+        vertical,
+        horizontal,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
 
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor_position);
 
+    let auto_hide =
+        vertical.auto_hide || horizontal.map_or(false, |h| h.auto_hide);
+
+    if auto_hide {
+        let is_active = match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::WheelScrolled { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                mouse_over_scrollable || state.scrollers_grabbed()
+            }
+            _ => false,
+        };
+
+        if is_active {
+            state.show_scrollbars(Instant::now());
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
     let event_status = {
         let cursor_position = if mouse_over_scrollable
             && !(mouse_over_y_scrollbar || mouse_over_x_scrollbar)
         {
-            cursor_position + state.offset(bounds, content_bounds)
+            content_cursor_position(
+                cursor_position,
+                state.offset(bounds, content_bounds),
+                sticky,
+                content,
+                bounds,
+            )
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
             // cursor availability.
@@ -483,6 +738,166 @@ pub fn update<Message>(
         return event::Status::Captured;
     }
 
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if auto_hide && (state.animation.is_some() || state.momentum.is_some())
+        {
+            state.show_scrollbars(now);
+        }
+
+        if let Some(fade) = state.fade {
+            let fade = Fade { now, ..fade };
+
+            if fade.is_finished() {
+                state.fade = None;
+            } else {
+                state.fade = Some(fade);
+
+                shell.request_redraw(if fade.alpha() < 1.0 {
+                    window::RedrawRequest::NextFrame
+                } else {
+                    window::RedrawRequest::At(fade.hides_at())
+                });
+            }
+        }
+
+        if let Some((offset, transition)) = state.scroll_request.take() {
+            let max = Vector::new(
+                (content_bounds.width - bounds.width).max(0.0),
+                (content_bounds.height - bounds.height).max(0.0),
+            );
+
+            state.animation = Some(Animation::new(
+                state.absolute_offset(bounds, content_bounds),
+                Vector::new(
+                    offset.x.clamp(0.0, max.x),
+                    offset.y.clamp(0.0, max.y),
+                ),
+                now,
+                transition.duration,
+                transition.easing,
+            ));
+            state.momentum = None;
+        }
+
+        if let Some(bounce) = state.bounce {
+            let progress = (now.saturating_duration_since(bounce.started_at))
+                .as_secs_f32()
+                / BOUNCE_DURATION.as_secs_f32();
+
+            if progress >= 1.0 {
+                state.bounce = None;
+                state.overscroll = Vector::new(0.0, 0.0);
+            } else {
+                state.overscroll =
+                    bounce.peak * (progress * std::f32::consts::PI).sin();
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        if let Some(animation) = state.animation {
+            let offset = animation.offset(now);
+
+            state.offset_x = Offset::Absolute(offset.x);
+            state.offset_y = Offset::Absolute(offset.y);
+
+            if animation.is_finished(now) {
+                state.animation = None;
+            } else {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+        } else if let Some(momentum) = state.momentum {
+            let elapsed = now
+                .saturating_duration_since(momentum.updated_at)
+                .as_secs_f32();
+
+            let delta = momentum.velocity * elapsed;
+            let before = state.absolute_offset(bounds, content_bounds);
+
+            state.translate(delta, bounds, content_bounds);
+
+            let after = state.absolute_offset(bounds, content_bounds);
+            let mut velocity =
+                momentum.velocity * (-MOMENTUM_DECELERATION * elapsed).exp();
+
+            // Hitting an edge stops the momentum along that axis
+            let is_blocked = |before: f32, delta: f32, after: f32| {
+                (before - delta - after).abs() > 0.5
+            };
+
+            let mut peak = Vector::new(0.0, 0.0);
+
+            if content_bounds.width > bounds.width
+                && is_blocked(before.x, delta.x, after.x)
+            {
+                peak.x = -velocity.x;
+                velocity.x = 0.0;
+            }
+
+            if content_bounds.height > bounds.height
+                && is_blocked(before.y, delta.y, after.y)
+            {
+                peak.y = -velocity.y;
+                velocity.y = 0.0;
+            }
+
+            if overscroll_bounce && peak != Vector::new(0.0, 0.0) {
+                let clamp = |peak: f32| {
+                    (peak * BOUNCE_FACTOR)
+                        .clamp(-MAX_OVERSCROLL, MAX_OVERSCROLL)
+                };
+
+                state.bounce = Some(Bounce {
+                    peak: Vector::new(clamp(peak.x), clamp(peak.y)),
+                    started_at: now,
+                });
+            }
+
+            if velocity.x.hypot(velocity.y) < MIN_MOMENTUM_VELOCITY {
+                state.momentum = None;
+
+                snap_to_nearest(
+                    state,
+                    snap,
+                    content,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+            } else {
+                state.momentum = Some(Momentum {
+                    velocity,
+                    updated_at: now,
+                });
+            }
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+
+            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+        } else if let Some(scrolled_at) = state.wheel_scrolled_at {
+            if now >= scrolled_at + SNAP_DELAY {
+                state.wheel_scrolled_at = None;
+
+                snap_to_nearest(
+                    state,
+                    snap,
+                    content,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+            } else {
+                shell.request_redraw(window::RedrawRequest::At(
+                    scrolled_at + SNAP_DELAY,
+                ));
+            }
+        }
+
+        return event::Status::Ignored;
+    }
+
     if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event
     {
         state.keyboard_modifiers = modifiers;
@@ -492,7 +907,9 @@ pub fn update<Message>(
 
     if mouse_over_scrollable {
         match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if wheel_routing != WheelRouting::Ignore =>
+            {
                 let delta = match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
                         // TODO: Configurable speed/friction (?)
@@ -507,8 +924,26 @@ pub fn update<Message>(
                     mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
                 };
 
+                let before = state.absolute_offset(bounds, content_bounds);
+
                 state.scroll(delta, bounds, content_bounds);
 
+                if wheel_routing == WheelRouting::ForwardAtEdges
+                    && state.absolute_offset(bounds, content_bounds) == before
+                {
+                    return event::Status::Ignored;
+                }
+
+                if snap.is_some() {
+                    let now = Instant::now();
+
+                    state.wheel_scrolled_at = Some(now);
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + SNAP_DELAY,
+                    ));
+                }
+
                 notify_on_scroll(
                     state,
                     on_scroll,
@@ -526,6 +961,11 @@ pub fn update<Message>(
                 match event {
                     touch::Event::FingerPressed { .. } => {
                         state.scroll_area_touched_at = Some(cursor_position);
+                        state.touch_velocity = Vector::new(0.0, 0.0);
+                        state.touch_moved_at = Some(Instant::now());
+                        state.animation = None;
+                        state.momentum = None;
+                        state.scroll_request = None;
                     }
                     touch::Event::FingerMoved { .. } => {
                         if let Some(scroll_box_touched_at) =
@@ -541,6 +981,23 @@ pub fn update<Message>(
                             state.scroll_area_touched_at =
                                 Some(cursor_position);
 
+                            let now = Instant::now();
+
+                            if let Some(moved_at) = state.touch_moved_at {
+                                let elapsed = now
+                                    .saturating_duration_since(moved_at)
+                                    .as_secs_f32();
+
+                                if elapsed > 0.0 {
+                                    state.touch_velocity = state.touch_velocity
+                                        * (1.0 - VELOCITY_SMOOTHING)
+                                        + delta
+                                            * (VELOCITY_SMOOTHING / elapsed);
+                                }
+                            }
+
+                            state.touch_moved_at = Some(now);
+
                             notify_on_scroll(
                                 state,
                                 on_scroll,
@@ -553,6 +1010,40 @@ pub fn update<Message>(
                     touch::Event::FingerLifted { .. }
                     | touch::Event::FingerLost { .. } => {
                         state.scroll_area_touched_at = None;
+
+                        // A finger resting before release carries no momentum
+                        let is_resting =
+                            state.touch_moved_at.take().map_or(true, |at| {
+                                at.elapsed() > MOMENTUM_RESTING_TIMEOUT
+                            });
+
+                        let velocity = std::mem::replace(
+                            &mut state.touch_velocity,
+                            Vector::new(0.0, 0.0),
+                        );
+
+                        if !is_resting
+                            && velocity.x.hypot(velocity.y)
+                                >= MIN_MOMENTUM_VELOCITY
+                        {
+                            state.momentum = Some(Momentum {
+                                velocity,
+                                updated_at: Instant::now(),
+                            });
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        } else {
+                            snap_to_nearest(
+                                state,
+                                snap,
+                                content,
+                                bounds,
+                                content_bounds,
+                                shell,
+                            );
+                        }
                     }
                 }
 
@@ -569,6 +1060,15 @@ pub fn update<Message>(
             | Event::Touch(touch::Event::FingerLost { .. }) => {
                 state.y_scroller_grabbed_at = None;
 
+                snap_to_nearest(
+                    state,
+                    snap,
+                    content,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
                 return event::Status::Captured;
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
@@ -636,6 +1136,15 @@ pub fn update<Message>(
             | Event::Touch(touch::Event::FingerLost { .. }) => {
                 state.x_scroller_grabbed_at = None;
 
+                snap_to_nearest(
+                    state,
+                    snap,
+                    content,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
                 return event::Status::Captured;
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
@@ -706,6 +1215,7 @@ pub fn mouse_interaction(
     cursor_position: Point,
     vertical: &Properties,
     horizontal: Option<&Properties>,
+    sticky: &[usize],
     content_interaction: impl FnOnce(
         Layout<'_>,
         Point,
@@ -718,8 +1228,14 @@ pub fn mouse_interaction(
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
 
-    let scrollbars =
-        Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
+    let scrollbars = Scrollbars::new(
+        state,
+        vertical,
+        horizontal,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
 
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor_position);
@@ -734,7 +1250,13 @@ pub fn mouse_interaction(
         let cursor_position = if mouse_over_scrollable
             && !(mouse_over_y_scrollbar || mouse_over_x_scrollbar)
         {
-            cursor_position + offset
+            content_cursor_position(
+                cursor_position,
+                offset,
+                sticky,
+                content_layout,
+                bounds,
+            )
         } else {
             Point::new(-1.0, -1.0)
         };
@@ -760,8 +1282,9 @@ pub fn draw<Renderer>(
     cursor_position: Point,
     vertical: &Properties,
     horizontal: Option<&Properties>,
+    sticky: &[usize],
     style: &<Renderer::Theme as StyleSheet>::Style,
-    draw_content: impl FnOnce(&mut Renderer, Layout<'_>, Point, &Rectangle),
+    mut draw_content: impl FnMut(&mut Renderer, Layout<'_>, Point, &Rectangle),
 ) where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
@@ -770,8 +1293,14 @@ pub fn draw<Renderer>(
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
 
-    let scrollbars =
-        Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
+    let scrollbars = Scrollbars::new(
+        state,
+        vertical,
+        horizontal,
+        bounds,
+        content_bounds,
+        layout.direction(),
+    );
 
     let mouse_over_scrollable = bounds.contains(cursor_position);
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
@@ -782,7 +1311,13 @@ pub fn draw<Renderer>(
     let cursor_position = if mouse_over_scrollable
         && !(mouse_over_x_scrollbar || mouse_over_y_scrollbar)
     {
-        cursor_position + offset
+        content_cursor_position(
+            cursor_position,
+            offset,
+            sticky,
+            content_layout,
+            bounds,
+        )
     } else {
         Point::new(-1.0, -1.0)
     };
@@ -805,12 +1340,41 @@ pub fn draw<Renderer>(
                     );
                 },
             );
+
+            // Draw the pinned sticky header over the content
+            if let Some((pinned, shift)) =
+                sticky_header(sticky, content_layout, bounds, offset)
+            {
+                renderer.with_layer(pinned, |renderer| {
+                    renderer.with_translation(
+                        Vector::new(-offset.x, shift.y - offset.y),
+                        |renderer| {
+                            draw_content(
+                                renderer,
+                                content_layout,
+                                cursor_position,
+                                &Rectangle {
+                                    x: pinned.x + offset.x,
+                                    y: pinned.y + offset.y - shift.y,
+                                    ..pinned
+                                },
+                            );
+                        },
+                    );
+                });
+            }
         });
 
         let draw_scrollbar =
             |renderer: &mut Renderer,
              style: style::Scrollbar,
              scrollbar: &Scrollbar| {
+                if scrollbar.alpha <= 0.0 {
+                    return;
+                }
+
+                let style = fade(style, scrollbar.alpha);
+
                 //track
                 if style.background.is_some()
                     || (style.border_color != Color::TRANSPARENT
@@ -920,6 +1484,242 @@ fn notify_on_scroll<Message>(
     }
 }
 
+/// Starts animating the [`State`] of a [`Scrollable`] towards the snap point
+/// nearest to its current offset, if any.
+fn snap_to_nearest<Message>(
+    state: &mut State,
+    snap: Option<Snap>,
+    content: Layout<'_>,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    shell: &mut Shell<'_, Message>,
+) {
+    let snap = match snap {
+        Some(snap) => snap,
+        None => return,
+    };
+
+    let offset = state.absolute_offset(bounds, content_bounds);
+    let max = Vector::new(
+        (content_bounds.width - bounds.width).max(0.0),
+        (content_bounds.height - bounds.height).max(0.0),
+    );
+
+    let target = match snap {
+        Snap::Interval(interval) => {
+            let interval = interval.max(1.0);
+
+            let candidates = |offset: f32| {
+                [
+                    (offset / interval).floor() * interval,
+                    (offset / interval).ceil() * interval,
+                ]
+            };
+
+            Vector::new(
+                nearest(offset.x, max.x, candidates(offset.x)),
+                nearest(offset.y, max.y, candidates(offset.y)),
+            )
+        }
+        Snap::Children => Vector::new(
+            nearest(
+                offset.x,
+                max.x,
+                content
+                    .children()
+                    .map(|child| child.bounds().x - content_bounds.x),
+            ),
+            nearest(
+                offset.y,
+                max.y,
+                content
+                    .children()
+                    .map(|child| child.bounds().y - content_bounds.y),
+            ),
+        ),
+    };
+
+    if target != offset {
+        state.animation = Some(Animation::new(
+            offset,
+            target,
+            Instant::now(),
+            SNAP_DURATION,
+            Easing::EaseOut,
+        ));
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+/// Returns the bounds where the active sticky header of a [`Scrollable`] is
+/// pinned, together with the translation that moves it there from its place
+/// in the content.
+fn sticky_header(
+    sticky: &[usize],
+    content: Layout<'_>,
+    bounds: Rectangle,
+    offset: Vector,
+) -> Option<(Rectangle, Vector)> {
+    if sticky.is_empty() {
+        return None;
+    }
+
+    let children: Vec<Rectangle> =
+        content.children().map(|child| child.bounds()).collect();
+
+    // The top of the viewport, in content coordinates
+    let top = bounds.y + offset.y;
+
+    let mut active = None;
+    let mut next = None;
+
+    for header in sticky.iter().filter_map(|index| children.get(*index)) {
+        if header.y <= top {
+            active = Some(*header);
+        } else {
+            next = Some(*header);
+            break;
+        }
+    }
+
+    let header = active?;
+
+    // The next header pushes the active one out of the viewport
+    let pinned_y = next.map_or(top, |next| top.min(next.y - header.height));
+
+    if pinned_y <= header.y {
+        return None;
+    }
+
+    let pinned = Rectangle {
+        x: header.x - offset.x,
+        y: pinned_y - offset.y,
+        ..header
+    };
+
+    pinned
+        .intersection(&bounds)
+        .map(|pinned| (pinned, Vector::new(0.0, pinned_y - header.y)))
+}
+
+/// Translates the cursor position over a [`Scrollable`] into the coordinates
+/// of its content, taking the pinned sticky header into account.
+fn content_cursor_position(
+    cursor_position: Point,
+    offset: Vector,
+    sticky: &[usize],
+    content: Layout<'_>,
+    bounds: Rectangle,
+) -> Point {
+    match sticky_header(sticky, content, bounds, offset) {
+        Some((pinned, shift)) if pinned.contains(cursor_position) => {
+            cursor_position + offset - shift
+        }
+        _ => cursor_position + offset,
+    }
+}
+
+/// Returns the snap point nearest to the given offset, including the end of
+/// the scrollable range.
+fn nearest(
+    offset: f32,
+    max: f32,
+    snap_points: impl IntoIterator<Item = f32>,
+) -> f32 {
+    snap_points
+        .into_iter()
+        .map(|point| point.clamp(0.0, max))
+        .chain(std::iter::once(max))
+        .min_by(|a, b| (a - offset).abs().total_cmp(&(b - offset).abs()))
+        .unwrap_or(offset)
+}
+
+/// Adjusts the offset of the [`State`] of a [`Scrollable`] to compensate for
+/// any children inserted at the start of its content since the last call, and
+/// takes a new [`Snapshot`] of the content.
+fn anchor(state: &mut State, content: Layout<'_>, bounds: Rectangle) {
+    let content_bounds = content.bounds();
+    let children: Vec<Rectangle> = content
+        .children()
+        .map(|child| {
+            let bounds = child.bounds();
+
+            Rectangle {
+                y: bounds.y - content_bounds.y,
+                ..bounds
+            }
+        })
+        .collect();
+
+    if let (Some(snapshot), Offset::Absolute(offset)) =
+        (state.snapshot, state.offset_y)
+    {
+        let inserted = children.len().saturating_sub(snapshot.count);
+
+        if let (true, Some(anchor), Some(last)) = (
+            inserted > 0,
+            children.get(snapshot.anchor_index + inserted),
+            children.last(),
+        ) {
+            let delta = anchor.y - snapshot.anchor.y;
+
+            // The content only moved if both the anchor and the last child
+            // were shifted by the same amount
+            let is_shifted = (anchor.height - snapshot.anchor.height).abs()
+                < 0.5
+                && (last.height - snapshot.last.height).abs() < 0.5
+                && (last.y - snapshot.last.y - delta).abs() < 0.5;
+
+            if is_shifted && delta != 0.0 {
+                state.offset_y = Offset::Absolute(offset + delta);
+            }
+        }
+    }
+
+    let offset = state.absolute_offset(bounds, content_bounds).y;
+
+    state.snapshot = children
+        .iter()
+        .position(|child| child.y + child.height > offset)
+        .zip(children.last())
+        .map(|(anchor_index, last)| Snapshot {
+            count: children.len(),
+            anchor_index,
+            anchor: children[anchor_index],
+            last: *last,
+        });
+}
+
+/// Produces a message if the [`Scrollable`] has just come within the given
+/// `distance` of the vertical end of its content.
+fn notify_on_reach_end<Message>(
+    state: &mut State,
+    layout: Layout<'_>,
+    distance: f32,
+    on_reach_end: &dyn Fn() -> Message,
+    shell: &mut Shell<'_, Message>,
+) {
+    let bounds = layout.bounds();
+    let content_bounds = layout.children().next().unwrap().bounds();
+
+    let offset = state.absolute_offset(bounds, content_bounds);
+    let remaining = content_bounds.height - bounds.height - offset.y;
+
+    let has_reached_end = remaining <= distance;
+    let has_grown = state
+        .reached_end_at
+        .map_or(false, |height| content_bounds.height > height);
+
+    if has_reached_end && (state.reached_end_at.is_none() || has_grown) {
+        state.reached_end_at = Some(content_bounds.height);
+
+        shell.publish(on_reach_end());
+    } else if !has_reached_end {
+        state.reached_end_at = None;
+    }
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -929,6 +1729,18 @@ pub struct State {
     offset_x: Offset,
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
+    wheel_scrolled_at: Option<Instant>,
+    animation: Option<Animation>,
+    touch_velocity: Vector,
+    touch_moved_at: Option<Instant>,
+    momentum: Option<Momentum>,
+    bounce: Option<Bounce>,
+    overscroll: Vector,
+    reached_end_at: Option<f32>,
+    snapshot: Option<Snapshot>,
+    fade: Option<Fade>,
+    scroll_request: Option<(AbsoluteOffset, Transition)>,
+    is_aligned: bool,
 }
 
 impl Default for State {
@@ -940,6 +1752,18 @@ impl Default for State {
             offset_x: Offset::Absolute(0.0),
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
+            wheel_scrolled_at: None,
+            animation: None,
+            touch_velocity: Vector::new(0.0, 0.0),
+            touch_moved_at: None,
+            momentum: None,
+            bounce: None,
+            overscroll: Vector::new(0.0, 0.0),
+            reached_end_at: None,
+            snapshot: None,
+            fade: None,
+            scroll_request: None,
+            is_aligned: false,
         }
     }
 }
@@ -948,6 +1772,14 @@ impl operation::Scrollable for State {
     fn snap_to(&mut self, offset: RelativeOffset) {
         State::snap_to(self, offset);
     }
+
+    fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        State::scroll_to(self, offset);
+    }
+
+    fn animate_to(&mut self, offset: AbsoluteOffset, transition: Transition) {
+        State::animate_to(self, offset, transition);
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -969,6 +1801,77 @@ impl Offset {
     }
 }
 
+/// The children of the content of a [`Scrollable`] relevant to scroll
+/// anchoring, relative to the content.
+#[derive(Debug, Clone, Copy)]
+struct Snapshot {
+    count: usize,
+    /// The index of the first visible child.
+    anchor_index: usize,
+    anchor: Rectangle,
+    last: Rectangle,
+}
+
+/// The kinetic scrolling of a [`Scrollable`] after a touch is released.
+#[derive(Debug, Clone, Copy)]
+struct Momentum {
+    /// The velocity of the scroll, in pixels per second.
+    velocity: Vector,
+    updated_at: Instant,
+}
+
+/// The bounce of a [`Scrollable`] after a kinetic scroll hits an edge.
+#[derive(Debug, Clone, Copy)]
+struct Bounce {
+    peak: Vector,
+    started_at: Instant,
+}
+
+/// An animated transition between two absolute offsets of a [`Scrollable`].
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    from: Vector,
+    to: Vector,
+    started_at: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Animation {
+    fn new(
+        from: Vector,
+        to: Vector,
+        started_at: Instant,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            started_at,
+            duration,
+            easing,
+        }
+    }
+
+    fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.started_at);
+
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32().max(f32::EPSILON))
+            .min(1.0)
+    }
+
+    fn offset(&self, now: Instant) -> Vector {
+        let t = self.easing.apply(self.progress(now));
+
+        self.from + (self.to - self.from) * t
+    }
+
+    fn is_finished(&self, now: Instant) -> bool {
+        self.progress(now) >= 1.0
+    }
+}
+
 impl State {
     /// Creates a new [`State`] with the scrollbar(s) at the beginning.
     pub fn new() -> Self {
@@ -982,6 +1885,19 @@ impl State {
         delta: Vector<f32>,
         bounds: Rectangle,
         content_bounds: Rectangle,
+    ) {
+        self.animation = None;
+        self.momentum = None;
+        self.scroll_request = None;
+
+        self.translate(delta, bounds, content_bounds);
+    }
+
+    fn translate(
+        &mut self,
+        delta: Vector<f32>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
     ) {
         if bounds.height < content_bounds.height {
             self.offset_y = Offset::Absolute(
@@ -1011,6 +1927,8 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.animation = None;
+        self.scroll_request = None;
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1025,6 +1943,8 @@ impl State {
         content_bounds: Rectangle,
     ) {
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.animation = None;
+        self.scroll_request = None;
         self.unsnap(bounds, content_bounds);
     }
 
@@ -1032,6 +1952,28 @@ impl State {
     pub fn snap_to(&mut self, offset: RelativeOffset) {
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
+        self.animation = None;
+        self.scroll_request = None;
+    }
+
+    /// Scrolls the [`Scrollable`] to an [`AbsoluteOffset`].
+    pub fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.offset_x = Offset::Absolute(offset.x.max(0.0));
+        self.offset_y = Offset::Absolute(offset.y.max(0.0));
+        self.animation = None;
+        self.scroll_request = None;
+    }
+
+    /// Smoothly scrolls the [`Scrollable`] to an [`AbsoluteOffset`],
+    /// following the given [`Transition`].
+    ///
+    /// The animation starts on the next redraw.
+    pub fn animate_to(
+        &mut self,
+        offset: AbsoluteOffset,
+        transition: Transition,
+    ) {
+        self.scroll_request = Some((offset, transition));
     }
 
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
@@ -1051,6 +1993,14 @@ impl State {
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
+    ) -> Vector {
+        self.absolute_offset(bounds, content_bounds) + self.overscroll
+    }
+
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
     ) -> Vector {
         Vector::new(
             self.offset_x.absolute(bounds.width, content_bounds.width),
@@ -1058,11 +2008,99 @@ impl State {
         )
     }
 
+    /// Stops any scrolling in progress, keeping the current scroll position.
+    pub(crate) fn settle(&mut self) {
+        *self = Self {
+            offset_x: self.offset_x,
+            offset_y: self.offset_y,
+            is_aligned: self.is_aligned,
+            ..Self::default()
+        };
+    }
+
     /// Returns whether any scroller is currently grabbed or not.
     pub fn scrollers_grabbed(&self) -> bool {
         self.x_scroller_grabbed_at.is_some()
             || self.y_scroller_grabbed_at.is_some()
     }
+
+    /// Shows the auto-hiding scrollbars, restarting their idle timeout.
+    fn show_scrollbars(&mut self, now: Instant) {
+        self.fade = Some(match self.fade {
+            Some(fade) if !fade.is_finished() => Fade {
+                // Resume fading in from the current opacity
+                shown_at: now
+                    .checked_sub(SCROLLBAR_FADE_DURATION.mul_f32(fade.alpha()))
+                    .unwrap_or(now),
+                active_at: now,
+                now,
+            },
+            _ => Fade {
+                shown_at: now,
+                active_at: now,
+                now,
+            },
+        });
+    }
+
+    /// Returns the opacity of a scrollbar with the given [`Properties`].
+    fn scrollbar_alpha(&self, properties: &Properties) -> f32 {
+        if properties.auto_hide {
+            self.fade.map_or(0.0, |fade| fade.alpha())
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The visibility of the auto-hiding scrollbars of a [`Scrollable`].
+#[derive(Debug, Clone, Copy)]
+struct Fade {
+    shown_at: Instant,
+    active_at: Instant,
+    now: Instant,
+}
+
+impl Fade {
+    fn hides_at(&self) -> Instant {
+        self.active_at + SCROLLBAR_IDLE_TIMEOUT
+    }
+
+    fn alpha(&self) -> f32 {
+        let duration = SCROLLBAR_FADE_DURATION.as_secs_f32();
+
+        let fade_in = self.now.saturating_duration_since(self.shown_at);
+        let fade_out = self.now.saturating_duration_since(self.hides_at());
+
+        (fade_in.as_secs_f32() / duration)
+            .min(1.0 - fade_out.as_secs_f32() / duration)
+            .clamp(0.0, 1.0)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.now >= self.hides_at() + SCROLLBAR_FADE_DURATION
+    }
+}
+
+/// Applies the given opacity to the appearance of a scrollbar.
+fn fade(style: style::Scrollbar, alpha: f32) -> style::Scrollbar {
+    let fade = |color: Color| Color {
+        a: color.a * alpha,
+        ..color
+    };
+
+    style::Scrollbar {
+        background: style
+            .background
+            .map(|background| background.scale_alpha(alpha)),
+        border_color: fade(style.border_color),
+        scroller: style::Scroller {
+            color: fade(style.scroller.color),
+            border_color: fade(style.scroller.border_color),
+            ..style.scroller
+        },
+        ..style
+    }
 }
 
 #[derive(Debug)]
@@ -1080,6 +2118,7 @@ impl Scrollbars {
         horizontal: Option<&Properties>,
         bounds: Rectangle,
         content_bounds: Rectangle,
+        direction: layout::Direction,
     ) -> Self {
         let offset = state.offset(bounds, content_bounds);
 
@@ -1091,11 +2130,12 @@ impl Scrollbars {
             }
         });
 
-        let y_scrollbar = if content_bounds.height > bounds.height {
+        let mut y_scrollbar = if content_bounds.height > bounds.height {
             let Properties {
                 width,
                 margin,
                 scroller_width,
+                ..
             } = *vertical;
 
             // Adjust the height of the vertical scrollbar if the horizontal scrollbar
@@ -1145,16 +2185,18 @@ impl Scrollbars {
                 scroller: Scroller {
                     bounds: scroller_bounds,
                 },
+                alpha: state.scrollbar_alpha(vertical),
             })
         } else {
             None
         };
 
-        let x_scrollbar = if let Some(horizontal) = show_scrollbar_x {
+        let mut x_scrollbar = if let Some(horizontal) = show_scrollbar_x {
             let Properties {
                 width,
                 margin,
                 scroller_width,
+                ..
             } = *horizontal;
 
             // Need to adjust the width of the horizontal scrollbar if the vertical scrollbar
@@ -1205,11 +2247,37 @@ impl Scrollbars {
                 scroller: Scroller {
                     bounds: scroller_bounds,
                 },
+                alpha: state.scrollbar_alpha(horizontal),
             })
         } else {
             None
         };
 
+        // In a right-to-left layout, the vertical scrollbar sits on the left
+        if direction == layout::Direction::RightToLeft {
+            let mirror = |rectangle: Rectangle| Rectangle {
+                x: 2.0 * bounds.x + bounds.width
+                    - rectangle.x
+                    - rectangle.width,
+                ..rectangle
+            };
+
+            if let Some(scrollbar) = &mut y_scrollbar {
+                scrollbar.total_bounds = mirror(scrollbar.total_bounds);
+                scrollbar.bounds = mirror(scrollbar.bounds);
+                scrollbar.scroller.bounds = mirror(scrollbar.scroller.bounds);
+
+                if let Some(x_scrollbar) = &mut x_scrollbar {
+                    let shift = vertical.width.max(vertical.scroller_width)
+                        + vertical.margin;
+
+                    x_scrollbar.total_bounds.x += shift;
+                    x_scrollbar.bounds.x += shift;
+                    x_scrollbar.scroller.bounds.x += shift;
+                }
+            }
+        }
+
         Self {
             y: y_scrollbar,
             x: x_scrollbar,
@@ -1276,12 +2344,15 @@ struct Scrollbar {
 
     /// The state of this scrollbar's [`Scroller`].
     scroller: Scroller,
+
+    /// The opacity of the [`Scrollbar`].
+    alpha: f32,
 }
 
 impl Scrollbar {
     /// Returns whether the mouse is over the scrollbar or not.
     fn is_mouse_over(&self, cursor_position: Point) -> bool {
-        self.total_bounds.contains(cursor_position)
+        self.alpha > 0.0 && self.total_bounds.contains(cursor_position)
     }
 
     /// Returns the y-axis scrolled percentage from the cursor position.
@@ -1325,3 +2396,37 @@ struct Scroller {
     /// The bounds of the [`Scroller`].
     bounds: Rectangle,
 }
+
+/// The time a wheel scroll must be idle before it is considered finished.
+const SNAP_DELAY: Duration = Duration::from_millis(150);
+
+/// The duration of the animation to the nearest snap point.
+const SNAP_DURATION: Duration = Duration::from_millis(200);
+
+/// The exponential deceleration rate of a kinetic scroll, per second.
+const MOMENTUM_DECELERATION: f32 = 4.0;
+
+/// The velocity, in pixels per second, below which a kinetic scroll stops.
+const MIN_MOMENTUM_VELOCITY: f32 = 30.0;
+
+/// The time after which a finger at rest is released without momentum.
+const MOMENTUM_RESTING_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The weight of the latest movement in the tracked touch velocity.
+const VELOCITY_SMOOTHING: f32 = 0.8;
+
+/// The time, in seconds, of the velocity of a kinetic scroll that turns into
+/// overscroll when bouncing.
+const BOUNCE_FACTOR: f32 = 0.05;
+
+/// The maximum amount of pixels a [`Scrollable`] can overscroll.
+const MAX_OVERSCROLL: f32 = 48.0;
+
+/// The duration of an overscroll bounce.
+const BOUNCE_DURATION: Duration = Duration::from_millis(300);
+
+/// The time an auto-hiding scrollbar stays visible after the last activity.
+const SCROLLBAR_IDLE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// The duration of the fade in and out of an auto-hiding scrollbar.
+const SCROLLBAR_FADE_DURATION: Duration = Duration::from_millis(200);
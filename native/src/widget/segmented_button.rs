@@ -0,0 +1,511 @@
+//! Let your users choose one of a few options in a joined group of buttons.
+//!
+//! A [`SegmentedButton`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+use std::borrow::Cow;
+
+pub use iced_style::segmented_button::{Appearance, StyleSheet};
+
+/// The duration of the slide of the selection indicator of a
+/// [`SegmentedButton`] from one segment to another.
+pub const SLIDE_DURATION: Duration = Duration::from_millis(150);
+
+/// A group of joined segments, of which at most one is selected.
+///
+/// All the segments share the same width. The selection indicator slides
+/// to the newly selected segment whenever the selection changes.
+///
+/// # Example
+/// ```
+/// # type SegmentedButton<'a, T, Message> =
+/// #     iced_native::widget::SegmentedButton<'a, T, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum View {
+///     Day,
+///     Week,
+///     Month,
+/// }
+///
+/// # impl std::fmt::Display for View {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "{self:?}")
+/// #     }
+/// # }
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ViewSelected(View),
+/// }
+///
+/// let view = View::Week;
+///
+/// let segmented_button = SegmentedButton::new(
+///     &[View::Day, View::Week, View::Month][..],
+///     Some(view),
+///     Message::ViewSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SegmentedButton<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T: 'a, Message, Renderer> SegmentedButton<'a, T, Message, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of the segments of a [`SegmentedButton`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        right: 12.0,
+        bottom: 5.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`SegmentedButton`] with the given list of options, the
+    /// current selected value, and the message to produce when an option is
+    /// selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        SegmentedButton {
+            options: options.into(),
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`SegmentedButton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the segments of the [`SegmentedButton`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`SegmentedButton`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SegmentedButton`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SegmentedButton`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.options.iter().position(|option| option == selected)
+    }
+
+    fn segment_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if self.options.is_empty() || !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let width = bounds.width / self.options.len() as f32;
+        let index = ((cursor_position.x - bounds.x) / width) as usize;
+
+        Some(index.min(self.options.len() - 1))
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for SegmentedButton<'a, T, Message, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.selected_index()))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let widest = self
+            .options
+            .iter()
+            .map(|option| {
+                renderer.measure_width(
+                    &option.to_string(),
+                    text_size,
+                    self.font.clone(),
+                )
+            })
+            .fold(0.0, f32::max);
+
+        let segment = widest + self.padding.horizontal();
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let size = limits.resolve(Size::new(
+            segment * self.options.len() as f32,
+            text_size + self.padding.vertical(),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let selected = self.selected_index();
+
+                if state.selected != selected {
+                    state.selected = selected;
+
+                    // The indicator only slides between segments; it
+                    // appears and disappears at once
+                    state.transition = match (state.position, selected) {
+                        (Some(from), Some(_)) => Some(Transition {
+                            from,
+                            started_at: now,
+                        }),
+                        _ => None,
+                    };
+
+                    state.position = selected.map(|index| index as f32);
+                }
+
+                if let (Some(transition), Some(target)) =
+                    (state.transition, state.selected)
+                {
+                    let progress = ((now - transition.started_at)
+                        .as_secs_f32()
+                        / SLIDE_DURATION.as_secs_f32())
+                    .min(1.0);
+
+                    state.position = Some(
+                        transition.from
+                            + (target as f32 - transition.from)
+                                * ease(progress),
+                    );
+
+                    if progress < 1.0 {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        state.transition = None;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    self.segment_at(layout.bounds(), cursor_position)
+                {
+                    if Some(index) != self.selected_index() {
+                        shell.publish((self.on_select)(
+                            self.options[index].clone(),
+                        ));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.segment_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        if self.options.is_empty() {
+            return;
+        }
+
+        let count = self.options.len();
+        let segment_width = bounds.width / count as f32;
+        let inset = appearance.border_width;
+        let inner_radius = (appearance.border_radius - inset).max(0.0);
+
+        let segment_bounds = |position: f32| Rectangle {
+            x: bounds.x + position * segment_width + inset,
+            y: bounds.y + inset,
+            width: segment_width - inset * 2.0,
+            height: bounds.height - inset * 2.0,
+        };
+
+        // The position of the indicator, if any, measured in segments
+        let position = state.position;
+
+        if let (Some(hovered), Some(background)) = (
+            self.segment_at(bounds, cursor_position),
+            appearance.hovered_background,
+        ) {
+            if Some(hovered) != state.selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: segment_bounds(hovered as f32),
+                        border_radius: inner_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+        }
+
+        for i in 1..count {
+            // Dividers next to the indicator fade away as it gets closer
+            let alpha = position.map_or(1.0, |position| {
+                let distance = (i as f32 - position)
+                    .abs()
+                    .min((i as f32 - position - 1.0).abs());
+
+                distance.min(1.0)
+            });
+
+            if alpha <= 0.0 {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + i as f32 * segment_width - 0.5,
+                        y: bounds.y + inset,
+                        width: 1.0,
+                        height: bounds.height - inset * 2.0,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color(Color {
+                    a: appearance.divider_color.a * alpha,
+                    ..appearance.divider_color
+                }),
+            );
+        }
+
+        if let Some(position) = position {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: segment_bounds(position),
+                    border_radius: inner_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.indicator,
+            );
+        }
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (i, option) in self.options.iter().enumerate() {
+            let coverage = position.map_or(0.0, |position| {
+                (1.0 - (i as f32 - position).abs()).max(0.0)
+            });
+
+            let segment = segment_bounds(i as f32);
+
+            renderer.fill_text(Text {
+                content: &option.to_string(),
+                bounds: Rectangle {
+                    x: segment.center_x(),
+                    y: segment.center_y(),
+                    ..segment
+                },
+                size: text_size,
+                color: mix(
+                    appearance.text_color,
+                    appearance.selected_text_color,
+                    coverage,
+                ),
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer>
+    From<SegmentedButton<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        segmented_button: SegmentedButton<'a, T, Message, Renderer>,
+    ) -> Self {
+        Element::new(segmented_button)
+    }
+}
+
+/// The local state of a [`SegmentedButton`].
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    selected: Option<usize>,
+    position: Option<f32>,
+    transition: Option<Transition>,
+}
+
+impl State {
+    /// Creates a new [`State`] of a [`SegmentedButton`] at rest on the
+    /// given selected segment.
+    pub fn new(selected: Option<usize>) -> Self {
+        Self {
+            selected,
+            position: selected.map(|index| index as f32),
+            transition: None,
+        }
+    }
+
+    /// Returns whether the selection indicator of the [`SegmentedButton`]
+    /// is sliding.
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from: f32,
+    started_at: Instant,
+}
+
+/// Eases the given progress of a transition in and out.
+fn ease(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
@@ -0,0 +1,510 @@
+//! Display text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input::{Cursor, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::borrow::Cow;
+
+pub use iced_style::selectable_text::{Appearance, StyleSheet};
+
+/// A paragraph of read-only text that can be selected and copied.
+///
+/// The text is never wrapped; every line of the content is laid out as-is.
+///
+/// # Example
+///
+/// ```
+/// # type SelectableText<'a> = iced_native::widget::SelectableText<'a, iced_native::renderer::Null>;
+/// #
+/// SelectableText::new("3f2a-77c1-b0e4").size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Cow<'a, str>,
+    size: Option<f32>,
+    width: Length,
+    height: Length,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> SelectableText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`SelectableText`] with the given contents.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        SelectableText {
+            content: content.into(),
+            size: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font.clone(),
+            Size::INFINITY,
+        );
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &self.content,
+            self.size,
+            &self.font,
+            tree.state.downcast_mut::<State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            style,
+            layout,
+            tree.state.downcast_ref::<State>(),
+            &self.content,
+            self.size,
+            &self.font,
+            theme.appearance(&self.style),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
+
+/// The state of a [`SelectableText`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`], without any selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current selection of the [`SelectableText`] with the
+    /// given content, as a range of graphemes.
+    pub fn selection(&self, content: &str) -> Option<(usize, usize)> {
+        self.cursor.selection(&Value::new(content))
+    }
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`SelectableText`]
+/// accordingly.
+pub fn update<Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    content: &str,
+    size: Option<f32>,
+    font: &Renderer::Font,
+    state: &mut State,
+) -> event::Status
+where
+    Renderer: text::Renderer,
+{
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if !layout.bounds().contains(cursor_position) {
+                state.is_focused = false;
+                state.cursor.move_to(0);
+
+                return event::Status::Ignored;
+            }
+
+            let value = Value::new(content);
+            let position = find_position(
+                renderer,
+                layout.bounds(),
+                content,
+                size,
+                font.clone(),
+                cursor_position,
+            );
+
+            let click = mouse::Click::new(cursor_position, state.last_click);
+
+            match click.kind() {
+                click::Kind::Single => {
+                    if state.is_focused && state.keyboard_modifiers.shift() {
+                        state
+                            .cursor
+                            .select_range(state.cursor.start(&value), position);
+                    } else {
+                        state.cursor.move_to(position);
+                    }
+
+                    state.is_dragging = true;
+                }
+                click::Kind::Double => {
+                    state.cursor.select_range(
+                        value.previous_start_of_word(position),
+                        value.next_end_of_word(position),
+                    );
+
+                    state.is_dragging = false;
+                }
+                click::Kind::Triple => {
+                    state.cursor.select_all(&value);
+                    state.is_dragging = false;
+                }
+            }
+
+            state.is_focused = true;
+            state.last_click = Some(click);
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_dragging = false;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            if state.is_dragging {
+                let value = Value::new(content);
+                let position = find_position(
+                    renderer,
+                    layout.bounds(),
+                    content,
+                    size,
+                    font.clone(),
+                    position,
+                );
+
+                state
+                    .cursor
+                    .select_range(state.cursor.start(&value), position);
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused && state.keyboard_modifiers.command() =>
+        {
+            let value = Value::new(content);
+
+            match key_code {
+                keyboard::KeyCode::C => {
+                    if let Some((start, end)) = state.cursor.selection(&value) {
+                        clipboard.write(value.select(start, end).to_string());
+                    }
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::A => {
+                    state.cursor.select_all(&value);
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.keyboard_modifiers = modifiers;
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws a [`SelectableText`] and its selection.
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    state: &State,
+    content: &str,
+    size: Option<f32>,
+    font: &Renderer::Font,
+    appearance: Appearance,
+) where
+    Renderer: text::Renderer,
+{
+    let bounds = layout.bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    if let Some((start, end)) = state.cursor.selection(&Value::new(content)) {
+        let line_height = line_height(renderer, content, size, font.clone());
+        let mut line_start = 0;
+
+        for (i, line) in lines(content).enumerate() {
+            let line = Value::new(line);
+            let line_end = line_start + line.len();
+
+            let left = start.clamp(line_start, line_end) - line_start;
+            let right = end.clamp(line_start, line_end) - line_start;
+
+            if right > left {
+                let left_x = renderer.measure_width(
+                    &line.until(left).to_string(),
+                    size,
+                    font.clone(),
+                );
+                let right_x = renderer.measure_width(
+                    &line.until(right).to_string(),
+                    size,
+                    font.clone(),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + left_x,
+                            y: bounds.y + i as f32 * line_height,
+                            width: right_x - left_x,
+                            height: line_height,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selection_color,
+                );
+            }
+
+            // Line breaks are a single grapheme, even `\r\n`
+            line_start = line_end + 1;
+        }
+    }
+
+    renderer.fill_text(Text {
+        content,
+        size,
+        bounds: Rectangle {
+            width: f32::INFINITY,
+            ..bounds
+        },
+        color: appearance.color.unwrap_or(style.text_color),
+        font: font.clone(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+    });
+}
+
+/// Computes the current [`mouse::Interaction`] of a [`SelectableText`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> mouse::Interaction {
+    if layout.bounds().contains(cursor_position) {
+        mouse::Interaction::Text
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+fn lines(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+fn line_height<Renderer>(
+    renderer: &Renderer,
+    content: &str,
+    size: f32,
+    font: Renderer::Font,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let (_, height) = renderer.measure(content, size, font, Size::INFINITY);
+
+    (height / lines(content).count() as f32).max(1.0)
+}
+
+/// Computes the grapheme of the content of a [`SelectableText`] closest to
+/// the given point.
+fn find_position<Renderer>(
+    renderer: &Renderer,
+    bounds: Rectangle,
+    content: &str,
+    size: Option<f32>,
+    font: Renderer::Font,
+    point: Point,
+) -> usize
+where
+    Renderer: text::Renderer,
+{
+    let size = size.unwrap_or_else(|| renderer.default_size());
+    let line_height = line_height(renderer, content, size, font.clone());
+
+    let row = ((point.y - bounds.y) / line_height).max(0.0) as usize;
+    let row = row.min(lines(content).count() - 1);
+
+    let mut position = 0;
+
+    for (i, line) in lines(content).enumerate() {
+        let line_length = Value::new(line).len();
+
+        if i < row {
+            position += line_length + 1;
+            continue;
+        }
+
+        let column = renderer
+            .hit_test(
+                line,
+                size,
+                font,
+                Size::INFINITY,
+                Point::new(point.x - bounds.x, line_height / 2.0),
+                true,
+            )
+            .map(text::Hit::cursor)
+            .unwrap_or(0);
+
+        return position + column.min(line_length);
+    }
+
+    position
+}
@@ -0,0 +1,122 @@
+//! Draw custom shaders in your user interface.
+use crate::layout;
+use crate::renderer;
+use crate::shader;
+use crate::widget::Tree;
+use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
+
+pub use shader::Handle;
+
+/// A widget that draws a custom shader inside its bounds.
+///
+/// It is useful to draw audio visualizers, shader toys and custom effects.
+/// Animations can be driven by passing the elapsed time as a uniform.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::widget::shader::{Handle, Shader};
+/// #
+/// let handle = Handle::from_wgsl(
+///     "
+///     @group(0) @binding(1) var<uniform> time: vec4<f32>;
+///
+///     @fragment
+///     fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+///         return vec4<f32>(input.uv, sin(time.x) * 0.5 + 0.5, 1.0);
+///     }
+///     ",
+/// );
+///
+/// let shader = Shader::new(handle).uniforms(vec![1.5, 0.0, 0.0, 0.0]);
+/// ```
+#[derive(Debug)]
+pub struct Shader {
+    handle: Handle,
+    uniforms: Vec<f32>,
+    width: Length,
+    height: Length,
+}
+
+impl Shader {
+    /// Creates a new [`Shader`] from the given [`Handle`].
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Shader {
+            handle: handle.into(),
+            uniforms: Vec::new(),
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets the uniforms bound to the [`Shader`].
+    ///
+    /// They must match the layout of the uniforms declared in its source.
+    pub fn uniforms(mut self, uniforms: impl Into<Vec<f32>>) -> Self {
+        self.uniforms = uniforms.into();
+        self
+    }
+
+    /// Sets the width of the [`Shader`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Shader`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Shader
+where
+    Renderer: shader::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        renderer.draw(self.handle.clone(), bounds, self.uniforms.clone());
+    }
+}
+
+impl<'a, Message, Renderer> From<Shader> for Element<'a, Message, Renderer>
+where
+    Renderer: shader::Renderer,
+{
+    fn from(shader: Shader) -> Element<'a, Message, Renderer> {
+        Element::new(shader)
+    }
+}
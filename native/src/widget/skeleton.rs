@@ -0,0 +1,433 @@
+//! Display shimmering placeholders while some content loads.
+use crate::event::{self, Event};
+use crate::gradient;
+use crate::layout;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+pub use iced_style::skeleton::{Appearance, StyleSheet};
+
+/// A stack of placeholder shapes with a shimmer sweeping across them, to be
+/// displayed in place of some content while it loads.
+///
+/// # Example
+/// ```
+/// # type Skeleton = iced_native::widget::Skeleton<iced_native::renderer::Null>;
+/// use iced_native::widget::skeleton::Shape;
+/// use iced_native::Length;
+///
+/// let placeholder = Skeleton::new()
+///     .push(Shape::Circle(40.0))
+///     .push(Shape::Bar(Length::Fill))
+///     .push(Shape::Bar(Length::Fixed(120.0)));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Skeleton<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    shapes: Vec<Shape>,
+    width: Length,
+    spacing: f32,
+    bar_height: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A placeholder shape of a [`Skeleton`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// A bar with the given width, standing for a line of text.
+    Bar(Length),
+    /// A block with the given width and height, standing for an image or a
+    /// paragraph.
+    Block(Length, f32),
+    /// A circle with the given diameter, standing for an avatar or an icon.
+    Circle(f32),
+}
+
+impl<Renderer> Skeleton<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default height of the bars of a [`Skeleton`].
+    pub const DEFAULT_BAR_HEIGHT: f32 = 12.0;
+
+    /// The duration of a sweep of the shimmer of a [`Skeleton`].
+    pub const SHIMMER_DURATION: Duration = Duration::from_millis(1500);
+
+    /// Creates a new [`Skeleton`] without any shapes.
+    pub fn new() -> Self {
+        Skeleton {
+            shapes: Vec::new(),
+            width: Length::Fill,
+            spacing: 8.0,
+            bar_height: Self::DEFAULT_BAR_HEIGHT,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Shape`] under the ones of the [`Skeleton`].
+    pub fn push(mut self, shape: Shape) -> Self {
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Adds the given amount of bars filling the width of the [`Skeleton`],
+    /// standing for a paragraph of text.
+    pub fn lines(mut self, count: usize) -> Self {
+        self.shapes
+            .extend(std::iter::repeat(Shape::Bar(Length::Fill)).take(count));
+        self
+    }
+
+    /// Sets the width of the [`Skeleton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the shapes of the [`Skeleton`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the height of the bars of the [`Skeleton`].
+    pub fn bar_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.bar_height = height.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Skeleton`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Renderer> Default for Skeleton<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Skeleton<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let shape_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(limits.max().width, f32::INFINITY),
+        )
+        .with_direction(limits.direction());
+
+        let mut y = 0.0;
+        let mut max_width: f32 = 0.0;
+
+        let children: Vec<_> = self
+            .shapes
+            .iter()
+            .map(|shape| {
+                let size = match *shape {
+                    Shape::Bar(width) => shape_limits
+                        .width(width)
+                        .height(Length::Fixed(self.bar_height))
+                        .resolve(Size::ZERO),
+                    Shape::Block(width, height) => shape_limits
+                        .width(width)
+                        .height(Length::Fixed(height))
+                        .resolve(Size::ZERO),
+                    Shape::Circle(diameter) => Size::new(diameter, diameter),
+                };
+
+                let mut node = layout::Node::new(size);
+                node.move_to(Point::new(0.0, y));
+
+                y += size.height + self.spacing;
+                max_width = max_width.max(size.width);
+
+                node
+            })
+            .collect();
+
+        let height = if children.is_empty() {
+            0.0
+        } else {
+            y - self.spacing
+        };
+
+        let size = limits.resolve(Size::new(max_width, height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+            let _ = state.started_at.get_or_insert(now);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        // Every shape shares the same shimmer, sweeping across the whole
+        // skeleton
+        let band_width = (bounds.width * SHIMMER_WIDTH).max(1.0);
+        let progress = (state.elapsed().as_secs_f32()
+            / Self::SHIMMER_DURATION.as_secs_f32())
+        .fract();
+        let band_center = bounds.x - band_width / 2.0
+            + progress * (bounds.width + band_width);
+
+        let color_at = |intensity: f32| {
+            mix(appearance.color, appearance.highlight, intensity)
+        };
+
+        for (shape, layout) in self.shapes.iter().zip(layout.children()) {
+            let shape_bounds = layout.bounds();
+
+            // Circles are too small to display a part of the shimmer, so they
+            // glow as it passes over them
+            if let Shape::Circle(diameter) = shape {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: shape_bounds,
+                        border_radius: (diameter / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color_at(intensity(
+                        shape_bounds.center_x(),
+                        band_center,
+                        band_width,
+                    )),
+                );
+
+                continue;
+            }
+
+            let radius = appearance
+                .border_radius
+                .min(shape_bounds.height / 2.0)
+                .min(shape_bounds.width / 2.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: shape_bounds,
+                    border_radius: radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.color,
+            );
+
+            let left = shape_bounds.x;
+            let right = shape_bounds.x + shape_bounds.width;
+
+            for (start, end) in segments(left, right, band_center, band_width) {
+                let start_intensity = intensity(start, band_center, band_width);
+                let end_intensity = intensity(end, band_center, band_width);
+
+                if start_intensity == 0.0 && end_intensity == 0.0 {
+                    continue;
+                }
+
+                let left_radius = if start == left { radius } else { 0.0 };
+                let right_radius = if end == right { radius } else { 0.0 };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: start,
+                            width: end - start,
+                            ..shape_bounds
+                        },
+                        border_radius: [
+                            left_radius,
+                            right_radius,
+                            right_radius,
+                            left_radius,
+                        ]
+                        .into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    gradient::Linear::horizontal(
+                        color_at(start_intensity),
+                        color_at(end_intensity),
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Skeleton<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(skeleton: Skeleton<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(skeleton)
+    }
+}
+
+/// The local state of a [`Skeleton`].
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    started_at: Option<Instant>,
+    now: Instant,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self {
+            started_at: None,
+            now: Instant::now(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.started_at
+            .map(|started_at| self.now.saturating_duration_since(started_at))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The width of the shimmer of a [`Skeleton`], relative to its width.
+const SHIMMER_WIDTH: f32 = 0.4;
+
+/// Returns the intensity of the shimmer at the given horizontal position,
+/// from `0.0` outside of it to `1.0` at its center.
+fn intensity(x: f32, band_center: f32, band_width: f32) -> f32 {
+    (1.0 - (x - band_center).abs() / (band_width / 2.0)).max(0.0)
+}
+
+/// Splits the horizontal span between `left` and `right` where the intensity
+/// of the shimmer changes its slope, so every segment can be filled with a
+/// linear gradient.
+fn segments(
+    left: f32,
+    right: f32,
+    band_center: f32,
+    band_width: f32,
+) -> Vec<(f32, f32)> {
+    let half = band_width / 2.0;
+
+    let mut points = vec![left];
+
+    points.extend(
+        [band_center - half, band_center, band_center + half]
+            .into_iter()
+            .filter(|x| *x > left && *x < right),
+    );
+
+    points.push(right);
+
+    points.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shimmer_is_split_where_its_slope_changes() {
+        assert_eq!(
+            segments(0.0, 100.0, 50.0, 40.0),
+            vec![(0.0, 30.0), (30.0, 50.0), (50.0, 70.0), (70.0, 100.0)]
+        );
+        assert_eq!(
+            segments(0.0, 100.0, 110.0, 40.0),
+            vec![(0.0, 90.0), (90.0, 100.0)]
+        );
+        assert_eq!(segments(0.0, 100.0, 200.0, 40.0), vec![(0.0, 100.0)]);
+
+        assert_eq!(intensity(50.0, 50.0, 40.0), 1.0);
+        assert_eq!(intensity(60.0, 50.0, 40.0), 0.5);
+        assert_eq!(intensity(80.0, 50.0, 40.0), 0.0);
+    }
+}
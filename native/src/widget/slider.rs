@@ -175,7 +175,7 @@ where
         let limits = limits.width(self.width).height(self.height);
         let size = limits.resolve(Size::ZERO);
 
-        layout::Node::new(size)
+        layout::Node::new(size).with_direction(limits.direction())
     }
 
     fn on_event(
@@ -277,17 +277,24 @@ where
 
     let mut change = || {
         let bounds = layout.bounds();
-        let new_value = if cursor_position.x <= bounds.x {
+
+        // The range starts at the right edge in a right-to-left layout
+        let distance = if layout.direction() == layout::Direction::RightToLeft {
+            bounds.x + bounds.width - cursor_position.x
+        } else {
+            cursor_position.x - bounds.x
+        };
+
+        let new_value = if distance <= 0.0 {
             *range.start()
-        } else if cursor_position.x >= bounds.x + bounds.width {
+        } else if distance >= bounds.width {
             *range.end()
         } else {
             let step = step.into();
             let start = (*range.start()).into();
             let end = (*range.end()).into();
 
-            let percent = f64::from(cursor_position.x - bounds.x)
-                / f64::from(bounds.width);
+            let percent = f64::from(distance) / f64::from(bounds.width);
 
             let steps = (percent * (end - start) / step).round();
             let value = steps * step + start;
@@ -425,6 +432,13 @@ pub fn draw<T, R>(
             / (range_end - range_start)
     };
 
+    let handle_offset = if layout.direction() == layout::Direction::RightToLeft
+    {
+        bounds.width - handle_width - handle_offset
+    } else {
+        handle_offset
+    };
+
     renderer.fill_quad(
         renderer::Quad {
             bounds: Rectangle {
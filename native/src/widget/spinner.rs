@@ -0,0 +1,250 @@
+//! Show a circular spinner while some work of unknown duration is ongoing.
+//!
+//! A [`Spinner`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+pub use iced_style::spinner::{Appearance, StyleSheet};
+
+/// A ring of dots rotating endlessly.
+///
+/// A [`Spinner`] animates by itself, requesting a redraw on every frame for
+/// as long as it is displayed.
+///
+/// # Example
+/// ```
+/// # type Spinner = iced_native::widget::Spinner<iced_native::renderer::Null>;
+/// let spinner = Spinner::new().size(32);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    size: f32,
+    dots: usize,
+    period: Duration,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of a [`Spinner`].
+    pub const DEFAULT_SIZE: f32 = 24.0;
+
+    /// Creates a new [`Spinner`].
+    pub fn new() -> Self {
+        Spinner {
+            size: Self::DEFAULT_SIZE,
+            dots: 8,
+            period: Duration::from_millis(1000),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`Spinner`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the amount of dots of the [`Spinner`].
+    pub fn dots(mut self, dots: usize) -> Self {
+        self.dots = dots.max(1);
+        self
+    }
+
+    /// Sets the duration of a full turn of the [`Spinner`].
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Renderer> Default for Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.size).height(self.size);
+
+        layout::Node::new(limits.resolve(Size::new(self.size, self.size)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let dot_radius = (radius * 0.18).max(1.0);
+        let center = bounds.center();
+
+        let turns = if self.period.is_zero() {
+            0.0
+        } else {
+            (state.now.saturating_duration_since(state.started_at))
+                .as_secs_f32()
+                / self.period.as_secs_f32()
+        };
+
+        // The leading dot advances from one position to the next, so the
+        // dots themselves stay in place
+        let leading = (turns.fract() * self.dots as f32) as usize;
+
+        for i in 0..self.dots {
+            let angle = std::f32::consts::TAU * i as f32 / self.dots as f32
+                - std::f32::consts::FRAC_PI_2;
+
+            let distance = (leading + self.dots - i) % self.dots;
+            let t = distance as f32 / self.dots as f32;
+
+            let color = mix(appearance.color, appearance.track_color, t);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x + (radius - dot_radius) * angle.cos()
+                            - dot_radius,
+                        y: center.y + (radius - dot_radius) * angle.sin()
+                            - dot_radius,
+                        width: dot_radius * 2.0,
+                        height: dot_radius * 2.0,
+                    },
+                    border_radius: dot_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Spinner<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(spinner: Spinner<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
+
+/// The local state of a [`Spinner`].
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    started_at: Instant,
+    now: Instant,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        let now = Instant::now();
+
+        Self {
+            started_at: now,
+            now,
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
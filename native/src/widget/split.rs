@@ -0,0 +1,606 @@
+//! Display two widgets side by side, with a draggable divider between them.
+//!
+//! A [`Split`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+pub use crate::widget::pane_grid::Axis;
+pub use iced_style::split::{Appearance, StyleSheet};
+
+/// Two widgets sharing some space, with a divider between them that can be
+/// dragged to resize them.
+///
+/// The position of the divider is a ratio of the available space owned by
+/// the application, which is notified when it is dragged.
+///
+/// A [`collapsible`] pane collapses to the edge once the divider is dragged
+/// halfway past its minimum size.
+///
+/// [`collapsible`]: Self::collapsible
+///
+/// # Example
+/// ```
+/// # type Split<'a, Message> = iced_native::widget::Split<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Resized(f32),
+/// }
+///
+/// let ratio = 0.3;
+///
+/// let split = Split::new(Text::new("Sidebar"), Text::new("Content"), ratio, Message::Resized)
+///     .min_size_first(120)
+///     .collapsible(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Split<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    panes: [Element<'a, Message, Renderer>; 2],
+    ratio: f32,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    axis: Axis,
+    width: Length,
+    height: Length,
+    spacing: f32,
+    min_size_first: f32,
+    min_size_second: f32,
+    is_collapsible: bool,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Split<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default thickness of the divider of a [`Split`].
+    pub const DEFAULT_SPACING: f32 = 6.0;
+
+    /// Creates a new [`Split`] with the given panes, dividing the available
+    /// space at the given ratio.
+    ///
+    /// The given function produces a message with the new ratio when the
+    /// divider is dragged.
+    pub fn new<F>(
+        first: impl Into<Element<'a, Message, Renderer>>,
+        second: impl Into<Element<'a, Message, Renderer>>,
+        ratio: f32,
+        on_resize: F,
+    ) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        Split {
+            panes: [first.into(), second.into()],
+            ratio: ratio.clamp(0.0, 1.0),
+            on_resize: Box::new(on_resize),
+            axis: Axis::Vertical,
+            width: Length::Fill,
+            height: Length::Fill,
+            spacing: Self::DEFAULT_SPACING,
+            min_size_first: 0.0,
+            min_size_second: 0.0,
+            is_collapsible: false,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Axis`] of the divider of the [`Split`].
+    ///
+    /// By default, the divider is vertical and the panes are side by side.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the width of the [`Split`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Split`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the thickness of the divider of the [`Split`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the minimum size of the first pane of the [`Split`].
+    pub fn min_size_first(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.min_size_first = min_size.into().0;
+        self
+    }
+
+    /// Sets the minimum size of the second pane of the [`Split`].
+    pub fn min_size_second(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.min_size_second = min_size.into().0;
+        self
+    }
+
+    /// Sets whether the panes of the [`Split`] collapse to the edge when the
+    /// divider is dragged past their minimum size.
+    ///
+    /// A collapsed pane has a ratio of `0.0` or `1.0`.
+    pub fn collapsible(mut self, is_collapsible: bool) -> Self {
+        self.is_collapsible = is_collapsible;
+        self
+    }
+
+    /// Sets the style of the [`Split`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn limits(&self) -> Limits {
+        Limits {
+            min_first: self.min_size_first,
+            min_second: self.min_size_second,
+            is_collapsible: self.is_collapsible,
+        }
+    }
+
+    /// Returns the sizes of the panes along the axis of the [`Split`], given
+    /// its whole size.
+    fn pane_sizes(&self, size: Size) -> (f32, f32) {
+        let length = match self.axis {
+            Axis::Horizontal => size.height,
+            Axis::Vertical => size.width,
+        };
+
+        let available = (length - self.spacing).max(0.0);
+        let first = self.limits().first_size(self.ratio, available);
+
+        (first, available - first)
+    }
+
+    /// Returns whether each pane of the [`Split`] has some space.
+    fn is_expanded(&self, layout: Layout<'_>) -> [bool; 2] {
+        let (first, second) = self.pane_sizes(layout.bounds().size());
+
+        [first > 0.0, second > 0.0]
+    }
+
+    fn divider_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+        let (first, _) = self.pane_sizes(bounds.size());
+
+        match self.axis {
+            Axis::Horizontal => Rectangle {
+                y: bounds.y + first,
+                height: self.spacing,
+                ..bounds
+            },
+            Axis::Vertical => Rectangle {
+                x: bounds.x + first,
+                width: self.spacing,
+                ..bounds
+            },
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Split<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.panes.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.panes)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        let (first, second) = self.pane_sizes(size);
+
+        let (first_size, second_size, offset) = match self.axis {
+            Axis::Horizontal => (
+                Size::new(size.width, first),
+                Size::new(size.width, second),
+                Vector::new(0.0, first + self.spacing),
+            ),
+            Axis::Vertical => (
+                Size::new(first, size.height),
+                Size::new(second, size.height),
+                Vector::new(first + self.spacing, 0.0),
+            ),
+        };
+
+        let first = self.panes[0].as_widget().layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, first_size)
+                .with_direction(limits.direction()),
+        );
+
+        let mut second = self.panes[1].as_widget().layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, second_size)
+                .with_direction(limits.direction()),
+        );
+        second.move_to(Point::ORIGIN + offset);
+
+        layout::Node::with_children(size, vec![first, second])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.panes
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((pane, state), layout)| {
+                    pane.as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let divider = self.divider_bounds(layout);
+        let is_expanded = self.is_expanded(layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if divider.contains(cursor_position) =>
+            {
+                // The offset of the cursor in the divider is kept, so the
+                // divider does not jump when picked
+                state.drag_offset = Some(match self.axis {
+                    Axis::Horizontal => cursor_position.y - divider.y,
+                    Axis::Vertical => cursor_position.x - divider.x,
+                });
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag_offset) = state.drag_offset {
+                    let bounds = layout.bounds();
+
+                    let (position, length) = match self.axis {
+                        Axis::Horizontal => {
+                            (cursor_position.y - bounds.y, bounds.height)
+                        }
+                        Axis::Vertical => {
+                            (cursor_position.x - bounds.x, bounds.width)
+                        }
+                    };
+
+                    let available = (length - self.spacing).max(0.0);
+
+                    if available > 0.0 {
+                        let ratio = self
+                            .limits()
+                            .drag_ratio(position - drag_offset, available);
+
+                        if ratio != self.ratio {
+                            shell.publish((self.on_resize)(ratio));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag_offset.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.panes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(is_expanded)
+            .filter(|(_, is_expanded)| *is_expanded)
+            .map(|(((pane, state), layout), _)| {
+                pane.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag_offset.is_some()
+            || self.divider_bounds(layout).contains(cursor_position)
+        {
+            return match self.axis {
+                Axis::Horizontal => mouse::Interaction::ResizingVertically,
+                Axis::Vertical => mouse::Interaction::ResizingHorizontally,
+            };
+        }
+
+        self.panes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(self.is_expanded(layout))
+            .filter(|(_, is_expanded)| *is_expanded)
+            .map(|(((pane, state), layout), _)| {
+                pane.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for (((pane, state), layout), is_expanded) in self
+            .panes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(self.is_expanded(layout))
+        {
+            if !is_expanded {
+                continue;
+            }
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                pane.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+
+        let divider = self.divider_bounds(layout);
+
+        let appearance = if state.drag_offset.is_some() {
+            theme.dragging(&self.style)
+        } else if divider.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let line = match self.axis {
+            Axis::Horizontal => Rectangle {
+                y: (divider.center_y() - appearance.divider_width / 2.0)
+                    .round(),
+                height: appearance.divider_width,
+                ..divider
+            },
+            Axis::Vertical => Rectangle {
+                x: (divider.center_x() - appearance.divider_width / 2.0)
+                    .round(),
+                width: appearance.divider_width,
+                ..divider
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: line,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.divider_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.panes, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Split<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(split: Split<'a, Message, Renderer>) -> Self {
+        Element::new(split)
+    }
+}
+
+/// The local state of a [`Split`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    drag_offset: Option<f32>,
+}
+
+impl State {
+    /// Returns whether the divider of the [`Split`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag_offset.is_some()
+    }
+}
+
+/// The constraints on the sizes of the panes of a [`Split`].
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    min_first: f32,
+    min_second: f32,
+    is_collapsible: bool,
+}
+
+impl Limits {
+    /// Returns the size of the first pane, given the ratio and the space
+    /// available for both panes.
+    fn first_size(&self, ratio: f32, available: f32) -> f32 {
+        if self.is_collapsible && ratio <= 0.0 {
+            return 0.0;
+        }
+
+        if self.is_collapsible && ratio >= 1.0 {
+            return available;
+        }
+
+        // The first pane wins when both minimum sizes do not fit
+        (ratio * available)
+            .min(available - self.min_second)
+            .max(self.min_first)
+            .clamp(0.0, available)
+            .round()
+    }
+
+    /// Returns the ratio of a divider dragged to the given position, given
+    /// the space available for both panes.
+    fn drag_ratio(&self, position: f32, available: f32) -> f32 {
+        let second = available - position;
+
+        if self.is_collapsible && position < self.min_first / 2.0 {
+            return 0.0;
+        }
+
+        if self.is_collapsible && second < self.min_second / 2.0 {
+            return 1.0;
+        }
+
+        // A ratio of exactly zero or one would collapse a pane
+        let size = self.first_size(position / available, available);
+
+        (size / available).clamp(f32::EPSILON, 1.0 - f32::EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dragging_respects_minimum_sizes() {
+        let limits = Limits {
+            min_first: 100.0,
+            min_second: 50.0,
+            is_collapsible: false,
+        };
+
+        assert_eq!(limits.first_size(0.5, 400.0), 200.0);
+        assert_eq!(limits.first_size(0.1, 400.0), 100.0);
+        assert_eq!(limits.first_size(0.95, 400.0), 350.0);
+        assert_eq!(limits.drag_ratio(20.0, 400.0), 0.25);
+    }
+
+    #[test]
+    fn dragging_past_half_the_minimum_size_collapses() {
+        let limits = Limits {
+            min_first: 100.0,
+            min_second: 50.0,
+            is_collapsible: true,
+        };
+
+        assert_eq!(limits.drag_ratio(60.0, 400.0), 0.25);
+        assert_eq!(limits.drag_ratio(40.0, 400.0), 0.0);
+        assert_eq!(limits.drag_ratio(390.0, 400.0), 1.0);
+        assert_eq!(limits.first_size(0.0, 400.0), 0.0);
+        assert_eq!(limits.first_size(1.0, 400.0), 400.0);
+    }
+}
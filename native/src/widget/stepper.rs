@@ -0,0 +1,565 @@
+//! Let your users step through numeric values with a pair of compact buttons.
+//!
+//! A [`Stepper`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+use num_traits::Num;
+
+use std::fmt::Display;
+
+pub use iced_style::stepper::{Appearance, StyleSheet};
+
+/// A compact `- value +` control stepping through numeric values.
+///
+/// Unlike a [`NumberInput`], the value of a [`Stepper`] cannot be typed.
+/// Holding a button down steps repeatedly, faster and faster.
+///
+/// [`NumberInput`]: crate::widget::NumberInput
+///
+/// # Example
+/// ```
+/// # pub type Stepper<'a, T, Message> = iced_native::widget::Stepper<'a, T, Message, iced_native::renderer::Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ZoomChanged(u32),
+/// }
+///
+/// let zoom = 100;
+///
+/// let stepper = Stepper::new(zoom, Message::ZoomChanged)
+///     .bounds(10..=400)
+///     .step(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Stepper<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    font: Renderer::Font,
+    padding: Padding,
+    size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> Stepper<'a, T, Message, Renderer>
+where
+    T: Num + PartialOrd + Copy + Display,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The delay before a held button of a [`Stepper`] starts repeating.
+    pub const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+    /// Creates a new [`Stepper`].
+    ///
+    /// It expects:
+    /// - the current value, and
+    /// - a function that produces a message when the value changes.
+    pub fn new<F>(value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Stepper {
+            value,
+            min: None,
+            max: None,
+            step: T::one(),
+            on_change: Box::new(on_change),
+            font: Default::default(),
+            padding: Padding::new(3.0),
+            size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum value of the [`Stepper`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`Stepper`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets both the minimum and maximum values of the [`Stepper`].
+    pub fn bounds(self, bounds: std::ops::RangeInclusive<T>) -> Self {
+        let (min, max) = bounds.into_inner();
+
+        self.min(min).max(max)
+    }
+
+    /// Sets the step size of the [`Stepper`].
+    ///
+    /// By default, it is `1`.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Stepper`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Stepper`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Stepper`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the [`Stepper`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn can_step(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Decrement => {
+                !matches!(self.min, Some(min) if self.value <= min)
+            }
+            Direction::Increment => {
+                !matches!(self.max, Some(max) if self.value >= max)
+            }
+        }
+    }
+
+    fn step_by(&self, direction: Direction, shell: &mut Shell<'_, Message>) {
+        let value = match direction {
+            Direction::Decrement => self.value - self.step,
+            Direction::Increment => self.value + self.step,
+        };
+
+        let value = match (self.min, self.max) {
+            (Some(min), _) if value < min => min,
+            (_, Some(max)) if value > max => max,
+            _ => value,
+        };
+
+        shell.publish((self.on_change)(value));
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Stepper<'a, T, Message, Renderer>
+where
+    T: Num + PartialOrd + Copy + Display,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let side = text_size + self.padding.vertical();
+
+        // The bounds are measured too, so the width does not change with
+        // the value
+        let value_width = [Some(self.value), self.min, self.max]
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                renderer.measure_width(
+                    &value.to_string(),
+                    text_size,
+                    self.font.clone(),
+                )
+            })
+            .fold(0.0, f32::max)
+            .ceil()
+            + self.padding.horizontal();
+
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(Size::new(value_width + side * 2.0, side));
+
+        let value_width = (size.width - side * 2.0).max(0.0);
+
+        let decrement = layout::Node::new(Size::new(side, side));
+
+        let mut value = layout::Node::new(Size::new(value_width, side));
+        value.move_to(Point::new(side, 0.0));
+
+        let mut increment = layout::Node::new(Size::new(side, side));
+        increment.move_to(Point::new(side + value_width, 0.0));
+
+        layout::Node::with_children(
+            Size::new(value_width + side * 2.0, side),
+            vec![decrement, value, increment],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let decrement_layout = children.next().unwrap();
+        let _value_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        let hovered_button =
+            if decrement_layout.bounds().contains(cursor_position) {
+                Some(Direction::Decrement)
+            } else if increment_layout.bounds().contains(cursor_position) {
+                Some(Direction::Increment)
+            } else {
+                None
+            };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(direction) = hovered_button {
+                    if self.can_step(direction) {
+                        self.step_by(direction, shell);
+
+                        let next = Instant::now() + Self::REPEAT_DELAY;
+
+                        state.pressed = Some(Pressed {
+                            direction,
+                            next,
+                            repeats: 0,
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::At(next));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.pressed.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                let direction = if y > 0.0 {
+                    Direction::Increment
+                } else {
+                    Direction::Decrement
+                };
+
+                if y != 0.0 && self.can_step(direction) {
+                    self.step_by(direction, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(pressed) = &mut state.pressed {
+                    if !self.can_step(pressed.direction) {
+                        state.pressed = None;
+
+                        return event::Status::Ignored;
+                    }
+
+                    if now >= pressed.next {
+                        // The repetition pauses while the cursor is away
+                        // from the held button
+                        if hovered_button == Some(pressed.direction) {
+                            self.step_by(pressed.direction, shell);
+
+                            pressed.repeats += 1;
+                        }
+
+                        pressed.next = now + repeat_interval(pressed.repeats);
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::At(
+                        pressed.next,
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style);
+        let bounds = layout.bounds();
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let mut children = layout.children();
+        let decrement_layout = children.next().unwrap();
+        let value_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        for (layout, label, direction) in [
+            (decrement_layout, "-", Direction::Decrement),
+            (increment_layout, "+", Direction::Increment),
+        ] {
+            let bounds = layout.bounds();
+            let is_enabled = self.can_step(direction);
+
+            let button_appearance = if !is_enabled {
+                appearance
+            } else if state.pressed.map(|pressed| pressed.direction)
+                == Some(direction)
+            {
+                theme.pressed(&self.style)
+            } else if bounds.contains(cursor_position) {
+                theme.hovered(&self.style)
+            } else {
+                appearance
+            };
+
+            if let Some(background) = button_appearance.button_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + appearance.border_width,
+                            y: bounds.y + appearance.border_width,
+                            width: bounds.width - appearance.border_width * 2.0,
+                            height: bounds.height
+                                - appearance.border_width * 2.0,
+                        },
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: if is_enabled {
+                    button_appearance.button_color
+                } else {
+                    appearance.disabled_button_color
+                },
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        let value_bounds = value_layout.bounds();
+
+        // The value is delimited from the buttons by the border
+        for x in [value_bounds.x, value_bounds.x + value_bounds.width] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - appearance.border_width / 2.0,
+                        width: appearance.border_width,
+                        ..value_bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color(appearance.border_color),
+            );
+        }
+
+        renderer.fill_text(Text {
+            content: &self.value.to_string(),
+            bounds: Rectangle {
+                x: value_bounds.center_x(),
+                y: value_bounds.center_y(),
+                ..value_bounds
+            },
+            size: text_size,
+            color: appearance.value_color,
+            font: self.font.clone(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let decrement_layout = children.next().unwrap();
+        let _value_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        if (self.can_step(Direction::Decrement)
+            && decrement_layout.bounds().contains(cursor_position))
+            || (self.can_step(Direction::Increment)
+                && increment_layout.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Stepper<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Num + PartialOrd + Copy + Display,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        stepper: Stepper<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stepper)
+    }
+}
+
+/// The local state of a [`Stepper`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed: Option<Pressed>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a button of the [`Stepper`] is being held down.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pressed {
+    direction: Direction,
+    next: Instant,
+    repeats: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Decrement,
+    Increment,
+}
+
+/// Returns the interval before the next step of a held button, given the
+/// amount of steps it has already repeated.
+///
+/// The interval shrinks with every repetition, down to a minimum.
+fn repeat_interval(repeats: u32) -> Duration {
+    const INITIAL: f32 = 150.0;
+    const MINIMUM: f32 = 20.0;
+    const ACCELERATION: f32 = 0.9;
+
+    let millis = INITIAL * ACCELERATION.powi(repeats.min(64) as i32);
+
+    Duration::from_millis(millis.max(MINIMUM) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_interval_accelerates() {
+        assert_eq!(repeat_interval(0), Duration::from_millis(150));
+        assert!(repeat_interval(5) < repeat_interval(1));
+        assert_eq!(repeat_interval(1000), Duration::from_millis(20));
+    }
+}
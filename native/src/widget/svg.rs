@@ -4,7 +4,7 @@ use crate::renderer;
 use crate::svg;
 use crate::widget::Tree;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Widget,
 };
 
 use std::path::PathBuf;
@@ -153,10 +153,7 @@ where
         let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
 
         let render = |renderer: &mut Renderer| {
-            let offset = Vector::new(
-                (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
-                (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
-            );
+            let offset = self.content_fit.offset(adjusted_fit, bounds.size());
 
             let drawing_bounds = Rectangle {
                 width: adjusted_fit.width,
@@ -165,12 +162,15 @@ where
             };
 
             let appearance = theme.appearance(&self.style);
+            let variables = theme.variables(&self.style);
 
-            renderer.draw(
-                self.handle.clone(),
-                appearance.color,
-                drawing_bounds + offset,
-            );
+            let handle = if variables.is_empty() {
+                self.handle.clone()
+            } else {
+                self.handle.with_variables(&variables)
+            };
+
+            renderer.draw(handle, appearance.color, drawing_bounds + offset);
         };
 
         if adjusted_fit.width > bounds.width
@@ -0,0 +1,751 @@
+//! Reveal actions or dismiss some content by swiping it horizontally.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A row that can be swiped horizontally to reveal some actions under it or
+/// to dismiss it entirely.
+///
+/// # Example
+/// ```
+/// # type Swipeable<'a, Message> =
+/// #     iced_native::widget::Swipeable<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Archive,
+/// }
+///
+/// let row = Swipeable::new(Text::new("Groceries"))
+///     .trailing(Text::new("Archive"))
+///     .on_dismiss(|_side| Message::Archive);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Swipeable<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    leading: Option<Element<'a, Message, Renderer>>,
+    trailing: Option<Element<'a, Message, Renderer>>,
+    on_reveal: Option<Box<dyn Fn(Side) -> Message + 'a>>,
+    on_dismiss: Option<Box<dyn Fn(Side) -> Message + 'a>>,
+    dismiss_threshold: f32,
+    duration: Duration,
+}
+
+/// The side of a [`Swipeable`] uncovered by a swipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The leading side, uncovered by swiping towards the right.
+    Leading,
+    /// The trailing side, uncovered by swiping towards the left.
+    Trailing,
+}
+
+impl<'a, Message, Renderer> Swipeable<'a, Message, Renderer> {
+    /// The default duration of the settle animation of a [`Swipeable`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Swipeable`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Swipeable {
+            content: content.into(),
+            leading: None,
+            trailing: None,
+            on_reveal: None,
+            on_dismiss: None,
+            dismiss_threshold: 0.5,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Sets the actions revealed by swiping the [`Swipeable`] towards the
+    /// right.
+    pub fn leading(
+        mut self,
+        actions: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.leading = Some(actions.into());
+        self
+    }
+
+    /// Sets the actions revealed by swiping the [`Swipeable`] towards the
+    /// left.
+    pub fn trailing(
+        mut self,
+        actions: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.trailing = Some(actions.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Swipeable`] settles with the
+    /// actions of the given [`Side`] revealed.
+    pub fn on_reveal(
+        mut self,
+        on_reveal: impl Fn(Side) -> Message + 'a,
+    ) -> Self {
+        self.on_reveal = Some(Box::new(on_reveal));
+        self
+    }
+
+    /// Sets the message produced when the [`Swipeable`] is swiped away
+    /// towards the given [`Side`].
+    ///
+    /// The [`Swipeable`] can only be dismissed when this is set.
+    pub fn on_dismiss(
+        mut self,
+        on_dismiss: impl Fn(Side) -> Message + 'a,
+    ) -> Self {
+        self.on_dismiss = Some(Box::new(on_dismiss));
+        self
+    }
+
+    /// Sets the fraction of its width the [`Swipeable`] needs to be swiped
+    /// past for it to be dismissed once released.
+    ///
+    /// By default, it is `0.5`.
+    pub fn dismiss_threshold(mut self, threshold: f32) -> Self {
+        self.dismiss_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the duration of the animation settling the [`Swipeable`] once
+    /// released.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    fn actions(&self) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.leading.iter().chain(self.trailing.iter())
+    }
+
+    /// Returns the layouts of the content and the actions of the
+    /// [`Swipeable`].
+    fn layouts<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> (Layout<'b>, Option<Layout<'b>>, Option<Layout<'b>>) {
+        let mut children = layout.children();
+
+        let content = children.next().unwrap();
+        let leading = self.leading.as_ref().and_then(|_| children.next());
+        let trailing = self.trailing.as_ref().and_then(|_| children.next());
+
+        (content, leading, trailing)
+    }
+
+    /// Returns the [`Settle`] of a [`Swipeable`] released at the given
+    /// offset.
+    fn settle(&self, offset: f32, layout: Layout<'_>) -> Settle {
+        let (_, leading, trailing) = self.layouts(layout);
+
+        settle(
+            offset,
+            layout.bounds().width,
+            leading.map(|layout| layout.bounds().width),
+            trailing.map(|layout| layout.bounds().width),
+            self.on_dismiss.as_ref().map(|_| self.dismiss_threshold),
+        )
+    }
+
+    /// Returns the furthest the [`Swipeable`] can be swiped towards each
+    /// side.
+    fn range(&self, layout: Layout<'_>) -> (f32, f32) {
+        let (_, leading, trailing) = self.layouts(layout);
+        let width = layout.bounds().width;
+
+        let reach = |actions: Option<Layout<'_>>| {
+            if self.on_dismiss.is_some() {
+                width
+            } else {
+                actions.map(|layout| layout.bounds().width).unwrap_or(0.0)
+            }
+        };
+
+        (-reach(trailing), reach(leading))
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Swipeable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(self.actions())
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &std::iter::once(&self.content)
+                .chain(self.actions())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+        let size = content.size();
+
+        // The actions span the whole height of the content
+        let action_limits =
+            layout::Limits::new(Size::new(0.0, size.height), size)
+                .with_direction(limits.direction());
+
+        let leading = self.leading.as_ref().map(|actions| {
+            actions.as_widget().layout(renderer, &action_limits)
+        });
+
+        let trailing = self.trailing.as_ref().map(|actions| {
+            let mut node = actions.as_widget().layout(renderer, &action_limits);
+            node.move_to(Point::new(size.width - node.size().width, 0.0));
+
+            node
+        });
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(content)
+                .chain(leading)
+                .chain(trailing)
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            std::iter::once(&self.content)
+                .chain(self.actions())
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(transition) = &mut state.transition {
+                    let started_at = *transition.started_at.get_or_insert(now);
+
+                    let progress = if self.duration.is_zero() {
+                        1.0
+                    } else {
+                        ((now - started_at).as_secs_f32()
+                            / self.duration.as_secs_f32())
+                        .min(1.0)
+                    };
+
+                    state.offset = transition.from
+                        + (transition.to - transition.from) * ease(progress);
+
+                    if progress < 1.0 {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        state.transition = None;
+
+                        if let Some(side) = state.dismissed.take() {
+                            // The state may be reused by whatever takes the
+                            // place of the dismissed content
+                            state.offset = 0.0;
+
+                            if let Some(on_dismiss) = &self.on_dismiss {
+                                shell.publish(on_dismiss(side));
+                            }
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                if state.dismissed.is_some() {
+                    return event::Status::Captured;
+                }
+
+                let (content, _, _) = self.layouts(layout);
+                let content_bounds =
+                    content.bounds() + Vector::new(state.offset, 0.0);
+
+                // Touching the content of an open row closes it
+                if state.offset != 0.0
+                    && content_bounds.contains(cursor_position)
+                {
+                    state.animate_to(0.0);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+
+                state.drag = Some(Drag {
+                    origin: cursor_position,
+                    offset: state.offset,
+                    is_swiping: false,
+                });
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    let delta = cursor_position - drag.origin;
+
+                    if !drag.is_swiping {
+                        if delta.x.abs() >= SWIPE_THRESHOLD
+                            && delta.x.abs() > delta.y.abs()
+                        {
+                            drag.is_swiping = true;
+                            drag.origin = cursor_position;
+                            state.transition = None;
+                        } else if delta.y.abs() >= SWIPE_THRESHOLD {
+                            // A vertical drag is left to any scrollable
+                            // around
+                            state.drag = None;
+                        }
+                    } else {
+                        let (min, max) = self.range(layout);
+
+                        state.offset = (drag.offset + delta.x).clamp(min, max);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_swiping {
+                        match self.settle(state.offset, layout) {
+                            Settle::Close => {
+                                state.animate_to(0.0);
+                            }
+                            Settle::Reveal(side, offset) => {
+                                state.animate_to(offset);
+
+                                if let Some(on_reveal) = &self.on_reveal {
+                                    shell.publish(on_reveal(side));
+                                }
+                            }
+                            Settle::Dismiss(side, offset) => {
+                                state.animate_to(offset);
+                                state.dismissed = Some(side);
+                            }
+                        }
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_swiping {
+                        state.animate_to(drag.offset);
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let offset = state.offset;
+        let (content, leading, trailing) = self.layouts(layout);
+
+        let mut children = tree.children.iter_mut();
+
+        let mut status = self.content.as_widget_mut().on_event(
+            children.next().unwrap(),
+            event.clone(),
+            content,
+            cursor_position - Vector::new(offset, 0.0),
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        for ((actions, layout), side) in self
+            .leading
+            .iter_mut()
+            .zip(leading)
+            .map(|pair| (pair, Side::Leading))
+            .chain(
+                self.trailing
+                    .iter_mut()
+                    .zip(trailing)
+                    .map(|pair| (pair, Side::Trailing)),
+            )
+        {
+            let state = children.next().unwrap();
+
+            let cursor_position =
+                if revealed(side, offset, bounds).contains(cursor_position) {
+                    cursor_position
+                } else {
+                    Point::new(-1.0, -1.0)
+                };
+
+            status = status.merge(actions.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.map_or(false, |drag| drag.is_swiping) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bounds = layout.bounds();
+        let (content, leading, trailing) = self.layouts(layout);
+
+        let mut children = tree.children.iter();
+
+        let content_interaction = self.content.as_widget().mouse_interaction(
+            children.next().unwrap(),
+            content,
+            cursor_position - Vector::new(state.offset, 0.0),
+            viewport,
+            renderer,
+        );
+
+        self.leading
+            .iter()
+            .zip(leading)
+            .map(|pair| (pair, Side::Leading))
+            .chain(
+                self.trailing
+                    .iter()
+                    .zip(trailing)
+                    .map(|pair| (pair, Side::Trailing)),
+            )
+            .zip(children)
+            .filter(|(((_, _), side), _)| {
+                revealed(*side, state.offset, bounds).contains(cursor_position)
+            })
+            .map(|(((actions, layout), _), tree)| {
+                actions.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .fold(content_interaction, mouse::Interaction::max)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let (content, leading, trailing) = self.layouts(layout);
+
+        let mut children = tree.children.iter();
+        let content_tree = children.next().unwrap();
+
+        // Only the part of the actions uncovered by the content is drawn, in
+        // case the content is translucent
+        for (((actions, layout), side), tree) in self
+            .leading
+            .iter()
+            .zip(leading)
+            .map(|pair| (pair, Side::Leading))
+            .chain(
+                self.trailing
+                    .iter()
+                    .zip(trailing)
+                    .map(|pair| (pair, Side::Trailing)),
+            )
+            .zip(children)
+        {
+            let revealed = revealed(side, state.offset, bounds);
+
+            if revealed.width <= 0.0 {
+                continue;
+            }
+
+            renderer.with_layer(revealed, |renderer| {
+                actions.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    &revealed,
+                );
+            });
+        }
+
+        let translation = Vector::new(state.offset, 0.0);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.content.as_widget().draw(
+                    content_tree,
+                    renderer,
+                    theme,
+                    style,
+                    content,
+                    cursor_position - translation,
+                    &(*viewport + Vector::new(-state.offset, 0.0)),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let offset = tree.state.downcast_ref::<State>().offset;
+        let content = layout.children().next().unwrap();
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content, renderer)
+            .map(|overlay| overlay.translate(Vector::new(offset, 0.0)))
+    }
+}
+
+impl<'a, Message, Renderer> From<Swipeable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(swipeable: Swipeable<'a, Message, Renderer>) -> Self {
+        Element::new(swipeable)
+    }
+}
+
+/// The local state of a [`Swipeable`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    offset: f32,
+    drag: Option<Drag>,
+    transition: Option<Transition>,
+    dismissed: Option<Side>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current horizontal offset of the content of the
+    /// [`Swipeable`].
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    fn animate_to(&mut self, offset: f32) {
+        self.transition = Some(Transition {
+            from: self.offset,
+            to: offset,
+            started_at: None,
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    offset: f32,
+    is_swiping: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from: f32,
+    to: f32,
+    // The release does not know the current time, so the transition starts
+    // on the next redraw
+    started_at: Option<Instant>,
+}
+
+/// Where a released [`Swipeable`] settles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Settle {
+    Close,
+    Reveal(Side, f32),
+    Dismiss(Side, f32),
+}
+
+/// The distance a drag needs to travel horizontally before it becomes a
+/// swipe.
+const SWIPE_THRESHOLD: f32 = 8.0;
+
+/// Decides where a [`Swipeable`] of the given width and actions settles once
+/// released at the given offset.
+///
+/// Actions are revealed when swiped past half of their width, while the
+/// whole row is dismissed when swiped past the given threshold, if any.
+fn settle(
+    offset: f32,
+    width: f32,
+    leading: Option<f32>,
+    trailing: Option<f32>,
+    dismiss_threshold: Option<f32>,
+) -> Settle {
+    let (side, actions, direction) = if offset > 0.0 {
+        (Side::Leading, leading, 1.0)
+    } else {
+        (Side::Trailing, trailing, -1.0)
+    };
+
+    let distance = offset.abs();
+
+    if let Some(threshold) = dismiss_threshold {
+        if distance > 0.0 && distance >= threshold * width {
+            return Settle::Dismiss(side, direction * width);
+        }
+    }
+
+    match actions {
+        Some(actions) if actions > 0.0 && distance >= actions / 2.0 => {
+            Settle::Reveal(side, direction * actions)
+        }
+        _ => Settle::Close,
+    }
+}
+
+/// Returns the area of a [`Swipeable`] uncovered on the given [`Side`] by
+/// its content at the given offset.
+fn revealed(side: Side, offset: f32, bounds: Rectangle) -> Rectangle {
+    match side {
+        Side::Leading => Rectangle {
+            width: offset.max(0.0),
+            ..bounds
+        },
+        Side::Trailing => {
+            let width = (-offset).max(0.0);
+
+            Rectangle {
+                x: bounds.x + bounds.width - width,
+                width,
+                ..bounds
+            }
+        }
+    }
+}
+
+/// Eases the given progress of a transition in and out.
+fn ease(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_settles_on_nearest_rest_position() {
+        let actions = Some(80.0);
+
+        assert_eq!(settle(30.0, 300.0, actions, None, None), Settle::Close);
+        assert_eq!(
+            settle(50.0, 300.0, actions, None, None),
+            Settle::Reveal(Side::Leading, 80.0)
+        );
+        assert_eq!(settle(-50.0, 300.0, actions, None, None), Settle::Close);
+        assert_eq!(
+            settle(-50.0, 300.0, None, actions, Some(0.5)),
+            Settle::Reveal(Side::Trailing, -80.0)
+        );
+        assert_eq!(
+            settle(-160.0, 300.0, None, actions, Some(0.5)),
+            Settle::Dismiss(Side::Trailing, -300.0)
+        );
+        assert_eq!(
+            settle(200.0, 300.0, None, None, Some(0.5)),
+            Settle::Dismiss(Side::Leading, 300.0)
+        );
+        assert_eq!(settle(0.0, 300.0, None, None, Some(0.0)), Settle::Close);
+    }
+}
@@ -0,0 +1,655 @@
+//! Display rows of data in resizable, sortable columns.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::{container, scrollable, Container, Operation, Scrollable};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+pub use iced_style::table::{Appearance, StyleSheet};
+
+/// A widget that displays rows of data in columns with a fixed header.
+///
+/// The header stays in place while the rows scroll. The columns can be
+/// resized by dragging the dividers of the header, and sorted by clicking on
+/// their header.
+///
+/// The widths of the columns are owned by the application. A [`Table`] only
+/// reports resizes and sorts through its messages.
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    columns: Vec<Properties>,
+    width: Length,
+    height: Length,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The headers of the columns, followed by the body.
+    children: Vec<Element<'a, Message, Renderer>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Renderer> {
+    header: Element<'a, Message, Renderer>,
+    properties: Properties,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Properties {
+    width: f32,
+    min_width: f32,
+    align_x: alignment::Horizontal,
+    sortable: bool,
+}
+
+impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
+    /// Creates a new [`Column`] with the given header and width.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Renderer>>,
+        width: impl Into<Pixels>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            properties: Properties {
+                width: width.into().0,
+                min_width: DEFAULT_MIN_WIDTH,
+                align_x: alignment::Horizontal::Left,
+                sortable: false,
+            },
+        }
+    }
+
+    /// Sets the minimum width the [`Column`] can be resized to.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.properties.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the horizontal alignment of the header and the cells of the
+    /// [`Column`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.properties.align_x = alignment;
+        self
+    }
+
+    /// Sets whether clicking on the header of the [`Column`] sorts the
+    /// [`Table`].
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.properties.sortable = sortable;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    /// Creates a new [`Table`] with the given columns and rows of cells.
+    ///
+    /// Every row should have a cell for each column. Extra cells are ignored.
+    pub fn new(
+        columns: Vec<Column<'a, Message, Renderer>>,
+        rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    ) -> Self {
+        let (headers, columns): (Vec<_>, Vec<_>) = columns
+            .into_iter()
+            .map(|column| (column.header, column.properties))
+            .unzip();
+
+        let rows = rows.into_iter().map(|cells| {
+            Element::from(crate::widget::Row::with_children(
+                cells
+                    .into_iter()
+                    .zip(&columns)
+                    .map(|(cell, column)| {
+                        Element::from(
+                            Container::new(cell)
+                                .width(column.width)
+                                .padding(CELL_PADDING)
+                                .align_x(column.align_x),
+                        )
+                    })
+                    .collect(),
+            ))
+        });
+
+        let body = Scrollable::new(crate::widget::Column::with_children(
+            rows.collect(),
+        ))
+        .height(Length::Fill);
+
+        let mut children = headers;
+        children.push(body.into());
+
+        Table {
+            columns,
+            width: Length::Shrink,
+            height: Length::Fill,
+            on_resize: None,
+            on_sort: None,
+            children,
+            style: Default::default(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when a column is resized.
+    ///
+    /// The function takes the index of the column and its new width. If it
+    /// is not set, the columns cannot be resized.
+    pub fn on_resize(mut self, f: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when the header of a
+    /// sortable column is clicked.
+    ///
+    /// The function takes the index of the column.
+    pub fn on_sort(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the header of every column.
+    fn headers(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+        let bounds = layout.bounds();
+        let header_height = header_height(layout);
+
+        let mut x = bounds.x;
+
+        self.columns
+            .iter()
+            .map(|column| {
+                let header = Rectangle {
+                    x,
+                    y: bounds.y,
+                    width: column.width,
+                    height: header_height,
+                };
+
+                x += column.width;
+
+                header
+            })
+            .collect()
+    }
+
+    /// Returns the index of the column whose resize handle is under the
+    /// cursor, if any.
+    fn handle_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if self.on_resize.is_none() {
+            return None;
+        }
+
+        self.headers(layout).iter().position(|header| {
+            let handle = Rectangle {
+                x: header.x + header.width - HANDLE_WIDTH / 2.0,
+                width: HANDLE_WIDTH,
+                ..*header
+            };
+
+            handle.contains(cursor_position)
+        })
+    }
+
+    /// Returns the index of the sortable column whose header is under the
+    /// cursor, if any.
+    fn sortable_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if self.on_sort.is_none() {
+            return None;
+        }
+
+        self.headers(layout).iter().zip(&self.columns).position(
+            |(header, column)| {
+                column.sortable && header.contains(cursor_position)
+            },
+        )
+    }
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    resizing: Option<Resizing>,
+}
+
+/// A column resize in progress.
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let total_width: f32 =
+            self.columns.iter().map(|column| column.width).sum();
+
+        let (headers, body) = self.children.split_at(self.columns.len());
+
+        let mut header_height: f32 = 0.0;
+        let mut x = 0.0;
+
+        let mut nodes: Vec<layout::Node> = headers
+            .iter()
+            .zip(&self.columns)
+            .map(|(header, column)| {
+                let width = (column.width - 2.0 * CELL_PADDING).max(0.0);
+
+                let mut node = header.as_widget().layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, limits.max().height),
+                    )
+                    .with_direction(limits.direction()),
+                );
+
+                node.move_to(Point::new(x + CELL_PADDING, CELL_PADDING));
+                node.align(
+                    column.align_x.into(),
+                    crate::Alignment::Start,
+                    Size::new(width, node.size().height),
+                );
+
+                header_height =
+                    header_height.max(node.size().height + 2.0 * CELL_PADDING);
+                x += column.width;
+
+                node
+            })
+            .collect();
+
+        let mut body = body[0].as_widget().layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    total_width.min(limits.max().width),
+                    (limits.max().height - header_height).max(0.0),
+                ),
+            )
+            .with_direction(limits.direction()),
+        );
+
+        body.move_to(Point::new(0.0, header_height));
+
+        let size = limits.resolve(Size::new(
+            total_width,
+            header_height + body.size().height,
+        ));
+
+        nodes.push(body);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(resizing) = state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some(on_resize) = &self.on_resize {
+                        let min_width = self.columns[resizing.column].min_width;
+                        let width = (resizing.width + cursor_position.x
+                            - resizing.origin)
+                            .max(min_width);
+
+                        shell.publish(on_resize(resizing.column, width));
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.resizing = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let status = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(column) = self.handle_at(layout, cursor_position) {
+                    tree.state.downcast_mut::<State>().resizing =
+                        Some(Resizing {
+                            column,
+                            origin: cursor_position.x,
+                            width: self.columns[column].width,
+                        });
+
+                    return event::Status::Captured;
+                }
+
+                if let (Some(column), Some(on_sort)) =
+                    (self.sortable_at(layout, cursor_position), &self.on_sort)
+                {
+                    shell.publish(on_sort(column));
+
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some()
+            || self.handle_at(layout, cursor_position).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.sortable_at(layout, cursor_position).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let header_height = header_height(layout);
+
+        if let Some(background) = appearance.header_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        height: header_height,
+                        ..bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        if appearance.divider_width <= 0.0 {
+            return;
+        }
+
+        let active = state
+            .resizing
+            .map(|resizing| resizing.column)
+            .or_else(|| self.handle_at(layout, cursor_position));
+
+        for (i, header) in self.headers(layout).into_iter().enumerate() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: header.x + header.width
+                            - appearance.divider_width / 2.0,
+                        width: appearance.divider_width,
+                        ..header
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                if active == Some(i) {
+                    appearance.active_divider_color
+                } else {
+                    appearance.divider_color
+                },
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + header_height
+                        - appearance.divider_width / 2.0,
+                    height: appearance.divider_width,
+                    ..bounds
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.divider_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Self::new(table)
+    }
+}
+
+/// Returns the height of the header of a [`Table`], given its layout.
+fn header_height(layout: Layout<'_>) -> f32 {
+    layout
+        .children()
+        .last()
+        .map_or(0.0, |body| body.bounds().y - layout.bounds().y)
+}
+
+/// The padding around the contents of every cell.
+const CELL_PADDING: f32 = 5.0;
+
+/// The width of the area around a divider that can be dragged.
+const HANDLE_WIDTH: f32 = 8.0;
+
+/// The default minimum width of a column.
+const DEFAULT_MIN_WIDTH: f32 = 20.0;
@@ -0,0 +1,952 @@
+//! Display a strip of tabs on top of the content of the selected one.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay::{self, menu, Menu};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::tree::{self, Tree};
+use crate::widget::{container, scrollable};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::tabs::{Appearance, StyleSheet};
+
+use std::fmt;
+use std::ops::Range;
+
+/// The distance the cursor has to travel before a pressed tab is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A strip of tabs, displayed on top of the content of the selected one.
+///
+/// Tabs can have a close button and be reordered by dragging them. When they
+/// do not fit in the available width, the tabs around the selected one are
+/// displayed and a button opens a menu listing all of them.
+///
+/// # Example
+/// ```
+/// # pub type Tabs<'a, Id, Message> = iced_native::widget::Tabs<'a, Id, Message, iced_native::renderer::Null>;
+/// # pub type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// use iced_native::widget::tabs::Reorder;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(usize),
+///     TabClosed(usize),
+///     TabsReordered(Reorder),
+/// }
+///
+/// let tabs = Tabs::new(0, Text::new("Content of the first tab"), Message::TabSelected)
+///     .push(0, "First")
+///     .push(1, "Second")
+///     .on_close(Message::TabClosed)
+///     .on_reorder(Message::TabsReordered);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Id, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    tabs: Vec<Tab<Id>>,
+    selected: Id,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(Tab<Id>) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, Id, Message, Renderer> Tabs<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    /// Creates a new [`Tabs`] widget without any tab.
+    ///
+    /// It expects:
+    /// - the identifier of the selected tab,
+    /// - the content of the selected tab, and
+    /// - a function that produces a message when a tab is selected.
+    pub fn new<F>(
+        selected: Id,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        Tabs {
+            tabs: Vec::new(),
+            selected,
+            content: content.into(),
+            on_select: Box::new(move |tab| on_select(tab.id)),
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::from([5.0, 10.0]),
+            spacing: 2.0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Adds a tab with the given identifier and label to the [`Tabs`].
+    pub fn push(mut self, id: Id, label: impl Into<String>) -> Self {
+        self.tabs.push(Tab {
+            id,
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Displays a close button in every tab, which produces the message of
+    /// the given function when pressed.
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Lets the tabs be reordered by dragging them, producing the message of
+    /// the given function when a tab is dropped in a new position.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'a + Fn(Reorder) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every tab.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels of the tabs.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the labels of the tabs.
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu listing the tabs when they overflow.
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.id == self.selected)
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn tab_width(&self, renderer: &Renderer, tab: &Tab<Id>) -> f32 {
+        let text_size = self.label_size(renderer);
+        let label =
+            renderer.measure_width(&tab.label, text_size, self.font.clone());
+
+        let close = if self.on_close.is_some() {
+            self.padding.right + text_size
+        } else {
+            0.0
+        };
+
+        label + close + self.padding.horizontal()
+    }
+
+    /// Returns the bounds of the close button of the tab with the given
+    /// bounds.
+    fn close_bounds(&self, tab: Rectangle, text_size: f32) -> Rectangle {
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right - text_size,
+            y: tab.y + self.padding.top,
+            width: text_size,
+            height: text_size,
+        }
+    }
+
+    /// Returns the index the dragged tab would be dropped at if released
+    /// with its center at the given horizontal position.
+    fn drop_index(&self, parts: &Parts<'_>, x: f32) -> Option<usize> {
+        let visible: Vec<_> = parts
+            .tabs
+            .iter()
+            .enumerate()
+            .filter(|(_, layout)| is_visible(layout))
+            .collect();
+
+        let (first, first_layout) = visible.first()?;
+        let (last, _) = visible.last()?;
+
+        if x < first_layout.bounds().x {
+            return Some(*first);
+        }
+
+        Some(
+            visible
+                .iter()
+                .find(|(_, layout)| {
+                    let bounds = layout.bounds();
+
+                    x < bounds.x + bounds.width + self.spacing
+                })
+                .map_or(*last, |(index, _)| *index),
+        )
+    }
+}
+
+impl<'a, Id, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+    <Renderer::Theme as menu::StyleSheet>::Style: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let tab_height = self.label_size(renderer) + self.padding.vertical();
+
+        let widths: Vec<f32> = self
+            .tabs
+            .iter()
+            .map(|tab| self.tab_width(renderer, tab))
+            .collect();
+
+        let total_width = widths.iter().sum::<f32>()
+            + self.spacing * widths.len().saturating_sub(1) as f32;
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(renderer, &limits.shrink(Size::new(0.0, tab_height)));
+
+        let size = limits.resolve(Size::new(
+            total_width.max(content.size().width),
+            tab_height + content.size().height,
+        ));
+
+        let overflows = total_width > size.width;
+        let available = if overflows {
+            size.width - tab_height - self.spacing
+        } else {
+            size.width
+        };
+
+        let visible = visible_range(
+            &widths,
+            self.spacing,
+            available,
+            self.selected_index(),
+        );
+
+        let mut x = 0.0;
+        let mut tabs: Vec<_> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                if visible.contains(&index) {
+                    let mut node =
+                        layout::Node::new(Size::new(*width, tab_height));
+                    node.move_to(Point::new(x, 0.0));
+
+                    x += width + self.spacing;
+
+                    node
+                } else {
+                    layout::Node::new(Size::ZERO)
+                }
+            })
+            .collect();
+
+        if overflows {
+            let mut button =
+                layout::Node::new(Size::new(tab_height, tab_height));
+            button.move_to(Point::new(size.width - tab_height, 0.0));
+
+            tabs.push(button);
+        }
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, tab_height),
+            tabs,
+        );
+
+        let mut content = content;
+        content.move_to(Point::new(0.0, tab_height));
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                Parts::new(layout, self.tabs.len()).content,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let parts = Parts::new(layout, self.tabs.len());
+        let text_size = self.label_size(renderer);
+
+        let status = {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    let mut status = event::Status::Ignored;
+
+                    if let Some(button) = parts.overflow {
+                        if button.bounds().contains(cursor_position) {
+                            if state.menu.is_open() {
+                                state.menu.close();
+                            } else if !state.menu.is_closing() {
+                                state.menu.open();
+                                state.hovered_option = self.selected_index();
+                            }
+
+                            status = event::Status::Captured;
+                        }
+                    }
+
+                    if state.menu.is_closing() {
+                        state.menu.close();
+                    }
+
+                    let pressed = parts.tabs.iter().position(|layout| {
+                        is_visible(layout)
+                            && layout.bounds().contains(cursor_position)
+                    });
+
+                    if let Some(index) = pressed {
+                        let bounds = parts.tabs[index].bounds();
+                        let tab = &self.tabs[index];
+
+                        match &self.on_close {
+                            Some(on_close)
+                                if self
+                                    .close_bounds(bounds, text_size)
+                                    .contains(cursor_position) =>
+                            {
+                                shell.publish(on_close(tab.id.clone()));
+                            }
+                            _ => {
+                                if tab.id != self.selected {
+                                    shell
+                                        .publish((self.on_select)(tab.clone()));
+                                }
+
+                                if self.on_reorder.is_some() {
+                                    state.drag = Some(Drag {
+                                        index,
+                                        origin: cursor_position.x,
+                                        position: cursor_position.x,
+                                        is_dragging: false,
+                                    });
+                                }
+                            }
+                        }
+
+                        status = event::Status::Captured;
+                    }
+
+                    status
+                }
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some(drag) = &mut state.drag {
+                        drag.position = cursor_position.x;
+                        drag.is_dragging |= (drag.position - drag.origin).abs()
+                            > DRAG_THRESHOLD;
+
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    match state.drag.take() {
+                        Some(drag) if drag.is_dragging => {
+                            let to =
+                                parts.tabs.get(drag.index).and_then(|tab| {
+                                    self.drop_index(
+                                        &parts,
+                                        tab.bounds().center_x() + drag.offset(),
+                                    )
+                                });
+
+                            if let (Some(to), Some(on_reorder)) =
+                                (to, &self.on_reorder)
+                            {
+                                if to != drag.index {
+                                    shell.publish(on_reorder(Reorder {
+                                        from: drag.index,
+                                        to,
+                                    }));
+                                }
+                            }
+
+                            event::Status::Captured
+                        }
+                        Some(_) => event::Status::Captured,
+                        None => event::Status::Ignored,
+                    }
+                }
+                _ => event::Status::Ignored,
+            }
+        };
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            parts.content,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout, self.tabs.len());
+
+        if state.drag.map_or(false, |drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if parts
+            .tabs
+            .iter()
+            .chain(parts.overflow.as_ref())
+            .any(|layout| {
+                is_visible(layout) && layout.bounds().contains(cursor_position)
+            })
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            parts.content,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout, self.tabs.len());
+        let text_size = self.label_size(renderer);
+
+        if let Some(background) = StyleSheet::bar(theme, &self.style) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: parts.bar.bounds(),
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let dragged = state.drag.filter(|drag| drag.is_dragging);
+
+        let draw_tab = |renderer: &mut Renderer, index: usize| {
+            let bounds = parts.tabs[index].bounds();
+            let tab = &self.tabs[index];
+            let is_selected = tab.id == self.selected;
+            let is_hovered = match dragged {
+                Some(drag) => drag.index == index,
+                None => bounds.contains(cursor_position),
+            };
+
+            let appearance = if is_hovered {
+                StyleSheet::hovered(theme, &self.style, is_selected)
+            } else {
+                StyleSheet::active(theme, &self.style, is_selected)
+            };
+
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            if let Some(color) = appearance.indicator_color {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + bounds.height - 2.0,
+                            height: 2.0,
+                            ..bounds
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: &tab.label,
+                bounds: Rectangle {
+                    x: bounds.x + self.padding.left,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+
+            if self.on_close.is_some() {
+                let close = self.close_bounds(bounds, text_size);
+
+                renderer.fill_text(Text {
+                    content: "×",
+                    bounds: Rectangle {
+                        x: close.center_x(),
+                        y: close.center_y(),
+                        ..close
+                    },
+                    size: text_size,
+                    color: if close.contains(cursor_position) {
+                        appearance.text_color
+                    } else {
+                        appearance.close_color
+                    },
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
+        };
+
+        for (index, layout) in parts.tabs.iter().enumerate() {
+            let is_dragged = dragged.map_or(false, |drag| drag.index == index);
+
+            if is_visible(layout) && !is_dragged {
+                draw_tab(renderer, index);
+            }
+        }
+
+        if let Some(drag) = dragged.filter(|drag| drag.index < parts.tabs.len())
+        {
+            renderer.with_layer(parts.bar.bounds(), |renderer| {
+                renderer.with_translation(
+                    Vector::new(drag.offset(), 0.0),
+                    |renderer| draw_tab(renderer, drag.index),
+                );
+            });
+        }
+
+        if let Some(button) = parts.overflow {
+            let bounds = button.bounds();
+            let appearance =
+                if bounds.contains(cursor_position) || state.menu.is_open() {
+                    StyleSheet::hovered(theme, &self.style, false)
+                } else {
+                    StyleSheet::active(theme, &self.style, false)
+                };
+
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: "»",
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            parts.content,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let parts = Parts::new(layout, self.tabs.len());
+        let (state, children) = (&mut tree.state, &mut tree.children);
+        let state = state.downcast_mut::<State>();
+
+        match parts.overflow {
+            Some(button) if state.menu.is_open() => {
+                let bounds = button.bounds();
+                let width = self
+                    .tabs
+                    .iter()
+                    .map(|tab| self.tab_width(renderer, tab))
+                    .fold(bounds.width, f32::max);
+
+                let menu = Menu::new(
+                    &mut state.menu,
+                    &self.tabs,
+                    &mut state.hovered_option,
+                    &*self.on_select,
+                )
+                .width(width)
+                .padding(self.padding)
+                .text_size(self.label_size(renderer))
+                .font(self.font.clone())
+                .style(self.menu_style.clone());
+
+                Some(menu.overlay(
+                    Point::new(bounds.x + bounds.width - width, bounds.y),
+                    bounds.height,
+                ))
+            }
+            _ => self.content.as_widget_mut().overlay(
+                &mut children[0],
+                parts.content,
+                renderer,
+            ),
+        }
+    }
+}
+
+impl<'a, Id, Message, Renderer> From<Tabs<'a, Id, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'a,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+    <Renderer::Theme as menu::StyleSheet>::Style: Clone,
+{
+    fn from(
+        tabs: Tabs<'a, Id, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
+
+/// A tab of a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tab<Id> {
+    /// The identifier of the tab.
+    pub id: Id,
+    /// The label of the tab.
+    pub label: String,
+}
+
+impl<Id> fmt::Display for Tab<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+/// A tab dropped in a new position of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index of the dragged tab.
+    pub from: usize,
+    /// The index the dragged tab should have once moved.
+    pub to: usize,
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug, Default)]
+pub struct State {
+    menu: menu::State,
+    hovered_option: Option<usize>,
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a tab is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.map_or(false, |drag| drag.is_dragging)
+    }
+}
+
+/// A pressed tab, which may be dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+    is_dragging: bool,
+}
+
+impl Drag {
+    fn offset(&self) -> f32 {
+        self.position - self.origin
+    }
+}
+
+/// The layouts of the parts of a [`Tabs`] widget.
+struct Parts<'a> {
+    bar: Layout<'a>,
+    tabs: Vec<Layout<'a>>,
+    overflow: Option<Layout<'a>>,
+    content: Layout<'a>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(layout: Layout<'a>, tabs: usize) -> Self {
+        let mut children = layout.children();
+
+        let bar = children.next().expect("Tabs bar");
+        let content = children.next().expect("Tabs content");
+
+        let mut tabs_and_overflow = bar.children();
+        let tabs = tabs_and_overflow.by_ref().take(tabs).collect();
+
+        Parts {
+            bar,
+            tabs,
+            overflow: tabs_and_overflow.next(),
+            content,
+        }
+    }
+}
+
+/// Returns whether the tab with the given [`Layout`] fits in the strip of
+/// tabs; the others are laid out with an empty size.
+fn is_visible(layout: &Layout<'_>) -> bool {
+    layout.bounds().width > 0.0
+}
+
+/// Returns the range of tabs with the given widths that fit in the
+/// available width, starting with the first one and always including the
+/// selected one.
+fn visible_range(
+    widths: &[f32],
+    spacing: f32,
+    available: f32,
+    selected: Option<usize>,
+) -> Range<usize> {
+    let fits = |range: Range<usize>| {
+        let count = range.len();
+
+        widths[range].iter().sum::<f32>()
+            + spacing * count.saturating_sub(1) as f32
+            <= available
+    };
+
+    let mut end = 0;
+
+    while end < widths.len() && fits(0..end + 1) {
+        end += 1;
+    }
+
+    match selected {
+        Some(selected) if selected >= end => {
+            let mut start = selected;
+
+            while start > 0 && fits(start - 1..selected + 1) {
+                start -= 1;
+            }
+
+            start..selected + 1
+        }
+        _ => 0..end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_range_keeps_the_selected_tab() {
+        let widths = [50.0, 50.0, 50.0, 50.0, 50.0];
+
+        assert_eq!(visible_range(&widths, 0.0, 500.0, Some(4)), 0..5);
+        assert_eq!(visible_range(&widths, 0.0, 120.0, Some(1)), 0..2);
+        assert_eq!(visible_range(&widths, 0.0, 120.0, None), 0..2);
+        assert_eq!(visible_range(&widths, 0.0, 120.0, Some(3)), 2..4);
+        assert_eq!(visible_range(&widths, 10.0, 120.0, Some(4)), 3..5);
+        assert_eq!(visible_range(&widths, 0.0, 10.0, Some(2)), 2..3);
+        assert_eq!(visible_range(&widths, 0.0, 10.0, None), 0..0);
+    }
+}
@@ -0,0 +1,767 @@
+//! Let your users enter a list of tags, displayed as chips.
+//!
+//! A [`TagsInput`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::fuzzy;
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::chip;
+use crate::widget::container;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::borrow::Cow;
+
+/// A field displaying a list of tags as chips, followed by some free text
+/// that becomes a new tag when `Enter` is pressed.
+///
+/// Pressing `Backspace` while the text is empty removes the last tag, and
+/// the `×` of every chip removes its tag. When some suggestions are given,
+/// the ones matching the text are listed in a menu under the field.
+///
+/// # Example
+/// ```
+/// # pub type TagsInput<'a, Message> = iced_native::widget::TagsInput<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TagAdded(String),
+///     TagRemoved(usize),
+/// }
+///
+/// let tags = vec![String::from("rust"), String::from("gui")];
+/// let suggestions = vec![String::from("async"), String::from("wgpu")];
+///
+/// let input = TagsInput::new(&tags, Message::TagAdded, Message::TagRemoved)
+///     .suggestions(&suggestions)
+///     .placeholder("Add a tag...");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TagsInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme:
+        text_input::StyleSheet + chip::StyleSheet + menu::StyleSheet,
+{
+    tags: Cow<'a, [String]>,
+    on_add: Box<dyn Fn(String) -> Message + 'a>,
+    on_remove: Box<dyn Fn(usize) -> Message + 'a>,
+    suggestions: Cow<'a, [String]>,
+    placeholder: String,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as text_input::StyleSheet>::Style,
+    chip_style: <Renderer::Theme as chip::StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TagsInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme:
+        text_input::StyleSheet + chip::StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`TagsInput`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`TagsInput`].
+    ///
+    /// It expects:
+    /// - the current tags,
+    /// - a function that produces a message when a tag is added, and
+    /// - a function that produces a message when the tag at the given index
+    ///   is removed.
+    pub fn new(
+        tags: impl Into<Cow<'a, [String]>>,
+        on_add: impl Fn(String) -> Message + 'a,
+        on_remove: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        TagsInput {
+            tags: tags.into(),
+            on_add: Box::new(on_add),
+            on_remove: Box::new(on_remove),
+            suggestions: Cow::Borrowed(&[]),
+            placeholder: String::new(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 5.0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            chip_style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the suggestions of the [`TagsInput`], listed in a menu when they
+    /// match the text typed by the user.
+    pub fn suggestions(
+        mut self,
+        suggestions: impl Into<Cow<'a, [String]>>,
+    ) -> Self {
+        self.suggestions = suggestions.into();
+        self
+    }
+
+    /// Sets the placeholder of the [`TagsInput`], displayed while it has no
+    /// tags and no text.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = String::from(placeholder);
+        self
+    }
+
+    /// Sets the width of the [`TagsInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TagsInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the chips of the [`TagsInput`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`TagsInput`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TagsInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the field of the [`TagsInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the chips of the [`TagsInput`].
+    pub fn chip_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as chip::StyleSheet>::Style>,
+    ) -> Self {
+        self.chip_style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu listing the suggestions of the
+    /// [`TagsInput`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the bounds of the delete button of the chip with the given
+    /// bounds.
+    fn delete_bounds(&self, chip: Rectangle, renderer: &Renderer) -> Rectangle {
+        let size = self.label_size(renderer);
+
+        Rectangle {
+            x: chip.x + chip.width - CHIP_PADDING.right - size,
+            y: chip.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    /// Adds the given tag, unless it is empty or already present.
+    fn add(&self, tag: &str, shell: &mut Shell<'_, Message>) {
+        let tag = tag.trim();
+
+        if !tag.is_empty() && !self.tags.iter().any(|other| other == tag) {
+            shell.publish((self.on_add)(tag.to_owned()));
+        }
+    }
+
+    /// Lists the suggestions matching the text of the [`TagsInput`], best
+    /// matches first.
+    fn refresh_suggestions(&self, state: &mut State) {
+        let available = self
+            .suggestions
+            .iter()
+            .filter(|suggestion| !self.tags.contains(*suggestion));
+
+        state.suggestions = fuzzy::filter(&state.text, available)
+            .into_iter()
+            .map(|(suggestion, _)| suggestion.clone())
+            .collect();
+
+        state.hovered_option = None;
+    }
+}
+
+/// The local state of a [`TagsInput`].
+#[derive(Debug, Default)]
+pub struct State {
+    input: text_input::State,
+    text: String,
+    menu: menu::State,
+    hovered_option: Option<usize>,
+    suggestions: Vec<String>,
+    is_menu_open: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TagsInput`] is focused.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+/// An edit of the text of a [`TagsInput`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TagsInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: text_input::StyleSheet
+        + chip::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.label_size(renderer);
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.resolve(Size::ZERO).width;
+        let content_width = (width - self.padding.horizontal()).max(0.0);
+
+        let chips: Vec<Size> = self
+            .tags
+            .iter()
+            .map(|tag| {
+                let label_width =
+                    renderer.measure_width(tag, text_size, self.font.clone());
+
+                Size::new(
+                    CHIP_PADDING.horizontal()
+                        + label_width
+                        + CHIP_SPACING
+                        + text_size,
+                    CHIP_PADDING.vertical() + text_size,
+                )
+            })
+            .collect();
+
+        let line_height = CHIP_PADDING.vertical() + text_size;
+
+        let (positions, input, height) = flow(
+            &chips,
+            text_size * MIN_INPUT_WIDTH,
+            content_width,
+            self.spacing,
+            line_height,
+        );
+
+        let offset = |point: Point| {
+            Point::new(point.x + self.padding.left, point.y + self.padding.top)
+        };
+
+        let size =
+            limits.resolve(Size::new(width, height + self.padding.vertical()));
+
+        // The text input covers the whole field, so it draws its frame, but
+        // its text only takes the room left after the chips
+        let mut text = layout::Node::new(Size::new(
+            (content_width - input.x).max(0.0),
+            text_size,
+        ));
+        text.move_to(offset(Point::new(
+            input.x,
+            input.y + (line_height - text_size) / 2.0,
+        )));
+
+        let mut children = vec![layout::Node::with_children(size, vec![text])];
+
+        children.extend(chips.into_iter().zip(positions).map(
+            |(size, position)| {
+                let mut chip = layout::Node::new(size);
+                chip.move_to(offset(position));
+
+                chip
+            },
+        ));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The menu closes itself when one of its suggestions is picked, once
+        // the message adding it has been published
+        if state.is_menu_open && !state.menu.is_open() {
+            if !state.menu.is_closing() {
+                state.text.clear();
+                self.refresh_suggestions(state);
+            }
+
+            state.menu.close();
+            state.is_menu_open = false;
+        }
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let deleted = children.position(|chip| {
+                    self.delete_bounds(chip.bounds(), renderer)
+                        .contains(cursor_position)
+                });
+
+                if let Some(index) = deleted {
+                    shell.publish((self.on_remove)(index));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.input.is_focused() => match key_code {
+                keyboard::KeyCode::Backspace
+                    if state.text.is_empty() && !self.tags.is_empty() =>
+                {
+                    shell.publish((self.on_remove)(self.tags.len() - 1));
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                    if state.is_menu_open =>
+                {
+                    let count = state.suggestions.len();
+
+                    state.hovered_option = Some(match state.hovered_option {
+                        Some(index) if key_code == keyboard::KeyCode::Up => {
+                            (index + count - 1) % count
+                        }
+                        Some(index) => (index + 1) % count,
+                        None if key_code == keyboard::KeyCode::Up => count - 1,
+                        None => 0,
+                    });
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter
+                    if state.is_menu_open =>
+                {
+                    if let Some(suggestion) = state
+                        .hovered_option
+                        .and_then(|index| state.suggestions.get(index))
+                    {
+                        self.add(suggestion, shell);
+
+                        state.text.clear();
+                        self.refresh_suggestions(state);
+                        state.menu.close();
+                        state.is_menu_open = false;
+
+                        return event::Status::Captured;
+                    }
+                }
+                keyboard::KeyCode::Escape if state.is_menu_open => {
+                    state.menu.close();
+                    state.is_menu_open = false;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let mut value = Value::new(&state.text);
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut value,
+            self.text_size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &Edit::Changed,
+            None,
+            None,
+            &Some(Edit::Submitted),
+            || &mut state.input,
+        );
+
+        if let Some(redraw_request) = input_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        let is_edited = !edits.is_empty();
+
+        for edit in edits {
+            match edit {
+                Edit::Changed(text) => state.text = text,
+                Edit::Submitted => {
+                    self.add(&state.text, shell);
+
+                    state.text.clear();
+                }
+            }
+        }
+
+        if is_edited {
+            self.refresh_suggestions(state);
+        }
+
+        let should_open = state.input.is_focused()
+            && !state.text.is_empty()
+            && !state.suggestions.is_empty();
+
+        if should_open != state.is_menu_open {
+            if should_open {
+                state.menu.open();
+            } else {
+                state.menu.close();
+            }
+
+            state.is_menu_open = should_open;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        let is_over_delete = children.any(|chip| {
+            self.delete_bounds(chip.bounds(), renderer)
+                .contains(cursor_position)
+        });
+
+        if is_over_delete {
+            mouse::Interaction::Pointer
+        } else {
+            text_input::mouse_interaction(input_layout, cursor_position)
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text_size = self.label_size(renderer);
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        text_input::draw(
+            renderer,
+            theme,
+            input_layout,
+            cursor_position,
+            &state.input,
+            &Value::new(&state.text),
+            if self.tags.is_empty() {
+                self.placeholder.as_str()
+            } else {
+                ""
+            },
+            self.text_size,
+            &self.font,
+            false,
+            &text_input::Options::default(),
+            &self.style,
+        );
+
+        for (tag, chip_layout) in self.tags.iter().zip(children) {
+            let bounds = chip_layout.bounds();
+
+            let appearance = if bounds.contains(cursor_position) {
+                chip::StyleSheet::hovered(theme, &self.chip_style, false)
+            } else {
+                chip::StyleSheet::active(theme, &self.chip_style, false)
+            };
+
+            if appearance.background.is_some() || appearance.border_width > 0.0
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: (bounds.height / 2.0).into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance
+                        .background
+                        .unwrap_or_else(|| Color::TRANSPARENT.into()),
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: tag,
+                bounds: Rectangle {
+                    x: bounds.x + CHIP_PADDING.left,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                color: appearance.text_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+
+            let delete = self.delete_bounds(bounds, renderer);
+
+            renderer.fill_text(Text {
+                content: "×",
+                bounds: Rectangle {
+                    x: delete.center_x(),
+                    y: delete.center_y(),
+                    ..delete
+                },
+                size: text_size,
+                color: appearance.delete_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let State {
+            menu,
+            hovered_option,
+            suggestions,
+            text,
+            ..
+        } = tree.state.downcast_mut::<State>();
+
+        if !menu.is_open() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let menu = Menu::new(menu, suggestions, hovered_option, &*self.on_add)
+            .width(bounds.width)
+            .padding(self.padding)
+            .text_size(self.label_size(renderer))
+            .font(self.font.clone())
+            .highlight(text)
+            .style(self.menu_style.clone());
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+impl<'a, Message, Renderer> From<TagsInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: text_input::StyleSheet
+        + chip::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn from(tags_input: TagsInput<'a, Message, Renderer>) -> Self {
+        Element::new(tags_input)
+    }
+}
+
+/// The padding of the chips of a [`TagsInput`].
+const CHIP_PADDING: Padding = Padding {
+    top: 2.0,
+    right: 8.0,
+    bottom: 2.0,
+    left: 8.0,
+};
+
+/// The spacing between the label of a chip and its delete button.
+const CHIP_SPACING: f32 = 4.0;
+
+/// The minimum width of the text of a [`TagsInput`], relative to its text
+/// size, before it wraps to a new line.
+const MIN_INPUT_WIDTH: f32 = 5.0;
+
+/// Lays out chips of the given sizes in lines of the given width, followed by
+/// the text input.
+///
+/// Returns the position of every chip, the position of the input, and the
+/// total height of the lines.
+fn flow(
+    chips: &[Size],
+    input_width: f32,
+    max_width: f32,
+    spacing: f32,
+    line_height: f32,
+) -> (Vec<Point>, Point, f32) {
+    let mut x = 0.0;
+    let mut y = 0.0;
+
+    let mut place = |width: f32, height: f32| {
+        if x > 0.0 && x + width > max_width {
+            x = 0.0;
+            y += line_height + spacing;
+        }
+
+        let position = Point::new(x, y + (line_height - height) / 2.0);
+        x += width + spacing;
+
+        position
+    };
+
+    let positions = chips
+        .iter()
+        .map(|size| place(size.width, size.height))
+        .collect();
+
+    let input = place(input_width, line_height);
+
+    (positions, input, input.y + line_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chips_wrap_before_the_input() {
+        let chip = Size::new(40.0, 20.0);
+
+        let (positions, input, height) =
+            flow(&[chip, chip], 50.0, 100.0, 5.0, 20.0);
+
+        assert_eq!(
+            positions,
+            vec![Point::new(0.0, 0.0), Point::new(45.0, 0.0)]
+        );
+        assert_eq!(input, Point::new(0.0, 25.0));
+        assert_eq!(height, 45.0);
+
+        let (_, input, height) = flow(&[chip], 50.0, 100.0, 5.0, 20.0);
+
+        assert_eq!(input, Point::new(45.0, 0.0));
+        assert_eq!(height, 20.0);
+
+        let (_, input, height) = flow(&[], 50.0, 100.0, 5.0, 20.0);
+
+        assert_eq!(input, Point::ORIGIN);
+        assert_eq!(height, 20.0);
+    }
+}
@@ -141,9 +141,34 @@ where
         let (width, height) =
             renderer.measure(&self.content, size, self.font.clone(), bounds);
 
+        let ascent = renderer.ascent(size, self.font.clone());
         let size = limits.resolve(Size::new(width, height));
 
-        layout::Node::new(size)
+        let top = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => (size.height - height) / 2.0,
+            alignment::Vertical::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size).with_baseline(top + ascent)
+    }
+
+    fn height_for_width(&self, renderer: &Renderer, width: f32) -> f32 {
+        let limits =
+            layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+                .width(self.width)
+                .height(self.height);
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font.clone(),
+            limits.max(),
+        );
+
+        limits.resolve(Size::new(width, height)).height
     }
 
     fn draw(
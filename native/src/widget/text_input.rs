@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod suggestions;
 mod value;
 
 pub mod cursor;
@@ -10,12 +11,16 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use suggestions::Suggestions;
 
 use crate::alignment;
+use crate::clipboard;
 use crate::event::{self, Event};
+use crate::input_method;
 use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
+use crate::overlay;
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::time::{Duration, Instant};
@@ -29,8 +34,22 @@ use crate::{
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+
 pub use iced_style::text_input::{Appearance, StyleSheet};
 
+/// The content of the [`Icon`].
+#[derive(Debug, Clone)]
+pub struct Icon<Font> {
+    /// The font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+    /// The font size of the content.
+    pub size: Option<f32>,
+}
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -61,13 +80,26 @@ where
     placeholder: String,
     value: Value,
     is_secure: bool,
+    reveal_icon: Option<Icon<Renderer::Font>>,
+    cursor_blink_interval: Option<Duration>,
+    accepts_drops: bool,
+    max_length: Option<usize>,
+    filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
     font: Renderer::Font,
     width: Length,
     padding: Padding,
     size: Option<f32>,
     on_change: Box<dyn Fn(String) -> Message + 'a>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_paste_content:
+        Option<Box<dyn Fn(clipboard::Content) -> Option<Message> + 'a>>,
     on_submit: Option<Message>,
+    validate: Option<Box<dyn Fn(&str) -> Result<(), String> + 'a>>,
+    spellcheck: Option<Box<dyn Fn(&str) -> Vec<Range<usize>> + 'a>>,
+    on_misspelling: Option<Box<dyn Fn(Misspelling) -> Message + 'a>>,
+    suggest: Option<Box<dyn Fn(&str) -> Vec<String> + 'a>>,
+    leading: Option<Element<'a, Message, Renderer>>,
+    trailing: Option<Element<'a, Message, Renderer>>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -92,13 +124,25 @@ where
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            reveal_icon: None,
+            cursor_blink_interval: Some(CURSOR_BLINK_INTERVAL),
+            accepts_drops: false,
+            max_length: None,
+            filter: None,
             font: Default::default(),
             width: Length::Fill,
             padding: Padding::new(5.0),
             size: None,
             on_change: Box::new(on_change),
             on_paste: None,
+            on_paste_content: None,
             on_submit: None,
+            validate: None,
+            spellcheck: None,
+            on_misspelling: None,
+            suggest: None,
+            leading: None,
+            trailing: None,
             style: Default::default(),
         }
     }
@@ -115,6 +159,53 @@ where
         self
     }
 
+    /// Adds a trailing [`Icon`] to a secure [`TextInput`] that reveals its
+    /// contents while it is pressed.
+    ///
+    /// The icon has no effect unless [`TextInput::password`] is also set.
+    pub fn password_reveal(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.reveal_icon = Some(icon);
+        self
+    }
+
+    /// Sets the interval at which the text cursor of the [`TextInput`] blinks,
+    /// or disables blinking if `None`.
+    ///
+    /// The cursor stays visible while the user is typing, regardless.
+    pub fn cursor_blink_interval(
+        mut self,
+        interval: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.cursor_blink_interval = interval.into();
+        self
+    }
+
+    /// Sets the maximum amount of characters of the [`TextInput`].
+    ///
+    /// Any input that would exceed it, including pasted text, is truncated.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the function that decides whether a character can be entered
+    /// into the [`TextInput`].
+    ///
+    /// Rejected characters are discarded, including those of pasted text.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets whether files dropped onto the [`TextInput`] are accepted.
+    ///
+    /// When enabled, the path of a file dropped onto the [`TextInput`] is
+    /// inserted where the text cursor would be placed by a click.
+    pub fn accepts_drops(mut self, accepts_drops: bool) -> Self {
+        self.accepts_drops = accepts_drops;
+        self
+    }
+
     /// Sets the message that should be produced when some text is pasted into
     /// the [`TextInput`].
     pub fn on_paste(
@@ -125,6 +216,19 @@ where
         self
     }
 
+    /// Sets the function that intercepts the [`clipboard::Content`] pasted
+    /// into the [`TextInput`], before any text is inserted.
+    ///
+    /// If the function produces a message, it is published and the paste is
+    /// consumed. Otherwise, any text is pasted as usual.
+    pub fn on_paste_content(
+        mut self,
+        on_paste_content: impl Fn(clipboard::Content) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_paste_content = Some(Box::new(on_paste_content));
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -157,6 +261,84 @@ where
         self
     }
 
+    /// Validates the value of the [`TextInput`] with the given function.
+    ///
+    /// The function is called with the value of the [`TextInput`] whenever
+    /// it is laid out or drawn. If the validation fails, the [`TextInput`] is
+    /// drawn with the [`StyleSheet::errored`] style and the returned message
+    /// is displayed beneath it.
+    pub fn on_validate(
+        mut self,
+        validate: impl Fn(&str) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets the function that checks the spelling of the value of the
+    /// [`TextInput`].
+    ///
+    /// The function returns the byte ranges of the misspelled words of the
+    /// given text, which are drawn with a squiggly underline. It is only
+    /// called again when the value changes.
+    pub fn spellcheck(
+        mut self,
+        spellcheck: impl Fn(&str) -> Vec<Range<usize>> + 'a,
+    ) -> Self {
+        self.spellcheck = Some(Box::new(spellcheck));
+        self
+    }
+
+    /// Sets the message that should be produced when a misspelled word of the
+    /// [`TextInput`] is right-clicked.
+    ///
+    /// The word is selected, so replacing the selection with a suggestion
+    /// corrects it.
+    pub fn on_misspelling(
+        mut self,
+        on_misspelling: impl Fn(Misspelling) -> Message + 'a,
+    ) -> Self {
+        self.on_misspelling = Some(Box::new(on_misspelling));
+        self
+    }
+
+    /// Sets the function that suggests corrections for a misspelled word of
+    /// the [`TextInput`].
+    ///
+    /// The suggestions are listed in a menu when the word is right-clicked,
+    /// and choosing one replaces the word.
+    pub fn suggestions(
+        mut self,
+        suggest: impl Fn(&str) -> Vec<String> + 'a,
+    ) -> Self {
+        self.suggest = Some(Box::new(suggest));
+        self
+    }
+
+    /// Docks the given adornment inside the [`TextInput`], before its text.
+    ///
+    /// The editable text area shrinks to make room for it, and clicks on it
+    /// do not move the text cursor.
+    pub fn leading(
+        mut self,
+        adornment: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.leading = Some(adornment.into());
+        self
+    }
+
+    /// Docks the given adornment inside the [`TextInput`], after its text.
+    ///
+    /// The editable text area shrinks to make room for it, and clicks on it
+    /// do not move the text cursor.
+    pub fn trailing(
+        mut self,
+        adornment: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.trailing = Some(adornment.into());
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     pub fn style(
         mut self,
@@ -179,19 +361,123 @@ where
         cursor_position: Point,
         value: Option<&Value>,
     ) {
+        let value = value.unwrap_or(&self.value);
+        let error = self.error();
+
         draw(
             renderer,
             theme,
-            layout,
+            self.input_layout(layout),
             cursor_position,
             tree.state.downcast_ref::<State>(),
-            value.unwrap_or(&self.value),
+            value,
             &self.placeholder,
             self.size,
             &self.font,
             self.is_secure,
+            &Options {
+                cursor_blink_interval: self.cursor_blink_interval,
+                reveal_icon: self.reveal_icon.as_ref(),
+                is_errored: error.is_some(),
+                misspellings: &self
+                    .misspellings(tree.state.downcast_ref::<State>(), value),
+                ..Options::default()
+            },
             &self.style,
-        )
+        );
+
+        if let (Some(error), Some(error_layout)) =
+            (&error, layout.children().nth(1))
+        {
+            draw_error(
+                renderer,
+                theme,
+                error_layout,
+                error,
+                self.size,
+                &self.font,
+                &self.style,
+            );
+        }
+
+        let style = renderer::Style {
+            text_color: theme.value_color(&self.style),
+        };
+
+        for ((adornment, state), layout) in self
+            .adornments()
+            .zip(&tree.children)
+            .zip(layout.children().skip(2))
+        {
+            adornment.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &style,
+                layout,
+                cursor_position,
+                &layout.bounds(),
+            );
+        }
+    }
+
+    /// Returns the [`Layout`] of the input field, excluding the validation
+    /// error message and the adornments.
+    fn input_layout<'b>(&self, layout: Layout<'b>) -> Layout<'b> {
+        layout.children().next().unwrap()
+    }
+
+    /// Returns the grapheme ranges of the misspelled words of the given
+    /// [`Value`], reusing the ones cached in the [`State`] if it did not
+    /// change.
+    /// Returns the message of the failed validation of the value of the
+    /// [`TextInput`], if any.
+    fn error(&self) -> Option<String> {
+        self.validate
+            .as_ref()
+            .and_then(|validate| validate(&self.value.to_string()).err())
+    }
+
+    fn misspellings(&self, state: &State, value: &Value) -> Vec<Range<usize>> {
+        let spellcheck = match &self.spellcheck {
+            Some(spellcheck) if !self.is_secure => spellcheck,
+            _ => return Vec::new(),
+        };
+
+        let text = value.to_string();
+        let mut cache = state.misspellings.borrow_mut();
+
+        if let Some((checked, misspellings)) = cache.as_ref() {
+            if *checked == text {
+                return misspellings.clone();
+            }
+        }
+
+        let misspellings: Vec<_> = spellcheck(&text)
+            .into_iter()
+            .filter_map(|range| {
+                let start = Value::new(text.get(..range.start)?).len();
+                let end = Value::new(text.get(..range.end)?).len();
+
+                (start < end).then_some(start..end)
+            })
+            .collect();
+
+        *cache = Some((text, misspellings.clone()));
+
+        misspellings
+    }
+
+    fn adornments(
+        &self,
+    ) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.leading.iter().chain(self.trailing.iter())
+    }
+
+    fn adornments_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Element<'a, Message, Renderer>> {
+        self.leading.iter_mut().chain(self.trailing.iter_mut())
     }
 }
 
@@ -210,6 +496,14 @@ where
         tree::State::new(State::new())
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.adornments().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.adornments().collect::<Vec<_>>());
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -223,20 +517,132 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(renderer, limits, self.width, self.padding, self.size)
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let adornment_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(limits.max().width, text_size),
+        )
+        .with_direction(limits.direction());
+        let leading = self.leading.as_ref().map(|adornment| {
+            adornment.as_widget().layout(renderer, &adornment_limits)
+        });
+        let trailing = self.trailing.as_ref().map(|adornment| {
+            adornment.as_widget().layout(renderer, &adornment_limits)
+        });
+
+        let space = |adornment: &Option<layout::Node>| {
+            adornment
+                .as_ref()
+                .map_or(0.0, |node| node.size().width + ADORNMENT_SPACING)
+        };
+
+        // The adornments are mirrored with the rest of the input below
+        let is_mirrored = limits.direction() == layout::Direction::RightToLeft;
+        let (start, end) = if is_mirrored {
+            (
+                self.padding.right + space(&trailing),
+                self.padding.left + space(&leading),
+            )
+        } else {
+            (
+                self.padding.left + space(&leading),
+                self.padding.right + space(&trailing),
+            )
+        };
+
+        let input = layout(
+            renderer,
+            limits,
+            self.width,
+            Padding {
+                left: start,
+                right: end,
+                ..self.padding
+            },
+            self.size,
+            self.reveal_icon.as_ref().filter(|_| self.is_secure),
+        );
+
+        let input_size = input.size();
+
+        let is_errored = self.error().is_some();
+
+        let error = if is_errored {
+            let mut error = layout::Node::new(Size::new(
+                input_size.width,
+                text_size * ERROR_TEXT_SCALE,
+            ));
+            error.move_to(Point::new(0.0, input_size.height + ERROR_SPACING));
+
+            error
+        } else {
+            layout::Node::default()
+        };
+
+        let mut children = vec![input];
+
+        if let Some(mut leading) = leading {
+            leading.move_to(Point::new(
+                self.padding.left,
+                (input_size.height - leading.size().height) / 2.0,
+            ));
+
+            children.push(leading);
+        }
+
+        if let Some(mut trailing) = trailing {
+            trailing.move_to(Point::new(
+                input_size.width - self.padding.right - trailing.size().width,
+                (input_size.height - trailing.size().height) / 2.0,
+            ));
+
+            children.push(trailing);
+        }
+
+        let height = if is_errored {
+            input_size.height + ERROR_SPACING + error.size().height
+        } else {
+            input_size.height
+        };
+
+        children.insert(1, error);
+
+        let mut node = layout::Node::with_children(
+            Size::new(input_size.width, height),
+            children,
+        );
+        node.mirror(limits.direction());
+
+        node
     }
 
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
-        _renderer: &Renderer,
+        layout: Layout<'_>,
+        renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
 
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+
+        operation.container_with_bounds(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &mut |operation| {
+                self.adornments()
+                    .zip(&mut tree.children)
+                    .zip(layout.children().skip(2))
+                    .for_each(|((adornment, state), layout)| {
+                        adornment
+                            .as_widget()
+                            .operate(state, layout, renderer, operation);
+                    })
+            },
+        );
     }
 
     fn on_event(
@@ -249,9 +655,114 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let mut is_over_adornment = false;
+
+        let status = self
+            .adornments_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().skip(2))
+            .map(|((adornment, state), layout)| {
+                is_over_adornment |= layout.bounds().contains(cursor_position);
+
+                adornment.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+            true,
+        ) = (
+            &event,
+            self.on_misspelling.is_some() || self.suggest.is_some(),
+        ) {
+            let text_layout =
+                self.input_layout(layout).children().next().unwrap();
+            let text_bounds = text_layout.bounds();
+
+            if text_bounds.contains(cursor_position) {
+                let state = tree.state.downcast_mut::<State>();
+
+                let misspelling = find_cursor_position(
+                    renderer,
+                    text_bounds,
+                    self.font.clone(),
+                    self.size,
+                    &self.value,
+                    state,
+                    cursor_position.x - text_bounds.x,
+                )
+                .and_then(|position| {
+                    self.misspellings(state, &self.value).into_iter().find(
+                        |range| {
+                            range.start <= position && position <= range.end
+                        },
+                    )
+                });
+
+                if let Some(range) = misspelling {
+                    if !state.is_focused() {
+                        state.focus();
+                    }
+
+                    state.cursor.select_range(range.start, range.end);
+
+                    let text = self.value.to_string();
+                    let start = self.value.until(range.start).to_string().len();
+                    let end = self.value.until(range.end).to_string().len();
+
+                    let word = &text[start..end];
+
+                    if let Some(on_misspelling) = &self.on_misspelling {
+                        shell.publish(on_misspelling(Misspelling {
+                            word: word.to_string(),
+                            range: start..end,
+                            position: cursor_position,
+                        }));
+                    }
+
+                    state.suggestions = self
+                        .suggest
+                        .as_ref()
+                        .map(|suggest| Suggestions {
+                            options: suggest(word),
+                            value: text.clone(),
+                            range: start..end,
+                            hovered_option: None,
+                            offset: cursor_position - layout.position(),
+                        })
+                        .filter(|suggestions| !suggestions.options.is_empty());
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        // Presses on an adornment must not move the text cursor
+        if is_over_adornment
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            )
+        {
+            return event::Status::Ignored;
+        }
+
         update(
             event,
-            layout,
+            self.input_layout(layout),
             cursor_position,
             renderer,
             clipboard,
@@ -260,8 +771,16 @@ where
             self.size,
             &self.font,
             self.is_secure,
+            &Options {
+                cursor_blink_interval: self.cursor_blink_interval,
+                accepts_drops: self.accepts_drops,
+                max_length: self.max_length,
+                filter: self.filter.as_deref(),
+                ..Options::default()
+            },
             self.on_change.as_ref(),
             self.on_paste.as_deref(),
+            self.on_paste_content.as_deref(),
             &self.on_submit,
             || tree.state.downcast_mut::<State>(),
         )
@@ -277,30 +796,80 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        draw(
-            renderer,
-            theme,
-            layout,
-            cursor_position,
-            tree.state.downcast_ref::<State>(),
-            &self.value,
-            &self.placeholder,
-            self.size,
-            &self.font,
-            self.is_secure,
-            &self.style,
-        )
+        self.draw(tree, renderer, theme, layout, cursor_position, None)
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor_position: Point,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position)
+        let adornment = self
+            .adornments()
+            .zip(&tree.children)
+            .zip(layout.children().skip(2))
+            .find(|(_, layout)| layout.bounds().contains(cursor_position));
+
+        if let Some(((adornment, state), layout)) = adornment {
+            return adornment.as_widget().mouse_interaction(
+                state,
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            );
+        }
+
+        mouse_interaction(self.input_layout(layout), cursor_position)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        // The suggestions are dismissed once the misspelled word is edited
+        if state.suggestions.as_ref().map_or(false, |suggestions| {
+            !state.is_focused() || suggestions.value != self.value.to_string()
+        }) {
+            state.suggestions = None;
+        }
+
+        if let Some(offset) = state
+            .suggestions
+            .as_ref()
+            .map(|suggestions| suggestions.offset)
+        {
+            return Some(overlay::Element::new(
+                layout.position() + offset,
+                Box::new(suggestions::Overlay::new(
+                    state,
+                    self.on_change.as_ref(),
+                    self.font.clone(),
+                    self.size,
+                    self.padding,
+                    &self.style,
+                )),
+            ));
+        }
+
+        self.leading
+            .iter_mut()
+            .chain(self.trailing.iter_mut())
+            .zip(children)
+            .zip(layout.children().skip(2))
+            .find_map(|((adornment, state), layout)| {
+                adornment.as_widget_mut().overlay(state, layout, renderer)
+            })
     }
 }
 
@@ -318,6 +887,53 @@ where
     }
 }
 
+/// A misspelled word of a [`TextInput`] that was right-clicked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Misspelling {
+    /// The misspelled word.
+    pub word: String,
+    /// The byte range of the word in the value of the [`TextInput`].
+    pub range: Range<usize>,
+    /// The position of the mouse cursor when the word was right-clicked.
+    pub position: Point,
+}
+
+/// The options of a text input that are passed to [`update`] and [`draw`].
+///
+/// Fields may be added to it over time, so the ones that are not used should
+/// be filled with [`Options::default`].
+#[allow(missing_debug_implementations)]
+pub struct Options<'a, Font> {
+    /// The interval at which the text cursor blinks, if it does.
+    pub cursor_blink_interval: Option<Duration>,
+    /// Whether dropped files are inserted as their paths.
+    pub accepts_drops: bool,
+    /// The maximum amount of characters of the value.
+    pub max_length: Option<usize>,
+    /// The function that accepts the characters that can be typed.
+    pub filter: Option<&'a dyn Fn(char) -> bool>,
+    /// The icon that reveals a secure value while pressed.
+    pub reveal_icon: Option<&'a Icon<Font>>,
+    /// Whether the value failed its validation.
+    pub is_errored: bool,
+    /// The grapheme ranges of the misspelled words of the value.
+    pub misspellings: &'a [Range<usize>],
+}
+
+impl<'a, Font> Default for Options<'a, Font> {
+    fn default() -> Self {
+        Self {
+            cursor_blink_interval: Some(CURSOR_BLINK_INTERVAL),
+            accepts_drops: false,
+            max_length: None,
+            filter: None,
+            reveal_icon: None,
+            is_errored: false,
+            misspellings: &[],
+        }
+    }
+}
+
 /// The identifier of a [`TextInput`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Id(widget::Id);
@@ -380,6 +996,7 @@ pub fn layout<Renderer>(
     width: Length,
     padding: Padding,
     size: Option<f32>,
+    reveal_icon: Option<&Icon<Renderer::Font>>,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -388,11 +1005,31 @@ where
 
     let padding = padding.fit(Size::ZERO, limits.max());
     let limits = limits.width(width).pad(padding).height(text_size);
+    let content_size = limits.resolve(Size::ZERO);
+
+    if let Some(icon) = reveal_icon {
+        let icon_width = icon.size.unwrap_or(text_size);
+        let text_width =
+            (content_size.width - icon_width - REVEAL_ICON_SPACING).max(0.0);
+
+        let mut text =
+            layout::Node::new(Size::new(text_width, content_size.height));
+        text.move_to(Point::new(padding.left, padding.top));
 
-    let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-    text.move_to(Point::new(padding.left, padding.top));
+        let mut icon =
+            layout::Node::new(Size::new(icon_width, content_size.height));
+        icon.move_to(Point::new(
+            padding.left + content_size.width - icon_width,
+            padding.top,
+        ));
 
-    layout::Node::with_children(text.size().pad(padding), vec![text])
+        layout::Node::with_children(content_size.pad(padding), vec![text, icon])
+    } else {
+        let mut text = layout::Node::new(content_size);
+        text.move_to(Point::new(padding.left, padding.top));
+
+        layout::Node::with_children(content_size.pad(padding), vec![text])
+    }
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`TextInput`]
@@ -408,8 +1045,10 @@ pub fn update<'a, Message, Renderer>(
     size: Option<f32>,
     font: &Renderer::Font,
     is_secure: bool,
+    options: &Options<'_, Renderer::Font>,
     on_change: &dyn Fn(String) -> Message,
     on_paste: Option<&dyn Fn(String) -> Message>,
+    on_paste_content: Option<&dyn Fn(clipboard::Content) -> Option<Message>>,
     on_submit: &Option<Message>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status
@@ -417,10 +1056,22 @@ where
     Message: Clone,
     Renderer: text::Renderer,
 {
+    let max_length = options.max_length;
+    let filter = options.filter;
+
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
+
+            if let Some(icon_layout) = layout.children().nth(1) {
+                if icon_layout.bounds().contains(cursor_position) {
+                    state.is_revealing = true;
+
+                    return event::Status::Captured;
+                }
+            }
+
             let is_clicked = layout.bounds().contains(cursor_position);
 
             state.is_focused = if is_clicked {
@@ -474,11 +1125,12 @@ where
                         } else {
                             state.cursor.move_to(position);
                         }
-                        state.is_dragging = true;
+                        state.is_dragging = Some(Drag::Characters);
                     }
                     click::Kind::Double => {
                         if is_secure {
                             state.cursor.select_all(value);
+                            state.is_dragging = None;
                         } else {
                             let position = find_cursor_position(
                                 renderer,
@@ -491,17 +1143,17 @@ where
                             )
                             .unwrap_or(0);
 
-                            state.cursor.select_range(
-                                value.previous_start_of_word(position),
-                                value.next_end_of_word(position),
-                            );
-                        }
+                            let start = value.previous_start_of_word(position);
+                            let end = value.next_end_of_word(position);
 
-                        state.is_dragging = false;
+                            state.cursor.select_range(start, end);
+                            state.is_dragging =
+                                Some(Drag::Words { start, end });
+                        }
                     }
                     click::Kind::Triple => {
                         state.cursor.select_all(value);
-                        state.is_dragging = false;
+                        state.is_dragging = None;
                     }
                 }
 
@@ -513,13 +1165,16 @@ where
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. })
         | Event::Touch(touch::Event::FingerLost { .. }) => {
-            state().is_dragging = false;
+            let state = state();
+
+            state.is_dragging = None;
+            state.is_revealing = false;
         }
         Event::Mouse(mouse::Event::CursorMoved { position })
         | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
             let state = state();
 
-            if state.is_dragging {
+            if let Some(drag) = state.is_dragging {
                 let text_layout = layout.children().next().unwrap();
                 let target = position.x - text_layout.bounds().x;
 
@@ -540,9 +1195,24 @@ where
                 )
                 .unwrap_or(0);
 
-                state
-                    .cursor
-                    .select_range(state.cursor.start(&value), position);
+                match drag {
+                    Drag::Characters => state
+                        .cursor
+                        .select_range(state.cursor.start(&value), position),
+                    Drag::Words { start, end } => {
+                        if position < start {
+                            state.cursor.select_range(
+                                end,
+                                value.previous_start_of_word(position),
+                            );
+                        } else {
+                            state.cursor.select_range(
+                                start,
+                                value.next_end_of_word(position).max(end),
+                            );
+                        }
+                    }
+                }
 
                 return event::Status::Captured;
             }
@@ -555,6 +1225,18 @@ where
                     && !state.keyboard_modifiers.command()
                     && !c.is_control()
                 {
+                    let content = restrict(
+                        &c.to_string(),
+                        value,
+                        &state.cursor,
+                        max_length,
+                        filter,
+                    );
+
+                    if content.is_empty() {
+                        return event::Status::Captured;
+                    }
+
                     let mut editor = Editor::new(value, &mut state.cursor);
 
                     editor.insert(c);
@@ -700,6 +1382,18 @@ where
                             let content = match state.is_pasting.take() {
                                 Some(content) => content,
                                 None => {
+                                    if let Some(message) = on_paste_content
+                                        .and_then(|on_paste_content| {
+                                            clipboard
+                                                .read_content()
+                                                .and_then(on_paste_content)
+                                        })
+                                    {
+                                        shell.publish(message);
+
+                                        return event::Status::Captured;
+                                    }
+
                                     let content: String = clipboard
                                         .read()
                                         .unwrap_or_default()
@@ -711,6 +1405,14 @@ where
                                 }
                             };
 
+                            let content = restrict(
+                                &content.to_string(),
+                                value,
+                                &state.cursor,
+                                max_length,
+                                filter,
+                            );
+
                             let mut editor =
                                 Editor::new(value, &mut state.cursor);
 
@@ -735,8 +1437,9 @@ where
                     }
                     keyboard::KeyCode::Escape => {
                         state.is_focused = None;
-                        state.is_dragging = false;
+                        state.is_dragging = None;
                         state.is_pasting = None;
+                        state.preedit = None;
 
                         state.keyboard_modifiers =
                             keyboard::Modifiers::default();
@@ -778,19 +1481,161 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
+        Event::InputMethod(event) => {
+            let state = state();
+
+            if state.is_focused.is_some() && !is_secure {
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = (!content.is_empty())
+                            .then_some(Preedit { content, selection });
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+
+                        let content = restrict(
+                            &content,
+                            value,
+                            &state.cursor,
+                            max_length,
+                            filter,
+                        );
+
+                        let mut editor = Editor::new(value, &mut state.cursor);
+                        editor.paste(content);
+
+                        let message = (on_change)(editor.contents());
+                        shell.publish(message);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Window(window::Event::FileHovered(_))
+            if options.accepts_drops =>
+        {
+            let state = state();
+
+            state.drop_position =
+                layout.bounds().contains(cursor_position).then(|| {
+                    let text_layout = layout.children().next().unwrap();
+
+                    find_cursor_position(
+                        renderer,
+                        text_layout.bounds(),
+                        font.clone(),
+                        size,
+                        value,
+                        state,
+                        cursor_position.x - text_layout.bounds().x,
+                    )
+                    .unwrap_or(value.len())
+                });
+        }
+        Event::Window(window::Event::FilesHoveredLeft) => {
+            state().drop_position = None;
+        }
+        Event::Window(window::Event::FileDropped(path))
+            if options.accepts_drops =>
+        {
+            let state = state();
+            let drop_position = state.drop_position.take();
+
+            if layout.bounds().contains(cursor_position) {
+                let text_layout = layout.children().next().unwrap();
+
+                let position = drop_position
+                    .or_else(|| {
+                        find_cursor_position(
+                            renderer,
+                            text_layout.bounds(),
+                            font.clone(),
+                            size,
+                            value,
+                            state,
+                            cursor_position.x - text_layout.bounds().x,
+                        )
+                    })
+                    .unwrap_or(value.len());
+
+                state.cursor.move_to(position);
+
+                let content = restrict(
+                    &path.to_string_lossy(),
+                    value,
+                    &state.cursor,
+                    max_length,
+                    filter,
+                );
+
+                let mut editor = Editor::new(value, &mut state.cursor);
+                editor.paste(content);
+
+                let message = (on_change)(editor.contents());
+                shell.publish(message);
+
+                let now = Instant::now();
+
+                state.is_focused = Some(Focus {
+                    updated_at: now,
+                    now,
+                });
+
+                return event::Status::Captured;
+            }
+        }
         Event::Window(window::Event::RedrawRequested(now)) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
                 focus.now = now;
 
-                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
-                    - (now - focus.updated_at).as_millis()
-                        % CURSOR_BLINK_INTERVAL_MILLIS;
+                if let Some(interval) = options
+                    .cursor_blink_interval
+                    .filter(|_| !state.is_cursor_static.get())
+                {
+                    let interval = interval.as_millis().max(1);
+
+                    let millis_until_redraw = interval
+                        - (now - focus.updated_at).as_millis() % interval;
 
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(millis_until_redraw as u64),
-                ));
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + Duration::from_millis(millis_until_redraw as u64),
+                    ));
+                }
+
+                if !is_secure {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let size = size.unwrap_or_else(|| renderer.default_size());
+
+                    let composition = compose(value, state);
+                    let (value, caret) = composition
+                        .as_ref()
+                        .map(|(value, _, caret)| (value, *caret))
+                        .unwrap_or((value, state.cursor.end(value)));
+
+                    let (caret_width, offset) =
+                        measure_cursor_and_scroll_offset(
+                            renderer,
+                            text_bounds,
+                            value,
+                            size,
+                            caret,
+                            font.clone(),
+                        );
+
+                    shell.request_input_method(Rectangle {
+                        x: text_bounds.x + caret_width - offset,
+                        width: 1.0,
+                        ..text_bounds
+                    });
+                }
             }
         }
         _ => {}
@@ -814,20 +1659,36 @@ pub fn draw<Renderer>(
     size: Option<f32>,
     font: &Renderer::Font,
     is_secure: bool,
+    options: &Options<'_, Renderer::Font>,
     style: &<Renderer::Theme as StyleSheet>::Style,
 ) where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    let is_secure = is_secure && !state.is_revealing;
     let secure_value = is_secure.then(|| value.secure());
     let value = secure_value.as_ref().unwrap_or(value);
 
+    let composition = if is_secure {
+        None
+    } else {
+        compose(value, state)
+    };
+    let value = composition
+        .as_ref()
+        .map(|(value, _, _)| value)
+        .unwrap_or(value);
+
     let bounds = layout.bounds();
     let text_bounds = layout.children().next().unwrap().bounds();
 
     let is_mouse_over = bounds.contains(cursor_position);
 
-    let appearance = if state.is_focused() {
+    let is_focused = state.is_focused() || state.drop_position.is_some();
+
+    let appearance = if options.is_errored {
+        theme.errored(style, is_focused)
+    } else if is_focused {
         theme.focused(style)
     } else if is_mouse_over {
         theme.hovered(style)
@@ -845,11 +1706,70 @@ pub fn draw<Renderer>(
         appearance.background,
     );
 
+    if let (Some(icon), Some(icon_layout)) =
+        (options.reveal_icon, layout.children().nth(1))
+    {
+        let icon_bounds = icon_layout.bounds();
+
+        renderer.fill_text(Text {
+            content: &icon.code_point.to_string(),
+            color: if state.is_revealing {
+                theme.value_color(style)
+            } else {
+                theme.placeholder_color(style)
+            },
+            font: icon.font.clone(),
+            bounds: Rectangle {
+                x: icon_bounds.center_x(),
+                y: icon_bounds.center_y(),
+                ..icon_bounds
+            },
+            size: icon.size.unwrap_or_else(|| renderer.default_size()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
     let text = value.to_string();
     let size = size.unwrap_or_else(|| renderer.default_size());
 
-    let (cursor, offset) = if let Some(focus) = &state.is_focused {
-        match state.cursor.state(value) {
+    let cursor_state = match &composition {
+        Some((_, _, caret)) => cursor::State::Index(*caret),
+        None => state.cursor.state(value),
+    };
+
+    let (cursor, offset) = if let Some(position) = state.drop_position {
+        let (text_value_width, offset) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            value,
+            size,
+            position,
+            font.clone(),
+        );
+
+        (
+            Some((
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + text_value_width,
+                        y: text_bounds.y,
+                        width: 1.0,
+                        height: text_bounds.height,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                theme.value_color(style),
+            )),
+            offset,
+        )
+    } else if let Some(focus) = &state.is_focused {
+        let is_cursor_blinking = theme.is_cursor_blinking(style);
+        state.is_cursor_static.set(!is_cursor_blinking);
+
+        match cursor_state {
             cursor::State::Index(position) => {
                 let (text_value_width, offset) =
                     measure_cursor_and_scroll_offset(
@@ -861,11 +1781,18 @@ pub fn draw<Renderer>(
                         font.clone(),
                     );
 
-                let is_cursor_visible = ((focus.now - focus.updated_at)
-                    .as_millis()
-                    / CURSOR_BLINK_INTERVAL_MILLIS)
-                    % 2
-                    == 0;
+                let is_cursor_visible = match options
+                    .cursor_blink_interval
+                    .filter(|_| is_cursor_blinking)
+                {
+                    Some(interval) => {
+                        ((focus.now - focus.updated_at).as_millis()
+                            / interval.as_millis().max(1))
+                            % 2
+                            == 0
+                    }
+                    None => true,
+                };
 
                 let cursor = if is_cursor_visible {
                     Some((
@@ -947,11 +1874,73 @@ pub fn draw<Renderer>(
         font.clone(),
     );
 
+    let underline = composition.as_ref().map(|(value, preedit, _)| {
+        let start = renderer.measure_width(
+            &value.until(preedit.start).to_string(),
+            size,
+            font.clone(),
+        );
+        let end = renderer.measure_width(
+            &value.until(preedit.end).to_string(),
+            size,
+            font.clone(),
+        );
+
+        renderer::Quad {
+            bounds: Rectangle {
+                x: text_bounds.x + start,
+                y: text_bounds.y + text_bounds.height - 1.0,
+                width: end - start,
+                height: 1.0,
+            },
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    });
+
+    // Misspellings are not tracked through compositions or secure values
+    let squiggles: Vec<_> = if composition.is_none() && !is_secure {
+        options
+            .misspellings
+            .iter()
+            .map(|range| {
+                let start = renderer.measure_width(
+                    &value.until(range.start).to_string(),
+                    size,
+                    font.clone(),
+                );
+                let end = renderer.measure_width(
+                    &value.until(range.end).to_string(),
+                    size,
+                    font.clone(),
+                );
+
+                Rectangle {
+                    x: text_bounds.x + start,
+                    y: text_bounds.y + text_bounds.height - SQUIGGLE_HEIGHT,
+                    width: end - start,
+                    height: SQUIGGLE_HEIGHT,
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     let render = |renderer: &mut Renderer| {
         if let Some((cursor, color)) = cursor {
             renderer.fill_quad(cursor, color);
         }
 
+        if let Some(underline) = underline {
+            renderer.fill_quad(underline, theme.value_color(style));
+        }
+
+        for squiggle in &squiggles {
+            draw_squiggle(renderer, *squiggle, theme.misspelling_color(style));
+        }
+
         renderer.fill_text(Text {
             content: if text.is_empty() { placeholder } else { &text },
             color: if text.is_empty() {
@@ -980,15 +1969,86 @@ pub fn draw<Renderer>(
     }
 }
 
+/// Draws the validation error message of a [`TextInput`].
+fn draw_error<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    error: &str,
+    size: Option<f32>,
+    font: &Renderer::Font,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+) where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let bounds = layout.bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    renderer.fill_text(Text {
+        content: error,
+        color: theme.error_color(style),
+        font: font.clone(),
+        bounds: Rectangle {
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size: size * ERROR_TEXT_SCALE,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Center,
+    });
+}
+
+/// Draws a squiggly underline filling the given bounds.
+fn draw_squiggle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    let step = SQUIGGLE_HEIGHT;
+    let mut x = bounds.x;
+    let mut is_raised = false;
+
+    while x < bounds.x + bounds.width {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: if is_raised {
+                        bounds.y
+                    } else {
+                        bounds.y + bounds.height - 1.0
+                    },
+                    width: step.min(bounds.x + bounds.width - x),
+                    height: 1.0,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+
+        x += step;
+        is_raised = !is_raised;
+    }
+}
+
 /// Computes the current [`mouse::Interaction`] of the [`TextInput`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
     cursor_position: Point,
 ) -> mouse::Interaction {
-    if layout.bounds().contains(cursor_position) {
-        mouse::Interaction::Text
-    } else {
-        mouse::Interaction::default()
+    match layout.children().nth(1) {
+        Some(icon) if icon.bounds().contains(cursor_position) => {
+            mouse::Interaction::Pointer
+        }
+        _ if layout.bounds().contains(cursor_position) => {
+            mouse::Interaction::Text
+        }
+        _ => mouse::Interaction::default(),
     }
 }
 
@@ -996,14 +2056,40 @@ pub fn mouse_interaction(
 #[derive(Debug, Default, Clone)]
 pub struct State {
     is_focused: Option<Focus>,
-    is_dragging: bool,
+    is_dragging: Option<Drag>,
     is_pasting: Option<Value>,
+    is_revealing: bool,
+    drop_position: Option<usize>,
+    preedit: Option<Preedit>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    suggestions: Option<Suggestions>,
+    // The last spellchecked value and its misspellings
+    misspellings: RefCell<Option<(String, Vec<Range<usize>>)>>,
+    // Whether the theme disabled blinking when last drawn, to stop
+    // requesting redraws for it
+    is_cursor_static: Cell<bool>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+/// The granularity of an ongoing drag selection.
+#[derive(Debug, Clone, Copy)]
+enum Drag {
+    Characters,
+    /// Started by a double click on the word between `start` and `end`.
+    Words {
+        start: usize,
+        end: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Preedit {
+    content: String,
+    selection: Option<std::ops::Range<usize>>,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
@@ -1020,11 +2106,17 @@ impl State {
     pub fn focused() -> Self {
         Self {
             is_focused: None,
-            is_dragging: false,
+            is_dragging: None,
             is_pasting: None,
+            is_revealing: false,
+            drop_position: None,
+            preedit: None,
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            suggestions: None,
+            misspellings: RefCell::new(None),
+            is_cursor_static: Cell::new(false),
         }
     }
 
@@ -1053,6 +2145,7 @@ impl State {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1154,6 +2247,69 @@ where
     }
 }
 
+/// Inserts the current input method composition of the [`State`] into the
+/// given [`Value`], replacing its selection.
+///
+/// Returns the composed [`Value`], the range of graphemes of the composition,
+/// and the position of the text cursor inside of it.
+fn compose(
+    value: &Value,
+    state: &State,
+) -> Option<(Value, std::ops::Range<usize>, usize)> {
+    let preedit = state.preedit.as_ref().filter(|_| state.is_focused())?;
+
+    let (left, right) = state.cursor.selection(value).unwrap_or_else(|| {
+        let position = state.cursor.end(value);
+
+        (position, position)
+    });
+
+    let content = Value::new(&preedit.content);
+    let length = content.len();
+
+    let caret = match &preedit.selection {
+        Some(selection) => {
+            Value::new(preedit.content.get(..selection.end).unwrap_or_default())
+                .len()
+        }
+        None => length,
+    };
+
+    let mut composed = value.clone();
+    composed.remove_many(left, right);
+    composed.insert_many(left, content);
+
+    Some((composed, left..left + length, left + caret))
+}
+
+/// Restricts some content about to be inserted at the [`Cursor`] to the
+/// characters accepted by the `filter` and to the space left by `max_length`.
+fn restrict(
+    content: &str,
+    value: &Value,
+    cursor: &Cursor,
+    max_length: Option<usize>,
+    filter: Option<&dyn Fn(char) -> bool>,
+) -> Value {
+    let content = match filter {
+        Some(filter) => Value::new(
+            &content.chars().filter(|c| filter(*c)).collect::<String>(),
+        ),
+        None => Value::new(content),
+    };
+
+    match max_length {
+        Some(max_length) => {
+            let selected = cursor
+                .selection(value)
+                .map_or(0, |(start, end)| end - start);
+
+            content.until(max_length.saturating_sub(value.len() - selected))
+        }
+        None => content,
+    }
+}
+
 fn measure_cursor_and_scroll_offset<Renderer>(
     renderer: &Renderer,
     text_bounds: Rectangle,
@@ -1206,4 +2362,76 @@ where
         .map(text::Hit::cursor)
 }
 
-const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+pub(crate) const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+const REVEAL_ICON_SPACING: f32 = 5.0;
+
+const ERROR_SPACING: f32 = 4.0;
+
+const ADORNMENT_SPACING: f32 = 5.0;
+
+const SQUIGGLE_HEIGHT: f32 = 2.0;
+
+const ERROR_TEXT_SCALE: f32 = 0.8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::rc::Rc;
+
+    type TextInput = super::TextInput<'static, String, crate::renderer::Null>;
+
+    #[test]
+    fn misspellings_are_checked_once_per_value() {
+        let checks = Rc::new(Cell::new(0));
+
+        let input =
+            TextInput::new("", "helo wrld", |value| value).spellcheck({
+                let checks = checks.clone();
+
+                move |text| {
+                    checks.set(checks.get() + 1);
+
+                    text.match_indices("wrld")
+                        .map(|(start, word)| start..start + word.len())
+                        .collect()
+                }
+            });
+
+        let state = State::new();
+
+        assert_eq!(input.misspellings(&state, &input.value), vec![5..9]);
+        assert_eq!(input.misspellings(&state, &input.value), vec![5..9]);
+        assert_eq!(checks.get(), 1);
+
+        assert_eq!(input.misspellings(&state, &Value::new("wrld")), vec![0..4]);
+        assert_eq!(checks.get(), 2);
+    }
+
+    #[test]
+    fn validation_is_deferred_until_needed() {
+        let validations = Rc::new(Cell::new(0));
+
+        let input = TextInput::new("", "42", |value| value).on_validate({
+            let validations = validations.clone();
+
+            move |text| {
+                validations.set(validations.get() + 1);
+
+                text.parse::<u8>()
+                    .map(|_| ())
+                    .map_err(|_| String::from("Not a number"))
+            }
+        });
+
+        assert_eq!(validations.get(), 0);
+        assert_eq!(input.error(), None);
+
+        let input = TextInput::new("", "abc", |value| value)
+            .on_validate(|text| Err(format!("{text} is invalid")));
+
+        assert_eq!(input.error(), Some(String::from("abc is invalid")));
+        assert_eq!(validations.get(), 1);
+    }
+}
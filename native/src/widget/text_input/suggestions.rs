@@ -0,0 +1,317 @@
+//! Suggest corrections for a misspelled word of a text input.
+use super::{State, StyleSheet, Value};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay::menu;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Clipboard, Color, Layout, Padding, Point, Rectangle, Shell, Size, Vector,
+};
+
+use std::ops::Range;
+
+/// The corrections suggested for a right-clicked misspelled word.
+#[derive(Debug, Clone)]
+pub(super) struct Suggestions {
+    /// The value of the text input the suggestions were made for.
+    pub(super) value: String,
+    /// The byte range of the misspelled word in the value.
+    pub(super) range: Range<usize>,
+    pub(super) options: Vec<String>,
+    pub(super) hovered_option: Option<usize>,
+    /// The position of the right click, relative to the text input.
+    pub(super) offset: Vector,
+}
+
+/// The menu listing the [`Suggestions`] of a text input.
+pub(super) struct Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    on_change: &'a dyn Fn(String) -> Message,
+    font: Renderer::Font,
+    text_size: Option<f32>,
+    padding: Padding,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(
+        state: &'a mut State,
+        on_change: &'a dyn Fn(String) -> Message,
+        font: Renderer::Font,
+        text_size: Option<f32>,
+        padding: Padding,
+        style: &'a <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            on_change,
+            font,
+            text_size,
+            padding,
+            style,
+        }
+    }
+
+    fn options(&self) -> &[String] {
+        self.state
+            .suggestions
+            .as_ref()
+            .map_or(&[], |suggestions| &suggestions.options)
+    }
+
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+            + self.padding.vertical()
+    }
+
+    /// Returns the index of the option under the cursor, if any.
+    fn option_at(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let index = ((cursor_position.y - bounds.y)
+            / self.option_height(renderer)) as usize;
+
+        (index < self.options().len()).then_some(index)
+    }
+
+    /// Replaces the misspelled word with the option at the given index.
+    fn choose(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(suggestions) = self.state.suggestions.take() {
+            if let Some(option) = suggestions.options.get(index) {
+                let mut value = suggestions.value;
+                value.replace_range(suggestions.range.clone(), option);
+
+                let end = suggestions.range.start + option.len();
+
+                self.state.cursor.move_to(Value::new(&value[..end]).len());
+
+                shell.publish((self.on_change)(value));
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let size = Size::new(
+            self.options()
+                .iter()
+                .map(|option| {
+                    renderer.measure_width(option, text_size, self.font.clone())
+                })
+                .fold(0.0, f32::max)
+                + self.padding.horizontal(),
+            self.option_height(renderer) * self.options().len() as f32,
+        );
+
+        // The menu is moved to the left when it would not fit on the right
+        let position = Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            position.y,
+        );
+
+        menu::layout_attached(bounds, position, 0.0, |limits| {
+            layout::Node::new(limits.resolve(size))
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) =
+                    self.option_at(layout, renderer, cursor_position)
+                {
+                    if let Some(suggestions) = &mut self.state.suggestions {
+                        suggestions.hovered_option = Some(index);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    self.option_at(layout, renderer, cursor_position)
+                {
+                    self.choose(index, shell);
+
+                    return event::Status::Captured;
+                }
+
+                // A press elsewhere dismisses the suggestions and reaches the
+                // widgets under it
+                self.state.suggestions = None;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let hovered_option = self
+                    .state
+                    .suggestions
+                    .as_ref()
+                    .and_then(|suggestions| suggestions.hovered_option);
+                let count = self.options().len();
+
+                match key_code {
+                    keyboard::KeyCode::Escape => {
+                        self.state.suggestions = None;
+                    }
+                    keyboard::KeyCode::Enter => match hovered_option {
+                        Some(index) => self.choose(index, shell),
+                        None => self.state.suggestions = None,
+                    },
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                        if count > 0 =>
+                    {
+                        let index = match (key_code, hovered_option) {
+                            (keyboard::KeyCode::Up, Some(index)) => {
+                                (index + count - 1) % count
+                            }
+                            (keyboard::KeyCode::Up, None) => count - 1,
+                            (_, Some(index)) => (index + 1) % count,
+                            (_, None) => 0,
+                        };
+
+                        if let Some(suggestions) = &mut self.state.suggestions {
+                            suggestions.hovered_option = Some(index);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let suggestions = match &self.state.suggestions {
+            Some(suggestions) => suggestions,
+            None => return,
+        };
+
+        let appearance = theme.suggestions(self.style);
+        let bounds = layout.bounds();
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let option_height = self.option_height(renderer);
+
+        renderer.fill_quad_with_shadow(
+            renderer::Quad {
+                bounds,
+                border_color: appearance.border_color,
+                border_width: appearance.border_width,
+                border_radius: appearance.border_radius.into(),
+            },
+            appearance.background,
+            appearance.shadow,
+        );
+
+        for (i, option) in suggestions.options.iter().enumerate() {
+            let is_selected = suggestions.hovered_option == Some(i);
+
+            let bounds = Rectangle {
+                y: bounds.y + option_height * i as f32,
+                height: option_height,
+                ..bounds
+            };
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_color: Color::TRANSPARENT,
+                        border_width: 0.0,
+                        border_radius: appearance.border_radius.into(),
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: option,
+                bounds: Rectangle {
+                    x: bounds.x + self.padding.left,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: text_size,
+                font: self.font.clone(),
+                color: if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
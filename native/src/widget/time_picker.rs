@@ -0,0 +1,658 @@
+//! Let your users pick a time of the day with a spinner for every unit.
+//!
+//! A [`TimePicker`] has some local [`State`].
+mod time;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+pub use time::{ParseError, Period, Time};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+/// The clock used by a [`TimePicker`] to display hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Hours from 0 to 23.
+    #[default]
+    TwentyFourHour,
+    /// Hours from 1 to 12, followed by a [`Period`].
+    TwelveHour,
+}
+
+/// A set of spinners to pick the hour, the minute and, optionally, the second
+/// of a [`Time`].
+///
+/// Every spinner can be stepped with its buttons, the mouse wheel or the
+/// arrow keys once focused, and typed into with the digit keys. The left and
+/// right arrow keys move the focus between spinners.
+///
+/// # Example
+/// ```
+/// # pub type TimePicker<'a, Message> = iced_native::widget::TimePicker<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::time_picker::{Format, Time};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AlarmChanged(Time),
+/// }
+///
+/// let alarm = Time::new(7, 30, 0).unwrap();
+///
+/// let picker = TimePicker::new(alarm, Message::AlarmChanged)
+///     .format(Format::TwelveHour);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    value: Time,
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    format: Format,
+    show_seconds: bool,
+    font: Renderer::Font,
+    padding: Padding,
+    size: Option<f32>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TimePicker`].
+    ///
+    /// It expects:
+    /// - the current [`Time`], and
+    /// - a function that produces a message when the [`Time`] changes.
+    pub fn new<F>(value: Time, on_change: F) -> Self
+    where
+        F: 'a + Fn(Time) -> Message,
+    {
+        TimePicker {
+            value,
+            on_change: Box::new(on_change),
+            format: Format::default(),
+            show_seconds: false,
+            font: Default::default(),
+            padding: Padding::new(5.0),
+            size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Format`] of the hours of the [`TimePicker`].
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether the [`TimePicker`] has a spinner for the seconds.
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TimePicker`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Padding`] of the spinners of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn segments(&self) -> Vec<Segment> {
+        let mut segments = vec![Segment::Hour, Segment::Minute];
+
+        if self.show_seconds {
+            segments.push(Segment::Second);
+        }
+
+        if self.format == Format::TwelveHour {
+            segments.push(Segment::Period);
+        }
+
+        segments
+    }
+
+    fn label(&self, segment: Segment) -> String {
+        match segment {
+            Segment::Hour => match self.format {
+                Format::TwentyFourHour => format!("{:02}", self.value.hour()),
+                Format::TwelveHour => format!("{:02}", self.value.hour12().0),
+            },
+            Segment::Minute => format!("{:02}", self.value.minute()),
+            Segment::Second => format!("{:02}", self.value.second()),
+            Segment::Period => self.value.hour12().1.to_string(),
+        }
+    }
+
+    fn change(&mut self, value: Time, shell: &mut Shell<'_, Message>) {
+        if value != self.value {
+            self.value = value;
+
+            shell.publish((self.on_change)(value));
+        }
+    }
+
+    /// Steps the given [`Segment`] of the value by the given amount,
+    /// wrapping around without changing the other segments.
+    fn step(
+        &mut self,
+        segment: Segment,
+        amount: i32,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let time = self.value;
+
+        let wrap = |value: u8, max: i32| {
+            (i32::from(value) + amount).rem_euclid(max) as u8
+        };
+
+        let value = match segment {
+            Segment::Hour => {
+                Time::new(wrap(time.hour(), 24), time.minute(), time.second())
+            }
+            Segment::Minute => {
+                Time::new(time.hour(), wrap(time.minute(), 60), time.second())
+            }
+            Segment::Second => {
+                Time::new(time.hour(), time.minute(), wrap(time.second(), 60))
+            }
+            Segment::Period if amount % 2 != 0 => {
+                Time::new((time.hour() + 12) % 24, time.minute(), time.second())
+            }
+            Segment::Period => None,
+        };
+
+        if let Some(value) = value {
+            self.change(value, shell);
+        }
+    }
+
+    /// Types the given digit into the focused [`Segment`].
+    ///
+    /// Returns whether the [`Segment`] is complete and the focus should move
+    /// to the next one.
+    fn type_digit(
+        &mut self,
+        state: &mut State,
+        segment: Segment,
+        digit: u8,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let time = self.value;
+        let (min, max) = match (segment, self.format) {
+            (Segment::Hour, Format::TwelveHour) => (1, 12),
+            (Segment::Hour, Format::TwentyFourHour) => (0, 23),
+            (Segment::Minute | Segment::Second, _) => (0, 59),
+            (Segment::Period, _) => return false,
+        };
+
+        let number = match state.typed.take() {
+            Some(first) if first * 10 + digit <= max => first * 10 + digit,
+            _ => digit,
+        };
+
+        // A second digit could still follow
+        let is_complete = number >= 10 || number * 10 > max;
+
+        if !is_complete {
+            state.typed = Some(number);
+        }
+
+        if number >= min {
+            let value = match segment {
+                Segment::Hour => match self.format {
+                    Format::TwentyFourHour => {
+                        Time::new(number, time.minute(), time.second())
+                    }
+                    Format::TwelveHour => Time::from_hour12(
+                        number,
+                        time.hour12().1,
+                        time.minute(),
+                        time.second(),
+                    ),
+                },
+                Segment::Minute => {
+                    Time::new(time.hour(), number, time.second())
+                }
+                Segment::Second => {
+                    Time::new(time.hour(), time.minute(), number)
+                }
+                Segment::Period => None,
+            };
+
+            if let Some(value) = value {
+                self.change(value, shell);
+            }
+        }
+
+        is_complete
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let row_height = text_size + self.padding.vertical();
+        let separator =
+            renderer.measure_width(":", text_size, self.font.clone())
+                + self.padding.horizontal();
+
+        let mut x = 0.0;
+
+        let segments = self
+            .segments()
+            .into_iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                let content = match segment {
+                    Segment::Period => "PM",
+                    _ => "00",
+                };
+
+                let width = renderer.measure_width(
+                    content,
+                    text_size,
+                    self.font.clone(),
+                ) + self.padding.horizontal();
+
+                if i > 0 {
+                    x += separator;
+                }
+
+                let rows = (0..3)
+                    .map(|row| {
+                        let mut node =
+                            layout::Node::new(Size::new(width, row_height));
+                        node.move_to(Point::new(0.0, row as f32 * row_height));
+
+                        node
+                    })
+                    .collect();
+
+                let mut node = layout::Node::with_children(
+                    Size::new(width, row_height * 3.0),
+                    rows,
+                );
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, row_height * 3.0));
+
+        layout::Node::with_children(size, segments)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let segments = self.segments();
+
+        let hovered = segments.iter().zip(layout.children()).find_map(
+            |(segment, layout)| {
+                layout
+                    .children()
+                    .position(|row| row.bounds().contains(cursor_position))
+                    .map(|row| (*segment, row))
+            },
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match hovered
+            {
+                Some((segment, row)) => {
+                    state.focus_segment(segment);
+
+                    match row {
+                        0 => self.step(segment, 1, shell),
+                        2 => self.step(segment, -1, shell),
+                        _ => {}
+                    }
+
+                    return event::Status::Captured;
+                }
+                None => {
+                    state.focused = None;
+                }
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if let Some((segment, _)) = hovered {
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. }
+                        | mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+
+                    if y != 0.0 {
+                        self.step(segment, if y > 0.0 { 1 } else { -1 }, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                if let Some(segment) = state.focused {
+                    let index = segments
+                        .iter()
+                        .position(|candidate| *candidate == segment)
+                        .unwrap_or(0);
+
+                    match key_code {
+                        keyboard::KeyCode::Up => self.step(segment, 1, shell),
+                        keyboard::KeyCode::Down => {
+                            self.step(segment, -1, shell)
+                        }
+                        keyboard::KeyCode::Left => {
+                            state.focus_segment(
+                                segments[index.saturating_sub(1)],
+                            );
+                        }
+                        keyboard::KeyCode::Right => {
+                            state.focus_segment(
+                                segments[(index + 1).min(segments.len() - 1)],
+                            );
+                        }
+                        keyboard::KeyCode::Escape
+                        | keyboard::KeyCode::Enter => {
+                            state.focused = None;
+                        }
+                        _ => return event::Status::Ignored,
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                if let Some(segment) = state.focused {
+                    if segment == Segment::Period {
+                        let period = match c.to_ascii_lowercase() {
+                            'a' => Some(Period::Am),
+                            'p' => Some(Period::Pm),
+                            _ => None,
+                        };
+
+                        if let Some(period) = period {
+                            if self.value.hour12().1 != period {
+                                self.step(segment, 1, shell);
+                            }
+
+                            return event::Status::Captured;
+                        }
+                    } else if let Some(digit) = c.to_digit(10) {
+                        if self.type_digit(state, segment, digit as u8, shell) {
+                            let index = segments
+                                .iter()
+                                .position(|candidate| *candidate == segment)
+                                .unwrap_or(0);
+
+                            if let Some(next) = segments.get(index + 1) {
+                                state.focus_segment(*next);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let value_color = theme.value_color(&self.style);
+
+        let mut previous: Option<Rectangle> = None;
+
+        for (segment, layout) in
+            self.segments().into_iter().zip(layout.children())
+        {
+            let mut rows = layout.children();
+            let increment = rows.next().unwrap().bounds();
+            let value = rows.next().unwrap().bounds();
+            let decrement = rows.next().unwrap().bounds();
+
+            // Time units are separated by colons
+            if let Some(previous) = previous {
+                if segment != Segment::Period {
+                    renderer.fill_text(Text {
+                        content: ":",
+                        bounds: Rectangle {
+                            x: (previous.x + previous.width + value.x) / 2.0,
+                            y: value.center_y(),
+                            ..value
+                        },
+                        size: text_size,
+                        color: value_color,
+                        font: self.font.clone(),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+            }
+
+            previous = Some(value);
+
+            let label = self.label(segment);
+
+            for (bounds, content, is_value) in [
+                (increment, "+", false),
+                (value, label.as_str(), true),
+                (decrement, "-", false),
+            ] {
+                let appearance = if is_value && state.focused == Some(segment) {
+                    theme.focused(&self.style)
+                } else if bounds.contains(cursor_position) {
+                    theme.hovered(&self.style)
+                } else {
+                    theme.active(&self.style)
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance.background,
+                );
+
+                renderer.fill_text(Text {
+                    content,
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: text_size,
+                    color: value_color,
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|segment| segment.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        time_picker: TimePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(time_picker)
+    }
+}
+
+/// A spinner of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+/// The local state of a [`TimePicker`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    focused: Option<Segment>,
+    typed: Option<u8>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn focus_segment(&mut self, segment: Segment) {
+        if self.focused != Some(segment) {
+            self.typed = None;
+        }
+
+        self.focused = Some(segment);
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.focus_segment(Segment::Hour);
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = None;
+        self.typed = None;
+    }
+}
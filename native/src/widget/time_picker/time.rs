@@ -0,0 +1,241 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The amount of seconds in a day.
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A time of the day, with a precision of seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    /// The start of the day.
+    pub const MIDNIGHT: Time = Time {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+
+    /// Creates a new [`Time`] with the given hour (from 0 to 23), minute and
+    /// second (from 0 to 59), if they are valid.
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 {
+            Some(Time {
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new [`Time`] with the given hour of a 12-hour clock (from 1
+    /// to 12), [`Period`], minute and second, if they are valid.
+    pub fn from_hour12(
+        hour: u8,
+        period: Period,
+        minute: u8,
+        second: u8,
+    ) -> Option<Self> {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+
+        let hour = match period {
+            Period::Am => hour % 12,
+            Period::Pm => hour % 12 + 12,
+        };
+
+        Time::new(hour, minute, second)
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`].
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second of the [`Time`].
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the hour of the [`Time`] in a 12-hour clock, from 1 to 12,
+    /// and its [`Period`].
+    pub fn hour12(&self) -> (u8, Period) {
+        let period = if self.hour < 12 {
+            Period::Am
+        } else {
+            Period::Pm
+        };
+
+        match self.hour % 12 {
+            0 => (12, period),
+            hour => (hour, period),
+        }
+    }
+
+    /// Returns the amount of seconds since midnight.
+    pub fn seconds_since_midnight(&self) -> u32 {
+        u32::from(self.hour) * 3600
+            + u32::from(self.minute) * 60
+            + u32::from(self.second)
+    }
+
+    /// Returns the [`Time`] the given amount of seconds after midnight,
+    /// wrapping around at the end of the day.
+    pub fn from_seconds_since_midnight(seconds: u32) -> Self {
+        let seconds = seconds % SECONDS_PER_DAY as u32;
+
+        Time {
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        }
+    }
+
+    /// Returns the [`Time`] the given amount of seconds after this one,
+    /// wrapping around at the end of the day.
+    ///
+    /// The amount may be negative.
+    pub fn add_seconds(self, seconds: i64) -> Self {
+        let seconds = (i64::from(self.seconds_since_midnight()) + seconds)
+            .rem_euclid(SECONDS_PER_DAY);
+
+        Self::from_seconds_since_midnight(seconds as u32)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseError;
+
+    /// Parses a [`Time`] in the `HH:MM` or `HH:MM:SS` formats, optionally
+    /// followed by `AM` or `PM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let upper = s.to_ascii_uppercase();
+
+        let (clock, period) = if let Some(clock) = upper.strip_suffix("AM") {
+            (clock, Some(Period::Am))
+        } else if let Some(clock) = upper.strip_suffix("PM") {
+            (clock, Some(Period::Pm))
+        } else {
+            (upper.as_str(), None)
+        };
+
+        let mut parts = clock.trim_end().split(':');
+
+        let mut next = |optional: bool| match parts.next() {
+            Some(part) => part.parse::<u8>().map_err(|_| ParseError),
+            None if optional => Ok(0),
+            None => Err(ParseError),
+        };
+
+        let hour = next(false)?;
+        let minute = next(false)?;
+        let second = next(true)?;
+
+        if parts.next().is_some() {
+            return Err(ParseError);
+        }
+
+        match period {
+            Some(period) => Time::from_hour12(hour, period, minute, second),
+            None => Time::new(hour, minute, second),
+        }
+        .ok_or(ParseError)
+    }
+}
+
+/// An error produced when parsing an invalid [`Time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid time, expected the HH:MM:SS format")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A half of the day in a 12-hour clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    /// Before noon.
+    Am,
+    /// After noon.
+    Pm,
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Period::Am => write!(f, "AM"),
+            Period::Pm => write!(f, "PM"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u8, minute: u8, second: u8) -> Time {
+        Time::new(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn twelve_hour_clock() {
+        assert_eq!(time(0, 30, 0).hour12(), (12, Period::Am));
+        assert_eq!(time(11, 0, 0).hour12(), (11, Period::Am));
+        assert_eq!(time(12, 0, 0).hour12(), (12, Period::Pm));
+        assert_eq!(time(23, 0, 0).hour12(), (11, Period::Pm));
+
+        assert_eq!(
+            Time::from_hour12(12, Period::Am, 0, 0),
+            Some(time(0, 0, 0))
+        );
+        assert_eq!(
+            Time::from_hour12(12, Period::Pm, 0, 0),
+            Some(time(12, 0, 0))
+        );
+        assert_eq!(Time::from_hour12(0, Period::Am, 0, 0), None);
+        assert_eq!(Time::from_hour12(13, Period::Pm, 0, 0), None);
+    }
+
+    #[test]
+    fn add_seconds_wraps_around() {
+        assert_eq!(time(23, 59, 59).add_seconds(1), Time::MIDNIGHT);
+        assert_eq!(Time::MIDNIGHT.add_seconds(-1), time(23, 59, 59));
+        assert_eq!(time(10, 0, 0).add_seconds(-3 * 86_400), time(10, 0, 0));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("09:05".parse(), Ok(time(9, 5, 0)));
+        assert_eq!("9:05:30".parse(), Ok(time(9, 5, 30)));
+        assert_eq!("12:15 am".parse(), Ok(time(0, 15, 0)));
+        assert_eq!("1:15:02PM".parse(), Ok(time(13, 15, 2)));
+        assert_eq!("24:00".parse::<Time>(), Err(ParseError));
+        assert_eq!("13:00 PM".parse::<Time>(), Err(ParseError));
+        assert_eq!("10".parse::<Time>(), Err(ParseError));
+        assert_eq!("10:00:00:00".parse::<Time>(), Err(ParseError));
+        assert_eq!(time(7, 4, 9).to_string(), "07:04:09");
+    }
+}
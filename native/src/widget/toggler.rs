@@ -1,20 +1,32 @@
 //! Show toggle controls using togglers.
+//!
+//! A [`Toggler`] has some local [`State`].
 use crate::alignment;
 use crate::event;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
-use crate::widget::{self, Row, Text, Tree};
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
+use crate::window;
 use crate::{
-    Alignment, Clipboard, Element, Event, Layout, Length, Pixels, Point,
+    Alignment, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
     Rectangle, Shell, Widget,
 };
 
+use std::cell::Cell;
+
 pub use iced_style::toggler::{Appearance, StyleSheet};
 
 /// A toggler widget.
 ///
+/// When toggled, its thumb slides to the other side over the
+/// [`transition`] duration of its style.
+///
+/// [`transition`]: StyleSheet::transition
+///
 /// # Example
 ///
 /// ```
@@ -138,6 +150,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.is_toggled))
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -176,7 +196,7 @@ where
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -184,7 +204,42 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
         match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if state.is_toggled != self.is_toggled {
+                    state.is_toggled = self.is_toggled;
+                    state.transition = Some(Transition {
+                        from: state.position,
+                        started_at: now,
+                    });
+                }
+
+                if let Some(transition) = state.transition {
+                    let duration = state.duration.get();
+                    let target = if self.is_toggled { 1.0 } else { 0.0 };
+
+                    let progress = if duration.is_zero() {
+                        1.0
+                    } else {
+                        ((now - transition.started_at).as_secs_f32()
+                            / duration.as_secs_f32())
+                        .min(1.0)
+                    };
+
+                    state.position = transition.from
+                        + (target - transition.from) * ease(progress);
+
+                    if progress < 1.0 {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        state.transition = None;
+                    }
+                }
+
+                event::Status::Ignored
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
@@ -217,7 +272,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -255,10 +310,32 @@ where
 
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_toggled)
+        let state = tree.state.downcast_ref::<State>();
+        state.duration.set(theme.transition(&self.style));
+
+        // The position of the thumb, from `0.0` when off to `1.0` when on
+        let position = state.position;
+
+        let style = if position == 0.0 || position == 1.0 {
+            if is_mouse_over {
+                theme.hovered(&self.style, self.is_toggled)
+            } else {
+                theme.active(&self.style, self.is_toggled)
+            }
         } else {
-            theme.active(&self.style, self.is_toggled)
+            let (off, on) = if is_mouse_over {
+                (
+                    theme.hovered(&self.style, false),
+                    theme.hovered(&self.style, true),
+                )
+            } else {
+                (
+                    theme.active(&self.style, false),
+                    theme.active(&self.style, true),
+                )
+            };
+
+            mix(off, on, position)
         };
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
@@ -285,11 +362,11 @@ where
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+                + 2.0 * space
+                + position
+                    * (bounds.width
+                        - 4.0 * space
+                        - (bounds.height - (4.0 * space))),
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -322,3 +399,66 @@ where
         Element::new(toggler)
     }
 }
+
+/// The local state of a [`Toggler`].
+#[derive(Debug, Clone)]
+pub struct State {
+    is_toggled: bool,
+    position: f32,
+    transition: Option<Transition>,
+    // The duration of the transitions is only known by the theme, which is
+    // not available outside of `draw`
+    duration: Cell<Duration>,
+}
+
+impl State {
+    /// Creates a new [`State`] of a [`Toggler`] at rest in the given state.
+    pub fn new(is_toggled: bool) -> Self {
+        Self {
+            is_toggled,
+            position: if is_toggled { 1.0 } else { 0.0 },
+            transition: None,
+            duration: Cell::new(Duration::from_millis(150)),
+        }
+    }
+
+    /// Returns whether the [`Toggler`] is transitioning between its states.
+    pub fn is_animating(&self) -> bool {
+        self.transition.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from: f32,
+    started_at: Instant,
+}
+
+/// Eases the given progress of a transition in and out.
+fn ease(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Interpolates linearly between the appearances of the two states of a
+/// [`Toggler`].
+fn mix(off: Appearance, on: Appearance, t: f32) -> Appearance {
+    let mix_color = |a: Color, b: Color| Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    };
+
+    Appearance {
+        background: mix_color(off.background, on.background),
+        background_border: off
+            .background_border
+            .zip(on.background_border)
+            .map(|(a, b)| mix_color(a, b)),
+        foreground: mix_color(off.foreground, on.foreground),
+        foreground_border: off
+            .foreground_border
+            .zip(on.foreground_border)
+            .map(|(a, b)| mix_color(a, b)),
+    }
+}
@@ -0,0 +1,574 @@
+//! Lay out actions in a row, moving the ones that do not fit into a menu.
+//!
+//! A [`Toolbar`] has some local [`State`].
+mod overlay;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay as native_overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::toolbar::{Appearance, StyleSheet};
+
+/// A row of actions that moves the ones that do not fit into a trailing `…`
+/// menu.
+///
+/// The actions are moved in and out of the menu whenever the [`Toolbar`] is
+/// laid out again, like when the window is resized.
+///
+/// # Example
+/// ```
+/// # type Toolbar<'a, Message> = iced_native::widget::Toolbar<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// # type Button<'a, Message> = iced_native::widget::Button<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Undo,
+///     Redo,
+/// }
+///
+/// let toolbar = Toolbar::new()
+///     .push(Button::new(Text::new("Save")).on_press(Message::Save))
+///     .push(Button::new(Text::new("Undo")).on_press(Message::Undo))
+///     .push(Button::new(Text::new("Redo")).on_press(Message::Redo));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toolbar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    children: Vec<Element<'a, Message, Renderer>>,
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Toolbar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates an empty [`Toolbar`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Toolbar`] with the given actions.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Toolbar {
+            children,
+            spacing: 5.0,
+            padding: Padding::new(5.0),
+            width: Length::Fill,
+            height: Length::Shrink,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Adds an action to the [`Toolbar`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the spacing between the actions of the [`Toolbar`], and between
+    /// the actions of its overflow menu.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Toolbar`] and of its overflow menu.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Toolbar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Toolbar`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the button opening the overflow menu of the
+    /// [`Toolbar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the button opening the overflow menu of the
+    /// [`Toolbar`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Toolbar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn label_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+}
+
+impl<'a, Message, Renderer> Default for Toolbar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The local state of a [`Toolbar`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the overflow menu of the [`Toolbar`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// Returns the layout of the visible actions of a [`Toolbar`], followed by
+/// the button opening its overflow menu.
+///
+/// The button has no size when every action fits.
+fn visible_children<'a>(
+    layout: Layout<'a>,
+) -> (impl Iterator<Item = Layout<'a>>, Layout<'a>) {
+    let count = layout.children().count();
+    let overflow = layout.children().last().unwrap();
+
+    (layout.children().take(count - 1), overflow)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toolbar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let max = limits.max();
+        let child_limits = layout::Limits::new(Size::ZERO, max)
+            .with_direction(limits.direction());
+
+        let mut nodes: Vec<_> = self
+            .children
+            .iter()
+            .map(|child| child.as_widget().layout(renderer, &child_limits))
+            .collect();
+
+        let button_width = self.label_size(renderer) * 2.0;
+
+        let widths: Vec<f32> =
+            nodes.iter().map(|node| node.size().width).collect();
+
+        let visible =
+            visible_count(&widths, max.width, self.spacing, button_width);
+
+        nodes.truncate(visible);
+
+        let height = nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(self.label_size(renderer), f32::max);
+
+        let mut x = self.padding.left;
+
+        for node in &mut nodes {
+            let size = node.size();
+
+            node.move_to(Point::new(
+                x,
+                self.padding.top + (height - size.height) / 2.0,
+            ));
+
+            x += size.width + self.spacing;
+        }
+
+        let is_overflowing = visible < self.children.len();
+
+        let mut button = layout::Node::new(if is_overflowing {
+            Size::new(button_width, height)
+        } else {
+            Size::ZERO
+        });
+        button.move_to(Point::new(x, self.padding.top));
+
+        let width = if is_overflowing {
+            x + button_width
+        } else {
+            x - if visible > 0 { self.spacing } else { 0.0 }
+        };
+
+        let content = Size::new(width - self.padding.left, height);
+
+        nodes.push(button);
+
+        let size = limits.resolve(content).pad(self.padding);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            let (children, _) = visible_children(layout);
+
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(children)
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let (children, overflow) = visible_children(layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        if overflow.bounds().width == 0.0 {
+            state.is_open = false;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if overflow.bounds().contains(cursor_position) {
+                state.is_open = !state.is_open;
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(children)
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (children, overflow) = visible_children(layout);
+
+        if overflow.bounds().contains(cursor_position) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(children)
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        if appearance.background.is_some() || appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        }
+
+        let (children, overflow) = visible_children(layout);
+
+        for ((child, state), layout) in
+            self.children.iter().zip(&tree.children).zip(children)
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        let button = overflow.bounds();
+
+        if button.width == 0.0 {
+            return;
+        }
+
+        if state.is_open || button.contains(cursor_position) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: button,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.overflow_hovered_background,
+            );
+        }
+
+        renderer.fill_text(Text {
+            content: "…",
+            bounds: Rectangle {
+                x: button.center_x(),
+                y: button.center_y(),
+                ..button
+            },
+            size: self.label_size(renderer),
+            color: appearance.overflow_color,
+            font: self.font.clone(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<native_overlay::Element<'b, Message, Renderer>> {
+        let (children, overflow) = visible_children(layout);
+        let visible = layout.children().count() - 1;
+
+        let (shown, hidden) = self.children.split_at_mut(visible);
+        let (shown_trees, hidden_trees) = tree.children.split_at_mut(visible);
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut overlays: Vec<_> = shown
+            .iter_mut()
+            .zip(shown_trees)
+            .zip(children)
+            .filter_map(|((child, state), layout)| {
+                child.as_widget_mut().overlay(state, layout, renderer)
+            })
+            .collect();
+
+        if state.is_open && !hidden.is_empty() {
+            let target = overflow.bounds();
+
+            overlays.push(native_overlay::Element::new(
+                target.position(),
+                Box::new(overlay::Overflow::new(
+                    hidden,
+                    hidden_trees,
+                    state,
+                    target,
+                    self.padding,
+                    self.spacing,
+                    &self.style,
+                )),
+            ));
+        }
+
+        match overlays.len() {
+            0 => None,
+            1 => overlays.pop(),
+            _ => Some(native_overlay::Group::with_children(overlays).overlay()),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Toolbar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(toolbar: Toolbar<'a, Message, Renderer>) -> Self {
+        Element::new(toolbar)
+    }
+}
+
+/// Returns how many of the actions with the given widths fit in the given
+/// width.
+///
+/// When some do not fit, room is kept for the button opening the overflow
+/// menu after the visible ones.
+fn visible_count(
+    widths: &[f32],
+    max_width: f32,
+    spacing: f32,
+    button_width: f32,
+) -> usize {
+    let total = widths.iter().sum::<f32>()
+        + spacing * widths.len().saturating_sub(1) as f32;
+
+    if total <= max_width {
+        return widths.len();
+    }
+
+    let mut x = 0.0;
+
+    widths
+        .iter()
+        .take_while(|width| {
+            x += *width + spacing;
+
+            x + button_width <= max_width
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actions_overflow_with_room_for_the_button() {
+        let widths = [30.0, 30.0, 30.0];
+
+        assert_eq!(visible_count(&widths, 100.0, 5.0, 20.0), 3);
+        assert_eq!(visible_count(&widths, 90.0, 5.0, 20.0), 2);
+        assert_eq!(visible_count(&widths, 60.0, 5.0, 20.0), 1);
+        assert_eq!(visible_count(&widths, 40.0, 5.0, 20.0), 0);
+        assert_eq!(visible_count(&[], 0.0, 5.0, 20.0), 0);
+    }
+}
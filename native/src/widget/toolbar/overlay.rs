@@ -0,0 +1,229 @@
+use super::{State, StyleSheet};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay::menu;
+use crate::renderer;
+use crate::touch;
+use crate::widget::Tree;
+use crate::{
+    Clipboard, Element, Layout, Padding, Point, Rectangle, Shell, Size,
+};
+
+/// The menu of the actions of a [`Toolbar`] that do not fit in it.
+///
+/// [`Toolbar`]: super::Toolbar
+pub(super) struct Overflow<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    children: &'a mut [Element<'b, Message, Renderer>],
+    trees: &'a mut [Tree],
+    state: &'a mut State,
+    target: Rectangle,
+    padding: Padding,
+    spacing: f32,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> Overflow<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates the menu of the given actions, attached to the bounds of the
+    /// button opening it.
+    pub(super) fn new(
+        children: &'a mut [Element<'b, Message, Renderer>],
+        trees: &'a mut [Tree],
+        state: &'a mut State,
+        target: Rectangle,
+        padding: Padding,
+        spacing: f32,
+        style: &'a <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            children,
+            trees,
+            state,
+            target,
+            padding,
+            spacing,
+            style,
+        }
+    }
+}
+
+impl<'a, 'b, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overflow<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds).pad(self.padding);
+
+        let mut y = self.padding.top;
+        let mut width: f32 = 0.0;
+
+        let children = self
+            .children
+            .iter()
+            .map(|child| {
+                let mut node = child.as_widget().layout(renderer, &limits);
+                let size = node.size();
+
+                node.move_to(Point::new(self.padding.left, y));
+
+                y += size.height + self.spacing;
+                width = width.max(size.width);
+
+                node
+            })
+            .collect();
+
+        let size = Size::new(
+            width + self.padding.horizontal(),
+            y - self.spacing + self.padding.bottom,
+        );
+
+        // The menu is aligned with the trailing edge of its button
+        let position = Point::new(
+            (position.x + self.target.width - size.width).max(0.0),
+            position.y,
+        );
+
+        menu::layout_attached(bounds, position, self.target.height, |_| {
+            layout::Node::with_children(size, children)
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !bounds.contains(cursor_position) =>
+            {
+                // The button toggles the menu by itself
+                if !self.target.contains(cursor_position) {
+                    self.state.is_open = false;
+                }
+
+                return event::Status::Ignored;
+            }
+            _ => {}
+        }
+
+        let is_release = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        );
+
+        let status = self
+            .children
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        // Actions react on release, so the menu closes only once they had
+        // the chance to
+        if is_release && bounds.contains(cursor_position) {
+            self.state.is_open = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.menu_border_radius.into(),
+                border_width: appearance.menu_border_width,
+                border_color: appearance.menu_border_color,
+            },
+            appearance.menu_background,
+        );
+
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                &bounds,
+            );
+        }
+    }
+}
@@ -0,0 +1,942 @@
+//! Display a hierarchy of nodes that can be expanded, collapsed and
+//! selected, like the files of a file explorer.
+//!
+//! A [`TreeView`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::tree_view::{Appearance, StyleSheet};
+
+/// A node of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<Id> {
+    /// The identifier of the node.
+    pub id: Id,
+    /// The label of the node.
+    pub label: String,
+    /// The children of the node.
+    pub children: Children<Id>,
+    /// Whether the children of the node are displayed.
+    pub is_expanded: bool,
+}
+
+impl<Id> Node<Id> {
+    /// Creates a new [`Node`] without children.
+    pub fn new(id: Id, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            children: Children::Leaf,
+            is_expanded: false,
+        }
+    }
+
+    /// Creates a new [`Node`] with the given children.
+    pub fn with_children(
+        id: Id,
+        label: impl Into<String>,
+        children: Vec<Node<Id>>,
+    ) -> Self {
+        Self {
+            children: Children::Loaded(children),
+            ..Self::new(id, label)
+        }
+    }
+
+    /// Creates a new [`Node`] whose children have not been loaded yet.
+    ///
+    /// See [`TreeView::on_load`].
+    pub fn lazy(id: Id, label: impl Into<String>) -> Self {
+        Self {
+            children: Children::Unloaded,
+            ..Self::new(id, label)
+        }
+    }
+
+    /// Sets whether the children of the [`Node`] are displayed.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Returns whether the [`Node`] has, or may have, children.
+    pub fn is_expandable(&self) -> bool {
+        !matches!(self.children, Children::Leaf)
+    }
+
+    /// Returns the [`Node`] with the given identifier among this one and its
+    /// loaded descendants.
+    pub fn find(&self, id: &Id) -> Option<&Node<Id>>
+    where
+        Id: PartialEq,
+    {
+        if &self.id == id {
+            return Some(self);
+        }
+
+        match &self.children {
+            Children::Loaded(children) => {
+                children.iter().find_map(|child| child.find(id))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Node`] with the given identifier among this one and its
+    /// loaded descendants, mutably.
+    pub fn find_mut(&mut self, id: &Id) -> Option<&mut Node<Id>>
+    where
+        Id: PartialEq,
+    {
+        if &self.id == id {
+            return Some(self);
+        }
+
+        match &mut self.children {
+            Children::Loaded(children) => {
+                children.iter_mut().find_map(|child| child.find_mut(id))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The children of a [`Node`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Children<Id> {
+    /// The [`Node`] is a leaf.
+    Leaf,
+    /// The children have not been loaded yet.
+    Unloaded,
+    /// The children are known.
+    Loaded(Vec<Node<Id>>),
+}
+
+/// A hierarchy of nodes that can be expanded, collapsed and selected.
+///
+/// The expanded nodes and the selection are owned by the application, which
+/// is notified of their changes. Several nodes can be selected by holding the
+/// command or shift keys.
+///
+/// Once focused, the arrow keys move the keyboard focus between nodes: the
+/// up and down keys visit the displayed nodes, while the left and right keys
+/// collapse and expand them.
+///
+/// The children of a [`Node::lazy`] can be supplied the first time it is
+/// expanded, with [`TreeView::on_load`].
+///
+/// # Example
+/// ```
+/// # pub type TreeView<'a, Id, Message> = iced_native::widget::TreeView<'a, Id, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(u32, bool),
+///     Load(u32),
+///     Selected(Vec<u32>),
+/// }
+///
+/// let roots = vec![
+///     Node::with_children(0, "src", vec![Node::new(1, "main.rs")]).expanded(true),
+///     Node::lazy(2, "target"),
+/// ];
+///
+/// let tree_view = TreeView::new(roots, Message::Toggled)
+///     .on_load(Message::Load)
+///     .on_select(Message::Selected)
+///     .selection(vec![1]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Id, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    roots: Vec<Node<Id>>,
+    selection: Vec<Id>,
+    on_toggle: Box<dyn Fn(Id, bool) -> Message + 'a>,
+    on_load: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<Id>) -> Message + 'a>>,
+    on_activate: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    text_size: Option<f32>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Id, Message, Renderer> TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default indentation of every level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 16.0;
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    ///
+    /// The given function produces a message when a node is expanded or
+    /// collapsed.
+    pub fn new<F>(roots: Vec<Node<Id>>, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(Id, bool) -> Message,
+    {
+        TreeView {
+            roots,
+            selection: Vec::new(),
+            on_toggle: Box::new(on_toggle),
+            on_load: None,
+            on_select: None,
+            on_activate: None,
+            width: Length::Fill,
+            indent: Self::DEFAULT_INDENT,
+            padding: Padding::from([2.0, 4.0]),
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the selected nodes of the [`TreeView`].
+    pub fn selection(mut self, selection: Vec<Id>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the function producing a message when a node whose children
+    /// have not been loaded is expanded.
+    ///
+    /// The application is then expected to supply its children.
+    pub fn on_load<F>(mut self, on_load: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_load = Some(Box::new(on_load));
+        self
+    }
+
+    /// Sets the function producing a message when the selected nodes change.
+    ///
+    /// The nodes cannot be selected otherwise.
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(Vec<Id>) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function producing a message when a node is double-clicked
+    /// or when the enter key is pressed on it.
+    pub fn on_activate<F>(mut self, on_activate: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_activate = Some(Box::new(on_activate));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of every node of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TreeView`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into().0);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TreeView`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+            + self.padding.vertical()
+    }
+
+    /// Returns the displayed rows of the [`TreeView`].
+    fn rows(&self) -> Vec<Row<'_, Id>> {
+        fn visit<'a, Id>(
+            nodes: &'a [Node<Id>],
+            depth: usize,
+            rows: &mut Vec<Row<'a, Id>>,
+        ) {
+            for node in nodes {
+                rows.push(Row::Node { node, depth });
+
+                if node.is_expanded {
+                    match &node.children {
+                        Children::Leaf => {}
+                        Children::Unloaded => {
+                            rows.push(Row::Loading { depth: depth + 1 })
+                        }
+                        Children::Loaded(children) => {
+                            visit(children, depth + 1, rows)
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        visit(&self.roots, 0, &mut rows);
+
+        rows
+    }
+
+    fn toggle(&self, node: &Node<Id>, shell: &mut Shell<'_, Message>) {
+        let is_expanded = !node.is_expanded;
+
+        shell.publish((self.on_toggle)(node.id.clone(), is_expanded));
+
+        if is_expanded && node.children == Children::Unloaded {
+            if let Some(on_load) = &self.on_load {
+                shell.publish(on_load(node.id.clone()));
+            }
+        }
+    }
+
+    fn select(&self, selection: Vec<Id>, shell: &mut Shell<'_, Message>) {
+        if let Some(on_select) = &self.on_select {
+            if selection != self.selection {
+                shell.publish(on_select(selection));
+            }
+        }
+    }
+
+    /// Moves the keyboard focus to the node of the given row, selecting it
+    /// or extending the selection up to it.
+    fn focus_row(
+        &self,
+        state: &mut State<Id>,
+        rows: &[Row<'_, Id>],
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let node = match rows.get(index) {
+            Some(Row::Node { node, .. }) => node,
+            _ => return,
+        };
+
+        let selection = if state.modifiers.shift() {
+            let anchor = state
+                .anchor
+                .as_ref()
+                .and_then(|anchor| position(rows, anchor))
+                .unwrap_or(index);
+
+            range(rows, anchor, index)
+        } else {
+            state.anchor = Some(node.id.clone());
+
+            vec![node.id.clone()]
+        };
+
+        state.focused = Some(node.id.clone());
+
+        self.select(selection, shell);
+    }
+}
+
+impl<'a, Id, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Id>::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let rows = self.rows();
+
+        let intrinsic_width = rows
+            .iter()
+            .map(|row| match row {
+                Row::Node { node, depth } => {
+                    (*depth + 1) as f32 * self.indent
+                        + renderer.measure_width(
+                            &node.label,
+                            text_size,
+                            self.font.clone(),
+                        )
+                }
+                Row::Loading { depth } => (*depth + 2) as f32 * self.indent,
+            })
+            .fold(0.0, f32::max)
+            + self.padding.horizontal();
+
+        let size =
+            limits
+                .width(self.width)
+                .height(Length::Shrink)
+                .resolve(Size::new(
+                    intrinsic_width,
+                    rows.len() as f32 * self.row_height(renderer),
+                ));
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        operation.focusable(state, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Id>>();
+        let bounds = layout.bounds();
+        let row_height = self.row_height(renderer);
+        let rows = self.rows();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                }
+
+                state.is_focused = true;
+
+                let index =
+                    ((cursor_position.y - bounds.y) / row_height) as usize;
+
+                let (node, depth) = match rows.get(index) {
+                    Some(Row::Node { node, depth }) => (node, depth),
+                    _ => return event::Status::Captured,
+                };
+
+                let arrow_x =
+                    bounds.x + self.padding.left + *depth as f32 * self.indent;
+
+                if node.is_expandable()
+                    && cursor_position.x >= arrow_x
+                    && cursor_position.x < arrow_x + self.indent
+                {
+                    self.toggle(node, shell);
+                    state.focused = Some(node.id.clone());
+
+                    return event::Status::Captured;
+                }
+
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+
+                if matches!(click.kind(), click::Kind::Double) {
+                    if let Some(on_activate) = &self.on_activate {
+                        shell.publish(on_activate(node.id.clone()));
+                    }
+                } else if state.modifiers.command() {
+                    let mut selection = self.selection.clone();
+
+                    match selection.iter().position(|id| id == &node.id) {
+                        Some(i) => {
+                            let _ = selection.remove(i);
+                        }
+                        None => selection.push(node.id.clone()),
+                    }
+
+                    state.focused = Some(node.id.clone());
+                    state.anchor = Some(node.id.clone());
+
+                    self.select(selection, shell);
+                } else {
+                    self.focus_row(state, &rows, index, shell);
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                let current = state
+                    .focused
+                    .as_ref()
+                    .and_then(|focused| position(&rows, focused));
+
+                let node_rows: Vec<usize> = rows
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, row)| matches!(row, Row::Node { .. }))
+                    .map(|(index, _)| index)
+                    .collect();
+
+                let (first, last) = match (node_rows.first(), node_rows.last())
+                {
+                    (Some(first), Some(last)) => (*first, *last),
+                    _ => return event::Status::Ignored,
+                };
+
+                let current = if let Some(current) = current {
+                    current
+                } else {
+                    if matches!(
+                        key_code,
+                        keyboard::KeyCode::Up
+                            | keyboard::KeyCode::Down
+                            | keyboard::KeyCode::Home
+                            | keyboard::KeyCode::End
+                    ) {
+                        self.focus_row(state, &rows, first, shell);
+
+                        return event::Status::Captured;
+                    }
+
+                    return event::Status::Ignored;
+                };
+
+                let (node, depth) = match rows[current] {
+                    Row::Node { node, depth } => (node, depth),
+                    Row::Loading { .. } => return event::Status::Ignored,
+                };
+
+                match key_code {
+                    keyboard::KeyCode::Up => {
+                        if let Some(previous) =
+                            node_rows.iter().rev().find(|&&i| i < current)
+                        {
+                            self.focus_row(state, &rows, *previous, shell);
+                        }
+                    }
+                    keyboard::KeyCode::Down => {
+                        if let Some(next) =
+                            node_rows.iter().find(|&&i| i > current)
+                        {
+                            self.focus_row(state, &rows, *next, shell);
+                        }
+                    }
+                    keyboard::KeyCode::Home => {
+                        self.focus_row(state, &rows, first, shell);
+                    }
+                    keyboard::KeyCode::End => {
+                        self.focus_row(state, &rows, last, shell);
+                    }
+                    keyboard::KeyCode::Left => {
+                        if node.is_expanded && node.is_expandable() {
+                            self.toggle(node, shell);
+                        } else if let Some(parent) = rows[..current]
+                            .iter()
+                            .rposition(|row| {
+                                matches!(row, Row::Node { depth: d, .. } if *d + 1 == depth)
+                            })
+                        {
+                            self.focus_row(state, &rows, parent, shell);
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        if !node.is_expanded && node.is_expandable() {
+                            self.toggle(node, shell);
+                        } else if let Some(Row::Node {
+                            depth: child_depth,
+                            ..
+                        }) = rows.get(current + 1)
+                        {
+                            if *child_depth > depth {
+                                self.focus_row(
+                                    state,
+                                    &rows,
+                                    current + 1,
+                                    shell,
+                                );
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::Space => {
+                        self.focus_row(state, &rows, current, shell);
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if let Some(on_activate) = &self.on_activate {
+                            shell.publish(on_activate(node.id.clone()));
+                        } else if node.is_expandable() {
+                            self.toggle(node, shell);
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Id>>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let row_height = self.row_height(renderer);
+        let rows = self.rows();
+
+        // Only the rows in the viewport are drawn
+        let first = ((viewport.y - bounds.y) / row_height).max(0.0) as usize;
+        let last = (((viewport.y + viewport.height - bounds.y) / row_height)
+            .ceil()
+            .max(0.0) as usize)
+            .min(rows.len());
+
+        for (index, row) in rows.iter().enumerate().take(last).skip(first) {
+            let row_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + index as f32 * row_height,
+                width: bounds.width,
+                height: row_height,
+            };
+
+            let depth = match row {
+                Row::Node { depth, .. } | Row::Loading { depth } => *depth,
+            };
+
+            let content_x = bounds.x + self.padding.left;
+
+            // Indentation guides
+            for level in 0..depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (content_x + (level as f32 + 0.5) * self.indent)
+                                .floor(),
+                            width: 1.0,
+                            ..row_bounds
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.guide_color,
+                );
+            }
+
+            let label_x = content_x + (depth + 1) as f32 * self.indent;
+            let text_y = row_bounds.center_y();
+
+            let node = match row {
+                Row::Node { node, .. } => node,
+                Row::Loading { .. } => {
+                    renderer.fill_text(Text {
+                        content: "Loading…",
+                        bounds: Rectangle {
+                            x: label_x - self.indent,
+                            y: text_y,
+                            ..row_bounds
+                        },
+                        size: text_size,
+                        color: appearance.placeholder_color,
+                        font: self.font.clone(),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+
+                    continue;
+                }
+            };
+
+            let is_selected = self.selection.contains(&node.id);
+            let is_focused =
+                state.is_focused && state.focused.as_ref() == Some(&node.id);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if row_bounds.contains(cursor_position) {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if background.is_some() || is_focused {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: 2.0.into(),
+                        border_width: if is_focused { 1.0 } else { 0.0 },
+                        border_color: appearance.focused_border_color,
+                    },
+                    background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            if node.is_expandable() {
+                draw_arrow(
+                    renderer,
+                    Point::new(
+                        content_x + (depth as f32 + 0.5) * self.indent,
+                        text_y,
+                    ),
+                    (text_size / 2.0).min(self.indent / 2.0),
+                    node.is_expanded,
+                    appearance.arrow_color,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: &node.label,
+                bounds: Rectangle {
+                    x: label_x,
+                    y: text_y,
+                    ..row_bounds
+                },
+                size: text_size,
+                color: if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Id, Message, Renderer> From<TreeView<'a, Id, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        tree_view: TreeView<'a, Id, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree_view)
+    }
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug, Clone)]
+pub struct State<Id> {
+    is_focused: bool,
+    focused: Option<Id>,
+    anchor: Option<Id>,
+    last_click: Option<mouse::Click>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<Id> State<Id> {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self {
+            is_focused: false,
+            focused: None,
+            anchor: None,
+            last_click: None,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+
+    /// Returns the identifier of the node with the keyboard focus, if any.
+    pub fn focused(&self) -> Option<&Id> {
+        self.focused.as_ref()
+    }
+}
+
+impl<Id> Default for State<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id> operation::Focusable for State<Id> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// A displayed row of a [`TreeView`].
+#[derive(Debug)]
+enum Row<'a, Id> {
+    Node { node: &'a Node<Id>, depth: usize },
+    Loading { depth: usize },
+}
+
+/// Returns the index of the row of the node with the given identifier.
+fn position<Id: PartialEq>(rows: &[Row<'_, Id>], id: &Id) -> Option<usize> {
+    rows.iter()
+        .position(|row| matches!(row, Row::Node { node, .. } if &node.id == id))
+}
+
+/// Returns the identifiers of the nodes displayed between the given rows,
+/// both included.
+fn range<Id: Clone>(rows: &[Row<'_, Id>], a: usize, b: usize) -> Vec<Id> {
+    rows[a.min(b)..=a.max(b)]
+        .iter()
+        .filter_map(|row| match row {
+            Row::Node { node, .. } => Some(node.id.clone()),
+            Row::Loading { .. } => None,
+        })
+        .collect()
+}
+
+/// Draws a triangle pointing right, or down when expanded, centered at the
+/// given position.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    size: f32,
+    is_expanded: bool,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    // The triangle is rasterized in strips one pixel wide
+    let strips = size.round().max(1.0) as usize;
+    let half = size / 2.0;
+
+    for strip in 0..strips {
+        let length = size * (1.0 - strip as f32 / strips as f32);
+        let offset = strip as f32 - half;
+
+        let bounds = if is_expanded {
+            Rectangle {
+                x: center.x - length / 2.0,
+                y: center.y + offset,
+                width: length,
+                height: 1.0,
+            }
+        } else {
+            Rectangle {
+                x: center.x + offset,
+                y: center.y - length / 2.0,
+                width: 1.0,
+                height: length,
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
@@ -1,14 +1,18 @@
 //! Build window-based GUI applications.
 mod action;
+mod antialiasing;
 mod event;
 mod mode;
 mod redraw_request;
+mod screenshot;
 mod user_attention;
 
 pub use action::Action;
+pub use antialiasing::Antialiasing;
 pub use event::Event;
 pub use mode::Mode;
 pub use redraw_request::RedrawRequest;
+pub use screenshot::Screenshot;
 pub use user_attention::UserAttention;
 
 use crate::subscription::{self, Subscription};
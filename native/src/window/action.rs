@@ -1,4 +1,4 @@
-use crate::window::{Mode, UserAttention};
+use crate::window::{Antialiasing, Mode, Screenshot, UserAttention};
 
 use iced_futures::MaybeSend;
 use std::fmt;
@@ -78,6 +78,22 @@ pub enum Action<T> {
     ChangeAlwaysOnTop(bool),
     /// Fetch an identifier unique to the window.
     FetchId(Box<dyn FnOnce(u64) -> T + 'static>),
+    /// Capture the current frame of the window as RGBA pixels.
+    Screenshot(Box<dyn FnOnce(Screenshot) -> T + 'static>),
+    /// Change the [`Antialiasing`] strategy used to render the window.
+    ///
+    /// Providing `None` disables antialiasing.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **OpenGL:** Only the strategy the context was created with can be
+    ///   enabled.
+    ChangeAntialiasing(Option<Antialiasing>),
+    /// Fetch the [`Antialiasing`] strategies supported by the graphics
+    /// adapter.
+    FetchSupportedAntialiasing(
+        Box<dyn FnOnce(Vec<Antialiasing>) -> T + 'static>,
+    ),
 }
 
 impl<T> Action<T> {
@@ -108,6 +124,15 @@ impl<T> Action<T> {
                 Action::ChangeAlwaysOnTop(on_top)
             }
             Self::FetchId(o) => Action::FetchId(Box::new(move |s| f(o(s)))),
+            Self::Screenshot(o) => {
+                Action::Screenshot(Box::new(move |s| f(o(s))))
+            }
+            Self::ChangeAntialiasing(antialiasing) => {
+                Action::ChangeAntialiasing(antialiasing)
+            }
+            Self::FetchSupportedAntialiasing(o) => {
+                Action::FetchSupportedAntialiasing(Box::new(move |s| f(o(s))))
+            }
         }
     }
 }
@@ -142,6 +167,13 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::AlwaysOnTop({on_top})")
             }
             Self::FetchId(_) => write!(f, "Action::FetchId"),
+            Self::Screenshot(_) => write!(f, "Action::Screenshot"),
+            Self::ChangeAntialiasing(antialiasing) => {
+                write!(f, "Action::ChangeAntialiasing({antialiasing:?})")
+            }
+            Self::FetchSupportedAntialiasing(_) => {
+                write!(f, "Action::FetchSupportedAntialiasing")
+            }
         }
     }
 }
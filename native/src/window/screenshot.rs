@@ -0,0 +1,42 @@
+use crate::Size;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A frame of the window, captured as RGBA pixels.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The RGBA pixels of the frame, row by row from the top.
+    pub bytes: Arc<Vec<u8>>,
+    /// The physical size of the frame.
+    pub size: Size<u32>,
+    /// The scale factor of the window when the frame was captured.
+    pub scale_factor: f64,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`] from the given RGBA pixels.
+    pub fn new(bytes: Vec<u8>, size: Size<u32>, scale_factor: f64) -> Self {
+        Self {
+            bytes: Arc::new(bytes),
+            size,
+            scale_factor,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Screenshot {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Screenshot")
+            .field("bytes", &format_args!("{} bytes", self.bytes.len()))
+            .field("size", &self.size)
+            .field("scale_factor", &self.scale_factor)
+            .finish()
+    }
+}
@@ -193,18 +193,7 @@ pub trait Application: Sized {
     where
         Self: 'static,
     {
-        #[allow(clippy::needless_update)]
-        let renderer_settings = crate::renderer::Settings {
-            default_font: settings.default_font,
-            default_text_size: settings.default_text_size,
-            text_multithreading: settings.text_multithreading,
-            antialiasing: if settings.antialiasing {
-                Some(crate::renderer::settings::Antialiasing::MSAAx4)
-            } else {
-                None
-            },
-            ..crate::renderer::Settings::from_env()
-        };
+        let renderer_settings = renderer_settings(&settings);
 
         Ok(crate::runtime::application::run::<
             Instance<Self>,
@@ -214,6 +203,25 @@ pub trait Application: Sized {
     }
 }
 
+/// Returns the settings of the renderer of an [`Application`].
+pub(crate) fn renderer_settings<Flags>(
+    settings: &Settings<Flags>,
+) -> crate::renderer::Settings {
+    #[allow(clippy::needless_update)]
+    crate::renderer::Settings {
+        default_font: settings.default_font,
+        default_text_size: settings.default_text_size,
+        text_multithreading: settings.text_multithreading,
+        image_budget: settings.image_budget,
+        antialiasing: if settings.antialiasing {
+            Some(crate::renderer::settings::Antialiasing::MSAAx4)
+        } else {
+            None
+        },
+        ..crate::renderer::Settings::from_env()
+    }
+}
+
 struct Instance<A: Application>(A);
 
 impl<A> iced_winit::Program for Instance<A>
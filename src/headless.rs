@@ -0,0 +1,155 @@
+//! Render applications without a window.
+//!
+//! A [`Headless`] application runs its `view`, lays it out and draws it into
+//! an offscreen target, producing the RGBA pixels of the frame. It is useful
+//! to write golden-image tests and to generate previews on a server.
+//!
+//! # Example
+//! ```no_run
+//! # use iced::widget::text;
+//! # use iced::{Element, Sandbox, Settings};
+//! #
+//! # struct Counter;
+//! #
+//! # impl Sandbox for Counter {
+//! #     type Message = ();
+//! #     fn new() -> Self { Counter }
+//! #     fn title(&self) -> String { String::new() }
+//! #     fn update(&mut self, _message: ()) {}
+//! #     fn view(&self) -> Element<()> { text("0").into() }
+//! # }
+//! #
+//! use iced::headless::{self, Headless};
+//! use iced::Size;
+//!
+//! let mut counter = Headless::<Counter>::new(Settings::default())?;
+//!
+//! let frame = counter.render(Size::new(800, 600), 1.0)?;
+//!
+//! std::fs::write("counter.png", headless::png(&frame))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+use crate::application::{self, Application, StyleSheet};
+use crate::renderer::{Error, Offscreen, Viewport};
+use crate::{Point, Settings, Size};
+
+use iced_native::renderer;
+use iced_native::user_interface::{self, UserInterface};
+
+pub use iced_native::window::Screenshot;
+
+/// An [`Application`] rendered into images, without a window.
+///
+/// Any [`Command`] or [`Subscription`] produced by the [`Application`] is
+/// ignored, since there is no runtime to execute them.
+///
+/// [`Command`]: crate::Command
+/// [`Subscription`]: crate::Subscription
+#[allow(missing_debug_implementations)]
+pub struct Headless<A: Application> {
+    application: A,
+    offscreen: Offscreen,
+    renderer: crate::Renderer<A::Theme>,
+    cache: user_interface::Cache,
+}
+
+impl<A: Application> Headless<A> {
+    /// Creates a new [`Headless`] application with the given [`Settings`].
+    ///
+    /// The window settings are ignored.
+    pub fn new(settings: Settings<A::Flags>) -> Result<Self, Error> {
+        let offscreen =
+            Offscreen::new(application::renderer_settings(&settings))?;
+        let renderer = offscreen.create_renderer();
+
+        let (application, _) = A::new(settings.flags);
+
+        Ok(Headless {
+            application,
+            offscreen,
+            renderer,
+            cache: user_interface::Cache::default(),
+        })
+    }
+
+    /// Returns a reference to the [`Application`].
+    pub fn application(&self) -> &A {
+        &self.application
+    }
+
+    /// Updates the [`Application`] with the given message.
+    pub fn update(&mut self, message: A::Message) {
+        let _ = self.application.update(message);
+    }
+
+    /// Lays out and draws the [`Application`] into a frame of the given
+    /// physical size, returning its pixels.
+    ///
+    /// The scale factor of the [`Application`] is applied on top of the given
+    /// `scale_factor`.
+    pub fn render(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f64,
+    ) -> Result<Screenshot, Error> {
+        let viewport = Viewport::with_physical_size(
+            size,
+            scale_factor * self.application.scale_factor(),
+        );
+
+        let theme = self.application.theme();
+        let appearance = theme.appearance(&self.application.style());
+
+        let mut user_interface = UserInterface::build(
+            self.application.view(),
+            viewport.logical_size(),
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+        );
+
+        let _ = user_interface.draw(
+            &mut self.renderer,
+            &theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            Point::new(-1.0, -1.0),
+        );
+
+        self.cache = user_interface.into_cache();
+
+        let pixels = self.offscreen.present(
+            &mut self.renderer,
+            &viewport,
+            appearance.background_color,
+        )?;
+
+        Ok(Screenshot::new(
+            pixels,
+            viewport.physical_size(),
+            viewport.scale_factor(),
+        ))
+    }
+}
+
+/// Encodes the given [`Screenshot`] as a PNG image.
+///
+/// # Panics
+/// Panics if the size of the [`Screenshot`] does not match its pixels.
+pub fn png(screenshot: &Screenshot) -> Vec<u8> {
+    use image_rs::codecs::png::PngEncoder;
+    use image_rs::{ColorType, ImageEncoder};
+
+    let mut bytes = Vec::new();
+
+    PngEncoder::new(&mut bytes)
+        .write_image(
+            &screenshot.bytes,
+            screenshot.size.width,
+            screenshot.size.height,
+            ColorType::Rgba8,
+        )
+        .expect("Encode PNG image");
+
+    bytes
+}
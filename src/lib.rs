@@ -182,6 +182,10 @@ pub mod touch;
 pub mod widget;
 pub mod window;
 
+#[cfg(all(feature = "headless", not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "headless")))]
+pub mod headless;
+
 #[cfg(all(not(feature = "glow"), feature = "wgpu"))]
 use iced_winit as runtime;
 
@@ -194,6 +198,7 @@ use iced_wgpu as renderer;
 #[cfg(feature = "glow")]
 use iced_glow as renderer;
 
+pub use iced_native::fuzzy;
 pub use iced_native::theme;
 pub use runtime::event;
 pub use runtime::subscription;
@@ -212,9 +217,10 @@ pub use theme::Theme;
 
 pub use runtime::alignment;
 pub use runtime::futures;
+pub use runtime::gradient;
 pub use runtime::{
     color, Alignment, Background, Color, Command, ContentFit, Font, Length,
-    Padding, Point, Rectangle, Size, Vector,
+    Padding, Point, Rectangle, Shadow, Size, Vector,
 };
 
 #[cfg(feature = "system")]
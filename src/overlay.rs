@@ -8,6 +8,8 @@
 pub type Element<'a, Message, Renderer = crate::Renderer> =
     iced_native::overlay::Element<'a, Message, Renderer>;
 
+pub use iced_native::overlay::Memory;
+
 pub mod menu {
     //! Build and show dropdown menus.
     pub use iced_native::overlay::menu::{Appearance, State, StyleSheet};
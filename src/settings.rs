@@ -64,6 +64,11 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: crate::Application
     pub try_opengles_first: bool,
+
+    /// The limit on the GPU memory used to cache images.
+    ///
+    /// By default, images are evicted as soon as they are not drawn.
+    pub image_budget: iced_native::image::Budget,
 }
 
 impl<Flags> Settings<Flags> {
@@ -83,6 +88,7 @@ impl<Flags> Settings<Flags> {
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
             try_opengles_first: default_settings.try_opengles_first,
+            image_budget: default_settings.image_budget,
         }
     }
 }
@@ -102,6 +108,7 @@ where
             antialiasing: false,
             exit_on_close_request: true,
             try_opengles_first: false,
+            image_budget: Default::default(),
         }
     }
 }
@@ -1,6 +1,7 @@
 //! Display information and interactive controls in your application.
 pub use iced_native::widget::helpers::*;
 
+pub use iced_native::layout::flex::Distribution;
 pub use iced_native::{column, row};
 
 /// A container that distributes its contents vertically.
@@ -11,6 +12,33 @@ pub type Column<'a, Message, Renderer = crate::Renderer> =
 pub type Row<'a, Message, Renderer = crate::Renderer> =
     iced_native::widget::Row<'a, Message, Renderer>;
 
+pub mod align {
+    //! Override the alignment of an element inside of a row or a column.
+
+    /// An element that overrides the cross axis alignment of its row or
+    /// column.
+    pub type Align<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Align<'a, Message, Renderer>;
+}
+
+pub mod aspect_ratio {
+    //! Constrain content to a fixed aspect ratio.
+
+    /// An element that constrains its content to a fixed width to height
+    /// ratio.
+    pub type AspectRatio<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::AspectRatio<'a, Message, Renderer>;
+}
+
+pub mod badge {
+    //! Attach a small count bubble to a corner of some content.
+    pub use iced_native::widget::badge::{Anchor, Appearance, StyleSheet};
+
+    /// Some content with a small bubble attached to one of its corners.
+    pub type Badge<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Badge<'a, Message, Renderer>;
+}
+
 pub mod text {
     //! Write some text for your users to read.
     pub use iced_native::widget::text::{Appearance, StyleSheet};
@@ -29,6 +57,28 @@ pub mod button {
         iced_native::widget::Button<'a, Message, Renderer>;
 }
 
+pub mod calendar {
+    //! Display a month as a grid of days that can be selected.
+    pub use iced_native::widget::calendar::{
+        Appearance, Date, Decoration, Locale, State, StyleSheet, Weekday,
+    };
+
+    /// A grid displaying the days of a month, which can be selected one at a
+    /// time or as a range.
+    pub type Calendar<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Calendar<'a, Message, Renderer>;
+}
+
+pub mod card {
+    //! Group related content in a card with an optional header and footer.
+    pub use iced_native::widget::card::{Appearance, StyleSheet};
+
+    /// A container with a body and an optional header and footer, casting a
+    /// shadow.
+    pub type Card<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Card<'a, Message, Renderer>;
+}
+
 pub mod checkbox {
     //! Show toggle controls using checkboxes.
     pub use iced_native::widget::checkbox::{Appearance, Icon, StyleSheet};
@@ -38,8 +88,47 @@ pub mod checkbox {
         iced_native::widget::Checkbox<'a, Message, Renderer>;
 }
 
+pub mod chip {
+    //! Display compact pills, optionally selectable or deletable.
+    pub use iced_native::widget::chip::{Appearance, StyleSheet};
+
+    /// A pill with a label, an optional icon and an optional delete button.
+    pub type Chip<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Chip<'a, Message, Renderer>;
+}
+
+pub mod code_editor {
+    //! Edit source code with syntax highlighting and line numbers.
+    pub use iced_native::widget::code_editor::{
+        highlighter, Action, Appearance, Content, Edit, Highlighter, Motion,
+        Position, StyleSheet,
+    };
+
+    /// A multi-line text editor for source code.
+    pub type CodeEditor<
+        'a,
+        Message,
+        Renderer = crate::Renderer,
+        Highlighter = highlighter::PlainText,
+    > = iced_native::widget::CodeEditor<'a, Message, Renderer, Highlighter>;
+}
+
+pub mod color_picker {
+    //! Let your users pick a color with the mouse, by typing its hexadecimal
+    //! code or by choosing one of a set of swatches.
+    pub use iced_native::widget::color_picker::{
+        parse_hex, to_hex, Appearance, Hsv, ParseError, StyleSheet,
+    };
+
+    /// A color picker with a saturation and value area, hue and alpha
+    /// sliders and a hexadecimal text field.
+    pub type ColorPicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ColorPicker<'a, Message, Renderer>;
+}
+
 pub mod container {
     //! Decorate content and apply alignment.
+    pub use iced_native::layout::Direction;
     pub use iced_native::widget::container::{Appearance, StyleSheet};
 
     /// An element decorating some content.
@@ -47,6 +136,105 @@ pub mod container {
         iced_native::widget::Container<'a, Message, Renderer>;
 }
 
+pub mod data_grid {
+    //! Display and edit large tables of data.
+    pub use iced_native::widget::data_grid::{
+        Appearance, Column, State, StyleSheet,
+    };
+
+    /// A grid of text cells with a fixed header, made for large datasets.
+    pub type DataGrid<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DataGrid<'a, Message, Renderer>;
+}
+
+pub mod date_picker {
+    //! Let your users pick a date by typing it or choosing it in a calendar.
+    pub use iced_native::widget::date_picker::{
+        Appearance, Date, Locale, ParseError, StyleSheet, Weekday,
+    };
+
+    /// A field that can be filled with a date, either by typing it or by
+    /// choosing it in a calendar.
+    pub type DatePicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DatePicker<'a, Message, Renderer>;
+}
+
+pub mod draggable {
+    //! Drag some content, with a payload, over a drop zone.
+    pub use iced_native::widget::draggable::State;
+
+    /// Some content that can be dragged around, carrying a payload.
+    pub type Draggable<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Draggable<'a, T, Message, Renderer>;
+}
+
+pub mod drop_zone {
+    //! Receive the payload of a draggable dropped over some content.
+    pub use iced_native::widget::drop_zone::{Appearance, State, StyleSheet};
+
+    /// Some content that accepts the payloads of a type dropped over it.
+    pub type DropZone<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DropZone<'a, T, Message, Renderer>;
+}
+
+pub mod fab {
+    //! Pin a floating action button to a corner of some content.
+    pub use iced_native::widget::fab::{Anchor, Appearance, StyleSheet};
+
+    /// Some content with a round button floating over one of its corners.
+    pub type Fab<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Fab<'a, Message, Renderer>;
+}
+
+pub mod grid {
+    //! Distribute content in rows and columns.
+    pub use iced_native::widget::grid::Track;
+
+    /// A container that distributes its contents in rows and columns.
+    pub type Grid<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Grid<'a, Message, Renderer>;
+}
+
+pub mod lazy_list {
+    //! Display a huge amount of rows, building only the visible ones.
+    pub use iced_native::widget::lazy_list::StyleSheet;
+
+    /// A scrollable list of rows with a fixed height that only builds, lays
+    /// out, and draws the rows that are visible.
+    pub type LazyList<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::LazyList<'a, Message, Renderer>;
+}
+
+pub mod menu_bar {
+    //! Show the menus of an application in a bar, like File, Edit or View.
+    pub use iced_native::widget::menu_bar::{
+        Appearance, Id, Item, Menu, StyleSheet,
+    };
+
+    /// A bar of menu titles, each opening a dropdown of items.
+    pub type MenuBar<Message, Renderer = crate::Renderer> =
+        iced_native::widget::MenuBar<Message, Renderer>;
+}
+
+pub mod number_input {
+    //! Let your users type and step through numeric values.
+    pub use iced_native::widget::number_input::{Appearance, StyleSheet};
+
+    /// A field that can be filled with a number.
+    pub type NumberInput<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::NumberInput<'a, T, Message, Renderer>;
+}
+
+pub mod pagination {
+    //! Let your users move between the pages of some paginated content.
+    pub use iced_native::widget::pagination::{Appearance, State, StyleSheet};
+
+    /// A row of buttons to move between pages, with an optional input to
+    /// jump to a typed page.
+    pub type Pagination<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Pagination<'a, Message, Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -58,8 +246,9 @@ pub mod pane_grid {
     //!
     //! [`pane_grid` example]: https://github.com/iced-rs/iced/tree/0.8/examples/pane_grid
     pub use iced_native::widget::pane_grid::{
-        Axis, Configuration, Direction, DragEvent, Line, Node, Pane,
-        ResizeEvent, Split, State, StyleSheet,
+        Appearance, Axis, Configuration, Direction, DragEvent, DropTarget,
+        Easing, FloatEvent, Line, Node, Pane, ResizeEvent, Split, State,
+        StyleSheet, TabGroup, Transition,
     };
 
     /// A collection of panes distributed using either vertical or horizontal splits
@@ -76,12 +265,16 @@ pub mod pane_grid {
     /// The title bar of a [`Pane`].
     pub type TitleBar<'a, Message, Renderer = crate::Renderer> =
         iced_native::widget::pane_grid::TitleBar<'a, Message, Renderer>;
+
+    /// The tabs of a [`Pane`] hosting many contents.
+    pub type TabBar<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::pane_grid::TabBar<'a, Message, Renderer>;
 }
 
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::widget::pick_list::{
-        Appearance, Handle, Icon, StyleSheet,
+        Appearance, Handle, Icon, Id, StyleSheet,
     };
 
     /// A widget allowing the selection of a single value from a list of options.
@@ -89,6 +282,15 @@ pub mod pick_list {
         iced_native::widget::PickList<'a, T, Message, Renderer>;
 }
 
+pub mod positioned {
+    //! Place content at explicit positions inside of a container.
+    pub use iced_native::widget::positioned::Anchor;
+
+    /// A container that places its children at explicit positions.
+    pub type Positioned<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Positioned<'a, Message, Renderer>;
+}
+
 pub mod radio {
     //! Create choices using radio buttons.
     pub use iced_native::widget::radio::{Appearance, StyleSheet};
@@ -98,11 +300,42 @@ pub mod radio {
         iced_native::widget::Radio<Message, Renderer>;
 }
 
+pub mod rating {
+    //! Rate something with a row of icons, like stars.
+    pub use iced_native::widget::rating::{Appearance, StyleSheet};
+
+    /// A row of icons displaying a value between zero and their amount.
+    pub type Rating<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Rating<'a, Message, Renderer>;
+}
+
+pub mod reorderable {
+    //! Let your users sort a list by dragging its items around.
+    pub use iced_native::widget::reorderable::{Appearance, State, StyleSheet};
+
+    /// A vertical list whose items can be dragged to new positions.
+    pub type Reorderable<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Reorderable<'a, Message, Renderer>;
+}
+
+pub mod segmented_button {
+    //! Let your users choose one of a few options in a joined group of
+    //! buttons.
+    pub use iced_native::widget::segmented_button::{
+        Appearance, StyleSheet, SLIDE_DURATION,
+    };
+
+    /// A group of joined segments, of which at most one is selected.
+    pub type SegmentedButton<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::SegmentedButton<'a, T, Message, Renderer>;
+}
+
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
-        snap_to, style::Scrollbar, style::Scroller, Id, Properties,
-        RelativeOffset, StyleSheet,
+        animate_to, ensure_visible, ensure_visible_animated, scroll_to,
+        snap_to, style::Scrollbar, style::Scroller, AbsoluteOffset, Easing, Id,
+        Properties, RelativeOffset, Snap, StyleSheet, Transition, WheelRouting,
     };
 
     /// A widget that can vertically display an infinite amount of content
@@ -111,6 +344,97 @@ pub mod scrollable {
         iced_native::widget::Scrollable<'a, Message, Renderer>;
 }
 
+pub mod selectable_text {
+    //! Display text that can be selected and copied.
+    pub use iced_native::widget::selectable_text::{Appearance, StyleSheet};
+
+    /// A paragraph of read-only text that can be selected and copied.
+    pub type SelectableText<'a, Renderer = crate::Renderer> =
+        iced_native::widget::SelectableText<'a, Renderer>;
+}
+
+pub mod skeleton {
+    //! Display shimmering placeholders while some content loads.
+    pub use iced_native::widget::skeleton::{
+        Appearance, Shape, State, StyleSheet,
+    };
+
+    /// A stack of placeholder shapes with a shimmer sweeping across them.
+    pub type Skeleton<Renderer = crate::Renderer> =
+        iced_native::widget::Skeleton<Renderer>;
+}
+
+pub mod stack {
+    //! Display content on top of other content.
+
+    /// A container that displays its children on top of each other.
+    pub type Stack<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Stack<'a, Message, Renderer>;
+}
+
+pub mod table {
+    //! Display rows of data in resizable, sortable columns.
+    pub use iced_native::widget::table::{Appearance, StyleSheet};
+
+    /// A widget that displays rows of data in columns with a fixed header.
+    pub type Table<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Table<'a, Message, Renderer>;
+
+    /// A column of a [`Table`].
+    pub type Column<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::table::Column<'a, Message, Renderer>;
+}
+
+pub mod split {
+    //! Display two widgets side by side, with a draggable divider between
+    //! them.
+    pub use iced_native::widget::split::{Appearance, Axis, StyleSheet};
+
+    /// Two widgets sharing some space, with a draggable divider between
+    /// them.
+    pub type Split<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Split<'a, Message, Renderer>;
+}
+
+pub mod stepper {
+    //! Step through numeric values with a pair of compact buttons.
+    pub use iced_native::widget::stepper::{Appearance, StyleSheet};
+
+    /// A compact `- value +` control stepping through numeric values.
+    pub type Stepper<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Stepper<'a, T, Message, Renderer>;
+}
+
+pub mod swipeable {
+    //! Reveal actions or dismiss some content by swiping it horizontally.
+    pub use iced_native::widget::swipeable::{Side, State};
+
+    /// A row that can be swiped horizontally to reveal some actions under it
+    /// or to dismiss it entirely.
+    pub type Swipeable<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Swipeable<'a, Message, Renderer>;
+}
+
+pub mod tabs {
+    //! Display a strip of tabs on top of the content of the selected one.
+    pub use iced_native::widget::tabs::{Appearance, Reorder, StyleSheet, Tab};
+
+    /// A strip of closable, reorderable tabs on top of the content of the
+    /// selected one.
+    pub type Tabs<'a, Id, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Tabs<'a, Id, Message, Renderer>;
+}
+
+pub mod tags_input {
+    //! Let your users enter a list of tags, displayed as chips.
+    pub use iced_native::widget::tags_input::State;
+
+    /// A field displaying a list of tags as chips, followed by some free text
+    /// that becomes a new tag.
+    pub type TagsInput<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TagsInput<'a, Message, Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
     pub use iced_native::widget::toggler::{Appearance, StyleSheet};
@@ -120,11 +444,33 @@ pub mod toggler {
         iced_native::widget::Toggler<'a, Message, Renderer>;
 }
 
+pub mod toolbar {
+    //! Lay out actions in a row, moving the ones that do not fit into a menu.
+    pub use iced_native::widget::toolbar::{Appearance, State, StyleSheet};
+
+    /// A row of actions that moves the ones that do not fit into a trailing
+    /// menu.
+    pub type Toolbar<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Toolbar<'a, Message, Renderer>;
+}
+
+pub mod tree_view {
+    //! Display a hierarchy of nodes that can be expanded, collapsed and
+    //! selected.
+    pub use iced_native::widget::tree_view::{
+        Appearance, Children, Node, StyleSheet,
+    };
+
+    /// A hierarchy of nodes that can be expanded, collapsed and selected.
+    pub type TreeView<'a, Id, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TreeView<'a, Id, Message, Renderer>;
+}
+
 pub mod text_input {
     //! Display fields that can be filled with text.
     pub use iced_native::widget::text_input::{
         focus, move_cursor_to, move_cursor_to_end, move_cursor_to_front,
-        select_all, Appearance, Id, StyleSheet,
+        select_all, Appearance, Icon, Id, Misspelling, StyleSheet,
     };
 
     /// A field that can be filled with text.
@@ -132,6 +478,18 @@ pub mod text_input {
         iced_native::widget::TextInput<'a, Message, Renderer>;
 }
 
+pub mod time_picker {
+    //! Let your users pick a time of the day with a spinner for every unit.
+    pub use iced_native::widget::time_picker::{
+        Appearance, Format, ParseError, Period, StyleSheet, Time,
+    };
+
+    /// A set of spinners to pick the hour, the minute and, optionally, the
+    /// second of a [`Time`].
+    pub type TimePicker<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TimePicker<'a, Message, Renderer>;
+}
+
 pub mod tooltip {
     //! Display a widget over another.
     pub use iced_native::widget::tooltip::Position;
@@ -141,26 +499,64 @@ pub mod tooltip {
         iced_native::widget::Tooltip<'a, Message, Renderer>;
 }
 
+pub use iced_native::widget::knob;
 pub use iced_native::widget::progress_bar;
 pub use iced_native::widget::rule;
 pub use iced_native::widget::slider;
+pub use iced_native::widget::spinner;
 pub use iced_native::widget::vertical_slider;
 pub use iced_native::widget::Space;
 
+pub use align::Align;
+pub use aspect_ratio::AspectRatio;
+pub use badge::Badge;
 pub use button::Button;
+pub use calendar::Calendar;
+pub use card::Card;
 pub use checkbox::Checkbox;
+pub use chip::Chip;
+pub use code_editor::CodeEditor;
+pub use color_picker::ColorPicker;
 pub use container::Container;
+pub use data_grid::DataGrid;
+pub use date_picker::DatePicker;
+pub use draggable::Draggable;
+pub use drop_zone::DropZone;
+pub use fab::Fab;
+pub use grid::Grid;
+pub use knob::Knob;
+pub use lazy_list::LazyList;
+pub use menu_bar::MenuBar;
+pub use number_input::NumberInput;
+pub use pagination::Pagination;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
+pub use positioned::Positioned;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use rating::Rating;
+pub use reorderable::Reorderable;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
+pub use segmented_button::SegmentedButton;
+pub use selectable_text::SelectableText;
+pub use skeleton::Skeleton;
 pub use slider::Slider;
+pub use spinner::Spinner;
+pub use split::Split;
+pub use stack::Stack;
+pub use stepper::Stepper;
+pub use swipeable::Swipeable;
+pub use table::Table;
+pub use tabs::Tabs;
+pub use tags_input::TagsInput;
 pub use text::Text;
 pub use text_input::TextInput;
+pub use time_picker::TimePicker;
 pub use toggler::Toggler;
+pub use toolbar::Toolbar;
 pub use tooltip::Tooltip;
+pub use tree_view::TreeView;
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "canvas")]
@@ -181,13 +577,25 @@ where
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_native::image::{Budget, Eviction, Handle, Usage};
 
     /// A frame that displays an image.
     pub type Image = iced_native::widget::Image<Handle>;
 
     pub use iced_native::widget::image::viewer;
     pub use viewer::Viewer;
+
+    /// An image that stretches without distorting its borders.
+    pub type NinePatch = iced_native::widget::image::NinePatch<Handle>;
+
+    pub use iced_native::widget::image::nine_patch;
+
+    /// An image loaded in the background, displaying a placeholder until
+    /// it is ready.
+    pub type Lazy<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::image::Lazy<'a, Message, Renderer>;
+
+    pub use iced_native::widget::image::lazy;
 }
 
 #[cfg(feature = "qr_code")]
@@ -202,6 +610,14 @@ pub mod svg {
     pub use iced_native::widget::svg::{Appearance, StyleSheet, Svg};
 }
 
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+pub mod shader {
+    //! Draw custom WGSL shaders in your user interface.
+    pub use iced_native::shader::Handle;
+    pub use iced_native::widget::shader::Shader;
+}
+
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
 pub use canvas::Canvas;
@@ -218,6 +634,10 @@ pub use qr_code::QRCode;
 #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
 pub use svg::Svg;
 
+#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu")))]
+pub use shader::Shader;
+
 use crate::Command;
 use iced_native::widget::operation;
 
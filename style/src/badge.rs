@@ -0,0 +1,24 @@
+//! Change the appearance of a badge.
+use iced_core::{Background, Color};
+
+/// The appearance of a badge.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the badge.
+    pub background: Background,
+    /// The text [`Color`] of the badge.
+    pub text_color: Color,
+    /// The border width of the badge.
+    pub border_width: f32,
+    /// The border [`Color`] of the badge.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a badge.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the badge.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
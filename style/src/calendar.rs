@@ -0,0 +1,40 @@
+//! Change the appearance of a calendar.
+use iced_core::{Background, Color};
+
+/// The appearance of a calendar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the calendar.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the days of the calendar.
+    pub text_color: Color,
+    /// The text [`Color`] of the names of the weekdays and of the days
+    /// outside of the displayed month.
+    pub weekday_color: Color,
+    /// The text [`Color`] of the week numbers.
+    pub week_number_color: Color,
+    /// The text [`Color`] of the days that cannot be selected.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of the day that is hovered or focused.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected days, and of the ends of a
+    /// selected range.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected days.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the days inside of a selected range.
+    pub range_background: Background,
+    /// The text [`Color`] of the days inside of a selected range.
+    pub range_text_color: Color,
+    /// The border [`Color`] marking the current day.
+    pub today_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a calendar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a calendar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
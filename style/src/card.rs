@@ -0,0 +1,40 @@
+//! Change the appearance of a card.
+use iced_core::{Background, Color, Shadow};
+
+/// The appearance of a card.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the card.
+    pub background: Background,
+    /// The [`Background`] of the header of the card, if any.
+    pub header_background: Option<Background>,
+    /// The [`Background`] of the footer of the card, if any.
+    pub footer_background: Option<Background>,
+    /// The text [`Color`] of the card, if any.
+    pub text_color: Option<Color>,
+    /// The border radius of the card.
+    pub border_radius: f32,
+    /// The border width of the card.
+    pub border_width: f32,
+    /// The border [`Color`] of the card.
+    pub border_color: Color,
+    /// The [`Color`] of the lines dividing the header and the footer from
+    /// the body of the card.
+    pub divider_color: Color,
+    /// The [`Shadow`] cast by the card.
+    pub shadow: Shadow,
+}
+
+/// A set of rules that dictate the style of a card.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active card.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered card.
+    ///
+    /// It usually elevates the card, casting a larger [`Shadow`].
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
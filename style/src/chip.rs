@@ -0,0 +1,29 @@
+//! Change the appearance of a chip.
+use iced_core::{Background, Color};
+
+/// The appearance of a chip.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the chip, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the chip.
+    pub text_color: Color,
+    /// The border width of the chip.
+    pub border_width: f32,
+    /// The border [`Color`] of the chip.
+    pub border_color: Color,
+    /// The [`Color`] of the delete button of the chip.
+    pub delete_color: Color,
+}
+
+/// A set of rules that dictate the style of a chip.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a chip.
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a chip.
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+}
@@ -0,0 +1,35 @@
+//! Change the appearance of a code editor.
+use iced_core::{Background, Color};
+
+/// The appearance of a code editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the code editor.
+    pub background: Background,
+    /// The border radius of the code editor.
+    pub border_radius: f32,
+    /// The border width of the code editor.
+    pub border_width: f32,
+    /// The border [`Color`] of the code editor.
+    pub border_color: Color,
+    /// The [`Background`] of the line numbers gutter.
+    pub gutter_background: Background,
+    /// The [`Color`] of the line numbers.
+    pub line_number_color: Color,
+    /// The default [`Color`] of the text.
+    pub text_color: Color,
+    /// The [`Color`] of the selection.
+    pub selection_color: Color,
+}
+
+/// A set of rules that dictate the style of a code editor.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active code editor.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a focused code editor.
+    fn focused(&self, style: &Self::Style) -> Appearance;
+}
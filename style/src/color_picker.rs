@@ -0,0 +1,34 @@
+//! Change the appearance of a color picker.
+use iced_core::{Background, Color};
+
+/// The appearance of a color picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the color picker.
+    pub background: Background,
+    /// The border width of the color picker.
+    pub border_width: f32,
+    /// The border radius of the color picker.
+    pub border_radius: f32,
+    /// The border [`Color`] of the color picker.
+    pub border_color: Color,
+    /// The [`Color`] of the handles marking the picked color in the
+    /// saturation and value area and in the sliders.
+    pub handle_color: Color,
+    /// The border [`Color`] of the swatches.
+    pub swatch_border_color: Color,
+    /// The two [`Color`] of the checkerboard displayed behind transparent
+    /// colors.
+    pub checkerboard_colors: (Color, Color),
+    /// The [`Color`] of the icon of the eyedropper button.
+    pub icon_color: Color,
+}
+
+/// A set of rules that dictate the style of a color picker.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a color picker.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,5 +1,5 @@
 //! Change the appearance of a container.
-use iced_core::{Background, Color};
+use iced_core::{Background, Color, Shadow};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +14,8 @@ pub struct Appearance {
     pub border_width: f32,
     /// The border [`Color`] of the container.
     pub border_color: Color,
+    /// The [`Shadow`] cast by the container.
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Appearance {
@@ -24,6 +26,7 @@ impl std::default::Default for Appearance {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         }
     }
 }
@@ -0,0 +1,38 @@
+//! Change the appearance of a data grid.
+use iced_core::{Background, Color};
+
+/// The appearance of a data grid.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the cells.
+    pub background: Option<Background>,
+    /// The [`Background`] of the header row.
+    pub header_background: Option<Background>,
+    /// The text [`Color`] of the header row.
+    pub header_text_color: Color,
+    /// The text [`Color`] of the cells.
+    pub text_color: Color,
+    /// The [`Background`] of the row under the cursor.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected rows.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected rows.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the lines between the rows and the columns.
+    pub divider_color: Color,
+    /// The width of the lines between the rows and the columns.
+    pub divider_width: f32,
+    /// The [`Color`] of the line after the frozen columns.
+    pub frozen_divider_color: Color,
+    /// The [`Color`] of the scrollbars.
+    pub scrollbar_color: Color,
+}
+
+/// A set of rules that dictate the style of a data grid.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a data grid.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
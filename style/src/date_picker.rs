@@ -0,0 +1,39 @@
+//! Change the appearance of the calendar of a date picker.
+use iced_core::{Background, Color};
+
+/// The appearance of the calendar of a date picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the days of the calendar.
+    pub text_color: Color,
+    /// The [`Background`] of the calendar.
+    pub background: Background,
+    /// The border width of the calendar.
+    pub border_width: f32,
+    /// The border radius of the calendar.
+    pub border_radius: f32,
+    /// The border [`Color`] of the calendar.
+    pub border_color: Color,
+    /// The text [`Color`] of the names of the weekdays and of the days
+    /// outside of the displayed month.
+    pub weekday_color: Color,
+    /// The text [`Color`] of the days that cannot be selected.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of the day that is hovered or focused.
+    pub hovered_background: Background,
+    /// The [`Background`] of the selected day.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected day.
+    pub selected_text_color: Color,
+    /// The border [`Color`] marking the current day.
+    pub today_border_color: Color,
+}
+
+/// A set of rules that dictate the style of the calendar of a date picker.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the calendar of a date picker.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
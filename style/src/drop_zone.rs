@@ -0,0 +1,29 @@
+//! Change the appearance of a drop zone.
+use iced_core::{Background, Color};
+
+/// The appearance of a drop zone while something it accepts is dragged.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] drawn behind the contents of the drop zone.
+    pub background: Option<Background>,
+    /// The border radius of the drop zone.
+    pub border_radius: f32,
+    /// The border width of the drop zone.
+    pub border_width: f32,
+    /// The border [`Color`] of the drop zone.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a drop zone.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a drop zone while something it accepts
+    /// is dragged elsewhere.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a drop zone while something it accepts
+    /// is dragged over it.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
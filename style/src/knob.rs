@@ -0,0 +1,35 @@
+//! Change the appearance of a knob.
+use iced_core::{Background, Color};
+
+/// The appearance of a knob.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the body of the knob.
+    pub background: Background,
+    /// The border width of the body of the knob.
+    pub border_width: f32,
+    /// The border [`Color`] of the body of the knob.
+    pub border_color: Color,
+    /// The [`Color`] of the indicator pointing at the current value.
+    pub indicator_color: Color,
+    /// The [`Color`] of the tick marks around the knob past the current value.
+    pub tick_color: Color,
+    /// The [`Color`] of the tick marks around the knob up to the current
+    /// value.
+    pub filled_tick_color: Color,
+}
+
+/// A set of rules that dictate the style of a knob.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active knob.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of an hovered knob.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a knob that is being dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance;
+}
@@ -21,21 +21,45 @@
 pub use iced_core::{Background, Color};
 
 pub mod application;
+pub mod badge;
 pub mod button;
+pub mod calendar;
+pub mod card;
 pub mod checkbox;
+pub mod chip;
+pub mod code_editor;
+pub mod color_picker;
 pub mod container;
+pub mod data_grid;
+pub mod date_picker;
+pub mod drop_zone;
+pub mod knob;
 pub mod menu;
+pub mod menu_bar;
+pub mod pagination;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rating;
+pub mod reorderable;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented_button;
+pub mod selectable_text;
+pub mod skeleton;
 pub mod slider;
+pub mod spinner;
+pub mod split;
+pub mod stepper;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_input;
 pub mod theme;
 pub mod toggler;
+pub mod toolbar;
+pub mod tree_view;
 
 pub use theme::Theme;
@@ -1,7 +1,12 @@
 //! Change the appearance of menus.
-use iced_core::{Background, Color};
+use crate::theme::{self, Theme};
+
+use iced_core::{Background, Color, Shadow};
 
 /// The appearance of a menu.
+///
+/// Fields may be added to it over time, so custom styles should fill the
+/// ones they do not change with [`Appearance::default`].
 #[derive(Debug, Clone, Copy)]
 pub struct Appearance {
     /// The text [`Color`] of the menu.
@@ -18,6 +23,21 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The text [`Color`] of the characters of an option that match the
+    /// filter of the menu.
+    pub highlighted_text_color: Color,
+    /// The text [`Color`] of the characters of a selected option that match
+    /// the filter of the menu.
+    pub selected_highlighted_text_color: Color,
+    /// The [`Shadow`] cast by the menu.
+    pub shadow: Shadow,
+}
+
+impl Default for Appearance {
+    /// Returns the [`Appearance`] of a menu in the light [`Theme`].
+    fn default() -> Self {
+        Theme::Light.appearance(&theme::Menu::Default)
+    }
 }
 
 /// The style sheet of a menu.
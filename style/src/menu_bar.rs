@@ -0,0 +1,36 @@
+//! Change the appearance of a menu bar.
+use iced_core::{Background, Color};
+
+/// The appearance of a menu bar and of its menus.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the bar, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the titles and the items.
+    pub text_color: Color,
+    /// The text [`Color`] of the disabled items and the shortcuts.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of the open title and of the highlighted item.
+    pub selected_background: Background,
+    /// The text [`Color`] of the open title and of the highlighted item.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the menus.
+    pub menu_background: Background,
+    /// The border width of the menus.
+    pub menu_border_width: f32,
+    /// The border radius of the menus.
+    pub menu_border_radius: f32,
+    /// The border [`Color`] of the menus.
+    pub menu_border_color: Color,
+    /// The [`Color`] of the separators between items.
+    pub separator_color: Color,
+}
+
+/// A set of rules that dictate the style of a menu bar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a menu bar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
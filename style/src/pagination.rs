@@ -0,0 +1,36 @@
+//! Change the appearance of a pagination control.
+use iced_core::{Background, Color};
+
+/// The appearance of a button of a pagination control.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the button, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the button.
+    pub text_color: Color,
+    /// The border radius of the button.
+    pub border_radius: f32,
+    /// The border width of the button.
+    pub border_width: f32,
+    /// The border [`Color`] of the button.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a pagination control.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of an idle button.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a hovered button.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of the button of the current page.
+    fn selected(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a button leading nowhere, like the
+    /// previous page on the first page.
+    fn disabled(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,16 +1,39 @@
 //! Change the appearance of a pane grid.
-use iced_core::Color;
+use iced_core::time::Duration;
+use iced_core::{Background, Color};
 
 /// A set of rules that dictate the style of a container.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
+    /// The [`Appearance`] of the region of a pane where a dragged pane would be
+    /// dropped.
+    fn hovered_region(&self, style: &Self::Style) -> Appearance;
+
     /// The [`Line`] to draw when a split is picked.
     fn picked_split(&self, style: &Self::Style) -> Option<Line>;
 
     /// The [`Line`] to draw when a split is hovered.
     fn hovered_split(&self, style: &Self::Style) -> Option<Line>;
+
+    /// The [`Transition`] of a pane when it is maximized or restored.
+    fn maximize_transition(&self, _style: &Self::Style) -> Transition {
+        Transition::default()
+    }
+}
+
+/// The appearance of a highlighted region of a pane grid.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the region.
+    pub background: Background,
+    /// The border width of the region.
+    pub border_width: f32,
+    /// The border [`Color`] of the region.
+    pub border_color: Color,
+    /// The border radius of the region.
+    pub border_radius: f32,
 }
 
 /// A line.
@@ -24,3 +47,59 @@ pub struct Line {
     /// The width of the [`Line`].
     pub width: f32,
 }
+
+/// The animation of a pane when it is maximized or restored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    /// The duration of the animation.
+    ///
+    /// A zero duration disables it.
+    pub duration: Duration,
+
+    /// The [`Easing`] of the animation.
+    pub easing: Easing,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(200),
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+/// The rate of change of an animation over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// A constant rate of change.
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    EaseOut,
+    /// Starts slowly, accelerates, and decelerates at the end.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress of an animation, given its linear
+    /// `progress` between `0.0` and `1.0`.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t.powi(3),
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Change the appearance of a rating.
+use iced_core::Color;
+
+/// The appearance of a rating.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the filled icons of the rating.
+    pub filled_color: Color,
+    /// The [`Color`] of the empty icons of the rating.
+    pub empty_color: Color,
+    /// The [`Color`] of the icons previewing the value under the cursor.
+    pub preview_color: Color,
+    /// The [`Color`] of the border of a focused rating.
+    pub focused_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a rating.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the rating.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,25 @@
+//! Change the appearance of a reorderable list.
+use iced_core::{Background, Color};
+
+/// The appearance of a reorderable list.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the line showing where a dragged item will be
+    /// dropped.
+    pub indicator_color: Color,
+    /// The width of the drop indicator.
+    pub indicator_width: f32,
+    /// The [`Background`] behind the item being dragged.
+    pub dragged_background: Option<Background>,
+    /// The border radius of the background of the item being dragged.
+    pub dragged_border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a reorderable list.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a reorderable list.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,34 @@
+//! Change the appearance of a segmented button.
+use iced_core::{Background, Color};
+
+/// The appearance of a segmented button.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the segmented button.
+    pub background: Background,
+    /// The border radius of the segmented button.
+    pub border_radius: f32,
+    /// The border width of the segmented button.
+    pub border_width: f32,
+    /// The border [`Color`] of the segmented button.
+    pub border_color: Color,
+    /// The [`Color`] of the dividers between the segments.
+    pub divider_color: Color,
+    /// The [`Background`] of the hovered segment, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selection indicator.
+    pub indicator: Background,
+    /// The text [`Color`] of the segments.
+    pub text_color: Color,
+    /// The text [`Color`] of the selected segment.
+    pub selected_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a segmented button.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a segmented button.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
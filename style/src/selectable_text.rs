@@ -0,0 +1,22 @@
+//! Change the appearance of selectable text.
+use iced_core::Color;
+
+/// The appearance of some selectable text.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the text.
+    ///
+    /// `None` means using the inherited color.
+    pub color: Option<Color>,
+    /// The [`Color`] of the selection.
+    pub selection_color: Color,
+}
+
+/// A set of rules that dictate the style of some selectable text.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of some selectable text.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
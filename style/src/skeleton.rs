@@ -0,0 +1,22 @@
+//! Change the appearance of a skeleton.
+use iced_core::Color;
+
+/// The appearance of a skeleton.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the shapes of the skeleton.
+    pub color: Color,
+    /// The [`Color`] of the shimmer sweeping across the shapes.
+    pub highlight: Color,
+    /// The border radius of the bars and blocks of the skeleton.
+    pub border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a skeleton.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a skeleton.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,26 @@
+//! Change the appearance of a split.
+use iced_core::Color;
+
+/// The appearance of a split.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the divider of the split.
+    pub divider_color: Color,
+    /// The width of the line drawn along the divider of the split.
+    pub divider_width: f32,
+}
+
+/// A set of rules that dictate the style of a split.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active split.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a split with a hovered divider.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a split whose divider is being dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance;
+}
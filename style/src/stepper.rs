@@ -0,0 +1,43 @@
+//! Change the appearance of a stepper.
+use iced_core::{Background, Color};
+
+/// The appearance of a stepper.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the stepper.
+    pub background: Background,
+    /// The border radius of the stepper.
+    pub border_radius: f32,
+    /// The border width of the stepper.
+    pub border_width: f32,
+    /// The border [`Color`] of the stepper.
+    pub border_color: Color,
+    /// The text [`Color`] of the value of the stepper.
+    pub value_color: Color,
+    /// The [`Background`] of the buttons of the stepper, if any.
+    pub button_background: Option<Background>,
+    /// The [`Color`] of the signs of the buttons of the stepper.
+    pub button_color: Color,
+    /// The [`Color`] of the signs of the buttons of the stepper that cannot
+    /// be pressed.
+    pub disabled_button_color: Color,
+}
+
+/// A set of rules that dictate the style of a stepper.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active stepper.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a stepper with a hovered button.
+    ///
+    /// Only the button properties apply to the hovered button.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a stepper with a pressed button.
+    ///
+    /// Only the button properties apply to the pressed button.
+    fn pressed(&self, style: &Self::Style) -> Appearance;
+}
@@ -20,4 +20,15 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of the svg.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the colors replacing the CSS variables used by the svg, by
+    /// name.
+    ///
+    /// Useful for icons with multiple colors that follow the theme. For
+    /// instance, a `("accent", color)` pair replaces every `var(--accent)`.
+    ///
+    /// By default, no variables are replaced.
+    fn variables(&self, _style: &Self::Style) -> Vec<(String, Color)> {
+        Vec::new()
+    }
 }
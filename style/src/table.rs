@@ -0,0 +1,25 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the header row.
+    pub header_background: Option<Background>,
+    /// The [`Color`] of the dividers between the columns of the header and
+    /// below the header.
+    pub divider_color: Color,
+    /// The [`Color`] of a divider that is hovered or being dragged.
+    pub active_divider_color: Color,
+    /// The width of the dividers.
+    pub divider_width: f32,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
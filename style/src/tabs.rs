@@ -0,0 +1,34 @@
+//! Change the appearance of a tab bar.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab of a tab bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the label of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+    /// The border radius of the tab.
+    pub border_radius: f32,
+    /// The [`Color`] of the line drawn under the tab, if any.
+    pub indicator_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a tab bar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Background`] of the strip of tabs.
+    fn bar(&self, style: &Self::Style) -> Option<Background>;
+
+    /// Produces the active [`Appearance`] of a tab, given whether it is the
+    /// selected one.
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a tab, given whether it is the
+    /// selected one.
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+}
@@ -1,4 +1,6 @@
 //! Change the appearance of a text input.
+use crate::menu;
+
 use iced_core::{Background, Color};
 
 /// The appearance of a text input.
@@ -38,4 +40,54 @@ pub trait StyleSheet {
     fn hovered(&self, style: &Self::Style) -> Appearance {
         self.focused(style)
     }
+
+    /// Produces the style of a text input whose value failed to validate,
+    /// depending on whether it is focused.
+    fn errored(&self, style: &Self::Style, is_focused: bool) -> Appearance {
+        let appearance = if is_focused {
+            self.focused(style)
+        } else {
+            self.active(style)
+        };
+
+        Appearance {
+            border_width: appearance.border_width.max(1.0),
+            border_color: self.error_color(style),
+            ..appearance
+        }
+    }
+
+    /// Produces the [`Color`] of the validation error message of a text input.
+    fn error_color(&self, _style: &Self::Style) -> Color {
+        Color::from_rgb(0.8, 0.2, 0.2)
+    }
+
+    /// Produces the [`Color`] of the underline of misspelled words in a text
+    /// input.
+    fn misspelling_color(&self, style: &Self::Style) -> Color {
+        self.error_color(style)
+    }
+
+    /// Returns whether the text cursor of a focused text input should blink.
+    fn is_cursor_blinking(&self, _style: &Self::Style) -> bool {
+        true
+    }
+
+    /// Produces the [`menu::Appearance`] of the spelling suggestions of a
+    /// text input.
+    fn suggestions(&self, style: &Self::Style) -> menu::Appearance {
+        let active = self.active(style);
+        let value_color = self.value_color(style);
+
+        menu::Appearance {
+            text_color: value_color,
+            background: active.background,
+            border_width: active.border_width.max(1.0),
+            border_radius: active.border_radius,
+            border_color: active.border_color,
+            selected_text_color: value_color,
+            selected_background: Background::Color(self.selection_color(style)),
+            ..menu::Appearance::default()
+        }
+    }
 }
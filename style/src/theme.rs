@@ -5,23 +5,48 @@ use self::palette::Extended;
 pub use self::palette::Palette;
 
 use crate::application;
+use crate::badge;
 use crate::button;
+use crate::calendar;
+use crate::card;
 use crate::checkbox;
+use crate::chip;
+use crate::code_editor;
+use crate::color_picker;
 use crate::container;
+use crate::data_grid;
+use crate::date_picker;
+use crate::drop_zone;
+use crate::knob;
 use crate::menu;
+use crate::menu_bar;
+use crate::pagination;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
 use crate::radio;
+use crate::rating;
+use crate::reorderable;
 use crate::rule;
 use crate::scrollable;
+use crate::segmented_button;
+use crate::selectable_text;
+use crate::skeleton;
 use crate::slider;
+use crate::spinner;
+use crate::split;
+use crate::stepper;
 use crate::svg;
+use crate::table;
+use crate::tabs;
 use crate::text;
 use crate::text_input;
 use crate::toggler;
+use crate::toolbar;
+use crate::tree_view;
 
-use iced_core::{Background, Color, Vector};
+use iced_core::time::Duration;
+use iced_core::{Background, Color, Shadow, Vector};
 
 use std::rc::Rc;
 
@@ -119,6 +144,39 @@ impl From<fn(&Theme) -> application::Appearance> for Application {
     }
 }
 
+/// The style of a badge.
+#[derive(Default)]
+pub enum Badge {
+    /// The primary style.
+    #[default]
+    Primary,
+    /// The danger style.
+    Danger,
+    /// A custom style.
+    Custom(Box<dyn badge::StyleSheet<Style = Theme>>),
+}
+
+impl badge::StyleSheet for Theme {
+    type Style = Badge;
+
+    fn appearance(&self, style: &Self::Style) -> badge::Appearance {
+        let palette = self.extended_palette();
+
+        let from_pair = |pair: palette::Pair| badge::Appearance {
+            background: pair.color.into(),
+            text_color: pair.text,
+            border_width: 1.0,
+            border_color: palette.background.base.color,
+        };
+
+        match style {
+            Badge::Primary => from_pair(palette.primary.strong),
+            Badge::Danger => from_pair(palette.danger.base),
+            Badge::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
 /// The style of a button.
 #[derive(Default)]
 pub enum Button {
@@ -212,12 +270,9 @@ impl button::StyleSheet for Theme {
 
         button::Appearance {
             shadow_offset: Vector::default(),
-            background: active.background.map(|background| match background {
-                Background::Color(color) => Background::Color(Color {
-                    a: color.a * 0.5,
-                    ..color
-                }),
-            }),
+            background: active
+                .background
+                .map(|background| background.scale_alpha(0.5)),
             text_color: Color {
                 a: active.text_color.a * 0.5,
                 ..active.text_color
@@ -227,6 +282,107 @@ impl button::StyleSheet for Theme {
     }
 }
 
+/// The style of a calendar.
+#[derive(Default)]
+pub enum Calendar {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn calendar::StyleSheet<Style = Theme>>),
+}
+
+impl calendar::StyleSheet for Theme {
+    type Style = Calendar;
+
+    fn appearance(&self, style: &Self::Style) -> calendar::Appearance {
+        match style {
+            Calendar::Default => {
+                let palette = self.extended_palette();
+
+                calendar::Appearance {
+                    background: None,
+                    text_color: palette.background.base.text,
+                    weekday_color: palette.background.strong.color,
+                    week_number_color: palette.background.strong.color,
+                    disabled_text_color: Color {
+                        a: 0.3,
+                        ..palette.background.base.text
+                    },
+                    hovered_background: palette.background.weak.color.into(),
+                    selected_background: palette.primary.strong.color.into(),
+                    selected_text_color: palette.primary.strong.text,
+                    range_background: palette.primary.weak.color.into(),
+                    range_text_color: palette.primary.weak.text,
+                    today_border_color: palette.primary.base.color,
+                }
+            }
+            Calendar::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a card.
+#[derive(Default)]
+pub enum Card {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn card::StyleSheet<Style = Theme>>),
+}
+
+impl card::StyleSheet for Theme {
+    type Style = Card;
+
+    fn active(&self, style: &Self::Style) -> card::Appearance {
+        match style {
+            Card::Default => {
+                let palette = self.extended_palette();
+
+                card::Appearance {
+                    background: palette.background.base.color.into(),
+                    header_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    footer_background: None,
+                    text_color: None,
+                    border_radius: 6.0,
+                    border_width: 1.0,
+                    border_color: palette.background.weak.color,
+                    divider_color: palette.background.weak.color,
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 4.0,
+                        spread_radius: 0.0,
+                    },
+                }
+            }
+            Card::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> card::Appearance {
+        match style {
+            Card::Default => {
+                let active = self.active(style);
+
+                card::Appearance {
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                        offset: Vector::new(0.0, 4.0),
+                        blur_radius: 12.0,
+                        spread_radius: 0.0,
+                    },
+                    ..active
+                }
+            }
+            Card::Custom(custom) => custom.hovered(self),
+        }
+    }
+}
+
 /// The style of a checkbox.
 #[derive(Default)]
 pub enum Checkbox {
@@ -339,6 +495,115 @@ fn checkbox_appearance(
     }
 }
 
+/// The style of a chip.
+#[derive(Default)]
+pub enum Chip {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn chip::StyleSheet<Style = Theme>>),
+}
+
+impl chip::StyleSheet for Theme {
+    type Style = Chip;
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> chip::Appearance {
+        match style {
+            Chip::Default => {
+                let palette = self.extended_palette();
+
+                if is_selected {
+                    chip::Appearance {
+                        background: Some(palette.primary.weak.color.into()),
+                        text_color: palette.primary.weak.text,
+                        border_width: 1.0,
+                        border_color: palette.primary.strong.color,
+                        delete_color: palette.primary.weak.text,
+                    }
+                } else {
+                    chip::Appearance {
+                        background: Some(palette.background.weak.color.into()),
+                        text_color: palette.background.weak.text,
+                        border_width: 1.0,
+                        border_color: palette.background.strong.color,
+                        delete_color: palette.background.strong.text,
+                    }
+                }
+            }
+            Chip::Custom(custom) => custom.active(self, is_selected),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> chip::Appearance {
+        match style {
+            Chip::Default => {
+                let palette = self.extended_palette();
+
+                chip::Appearance {
+                    border_color: palette.primary.base.color,
+                    ..self.active(style, is_selected)
+                }
+            }
+            Chip::Custom(custom) => custom.hovered(self, is_selected),
+        }
+    }
+}
+
+/// The style of a code editor.
+#[derive(Default)]
+pub enum CodeEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn code_editor::StyleSheet<Style = Theme>>),
+}
+
+impl code_editor::StyleSheet for Theme {
+    type Style = CodeEditor;
+
+    fn active(&self, style: &Self::Style) -> code_editor::Appearance {
+        if let CodeEditor::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        code_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+            gutter_background: palette.background.weak.color.into(),
+            line_number_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            selection_color: palette.primary.weak.color,
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> code_editor::Appearance {
+        if let CodeEditor::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        code_editor::Appearance {
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
+
 /// The style of a container.
 #[derive(Default)]
 pub enum Container {
@@ -372,6 +637,7 @@ impl container::StyleSheet for Theme {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 }
             }
             Container::Custom(custom) => custom.appearance(self),
@@ -468,6 +734,196 @@ impl slider::StyleSheet for Theme {
     }
 }
 
+/// The style of a color picker.
+#[derive(Default)]
+pub enum ColorPicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn color_picker::StyleSheet<Style = Theme>>),
+}
+
+impl color_picker::StyleSheet for Theme {
+    type Style = ColorPicker;
+
+    fn appearance(&self, style: &Self::Style) -> color_picker::Appearance {
+        match style {
+            ColorPicker::Default => {
+                let palette = self.extended_palette();
+
+                color_picker::Appearance {
+                    background: palette.background.weak.color.into(),
+                    border_width: 1.0,
+                    border_radius: 2.0,
+                    border_color: palette.background.strong.color,
+                    handle_color: Color::WHITE,
+                    swatch_border_color: palette.background.strong.color,
+                    checkerboard_colors: (
+                        Color::from_rgb(0.8, 0.8, 0.8),
+                        Color::WHITE,
+                    ),
+                    icon_color: palette.background.weak.text,
+                }
+            }
+            ColorPicker::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of the calendar of a date picker.
+#[derive(Default)]
+pub enum DatePicker {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn date_picker::StyleSheet<Style = Theme>>),
+}
+
+impl date_picker::StyleSheet for Theme {
+    type Style = DatePicker;
+
+    fn appearance(&self, style: &Self::Style) -> date_picker::Appearance {
+        match style {
+            DatePicker::Default => {
+                let palette = self.extended_palette();
+
+                date_picker::Appearance {
+                    text_color: palette.background.weak.text,
+                    background: palette.background.weak.color.into(),
+                    border_width: 1.0,
+                    border_radius: 2.0,
+                    border_color: palette.background.strong.color,
+                    weekday_color: palette.background.strong.color,
+                    disabled_text_color: Color {
+                        a: 0.3,
+                        ..palette.background.weak.text
+                    },
+                    hovered_background: palette.background.strong.color.into(),
+                    selected_background: palette.primary.strong.color.into(),
+                    selected_text_color: palette.primary.strong.text,
+                    today_border_color: palette.primary.base.color,
+                }
+            }
+            DatePicker::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a drop zone.
+#[derive(Default)]
+pub enum DropZone {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn drop_zone::StyleSheet<Style = Theme>>),
+}
+
+impl drop_zone::StyleSheet for Theme {
+    type Style = DropZone;
+
+    fn active(&self, style: &Self::Style) -> drop_zone::Appearance {
+        if let DropZone::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        drop_zone::Appearance {
+            background: None,
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: palette.primary.weak.color,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> drop_zone::Appearance {
+        if let DropZone::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        drop_zone::Appearance {
+            background: Some(
+                Color {
+                    a: 0.2,
+                    ..palette.primary.weak.color
+                }
+                .into(),
+            ),
+            border_width: 2.0,
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
+
+/// The style of a knob.
+#[derive(Default)]
+pub enum Knob {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn knob::StyleSheet<Style = Theme>>),
+}
+
+impl knob::StyleSheet for Theme {
+    type Style = Knob;
+
+    fn active(&self, style: &Self::Style) -> knob::Appearance {
+        match style {
+            Knob::Default => {
+                let palette = self.extended_palette();
+
+                knob::Appearance {
+                    background: palette.background.weak.color.into(),
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    indicator_color: palette.primary.strong.color,
+                    tick_color: palette.background.strong.color,
+                    filled_tick_color: palette.primary.base.color,
+                }
+            }
+            Knob::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> knob::Appearance {
+        match style {
+            Knob::Default => {
+                let active = self.active(style);
+                let palette = self.extended_palette();
+
+                knob::Appearance {
+                    border_color: palette.primary.weak.color,
+                    ..active
+                }
+            }
+            Knob::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> knob::Appearance {
+        match style {
+            Knob::Default => {
+                let active = self.active(style);
+                let palette = self.extended_palette();
+
+                knob::Appearance {
+                    border_color: palette.primary.base.color,
+                    indicator_color: palette.primary.base.color,
+                    ..active
+                }
+            }
+            Knob::Custom(custom) => custom.dragging(self),
+        }
+    }
+}
+
 /// The style of a menu.
 #[derive(Clone, Default)]
 pub enum Menu {
@@ -494,108 +950,544 @@ impl menu::StyleSheet for Theme {
                     border_color: palette.background.strong.color,
                     selected_text_color: palette.primary.strong.text,
                     selected_background: palette.primary.strong.color.into(),
+                    highlighted_text_color: palette.primary.base.color,
+                    selected_highlighted_text_color: palette.primary.weak.color,
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 6.0,
+                        spread_radius: 0.0,
+                    },
+                }
+            }
+            Menu::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a menu bar.
+#[derive(Default)]
+pub enum MenuBar {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn menu_bar::StyleSheet<Style = Theme>>),
+}
+
+impl menu_bar::StyleSheet for Theme {
+    type Style = MenuBar;
+
+    fn appearance(&self, style: &Self::Style) -> menu_bar::Appearance {
+        match style {
+            MenuBar::Default => {
+                let palette = self.extended_palette();
+
+                menu_bar::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    text_color: palette.background.weak.text,
+                    disabled_text_color: palette.background.strong.color,
+                    selected_background: palette.primary.strong.color.into(),
+                    selected_text_color: palette.primary.strong.text,
+                    menu_background: palette.background.base.color.into(),
+                    menu_border_width: 1.0,
+                    menu_border_radius: 2.0,
+                    menu_border_color: palette.background.strong.color,
+                    separator_color: palette.background.strong.color,
+                }
+            }
+            MenuBar::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl From<PickList> for Menu {
+    fn from(pick_list: PickList) -> Self {
+        match pick_list {
+            PickList::Default => Self::Default,
+            PickList::Custom(_, menu) => Self::Custom(menu),
+        }
+    }
+}
+
+/// The style of a pagination control.
+#[derive(Default)]
+pub enum Pagination {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn pagination::StyleSheet<Style = Theme>>),
+}
+
+impl pagination::StyleSheet for Theme {
+    type Style = Pagination;
+
+    fn active(&self, style: &Self::Style) -> pagination::Appearance {
+        match style {
+            Pagination::Default => {
+                let palette = self.extended_palette();
+
+                pagination::Appearance {
+                    background: None,
+                    text_color: palette.background.base.text,
+                    border_radius: 4.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                }
+            }
+            Pagination::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> pagination::Appearance {
+        match style {
+            Pagination::Default => {
+                let palette = self.extended_palette();
+
+                pagination::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    text_color: palette.background.weak.text,
+                    ..self.active(style)
+                }
+            }
+            Pagination::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn selected(&self, style: &Self::Style) -> pagination::Appearance {
+        match style {
+            Pagination::Default => {
+                let palette = self.extended_palette();
+
+                pagination::Appearance {
+                    background: Some(palette.primary.strong.color.into()),
+                    text_color: palette.primary.strong.text,
+                    ..self.active(style)
+                }
+            }
+            Pagination::Custom(custom) => custom.selected(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> pagination::Appearance {
+        match style {
+            Pagination::Default => {
+                let palette = self.extended_palette();
+
+                pagination::Appearance {
+                    text_color: palette.background.strong.color,
+                    ..self.active(style)
+                }
+            }
+            Pagination::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
+
+/// The style of a pick list.
+#[derive(Clone, Default)]
+pub enum PickList {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(
+        Rc<dyn pick_list::StyleSheet<Style = Theme>>,
+        Rc<dyn menu::StyleSheet<Style = Theme>>,
+    ),
+}
+
+impl pick_list::StyleSheet for Theme {
+    type Style = PickList;
+
+    fn active(&self, style: &Self::Style) -> pick_list::Appearance {
+        match style {
+            PickList::Default => {
+                let palette = self.extended_palette();
+
+                pick_list::Appearance {
+                    text_color: palette.background.weak.text,
+                    background: palette.background.weak.color.into(),
+                    placeholder_color: palette.background.strong.color,
+                    handle_color: palette.background.weak.text,
+                    border_radius: 2.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                }
+            }
+            PickList::Custom(custom, _) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> pick_list::Appearance {
+        match style {
+            PickList::Default => {
+                let palette = self.extended_palette();
+
+                pick_list::Appearance {
+                    text_color: palette.background.weak.text,
+                    background: palette.background.weak.color.into(),
+                    placeholder_color: palette.background.strong.color,
+                    handle_color: palette.background.weak.text,
+                    border_radius: 2.0,
+                    border_width: 1.0,
+                    border_color: palette.primary.strong.color,
+                }
+            }
+            PickList::Custom(custom, _) => custom.hovered(self),
+        }
+    }
+}
+
+/// The style of a radio button.
+#[derive(Default)]
+pub enum Radio {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn radio::StyleSheet<Style = Theme>>),
+}
+
+impl radio::StyleSheet for Theme {
+    type Style = Radio;
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> radio::Appearance {
+        match style {
+            Radio::Default => {
+                let palette = self.extended_palette();
+
+                radio::Appearance {
+                    background: Color::TRANSPARENT.into(),
+                    dot_color: palette.primary.strong.color,
+                    border_width: 1.0,
+                    border_color: palette.primary.strong.color,
+                    text_color: None,
+                }
+            }
+            Radio::Custom(custom) => custom.active(self, is_selected),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> radio::Appearance {
+        match style {
+            Radio::Default => {
+                let active = self.active(style, is_selected);
+                let palette = self.extended_palette();
+
+                radio::Appearance {
+                    dot_color: palette.primary.strong.color,
+                    background: palette.primary.weak.color.into(),
+                    ..active
+                }
+            }
+            Radio::Custom(custom) => custom.hovered(self, is_selected),
+        }
+    }
+}
+
+/// The style of a rating.
+#[derive(Default)]
+pub enum Rating {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn rating::StyleSheet<Style = Theme>>),
+}
+
+impl rating::StyleSheet for Theme {
+    type Style = Rating;
+
+    fn appearance(&self, style: &Self::Style) -> rating::Appearance {
+        match style {
+            Rating::Default => {
+                let palette = self.extended_palette();
+
+                rating::Appearance {
+                    filled_color: palette.primary.strong.color,
+                    empty_color: palette.background.strong.color,
+                    preview_color: palette.primary.weak.color,
+                    focused_border_color: palette.primary.strong.color,
+                }
+            }
+            Rating::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a segmented button.
+#[derive(Default)]
+pub enum SegmentedButton {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn segmented_button::StyleSheet<Style = Theme>>),
+}
+
+impl segmented_button::StyleSheet for Theme {
+    type Style = SegmentedButton;
+
+    fn appearance(&self, style: &Self::Style) -> segmented_button::Appearance {
+        match style {
+            SegmentedButton::Default => {
+                let palette = self.extended_palette();
+
+                segmented_button::Appearance {
+                    background: palette.background.base.color.into(),
+                    border_radius: 6.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    divider_color: palette.background.strong.color,
+                    hovered_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    indicator: palette.primary.strong.color.into(),
+                    text_color: palette.background.base.text,
+                    selected_text_color: palette.primary.strong.text,
+                }
+            }
+            SegmentedButton::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a skeleton.
+#[derive(Default)]
+pub enum Skeleton {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn skeleton::StyleSheet<Style = Theme>>),
+}
+
+impl skeleton::StyleSheet for Theme {
+    type Style = Skeleton;
+
+    fn appearance(&self, style: &Self::Style) -> skeleton::Appearance {
+        match style {
+            Skeleton::Default => {
+                let palette = self.extended_palette();
+
+                skeleton::Appearance {
+                    color: palette.background.weak.color,
+                    highlight: palette.background.base.color,
+                    border_radius: 4.0,
+                }
+            }
+            Skeleton::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a spinner.
+#[derive(Default)]
+pub enum Spinner {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn spinner::StyleSheet<Style = Theme>>),
+}
+
+impl spinner::StyleSheet for Theme {
+    type Style = Spinner;
+
+    fn appearance(&self, style: &Self::Style) -> spinner::Appearance {
+        match style {
+            Spinner::Default => {
+                let palette = self.extended_palette();
+
+                spinner::Appearance {
+                    color: palette.primary.base.color,
+                    track_color: palette.background.strong.color,
+                }
+            }
+            Spinner::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a split.
+#[derive(Default)]
+pub enum Split {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn split::StyleSheet<Style = Theme>>),
+}
+
+impl split::StyleSheet for Theme {
+    type Style = Split;
+
+    fn active(&self, style: &Self::Style) -> split::Appearance {
+        match style {
+            Split::Default => {
+                let palette = self.extended_palette();
+
+                split::Appearance {
+                    divider_color: palette.background.strong.color,
+                    divider_width: 1.0,
+                }
+            }
+            Split::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> split::Appearance {
+        match style {
+            Split::Default => {
+                let palette = self.extended_palette();
+
+                split::Appearance {
+                    divider_color: palette.primary.base.color,
+                    divider_width: 2.0,
                 }
             }
-            Menu::Custom(custom) => custom.appearance(self),
+            Split::Custom(custom) => custom.hovered(self),
         }
     }
-}
 
-impl From<PickList> for Menu {
-    fn from(pick_list: PickList) -> Self {
-        match pick_list {
-            PickList::Default => Self::Default,
-            PickList::Custom(_, menu) => Self::Custom(menu),
+    fn dragging(&self, style: &Self::Style) -> split::Appearance {
+        match style {
+            Split::Default => {
+                let palette = self.extended_palette();
+
+                split::Appearance {
+                    divider_color: palette.primary.strong.color,
+                    divider_width: 2.0,
+                }
+            }
+            Split::Custom(custom) => custom.dragging(self),
         }
     }
 }
 
-/// The style of a pick list.
-#[derive(Clone, Default)]
-pub enum PickList {
+/// The style of a stepper.
+#[derive(Default)]
+pub enum Stepper {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(
-        Rc<dyn pick_list::StyleSheet<Style = Theme>>,
-        Rc<dyn menu::StyleSheet<Style = Theme>>,
-    ),
+    Custom(Box<dyn stepper::StyleSheet<Style = Theme>>),
 }
 
-impl pick_list::StyleSheet for Theme {
-    type Style = PickList;
+impl stepper::StyleSheet for Theme {
+    type Style = Stepper;
 
-    fn active(&self, style: &Self::Style) -> pick_list::Appearance {
+    fn active(&self, style: &Self::Style) -> stepper::Appearance {
         match style {
-            PickList::Default => {
+            Stepper::Default => {
                 let palette = self.extended_palette();
 
-                pick_list::Appearance {
-                    text_color: palette.background.weak.text,
-                    background: palette.background.weak.color.into(),
-                    placeholder_color: palette.background.strong.color,
-                    handle_color: palette.background.weak.text,
+                stepper::Appearance {
+                    background: palette.background.base.color.into(),
                     border_radius: 2.0,
                     border_width: 1.0,
                     border_color: palette.background.strong.color,
+                    value_color: palette.background.base.text,
+                    button_background: None,
+                    button_color: palette.primary.strong.color,
+                    disabled_button_color: palette.background.strong.color,
                 }
             }
-            PickList::Custom(custom, _) => custom.active(self),
+            Stepper::Custom(custom) => custom.active(self),
         }
     }
 
-    fn hovered(&self, style: &Self::Style) -> pick_list::Appearance {
+    fn hovered(&self, style: &Self::Style) -> stepper::Appearance {
         match style {
-            PickList::Default => {
+            Stepper::Default => {
                 let palette = self.extended_palette();
 
-                pick_list::Appearance {
-                    text_color: palette.background.weak.text,
-                    background: palette.background.weak.color.into(),
-                    placeholder_color: palette.background.strong.color,
-                    handle_color: palette.background.weak.text,
-                    border_radius: 2.0,
-                    border_width: 1.0,
-                    border_color: palette.primary.strong.color,
+                stepper::Appearance {
+                    button_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    ..self.active(style)
                 }
             }
-            PickList::Custom(custom, _) => custom.hovered(self),
+            Stepper::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> stepper::Appearance {
+        match style {
+            Stepper::Default => {
+                let palette = self.extended_palette();
+
+                stepper::Appearance {
+                    button_background: Some(
+                        palette.background.strong.color.into(),
+                    ),
+                    button_color: palette.background.strong.text,
+                    ..self.active(style)
+                }
+            }
+            Stepper::Custom(custom) => custom.pressed(self),
         }
     }
 }
 
-/// The style of a radio button.
+/// The style of a tab bar.
 #[derive(Default)]
-pub enum Radio {
+pub enum Tabs {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn radio::StyleSheet<Style = Theme>>),
+    Custom(Box<dyn tabs::StyleSheet<Style = Theme>>),
 }
 
-impl radio::StyleSheet for Theme {
-    type Style = Radio;
+impl tabs::StyleSheet for Theme {
+    type Style = Tabs;
+
+    fn bar(&self, style: &Self::Style) -> Option<Background> {
+        match style {
+            Tabs::Default => {
+                Some(self.extended_palette().background.weak.color.into())
+            }
+            Tabs::Custom(custom) => custom.bar(self),
+        }
+    }
 
     fn active(
         &self,
         style: &Self::Style,
         is_selected: bool,
-    ) -> radio::Appearance {
+    ) -> tabs::Appearance {
         match style {
-            Radio::Default => {
+            Tabs::Default => {
                 let palette = self.extended_palette();
 
-                radio::Appearance {
-                    background: Color::TRANSPARENT.into(),
-                    dot_color: palette.primary.strong.color,
-                    border_width: 1.0,
-                    border_color: palette.primary.strong.color,
-                    text_color: None,
+                tabs::Appearance {
+                    background: is_selected
+                        .then(|| palette.background.base.color.into()),
+                    text_color: if is_selected {
+                        palette.background.base.text
+                    } else {
+                        palette.background.weak.text
+                    },
+                    close_color: palette.background.strong.color,
+                    border_radius: 2.0,
+                    indicator_color: is_selected
+                        .then_some(palette.primary.strong.color),
                 }
             }
-            Radio::Custom(custom) => custom.active(self, is_selected),
+            Tabs::Custom(custom) => custom.active(self, is_selected),
         }
     }
 
@@ -603,19 +1495,22 @@ impl radio::StyleSheet for Theme {
         &self,
         style: &Self::Style,
         is_selected: bool,
-    ) -> radio::Appearance {
+    ) -> tabs::Appearance {
         match style {
-            Radio::Default => {
-                let active = self.active(style, is_selected);
+            Tabs::Default => {
                 let palette = self.extended_palette();
 
-                radio::Appearance {
-                    dot_color: palette.primary.strong.color,
-                    background: palette.primary.weak.color.into(),
-                    ..active
+                tabs::Appearance {
+                    background: Some(if is_selected {
+                        palette.background.base.color.into()
+                    } else {
+                        palette.background.strong.color.into()
+                    }),
+                    close_color: palette.background.base.text,
+                    ..self.active(style, is_selected)
                 }
             }
-            Radio::Custom(custom) => custom.hovered(self, is_selected),
+            Tabs::Custom(custom) => custom.hovered(self, is_selected),
         }
     }
 }
@@ -685,6 +1580,89 @@ impl toggler::StyleSheet for Theme {
             Toggler::Custom(custom) => custom.hovered(self, is_active),
         }
     }
+
+    fn transition(&self, style: &Self::Style) -> Duration {
+        match style {
+            Toggler::Default => Duration::from_millis(150),
+            Toggler::Custom(custom) => custom.transition(self),
+        }
+    }
+}
+
+/// The style of a toolbar.
+#[derive(Default)]
+pub enum Toolbar {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn toolbar::StyleSheet<Style = Theme>>),
+}
+
+impl toolbar::StyleSheet for Theme {
+    type Style = Toolbar;
+
+    fn appearance(&self, style: &Self::Style) -> toolbar::Appearance {
+        match style {
+            Toolbar::Default => {
+                let palette = self.extended_palette();
+
+                toolbar::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    border_radius: 2.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    overflow_color: palette.background.weak.text,
+                    overflow_hovered_background: palette
+                        .background
+                        .strong
+                        .color
+                        .into(),
+                    menu_background: palette.background.base.color.into(),
+                    menu_border_radius: 2.0,
+                    menu_border_width: 1.0,
+                    menu_border_color: palette.background.strong.color,
+                }
+            }
+            Toolbar::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The style of a tree view.
+#[derive(Default)]
+pub enum TreeView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tree_view::StyleSheet<Style = Theme>>),
+}
+
+impl tree_view::StyleSheet for Theme {
+    type Style = TreeView;
+
+    fn appearance(&self, style: &Self::Style) -> tree_view::Appearance {
+        match style {
+            TreeView::Default => {
+                let palette = self.extended_palette();
+
+                tree_view::Appearance {
+                    text_color: palette.background.base.text,
+                    arrow_color: palette.background.strong.text,
+                    guide_color: palette.background.weak.color,
+                    placeholder_color: palette.background.strong.color,
+                    hovered_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    selected_background: palette.primary.weak.color.into(),
+                    selected_text_color: palette.primary.weak.text,
+                    focused_border_color: palette.primary.strong.color,
+                }
+            }
+            TreeView::Custom(custom) => custom.appearance(self),
+        }
+    }
 }
 
 /// The style of a pane grid.
@@ -700,6 +1678,25 @@ pub enum PaneGrid {
 impl pane_grid::StyleSheet for Theme {
     type Style = PaneGrid;
 
+    fn hovered_region(&self, style: &Self::Style) -> pane_grid::Appearance {
+        match style {
+            PaneGrid::Default => {
+                let palette = self.extended_palette();
+
+                pane_grid::Appearance {
+                    background: Background::Color(Color {
+                        a: 0.5,
+                        ..palette.primary.base.color
+                    }),
+                    border_width: 2.0,
+                    border_color: palette.primary.strong.color,
+                    border_radius: 0.0,
+                }
+            }
+            PaneGrid::Custom(custom) => custom.hovered_region(self),
+        }
+    }
+
     fn picked_split(&self, style: &Self::Style) -> Option<pane_grid::Line> {
         match style {
             PaneGrid::Default => {
@@ -727,6 +1724,16 @@ impl pane_grid::StyleSheet for Theme {
             PaneGrid::Custom(custom) => custom.hovered_split(self),
         }
     }
+
+    fn maximize_transition(
+        &self,
+        style: &Self::Style,
+    ) -> pane_grid::Transition {
+        match style {
+            PaneGrid::Default => pane_grid::Transition::default(),
+            PaneGrid::Custom(custom) => custom.maximize_transition(self),
+        }
+    }
 }
 
 /// The style of a progress bar.
@@ -782,6 +1789,35 @@ impl progress_bar::StyleSheet for fn(&Theme) -> progress_bar::Appearance {
     }
 }
 
+/// The style of a reorderable list.
+#[derive(Default)]
+pub enum Reorderable {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn reorderable::StyleSheet<Style = Theme>>),
+}
+
+impl reorderable::StyleSheet for Theme {
+    type Style = Reorderable;
+
+    fn appearance(&self, style: &Self::Style) -> reorderable::Appearance {
+        if let Reorderable::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        reorderable::Appearance {
+            indicator_color: palette.primary.strong.color,
+            indicator_width: 2.0,
+            dragged_background: Some(palette.background.weak.color.into()),
+            dragged_border_radius: 4.0,
+        }
+    }
+}
+
 /// The style of a rule.
 #[derive(Default)]
 pub enum Rule {
@@ -832,6 +1868,12 @@ pub enum Svg {
     /// No filtering to the rendered SVG.
     #[default]
     Default,
+    /// Colors a monochrome icon with the text color of the theme.
+    Symbolic,
+    /// Replaces the `--background`, `--text`, `--primary`, `--success` and
+    /// `--danger` CSS variables of the SVG with the colors of the palette of
+    /// the theme.
+    Palette,
     /// A custom style.
     Custom(Box<dyn svg::StyleSheet<Style = Theme>>),
 }
@@ -849,9 +1891,31 @@ impl svg::StyleSheet for Theme {
     fn appearance(&self, style: &Self::Style) -> svg::Appearance {
         match style {
             Svg::Default => Default::default(),
+            Svg::Symbolic => svg::Appearance {
+                color: Some(self.palette().text),
+            },
+            Svg::Palette => svg::Appearance { color: None },
             Svg::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn variables(&self, style: &Self::Style) -> Vec<(String, Color)> {
+        match style {
+            Svg::Default | Svg::Symbolic => Vec::new(),
+            Svg::Palette => {
+                let palette = self.palette();
+
+                vec![
+                    (String::from("background"), palette.background),
+                    (String::from("text"), palette.text),
+                    (String::from("primary"), palette.primary),
+                    (String::from("success"), palette.success),
+                    (String::from("danger"), palette.danger),
+                ]
+            }
+            Svg::Custom(custom) => custom.variables(self),
+        }
+    }
 }
 
 impl svg::StyleSheet for fn(&Theme) -> svg::Appearance {
@@ -987,6 +2051,104 @@ impl text::StyleSheet for Theme {
     }
 }
 
+/// The style of some selectable text.
+#[derive(Default)]
+pub enum SelectableText {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn selectable_text::StyleSheet<Style = Theme>>),
+}
+
+impl selectable_text::StyleSheet for Theme {
+    type Style = SelectableText;
+
+    fn appearance(&self, style: &Self::Style) -> selectable_text::Appearance {
+        if let SelectableText::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        selectable_text::Appearance {
+            color: None,
+            selection_color: palette.primary.weak.color,
+        }
+    }
+}
+
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        if let Table::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        table::Appearance {
+            header_background: Some(palette.background.weak.color.into()),
+            divider_color: palette.background.strong.color,
+            active_divider_color: palette.primary.strong.color,
+            divider_width: 1.0,
+        }
+    }
+}
+
+/// The style of a data grid.
+#[derive(Default)]
+pub enum DataGrid {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn data_grid::StyleSheet<Style = Theme>>),
+}
+
+impl data_grid::StyleSheet for Theme {
+    type Style = DataGrid;
+
+    fn appearance(&self, style: &Self::Style) -> data_grid::Appearance {
+        if let DataGrid::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        data_grid::Appearance {
+            background: Some(palette.background.base.color.into()),
+            header_background: Some(palette.background.weak.color.into()),
+            header_text_color: palette.background.weak.text,
+            text_color: palette.background.base.text,
+            hovered_background: Some(
+                Color {
+                    a: 0.5,
+                    ..palette.background.weak.color
+                }
+                .into(),
+            ),
+            selected_background: palette.primary.weak.color.into(),
+            selected_text_color: palette.primary.weak.text,
+            divider_color: palette.background.weak.color,
+            divider_width: 1.0,
+            frozen_divider_color: palette.background.strong.color,
+            scrollbar_color: palette.background.strong.color,
+        }
+    }
+}
+
 /// The style of a text input.
 #[derive(Default)]
 pub enum TextInput {
@@ -1045,6 +2207,39 @@ impl text_input::StyleSheet for Theme {
         }
     }
 
+    fn errored(
+        &self,
+        style: &Self::Style,
+        is_focused: bool,
+    ) -> text_input::Appearance {
+        if let TextInput::Custom(custom) = style {
+            return custom.errored(self, is_focused);
+        }
+
+        let palette = self.extended_palette();
+
+        text_input::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: if is_focused {
+                palette.danger.strong.color
+            } else {
+                palette.danger.base.color
+            },
+        }
+    }
+
+    fn error_color(&self, style: &Self::Style) -> Color {
+        if let TextInput::Custom(custom) = style {
+            return custom.error_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.danger.base.color
+    }
+
     fn placeholder_color(&self, style: &Self::Style) -> Color {
         if let TextInput::Custom(custom) = style {
             return custom.placeholder_color(self);
@@ -1074,4 +2269,20 @@ impl text_input::StyleSheet for Theme {
 
         palette.primary.weak.color
     }
+
+    fn misspelling_color(&self, style: &Self::Style) -> Color {
+        if let TextInput::Custom(custom) = style {
+            return custom.misspelling_color(self);
+        }
+
+        self.error_color(style)
+    }
+
+    fn is_cursor_blinking(&self, style: &Self::Style) -> bool {
+        if let TextInput::Custom(custom) = style {
+            return custom.is_cursor_blinking(self);
+        }
+
+        true
+    }
 }
@@ -1,4 +1,5 @@
 //! Change the appearance of a toggler.
+use iced_core::time::Duration;
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -28,4 +29,15 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// Returns the duration of the transition of the toggler between its
+    /// states for the provided [`Style`].
+    ///
+    /// The thumb slides and the colors are interpolated during the
+    /// transition. A zero duration disables it.
+    ///
+    /// [`Style`]: Self::Style
+    fn transition(&self, _style: &Self::Style) -> Duration {
+        Duration::from_millis(150)
+    }
 }
@@ -0,0 +1,37 @@
+//! Change the appearance of a toolbar.
+use iced_core::{Background, Color};
+
+/// The appearance of a toolbar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the toolbar, if any.
+    pub background: Option<Background>,
+    /// The border radius of the toolbar.
+    pub border_radius: f32,
+    /// The border width of the toolbar.
+    pub border_width: f32,
+    /// The border [`Color`] of the toolbar.
+    pub border_color: Color,
+    /// The [`Color`] of the button opening the overflow menu.
+    pub overflow_color: Color,
+    /// The [`Background`] of the button opening the overflow menu, when it
+    /// is hovered or its menu is open.
+    pub overflow_hovered_background: Background,
+    /// The [`Background`] of the overflow menu.
+    pub menu_background: Background,
+    /// The border radius of the overflow menu.
+    pub menu_border_radius: f32,
+    /// The border width of the overflow menu.
+    pub menu_border_width: f32,
+    /// The border [`Color`] of the overflow menu.
+    pub menu_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a toolbar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a toolbar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
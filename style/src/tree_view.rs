@@ -0,0 +1,32 @@
+//! Change the appearance of a tree view.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the labels of the nodes.
+    pub text_color: Color,
+    /// The [`Color`] of the arrows expanding and collapsing the nodes.
+    pub arrow_color: Color,
+    /// The [`Color`] of the indentation guides.
+    pub guide_color: Color,
+    /// The text [`Color`] displayed while the children of a node load.
+    pub placeholder_color: Color,
+    /// The [`Background`] of the hovered node, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected nodes.
+    pub selected_background: Background,
+    /// The text [`Color`] of the labels of the selected nodes.
+    pub selected_text_color: Color,
+    /// The border [`Color`] of the node with the keyboard focus.
+    pub focused_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a tree view.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
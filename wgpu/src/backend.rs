@@ -1,11 +1,12 @@
+use crate::custom;
 use crate::quad;
 use crate::text;
 use crate::triangle;
-use crate::{Settings, Transformation};
+use crate::{Antialiasing, Settings, Transformation};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::layer::Layer;
+use iced_graphics::layer::{Layer, Text};
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
 use iced_native::{Font, Size};
@@ -25,6 +26,7 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    custom_pipeline: custom::Pipeline,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
@@ -49,14 +51,17 @@ impl Backend {
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
+        let custom_pipeline = custom::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(device, format);
+        let image_pipeline =
+            image::Pipeline::new(device, format, settings.image_budget);
 
         Self {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            custom_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -65,6 +70,17 @@ impl Backend {
         }
     }
 
+    /// Changes the [`Antialiasing`] strategy used to draw triangle primitives.
+    pub fn set_antialiasing(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<Antialiasing>,
+    ) {
+        self.triangle_pipeline =
+            triangle::Pipeline::new(device, format, antialiasing);
+    }
+
     /// Draws the provided primitives in the given `TextureView`.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -105,6 +121,8 @@ impl Backend {
 
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.trim_cache(device, encoder);
+
+        self.custom_pipeline.trim_cache();
     }
 
     fn flush(
@@ -149,6 +167,7 @@ impl Backend {
                 target_size,
                 scaled,
                 scale_factor,
+                layer.blend_mode,
                 &layer.meshes,
             );
         }
@@ -172,69 +191,28 @@ impl Backend {
             }
         }
 
+        if !layer.shaders.is_empty() {
+            self.custom_pipeline.draw(
+                device,
+                encoder,
+                &layer.shaders,
+                transformation,
+                scale_factor,
+                bounds,
+                target,
+            );
+        }
+
         if !layer.text.is_empty() {
-            for text in layer.text.iter() {
-                // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
-                    // TODO: We `round` here to avoid rerasterizing text when
-                    // its position changes slightly. This can make text feel a
-                    // bit "jumpy". We may be able to do better once we improve
-                    // our text rendering/caching pipeline.
-                    screen_position: (
-                        (text.bounds.x * scale_factor).round(),
-                        (text.bounds.y * scale_factor).round(),
-                    ),
-                    // TODO: Fix precision issues with some scale factors.
-                    //
-                    // The `ceil` here can cause some words to render on the
-                    // same line when they should not.
-                    //
-                    // Ideally, `wgpu_glyph` should be able to compute layout
-                    // using logical positions, and then apply the proper
-                    // scaling when rendering. This would ensure that both
-                    // measuring and rendering follow the same layout rules.
-                    bounds: (
-                        (text.bounds.width * scale_factor).ceil(),
-                        (text.bounds.height * scale_factor).ceil(),
-                    ),
-                    text: vec![wgpu_glyph::Text {
-                        text: text.content,
-                        scale: wgpu_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: wgpu_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
-                        })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
-                };
-
-                self.text_pipeline.queue(text);
+            // Rotated text needs its own transformation, so it is drawn after
+            // the rest
+            let (rotated, text): (Vec<_>, Vec<_>) =
+                layer.text.iter().partition(|text| text.rotation != 0.0);
+
+            for text in text {
+                let section = self.text_section(text, scale_factor);
+
+                self.text_pipeline.queue(section);
             }
 
             self.text_pipeline.draw_queued(
@@ -250,6 +228,94 @@ impl Backend {
                     height: bounds.height,
                 },
             );
+
+            for text in rotated {
+                let section = self.text_section(text, scale_factor);
+                let (x, y) = section.screen_position;
+
+                self.text_pipeline.queue(section);
+
+                self.text_pipeline.draw_queued(
+                    device,
+                    staging_belt,
+                    encoder,
+                    target,
+                    transformation
+                        * Transformation::translate(x, y)
+                        * Transformation::rotate(text.rotation)
+                        * Transformation::translate(-x, -y),
+                    wgpu_glyph::Region {
+                        x: bounds.x,
+                        y: bounds.y,
+                        width: bounds.width,
+                        height: bounds.height,
+                    },
+                );
+            }
+        }
+    }
+
+    fn text_section<'a>(
+        &self,
+        text: &Text<'a>,
+        scale_factor: f32,
+    ) -> wgpu_glyph::Section<'a> {
+        // Target physical coordinates directly to avoid blurry text
+        wgpu_glyph::Section {
+            // TODO: We `round` here to avoid rerasterizing text when
+            // its position changes slightly. This can make text feel a
+            // bit "jumpy". We may be able to do better once we improve
+            // our text rendering/caching pipeline.
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            // TODO: Fix precision issues with some scale factors.
+            //
+            // The `ceil` here can cause some words to render on the
+            // same line when they should not.
+            //
+            // Ideally, `wgpu_glyph` should be able to compute layout
+            // using logical positions, and then apply the proper
+            // scaling when rendering. This would ensure that both
+            // measuring and rendering follow the same layout rules.
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![wgpu_glyph::Text {
+                text: text.content,
+                scale: wgpu_glyph::ab_glyph::PxScale {
+                    x: text.size * scale_factor,
+                    y: text.size * scale_factor,
+                },
+                font_id: self.text_pipeline.find_font(text.font),
+                extra: wgpu_glyph::Extra {
+                    color: text.color,
+                    z: 0.0,
+                },
+            }],
+            layout: wgpu_glyph::Layout::default()
+                .h_align(match text.horizontal_alignment {
+                    alignment::Horizontal::Left => {
+                        wgpu_glyph::HorizontalAlign::Left
+                    }
+                    alignment::Horizontal::Center => {
+                        wgpu_glyph::HorizontalAlign::Center
+                    }
+                    alignment::Horizontal::Right => {
+                        wgpu_glyph::HorizontalAlign::Right
+                    }
+                })
+                .v_align(match text.vertical_alignment {
+                    alignment::Vertical::Top => wgpu_glyph::VerticalAlign::Top,
+                    alignment::Vertical::Center => {
+                        wgpu_glyph::VerticalAlign::Center
+                    }
+                    alignment::Vertical::Bottom => {
+                        wgpu_glyph::VerticalAlign::Bottom
+                    }
+                }),
         }
     }
 }
@@ -279,6 +345,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn ascent(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.ascent(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
@@ -304,8 +374,29 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Vec<iced_native::time::Duration> {
+        self.image_pipeline.frame_delays(handle)
+    }
+
+    fn preload(&mut self, handle: &iced_native::image::Handle) {
+        self.image_pipeline.preload(handle);
+    }
+
+    fn evict(&mut self, handle: &iced_native::image::Handle) {
+        self.image_pipeline.evict(handle);
+    }
+
+    fn memory_usage(&self) -> iced_native::image::Usage {
+        self.image_pipeline.memory_usage()
+    }
 }
 
+impl backend::Shader for Backend {}
+
 #[cfg(feature = "svg")]
 impl backend::Svg for Backend {
     fn viewport_dimensions(
@@ -0,0 +1,264 @@
+use crate::Transformation;
+use iced_graphics::layer;
+use iced_native::Rectangle;
+
+use bytemuck::{Pod, Zeroable};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use wgpu::util::DeviceExt;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+/// The declarations shared by every custom shader.
+const PRELUDE: &str = include_str!("shader/custom.wgsl");
+
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    layout: wgpu::PipelineLayout,
+    constant_layout: wgpu::BindGroupLayout,
+    pipelines: HashMap<u64, Option<wgpu::RenderPipeline>>,
+    hits: HashSet<u64>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::custom uniforms layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Globals>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::custom pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout],
+            });
+
+        Pipeline {
+            format,
+            layout,
+            constant_layout,
+            pipelines: HashMap::new(),
+            hits: HashSet::new(),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        shaders: &[layer::Shader],
+        transformation: Transformation,
+        scale_factor: f32,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Custom", "DRAW").entered();
+
+        for shader in shaders {
+            let id = shader.handle.id();
+            let _ = self.hits.insert(id);
+
+            let (format, layout) = (self.format, &self.layout);
+
+            let pipeline = self.pipelines.entry(id).or_insert_with(|| {
+                create_pipeline(device, format, layout, shader.handle.source())
+            });
+
+            let pipeline = match pipeline {
+                Some(pipeline) => pipeline,
+                None => continue,
+            };
+
+            let shader_bounds = shader.bounds * scale_factor;
+
+            let globals = Globals {
+                transform: transformation.into(),
+                bounds: [
+                    shader_bounds.x,
+                    shader_bounds.y,
+                    shader_bounds.width,
+                    shader_bounds.height,
+                ],
+                scale: scale_factor,
+                _padding: [0.0; 3],
+            };
+
+            let globals =
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("iced_wgpu::custom globals buffer"),
+                    contents: bytemuck::bytes_of(&globals),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+
+            // Uniform buffers are sized in multiples of 16 bytes
+            let mut uniforms = shader.uniforms.clone();
+
+            if uniforms.is_empty() || uniforms.len() % 4 != 0 {
+                uniforms.resize(uniforms.len() + 4 - uniforms.len() % 4, 0.0);
+            }
+
+            let uniforms =
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("iced_wgpu::custom uniforms buffer"),
+                    contents: bytemuck::cast_slice(&uniforms),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+
+            let constants =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu::custom uniforms bind group"),
+                    layout: &self.constant_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: globals.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: uniforms.as_entire_binding(),
+                        },
+                    ],
+                });
+
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::custom render pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        },
+                    )],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, &constants, &[]);
+            render_pass.set_scissor_rect(
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+            );
+
+            render_pass.draw(0..4, 0..1);
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        let hits = &self.hits;
+
+        self.pipelines.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+}
+
+/// Creates the render pipeline of a custom shader, logging any errors found
+/// in its source.
+fn create_pipeline(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    layout: &wgpu::PipelineLayout,
+    source: &str,
+) -> Option<wgpu::RenderPipeline> {
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("iced_wgpu::custom::shader"),
+        source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Owned(format!(
+            "{PRELUDE}\n{source}"
+        ))),
+    });
+
+    let pipeline =
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu::custom pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+        });
+
+    match futures::executor::block_on(device.pop_error_scope()) {
+        Some(error) => {
+            log::error!("Invalid custom shader: {error}");
+
+            None
+        }
+        None => Some(pipeline),
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Globals {
+    transform: [f32; 16],
+    bounds: [f32; 4],
+    scale: f32,
+    _padding: [f32; 3],
+}
@@ -16,9 +16,10 @@ use std::cell::RefCell;
 use std::mem;
 
 use bytemuck::{Pod, Zeroable};
+use iced_native::image;
 
 #[cfg(feature = "image")]
-use iced_native::image;
+use iced_native::time::Duration;
 
 #[cfg(feature = "svg")]
 use iced_native::svg;
@@ -43,10 +44,25 @@ pub struct Pipeline {
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
     texture_atlas: Atlas,
+    budget: image::Budget,
+    #[cfg(feature = "image")]
+    requests: Vec<Request>,
+}
+
+/// A request to change the cache of raster images before the next frame.
+#[cfg(feature = "image")]
+#[derive(Debug)]
+enum Request {
+    Preload(image::Handle),
+    Evict(image::Handle),
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        budget: image::Budget,
+    ) -> Self {
         use wgpu::util::DeviceExt;
 
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -173,6 +189,11 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32x2,
+                                7 => Float32,
+                                8 => Float32,
+                                9 => Float32x4,
+                                10 => Float32x4,
                             ),
                         },
                     ],
@@ -259,9 +280,12 @@ impl Pipeline {
             instances,
             constants: constant_bind_group,
             texture,
-            texture_version: texture_atlas.layer_count(),
+            texture_version: texture_atlas.version(),
             texture_layout,
             texture_atlas,
+            budget,
+            #[cfg(feature = "image")]
+            requests: Vec::new(),
         }
     }
 
@@ -273,6 +297,38 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        self.raster_cache.borrow_mut().frame_delays(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn preload(&mut self, handle: &image::Handle) {
+        self.requests.push(Request::Preload(handle.clone()));
+    }
+
+    #[cfg(feature = "image")]
+    pub fn evict(&mut self, handle: &image::Handle) {
+        self.requests.push(Request::Evict(handle.clone()));
+    }
+
+    #[cfg(feature = "image")]
+    pub fn memory_usage(&self) -> image::Usage {
+        let raster = self.raster_cache.borrow().usage();
+
+        #[cfg(feature = "svg")]
+        let vector = self.vector_cache.borrow().usage();
+        #[cfg(not(feature = "svg"))]
+        let vector = image::Usage::default();
+
+        image::Usage {
+            images: raster.images,
+            vectors: vector.vectors,
+            bytes: raster.bytes + vector.bytes,
+            allocated: self.texture_atlas.allocated(),
+        }
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32> {
         let mut cache = self.vector_cache.borrow_mut();
@@ -306,7 +362,14 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    rotation,
+                    flip,
+                    filter,
+                    opacity,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         &mut (device, encoder),
@@ -315,6 +378,10 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *rotation,
+                            *flip,
+                            *filter,
+                            *opacity,
                             atlas_entry,
                             instances,
                         );
@@ -342,6 +409,10 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            0.0,
+                            image::Flip::default(),
+                            image::Filter::NONE,
+                            1.0,
                             atlas_entry,
                             instances,
                         );
@@ -356,10 +427,10 @@ impl Pipeline {
             return;
         }
 
-        let texture_version = self.texture_atlas.layer_count();
+        let texture_version = self.texture_atlas.version();
 
         if self.texture_version != texture_version {
-            log::info!("Atlas has grown. Recreating bind group...");
+            log::info!("Atlas has been resized. Recreating bind group...");
 
             self.texture =
                 device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -461,15 +532,65 @@ impl Pipeline {
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
     ) {
+        // Both caches share the memory of the budget
+        let reserve = |bytes: u64| image::Budget {
+            memory: self
+                .budget
+                .memory
+                .map(|memory| memory.saturating_sub(bytes)),
+            ..self.budget
+        };
+
         #[cfg(feature = "image")]
-        self.raster_cache
-            .borrow_mut()
-            .trim(&mut self.texture_atlas, &mut (device, encoder));
+        {
+            #[cfg(feature = "svg")]
+            let budget = reserve(self.vector_cache.borrow().usage().bytes);
+            #[cfg(not(feature = "svg"))]
+            let budget = reserve(0);
+
+            let mut raster_cache = self.raster_cache.borrow_mut();
+
+            raster_cache.trim(
+                &budget,
+                &mut self.texture_atlas,
+                &mut (device, encoder),
+            );
+
+            for request in self.requests.drain(..) {
+                match request {
+                    Request::Preload(handle) => {
+                        let _ = raster_cache.upload(
+                            &handle,
+                            &mut (device, encoder),
+                            &mut self.texture_atlas,
+                        );
+                    }
+                    Request::Evict(handle) => {
+                        raster_cache.evict(
+                            &handle,
+                            &mut self.texture_atlas,
+                            &mut (device, encoder),
+                        );
+                    }
+                }
+            }
+        }
 
         #[cfg(feature = "svg")]
-        self.vector_cache
-            .borrow_mut()
-            .trim(&mut self.texture_atlas, &mut (device, encoder));
+        {
+            #[cfg(feature = "image")]
+            let budget = reserve(self.raster_cache.borrow().usage().bytes);
+            #[cfg(not(feature = "image"))]
+            let budget = reserve(0);
+
+            self.vector_cache.borrow_mut().trim(
+                &budget,
+                &mut self.texture_atlas,
+                &mut (device, encoder),
+            );
+        }
+
+        self.texture_atlas.shrink(device, encoder);
     }
 }
 
@@ -504,6 +625,11 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _center: [f32; 2],
+    _rotation: f32,
+    _opacity: f32,
+    _filter: [f32; 4],
+    _tint: [f32; 4],
 }
 
 impl Instance {
@@ -519,12 +645,34 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    rotation: f32,
+    flip: image::Flip,
+    filter: image::Filter,
+    opacity: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    // Every fragment of an image rotates around the center of the image
+    let transform = Transform {
+        center: [
+            image_position[0] + image_size[0] / 2.0,
+            image_position[1] + image_size[1] / 2.0,
+        ],
+        rotation,
+        flip,
+        filter,
+        opacity,
+    };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                transform,
+                allocation,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.width as f32;
@@ -540,17 +688,28 @@ fn add_instances(
                     height: fragment_height,
                 } = allocation.size();
 
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
-
                 let size = [
                     fragment_width as f32 * scaling_x,
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                let mut offset = [
+                    fragment_x as f32 * scaling_x,
+                    fragment_y as f32 * scaling_y,
+                ];
+
+                // Mirrored fragments swap places within the image
+                if flip.horizontal {
+                    offset[0] = image_size[0] - offset[0] - size[0];
+                }
+
+                if flip.vertical {
+                    offset[1] = image_size[1] - offset[1] - size[1];
+                }
+
+                let position = [x + offset[0], y + offset[1]];
+
+                add_instance(position, size, transform, allocation, instances);
             }
         }
     }
@@ -560,6 +719,7 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    transform: Transform,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -567,19 +727,57 @@ fn add_instance(
     let Size { width, height } = allocation.size();
     let layer = allocation.layer();
 
+    let mut position_in_atlas = [
+        (x as f32 + 0.5) / atlas::SIZE as f32,
+        (y as f32 + 0.5) / atlas::SIZE as f32,
+    ];
+    let mut size_in_atlas = [
+        (width as f32 - 1.0) / atlas::SIZE as f32,
+        (height as f32 - 1.0) / atlas::SIZE as f32,
+    ];
+
+    // Mirror the image by sampling the atlas backwards
+    if transform.flip.horizontal {
+        position_in_atlas[0] += size_in_atlas[0];
+        size_in_atlas[0] = -size_in_atlas[0];
+    }
+
+    if transform.flip.vertical {
+        position_in_atlas[1] += size_in_atlas[1];
+        size_in_atlas[1] = -size_in_atlas[1];
+    }
+
     let instance = Instance {
         _position: position,
         _size: size,
-        _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
-        ],
-        _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
-        ],
+        _position_in_atlas: position_in_atlas,
+        _size_in_atlas: size_in_atlas,
         _layer: layer as u32,
+        _center: transform.center,
+        _rotation: transform.rotation,
+        _opacity: transform.opacity,
+        _filter: [
+            transform.filter.blur / atlas::SIZE as f32,
+            transform.filter.grayscale,
+            transform.filter.brightness,
+            transform.filter.contrast,
+        ],
+        _tint: transform
+            .filter
+            .tint
+            .map_or([0.0; 4], |tint| [tint.r, tint.g, tint.b, tint.a]),
     };
 
     instances.push(instance);
 }
+
+/// The rotation, mirroring, filter and opacity shared by the instances of an
+/// image.
+#[derive(Debug, Clone, Copy)]
+struct Transform {
+    center: [f32; 2],
+    rotation: f32,
+    flip: image::Flip,
+    filter: image::Filter,
+    opacity: f32,
+}
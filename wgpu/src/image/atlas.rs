@@ -22,6 +22,7 @@ pub struct Atlas {
     texture: wgpu::Texture,
     texture_view: wgpu::TextureView,
     layers: Vec<Layer>,
+    version: usize,
 }
 
 impl Atlas {
@@ -53,6 +54,7 @@ impl Atlas {
             texture,
             texture_view,
             layers: vec![Layer::Empty],
+            version: 0,
         }
     }
 
@@ -60,8 +62,38 @@ impl Atlas {
         &self.texture_view
     }
 
-    pub fn layer_count(&self) -> usize {
-        self.layers.len()
+    /// Returns a number that changes every time the texture of the atlas is
+    /// recreated.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the amount of bytes allocated by the texture of the atlas.
+    pub fn allocated(&self) -> u64 {
+        self.layers.len() as u64 * u64::from(SIZE) * u64::from(SIZE) * 4
+    }
+
+    /// Releases the empty layers at the end of the atlas, keeping at least
+    /// one layer.
+    pub fn shrink(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let used = self
+            .layers
+            .iter()
+            .rposition(|layer| !layer.is_empty())
+            .map_or(1, |last| last + 1);
+
+        if used >= self.layers.len() {
+            return;
+        }
+
+        log::info!("Shrinking atlas to {} layers", used);
+
+        self.layers.truncate(used);
+        self.resize(used, device, encoder);
     }
 
     fn allocate(&mut self, width: u32, height: u32) -> Option<Entry> {
@@ -236,6 +268,17 @@ impl Atlas {
             return;
         }
 
+        self.resize(self.layers.len() - amount, device, encoder);
+    }
+
+    /// Recreates the texture of the atlas with the current amount of layers,
+    /// copying the contents of the given amount of layers.
+    fn resize(
+        &mut self,
+        amount_to_copy: usize,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
         let new_texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("iced_wgpu::image texture atlas"),
             size: wgpu::Extent3d {
@@ -252,8 +295,6 @@ impl Atlas {
                 | wgpu::TextureUsages::TEXTURE_BINDING,
         });
 
-        let amount_to_copy = self.layers.len() - amount;
-
         for (i, layer) in
             self.layers.iter_mut().take(amount_to_copy).enumerate()
         {
@@ -296,6 +337,7 @@ impl Atlas {
                 dimension: Some(wgpu::TextureViewDimension::D2Array),
                 ..Default::default()
             });
+        self.version += 1;
     }
 }
 
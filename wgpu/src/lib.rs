@@ -38,11 +38,13 @@
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod offscreen;
 pub mod settings;
 pub mod window;
 
 mod backend;
 mod buffer;
+mod custom;
 mod quad;
 mod text;
 mod triangle;
@@ -52,6 +54,7 @@ pub use iced_native::Theme;
 pub use wgpu;
 
 pub use backend::Backend;
+pub use offscreen::Offscreen;
 pub use settings::Settings;
 
 pub(crate) use iced_graphics::Transformation;
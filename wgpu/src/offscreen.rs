@@ -0,0 +1,301 @@
+//! Render primitives into images without a window.
+use crate::{Backend, Color, Error, Primitive, Renderer, Settings, Viewport};
+
+use futures::stream::{self, StreamExt};
+use iced_native::futures;
+
+use std::num::NonZeroU32;
+
+/// A graphics context that renders primitives into RGBA pixels, without
+/// needing a window.
+///
+/// It can be used to export the [`Geometry`] of a [`Canvas`] as an image,
+/// by rendering its primitive and creating an [`image::Handle`] with
+/// [`image::Handle::from_pixels`].
+///
+/// [`Geometry`]: iced_graphics::widget::canvas::Geometry
+/// [`Canvas`]: iced_graphics::widget::Canvas
+/// [`image::Handle`]: iced_native::image::Handle
+/// [`image::Handle::from_pixels`]: iced_native::image::Handle::from_pixels
+#[allow(missing_debug_implementations)]
+pub struct Offscreen {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    staging_belt: wgpu::util::StagingBelt,
+    settings: Settings,
+    backend: Backend,
+}
+
+impl Offscreen {
+    const CHUNK_SIZE: u64 = 10 * 1024;
+    const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+    /// Creates a new [`Offscreen`] context with the given [`Settings`].
+    pub fn new(settings: Settings) -> Result<Self, Error> {
+        futures::executor::block_on(Self::request(settings))
+            .ok_or(Error::GraphicsAdapterNotFound)
+    }
+
+    async fn request(settings: Settings) -> Option<Self> {
+        let instance = wgpu::Instance::new(settings.internal_backend);
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::LowPower,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await?;
+
+        #[cfg(target_arch = "wasm32")]
+        let limits = [wgpu::Limits::downlevel_webgl2_defaults()
+            .using_resolution(adapter.limits())];
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let limits =
+            [wgpu::Limits::default(), wgpu::Limits::downlevel_defaults()];
+
+        let limits = limits.into_iter().map(|limits| wgpu::Limits {
+            max_bind_groups: 2,
+            ..limits
+        });
+
+        let (device, queue) = stream::iter(limits)
+            .filter_map(|limits| async {
+                adapter
+                    .request_device(
+                        &wgpu::DeviceDescriptor {
+                            label: Some(
+                                "iced_wgpu::offscreen device descriptor",
+                            ),
+                            features: wgpu::Features::empty(),
+                            limits,
+                        },
+                        None,
+                    )
+                    .await
+                    .ok()
+            })
+            .boxed()
+            .next()
+            .await?;
+
+        let backend = Backend::new(&device, settings, Self::FORMAT);
+
+        Some(Offscreen {
+            device,
+            queue,
+            staging_belt: wgpu::util::StagingBelt::new(Self::CHUNK_SIZE),
+            settings,
+            backend,
+        })
+    }
+
+    /// Renders the primitives with the given [`Viewport`] and returns the
+    /// RGBA pixels of the result, row by row.
+    ///
+    /// The resolution of the image is the physical size of the [`Viewport`].
+    pub fn render(
+        &mut self,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Result<Vec<u8>, Error> {
+        render::<&str>(
+            &self.device,
+            &self.queue,
+            &mut self.staging_belt,
+            &mut self.backend,
+            Self::FORMAT,
+            primitives,
+            viewport,
+            background_color,
+            &[],
+        )
+    }
+
+    /// Creates a new [`Renderer`] for this [`Offscreen`] context.
+    ///
+    /// It can be used to lay out and draw widgets, and to present the
+    /// resulting primitives with [`Offscreen::present`].
+    pub fn create_renderer<Theme>(&self) -> Renderer<Theme> {
+        Renderer::new(Backend::new(&self.device, self.settings, Self::FORMAT))
+    }
+
+    /// Renders the primitives drawn by the given [`Renderer`] with the given
+    /// [`Viewport`] and returns the RGBA pixels of the result, row by row.
+    ///
+    /// The [`Renderer`] must have been created with
+    /// [`Offscreen::create_renderer`].
+    pub fn present<Theme>(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Result<Vec<u8>, Error> {
+        let Self {
+            device,
+            queue,
+            staging_belt,
+            ..
+        } = self;
+
+        renderer.with_primitives(|backend, primitives| {
+            render::<&str>(
+                device,
+                queue,
+                staging_belt,
+                backend,
+                Self::FORMAT,
+                primitives,
+                viewport,
+                background_color,
+                &[],
+            )
+        })
+    }
+}
+
+/// Renders the primitives with the given [`Viewport`] into a texture of the
+/// given format and reads back its RGBA pixels, row by row.
+pub(crate) fn render<T: AsRef<str>>(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    staging_belt: &mut wgpu::util::StagingBelt,
+    backend: &mut Backend,
+    format: wgpu::TextureFormat,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Result<Vec<u8>, Error> {
+    let size = viewport.physical_size();
+
+    let extent = wgpu::Extent3d {
+        width: size.width.max(1),
+        height: size.height.max(1),
+        depth_or_array_layers: 1,
+    };
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::offscreen texture"),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC,
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("iced_wgpu::offscreen encoder"),
+        });
+
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::offscreen clear render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: &view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = background_color.into_linear();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                store: true,
+            },
+        })],
+        depth_stencil_attachment: None,
+    });
+
+    backend.present(
+        device,
+        staging_belt,
+        &mut encoder,
+        &view,
+        primitives,
+        viewport,
+        overlay,
+    );
+
+    // Rows of a texture copy must be aligned
+    let unpadded_bytes_per_row = extent.width * 4;
+    let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padded_bytes_per_row =
+        (unpadded_bytes_per_row + alignment - 1) / alignment * alignment;
+
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("iced_wgpu::offscreen output buffer"),
+        size: u64::from(padded_bytes_per_row * extent.height),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture: &texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(padded_bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        extent,
+    );
+
+    staging_belt.finish();
+    let _ = queue.submit(Some(encoder.finish()));
+    staging_belt.recall();
+
+    let slice = buffer.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+
+    let _ = device.poll(wgpu::Maintain::Wait);
+
+    receiver
+        .recv()
+        .map_err(|error| Error::BackendError(error.to_string()))?
+        .map_err(|error| Error::BackendError(error.to_string()))?;
+
+    let mut pixels: Vec<u8> = {
+        let data = slice.get_mapped_range();
+
+        data.chunks(padded_bytes_per_row as usize)
+            .flat_map(|row| &row[..unpadded_bytes_per_row as usize])
+            .copied()
+            .collect()
+    };
+
+    buffer.unmap();
+
+    // Surfaces may prefer a BGRA layout
+    if matches!(
+        format,
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+    ) {
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+
+    Ok(pixels)
+}
@@ -96,6 +96,12 @@ impl Pipeline {
                                 4 => Float32x4,
                                 5 => Float32x4,
                                 6 => Float32,
+                                7 => Float32x4,
+                                8 => Float32x2,
+                                9 => Float32x4,
+                                10 => Float32x2,
+                                11 => Float32,
+                                12 => Float32,
                             ),
                         },
                     ],
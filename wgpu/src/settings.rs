@@ -3,6 +3,8 @@ use std::fmt;
 
 pub use crate::Antialiasing;
 
+use iced_native::image;
+
 /// The settings of a [`Backend`].
 ///
 /// [`Backend`]: crate::Backend
@@ -36,6 +38,11 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The limit on the memory of the texture atlas used to cache images.
+    ///
+    /// By default, images are evicted as soon as they are not drawn.
+    pub image_budget: image::Budget,
 }
 
 impl fmt::Debug for Settings {
@@ -48,6 +55,7 @@ impl fmt::Debug for Settings {
             .field("default_text_size", &self.default_text_size)
             .field("text_multithreading", &self.text_multithreading)
             .field("antialiasing", &self.antialiasing)
+            .field("image_budget", &self.image_budget)
             .finish()
     }
 }
@@ -85,6 +93,7 @@ impl Default for Settings {
             default_text_size: 20.0,
             text_multithreading: false,
             antialiasing: None,
+            image_budget: image::Budget::default(),
         }
     }
 }
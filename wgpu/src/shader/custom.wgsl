@@ -0,0 +1,23 @@
+struct Globals {
+    transform: mat4x4<f32>,
+    bounds: vec4<f32>,
+    scale: f32,
+}
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32(index & 1u), f32((index >> 1u) & 1u));
+
+    var out: VertexOutput;
+    out.uv = uv;
+    out.position = globals.transform * vec4<f32>(globals.bounds.xy + uv * globals.bounds.zw, 0.0, 1.0);
+
+    return out;
+}
@@ -1,8 +1,9 @@
 struct Uniforms {
     transform: mat4x4<f32>,
-    //xy = start, wz = end
+    //linear: xy = start, wz = end
+    //radial: xy = center, z = radius, w = padding
     position: vec4<f32>,
-    //x = start stop, y = end stop, zw = padding
+    //x = start stop, y = end stop, z = kind (0 = linear, 1 = radial), w = padding
     stop_range: vec4<i32>,
 }
 
@@ -34,15 +35,25 @@ fn vs_main(@location(0) input: vec2<f32>) -> VertexOutput {
 //TODO: rewrite without branching
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    let start = uniforms.position.xy;
-    let end = uniforms.position.zw;
     let start_stop = uniforms.stop_range.x;
     let end_stop = uniforms.stop_range.y;
 
-    let v1 = end - start;
-    let v2 = input.raw_position.xy - start;
-    let unit = normalize(v1);
-    let offset = dot(unit, v2) / length(v1);
+    var offset: f32;
+
+    if (uniforms.stop_range.z == 1) {
+        let center = uniforms.position.xy;
+        let radius = uniforms.position.z;
+
+        offset = length(input.raw_position.xy - center) / radius;
+    } else {
+        let start = uniforms.position.xy;
+        let end = uniforms.position.zw;
+
+        let v1 = end - start;
+        let v2 = input.raw_position.xy - start;
+        let unit = normalize(v1);
+        offset = dot(unit, v2) / length(v1);
+    }
 
     let min_stop = color_stops[start_stop];
     let max_stop = color_stops[end_stop];
@@ -84,5 +95,5 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
         ));
     }
 
-    return color;
+    return vec4<f32>(color.rgb * color.a, color.a);
 }
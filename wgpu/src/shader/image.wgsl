@@ -13,12 +13,22 @@ struct VertexInput {
     @location(3) atlas_pos: vec2<f32>,
     @location(4) atlas_scale: vec2<f32>,
     @location(5) layer: i32,
+    @location(6) center: vec2<f32>,
+    @location(7) rotation: f32,
+    @location(8) opacity: f32,
+    @location(9) effect: vec4<f32>,
+    @location(10) tint: vec4<f32>,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
+    @location(2) opacity: f32,
+    @location(3) atlas_min: vec2<f32>,
+    @location(4) atlas_max: vec2<f32>,
+    @location(5) effect: vec4<f32>,
+    @location(6) tint: vec4<f32>,
 }
 
 @vertex
@@ -27,20 +37,87 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.opacity = input.opacity;
 
-    var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, input.scale.y, 0.0, 0.0),
-        vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(input.pos, 0.0, 1.0)
-    );
+    // Flipped images have a negative scale in the atlas
+    let atlas_end = input.atlas_pos + input.atlas_scale;
+    out.atlas_min = min(input.atlas_pos, atlas_end);
+    out.atlas_max = max(input.atlas_pos, atlas_end);
+    out.effect = input.effect;
+    out.tint = input.tint;
 
-    out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
+    // Rotate the vertex around the center of the image
+    let position = input.pos + input.v_pos * input.scale - input.center;
+    let cos_rotation = cos(input.rotation);
+    let sin_rotation = sin(input.rotation);
+
+    let rotated = vec2<f32>(
+        position.x * cos_rotation - position.y * sin_rotation,
+        position.x * sin_rotation + position.y * cos_rotation
+    ) + input.center;
+
+    out.position = globals.transform * vec4<f32>(rotated, 0.0, 1.0);
 
     return out;
 }
 
+// Approximates a gaussian blur with a grid of samples spanning the radius,
+// without leaving the allocation of the image in the atlas
+fn blur(uv: vec2<f32>, layer: i32, atlas_min: vec2<f32>, atlas_max: vec2<f32>, radius: f32) -> vec4<f32> {
+    let spacing = radius / 3.0;
+
+    var sum = vec4<f32>(0.0);
+    var total = 0.0;
+
+    for (var i = -3; i <= 3; i = i + 1) {
+        for (var j = -3; j <= 3; j = j + 1) {
+            let offset = vec2<f32>(f32(i), f32(j));
+            let weight = exp(-2.0 * dot(offset, offset) / 9.0);
+
+            let position = clamp(uv + offset * spacing, atlas_min, atlas_max);
+            let texel = textureSampleLevel(u_texture, u_sampler, position, layer, 0.0);
+
+            // Weigh colors by their alpha, so transparent pixels do not darken the edges
+            sum = sum + vec4<f32>(texel.rgb * texel.a, texel.a) * weight;
+            total = total + weight;
+        }
+    }
+
+    if (sum.a <= 0.0) {
+        return vec4<f32>(0.0);
+    }
+
+    return vec4<f32>(sum.rgb / sum.a, sum.a / total);
+}
+
+// The effect of the filter is packed as (blur, grayscale, brightness, contrast), with the
+// radius of the blur in atlas coordinates
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    var color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    let radius = input.effect.x;
+
+    if (radius > 0.0) {
+        color = blur(input.uv, i32(input.layer), input.atlas_min, input.atlas_max, radius);
+    }
+
+    let grayscale = input.effect.y;
+    let brightness = input.effect.z;
+    let contrast = input.effect.w;
+
+    if (grayscale > 0.0 || brightness != 1.0 || contrast != 1.0 || input.tint.a > 0.0) {
+        // Adjust colors in sRGB space, like CSS filters do
+        var rgb = pow(color.rgb, vec3<f32>(1.0 / 2.2));
+
+        let luma = dot(rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+        rgb = mix(rgb, vec3<f32>(luma), grayscale);
+        rgb = rgb * brightness;
+        rgb = (rgb - 0.5) * contrast + 0.5;
+        rgb = mix(rgb, input.tint.rgb, input.tint.a);
+
+        color = vec4<f32>(pow(clamp(rgb, vec3<f32>(0.0), vec3<f32>(1.0)), vec3<f32>(2.2)), color.a);
+    }
+
+    return vec4<f32>(color.rgb, color.a * input.opacity);
 }
@@ -13,6 +13,12 @@ struct VertexInput {
     @location(4) border_color: vec4<f32>,
     @location(5) border_radius: vec4<f32>,
     @location(6) border_width: f32,
+    @location(7) end_color: vec4<f32>,
+    @location(8) direction: vec2<f32>,
+    @location(9) shadow_color: vec4<f32>,
+    @location(10) shadow_offset: vec2<f32>,
+    @location(11) shadow_blur_radius: f32,
+    @location(12) shadow_spread_radius: f32,
 }
 
 struct VertexOutput {
@@ -23,6 +29,12 @@ struct VertexOutput {
     @location(3) scale: vec2<f32>,
     @location(4) border_radius: vec4<f32>,
     @location(5) border_width: f32,
+    @location(6) end_color: vec4<f32>,
+    @location(7) direction: vec2<f32>,
+    @location(8) shadow_color: vec4<f32>,
+    @location(9) shadow_offset: vec2<f32>,
+    @location(10) shadow_blur_radius: f32,
+    @location(11) shadow_spread_radius: f32,
 }
 
 @vertex
@@ -40,11 +52,28 @@ fn vs_main(input: VertexInput) -> VertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    // Grow the quad to cover its shadow, if any
+    var shadow_offset: vec2<f32> = input.shadow_offset * globals.scale;
+    var shadow_extent: f32 = max(input.shadow_spread_radius + input.shadow_blur_radius, 0.0) * globals.scale;
+    var has_shadow: bool = input.shadow_color.w > 0.0;
+
+    var min_pos: vec2<f32> = select(
+        pos,
+        min(pos, pos + shadow_offset - vec2<f32>(shadow_extent, shadow_extent)),
+        has_shadow
+    );
+    var max_pos: vec2<f32> = select(
+        pos + scale,
+        max(pos + scale, pos + scale + shadow_offset + vec2<f32>(shadow_extent, shadow_extent)),
+        has_shadow
+    );
+    var size: vec2<f32> = max_pos - min_pos;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(size.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, size.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(min_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.color = input.color;
@@ -53,6 +82,12 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.end_color = input.end_color;
+    out.direction = input.direction;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = shadow_offset;
+    out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_spread_radius = input.shadow_spread_radius * globals.scale;
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
 
     return out;
@@ -91,12 +126,59 @@ fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>)
     return rx;
 }
 
+// Interpolates the colors of a linear gradient crossing the whole quad along the given direction.
+fn gradient_color(
+    start: vec4<f32>,
+    end: vec4<f32>,
+    direction: vec2<f32>,
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>
+) -> vec4<f32> {
+    var extent: f32 = max(abs(direction.x) * size.x + abs(direction.y) * size.y, 0.0001);
+    var offset: f32 = dot(frag_coord - (position + size * 0.5), direction) / extent + 0.5;
+
+    return mix(start, end, clamp(offset, 0.0, 1.0));
+}
+
+// The signed distance from a point to the edges of a rounded box, given the vector from its center.
+fn rounded_box_sdf(to_center: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    var q: vec2<f32> = abs(to_center) - half_size + vec2<f32>(radius, radius);
+
+    return length(max(q, vec2<f32>(0.0, 0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+// Computes the coverage of the blurred shadow cast by a rounded box.
+fn shadow_alpha(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    border_radius: f32,
+    spread_radius: f32,
+    blur_radius: f32
+) -> f32 {
+    var half_size: vec2<f32> = max(size * 0.5 + vec2<f32>(spread_radius, spread_radius), vec2<f32>(0.0, 0.0));
+    var radius: f32 = clamp(border_radius + spread_radius, 0.0, min(half_size.x, half_size.y));
+
+    var dist: f32 = rounded_box_sdf(frag_coord - (position + size * 0.5), half_size, radius);
+    var blur: f32 = max(blur_radius, 0.5);
+
+    return 1.0 - smoothstep(-blur, blur, dist);
+}
 
 @fragment
 fn fs_main(
     input: VertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var mixed_color: vec4<f32> = gradient_color(
+        input.color,
+        input.end_color,
+        input.direction,
+        input.position.xy,
+        input.pos,
+        input.scale
+    );
+    var fill_color: vec4<f32> = mixed_color;
 
     var border_radius = select_border_radius(
         input.border_radius,
@@ -120,7 +202,7 @@ fn fs_main(
             internal_distance
         );
 
-        mixed_color = mix(input.color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        mixed_color = mix(fill_color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
@@ -136,5 +218,41 @@ fn fs_main(
         dist
     );
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    var quad_color: vec4<f32> = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+
+    if (input.shadow_color.w <= 0.0) {
+        return quad_color;
+    }
+
+    var shadow_position: vec2<f32> = input.pos + input.shadow_offset;
+
+    var shadow_border_radius = select_border_radius(
+        input.border_radius,
+        input.position.xy,
+        shadow_position + input.scale * 0.5
+    );
+
+    // The shadow is only visible outside of the quad
+    var shadow: f32 = shadow_alpha(
+        input.position.xy,
+        shadow_position,
+        input.scale,
+        shadow_border_radius,
+        input.shadow_spread_radius,
+        input.shadow_blur_radius
+    ) * input.shadow_color.w * (1.0 - radius_alpha);
+
+    // Draw the quad over its shadow
+    var alpha: f32 = quad_color.w + shadow * (1.0 - quad_color.w);
+
+    if (alpha <= 0.0) {
+        return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    }
+
+    var color: vec3<f32> = (
+        quad_color.xyz * quad_color.w
+        + input.shadow_color.xyz * shadow * (1.0 - quad_color.w)
+    ) / alpha;
+
+    return vec4<f32>(color, alpha);
 }
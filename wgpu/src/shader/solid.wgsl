@@ -26,5 +26,5 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return input.color;
+    return vec4<f32>(input.color.rgb * input.color.a, input.color.a);
 }
@@ -7,7 +7,7 @@ use crate::Transformation;
 
 use iced_graphics::layer::mesh::{self, Mesh};
 use iced_graphics::triangle::ColoredVertex2D;
-use iced_graphics::Size;
+use iced_graphics::{BlendMode, Size};
 #[cfg(feature = "tracing")]
 use tracing::info_span;
 
@@ -53,6 +53,7 @@ impl Pipeline {
         target_size: Size<u32>,
         transformation: Transformation,
         scale_factor: f32,
+        blend_mode: BlendMode,
         meshes: &[Mesh<'_>],
     ) {
         #[cfg(feature = "tracing")]
@@ -139,52 +140,64 @@ impl Pipeline {
 
                     gradient_vertex_offset += written_bytes;
 
-                    match gradient {
-                        iced_graphics::Gradient::Linear(linear) => {
-                            use glam::{IVec4, Vec4};
-
-                            let start_offset = self.gradient.color_stop_offset;
-                            let end_offset = (linear.color_stops.len() as i32)
-                                + start_offset
-                                - 1;
-
-                            self.gradient.uniforms.push(&gradient::Uniforms {
-                                transform: transform.into(),
-                                direction: Vec4::new(
-                                    linear.start.x,
-                                    linear.start.y,
-                                    linear.end.x,
-                                    linear.end.y,
-                                ),
-                                stop_range: IVec4::new(
-                                    start_offset,
-                                    end_offset,
-                                    0,
-                                    0,
-                                ),
-                            });
-
-                            self.gradient.color_stop_offset = end_offset + 1;
-
-                            let stops: Vec<gradient::ColorStop> = linear
-                                .color_stops
-                                .iter()
-                                .map(|stop| {
-                                    let [r, g, b, a] = stop.color.into_linear();
-
-                                    gradient::ColorStop {
-                                        offset: stop.offset,
-                                        color: Vec4::new(r, g, b, a),
-                                    }
-                                })
-                                .collect();
+                    use glam::{IVec4, Vec4};
 
-                            self.gradient
-                                .color_stops_pending_write
-                                .color_stops
-                                .extend(stops);
-                        }
-                    }
+                    let (direction, kind) = match gradient {
+                        iced_graphics::Gradient::Linear(linear) => (
+                            Vec4::new(
+                                linear.start.x,
+                                linear.start.y,
+                                linear.end.x,
+                                linear.end.y,
+                            ),
+                            gradient::LINEAR,
+                        ),
+                        iced_graphics::Gradient::Radial(radial) => (
+                            Vec4::new(
+                                radial.center.x,
+                                radial.center.y,
+                                radial.radius,
+                                0.0,
+                            ),
+                            gradient::RADIAL,
+                        ),
+                    };
+
+                    let color_stops = gradient.color_stops();
+
+                    let start_offset = self.gradient.color_stop_offset;
+                    let end_offset =
+                        (color_stops.len() as i32) + start_offset - 1;
+
+                    self.gradient.uniforms.push(&gradient::Uniforms {
+                        transform: transform.into(),
+                        direction,
+                        stop_range: IVec4::new(
+                            start_offset,
+                            end_offset,
+                            kind,
+                            0,
+                        ),
+                    });
+
+                    self.gradient.color_stop_offset = end_offset + 1;
+
+                    let stops: Vec<gradient::ColorStop> = color_stops
+                        .iter()
+                        .map(|stop| {
+                            let [r, g, b, a] = stop.color.into_linear();
+
+                            gradient::ColorStop {
+                                offset: stop.offset,
+                                color: Vec4::new(r, g, b, a),
+                            }
+                        })
+                        .collect();
+
+                    self.gradient
+                        .color_stops_pending_write
+                        .color_stops
+                        .extend(stops);
                 }
                 #[cfg(target_arch = "wasm32")]
                 Mesh::Gradient { .. } => {}
@@ -235,6 +248,13 @@ impl Pipeline {
             self.gradient.color_stops_pending_write.color_stops.clear();
         }
 
+        // With multisampling, the meshes are blended when resolved instead
+        let mesh_blend_mode = if self.blit.is_some() {
+            BlendMode::Normal
+        } else {
+            blend_mode
+        };
+
         // Configure render pass
         {
             let (attachment, resolve_target, load) = if let Some(blit) =
@@ -286,7 +306,9 @@ impl Pipeline {
                 match mesh {
                     Mesh::Solid { .. } => {
                         if !last_is_solid.unwrap_or(false) {
-                            render_pass.set_pipeline(&self.solid.pipeline);
+                            render_pass.set_pipeline(
+                                &self.solid.pipelines[mesh_blend_mode as usize],
+                            );
 
                             last_is_solid = Some(true);
                         }
@@ -307,7 +329,10 @@ impl Pipeline {
                     #[cfg(not(target_arch = "wasm32"))]
                     Mesh::Gradient { .. } => {
                         if last_is_solid.unwrap_or(true) {
-                            render_pass.set_pipeline(&self.gradient.pipeline);
+                            render_pass.set_pipeline(
+                                &self.gradient.pipelines
+                                    [mesh_blend_mode as usize],
+                            );
 
                             last_is_solid = Some(false);
                         }
@@ -346,21 +371,50 @@ impl Pipeline {
         self.index_buffer.clear();
 
         if let Some(blit) = &mut self.blit {
-            blit.draw(encoder, target);
+            blit.draw(encoder, target, blend_mode);
         }
     }
 }
 
 fn fragment_target(
     texture_format: wgpu::TextureFormat,
+    blend_mode: BlendMode,
 ) -> Option<wgpu::ColorTargetState> {
     Some(wgpu::ColorTargetState {
         format: texture_format,
-        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+        blend: Some(blend_state(blend_mode)),
         write_mask: wgpu::ColorWrites::ALL,
     })
 }
 
+/// Returns the [`wgpu::BlendState`] of a [`BlendMode`], for fragments with
+/// premultiplied alpha.
+fn blend_state(blend_mode: BlendMode) -> wgpu::BlendState {
+    let color = match blend_mode {
+        BlendMode::Normal => wgpu::BlendComponent::OVER,
+        BlendMode::Multiply => wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::Dst,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        },
+        BlendMode::Screen => wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrc,
+            operation: wgpu::BlendOperation::Add,
+        },
+        BlendMode::Additive => wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        },
+    };
+
+    wgpu::BlendState {
+        color,
+        alpha: wgpu::BlendComponent::OVER,
+    }
+}
+
 fn primitive_state() -> wgpu::PrimitiveState {
     wgpu::PrimitiveState {
         topology: wgpu::PrimitiveTopology::TriangleList,
@@ -385,11 +439,13 @@ mod solid {
     use crate::settings;
     use crate::triangle;
     use encase::ShaderType;
-    use iced_graphics::Transformation;
+    use iced_graphics::{BlendMode, Transformation};
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        /// A pipeline for each [`BlendMode`], in the order of
+        /// [`BlendMode::ALL`].
+        pub pipelines: [wgpu::RenderPipeline; 4],
         pub vertices: Buffer<triangle::ColoredVertex2D>,
         pub uniforms: dynamic::Buffer<Uniforms>,
         pub bind_group_layout: wgpu::BindGroupLayout,
@@ -466,8 +522,8 @@ mod solid {
                     ),
                 });
 
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
+            let pipelines = BlendMode::ALL.map(|blend_mode| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                     label: Some("iced_wgpu::triangle::solid pipeline"),
                     layout: Some(&layout),
                     vertex: wgpu::VertexState {
@@ -490,17 +546,19 @@ mod solid {
                     fragment: Some(wgpu::FragmentState {
                         module: &shader,
                         entry_point: "fs_main",
-                        targets: &[triangle::fragment_target(format)],
+                        targets: &[triangle::fragment_target(
+                            format, blend_mode,
+                        )],
                     }),
                     primitive: triangle::primitive_state(),
                     depth_stencil: None,
                     multisample: triangle::multisample_state(antialiasing),
                     multiview: None,
-                },
-            );
+                })
+            });
 
             Self {
-                pipeline,
+                pipelines,
                 vertices,
                 uniforms,
                 bind_group_layout,
@@ -541,10 +599,13 @@ mod gradient {
     use encase::ShaderType;
     use glam::{IVec4, Vec4};
     use iced_graphics::triangle::Vertex2D;
+    use iced_graphics::BlendMode;
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        /// A pipeline for each [`BlendMode`], in the order of
+        /// [`BlendMode::ALL`].
+        pub pipelines: [wgpu::RenderPipeline; 4],
         pub vertices: Buffer<Vertex2D>,
         pub uniforms: dynamic::Buffer<Uniforms>,
         pub storage: dynamic::Buffer<Storage>,
@@ -556,12 +617,19 @@ mod gradient {
         pub bind_group: wgpu::BindGroup,
     }
 
+    /// The kind of a linear gradient in the `stop_range` of its [`Uniforms`].
+    pub const LINEAR: i32 = 0;
+
+    /// The kind of a radial gradient in the `stop_range` of its [`Uniforms`].
+    pub const RADIAL: i32 = 1;
+
     #[derive(Debug, ShaderType)]
     pub struct Uniforms {
         pub transform: glam::Mat4,
-        //xy = start, zw = end
+        //linear: xy = start, zw = end
+        //radial: xy = center, z = radius, w = padding
         pub direction: Vec4,
-        //x = start stop, y = end stop, zw = padding
+        //x = start stop, y = end stop, z = kind, w = padding
         pub stop_range: IVec4,
     }
 
@@ -663,8 +731,8 @@ mod gradient {
                     ),
                 });
 
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
+            let pipelines = BlendMode::ALL.map(|blend_mode| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                     label: Some("iced_wgpu::triangle::gradient pipeline"),
                     layout: Some(&layout),
                     vertex: wgpu::VertexState {
@@ -683,17 +751,19 @@ mod gradient {
                     fragment: Some(wgpu::FragmentState {
                         module: &shader,
                         entry_point: "fs_main",
-                        targets: &[triangle::fragment_target(format)],
+                        targets: &[triangle::fragment_target(
+                            format, blend_mode,
+                        )],
                     }),
                     primitive: triangle::primitive_state(),
                     depth_stencil: None,
                     multisample: triangle::multisample_state(antialiasing),
                     multiview: None,
-                },
-            );
+                })
+            });
 
             Self {
-                pipeline,
+                pipelines,
                 vertices,
                 uniforms,
                 storage,
@@ -1,9 +1,12 @@
 use crate::settings;
+use crate::triangle;
+
+use iced_graphics::BlendMode;
 
 #[derive(Debug)]
 pub struct Blit {
     format: wgpu::TextureFormat,
-    pipeline: wgpu::RenderPipeline,
+    pipelines: [wgpu::RenderPipeline; 4],
     constants: wgpu::BindGroup,
     texture_layout: wgpu::BindGroupLayout,
     sample_count: u32,
@@ -81,7 +84,7 @@ impl Blit {
                 )),
             });
 
-        let pipeline =
+        let pipelines = BlendMode::ALL.map(|blend_mode| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 label: Some("iced_wgpu::triangle::msaa pipeline"),
                 layout: Some(&layout),
@@ -93,13 +96,7 @@ impl Blit {
                 fragment: Some(wgpu::FragmentState {
                     module: &shader,
                     entry_point: "fs_main",
-                    targets: &[Some(wgpu::ColorTargetState {
-                        format,
-                        blend: Some(
-                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
-                        ),
-                        write_mask: wgpu::ColorWrites::ALL,
-                    })],
+                    targets: &[triangle::fragment_target(format, blend_mode)],
                 }),
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
@@ -113,11 +110,12 @@ impl Blit {
                     alpha_to_coverage_enabled: false,
                 },
                 multiview: None,
-            });
+            })
+        });
 
         Blit {
             format,
-            pipeline,
+            pipelines,
             constants: constant_bind_group,
             texture_layout,
             sample_count: antialiasing.sample_count(),
@@ -165,6 +163,7 @@ impl Blit {
         &self,
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
+        blend_mode: BlendMode,
     ) {
         let mut render_pass =
             encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -180,7 +179,7 @@ impl Blit {
                 depth_stencil_attachment: None,
             });
 
-        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_pipeline(&self.pipelines[blend_mode as usize]);
         render_pass.set_bind_group(0, &self.constants, &[]);
         render_pass.set_bind_group(
             1,
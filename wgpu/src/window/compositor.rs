@@ -1,8 +1,12 @@
-use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+use crate::offscreen;
+use crate::{
+    Antialiasing, Backend, Color, Error, Renderer, Settings, Viewport,
+};
 
 use futures::stream::{self, StreamExt};
 
 use iced_graphics::compositor;
+use iced_graphics::damage;
 use iced_native::futures;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
@@ -18,6 +22,7 @@ pub struct Compositor<Theme> {
     queue: wgpu::Queue,
     staging_belt: wgpu::util::StagingBelt,
     format: wgpu::TextureFormat,
+    damage: damage::Tracker,
     theme: PhantomData<Theme>,
 }
 
@@ -108,6 +113,7 @@ impl<Theme> Compositor<Theme> {
             queue,
             staging_belt,
             format,
+            damage: damage::Tracker::default(),
             theme: PhantomData,
         })
     }
@@ -116,6 +122,31 @@ impl<Theme> Compositor<Theme> {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Returns the [`Antialiasing`] strategies supported by the adapter of
+    /// this [`Compositor`].
+    ///
+    /// Every strategy is returned if the adapter supports multisampling, like
+    /// when choosing one in the [`Settings`].
+    pub fn supported_antialiasing(&self) -> Vec<Antialiasing> {
+        let features = self.adapter.get_texture_format_features(self.format);
+
+        if !features
+            .flags
+            .contains(wgpu::TextureFormatFeatureFlags::MULTISAMPLE)
+        {
+            return Vec::new();
+        }
+
+        // The sample counts supported by the adapter cannot be queried, so
+        // every strategy accepted by the `Settings` is reported
+        vec![
+            Antialiasing::MSAAx2,
+            Antialiasing::MSAAx4,
+            Antialiasing::MSAAx8,
+            Antialiasing::MSAAx16,
+        ]
+    }
 }
 
 impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
@@ -154,6 +185,8 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
         width: u32,
         height: u32,
     ) {
+        self.damage.invalidate();
+
         surface.configure(
             &self.device,
             &wgpu::SurfaceConfiguration {
@@ -173,6 +206,7 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
         compositor::Information {
             adapter: information.name,
             backend: format!("{:?}", information.backend),
+            antialiasing: self.supported_antialiasing(),
         }
     }
 
@@ -184,6 +218,18 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), compositor::SurfaceError> {
+        let tracker = &mut self.damage;
+
+        let damage = renderer.with_primitives(|_, primitives| {
+            tracker.damage(primitives, viewport, background_color, overlay)
+        });
+
+        // The contents of a surface texture are lost once presented, so any
+        // damage redraws the whole frame
+        if damage.map_or(false, |regions| regions.is_empty()) {
+            return Ok(());
+        }
+
         match surface.get_current_texture() {
             Ok(frame) => {
                 let mut encoder = self.device.create_command_encoder(
@@ -246,18 +292,75 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
 
                 Ok(())
             }
-            Err(error) => match error {
-                wgpu::SurfaceError::Timeout => {
-                    Err(compositor::SurfaceError::Timeout)
-                }
-                wgpu::SurfaceError::Outdated => {
-                    Err(compositor::SurfaceError::Outdated)
+            Err(error) => {
+                // The frame was not presented
+                self.damage.invalidate();
+
+                match error {
+                    wgpu::SurfaceError::Timeout => {
+                        Err(compositor::SurfaceError::Timeout)
+                    }
+                    wgpu::SurfaceError::Outdated => {
+                        Err(compositor::SurfaceError::Outdated)
+                    }
+                    wgpu::SurfaceError::Lost => {
+                        Err(compositor::SurfaceError::Lost)
+                    }
+                    wgpu::SurfaceError::OutOfMemory => {
+                        Err(compositor::SurfaceError::OutOfMemory)
+                    }
                 }
-                wgpu::SurfaceError::Lost => Err(compositor::SurfaceError::Lost),
-                wgpu::SurfaceError::OutOfMemory => {
-                    Err(compositor::SurfaceError::OutOfMemory)
-                }
-            },
+            }
+        }
+    }
+
+    fn invalidate(&mut self) {
+        self.damage.invalidate();
+    }
+
+    fn change_antialiasing(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        antialiasing: Option<Antialiasing>,
+    ) -> Result<(), Error> {
+        if let Some(antialiasing) = antialiasing {
+            if !self.supported_antialiasing().contains(&antialiasing) {
+                return Err(Error::AntialiasingNotSupported);
+            }
         }
+
+        let device = &self.device;
+        let format = self.format;
+
+        renderer.with_primitives(|backend, _| {
+            backend.set_antialiasing(device, format, antialiasing);
+        });
+
+        self.settings.antialiasing = antialiasing;
+        self.damage.invalidate();
+
+        Ok(())
+    }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, Error> {
+        renderer.with_primitives(|backend, primitives| {
+            offscreen::render(
+                &self.device,
+                &self.queue,
+                &mut self.staging_belt,
+                backend,
+                self.format,
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            )
+        })
     }
 }
@@ -10,6 +10,7 @@ use crate::conversion;
 use crate::mouse;
 use crate::renderer;
 use crate::widget::operation;
+use crate::window::{Antialiasing, Screenshot};
 use crate::{
     Command, Debug, Error, Event, Executor, Proxy, Runtime, Settings, Size,
     Subscription,
@@ -19,6 +20,7 @@ use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::compositor;
 use iced_graphics::window;
+use iced_native::input_method::InputMethod;
 use iced_native::program::Program;
 use iced_native::time::Instant;
 use iced_native::user_interface::{self, UserInterface};
@@ -289,6 +291,8 @@ async fn run_instance<A, E, C>(
     let mut cache = user_interface::Cache::default();
     let mut surface = compositor.create_surface(&window);
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
+    let mut antialiasing = None;
 
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
@@ -316,6 +320,8 @@ async fn run_instance<A, E, C>(
         &mut should_exit,
         &mut proxy,
         &mut debug,
+        &mut screenshots,
+        &mut antialiasing,
         &window,
         || compositor.fetch_information(),
     );
@@ -330,9 +336,11 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut redraw_requested = false;
 
     debug.startup_finished();
 
@@ -388,6 +396,8 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
+                        &mut antialiasing,
                         &window,
                         || compositor.fetch_information(),
                     );
@@ -444,13 +454,28 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    update_input_method(
+                        &window,
+                        state.scale_factor(),
+                        &mut input_method,
+                        new_input_method,
+                    );
+                }
+
                 window.request_redraw();
+                redraw_requested = true;
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
 
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         crate::window::RedrawRequest::NextFrame => {
                             ControlFlow::Poll
@@ -482,6 +507,22 @@ async fn run_instance<A, E, C>(
                 #[cfg(feature = "trace")]
                 let _ = info_span!("Application", "FRAME").entered();
 
+                // Redraws requested by the platform may need the whole
+                // frame, since the contents of the window could be lost
+                if !redraw_requested {
+                    compositor.invalidate();
+                }
+
+                redraw_requested = false;
+
+                if let Some(antialiasing) = antialiasing.take() {
+                    if let Err(error) = compositor
+                        .change_antialiasing(&mut renderer, antialiasing)
+                    {
+                        log::warn!("Failed to change antialiasing: {error}");
+                    }
+                }
+
                 let physical_size = state.physical_size();
 
                 if physical_size.width == 0 || physical_size.height == 0 {
@@ -539,6 +580,22 @@ async fn run_instance<A, E, C>(
                     Ok(()) => {
                         debug.render_finished();
 
+                        if !screenshots.is_empty() {
+                            let result = compositor.screenshot(
+                                &mut renderer,
+                                state.viewport(),
+                                state.background_color(),
+                                &debug.overlay(),
+                            );
+
+                            send_screenshots(
+                                &mut screenshots,
+                                result,
+                                &state,
+                                &proxy,
+                            );
+                        }
+
                         // TODO: Handle animations!
                         // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
@@ -552,6 +609,7 @@ async fn run_instance<A, E, C>(
 
                             // Try rendering again next frame.
                             window.request_redraw();
+                            redraw_requested = true;
                         }
                     },
                 }
@@ -584,6 +642,38 @@ async fn run_instance<A, E, C>(
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Answers the pending screenshot requests with the pixels of the current
+/// frame of the window, logging any error found while capturing it.
+pub fn send_screenshots<A: Application>(
+    screenshots: &mut Vec<Box<dyn FnOnce(Screenshot) -> A::Message>>,
+    pixels: Result<Vec<u8>, iced_graphics::Error>,
+    state: &State<A>,
+    proxy: &winit::event_loop::EventLoopProxy<A::Message>,
+) where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    match pixels {
+        Ok(pixels) => {
+            let screenshot = Screenshot::new(
+                pixels,
+                state.physical_size(),
+                state.scale_factor(),
+            );
+
+            for tag in screenshots.drain(..) {
+                proxy
+                    .send_event(tag(screenshot.clone()))
+                    .expect("Send message to event loop");
+            }
+        }
+        Err(error) => {
+            log::error!("Failed to capture a screenshot: {error}");
+
+            screenshots.clear();
+        }
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -608,6 +698,32 @@ pub fn requests_exit(
     }
 }
 
+/// Enables or disables the input method of the given window, if needed,
+/// placing its candidate window under the requested text cursor.
+pub fn update_input_method(
+    window: &winit::window::Window,
+    scale_factor: f64,
+    current: &mut InputMethod,
+    new: InputMethod,
+) {
+    if *current == new {
+        return;
+    }
+
+    if current.is_enabled() != new.is_enabled() {
+        window.set_ime_allowed(new.is_enabled());
+    }
+
+    if let InputMethod::Enabled { cursor } = new {
+        window.set_ime_position(winit::dpi::PhysicalPosition::new(
+            f64::from(cursor.x) * scale_factor,
+            f64::from(cursor.y + cursor.height) * scale_factor,
+        ));
+    }
+
+    *current = new;
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -656,6 +772,8 @@ pub fn update<A: Application, E: Executor>(
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+    screenshots: &mut Vec<Box<dyn FnOnce(Screenshot) -> A::Message>>,
+    antialiasing: &mut Option<Option<Antialiasing>>,
     window: &winit::window::Window,
     graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
@@ -685,6 +803,8 @@ pub fn update<A: Application, E: Executor>(
             should_exit,
             proxy,
             debug,
+            screenshots,
+            antialiasing,
             window,
             graphics_info,
         );
@@ -706,8 +826,10 @@ pub fn run_command<A, E>(
     should_exit: &mut bool,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
+    screenshots: &mut Vec<Box<dyn FnOnce(Screenshot) -> A::Message>>,
+    antialiasing: &mut Option<Option<Antialiasing>>,
     window: &winit::window::Window,
-    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     A: Application,
     E: Executor,
@@ -799,12 +921,25 @@ pub fn run_command<A, E>(
                         .send_event(tag(window.id().into()))
                         .expect("Send message to event loop");
                 }
+                window::Action::Screenshot(tag) => {
+                    screenshots.push(tag);
+                    window.request_redraw();
+                }
+                window::Action::ChangeAntialiasing(strategy) => {
+                    *antialiasing = Some(strategy);
+                    window.request_redraw();
+                }
+                window::Action::FetchSupportedAntialiasing(tag) => {
+                    proxy
+                        .send_event(tag(graphics_info().antialiasing))
+                        .expect("Send message to event loop");
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
                     #[cfg(feature = "system")]
                     {
-                        let graphics_info = _graphics_info();
+                        let graphics_info = graphics_info();
                         let proxy = proxy.clone();
 
                         let _ = std::thread::spawn(move || {
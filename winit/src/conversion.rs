@@ -2,6 +2,7 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.8/native
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -129,6 +130,9 @@ pub fn window_event(
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(*touch, scale_factor)))
         }
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(input_method_event(ime.clone())))
+        }
         WindowEvent::Moved(position) => {
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
@@ -215,6 +219,27 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts a `Ime` event from [`winit`] to an [`iced_native`] input method
+/// event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/iced-rs/iced/tree/0.8/native
+pub fn input_method_event(ime: winit::event::Ime) -> input_method::Event {
+    match ime {
+        winit::event::Ime::Enabled => input_method::Event::Opened,
+        winit::event::Ime::Preedit(content, selection) => {
+            input_method::Event::Preedit(
+                content,
+                selection.map(|(start, end)| start..end),
+            )
+        }
+        winit::event::Ime::Commit(content) => {
+            input_method::Event::Commit(content)
+        }
+        winit::event::Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
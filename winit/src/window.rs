@@ -2,7 +2,9 @@
 use crate::command::{self, Command};
 use iced_native::window;
 
-pub use window::{frames, Event, Mode, RedrawRequest, UserAttention};
+pub use window::{
+    frames, Antialiasing, Event, Mode, RedrawRequest, Screenshot, UserAttention,
+};
 
 /// Closes the current window and exits the application.
 pub fn close<Message>() -> Command<Message> {
@@ -104,3 +106,36 @@ pub fn fetch_id<Message>(
         f,
     ))))
 }
+
+/// Captures the current frame of the window as a [`Screenshot`].
+///
+/// The frame is captured the next time the window is presented, once any
+/// pending changes have been drawn.
+pub fn screenshot<Message>(
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Screenshot(
+        Box::new(f),
+    )))
+}
+
+/// Changes the [`Antialiasing`] strategy used to render the window.
+///
+/// Providing `None` disables antialiasing. Strategies not listed by
+/// [`fetch_supported_antialiasing`] are ignored.
+pub fn change_antialiasing<Message>(
+    antialiasing: Option<Antialiasing>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::ChangeAntialiasing(antialiasing),
+    ))
+}
+
+/// Fetches the [`Antialiasing`] strategies supported by the graphics adapter.
+pub fn fetch_supported_antialiasing<Message>(
+    f: impl FnOnce(Vec<Antialiasing>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::FetchSupportedAntialiasing(Box::new(f)),
+    ))
+}